@@ -31,6 +31,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/common/file_system.proto",
                 "proto/golem/common/resource_limits.proto",
                 "proto/golem/common/uuid.proto",
+                "proto/golem/common/worker_quotas.proto",
                 "proto/golem/component/producer_field.proto",
                 "proto/golem/component/producers.proto",
                 "proto/golem/component/export.proto",
@@ -49,17 +50,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/component/v1/component_error.proto",
                 "proto/golem/componentcompilation/v1/component_compilation_service.proto",
                 "proto/golem/worker/complete_parameters.proto",
+                "proto/golem/worker/dead_letter.proto",
                 "proto/golem/worker/idempotency_key.proto",
                 "proto/golem/worker/invoke_parameters.proto",
                 "proto/golem/worker/invoke_result.proto",
                 "proto/golem/worker/log_event.proto",
                 "proto/golem/worker/promise_id.proto",
                 "proto/golem/worker/public_oplog.proto",
+                "proto/golem/worker/revert_worker_target.proto",
+                "proto/golem/worker/scheduled_invocation.proto",
+                "proto/golem/worker/topic.proto",
                 "proto/golem/worker/update_mode.proto",
                 "proto/golem/worker/worker_id.proto",
                 "proto/golem/worker/worker_metadata.proto",
                 "proto/golem/worker/worker_filter.proto",
                 "proto/golem/worker/worker_status.proto",
+                "proto/golem/worker/worker_status_change.proto",
                 "proto/golem/worker/v1/worker_service.proto",
                 "proto/golem/worker/v1/worker_execution_error.proto",
                 "proto/golem/worker/v1/worker_error.proto",
@@ -14,7 +14,7 @@
 
 use crate::model::{ApiDefinitionId, ApiDefinitionVersion, GolemError, PathBufOrStdin};
 use async_trait::async_trait;
-use golem_client::model::HttpApiDefinitionWithTypeInfo;
+use golem_client::model::{HttpApiDefinitionRequest, HttpApiDefinitionWithTypeInfo};
 
 #[async_trait]
 pub trait ApiDefinitionClient {
@@ -41,11 +41,27 @@ pub trait ApiDefinitionClient {
         path: PathBufOrStdin,
         project: &Self::ProjectContext,
     ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError>;
+    async fn create_from_request(
+        &self,
+        definition: &HttpApiDefinitionRequest,
+        project: &Self::ProjectContext,
+    ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError>;
+    async fn update_from_request(
+        &self,
+        definition: &HttpApiDefinitionRequest,
+        project: &Self::ProjectContext,
+    ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError>;
     async fn import(
         &self,
         path: PathBufOrStdin,
         project: &Self::ProjectContext,
     ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError>;
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        project: &Self::ProjectContext,
+    ) -> Result<serde_json::Value, GolemError>;
     async fn delete(
         &self,
         id: ApiDefinitionId,
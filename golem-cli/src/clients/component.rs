@@ -16,7 +16,9 @@ use crate::model::component::Component;
 use crate::model::{ComponentName, GolemError, PathBufOrStdin};
 use async_trait::async_trait;
 use golem_client::model::ComponentType;
-use golem_common::file_system::PackagedFileSet;
+use golem_common::file_system::{PackagedFileSet, PackagedFiles};
+use golem_common::model::quotas::WorkerQuotas;
+use golem_common::model::FileSystemPermission;
 use golem_common::uri::oss::urn::ComponentUrn;
 
 #[async_trait]
@@ -44,6 +46,7 @@ pub trait ComponentClient {
         project: &Option<Self::ProjectContext>,
         component_type: ComponentType,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component, GolemError>;
     async fn update(
         &self,
@@ -51,5 +54,17 @@ pub trait ComponentClient {
         file: PathBufOrStdin,
         component_type: Option<ComponentType>,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component, GolemError>;
+    async fn download(
+        &self,
+        urn: &ComponentUrn,
+        version: Option<u64>,
+    ) -> Result<Vec<u8>, GolemError>;
+    async fn download_initial_files(
+        &self,
+        urn: &ComponentUrn,
+        version: Option<u64>,
+        permission: FileSystemPermission,
+    ) -> Result<Option<PackagedFiles>, GolemError>;
 }
@@ -20,9 +20,12 @@ use crate::model::{
     WorkersMetadataResponse,
 };
 use async_trait::async_trait;
-use golem_client::model::{GetFilesResponse, InvokeParameters, InvokeResult, ScanCursor, WorkerFilter, WorkerId};
+use golem_client::model::{DeadLetterPolicy, DeadLetteredInvocation, GetFilesResponse, InvokeParameters, InvokeResult, RevertWorkerTarget, ScanCursor, ScheduleInvocationRequest, ScheduledInvocation, WorkerFilter, WorkerId};
 use golem_common::model::public_oplog::PublicOplogEntry;
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
+use uuid::Uuid;
 
 #[async_trait]
 pub trait WorkerClient {
@@ -32,6 +35,7 @@ pub trait WorkerClient {
         component_urn: ComponentUrn,
         args: Vec<String>,
         env: Vec<(String, String)>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<WorkerId, GolemError>;
 
     async fn invoke_and_await(
@@ -90,6 +94,25 @@ pub trait WorkerClient {
         }
     }
 
+    async fn watch(
+        &self,
+        component_urn: ComponentUrn,
+        filter: Option<Vec<String>>,
+        format: Format,
+    ) -> Result<(), GolemError>;
+
+    async fn watch_forever(
+        &self,
+        component_urn: ComponentUrn,
+        filter: Option<Vec<String>>,
+        format: Format,
+    ) -> Result<(), GolemError> {
+        loop {
+            self.watch(component_urn.clone(), filter.clone(), format)
+                .await?;
+        }
+    }
+
     async fn update(
         &self,
         worker_urn: WorkerUrn,
@@ -119,6 +142,94 @@ pub trait WorkerClient {
         worker_uri: WorkerUrn,
         path: &Path,
     ) -> Result<GetFileResponse, GolemError>;
+
+    async fn put_file(
+        &self,
+        worker_urn: WorkerUrn,
+        path: &Path,
+        content: Vec<u8>,
+    ) -> Result<(), GolemError>;
+
+    async fn delete_file(&self, worker_urn: WorkerUrn, path: &Path) -> Result<(), GolemError>;
+
+    async fn make_directory(&self, worker_urn: WorkerUrn, path: &Path) -> Result<(), GolemError>;
+
+    async fn schedule_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        request: ScheduleInvocationRequest,
+    ) -> Result<ScheduledInvocation, GolemError>;
+
+    async fn cancel_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        schedule_id: Uuid,
+    ) -> Result<(), GolemError>;
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<ScheduledInvocation>, GolemError>;
+
+    async fn get_dead_lettered_invocations(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<DeadLetteredInvocation>, GolemError>;
+
+    async fn retry_dead_lettered_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        dead_letter_id: Uuid,
+    ) -> Result<(), GolemError>;
+
+    async fn discard_dead_lettered_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        dead_letter_id: Uuid,
+    ) -> Result<(), GolemError>;
+
+    async fn get_dead_letter_policy(
+        &self,
+        component_urn: ComponentUrn,
+    ) -> Result<DeadLetterPolicy, GolemError>;
+
+    async fn update_dead_letter_policy(
+        &self,
+        component_urn: ComponentUrn,
+        policy: DeadLetterPolicy,
+    ) -> Result<DeadLetterPolicy, GolemError>;
+
+    async fn get_topic_subscriptions(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<TopicSubscription>, GolemError>;
+
+    async fn subscribe_to_topic(
+        &self,
+        worker_urn: WorkerUrn,
+        topic: String,
+        function_name: String,
+    ) -> Result<(), GolemError>;
+
+    async fn unsubscribe_from_topic(
+        &self,
+        worker_urn: WorkerUrn,
+        topic: String,
+        function_name: String,
+    ) -> Result<(), GolemError>;
+
+    async fn fork(
+        &self,
+        worker_urn: WorkerUrn,
+        target_worker_name: String,
+        oplog_index_cutoff: u64,
+    ) -> Result<(), GolemError>;
+
+    async fn revert(
+        &self,
+        worker_urn: WorkerUrn,
+        target: RevertWorkerTarget,
+    ) -> Result<(), GolemError>;
 }
 
 pub enum GetFileResponse {
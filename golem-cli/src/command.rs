@@ -18,8 +18,10 @@ use golem_common::uri::oss::uri::ComponentUri;
 
 pub mod api_definition;
 pub mod api_deployment;
+pub mod app;
 pub mod component;
 pub mod profile;
+pub mod rib;
 pub mod worker;
 
 pub trait ComponentRefSplit<ProjectRef> {
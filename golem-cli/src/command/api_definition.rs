@@ -92,6 +92,24 @@ pub enum ApiDefinitionSubcommand<ProjectRef: clap::Args> {
         version: ApiDefinitionVersion,
     },
 
+    /// Exports an existing api definition as an OpenAPI document
+    ///
+    /// The document is printed in the selected output format and can be imported again
+    #[command()]
+    Export {
+        /// The newly created component's owner project
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Api definition id
+        #[arg(short, long)]
+        id: ApiDefinitionId,
+
+        /// Version of the api definition
+        #[arg(short = 'V', long)]
+        version: ApiDefinitionVersion,
+    },
+
     /// Deletes an existing api definition
     #[command()]
     Delete {
@@ -149,6 +167,14 @@ impl<ProjectRef: clap::Args + Send + Sync + 'static> ApiDefinitionSubcommand<Pro
                 let project_id = projects.resolve_id_or_default(project_ref).await?;
                 service.list(id, &project_id).await
             }
+            ApiDefinitionSubcommand::Export {
+                project_ref,
+                id,
+                version,
+            } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.export(id, version, &project_id).await
+            }
             ApiDefinitionSubcommand::Delete {
                 project_ref,
                 id,
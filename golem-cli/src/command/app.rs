@@ -0,0 +1,61 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{Format, GolemError, GolemResult};
+use crate::service::app::AppService;
+use crate::service::project::ProjectResolver;
+use clap::Subcommand;
+use std::path::PathBuf;
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum AppSubcommand<ProjectRef: clap::Args> {
+    /// Deploy the components, workers, API definitions and API deployments of an application manifest
+    ///
+    /// Resources which already match the manifest are left untouched, so the command can be run repeatedly.
+    #[command()]
+    Deploy {
+        /// The project of the application's resources
+        #[command(flatten)]
+        project_ref: ProjectRef,
+
+        /// Path to the application manifest
+        #[arg(short = 'f', long, default_value = "golem.yaml")]
+        manifest: PathBuf,
+
+        /// Only print the planned changes, without deploying anything
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+    },
+}
+
+impl<ProjectRef: clap::Args + Send + Sync + 'static> AppSubcommand<ProjectRef> {
+    pub async fn handle<ProjectContext: Send + Sync>(
+        self,
+        format: Format,
+        service: &(dyn AppService<ProjectContext = ProjectContext> + Send + Sync),
+        projects: &(dyn ProjectResolver<ProjectRef, ProjectContext> + Send + Sync),
+    ) -> Result<GolemResult, GolemError> {
+        match self {
+            AppSubcommand::Deploy {
+                project_ref,
+                manifest,
+                dry_run,
+            } => {
+                let project_id = projects.resolve_id_or_default(project_ref).await?;
+                service.deploy(manifest, project_id, dry_run, format).await
+            }
+        }
+    }
+}
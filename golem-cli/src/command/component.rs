@@ -24,6 +24,7 @@ use crate::service::project::ProjectResolver;
 use clap::Subcommand;
 use golem_client::model::ComponentType;
 use golem_common::file_system::PackagedFileSet;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::uri::oss::uri::ComponentUri;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -54,6 +55,10 @@ pub enum ComponentSubCommand<ProjectRef: clap::Args, ComponentRef: clap::Args> {
         #[arg(short, long)]
         manifest: Option<PathBuf>,
 
+        /// Resource quotas applying to all the workers of the component
+        #[command(flatten)]
+        quotas: WorkerQuotasArg,
+
         /// Do not ask for confirmation for performing an update in case the component already exists
         #[arg(short = 'y', long)]
         non_interactive: bool,
@@ -86,6 +91,11 @@ pub enum ComponentSubCommand<ProjectRef: clap::Args, ComponentRef: clap::Args> {
         #[arg(short, long)]
         manifest: Option<PathBuf>,
 
+        /// Resource quotas applying to all the workers of the new version. If none specified, the
+        /// previous version's quotas are used.
+        #[command(flatten)]
+        quotas: WorkerQuotasArg,
+
         /// Do not ask for confirmation for creating a new component in case it does not exist
         #[arg(short = 'y', long)]
         non_interactive: bool,
@@ -183,6 +193,46 @@ impl UpdatedComponentTypeArg {
     }
 }
 
+#[derive(clap::Args, Debug, Clone)]
+pub struct WorkerQuotasArg {
+    /// Maximum amount of fuel a single invocation of a worker can consume
+    #[arg(long)]
+    max_fuel_per_invocation: Option<u64>,
+
+    /// Maximum size of a worker's linear memories in bytes
+    #[arg(long)]
+    max_memory: Option<u64>,
+
+    /// Maximum number of entries in a worker's oplog
+    #[arg(long)]
+    max_oplog_entries: Option<u64>,
+
+    /// Maximum total size of a worker's oplog entries in bytes
+    #[arg(long)]
+    max_oplog_bytes: Option<u64>,
+
+    /// Maximum number of outgoing HTTP requests a worker can initiate in any one minute window
+    #[arg(long)]
+    max_outgoing_http_calls_per_minute: Option<u32>,
+}
+
+impl WorkerQuotasArg {
+    pub fn quotas(&self) -> Option<WorkerQuotas> {
+        let quotas = WorkerQuotas {
+            max_fuel_per_invocation: self.max_fuel_per_invocation,
+            max_memory: self.max_memory,
+            max_oplog_entries: self.max_oplog_entries,
+            max_oplog_bytes: self.max_oplog_bytes,
+            max_outgoing_http_calls_per_minute: self.max_outgoing_http_calls_per_minute,
+        };
+        if quotas.is_empty() {
+            None
+        } else {
+            Some(quotas)
+        }
+    }
+}
+
 impl<
         ProjectRef: clap::Args + Send + Sync + 'static,
         ComponentRef: ComponentRefSplit<ProjectRef> + clap::Args,
@@ -203,6 +253,7 @@ impl<
                 component_type,
                 non_interactive,
                 manifest,
+                quotas,
             } => {
                 let project_id = projects.resolve_id_or_default(project_ref).await?;
                 let manifest_directory = manifest.as_ref().map(PathBuf::as_path).and_then(Path::parent);
@@ -220,6 +271,7 @@ impl<
                         non_interactive,
                         format,
                         initial_files,
+                        quotas.quotas(),
                     )
                     .await
             }
@@ -230,6 +282,7 @@ impl<
                 try_update_workers,
                 update_mode,
                 manifest,
+                quotas,
                 non_interactive,
             } => {
                 let (component_name_or_uri, project_ref) = component_name_or_uri.split();
@@ -256,6 +309,7 @@ impl<
                         non_interactive,
                         format,
                         initial_files,
+                        quotas.quotas(),
                     )
                     .await?;
 
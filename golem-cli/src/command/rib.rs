@@ -0,0 +1,99 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::worker::WorkerClient;
+use crate::command::ComponentRefSplit;
+use crate::model::{GolemError, GolemResult, WorkerName};
+use crate::rib_repl::RibRepl;
+use crate::service::component::ComponentService;
+use crate::service::project::ProjectResolver;
+use clap::Subcommand;
+use golem_common::model::TargetWorkerId;
+use golem_common::uri::oss::urn::WorkerUrn;
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum RibSubcommand<ProjectRef: clap::Args, ComponentRef: clap::Args> {
+    /// Start an interactive Rib session for a component
+    ///
+    /// Each line is type checked against the functions exported by the component,
+    /// and the worker functions it calls are invoked on the chosen worker.
+    #[command()]
+    Repl {
+        /// The Golem component
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+
+        /// The version of the component. Defaults to the latest version
+        #[arg(short = 't', long)]
+        version: Option<u64>,
+
+        /// Name of the worker invoked by the functions called in Rib. Defaults to an ephemeral worker
+        #[arg(short, long)]
+        worker_name: Option<WorkerName>,
+    },
+}
+
+impl<
+        ProjectRef: clap::Args + Send + Sync + 'static,
+        ComponentRef: ComponentRefSplit<ProjectRef> + clap::Args,
+    > RibSubcommand<ProjectRef, ComponentRef>
+{
+    pub async fn handle<ProjectContext: Send + Sync>(
+        self,
+        components: Arc<dyn ComponentService<ProjectContext = ProjectContext> + Send + Sync>,
+        worker_client: Arc<dyn WorkerClient + Send + Sync>,
+        projects: &(dyn ProjectResolver<ProjectRef, ProjectContext> + Send + Sync),
+        config_dir: &Path,
+    ) -> Result<GolemResult, GolemError> {
+        match self {
+            RibSubcommand::Repl {
+                component_name_or_uri,
+                version,
+                worker_name,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                let component_urn = components
+                    .resolve_uri(component_name_or_uri, &project_id)
+                    .await?;
+
+                let component = match version {
+                    Some(version) => components.get_metadata(&component_urn, version).await?,
+                    None => components.get_latest_metadata(&component_urn).await?,
+                };
+
+                let worker_urn = WorkerUrn {
+                    id: TargetWorkerId {
+                        component_id: component_urn.id,
+                        worker_name: worker_name.map(|name| name.0),
+                    },
+                };
+
+                RibRepl::new(
+                    component.metadata.exports,
+                    worker_client,
+                    worker_urn,
+                    config_dir.join("rib_history"),
+                )
+                .run()
+                .await?;
+
+                Ok(GolemResult::Str("".to_string()))
+            }
+        }
+    }
+}
@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::command::component::WorkerQuotasArg;
 use crate::command::ComponentRefSplit;
+use chrono::{DateTime, Utc};
 use clap::builder::ValueParser;
 use clap::{ArgMatches, Args, Error, FromArgMatches, Subcommand};
-use golem_client::model::ScanCursor;
+use golem_client::model::{
+    CronSchedule, IntervalSchedule, InvocationSchedule, OnceSchedule, RevertLastInvocations,
+    RevertToOplogIndex, RevertWorkerTarget, ScanCursor,
+};
 use golem_common::model::TargetWorkerId;
 use golem_common::uri::oss::uri::{ComponentUri, WorkerUri};
 use golem_common::uri::oss::url::{ComponentUrl, WorkerUrl};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::join;
 use tokio::task::spawn;
+use uuid::Uuid;
 
 use crate::model::{
     Format, GolemError, GolemResult, IdempotencyKey, JsonValueParser, WorkerName, WorkerUpdateMode,
@@ -78,6 +86,71 @@ pub struct InvokeParameterList {
     wave: Vec<String>,
 }
 
+#[derive(clap::Args, Debug, Clone)]
+#[group(required = true, multiple = false)]
+pub struct ScheduleArgs {
+    /// Perform the invocation once, at the given point in time (RFC 3339)
+    #[arg(long, value_name = "timestamp")]
+    at: Option<DateTime<Utc>>,
+
+    /// Perform the invocation repeatedly with a fixed period, for example `30s` or `1h 30m`
+    #[arg(long, value_name = "duration", value_parser = humantime::parse_duration)]
+    every: Option<Duration>,
+
+    /// Perform the invocation repeatedly at the times matching a cron expression, including the seconds field,
+    /// for example `0 */5 * * * *`
+    #[arg(long, value_name = "expression")]
+    cron: Option<String>,
+}
+
+impl From<ScheduleArgs> for InvocationSchedule {
+    fn from(value: ScheduleArgs) -> Self {
+        match value {
+            ScheduleArgs { at: Some(at), .. } => InvocationSchedule::Once(OnceSchedule { at }),
+            ScheduleArgs {
+                every: Some(every), ..
+            } => InvocationSchedule::Interval(IntervalSchedule {
+                period_millis: every.as_millis() as u64,
+            }),
+            ScheduleArgs {
+                cron: Some(expression),
+                ..
+            } => InvocationSchedule::Cron(CronSchedule { expression }),
+            _ => unreachable!("clap guarantees that exactly one schedule argument is present"),
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+#[group(required = true, multiple = false)]
+pub struct RevertArgs {
+    /// Revert the worker to the state right after the given oplog index
+    #[arg(long, value_name = "index")]
+    last_oplog_index: Option<u64>,
+
+    /// Revert the given number of the worker's most recent invocations
+    #[arg(long, value_name = "count")]
+    number_of_invocations: Option<u64>,
+}
+
+impl From<RevertArgs> for RevertWorkerTarget {
+    fn from(value: RevertArgs) -> Self {
+        match value {
+            RevertArgs {
+                last_oplog_index: Some(last_oplog_index),
+                ..
+            } => RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex { last_oplog_index }),
+            RevertArgs {
+                number_of_invocations: Some(number_of_invocations),
+                ..
+            } => RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations,
+            }),
+            _ => unreachable!("clap guarantees that exactly one revert argument is present"),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct OssWorkerUriArg {
     pub uri: WorkerUri,
@@ -281,6 +354,10 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         /// List of command line arguments passed to the worker
         #[arg(value_name = "args")]
         args: Vec<String>,
+
+        /// Resource quotas of the worker, overriding the ones defined for its component
+        #[command(flatten)]
+        quotas: WorkerQuotasArg,
     },
 
     /// Generates an idempotency key for achieving at-most-one invocation when doing retries
@@ -346,6 +423,21 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         connect_options: WorkerConnectOptions,
     },
 
+    /// Watch the workers of a component and live stream their status changes
+    #[command()]
+    Watch {
+        /// The Golem component of the watched workers
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+
+        /// Filter for the watched workers in form of `property op value`.
+        ///
+        /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`.
+        /// Can be used multiple times (AND condition is applied between them)
+        #[arg(short, long)]
+        filter: Option<Vec<String>>,
+    },
+
     /// Interrupts a running worker
     #[command()]
     Interrupt {
@@ -447,6 +539,62 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[arg(short = 't', long)]
         target_version: u64,
     },
+    /// Schedules an invocation to be performed once or repeatedly by the worker executor
+    #[command()]
+    Schedule {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the function to be invoked
+        #[arg(short, long)]
+        function: String,
+
+        #[command(flatten)]
+        parameters: InvokeParameterList,
+
+        #[command(flatten)]
+        schedule: ScheduleArgs,
+    },
+    /// Cancels a scheduled invocation
+    #[command()]
+    Unschedule {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// ID of the scheduled invocation, as returned by the schedule command
+        #[arg(short = 's', long)]
+        schedule_id: Uuid,
+    },
+    /// Lists the scheduled invocations of a worker
+    #[command()]
+    ListSchedules {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+    },
+    /// Creates a new worker with a copy of an existing worker's state up to a given oplog index
+    #[command()]
+    Fork {
+        /// The source worker to be forked
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the newly created worker
+        #[arg(short, long)]
+        target: WorkerName,
+
+        /// Index of the last oplog entry copied to the new worker
+        #[arg(short, long)]
+        oplog_index: u64,
+    },
+    /// Reverts a worker by undoing its last invocations or its last oplog entries
+    #[command()]
+    Revert {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        #[command(flatten)]
+        target: RevertArgs,
+    },
     /// Queries and dumps a worker's full oplog
     #[command()]
     Oplog {
@@ -461,6 +609,160 @@ pub enum WorkerSubcommand<ComponentRef: clap::Args, WorkerRef: clap::Args> {
         #[arg(long, conflicts_with = "from")]
         query: Option<String>,
     },
+    /// Changes the files of a worker's file system
+    #[command()]
+    Files {
+        #[command(subcommand)]
+        subcommand: WorkerFilesSubcommand<WorkerRef>,
+    },
+    /// Manages the invocations which failed permanently and were set aside by the component's dead-letter policy
+    #[command()]
+    DeadLetters {
+        #[command(subcommand)]
+        subcommand: WorkerDeadLettersSubcommand<WorkerRef>,
+    },
+    /// Gets or updates the dead-letter policy of a component
+    ///
+    /// When enabled, an invocation which fails permanently no longer fails its worker. Instead it gets recorded
+    /// as a dead letter, and the worker continues with its next pending invocation.
+    #[command()]
+    DeadLetterPolicy {
+        /// The Golem component
+        #[command(flatten)]
+        component_name_or_uri: ComponentRef,
+
+        /// Enables or disables dead-lettering. If missing, the current policy is shown
+        #[arg(long, value_parser = parse_bool)]
+        enabled: Option<bool>,
+    },
+    /// Manages the topics a worker is subscribed to
+    ///
+    /// Every message published to a subscribed topic gets delivered by invoking the subscription's function,
+    /// with the topic's name and the message's payload as its parameters.
+    #[command()]
+    Subscriptions {
+        #[command(subcommand)]
+        subcommand: WorkerSubscriptionsSubcommand<WorkerRef>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum WorkerFilesSubcommand<WorkerRef: clap::Args> {
+    /// Writes a local file to the worker's file system, overwriting the existing file
+    #[command()]
+    Put {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Path of the file in the worker's file system
+        #[arg(short, long)]
+        path: PathBuf,
+
+        /// The local file to be written
+        #[arg(short, long, value_name = "FILE")]
+        source: PathBuf,
+    },
+    /// Deletes a file or a directory with all its contents from the worker's file system
+    #[command()]
+    Rm {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Path of the file or directory in the worker's file system
+        #[arg(short, long)]
+        path: PathBuf,
+    },
+    /// Creates a directory and its missing parents in the worker's file system
+    #[command()]
+    Mkdir {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Path of the directory in the worker's file system
+        #[arg(short, long)]
+        path: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum WorkerDeadLettersSubcommand<WorkerRef: clap::Args> {
+    /// Lists the dead-lettered invocations of a worker
+    #[command()]
+    List {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+    },
+    /// Shows the function, parameters and error of a dead-lettered invocation
+    #[command()]
+    Get {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// ID of the dead-lettered invocation
+        #[arg(short, long)]
+        dead_letter_id: Uuid,
+    },
+    /// Enqueues a dead-lettered invocation again, with its original idempotency key
+    #[command()]
+    Retry {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// ID of the dead-lettered invocation
+        #[arg(short, long)]
+        dead_letter_id: Uuid,
+    },
+    /// Discards a dead-lettered invocation
+    #[command()]
+    Discard {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// ID of the dead-lettered invocation
+        #[arg(short, long)]
+        dead_letter_id: Uuid,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+#[command()]
+pub enum WorkerSubscriptionsSubcommand<WorkerRef: clap::Args> {
+    /// Lists the topic subscriptions of a worker
+    #[command()]
+    List {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+    },
+    /// Subscribes a worker to a topic
+    #[command()]
+    Add {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the topic
+        #[arg(short, long)]
+        topic: String,
+
+        /// Name of the function invoked with each message published to the topic
+        #[arg(short, long)]
+        function: String,
+    },
+    /// Removes a topic subscription of a worker
+    #[command()]
+    Remove {
+        #[command(flatten)]
+        worker_ref: WorkerRef,
+
+        /// Name of the topic
+        #[arg(short, long)]
+        topic: String,
+
+        /// Name of the subscribed function
+        #[arg(short, long)]
+        function: String,
+    },
 }
 
 pub trait WorkerRefSplit<ProjectRef> {
@@ -493,11 +795,19 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                 worker_name,
                 env,
                 args,
+                quotas,
             } => {
                 let (component_name_or_uri, project_ref) = component_name_or_uri.split();
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
                 service
-                    .add(component_name_or_uri, worker_name, env, args, project_id)
+                    .add(
+                        component_name_or_uri,
+                        worker_name,
+                        env,
+                        args,
+                        quotas.quotas(),
+                        project_id,
+                    )
                     .await
             }
             WorkerSubcommand::IdempotencyKey {} => service.idempotency_key().await,
@@ -595,6 +905,16 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                     .connect(worker_uri, project_id, connect_options, format)
                     .await
             }
+            WorkerSubcommand::Watch {
+                component_name_or_uri,
+                filter,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .watch(component_name_or_uri, filter, project_id, format)
+                    .await
+            }
             WorkerSubcommand::Interrupt { worker_ref } => {
                 let (worker_uri, project_ref) = worker_ref.split();
                 let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
@@ -669,6 +989,56 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                     )
                     .await
             }
+            WorkerSubcommand::Schedule {
+                worker_ref,
+                function,
+                parameters,
+                schedule,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .schedule(
+                        worker_uri,
+                        function,
+                        parameters.parameters,
+                        parameters.wave,
+                        schedule.into(),
+                        project_id,
+                    )
+                    .await
+            }
+            WorkerSubcommand::Unschedule {
+                worker_ref,
+                schedule_id,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .unschedule(worker_uri, schedule_id, project_id)
+                    .await
+            }
+            WorkerSubcommand::ListSchedules { worker_ref } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service.list_schedules(worker_uri, project_id).await
+            }
+            WorkerSubcommand::Fork {
+                worker_ref,
+                target,
+                oplog_index,
+            } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .fork(worker_uri, target, oplog_index, project_id)
+                    .await
+            }
+            WorkerSubcommand::Revert { worker_ref, target } => {
+                let (worker_uri, project_ref) = worker_ref.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service.revert(worker_uri, target.into(), project_id).await
+            }
             WorkerSubcommand::Oplog {
                 worker_ref,
                 from,
@@ -687,6 +1057,105 @@ impl<ComponentRef: clap::Args, WorkerRef: clap::Args> WorkerSubcommand<Component
                     (Some(from), None) => service.get_oplog(worker_uri, from, project_id).await,
                 }
             }
+            WorkerSubcommand::Files { subcommand } => match subcommand {
+                WorkerFilesSubcommand::Put {
+                    worker_ref,
+                    path,
+                    source,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .put_file(worker_uri, &path, &source, project_id)
+                        .await
+                }
+                WorkerFilesSubcommand::Rm { worker_ref, path } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.delete_file(worker_uri, &path, project_id).await
+                }
+                WorkerFilesSubcommand::Mkdir { worker_ref, path } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.make_directory(worker_uri, &path, project_id).await
+                }
+            },
+            WorkerSubcommand::DeadLetters { subcommand } => match subcommand {
+                WorkerDeadLettersSubcommand::List { worker_ref } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.list_dead_letters(worker_uri, project_id).await
+                }
+                WorkerDeadLettersSubcommand::Get {
+                    worker_ref,
+                    dead_letter_id,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .get_dead_letter(worker_uri, dead_letter_id, project_id)
+                        .await
+                }
+                WorkerDeadLettersSubcommand::Retry {
+                    worker_ref,
+                    dead_letter_id,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .retry_dead_letter(worker_uri, dead_letter_id, project_id)
+                        .await
+                }
+                WorkerDeadLettersSubcommand::Discard {
+                    worker_ref,
+                    dead_letter_id,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .discard_dead_letter(worker_uri, dead_letter_id, project_id)
+                        .await
+                }
+            },
+            WorkerSubcommand::DeadLetterPolicy {
+                component_name_or_uri,
+                enabled,
+            } => {
+                let (component_name_or_uri, project_ref) = component_name_or_uri.split();
+                let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                service
+                    .dead_letter_policy(component_name_or_uri, enabled, project_id)
+                    .await
+            }
+            WorkerSubcommand::Subscriptions { subcommand } => match subcommand {
+                WorkerSubscriptionsSubcommand::List { worker_ref } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service.list_subscriptions(worker_uri, project_id).await
+                }
+                WorkerSubscriptionsSubcommand::Add {
+                    worker_ref,
+                    topic,
+                    function,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .subscribe(worker_uri, topic, function, project_id)
+                        .await
+                }
+                WorkerSubscriptionsSubcommand::Remove {
+                    worker_ref,
+                    topic,
+                    function,
+                } => {
+                    let (worker_uri, project_ref) = worker_ref.split();
+                    let project_id = projects.resolve_id_or_default_opt(project_ref).await?;
+                    service
+                        .unsubscribe(worker_uri, topic, function, project_id)
+                        .await
+                }
+            },
         }
     }
 }
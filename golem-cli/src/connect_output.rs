@@ -15,7 +15,7 @@
 use crate::command::worker::WorkerConnectOptions;
 use crate::model::Format;
 use colored::Colorize;
-use golem_common::model::{LogLevel, Timestamp};
+use golem_common::model::{LogLevel, Timestamp, WorkerStatusChange};
 use std::fmt::Write;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -210,3 +210,19 @@ impl ConnectOutput {
         result
     }
 }
+
+/// Prints a worker status change received while watching the workers of a component
+pub fn print_worker_status_change(change: &WorkerStatusChange, format: Format) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string(change).unwrap()),
+        Format::Yaml => println!("{}", serde_yaml::to_string(change).unwrap()),
+        Format::Text => println!(
+            "[{}] {} (version {}): {} -> {}",
+            change.timestamp,
+            change.worker_id,
+            change.component_version,
+            change.previous_status,
+            change.status
+        ),
+    }
+}
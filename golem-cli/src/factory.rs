@@ -19,6 +19,7 @@ use crate::clients::health_check::HealthCheckClient;
 use crate::clients::worker::WorkerClient;
 use crate::service::api_definition::{ApiDefinitionService, ApiDefinitionServiceLive};
 use crate::service::api_deployment::{ApiDeploymentService, ApiDeploymentServiceLive};
+use crate::service::app::{AppService, AppServiceLive};
 use crate::service::component::{ComponentService, ComponentServiceLive};
 use crate::service::deploy::{DeployService, DeployServiceLive};
 use crate::service::project::ProjectResolver;
@@ -104,4 +105,18 @@ pub trait ServiceFactory {
             worker_service: self.worker_service(),
         })
     }
+
+    fn app_service(
+        &self,
+    ) -> Arc<dyn AppService<ProjectContext = Self::ProjectContext> + Send + Sync>
+    where
+        Self: Send + Sync + Sized + 'static,
+    {
+        Arc::new(AppServiceLive {
+            component_client: self.component_client(),
+            worker_client: self.worker_client(),
+            api_definition_client: self.api_definition_client(),
+            api_deployment_client: self.api_deployment_client(),
+        })
+    }
 }
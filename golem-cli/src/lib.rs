@@ -42,6 +42,7 @@ pub mod file_system;
 pub mod init;
 pub mod model;
 pub mod oss;
+pub mod rib_repl;
 pub mod service;
 pub mod stubgen;
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod app;
 pub mod component;
 pub mod deploy;
 pub mod invoke_result_view;
@@ -35,10 +36,12 @@ use clap::{Arg, ArgMatches, Error, FromArgMatches};
 use clap_verbosity_flag::Verbosity;
 use derive_more::{Display, FromStr};
 use golem_client::model::{ApiDefinitionInfo, ApiSite, ScanCursor};
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::trim_date::TrimDateTime;
 use golem_common::uri::oss::uri::ComponentUri;
 use golem_common::uri::oss::url::ComponentUrl;
-use golem_common::uri::oss::urn::WorkerUrn;
+use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
 use golem_examples::model::{Example, ExampleName, GuestLanguage, GuestLanguageTier};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -514,6 +517,9 @@ pub struct WorkerMetadataView {
     pub total_linear_memory_size: u64,
     #[serde(rename = "ownedResources")]
     pub owned_resources: HashMap<String, golem_client::model::ResourceMetadata>,
+    pub quotas: WorkerQuotas,
+    #[serde(rename = "quotaUsage")]
+    pub quota_usage: golem_client::model::WorkerQuotaUsage,
 }
 
 impl TrimDateTime for WorkerMetadataView {
@@ -542,6 +548,8 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            quotas,
+            quota_usage,
         } = value;
 
         WorkerMetadataView {
@@ -561,10 +569,121 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            quotas,
+            quota_usage,
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledInvocationView {
+    pub schedule_id: Uuid,
+    pub worker_urn: WorkerUrn,
+    pub function: String,
+    pub schedule: String,
+    pub next_invocation_at: DateTime<Utc>,
+}
+
+impl From<golem_client::model::ScheduledInvocation> for ScheduledInvocationView {
+    fn from(value: golem_client::model::ScheduledInvocation) -> Self {
+        let schedule = match value.schedule {
+            golem_client::model::InvocationSchedule::Once(once) => {
+                format!("once at {}", once.at)
+            }
+            golem_client::model::InvocationSchedule::Interval(interval) => format!(
+                "every {}",
+                humantime::format_duration(std::time::Duration::from_millis(
+                    interval.period_millis
+                ))
+            ),
+            golem_client::model::InvocationSchedule::Cron(cron) => {
+                format!("cron {}", cron.expression)
+            }
+        };
+
+        ScheduledInvocationView {
+            schedule_id: value.schedule_id,
+            worker_urn: WorkerUrn {
+                id: value.worker_id.into_target_worker_id(),
+            },
+            function: value.function,
+            schedule,
+            next_invocation_at: value.next_invocation_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledInvocationsView(pub Vec<ScheduledInvocationView>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadLetteredInvocationView {
+    pub dead_letter_id: Uuid,
+    pub worker_urn: WorkerUrn,
+    pub idempotency_key: String,
+    pub function: String,
+    /// The invocation's parameters in WAVE syntax
+    pub params: Vec<String>,
+    pub error: String,
+    pub failed_at: DateTime<Utc>,
+}
+
+impl TryFrom<golem_client::model::DeadLetteredInvocation> for DeadLetteredInvocationView {
+    type Error = GolemError;
+
+    fn try_from(value: golem_client::model::DeadLetteredInvocation) -> Result<Self, Self::Error> {
+        let params = value
+            .params
+            .iter()
+            .map(golem_wasm_rpc::type_annotated_value_to_string)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| GolemError(format!("Failed to format parameter: {err}")))?;
+
+        Ok(DeadLetteredInvocationView {
+            dead_letter_id: value.dead_letter_id,
+            worker_urn: WorkerUrn {
+                id: value.worker_id.into_target_worker_id(),
+            },
+            idempotency_key: value.idempotency_key,
+            function: value.function,
+            params,
+            error: value.error,
+            failed_at: value.failed_at,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetteredInvocationsView(pub Vec<DeadLetteredInvocationView>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeadLetterPolicyView {
+    pub component_urn: ComponentUrn,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicSubscriptionView {
+    pub topic: String,
+    pub function: String,
+}
+
+impl From<TopicSubscription> for TopicSubscriptionView {
+    fn from(value: TopicSubscription) -> Self {
+        TopicSubscriptionView {
+            topic: value.topic,
+            function: value.function_name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicSubscriptionsView(pub Vec<TopicSubscriptionView>);
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct WorkerMetadata {
     pub worker_id: golem_client::model::WorkerId,
@@ -581,6 +700,8 @@ pub struct WorkerMetadata {
     pub component_size: u64,
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<String, golem_client::model::ResourceMetadata>,
+    pub quotas: WorkerQuotas,
+    pub quota_usage: golem_client::model::WorkerQuotaUsage,
 }
 
 impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
@@ -599,6 +720,8 @@ impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            quotas,
+            quota_usage,
         } = value;
 
         WorkerMetadata {
@@ -616,6 +739,8 @@ impl From<golem_client::model::WorkerMetadata> for WorkerMetadata {
             component_size,
             total_linear_memory_size,
             owned_resources,
+            quotas,
+            quota_usage,
         }
     }
 }
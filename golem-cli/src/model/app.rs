@@ -0,0 +1,622 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::oam::{Application, TypedComponentProperties, TypedTraitProperties};
+use crate::model::GolemError;
+use golem_client::model::ComponentType;
+use golem_common::file_system::InitialFile;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+pub const OAM_COMPONENT_TYPE_WASM: &str = "wasm";
+pub const OAM_COMPONENT_TYPE_API_DEFINITION: &str = "api-definition";
+pub const OAM_TRAIT_TYPE_WORKER: &str = "worker";
+pub const OAM_TRAIT_TYPE_API_DEPLOYMENT: &str = "api-deployment";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppComponentType {
+    Durable,
+    Ephemeral,
+}
+
+impl From<AppComponentType> for ComponentType {
+    fn from(value: AppComponentType) -> Self {
+        match value {
+            AppComponentType::Durable => ComponentType::Durable,
+            AppComponentType::Ephemeral => ComponentType::Ephemeral,
+        }
+    }
+}
+
+/// Properties of a `wasm` component of the manifest
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmComponentProperties {
+    pub input_wasm: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<AppComponentType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<InitialFile>,
+}
+
+impl TypedComponentProperties for WasmComponentProperties {
+    fn component_type() -> &'static str {
+        OAM_COMPONENT_TYPE_WASM
+    }
+}
+
+/// Properties of an `api-definition` component of the manifest.
+///
+/// The definition file uses the same format as `api-definition add`, except that the bindings
+/// of the routes can refer to a component of the manifest with `componentName` instead of `componentId`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiDefinitionProperties {
+    pub definition: PathBuf,
+}
+
+impl TypedComponentProperties for ApiDefinitionProperties {
+    fn component_type() -> &'static str {
+        OAM_COMPONENT_TYPE_API_DEFINITION
+    }
+}
+
+/// A worker pre-created for a `wasm` component
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerTraitProperties {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+}
+
+impl TypedTraitProperties for WorkerTraitProperties {
+    fn trait_type() -> &'static str {
+        OAM_TRAIT_TYPE_WORKER
+    }
+}
+
+/// A site an `api-definition` component is deployed to
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiDeploymentTraitProperties {
+    pub host: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdomain: Option<String>,
+}
+
+impl ApiDeploymentTraitProperties {
+    pub fn site(&self) -> String {
+        match &self.subdomain {
+            Some(subdomain) => format!("{}.{}", subdomain, self.host),
+            None => self.host.clone(),
+        }
+    }
+}
+
+impl TypedTraitProperties for ApiDeploymentTraitProperties {
+    fn trait_type() -> &'static str {
+        OAM_TRAIT_TYPE_API_DEPLOYMENT
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AppComponentSpec {
+    pub name: String,
+    pub properties: WasmComponentProperties,
+    pub workers: Vec<WorkerTraitProperties>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AppApiDefinitionSpec {
+    pub name: String,
+    pub properties: ApiDefinitionProperties,
+    pub deployments: Vec<ApiDeploymentTraitProperties>,
+}
+
+/// The resources declared by an application manifest, in the order they have to be deployed
+#[derive(Clone, Debug)]
+pub struct AppDeploySpec {
+    pub name: String,
+    pub components: Vec<AppComponentSpec>,
+    pub api_definitions: Vec<AppApiDefinitionSpec>,
+}
+
+impl AppDeploySpec {
+    pub fn from_application(application: &Application) -> Result<Self, GolemError> {
+        let mut components = vec![];
+        let mut api_definitions = vec![];
+
+        for component in &application.spec.components {
+            match component.component_type.as_str() {
+                OAM_COMPONENT_TYPE_WASM => {
+                    let properties = component
+                        .typed_properties::<WasmComponentProperties>()
+                        .map_err(|err| {
+                            GolemError(format!(
+                                "Invalid properties of component {}: {err}",
+                                component.name
+                            ))
+                        })?;
+
+                    let mut workers = vec![];
+                    for component_trait in &component.traits {
+                        match component_trait.trait_type.as_str() {
+                            OAM_TRAIT_TYPE_WORKER => workers.push(
+                                WorkerTraitProperties::from_generic_trait(component_trait.clone())
+                                    .map_err(|err| {
+                                        GolemError(format!(
+                                            "Invalid worker of component {}: {err}",
+                                            component.name
+                                        ))
+                                    })?,
+                            ),
+                            other => {
+                                return Err(GolemError(format!(
+                                    "Unsupported trait {other} of component {}",
+                                    component.name
+                                )))
+                            }
+                        }
+                    }
+
+                    components.push(AppComponentSpec {
+                        name: component.name.clone(),
+                        properties,
+                        workers,
+                    })
+                }
+                OAM_COMPONENT_TYPE_API_DEFINITION => {
+                    let properties = component
+                        .typed_properties::<ApiDefinitionProperties>()
+                        .map_err(|err| {
+                            GolemError(format!(
+                                "Invalid properties of API definition {}: {err}",
+                                component.name
+                            ))
+                        })?;
+
+                    let mut deployments = vec![];
+                    for component_trait in &component.traits {
+                        match component_trait.trait_type.as_str() {
+                            OAM_TRAIT_TYPE_API_DEPLOYMENT => deployments.push(
+                                ApiDeploymentTraitProperties::from_generic_trait(
+                                    component_trait.clone(),
+                                )
+                                .map_err(|err| {
+                                    GolemError(format!(
+                                        "Invalid deployment of API definition {}: {err}",
+                                        component.name
+                                    ))
+                                })?,
+                            ),
+                            other => {
+                                return Err(GolemError(format!(
+                                    "Unsupported trait {other} of API definition {}",
+                                    component.name
+                                )))
+                            }
+                        }
+                    }
+
+                    api_definitions.push(AppApiDefinitionSpec {
+                        name: component.name.clone(),
+                        properties,
+                        deployments,
+                    })
+                }
+                other => {
+                    return Err(GolemError(format!(
+                        "Unsupported type {other} of component {}",
+                        component.name
+                    )))
+                }
+            }
+        }
+
+        Ok(AppDeploySpec {
+            name: application.metadata.name.clone(),
+            components,
+            api_definitions,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppResourceKind {
+    Component,
+    Worker,
+    ApiDefinition,
+    ApiDeployment,
+}
+
+impl Display for AppResourceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            AppResourceKind::Component => "component",
+            AppResourceKind::Worker => "worker",
+            AppResourceKind::ApiDefinition => "api-definition",
+            AppResourceKind::ApiDeployment => "api-deployment",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// What has to be done to bring a resource of the manifest in sync with the server
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppResourceAction {
+    Create,
+    Update,
+    Unchanged,
+    /// The resource exists, but it cannot be changed to match the manifest
+    Conflict,
+}
+
+impl Display for AppResourceAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            AppResourceAction::Create => "create",
+            AppResourceAction::Update => "update",
+            AppResourceAction::Unchanged => "unchanged",
+            AppResourceAction::Conflict => "conflict",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppResourceStatus {
+    Planned,
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+impl Display for AppResourceStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            AppResourceStatus::Planned => "planned",
+            AppResourceStatus::Succeeded => "succeeded",
+            AppResourceStatus::Failed => "failed",
+            AppResourceStatus::Skipped => "skipped",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppResourceOutcome {
+    pub kind: AppResourceKind,
+    pub name: String,
+    pub action: AppResourceAction,
+    pub status: AppResourceStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppDeployResult {
+    pub application: String,
+    pub dry_run: bool,
+    pub resources: Vec<AppResourceOutcome>,
+}
+
+impl AppDeployResult {
+    pub fn failed_count(&self) -> usize {
+        self.resources
+            .iter()
+            .filter(|resource| resource.status == AppResourceStatus::Failed)
+            .count()
+    }
+}
+
+/// Names of the manifest components referred to by `componentName` in the route bindings
+pub fn referenced_component_names(definition: &Value) -> Vec<String> {
+    route_bindings(definition)
+        .filter_map(|binding| binding.get("componentName"))
+        .filter_map(|name| name.as_str())
+        .map(|name| name.to_string())
+        .unique()
+        .collect()
+}
+
+/// Replaces the `componentName` of the route bindings with a `componentId` pointing to
+/// the component id and version returned by `resolve`
+pub fn resolve_component_names(
+    definition: &mut Value,
+    resolve: impl Fn(&str) -> Result<(Uuid, u64), GolemError>,
+) -> Result<(), GolemError> {
+    let bindings = definition
+        .get_mut("routes")
+        .and_then(|routes| routes.as_array_mut())
+        .into_iter()
+        .flatten()
+        .filter_map(|route| route.get_mut("binding"))
+        .filter_map(|binding| binding.as_object_mut());
+
+    for binding in bindings {
+        if let Some(name) = binding.remove("componentName") {
+            let name = name
+                .as_str()
+                .ok_or_else(|| GolemError("componentName must be a string".to_string()))?;
+            let (component_id, version) = resolve(name)?;
+            binding.insert(
+                "componentId".to_string(),
+                json!({ "componentId": component_id, "version": version }),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps only the parts of an API definition which are both sent to and returned by the server,
+/// so the definition of the manifest can be compared with the deployed one.
+///
+/// Rib scripts are compared by their parsed form, so formatting differences are not reported as changes.
+pub fn comparable_api_definition(definition: &Value) -> Value {
+    let routes = definition
+        .get("routes")
+        .and_then(|routes| routes.as_array())
+        .map(|routes| routes.iter().map(comparable_route).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    json!({
+        "routes": routes,
+        "draft": definition.get("draft").cloned().unwrap_or(Value::Bool(true)),
+        "securitySchemes": without_nulls(
+            definition.get("securitySchemes").cloned().unwrap_or(json!([]))
+        ),
+    })
+}
+
+fn route_bindings(definition: &Value) -> impl Iterator<Item = &Value> {
+    definition
+        .get("routes")
+        .and_then(|routes| routes.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|route| route.get("binding"))
+}
+
+fn comparable_route(route: &Value) -> Value {
+    let mut route = without_nulls(route.clone());
+
+    if let Some(binding) = route.get_mut("binding").and_then(|b| b.as_object_mut()) {
+        // The server adds the inferred input types of the Rib scripts to the bindings
+        binding.retain(|key, _| !key.ends_with("Input"));
+        binding
+            .entry("bindingType")
+            .or_insert(Value::String("Default".to_string()));
+
+        for key in ["workerName", "idempotencyKey", "response"] {
+            if let Some(Value::String(script)) = binding.get_mut(key) {
+                if let Some(normalized) = rib::from_string(script.as_str())
+                    .ok()
+                    .and_then(|expr| rib::to_string(&expr).ok())
+                {
+                    *script = normalized;
+                }
+            }
+        }
+    }
+
+    route
+}
+
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(without_nulls).collect()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::app::{
+        comparable_api_definition, referenced_component_names, resolve_component_names,
+        ApiDeploymentTraitProperties, AppComponentType, AppDeploySpec,
+    };
+    use crate::model::oam::Application;
+    use crate::model::GolemError;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    const MANIFEST: &str = r#"
+        apiVersion: core.oam.dev/v1beta1
+        kind: Application
+        metadata:
+          name: shopping-cart-app
+        spec:
+          components:
+          - name: shopping-cart
+            type: wasm
+            properties:
+              inputWasm: target/shopping_cart.wasm
+              componentType: ephemeral
+              files:
+              - sourcePath: ./data/config.json
+                targetPath: /config.json
+            traits:
+            - type: worker
+              properties:
+                name: cart-1
+                env:
+                  CURRENCY: EUR
+          - name: shopping-cart-api
+            type: api-definition
+            properties:
+              definition: api/shopping-cart.yaml
+            traits:
+            - type: api-deployment
+              properties:
+                host: localhost:9006
+                subdomain: shop
+    "#;
+
+    #[test]
+    fn app_deploy_spec_from_application() {
+        let application = Application::from_yaml_str(MANIFEST).unwrap();
+        let spec = AppDeploySpec::from_application(&application).unwrap();
+
+        assert_eq!(spec.name, "shopping-cart-app");
+        assert_eq!(spec.components.len(), 1);
+        assert_eq!(
+            spec.components[0].properties.component_type,
+            Some(AppComponentType::Ephemeral)
+        );
+        assert_eq!(spec.components[0].properties.files.len(), 1);
+        assert_eq!(spec.components[0].workers[0].name, "cart-1");
+        assert_eq!(
+            spec.components[0].workers[0].env.get("CURRENCY"),
+            Some(&"EUR".to_string())
+        );
+        assert_eq!(spec.api_definitions.len(), 1);
+        assert_eq!(
+            spec.api_definitions[0].deployments,
+            vec![ApiDeploymentTraitProperties {
+                host: "localhost:9006".to_string(),
+                subdomain: Some("shop".to_string()),
+            }]
+        );
+        assert_eq!(
+            spec.api_definitions[0].deployments[0].site(),
+            "shop.localhost:9006"
+        );
+    }
+
+    #[test]
+    fn app_deploy_spec_with_unsupported_trait() {
+        let manifest = MANIFEST.replace("type: worker", "type: scaler");
+        let application = Application::from_yaml_str(&manifest).unwrap();
+        let result = AppDeploySpec::from_application(&application);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_component_names_in_bindings() {
+        let component_id = Uuid::new_v4();
+        let mut definition = json!({
+            "id": "shopping-cart",
+            "version": "0.1.0",
+            "draft": true,
+            "routes": [{
+                "method": "Get",
+                "path": "/cart",
+                "binding": {
+                    "componentName": "shopping-cart",
+                    "workerName": "\"cart-1\"",
+                    "response": "{ status: 200u64 }"
+                }
+            }]
+        });
+
+        assert_eq!(
+            referenced_component_names(&definition),
+            vec!["shopping-cart".to_string()]
+        );
+
+        resolve_component_names(&mut definition, |name| {
+            if name == "shopping-cart" {
+                Ok((component_id, 3))
+            } else {
+                Err(GolemError(format!("Unknown component {name}")))
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            definition["routes"][0]["binding"]["componentId"],
+            json!({ "componentId": component_id, "version": 3 })
+        );
+        assert!(definition["routes"][0]["binding"]
+            .get("componentName")
+            .is_none());
+    }
+
+    #[test]
+    fn comparable_api_definition_ignores_server_additions() {
+        let component_id = Uuid::new_v4();
+        let local = json!({
+            "id": "shopping-cart",
+            "version": "0.1.0",
+            "draft": true,
+            "routes": [{
+                "method": "Get",
+                "path": "/cart",
+                "binding": {
+                    "componentId": { "componentId": component_id, "version": 0 },
+                    "workerName": "\"cart-1\"",
+                    "idempotencyKey": null,
+                    "response": "{ status: 200u64 }"
+                }
+            }]
+        });
+        let deployed = json!({
+            "id": "shopping-cart",
+            "version": "0.1.0",
+            "draft": true,
+            "securitySchemes": [],
+            "createdAt": "2024-10-01T10:00:00Z",
+            "routes": [{
+                "method": "Get",
+                "path": "/cart",
+                "binding": {
+                    "componentId": { "componentId": component_id, "version": 0 },
+                    "workerName": "\"cart-1\"",
+                    "response": "{status: 200u64}",
+                    "bindingType": "Default",
+                    "responseMappingInput": { "types": {} },
+                    "workerNameInput": { "types": {} }
+                }
+            }]
+        });
+
+        assert_eq!(
+            comparable_api_definition(&local),
+            comparable_api_definition(&deployed)
+        );
+
+        let mut changed = local.clone();
+        changed["routes"][0]["path"] = json!("/carts");
+
+        assert_ne!(
+            comparable_api_definition(&changed),
+            comparable_api_definition(&deployed)
+        );
+    }
+}
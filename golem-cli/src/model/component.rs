@@ -125,7 +125,7 @@ impl From<&Component> for ComponentView {
     }
 }
 
-fn render_type(typ: &AnalysedType) -> String {
+pub fn render_type(typ: &AnalysedType) -> String {
     match typ {
         AnalysedType::Variant(TypeVariant { cases }) => {
             let cases_str = cases
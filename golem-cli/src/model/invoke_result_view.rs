@@ -126,6 +126,7 @@ mod tests {
     use golem_client::model::{
         AnalysedType, ComponentMetadata, ComponentType, InvokeResult, VersionedComponentId,
     };
+    use golem_common::model::quotas::WorkerQuotas;
 
     use crate::model::component::Component;
     use crate::model::invoke_result_view::InvokeResultView;
@@ -166,6 +167,7 @@ mod tests {
                     results: func_res,
                 })],
                 memories: vec![],
+                quotas: WorkerQuotas::default(),
             },
             project_id: None,
             created_at: Some(Utc::now()),
@@ -16,7 +16,8 @@ pub mod fmt {
     use cli_table::{print_stdout, Row, Title, WithTitle};
     use colored::control::SHOULD_COLORIZE;
     use colored::Colorize;
-    use golem_client::model::WorkerStatus;
+    use golem_client::model::{WorkerQuotaUsage, WorkerStatus};
+    use golem_common::model::quotas::WorkerQuotas;
     use itertools::Itertools;
     use regex::Regex;
 
@@ -166,6 +167,43 @@ pub mod fmt {
         }
     }
 
+    pub fn format_quotas(quotas: &WorkerQuotas) -> String {
+        let mut lines = Vec::new();
+        if let Some(fuel) = quotas.max_fuel_per_invocation {
+            lines.push(format!("fuel per invocation: {fuel}"));
+        }
+        if let Some(memory) = &quotas.max_memory {
+            lines.push(format!("memory: {}", format_binary_size(memory)));
+        }
+        if let Some(entries) = quotas.max_oplog_entries {
+            lines.push(format!("oplog entries: {entries}"));
+        }
+        if let Some(bytes) = &quotas.max_oplog_bytes {
+            lines.push(format!("oplog size: {}", format_binary_size(bytes)));
+        }
+        if let Some(calls) = quotas.max_outgoing_http_calls_per_minute {
+            lines.push(format!("outgoing HTTP calls per minute: {calls}"));
+        }
+        lines.join("\n")
+    }
+
+    pub fn format_quota_usage(usage: &WorkerQuotaUsage) -> String {
+        let mut lines = vec![
+            format!("memory: {}", format_binary_size(&usage.memory)),
+            format!("oplog entries: {}", usage.oplog_entries),
+        ];
+        if let Some(bytes) = &usage.oplog_bytes {
+            lines.push(format!("oplog size: {}", format_binary_size(bytes)));
+        }
+        if let Some(fuel) = usage.fuel_consumed_by_last_invocation {
+            lines.push(format!("fuel consumed by last invocation: {fuel}"));
+        }
+        if let Some(calls) = usage.outgoing_http_calls_in_last_minute {
+            lines.push(format!("outgoing HTTP calls in last minute: {calls}"));
+        }
+        lines.join("\n")
+    }
+
     static BUILTIN_TYPES: phf::Set<&'static str> = phf::phf_set! {
         "bool",
         "s8", "s16", "s32", "s64",
@@ -461,6 +499,68 @@ pub mod api_deployment {
     }
 }
 
+pub mod app {
+    use crate::model::app::{AppDeployResult, AppResourceOutcome, AppResourceStatus};
+    use crate::model::text::fmt::*;
+    use cli_table::{print_stdout, Table, WithTitle};
+
+    #[derive(Table)]
+    struct AppResourceTableView {
+        #[table(title = "Kind")]
+        pub kind: String,
+        #[table(title = "Name")]
+        pub name: String,
+        #[table(title = "Action")]
+        pub action: String,
+        #[table(title = "Status")]
+        pub status: String,
+        #[table(title = "Message")]
+        pub message: String,
+    }
+
+    impl From<&AppResourceOutcome> for AppResourceTableView {
+        fn from(value: &AppResourceOutcome) -> Self {
+            let status = match value.status {
+                AppResourceStatus::Failed => format_warn(&value.status),
+                _ => value.status.to_string(),
+            };
+
+            Self {
+                kind: value.kind.to_string(),
+                name: value.name.clone(),
+                action: value.action.to_string(),
+                status,
+                message: value.message.clone().unwrap_or_default(),
+            }
+        }
+    }
+
+    impl TextFormat for AppDeployResult {
+        fn print(&self) {
+            if self.dry_run {
+                println!(
+                    "Planned deployment of application {}:",
+                    format_message_highlight(&self.application)
+                );
+            } else {
+                println!(
+                    "Deployed application {}:",
+                    format_message_highlight(&self.application)
+                );
+            }
+
+            print_stdout(
+                self.resources
+                    .iter()
+                    .map(AppResourceTableView::from)
+                    .collect::<Vec<_>>()
+                    .with_title(),
+            )
+            .unwrap()
+        }
+    }
+}
+
 pub mod component {
     use crate::model::component::ComponentView;
     use crate::model::text::fmt::*;
@@ -689,7 +789,9 @@ pub mod worker {
     use crate::model::invoke_result_view::InvokeResultView;
     use crate::model::text::fmt::*;
     use crate::model::{
-        IdempotencyKey, WorkerMetadata, WorkerMetadataView, WorkersMetadataResponseView,
+        DeadLetterPolicyView, DeadLetteredInvocationView, DeadLetteredInvocationsView,
+        IdempotencyKey, ScheduledInvocationView, ScheduledInvocationsView, TopicSubscriptionView,
+        TopicSubscriptionsView, WorkerMetadata, WorkerMetadataView, WorkersMetadataResponseView,
     };
     use base64::prelude::BASE64_STANDARD;
     use base64::Engine;
@@ -801,7 +903,19 @@ pub mod worker {
                 )
                 .fmt_field_option("Last error", &self.0.last_error, |err| {
                     format_stack(err.as_ref())
-                });
+                })
+                .fmt_field_optional(
+                    "Quotas",
+                    &self.0.quotas,
+                    !self.0.quotas.is_empty(),
+                    format_quotas,
+                )
+                .fmt_field_optional(
+                    "Quota usage",
+                    &self.0.quota_usage,
+                    !self.0.quotas.is_empty(),
+                    format_quota_usage,
+                );
 
             fields.build()
         }
@@ -1002,6 +1116,9 @@ pub mod worker {
                         "{pad}idempotency key:   {}",
                         format_id(&params.idempotency_key)
                     );
+                    if let Some(traceparent) = &params.traceparent {
+                        println!("{pad}traceparent:       {}", format_id(traceparent));
+                    }
                     println!("{pad}input:");
                     for param in &params.request {
                         println!("{pad}  - {}", print_value(param));
@@ -1202,6 +1319,182 @@ pub mod worker {
                     println!("{}", format_message_highlight("RESTART"));
                     println!("{pad}at:                {}", format_id(&params.timestamp));
                 }
+                PublicOplogEntry::ChangeFileSystem(params) => {
+                    println!("{}", format_message_highlight("CHANGE FILE SYSTEM"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}kind:              {}",
+                        format_id(&format!("{:?}", params.kind))
+                    );
+                    println!("{pad}path:              {}", params.path);
+                }
+                PublicOplogEntry::Snapshot(params) => {
+                    println!("{}", format_message_highlight("SNAPSHOT"));
+                    println!("{pad}at:                {}", format_id(&params.timestamp));
+                    println!(
+                        "{pad}size:              {}",
+                        format_id(&format!("{} bytes", params.payload.len()))
+                    );
+                }
+            }
+        }
+    }
+
+    impl MessageWithFields for ScheduledInvocationView {
+        fn message(&self) -> String {
+            format!(
+                "Scheduled invocation of {} {}",
+                format_message_highlight(&self.function),
+                self.schedule
+            )
+        }
+
+        fn fields(&self) -> Vec<(&'static str, String)> {
+            let mut fields = FieldsBuilder::new();
+
+            fields
+                .fmt_field("Schedule ID", &self.schedule_id, format_main_id)
+                .fmt_field("Worker URN", &self.worker_urn, format_id)
+                .field("Function", &self.function)
+                .field("Schedule", &self.schedule)
+                .field("Next invocation at", &self.next_invocation_at);
+
+            fields.build()
+        }
+    }
+
+    #[derive(Table)]
+    struct ScheduledInvocationTableView {
+        #[table(title = "Schedule ID")]
+        pub schedule_id: String,
+        #[table(title = "Function")]
+        pub function: String,
+        #[table(title = "Schedule")]
+        pub schedule: String,
+        #[table(title = "Next invocation at")]
+        pub next_invocation_at: DateTime<Utc>,
+    }
+
+    impl From<&ScheduledInvocationView> for ScheduledInvocationTableView {
+        fn from(value: &ScheduledInvocationView) -> Self {
+            Self {
+                schedule_id: value.schedule_id.to_string(),
+                function: value.function.clone(),
+                schedule: value.schedule.clone(),
+                next_invocation_at: value.next_invocation_at,
+            }
+        }
+    }
+
+    impl TextFormat for ScheduledInvocationsView {
+        fn print(&self) {
+            if self.0.is_empty() {
+                println!("No scheduled invocations");
+            } else {
+                print_table::<_, ScheduledInvocationTableView>(&self.0);
+            }
+        }
+    }
+
+    impl MessageWithFields for DeadLetteredInvocationView {
+        fn message(&self) -> String {
+            format!(
+                "Dead-lettered invocation of {}",
+                format_message_highlight(&self.function)
+            )
+        }
+
+        fn fields(&self) -> Vec<(&'static str, String)> {
+            let mut fields = FieldsBuilder::new();
+
+            fields
+                .fmt_field("Dead letter ID", &self.dead_letter_id, format_main_id)
+                .fmt_field("Worker URN", &self.worker_urn, format_id)
+                .fmt_field("Idempotency key", &self.idempotency_key, format_id)
+                .field("Function", &self.function)
+                .fmt_field_optional(
+                    "Parameters",
+                    &self.params,
+                    !self.params.is_empty(),
+                    |params| params.join(", "),
+                )
+                .field("Failed at", &self.failed_at)
+                .fmt_field("Error", self.error.as_str(), format_stack);
+
+            fields.build()
+        }
+    }
+
+    #[derive(Table)]
+    struct DeadLetteredInvocationTableView {
+        #[table(title = "Dead letter ID")]
+        pub dead_letter_id: String,
+        #[table(title = "Function")]
+        pub function: String,
+        #[table(title = "Failed at")]
+        pub failed_at: DateTime<Utc>,
+        #[table(title = "Error")]
+        pub error: String,
+    }
+
+    impl From<&DeadLetteredInvocationView> for DeadLetteredInvocationTableView {
+        fn from(value: &DeadLetteredInvocationView) -> Self {
+            Self {
+                dead_letter_id: value.dead_letter_id.to_string(),
+                function: value.function.clone(),
+                failed_at: value.failed_at,
+                error: value.error.lines().next().unwrap_or_default().to_string(),
+            }
+        }
+    }
+
+    impl TextFormat for DeadLetteredInvocationsView {
+        fn print(&self) {
+            if self.0.is_empty() {
+                println!("No dead-lettered invocations");
+            } else {
+                print_table::<_, DeadLetteredInvocationTableView>(&self.0);
+            }
+        }
+    }
+
+    impl TextFormat for DeadLetterPolicyView {
+        fn print(&self) {
+            println!(
+                "Dead-lettering is {} for component {}",
+                if self.enabled {
+                    format_message_highlight("enabled")
+                } else {
+                    format_message_highlight("disabled")
+                },
+                format_main_id(&self.component_urn)
+            )
+        }
+    }
+
+    #[derive(Table)]
+    struct TopicSubscriptionTableView {
+        #[table(title = "Topic")]
+        pub topic: String,
+        #[table(title = "Function")]
+        pub function: String,
+    }
+
+    impl From<&TopicSubscriptionView> for TopicSubscriptionTableView {
+        fn from(value: &TopicSubscriptionView) -> Self {
+            Self {
+                topic: value.topic.clone(),
+                function: value.function.clone(),
+            }
+        }
+    }
+
+    impl TextFormat for TopicSubscriptionsView {
+        fn print(&self) {
+            if self.0.is_empty() {
+                println!("No topic subscriptions");
+            } else {
+                print_table::<_, TopicSubscriptionTableView>(&self.0);
             }
         }
     }
@@ -148,6 +148,35 @@ impl<C: golem_client::api::ApiDefinitionClient + Sync + Send> ApiDefinitionClien
         create_or_update_api_definition(Action::Update, &self.client, path).await
     }
 
+    async fn create_from_request(
+        &self,
+        definition: &HttpApiDefinitionRequest,
+        _project: &Self::ProjectContext,
+    ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError> {
+        info!(
+            "Creating api definition {}/{}",
+            definition.id, definition.version
+        );
+
+        Ok(self.client.create_definition(definition).await?)
+    }
+
+    async fn update_from_request(
+        &self,
+        definition: &HttpApiDefinitionRequest,
+        _project: &Self::ProjectContext,
+    ) -> Result<HttpApiDefinitionWithTypeInfo, GolemError> {
+        info!(
+            "Updating api definition {}/{}",
+            definition.id, definition.version
+        );
+
+        Ok(self
+            .client
+            .update_definition(&definition.id, &definition.version, definition)
+            .await?)
+    }
+
     async fn import(
         &self,
         path: PathBufOrStdin,
@@ -156,6 +185,20 @@ impl<C: golem_client::api::ApiDefinitionClient + Sync + Send> ApiDefinitionClien
         create_or_update_api_definition(Action::Import, &self.client, path).await
     }
 
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        _project: &Self::ProjectContext,
+    ) -> Result<serde_json::Value, GolemError> {
+        info!("Exporting api definition for {}/{}", id.0, version.0);
+
+        Ok(self
+            .client
+            .export_definition(id.0.as_str(), version.0.as_str())
+            .await?)
+    }
+
     async fn delete(
         &self,
         id: ApiDefinitionId,
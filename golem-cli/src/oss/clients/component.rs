@@ -15,7 +15,9 @@
 use std::io::Read;
 
 use async_trait::async_trait;
-use golem_common::file_system::PackagedFileSet;
+use golem_common::file_system::{PackagedFileSet, PackagedFiles};
+use golem_common::model::quotas::WorkerQuotas;
+use golem_common::model::FileSystemPermission;
 
 use crate::clients::component::ComponentClient;
 use golem_common::uri::oss::urn::ComponentUrn;
@@ -84,6 +86,7 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
         _project: &Option<Self::ProjectContext>,
         component_type: golem_client::model::ComponentType,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component, GolemError> {
         info!("Adding component {name:?} from {path:?}");
 
@@ -96,7 +99,14 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                     .map_err(|e| GolemError(format!("Can't open component file: {e}")))?;
 
                 self.client
-                    .create_component(&name.0, Some(&component_type), file, files_ro, files_rw)
+                    .create_component(
+                        &name.0,
+                        Some(&component_type),
+                        file,
+                        files_ro,
+                        files_rw,
+                        quotas.as_ref(),
+                    )
                     .await?
             }
             PathBufOrStdin::Stdin => {
@@ -107,7 +117,14 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                     .map_err(|e| GolemError(format!("Failed to read stdin: {e:?}")))?;
 
                 self.client
-                    .create_component(&name.0, Some(&component_type), bytes, files_ro, files_rw)
+                    .create_component(
+                        &name.0,
+                        Some(&component_type),
+                        bytes,
+                        files_ro,
+                        files_rw,
+                        quotas.as_ref(),
+                    )
                     .await?
             }
         };
@@ -121,6 +138,7 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
         path: PathBufOrStdin,
         component_type: Option<golem_client::model::ComponentType>,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component, GolemError> {
         info!("Updating component {urn} from {path:?}");
 
@@ -133,7 +151,14 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                     .map_err(|e| GolemError(format!("Can't open component file: {e}")))?;
 
                 self.client
-                    .update_component(&urn.id.0, component_type.as_ref(), file, files_ro, files_rw)
+                    .update_component(
+                        &urn.id.0,
+                        component_type.as_ref(),
+                        file,
+                        files_ro,
+                        files_rw,
+                        quotas.as_ref(),
+                    )
                     .await?
             }
             PathBufOrStdin::Stdin => {
@@ -144,11 +169,49 @@ impl<C: golem_client::api::ComponentClient + Sync + Send> ComponentClient
                     .map_err(|e| GolemError(format!("Failed to read stdin: {e:?}")))?;
 
                 self.client
-                    .update_component(&urn.id.0, component_type.as_ref(), bytes, files_ro, files_rw)
+                    .update_component(
+                        &urn.id.0,
+                        component_type.as_ref(),
+                        bytes,
+                        files_ro,
+                        files_rw,
+                        quotas.as_ref(),
+                    )
                     .await?
             }
         };
 
         Ok(component.into())
     }
+
+    async fn download(
+        &self,
+        urn: &ComponentUrn,
+        version: Option<u64>,
+    ) -> Result<Vec<u8>, GolemError> {
+        info!("Downloading component {urn}");
+
+        let bytes = self.client.download_component(&urn.id.0, version).await?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn download_initial_files(
+        &self,
+        urn: &ComponentUrn,
+        version: Option<u64>,
+        permission: FileSystemPermission,
+    ) -> Result<Option<PackagedFiles>, GolemError> {
+        info!("Downloading initial files of component {urn}");
+
+        let permission = match permission {
+            FileSystemPermission::ReadOnly => golem_client::model::FileSystemPermission::ReadOnly,
+            FileSystemPermission::ReadWrite => golem_client::model::FileSystemPermission::ReadWrite,
+        };
+
+        let bytes = self
+            .client
+            .download_initial_files(&urn.id.0, version, &permission)
+            .await?;
+        Ok(PackagedFiles::from_vec(bytes.to_vec()))
+    }
 }
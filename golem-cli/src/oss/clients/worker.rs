@@ -17,27 +17,37 @@ use std::time::Duration;
 
 use crate::clients::worker::{worker_name_required, GetFileResponse, WorkerClient};
 use crate::command::worker::WorkerConnectOptions;
-use crate::connect_output::ConnectOutput;
+use crate::connect_output::{print_worker_status_change, ConnectOutput};
 use crate::model::{
     Format, GolemError, IdempotencyKey, WorkerMetadata, WorkerName, WorkerUpdateMode,
     WorkersMetadataResponse,
 };
 use async_trait::async_trait;
+use futures_util::stream::SplitSink;
 use futures_util::{future, pin_mut, SinkExt, StreamExt};
 use golem_client::api::WorkerError;
 use golem_client::model::{
-    GetFilesResponse, InvokeParameters, InvokeResult, ScanCursor, UpdateWorkerRequest, WorkerCreationRequest, WorkerFilter, WorkerId, WorkersMetadataRequest
+    DeadLetterPolicy, DeadLetteredInvocation, ForkWorkerRequest, GetFilesResponse, InvokeParameters, InvokeResult, RevertWorkerTarget, ScanCursor, ScheduleInvocationRequest,
+    ScheduledInvocation, SubscribeToTopicRequest, UpdateWorkerRequest, WorkerCreationRequest, WorkerFilter, WorkerId,
+    WorkersMetadataRequest,
 };
 use golem_client::{Context, Error};
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
-use golem_common::model::WorkerEvent;
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::quotas::WorkerQuotas;
+use golem_common::model::{WorkerEvent, WorkerStatusChange};
 use golem_common::uri::oss::urn::{ComponentUrn, WorkerUrn};
 use native_tls::TlsConnector;
+use tokio::net::TcpStream;
 use tokio::{task, time};
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::protocol::Message;
-use tokio_tungstenite::{connect_async_tls_with_config, Connector};
+use tokio_tungstenite::{
+    connect_async_tls_with_config, Connector, MaybeTlsStream, WebSocketStream,
+};
 use tracing::{debug, error, info, trace};
+use url::Url;
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct WorkerClientLive<C: golem_client::api::WorkerClient + Sync + Send> {
@@ -46,6 +56,93 @@ pub struct WorkerClientLive<C: golem_client::api::WorkerClient + Sync + Send> {
     pub allow_insecure: bool,
 }
 
+impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClientLive<C> {
+    fn websocket_url(&self, segments: &[&str]) -> Result<Url, GolemError> {
+        let mut url = self.context.base_url.clone();
+
+        let ws_schema = if url.scheme() == "http" { "ws" } else { "wss" };
+
+        url.set_scheme(ws_schema)
+            .map_err(|_| GolemError("Can't set schema.".to_string()))?;
+
+        url.path_segments_mut()
+            .map_err(|_| GolemError("Can't get path.".to_string()))?
+            .extend(segments);
+
+        Ok(url)
+    }
+
+    async fn open_websocket(
+        &self,
+        url: Url,
+    ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, GolemError> {
+        let mut request = url
+            .into_client_request()
+            .map_err(|e| GolemError(format!("Can't create request: {e}")))?;
+        let headers = request.headers_mut();
+
+        if let Some(token) = self.context.bearer_token() {
+            headers.insert(
+                "Authorization",
+                format!("Bearer {}", token).parse().unwrap(),
+            );
+        }
+
+        let connector = if self.allow_insecure {
+            Some(Connector::NativeTls(
+                TlsConnector::builder()
+                    .danger_accept_invalid_certs(true)
+                    .danger_accept_invalid_hostnames(true)
+                    .build()
+                    .unwrap(),
+            ))
+        } else {
+            None
+        };
+
+        let (ws_stream, _) = connect_async_tls_with_config(request, None, false, connector)
+            .await
+            .map_err(|e| match e {
+                tungstenite::error::Error::Http(http_error_response) => {
+                    let status = http_error_response.status().as_u16();
+
+                    match http_error_response.body().clone() {
+                        Some(body) => get_worker_golem_error(status, body),
+                        None => GolemError(format!("Failed Websocket. Http error: {}", status)),
+                    }
+                }
+                _ => GolemError(format!("Failed Websocket. Error: {}", e)),
+            })?;
+
+        Ok(ws_stream)
+    }
+}
+
+fn spawn_pings(
+    mut write: SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
+) -> task::JoinHandle<GolemError> {
+    task::spawn(async move {
+        let mut interval = time::interval(Duration::from_secs(1)); // TODO configure
+        let mut cnt: i32 = 1;
+
+        loop {
+            interval.tick().await;
+
+            let ping_result = write
+                .send(Message::Ping(cnt.to_ne_bytes().to_vec()))
+                .await
+                .map_err(|err| GolemError(format!("Worker connection ping failure: {err}")));
+
+            if let Err(err) = ping_result {
+                error!("{}", err);
+                break err;
+            }
+
+            cnt += 1;
+        }
+    })
+}
+
 #[async_trait]
 impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerClientLive<C> {
     async fn new_worker(
@@ -54,6 +151,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         component_urn: ComponentUrn,
         args: Vec<String>,
         env: Vec<(String, String)>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<WorkerId, GolemError> {
         info!("Creating worker {name} of {component_urn}");
 
@@ -65,6 +163,7 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                     name: name.0,
                     args,
                     env: env.into_iter().collect(),
+                    quotas,
                 },
             )
             .await?
@@ -87,6 +186,8 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                     &worker_urn.id.component_id.0,
                     worker_name,
                     idempotency_key.as_ref().map(|k| k.0.as_str()),
+                    None,
+                    None,
                     &function,
                     &parameters,
                 )
@@ -97,6 +198,8 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                 .invoke_and_await_function_without_name(
                     &worker_urn.id.component_id.0,
                     idempotency_key.as_ref().map(|k| k.0.as_str()),
+                    None,
+                    None,
                     &function,
                     &parameters,
                 )
@@ -120,6 +223,8 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                     &worker_urn.id.component_id.0,
                     worker_name,
                     idempotency_key.as_ref().map(|k| k.0.as_str()),
+                    None,
+                    None,
                     &function,
                     &parameters,
                 )
@@ -130,6 +235,8 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
                 .invoke_function_without_name(
                     &worker_urn.id.component_id.0,
                     idempotency_key.as_ref().map(|k| k.0.as_str()),
+                    None,
+                    None,
                     &function,
                     &parameters,
                 )
@@ -272,84 +379,19 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         connect_options: WorkerConnectOptions,
         format: Format,
     ) -> Result<(), GolemError> {
-        let mut url = self.context.base_url.clone();
-
-        let ws_schema = if url.scheme() == "http" { "ws" } else { "wss" };
-
-        url.set_scheme(ws_schema)
-            .map_err(|_| GolemError("Can't set schema.".to_string()))?;
-
-        url.path_segments_mut()
-            .map_err(|_| GolemError("Can't get path.".to_string()))?
-            .push("v1")
-            .push("components")
-            .push(&worker_urn.id.component_id.0.to_string())
-            .push("workers")
-            .push(&worker_name_required(&worker_urn)?)
-            .push("connect");
-
-        let mut request = url
-            .into_client_request()
-            .map_err(|e| GolemError(format!("Can't create request: {e}")))?;
-        let headers = request.headers_mut();
-
-        if let Some(token) = self.context.bearer_token() {
-            headers.insert(
-                "Authorization",
-                format!("Bearer {}", token).parse().unwrap(),
-            );
-        }
-
-        let connector = if self.allow_insecure {
-            Some(Connector::NativeTls(
-                TlsConnector::builder()
-                    .danger_accept_invalid_certs(true)
-                    .danger_accept_invalid_hostnames(true)
-                    .build()
-                    .unwrap(),
-            ))
-        } else {
-            None
-        };
+        let url = self.websocket_url(&[
+            "v1",
+            "components",
+            &worker_urn.id.component_id.0.to_string(),
+            "workers",
+            &worker_name_required(&worker_urn)?,
+            "connect",
+        ])?;
 
         info!("Connecting to {worker_urn}");
 
-        let (ws_stream, _) = connect_async_tls_with_config(request, None, false, connector)
-            .await
-            .map_err(|e| match e {
-                tungstenite::error::Error::Http(http_error_response) => {
-                    let status = http_error_response.status().as_u16();
-
-                    match http_error_response.body().clone() {
-                        Some(body) => get_worker_golem_error(status, body),
-                        None => GolemError(format!("Failed Websocket. Http error: {}", status)),
-                    }
-                }
-                _ => GolemError(format!("Failed Websocket. Error: {}", e)),
-            })?;
-
-        let (mut write, read) = ws_stream.split();
-
-        let pings = task::spawn(async move {
-            let mut interval = time::interval(Duration::from_secs(1)); // TODO configure
-            let mut cnt: i32 = 1;
-
-            loop {
-                interval.tick().await;
-
-                let ping_result = write
-                    .send(Message::Ping(cnt.to_ne_bytes().to_vec()))
-                    .await
-                    .map_err(|err| GolemError(format!("Worker connection ping failure: {err}")));
-
-                if let Err(err) = ping_result {
-                    error!("{}", err);
-                    break err;
-                }
-
-                cnt += 1;
-            }
-        });
+        let (write, read) = self.open_websocket(url).await?.split();
+        let pings = spawn_pings(write);
 
         let output = ConnectOutput::new(connect_options, format);
 
@@ -453,6 +495,67 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
         Ok(())
     }
 
+    async fn watch(
+        &self,
+        component_urn: ComponentUrn,
+        filter: Option<Vec<String>>,
+        format: Format,
+    ) -> Result<(), GolemError> {
+        let mut url =
+            self.websocket_url(&["v1", "components", &component_urn.id.0.to_string(), "watch"])?;
+
+        for filter in filter.unwrap_or_default() {
+            url.query_pairs_mut().append_pair("filter", &filter);
+        }
+
+        info!("Watching the workers of {component_urn}");
+
+        let (write, read) = self.open_websocket(url).await?.split();
+        let pings = spawn_pings(write);
+
+        let read_res = read.for_each(move |message_or_error| async move {
+            match message_or_error {
+                Err(error) => {
+                    error!("Error reading message: {}", error);
+                }
+                Ok(message) => {
+                    let parsed: Option<serde_json::Result<WorkerStatusChange>> = match message {
+                        Message::Text(str) => Some(serde_json::from_str(&str)),
+                        Message::Binary(data) => Some(serde_json::from_slice(&data)),
+                        Message::Close(details) => {
+                            match details {
+                                Some(closed_frame) => {
+                                    info!("Connection Closed: {}", closed_frame);
+                                }
+                                None => {
+                                    info!("Connection Closed");
+                                }
+                            }
+                            None
+                        }
+                        _ => {
+                            trace!("Ignore non-data message");
+                            None
+                        }
+                    };
+
+                    match parsed {
+                        None => {}
+                        Some(Ok(change)) => print_worker_status_change(&change, format),
+                        Some(Err(err)) => {
+                            error!("Failed to parse worker status change message: {err}");
+                        }
+                    }
+                }
+            }
+        });
+
+        pin_mut!(read_res, pings);
+
+        future::select(pings, read_res).await;
+        Ok(())
+    }
+
     async fn update(
         &self,
         worker_urn: WorkerUrn,
@@ -584,6 +687,283 @@ impl<C: golem_client::api::WorkerClient + Sync + Send> WorkerClient for WorkerCl
             Ok(GetFileResponse::File(response.into()))
         }
     }
+
+    async fn put_file(
+        &self,
+        worker_urn: WorkerUrn,
+        path: &Path,
+        content: Vec<u8>,
+    ) -> Result<(), GolemError> {
+        info!("Writing {} to {worker_urn}", path.display());
+
+        let _ = self
+            .client
+            .put_file(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &path.to_string_lossy(),
+                content,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_file(&self, worker_urn: WorkerUrn, path: &Path) -> Result<(), GolemError> {
+        info!("Deleting {} from {worker_urn}", path.display());
+
+        let _ = self
+            .client
+            .delete_file(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &path.to_string_lossy(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn make_directory(&self, worker_urn: WorkerUrn, path: &Path) -> Result<(), GolemError> {
+        info!("Creating directory {} in {worker_urn}", path.display());
+
+        let _ = self
+            .client
+            .make_directory(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &path.to_string_lossy(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn schedule_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        request: ScheduleInvocationRequest,
+    ) -> Result<ScheduledInvocation, GolemError> {
+        info!(
+            "Scheduling invocation of {} in {worker_urn}",
+            request.function
+        );
+
+        Ok(self
+            .client
+            .schedule_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &request,
+            )
+            .await?)
+    }
+
+    async fn cancel_scheduled_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        schedule_id: Uuid,
+    ) -> Result<(), GolemError> {
+        info!("Cancelling scheduled invocation {schedule_id} of {worker_urn}");
+
+        let _ = self
+            .client
+            .cancel_scheduled_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &schedule_id,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<ScheduledInvocation>, GolemError> {
+        info!("Getting scheduled invocations of {worker_urn}");
+
+        let response = self
+            .client
+            .get_scheduled_invocations(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+            )
+            .await?;
+        Ok(response.invocations)
+    }
+
+    async fn get_dead_lettered_invocations(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<DeadLetteredInvocation>, GolemError> {
+        info!("Getting dead-lettered invocations of {worker_urn}");
+
+        let response = self
+            .client
+            .get_dead_lettered_invocations(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+            )
+            .await?;
+        Ok(response.invocations)
+    }
+
+    async fn retry_dead_lettered_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        dead_letter_id: Uuid,
+    ) -> Result<(), GolemError> {
+        info!("Retrying dead-lettered invocation {dead_letter_id} of {worker_urn}");
+
+        let _ = self
+            .client
+            .retry_dead_lettered_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &dead_letter_id,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn discard_dead_lettered_invocation(
+        &self,
+        worker_urn: WorkerUrn,
+        dead_letter_id: Uuid,
+    ) -> Result<(), GolemError> {
+        info!("Discarding dead-lettered invocation {dead_letter_id} of {worker_urn}");
+
+        let _ = self
+            .client
+            .discard_dead_lettered_invocation(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &dead_letter_id,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_dead_letter_policy(
+        &self,
+        component_urn: ComponentUrn,
+    ) -> Result<DeadLetterPolicy, GolemError> {
+        info!("Getting dead-letter policy of {component_urn}");
+
+        Ok(self
+            .client
+            .get_dead_letter_policy(&component_urn.id.0)
+            .await?)
+    }
+
+    async fn update_dead_letter_policy(
+        &self,
+        component_urn: ComponentUrn,
+        policy: DeadLetterPolicy,
+    ) -> Result<DeadLetterPolicy, GolemError> {
+        info!("Updating dead-letter policy of {component_urn}");
+
+        Ok(self
+            .client
+            .update_dead_letter_policy(&component_urn.id.0, &policy)
+            .await?)
+    }
+
+    async fn get_topic_subscriptions(
+        &self,
+        worker_urn: WorkerUrn,
+    ) -> Result<Vec<TopicSubscription>, GolemError> {
+        info!("Getting topic subscriptions of {worker_urn}");
+
+        let response = self
+            .client
+            .get_worker_topic_subscriptions(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+            )
+            .await?;
+        Ok(response.subscriptions)
+    }
+
+    async fn subscribe_to_topic(
+        &self,
+        worker_urn: WorkerUrn,
+        topic: String,
+        function_name: String,
+    ) -> Result<(), GolemError> {
+        info!("Subscribing {worker_urn} to topic {topic} with {function_name}");
+
+        let _ = self
+            .client
+            .subscribe_to_topic(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &SubscribeToTopicRequest {
+                    topic,
+                    function_name,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn unsubscribe_from_topic(
+        &self,
+        worker_urn: WorkerUrn,
+        topic: String,
+        function_name: String,
+    ) -> Result<(), GolemError> {
+        info!("Unsubscribing {worker_urn} from topic {topic} with {function_name}");
+
+        let _ = self
+            .client
+            .unsubscribe_from_topic(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &topic,
+                &function_name,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn fork(
+        &self,
+        worker_urn: WorkerUrn,
+        target_worker_name: String,
+        oplog_index_cutoff: u64,
+    ) -> Result<(), GolemError> {
+        info!("Forking {worker_urn} at oplog index {oplog_index_cutoff} into {target_worker_name}");
+
+        let _ = self
+            .client
+            .fork_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &ForkWorkerRequest {
+                    target_worker_name,
+                    oplog_index_cutoff,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn revert(
+        &self,
+        worker_urn: WorkerUrn,
+        target: RevertWorkerTarget,
+    ) -> Result<(), GolemError> {
+        info!("Reverting {worker_urn}");
+
+        let _ = self
+            .client
+            .revert_worker(
+                &worker_urn.id.component_id.0,
+                &worker_name_required(&worker_urn)?,
+                &target,
+            )
+            .await?;
+        Ok(())
+    }
 }
 
 fn get_worker_golem_error(status: u16, body: Vec<u8>) -> GolemError {
@@ -14,8 +14,10 @@
 
 use crate::command::api_definition::ApiDefinitionSubcommand;
 use crate::command::api_deployment::ApiDeploymentSubcommand;
+use crate::command::app::AppSubcommand;
 use crate::command::component::ComponentSubCommand;
 use crate::command::profile::ProfileSubCommand;
+use crate::command::rib::RibSubcommand;
 use crate::command::worker::{OssWorkerUriArg, WorkerSubcommand};
 use crate::completion;
 use crate::completion::PrintCompletion;
@@ -78,6 +80,20 @@ pub enum OssCommand<ProfileAdd: clap::Args> {
         subcommand: ApiDeploymentSubcommand<OssContext>,
     },
 
+    /// Deploy applications described by a golem.yaml manifest
+    #[command()]
+    App {
+        #[command(subcommand)]
+        subcommand: AppSubcommand<OssContext>,
+    },
+
+    /// Evaluate Rib expressions interactively
+    #[command()]
+    Rib {
+        #[command(subcommand)]
+        subcommand: RibSubcommand<OssContext, ComponentUriArg>,
+    },
+
     /// Manage profiles
     #[command()]
     Profile {
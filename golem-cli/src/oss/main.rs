@@ -102,6 +102,29 @@ pub async fn async_main<ProfileAdd: Into<UniversalProfileAdd> + clap::Args>(
                 )
                 .await
         }
+        OssCommand::App { subcommand } => {
+            let factory = factory().await?;
+
+            subcommand
+                .handle(
+                    format,
+                    factory.app_service().as_ref(),
+                    factory.project_resolver().as_ref(),
+                )
+                .await
+        }
+        OssCommand::Rib { subcommand } => {
+            let factory = factory().await?;
+
+            subcommand
+                .handle(
+                    factory.component_service(),
+                    factory.worker_client(),
+                    factory.project_resolver().as_ref(),
+                    &config_dir,
+                )
+                .await
+        }
         OssCommand::Profile { subcommand } => {
             subcommand.handle(cli_kind, &config_dir, profile_auth).await
         }
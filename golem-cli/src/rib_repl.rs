@@ -0,0 +1,469 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::worker::WorkerClient;
+use crate::model::component::render_type;
+use crate::model::GolemError;
+use golem_common::uri::oss::urn::WorkerUrn;
+use golem_wasm_ast::analysis::{AnalysedExport, AnalysedType};
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::type_annotated_value_to_string;
+use indoc::indoc;
+use rib::{
+    AnalysedTypeWithUnit, Expr, FunctionTypeRegistry, Interpreter, RibFunctionInvoke, RibInput,
+    RibResult,
+};
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::Editor;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const HELP: &str = indoc! {"
+    Enter a Rib expression to evaluate it, such as `let x = 1; x + 1`.
+    The variables defined in a line ending with `let` are available in the next lines.
+
+    :type <expr>  Show the type of an expression
+    :help         Show this help
+    :quit         Exit the REPL
+"};
+
+pub struct RibRepl {
+    exports: Vec<AnalysedExport>,
+    invoke: RibFunctionInvoke,
+    history_file: PathBuf,
+    state: ReplState,
+}
+
+// The let bindings of the previous lines.
+// Values are given to the next lines as global inputs, while functions,
+// which have no value, are defined again at the beginning of every line.
+#[derive(Default)]
+struct ReplState {
+    values: HashMap<String, TypeAnnotatedValue>,
+    types: HashMap<String, AnalysedType>,
+    functions: Vec<(String, Expr)>,
+}
+
+impl RibRepl {
+    pub fn new(
+        exports: Vec<AnalysedExport>,
+        worker_client: Arc<dyn WorkerClient + Send + Sync>,
+        worker_urn: WorkerUrn,
+        history_file: PathBuf,
+    ) -> Self {
+        RibRepl {
+            exports,
+            invoke: internal::worker_invoke(worker_client, worker_urn),
+            history_file,
+            state: ReplState::default(),
+        }
+    }
+
+    pub async fn run(mut self) -> Result<(), GolemError> {
+        let mut editor = Editor::<internal::RibReplHelper, DefaultHistory>::new()
+            .map_err(|err| GolemError(format!("Failed to start the REPL: {err}")))?;
+
+        editor.set_helper(Some(internal::RibReplHelper {
+            function_names: internal::exported_function_names(&self.exports),
+        }));
+
+        // There is no history before the first session
+        let _ = editor.load_history(&self.history_file);
+
+        println!("Type :help for the available commands");
+
+        loop {
+            match editor.readline(">>> ") {
+                Ok(line) => {
+                    let line = line.trim();
+
+                    if line.is_empty() {
+                        continue;
+                    }
+
+                    let _ = editor.add_history_entry(line);
+
+                    let result = match internal::ReplCommand::parse(line) {
+                        internal::ReplCommand::Quit => break,
+                        internal::ReplCommand::Help => Ok(Some(HELP.to_string())),
+                        internal::ReplCommand::Type(expr) => self.type_of(expr).map(Some),
+                        internal::ReplCommand::Eval(expr) => self.eval(expr).await,
+                    };
+
+                    match result {
+                        Ok(Some(output)) => println!("{}", output.trim_end()),
+                        Ok(None) => {}
+                        Err(err) => eprintln!("{err}"),
+                    }
+                }
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(err) => return Err(GolemError(format!("Failed to read the input: {err}"))),
+            }
+        }
+
+        editor
+            .save_history(&self.history_file)
+            .map_err(|err| GolemError(format!("Failed to save the REPL history: {err}")))
+    }
+
+    fn type_of(&self, line: &str) -> Result<String, String> {
+        let mut expr = self.program(internal::top_level_exprs(Expr::from_text(line)?))?;
+
+        expr.infer_types(&FunctionTypeRegistry::from_export_metadata(&self.exports))
+            .map_err(|errs| errs.join("\n"))?;
+
+        match AnalysedTypeWithUnit::try_from(&expr.inferred_type())? {
+            AnalysedTypeWithUnit::Unit => Ok("()".to_string()),
+            AnalysedTypeWithUnit::Type(analysed_type) => Ok(render_type(&analysed_type)),
+        }
+    }
+
+    async fn eval(&mut self, line: &str) -> Result<Option<String>, String> {
+        let mut exprs = internal::top_level_exprs(Expr::from_text(line)?);
+
+        let functions = internal::function_definitions(&exprs);
+        let bindings = internal::value_bindings(&exprs);
+        let ends_with_let = matches!(exprs.last(), Some(Expr::Let(..)));
+
+        // The values of the variables are the result of the line
+        if ends_with_let && !bindings.is_empty() {
+            exprs.push(Expr::tuple(
+                bindings.iter().map(|name| Expr::identifier(name)).collect(),
+            ));
+        }
+
+        let program = self.program(exprs)?;
+        let compiled = rib::compile(&program, &self.exports)?;
+
+        let unknown_variables = compiled
+            .global_input_type_info
+            .types
+            .keys()
+            .filter(|name| !self.state.values.contains_key(*name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !unknown_variables.is_empty() {
+            return Err(format!(
+                "Unknown variables: {}",
+                unknown_variables.join(", ")
+            ));
+        }
+
+        // A line that only defines functions has nothing to evaluate
+        let result = if ends_with_let && bindings.is_empty() {
+            RibResult::Unit
+        } else {
+            let mut interpreter = Interpreter::new(
+                &RibInput::new(self.state.values.clone()),
+                self.invoke.clone(),
+            );
+
+            interpreter.run(compiled.byte_code).await?
+        };
+
+        for (name, function) in functions {
+            self.state.values.remove(&name);
+            self.state.types.remove(&name);
+            self.state
+                .functions
+                .retain(|(existing, _)| *existing != name);
+            self.state.functions.push((name, function));
+        }
+
+        match result {
+            RibResult::Val(TypeAnnotatedValue::Tuple(tuple)) if ends_with_let => {
+                for (name, value) in bindings.into_iter().zip(tuple.value) {
+                    let value = value
+                        .type_annotated_value
+                        .ok_or(format!("Missing value of `{}`", name))?;
+
+                    self.state
+                        .functions
+                        .retain(|(existing, _)| *existing != name);
+                    self.state
+                        .types
+                        .insert(name.clone(), AnalysedType::try_from(&value)?);
+                    self.state.values.insert(name, value);
+                }
+
+                Ok(None)
+            }
+            RibResult::Val(value) => type_annotated_value_to_string(&value)
+                .map(Some)
+                .map_err(|err| format!("Failed to format the result: {err:?}")),
+            RibResult::Unit => Ok(None),
+        }
+    }
+
+    // The line, preceded by the functions defined in the previous lines,
+    // with the types of the variables defined in the previous lines
+    fn program(&self, exprs: Vec<Expr>) -> Result<Expr, String> {
+        let mut all_exprs = self
+            .state
+            .functions
+            .iter()
+            .map(|(_, function)| function.clone())
+            .collect::<Vec<_>>();
+
+        all_exprs.extend(exprs);
+
+        let mut program = Expr::expr_block(all_exprs);
+        internal::bind_global_types(&mut program, &self.state.types)?;
+
+        Ok(program)
+    }
+}
+
+mod internal {
+    use crate::clients::worker::WorkerClient;
+    use crate::model::component::format_function_name;
+    use futures_util::FutureExt;
+    use golem_client::model::InvokeParameters;
+    use golem_common::uri::oss::urn::WorkerUrn;
+    use golem_wasm_ast::analysis::{AnalysedExport, AnalysedType};
+    use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+    use rib::{Expr, RibFunctionInvoke};
+    use rustyline::completion::{Completer, Pair};
+    use rustyline::highlight::Highlighter;
+    use rustyline::hint::Hinter;
+    use rustyline::validate::Validator;
+    use rustyline::{Context, Helper};
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Arc;
+
+    pub(crate) enum ReplCommand<'a> {
+        Eval(&'a str),
+        Type(&'a str),
+        Help,
+        Quit,
+    }
+
+    impl<'a> ReplCommand<'a> {
+        pub(crate) fn parse(line: &'a str) -> ReplCommand<'a> {
+            match line.split_once(char::is_whitespace) {
+                Some((":type", expr)) => ReplCommand::Type(expr.trim()),
+                _ if line == ":help" => ReplCommand::Help,
+                _ if line == ":quit" || line == ":q" => ReplCommand::Quit,
+                _ => ReplCommand::Eval(line),
+            }
+        }
+    }
+
+    pub(crate) fn worker_invoke(
+        worker_client: Arc<dyn WorkerClient + Send + Sync>,
+        worker_urn: WorkerUrn,
+    ) -> RibFunctionInvoke {
+        Arc::new(
+            move |function_name: String, parameters: Vec<TypeAnnotatedValue>| {
+                let worker_client = worker_client.clone();
+                let worker_urn = worker_urn.clone();
+
+                async move {
+                    worker_client
+                        .invoke_and_await(
+                            worker_urn,
+                            function_name,
+                            InvokeParameters { params: parameters },
+                            None,
+                        )
+                        .await
+                        .map(|result| result.result)
+                        .map_err(|err| err.to_string())
+                }
+                .boxed()
+            },
+        )
+    }
+
+    pub(crate) fn exported_function_names(exports: &[AnalysedExport]) -> Vec<String> {
+        exports
+            .iter()
+            .flat_map(|export| match export {
+                AnalysedExport::Instance(instance) => instance
+                    .functions
+                    .iter()
+                    .map(|function| format_function_name(Some(&instance.name), &function.name))
+                    .collect::<Vec<_>>(),
+                AnalysedExport::Function(function) => vec![function.name.clone()],
+            })
+            .collect()
+    }
+
+    pub(crate) fn top_level_exprs(expr: Expr) -> Vec<Expr> {
+        match expr {
+            Expr::ExprBlock(exprs, _) => exprs,
+            expr => vec![expr],
+        }
+    }
+
+    pub(crate) fn function_definitions(exprs: &[Expr]) -> Vec<(String, Expr)> {
+        exprs
+            .iter()
+            .filter_map(|expr| match expr {
+                Expr::Let(variable_id, _, function, _)
+                    if matches!(function.as_ref(), Expr::Function { .. }) =>
+                {
+                    Some((variable_id.name(), expr.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    // The variables defined using `let`, other than functions, in the order of their last definition
+    pub(crate) fn value_bindings(exprs: &[Expr]) -> Vec<String> {
+        let mut bindings: Vec<String> = vec![];
+
+        for expr in exprs {
+            if let Expr::Let(variable_id, _, value, _) = expr {
+                let name = variable_id.name();
+                bindings.retain(|existing| *existing != name);
+
+                if !matches!(value.as_ref(), Expr::Function { .. }) {
+                    bindings.push(name);
+                }
+            }
+        }
+
+        bindings
+    }
+
+    // Variables are bound to their definitions in the same way as in the compiler,
+    // so that only the variables defined in the previous lines remain global
+    pub(crate) fn bind_global_types(
+        expr: &mut Expr,
+        types: &HashMap<String, AnalysedType>,
+    ) -> Result<(), String> {
+        expr.bind_variables_of_list_comprehension();
+        expr.bind_variables_of_list_reduce();
+        expr.bind_variables_of_pattern_match();
+        expr.bind_variables_of_let_assignment();
+        expr.bind_local_functions()?;
+
+        let mut queue = VecDeque::new();
+        queue.push_back(expr);
+
+        while let Some(expr) = queue.pop_back() {
+            match expr {
+                Expr::Identifier(variable_id, inferred_type) if variable_id.is_global() => {
+                    if let Some(analysed_type) = types.get(&variable_id.name()) {
+                        *inferred_type = inferred_type.merge(analysed_type.clone().into());
+                    }
+                }
+                _ => expr.visit_children_mut_bottom_up(&mut queue),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) struct RibReplHelper {
+        pub(crate) function_names: Vec<String>,
+    }
+
+    impl Completer for RibReplHelper {
+        type Candidate = Pair;
+
+        fn complete(
+            &self,
+            line: &str,
+            pos: usize,
+            _ctx: &Context<'_>,
+        ) -> rustyline::Result<(usize, Vec<Pair>)> {
+            let start = line[..pos]
+                .rfind(|c: char| c.is_whitespace() || "(),;=[".contains(c))
+                .map(|index| index + 1)
+                .unwrap_or(0);
+
+            let prefix = &line[start..pos];
+
+            let candidates = self
+                .function_names
+                .iter()
+                .filter(|name| name.starts_with(prefix))
+                .map(|name| Pair {
+                    display: name.clone(),
+                    replacement: name.clone(),
+                })
+                .collect();
+
+            Ok((start, candidates))
+        }
+    }
+
+    impl Hinter for RibReplHelper {
+        type Hint = String;
+    }
+
+    impl Highlighter for RibReplHelper {}
+
+    impl Validator for RibReplHelper {}
+
+    impl Helper for RibReplHelper {}
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::rib_repl::internal;
+    use rib::Expr;
+
+    #[test]
+    fn test_parse_repl_command() {
+        assert!(matches!(
+            internal::ReplCommand::parse(":type 1 + 2"),
+            internal::ReplCommand::Type("1 + 2")
+        ));
+        assert!(matches!(
+            internal::ReplCommand::parse(":quit"),
+            internal::ReplCommand::Quit
+        ));
+        assert!(matches!(
+            internal::ReplCommand::parse("let x = 1;"),
+            internal::ReplCommand::Eval("let x = 1;")
+        ));
+    }
+
+    #[test]
+    fn test_value_bindings() {
+        let expr = Expr::from_text(
+            r#"
+              let x = 1;
+              let f = fn(y: u64) -> u64 { y };
+              let z = 2;
+              let x = 3;
+            "#,
+        )
+        .unwrap();
+
+        let exprs = internal::top_level_exprs(expr);
+
+        assert_eq!(
+            internal::value_bindings(&exprs),
+            vec!["z".to_string(), "x".to_string()]
+        );
+        assert_eq!(
+            internal::function_definitions(&exprs)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            vec!["f".to_string()]
+        );
+    }
+}
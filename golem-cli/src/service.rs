@@ -14,6 +14,7 @@
 
 pub mod api_definition;
 pub mod api_deployment;
+pub mod app;
 pub mod component;
 pub mod deploy;
 pub mod project;
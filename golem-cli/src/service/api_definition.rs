@@ -50,6 +50,12 @@ pub trait ApiDefinitionService {
         id: Option<ApiDefinitionId>,
         project: &Self::ProjectContext,
     ) -> Result<GolemResult, GolemError>;
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError>;
     async fn delete(
         &self,
         id: ApiDefinitionId,
@@ -118,6 +124,16 @@ impl<ProjectContext: Send + Sync> ApiDefinitionService
         Ok(GolemResult::Ok(Box::new(definitions)))
     }
 
+    async fn export(
+        &self,
+        id: ApiDefinitionId,
+        version: ApiDefinitionVersion,
+        project: &Self::ProjectContext,
+    ) -> Result<GolemResult, GolemError> {
+        let openapi = self.client.export(id, version, project).await?;
+        Ok(GolemResult::Json(openapi))
+    }
+
     async fn delete(
         &self,
         id: ApiDefinitionId,
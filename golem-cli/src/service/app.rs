@@ -0,0 +1,722 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::clients::api_definition::ApiDefinitionClient;
+use crate::clients::api_deployment::ApiDeploymentClient;
+use crate::clients::component::ComponentClient;
+use crate::clients::worker::WorkerClient;
+use crate::model::app::{
+    comparable_api_definition, referenced_component_names, resolve_component_names,
+    AppApiDefinitionSpec, AppComponentSpec, AppDeployResult, AppDeploySpec, AppResourceAction,
+    AppResourceKind, AppResourceOutcome, AppResourceStatus, WorkerTraitProperties,
+};
+use crate::model::component::Component;
+use crate::model::oam::Application;
+use crate::model::{
+    ApiDefinitionId, ApiDefinitionIdWithVersion, ApiDefinitionVersion, ComponentName, Format,
+    GolemError, GolemResult, PathBufOrStdin, PrintRes, WorkerName,
+};
+use async_trait::async_trait;
+use golem_client::model::{ComponentType, HttpApiDefinitionRequest};
+use golem_common::file_system::{InitialFileSet, PackagedFileSet, PackagedFiles};
+use golem_common::model::{ComponentId, FileSystemPermission};
+use golem_common::uri::oss::urn::ComponentUrn;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Deploys all the resources of an application manifest
+#[async_trait]
+pub trait AppService {
+    type ProjectContext: Send + Sync;
+
+    /// Creates or updates the components, workers, API definitions and API deployments of the
+    /// manifest, leaving the resources which already match the manifest untouched.
+    ///
+    /// With `dry_run` the server is only queried, and the result contains the planned actions.
+    async fn deploy(
+        &self,
+        manifest: PathBuf,
+        project: Self::ProjectContext,
+        dry_run: bool,
+        format: Format,
+    ) -> Result<GolemResult, GolemError>;
+}
+
+pub struct AppServiceLive<ProjectContext> {
+    pub component_client: Box<dyn ComponentClient<ProjectContext = ProjectContext> + Send + Sync>,
+    pub worker_client: Arc<dyn WorkerClient + Send + Sync>,
+    pub api_definition_client:
+        Box<dyn ApiDefinitionClient<ProjectContext = ProjectContext> + Send + Sync>,
+    pub api_deployment_client:
+        Box<dyn ApiDeploymentClient<ProjectContext = ProjectContext> + Send + Sync>,
+}
+
+/// State of a single `app deploy` run, shared by the steps deploying the different kinds of resources
+struct AppDeployment<'a, ProjectContext> {
+    manifest_dir: &'a Path,
+    project: &'a ProjectContext,
+    dry_run: bool,
+    /// Component id and latest version of the components of the manifest which are (or, with
+    /// `dry_run`, would be) in sync with the manifest
+    components: HashMap<String, (Uuid, u64)>,
+    resources: Vec<AppResourceOutcome>,
+}
+
+impl<ProjectContext> AppDeployment<'_, ProjectContext> {
+    fn record(
+        &mut self,
+        kind: AppResourceKind,
+        name: &str,
+        action: AppResourceAction,
+        status: AppResourceStatus,
+        message: Option<String>,
+    ) {
+        self.resources.push(AppResourceOutcome {
+            kind,
+            name: name.to_string(),
+            action,
+            status,
+            message,
+        })
+    }
+
+    fn planned_or_succeeded(&self) -> AppResourceStatus {
+        if self.dry_run {
+            AppResourceStatus::Planned
+        } else {
+            AppResourceStatus::Succeeded
+        }
+    }
+}
+
+fn component_urn(component_id: Uuid) -> ComponentUrn {
+    ComponentUrn {
+        id: ComponentId(component_id),
+    }
+}
+
+async fn packaged_file_contents(
+    files: Option<PackagedFiles>,
+) -> Result<BTreeMap<String, Vec<u8>>, GolemError> {
+    match files {
+        Some(files) => files
+            .entries()
+            .await
+            .map_err(|err| GolemError(format!("Failed to read initial files: {err}"))),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+impl<ProjectContext: Clone + Send + Sync> AppServiceLive<ProjectContext> {
+    async fn find_latest_component(
+        &self,
+        name: &str,
+        project: &ProjectContext,
+    ) -> Result<Option<Component>, GolemError> {
+        let components = self
+            .component_client
+            .find(
+                Some(ComponentName(name.to_string())),
+                &Some(project.clone()),
+            )
+            .await?;
+
+        Ok(components
+            .into_iter()
+            .max_by_key(|component| component.versioned_component_id.version))
+    }
+
+    async fn deploy_component(
+        &self,
+        deployment: &mut AppDeployment<'_, ProjectContext>,
+        spec: &AppComponentSpec,
+    ) -> Result<(), GolemError> {
+        let wasm = deployment.manifest_dir.join(&spec.properties.input_wasm);
+        let wasm_content = tokio::fs::read(&wasm)
+            .await
+            .map_err(|err| GolemError(format!("Failed to read {}: {err}", wasm.display())))?;
+        let component_type = spec.properties.component_type.map(ComponentType::from);
+        let initial_files = InitialFileSet {
+            files: spec.properties.files.clone(),
+        }
+        .package(Some(deployment.manifest_dir))
+        .await
+        .map_err(GolemError)?;
+
+        let existing = self
+            .find_latest_component(&spec.name, deployment.project)
+            .await?;
+
+        let action = match &existing {
+            None => AppResourceAction::Create,
+            Some(component) if component_type.is_some_and(|t| t != component.component_type) => {
+                AppResourceAction::Update
+            }
+            Some(component) => {
+                if self
+                    .component_differs(component, &wasm_content, &initial_files)
+                    .await?
+                {
+                    AppResourceAction::Update
+                } else {
+                    AppResourceAction::Unchanged
+                }
+            }
+        };
+
+        let version = match (action, existing) {
+            (AppResourceAction::Unchanged, Some(component)) => (
+                component.versioned_component_id.component_id,
+                component.versioned_component_id.version,
+            ),
+            (_, existing) if deployment.dry_run => match existing {
+                Some(component) => (
+                    component.versioned_component_id.component_id,
+                    component.versioned_component_id.version + 1,
+                ),
+                None => (Uuid::nil(), 0),
+            },
+            (_, existing) => {
+                let component = match existing {
+                    Some(component) => {
+                        info!("Updating component {}", spec.name);
+                        self.component_client
+                            .update(
+                                component_urn(component.versioned_component_id.component_id),
+                                PathBufOrStdin::Path(wasm),
+                                component_type,
+                                initial_files,
+                                None,
+                            )
+                            .await?
+                    }
+                    None => {
+                        info!("Creating component {}", spec.name);
+                        self.component_client
+                            .add(
+                                ComponentName(spec.name.clone()),
+                                PathBufOrStdin::Path(wasm),
+                                &Some(deployment.project.clone()),
+                                component_type.unwrap_or(ComponentType::Durable),
+                                initial_files,
+                                None,
+                            )
+                            .await?
+                    }
+                };
+
+                (
+                    component.versioned_component_id.component_id,
+                    component.versioned_component_id.version,
+                )
+            }
+        };
+
+        deployment.components.insert(spec.name.clone(), version);
+        let status = deployment.planned_or_succeeded();
+        deployment.record(AppResourceKind::Component, &spec.name, action, status, None);
+
+        Ok(())
+    }
+
+    /// Compares the WASM and the initial files of the latest version of a component with the
+    /// ones of the manifest by their contents
+    async fn component_differs(
+        &self,
+        component: &Component,
+        wasm: &[u8],
+        initial_files: &PackagedFileSet,
+    ) -> Result<bool, GolemError> {
+        if component.component_size != wasm.len() as u64 {
+            return Ok(true);
+        }
+
+        let urn = component_urn(component.versioned_component_id.component_id);
+        let version = Some(component.versioned_component_id.version);
+
+        let deployed_wasm = self.component_client.download(&urn, version).await?;
+        if Sha256::digest(&deployed_wasm) != Sha256::digest(wasm) {
+            return Ok(true);
+        }
+
+        let (files_ro, files_rw) = initial_files.clone().split();
+        for (permission, files) in [
+            (FileSystemPermission::ReadOnly, files_ro),
+            (FileSystemPermission::ReadWrite, files_rw),
+        ] {
+            // Versions created without initial files have nothing to download
+            let deployed_files = self
+                .component_client
+                .download_initial_files(&urn, version, permission)
+                .await
+                .unwrap_or(None);
+
+            if packaged_file_contents(files).await?
+                != packaged_file_contents(deployed_files).await?
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn deploy_worker(
+        &self,
+        deployment: &mut AppDeployment<'_, ProjectContext>,
+        component_id: Uuid,
+        spec: &WorkerTraitProperties,
+    ) -> Result<(), GolemError> {
+        let existing = if component_id.is_nil() {
+            None
+        } else {
+            self.worker_client
+                .list_metadata(
+                    component_urn(component_id),
+                    Some(vec![format!("name == {}", spec.name)]),
+                    None,
+                    None,
+                    Some(true),
+                )
+                .await?
+                .workers
+                .into_iter()
+                .find(|worker| worker.worker_id.worker_name == spec.name)
+        };
+
+        match existing {
+            Some(worker) => {
+                let env: BTreeMap<String, String> = worker.env.into_iter().collect();
+
+                if env == spec.env && worker.args == spec.args {
+                    deployment.record(
+                        AppResourceKind::Worker,
+                        &spec.name,
+                        AppResourceAction::Unchanged,
+                        deployment.planned_or_succeeded(),
+                        None,
+                    );
+                } else {
+                    // The environment and arguments of a worker are fixed when it is created
+                    deployment.record(
+                        AppResourceKind::Worker,
+                        &spec.name,
+                        AppResourceAction::Conflict,
+                        AppResourceStatus::Failed,
+                        Some(
+                            "The worker exists with different env or args, delete it to recreate"
+                                .to_string(),
+                        ),
+                    );
+                }
+            }
+            None => {
+                if !deployment.dry_run {
+                    info!("Creating worker {}", spec.name);
+                    self.worker_client
+                        .new_worker(
+                            WorkerName(spec.name.clone()),
+                            component_urn(component_id),
+                            spec.args.clone(),
+                            spec.env.clone().into_iter().collect(),
+                            None,
+                        )
+                        .await?;
+                }
+
+                deployment.record(
+                    AppResourceKind::Worker,
+                    &spec.name,
+                    AppResourceAction::Create,
+                    deployment.planned_or_succeeded(),
+                    None,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn read_api_definition(
+        &self,
+        deployment: &AppDeployment<'_, ProjectContext>,
+        spec: &AppApiDefinitionSpec,
+    ) -> Result<serde_json::Value, GolemError> {
+        let path = deployment.manifest_dir.join(&spec.properties.definition);
+        let source = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|err| GolemError(format!("Failed to read {}: {err}", path.display())))?;
+
+        // YAML is a superset of JSON, so this accepts definitions in both formats
+        serde_yaml::from_str(&source)
+            .map_err(|err| GolemError(format!("Failed to parse {}: {err}", path.display())))
+    }
+
+    /// Returns the id and version of the API definition if it is (or would be) in sync with the manifest
+    async fn deploy_api_definition(
+        &self,
+        deployment: &mut AppDeployment<'_, ProjectContext>,
+        spec: &AppApiDefinitionSpec,
+    ) -> Result<Option<ApiDefinitionIdWithVersion>, GolemError> {
+        let mut definition = self.read_api_definition(deployment, spec).await?;
+
+        let mut components = HashMap::new();
+        for name in referenced_component_names(&definition) {
+            let version = match deployment.components.get(&name) {
+                Some(version) => Some(*version),
+                None => self
+                    .find_latest_component(&name, deployment.project)
+                    .await?
+                    .map(|component| {
+                        (
+                            component.versioned_component_id.component_id,
+                            component.versioned_component_id.version,
+                        )
+                    }),
+            };
+            if let Some(version) = version {
+                components.insert(name, version);
+            }
+        }
+
+        resolve_component_names(&mut definition, |name| {
+            components
+                .get(name)
+                .copied()
+                .ok_or_else(|| GolemError(format!("Component {name} not found")))
+        })?;
+
+        let request: HttpApiDefinitionRequest = serde_json::from_value(definition.clone())
+            .map_err(|err| GolemError(format!("Invalid API definition {}: {err}", spec.name)))?;
+
+        let existing = self
+            .api_definition_client
+            .list(
+                Some(&ApiDefinitionId(request.id.clone())),
+                deployment.project,
+            )
+            .await?
+            .into_iter()
+            .find(|existing| existing.version == request.version);
+
+        let action = match &existing {
+            None => AppResourceAction::Create,
+            Some(existing) => {
+                let existing = serde_json::to_value(existing)
+                    .map_err(|err| GolemError(format!("Unexpected API definition: {err}")))?;
+
+                if comparable_api_definition(&existing) == comparable_api_definition(&definition) {
+                    AppResourceAction::Unchanged
+                } else if existing.get("draft") == Some(&serde_json::Value::Bool(true)) {
+                    AppResourceAction::Update
+                } else {
+                    AppResourceAction::Conflict
+                }
+            }
+        };
+
+        let name = format!("{}/{}", request.id, request.version);
+
+        if action == AppResourceAction::Conflict {
+            deployment.record(
+                AppResourceKind::ApiDefinition,
+                &name,
+                action,
+                AppResourceStatus::Failed,
+                Some(
+                    "The version is published and differs from the manifest, change the version"
+                        .to_string(),
+                ),
+            );
+            return Ok(None);
+        }
+
+        if !deployment.dry_run {
+            match action {
+                AppResourceAction::Create => {
+                    info!("Creating API definition {name}");
+                    self.api_definition_client
+                        .create_from_request(&request, deployment.project)
+                        .await?;
+                }
+                AppResourceAction::Update => {
+                    info!("Updating API definition {name}");
+                    self.api_definition_client
+                        .update_from_request(&request, deployment.project)
+                        .await?;
+                }
+                _ => {}
+            }
+        }
+
+        let status = deployment.planned_or_succeeded();
+        deployment.record(AppResourceKind::ApiDefinition, &name, action, status, None);
+
+        Ok(Some(ApiDefinitionIdWithVersion {
+            id: ApiDefinitionId(request.id),
+            version: ApiDefinitionVersion(request.version),
+        }))
+    }
+
+    async fn deploy_site(
+        &self,
+        deployment: &mut AppDeployment<'_, ProjectContext>,
+        host: &str,
+        subdomain: &Option<String>,
+        api_definitions: &[ApiDefinitionIdWithVersion],
+    ) -> Result<(), GolemError> {
+        let site = match subdomain {
+            Some(subdomain) => format!("{subdomain}.{host}"),
+            None => host.to_string(),
+        };
+
+        let mut deployed = None;
+        for api_definition in api_definitions {
+            let existing = self
+                .api_deployment_client
+                .list(&api_definition.id, deployment.project)
+                .await?
+                .into_iter()
+                .find(|existing| {
+                    existing.site.host == host && &existing.site.subdomain == subdomain
+                });
+            if existing.is_some() {
+                deployed = existing;
+                break;
+            }
+        }
+
+        let action = match &deployed {
+            None => AppResourceAction::Create,
+            Some(deployed) => {
+                let deployed: HashSet<(String, String)> = deployed
+                    .api_definitions
+                    .iter()
+                    .map(|info| (info.id.clone(), info.version.clone()))
+                    .collect();
+
+                if api_definitions
+                    .iter()
+                    .all(|def| deployed.contains(&(def.id.0.clone(), def.version.0.clone())))
+                {
+                    AppResourceAction::Unchanged
+                } else {
+                    AppResourceAction::Update
+                }
+            }
+        };
+
+        if !deployment.dry_run && action != AppResourceAction::Unchanged {
+            info!("Deploying API definitions to {site}");
+            self.api_deployment_client
+                .deploy(
+                    api_definitions.to_vec(),
+                    host,
+                    subdomain.clone(),
+                    deployment.project,
+                )
+                .await?;
+        }
+
+        let status = deployment.planned_or_succeeded();
+        deployment.record(AppResourceKind::ApiDeployment, &site, action, status, None);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<ProjectContext: Clone + Send + Sync> AppService for AppServiceLive<ProjectContext> {
+    type ProjectContext = ProjectContext;
+
+    async fn deploy(
+        &self,
+        manifest: PathBuf,
+        project: Self::ProjectContext,
+        dry_run: bool,
+        format: Format,
+    ) -> Result<GolemResult, GolemError> {
+        let application = Application::from_yaml_file(&manifest)?;
+        let spec = AppDeploySpec::from_application(&application)?;
+        let manifest_dir = manifest
+            .parent()
+            .map(|dir| dir.to_path_buf())
+            .unwrap_or_default();
+
+        let mut deployment = AppDeployment {
+            manifest_dir: &manifest_dir,
+            project: &project,
+            dry_run,
+            components: HashMap::new(),
+            resources: vec![],
+        };
+
+        for component in &spec.components {
+            if let Err(err) = self.deploy_component(&mut deployment, component).await {
+                deployment.record(
+                    AppResourceKind::Component,
+                    &component.name,
+                    AppResourceAction::Create,
+                    AppResourceStatus::Failed,
+                    Some(err.0),
+                );
+            }
+        }
+
+        for component in &spec.components {
+            let component_id = deployment
+                .components
+                .get(&component.name)
+                .map(|(component_id, _)| *component_id);
+
+            for worker in &component.workers {
+                match component_id {
+                    Some(component_id) => {
+                        if let Err(err) = self
+                            .deploy_worker(&mut deployment, component_id, worker)
+                            .await
+                        {
+                            deployment.record(
+                                AppResourceKind::Worker,
+                                &worker.name,
+                                AppResourceAction::Create,
+                                AppResourceStatus::Failed,
+                                Some(err.0),
+                            );
+                        }
+                    }
+                    None => deployment.record(
+                        AppResourceKind::Worker,
+                        &worker.name,
+                        AppResourceAction::Create,
+                        AppResourceStatus::Skipped,
+                        Some(format!("Component {} was not deployed", component.name)),
+                    ),
+                }
+            }
+        }
+
+        let failed_components: BTreeSet<&str> = spec
+            .components
+            .iter()
+            .map(|component| component.name.as_str())
+            .filter(|name| !deployment.components.contains_key(*name))
+            .collect();
+
+        // API definition ids and versions are only known after reading the definition files
+        let mut sites: BTreeMap<(String, Option<String>), Vec<ApiDefinitionIdWithVersion>> =
+            BTreeMap::new();
+
+        for api_definition in &spec.api_definitions {
+            let depends_on_failed =
+                match self.read_api_definition(&deployment, api_definition).await {
+                    Ok(definition) => referenced_component_names(&definition)
+                        .into_iter()
+                        .find(|name| failed_components.contains(name.as_str())),
+                    Err(_) => None,
+                };
+
+            let id_with_version = match depends_on_failed {
+                Some(component) => {
+                    deployment.record(
+                        AppResourceKind::ApiDefinition,
+                        &api_definition.name,
+                        AppResourceAction::Create,
+                        AppResourceStatus::Skipped,
+                        Some(format!("Component {component} was not deployed")),
+                    );
+                    None
+                }
+                None => match self
+                    .deploy_api_definition(&mut deployment, api_definition)
+                    .await
+                {
+                    Ok(id_with_version) => id_with_version,
+                    Err(err) => {
+                        deployment.record(
+                            AppResourceKind::ApiDefinition,
+                            &api_definition.name,
+                            AppResourceAction::Create,
+                            AppResourceStatus::Failed,
+                            Some(err.0),
+                        );
+                        None
+                    }
+                },
+            };
+
+            for site in &api_definition.deployments {
+                let key = (site.host.clone(), site.subdomain.clone());
+                match &id_with_version {
+                    Some(id_with_version) => {
+                        let api_definitions = sites.entry(key).or_default();
+                        if !api_definitions.contains(id_with_version) {
+                            api_definitions.push(id_with_version.clone());
+                        }
+                    }
+                    None => deployment.record(
+                        AppResourceKind::ApiDeployment,
+                        &site.site(),
+                        AppResourceAction::Create,
+                        AppResourceStatus::Skipped,
+                        Some(format!(
+                            "API definition {} was not deployed",
+                            api_definition.name
+                        )),
+                    ),
+                }
+            }
+        }
+
+        for ((host, subdomain), api_definitions) in sites {
+            if let Err(err) = self
+                .deploy_site(&mut deployment, &host, &subdomain, &api_definitions)
+                .await
+            {
+                let site = match &subdomain {
+                    Some(subdomain) => format!("{subdomain}.{host}"),
+                    None => host,
+                };
+                deployment.record(
+                    AppResourceKind::ApiDeployment,
+                    &site,
+                    AppResourceAction::Create,
+                    AppResourceStatus::Failed,
+                    Some(err.0),
+                );
+            }
+        }
+
+        let result = AppDeployResult {
+            application: spec.name,
+            dry_run,
+            resources: deployment.resources,
+        };
+
+        let failed = result.failed_count();
+        if failed == 0 {
+            Ok(GolemResult::Ok(Box::new(result)))
+        } else {
+            result.println(&format);
+            Err(GolemError(format!(
+                "Failed to deploy {failed} resource(s) of application {}",
+                result.application
+            )))
+        }
+    }
+}
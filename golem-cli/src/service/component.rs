@@ -19,6 +19,7 @@ use crate::model::{ComponentName, Format, GolemError, GolemResult, PathBufOrStdi
 use async_trait::async_trait;
 use golem_client::model::ComponentType;
 use golem_common::file_system::PackagedFileSet;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::ComponentId;
 use golem_common::uri::oss::uri::ComponentUri;
 use golem_common::uri::oss::url::ComponentUrl;
@@ -40,6 +41,7 @@ pub trait ComponentService {
         non_interactive: bool,
         format: Format,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<GolemResult, GolemError>;
     async fn update(
         &self,
@@ -50,6 +52,7 @@ pub trait ComponentService {
         non_interactive: bool,
         format: Format,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<GolemResult, GolemError>;
     async fn list(
         &self,
@@ -97,6 +100,7 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
         non_interactive: bool,
         format: Format,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<GolemResult, GolemError> {
         let result = self
             .client
@@ -106,6 +110,7 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
                 &project,
                 component_type,
                 initial_files.clone(),
+                quotas.clone(),
             )
             .await;
 
@@ -131,7 +136,7 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
                             name: component_name.0.clone(),
                         });
                         let urn = self.resolve_uri(component_uri, &project).await?;
-                        self.client.update(urn, component_file, Some(component_type), initial_files).await.map(|component| GolemResult::Ok(Box::new(ComponentUpdateView(component.into()))))
+                        self.client.update(urn, component_file, Some(component_type), initial_files, quotas).await.map(|component| GolemResult::Ok(Box::new(ComponentUpdateView(component.into()))))
 
                     }
                     Ok(false) => Err(GolemError(message)),
@@ -156,6 +161,7 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
         non_interactive: bool,
         format: Format,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<GolemResult, GolemError> {
         let result = self.resolve_uri(component_uri.clone(), &project).await;
 
@@ -182,7 +188,7 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
                                 ComponentUri::URL(ComponentUrl { name }) => ComponentName(name.clone()),
                                 _ => unreachable!(),
                             };
-                            self.client.add(component_name, component_file, &project, component_type.unwrap_or(ComponentType::Durable), initial_files).await.map(|component| {
+                            self.client.add(component_name, component_file, &project, component_type.unwrap_or(ComponentType::Durable), initial_files, quotas).await.map(|component| {
                                 GolemResult::Ok(Box::new(ComponentAddView(component.into())))
                             })
 
@@ -194,7 +200,13 @@ impl<ProjectContext: Display + Send + Sync> ComponentService
             Err(other) => Err(other),
             Ok(urn) => self
                 .client
-                .update(urn, component_file.clone(), component_type, initial_files)
+                .update(
+                    urn,
+                    component_file.clone(),
+                    component_type,
+                    initial_files,
+                    quotas,
+                )
                 .await
                 .map(|component| GolemResult::Ok(Box::new(ComponentUpdateView(component.into())))),
         }?;
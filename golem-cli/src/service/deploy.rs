@@ -149,6 +149,7 @@ impl<ProjectContext: Display + Send + Sync> DeployService for DeployServiceLive<
                         .map(|(k, v)| (k.clone(), v.clone()))
                         .collect::<Vec<_>>(),
                     worker.args.clone(),
+                    Some(worker.quotas.clone()).filter(|quotas| !quotas.is_empty()),
                 )
                 .await?;
         }
@@ -21,12 +21,18 @@ use crate::model::deploy::TryUpdateAllWorkersResult;
 use crate::model::invoke_result_view::InvokeResultView;
 use crate::model::text::worker::{WorkerAddView, WorkerGetView};
 use crate::model::{
-    Format, GolemError, GolemResult, IdempotencyKey, WorkerMetadata, WorkerName, WorkerUpdateMode,
+    DeadLetterPolicyView, DeadLetteredInvocationView, DeadLetteredInvocationsView, Format,
+    GolemError, GolemResult, IdempotencyKey, ScheduledInvocationView, ScheduledInvocationsView,
+    TopicSubscriptionsView, WorkerMetadata, WorkerName, WorkerUpdateMode,
     WorkersMetadataResponseView,
 };
 use crate::service::component::ComponentService;
 use async_trait::async_trait;
-use golem_client::model::{AnalysedType, InvokeParameters, InvokeResult, ScanCursor, WorkerFilter};
+use golem_client::model::{
+    AnalysedType, DeadLetterPolicy, InvocationSchedule, InvokeParameters, InvokeResult,
+    RevertWorkerTarget, ScanCursor, ScheduleInvocationRequest, WorkerFilter,
+};
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{StringFilterComparator, TargetWorkerId, WorkerNameFilter};
 use golem_common::uri::oss::uri::{ComponentUri, WorkerUri};
 use golem_common::uri::oss::url::{ComponentUrl, WorkerUrl};
@@ -53,6 +59,7 @@ pub trait WorkerService {
         worker_name: WorkerName,
         env: Vec<(String, String)>,
         args: Vec<String>,
+        quotas: Option<WorkerQuotas>,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError>;
 
@@ -62,6 +69,7 @@ pub trait WorkerService {
         worker_name: WorkerName,
         env: Vec<(String, String)>,
         args: Vec<String>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<GolemResult, GolemError>;
 
     async fn idempotency_key(&self) -> Result<GolemResult, GolemError> {
@@ -104,6 +112,14 @@ pub trait WorkerService {
         format: Format,
     ) -> Result<GolemResult, GolemError>;
 
+    async fn watch(
+        &self,
+        component_uri: ComponentUri,
+        filter: Option<Vec<String>>,
+        project: Option<Self::ProjectContext>,
+        format: Format,
+    ) -> Result<GolemResult, GolemError>;
+
     async fn interrupt(
         &self,
         worker_uri: WorkerUri,
@@ -218,6 +234,122 @@ pub trait WorkerService {
         path: &Path,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError>;
+
+    async fn put_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: &Path,
+        source: &Path,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn delete_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: &Path,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn make_directory(
+        &self,
+        worker_uri: WorkerUri,
+        path: &Path,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn schedule(
+        &self,
+        worker_uri: WorkerUri,
+        function: String,
+        parameters: Option<Value>,
+        wave: Vec<String>,
+        schedule: InvocationSchedule,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn unschedule(
+        &self,
+        worker_uri: WorkerUri,
+        schedule_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn list_schedules(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn list_dead_letters(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn get_dead_letter(
+        &self,
+        worker_uri: WorkerUri,
+        dead_letter_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn retry_dead_letter(
+        &self,
+        worker_uri: WorkerUri,
+        dead_letter_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn discard_dead_letter(
+        &self,
+        worker_uri: WorkerUri,
+        dead_letter_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn dead_letter_policy(
+        &self,
+        component_uri: ComponentUri,
+        enabled: Option<bool>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn list_subscriptions(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn subscribe(
+        &self,
+        worker_uri: WorkerUri,
+        topic: String,
+        function: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn unsubscribe(
+        &self,
+        worker_uri: WorkerUri,
+        topic: String,
+        function: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn fork(
+        &self,
+        worker_uri: WorkerUri,
+        target_worker_name: WorkerName,
+        oplog_index: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
+
+    async fn revert(
+        &self,
+        worker_uri: WorkerUri,
+        target: RevertWorkerTarget,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError>;
 }
 
 pub struct WorkerServiceLive<ProjectContext: Send + Sync> {
@@ -434,10 +566,12 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         worker_name: WorkerName,
         env: Vec<(String, String)>,
         args: Vec<String>,
+        quotas: Option<WorkerQuotas>,
         project: Option<Self::ProjectContext>,
     ) -> Result<GolemResult, GolemError> {
         let component_urn = self.components.resolve_uri(component_uri, &project).await?;
-        self.add_by_urn(component_urn, worker_name, env, args).await
+        self.add_by_urn(component_urn, worker_name, env, args, quotas)
+            .await
     }
 
     async fn add_by_urn(
@@ -446,10 +580,11 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         worker_name: WorkerName,
         env: Vec<(String, String)>,
         args: Vec<String>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<GolemResult, GolemError> {
         let worker_id = self
             .client
-            .new_worker(worker_name, component_urn, args, env)
+            .new_worker(worker_name, component_urn, args, env, quotas)
             .await?;
 
         Ok(GolemResult::Ok(Box::new(WorkerAddView(WorkerUrn {
@@ -602,6 +737,22 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         Err(GolemError("Unexpected connection closure".to_string()))
     }
 
+    async fn watch(
+        &self,
+        component_uri: ComponentUri,
+        filter: Option<Vec<String>>,
+        project: Option<Self::ProjectContext>,
+        format: Format,
+    ) -> Result<GolemResult, GolemError> {
+        let component_urn = self.components.resolve_uri(component_uri, &project).await?;
+
+        self.client
+            .watch_forever(component_urn, filter, format)
+            .await?;
+
+        Err(GolemError("Unexpected connection closure".to_string()))
+    }
+
     async fn interrupt(
         &self,
         worker_uri: WorkerUri,
@@ -896,4 +1047,292 @@ impl<ProjectContext: Send + Sync + 'static> WorkerService for WorkerServiceLive<
         let _response = self.client.get_file(worker_urn, path).await?;
         todo!()
     }
+
+    async fn put_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: &Path,
+        source: &Path,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let content = tokio::fs::read(source)
+            .await
+            .map_err(|e| GolemError(format!("Can't read file {}: {e}", source.display())))?;
+
+        self.client.put_file(worker_urn, path, content).await?;
+
+        Ok(GolemResult::Str("Written".to_string()))
+    }
+
+    async fn delete_file(
+        &self,
+        worker_uri: WorkerUri,
+        path: &Path,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.delete_file(worker_urn, path).await?;
+
+        Ok(GolemResult::Str("Deleted".to_string()))
+    }
+
+    async fn make_directory(
+        &self,
+        worker_uri: WorkerUri,
+        path: &Path,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.make_directory(worker_urn, path).await?;
+
+        Ok(GolemResult::Str("Created".to_string()))
+    }
+
+    async fn schedule(
+        &self,
+        worker_uri: WorkerUri,
+        function: String,
+        parameters: Option<Value>,
+        wave: Vec<String>,
+        schedule: InvocationSchedule,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let (parameters, _) = resolve_parameters(
+            self.client.as_ref(),
+            self.components.as_ref(),
+            &worker_urn,
+            parameters,
+            wave,
+            &function,
+        )
+        .await?;
+
+        let invocation = self
+            .client
+            .schedule_invocation(
+                worker_urn,
+                ScheduleInvocationRequest {
+                    function,
+                    params: parameters,
+                    schedule,
+                },
+            )
+            .await?;
+
+        Ok(GolemResult::Ok(Box::new(ScheduledInvocationView::from(
+            invocation,
+        ))))
+    }
+
+    async fn unschedule(
+        &self,
+        worker_uri: WorkerUri,
+        schedule_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .cancel_scheduled_invocation(worker_urn, schedule_id)
+            .await?;
+
+        Ok(GolemResult::Str("Unscheduled".to_string()))
+    }
+
+    async fn list_schedules(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let invocations = self.client.get_scheduled_invocations(worker_urn).await?;
+
+        Ok(GolemResult::Ok(Box::new(ScheduledInvocationsView(
+            invocations.into_iter().map(|i| i.into()).collect(),
+        ))))
+    }
+
+    async fn list_dead_letters(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let invocations = self
+            .client
+            .get_dead_lettered_invocations(worker_urn)
+            .await?;
+
+        Ok(GolemResult::Ok(Box::new(DeadLetteredInvocationsView(
+            invocations
+                .into_iter()
+                .map(|i| i.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+        ))))
+    }
+
+    async fn get_dead_letter(
+        &self,
+        worker_uri: WorkerUri,
+        dead_letter_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let invocation = self
+            .client
+            .get_dead_lettered_invocations(worker_urn)
+            .await?
+            .into_iter()
+            .find(|i| i.dead_letter_id == dead_letter_id)
+            .ok_or_else(|| {
+                GolemError(format!(
+                    "Dead-lettered invocation {dead_letter_id} not found"
+                ))
+            })?;
+
+        Ok(GolemResult::Ok(Box::new(
+            DeadLetteredInvocationView::try_from(invocation)?,
+        )))
+    }
+
+    async fn retry_dead_letter(
+        &self,
+        worker_uri: WorkerUri,
+        dead_letter_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .retry_dead_lettered_invocation(worker_urn, dead_letter_id)
+            .await?;
+
+        Ok(GolemResult::Str("Retried".to_string()))
+    }
+
+    async fn discard_dead_letter(
+        &self,
+        worker_uri: WorkerUri,
+        dead_letter_id: Uuid,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .discard_dead_lettered_invocation(worker_urn, dead_letter_id)
+            .await?;
+
+        Ok(GolemResult::Str("Discarded".to_string()))
+    }
+
+    async fn dead_letter_policy(
+        &self,
+        component_uri: ComponentUri,
+        enabled: Option<bool>,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let component_urn = self.components.resolve_uri(component_uri, &project).await?;
+
+        let policy = match enabled {
+            Some(enabled) => {
+                self.client
+                    .update_dead_letter_policy(component_urn.clone(), DeadLetterPolicy { enabled })
+                    .await?
+            }
+            None => {
+                self.client
+                    .get_dead_letter_policy(component_urn.clone())
+                    .await?
+            }
+        };
+
+        Ok(GolemResult::Ok(Box::new(DeadLetterPolicyView {
+            component_urn,
+            enabled: policy.enabled,
+        })))
+    }
+
+    async fn list_subscriptions(
+        &self,
+        worker_uri: WorkerUri,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        let subscriptions = self.client.get_topic_subscriptions(worker_urn).await?;
+
+        Ok(GolemResult::Ok(Box::new(TopicSubscriptionsView(
+            subscriptions.into_iter().map(|s| s.into()).collect(),
+        ))))
+    }
+
+    async fn subscribe(
+        &self,
+        worker_uri: WorkerUri,
+        topic: String,
+        function: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .subscribe_to_topic(worker_urn, topic, function)
+            .await?;
+
+        Ok(GolemResult::Str("Subscribed".to_string()))
+    }
+
+    async fn unsubscribe(
+        &self,
+        worker_uri: WorkerUri,
+        topic: String,
+        function: String,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .unsubscribe_from_topic(worker_urn, topic, function)
+            .await?;
+
+        Ok(GolemResult::Str("Unsubscribed".to_string()))
+    }
+
+    async fn fork(
+        &self,
+        worker_uri: WorkerUri,
+        target_worker_name: WorkerName,
+        oplog_index: u64,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client
+            .fork(worker_urn, target_worker_name.0, oplog_index)
+            .await?;
+
+        Ok(GolemResult::Str("Forked".to_string()))
+    }
+
+    async fn revert(
+        &self,
+        worker_uri: WorkerUri,
+        target: RevertWorkerTarget,
+        project: Option<Self::ProjectContext>,
+    ) -> Result<GolemResult, GolemError> {
+        let worker_urn = self.resolve_uri(worker_uri, project).await?;
+
+        self.client.revert(worker_urn, target).await?;
+
+        Ok(GolemResult::Str("Reverted".to_string()))
+    }
 }
@@ -108,6 +108,14 @@ fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static st
             api_definition_get((deps, name.to_string(), cli.with_args(short)))
         }
     );
+    add_test!(
+        r,
+        format!("api_definition_export{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            api_definition_export((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
     add_test!(
         r,
         format!("api_definition_delete{suffix}"),
@@ -166,7 +174,12 @@ fn golem_def_with_response(
                 response,
                 binding_type: WorkerBindingType::Default,
             },
+            max_body_size: None,
+            cors: None,
+            security: None,
         }],
+        cors: None,
+        security_schemes: None,
     }
 }
 
@@ -294,9 +307,13 @@ pub fn to_definition(
                         }),
                         idempotency_key_input: None,
                     },
+                    max_body_size: v.max_body_size,
+                    cors: v.cors,
+                    security: v.security,
                 }
             })
             .collect(),
+        security_schemes: request.security_schemes.unwrap_or_default(),
         created_at,
     }
 }
@@ -501,6 +518,52 @@ fn api_definition_get(
     Ok(())
 }
 
+fn api_definition_export(
+    (deps, name, cli): (
+        &(impl TestDependencies + Send + Sync + 'static),
+        String,
+        CliLive,
+    ),
+) -> anyhow::Result<()> {
+    let component_name = format!("api_definition_export{name}");
+    let component = make_shopping_cart_component(deps, &component_name, &cli)?;
+    let component_id = component.component_urn.id.0.to_string();
+    let def = golem_def(&component_name, &component_id);
+    let path = make_golem_file(&def)?;
+
+    let _: HttpApiDefinitionWithTypeInfo =
+        cli.run(&["api-definition", "add", path.to_str().unwrap()])?;
+
+    let cfg = &cli.config;
+
+    let res: serde_json::value::Value = cli.run(&[
+        "api-definition",
+        "export",
+        &cfg.arg('i', "id"),
+        &component_name,
+        &cfg.arg('V', "version"),
+        "0.1.0",
+    ])?;
+
+    assert_eq!(res["x-golem-api-definition-id"], json!(component_name));
+    assert_eq!(res["x-golem-api-definition-version"], json!("0.1.0"));
+    assert!(res["paths"]["/{user-id}/get-cart-contents"]["get"].is_object());
+
+    // Importing the exported document results in the same definition
+    let path = make_file(&format!("{component_name}-exported"), &res)?;
+
+    let res: HttpApiDefinitionWithTypeInfo =
+        cli.run(&["api-definition", "import", path.to_str().unwrap()])?;
+
+    let expected = to_definition(def, res.created_at);
+
+    assert_eq!(res.id, expected.id);
+    assert_eq!(res.version, expected.version);
+    assert_eq!(res.routes.len(), expected.routes.len());
+
+    Ok(())
+}
+
 fn api_definition_delete(
     (deps, name, cli): (
         &(impl TestDependencies + Send + Sync + 'static),
@@ -0,0 +1,245 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::cli::{Cli, CliLive};
+use crate::Tracing;
+use assert2::assert;
+use golem_cli::model::app::{
+    AppDeployResult, AppResourceAction, AppResourceKind, AppResourceStatus,
+};
+use golem_cli::model::component::ComponentView;
+use golem_test_framework::config::{EnvBasedTestDependencies, TestDependencies};
+use indoc::formatdoc;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use test_r::core::{DynamicTestRegistration, TestType};
+use test_r::{add_test, inherit_test_dep, test_dep, test_gen};
+
+inherit_test_dep!(EnvBasedTestDependencies);
+inherit_test_dep!(Tracing);
+
+#[test_dep]
+fn cli(deps: &EnvBasedTestDependencies) -> CliLive {
+    CliLive::make("app", Arc::new(deps.clone())).unwrap()
+}
+
+#[test_gen]
+fn generated(r: &mut DynamicTestRegistration) {
+    make(r, "_short", "CLI_short", true);
+    make(r, "_long", "CLI_long", false);
+}
+
+fn make(r: &mut DynamicTestRegistration, suffix: &'static str, name: &'static str, short: bool) {
+    add_test!(
+        r,
+        format!("app_deploy{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            app_deploy((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+    add_test!(
+        r,
+        format!("app_deploy_dry_run{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            app_deploy_dry_run((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+    add_test!(
+        r,
+        format!("app_deploy_changed_initial_files{suffix}"),
+        TestType::IntegrationTest,
+        move |deps: &EnvBasedTestDependencies, cli: &CliLive, _tracing: &Tracing| {
+            app_deploy_changed_initial_files((deps, name.to_string(), cli.with_args(short)))
+        }
+    );
+}
+
+fn make_app_manifest(
+    deps: &EnvBasedTestDependencies,
+    app_name: &str,
+) -> Result<PathBuf, anyhow::Error> {
+    let dir = PathBuf::from(format!("../target/app-{app_name}"));
+    fs::create_dir_all(&dir)?;
+
+    let wasm = fs::canonicalize(deps.component_directory().join("shopping-cart.wasm"))?;
+
+    fs::create_dir_all(dir.join("data"))?;
+    fs::write(dir.join("data/config.json"), r#"{"currency": "EUR"}"#)?;
+
+    let definition = formatdoc!(
+        r#"
+        id: {app_name}-api
+        version: 0.1.0
+        draft: true
+        routes:
+        - method: Get
+          path: /{{user-id}}/get-cart-contents
+          binding:
+            componentName: {app_name}
+            workerName: '"cart-1"'
+            response: '{{status: 200u64, body: "ok"}}'
+        "#
+    );
+    fs::write(dir.join("api.yaml"), definition)?;
+
+    let manifest = formatdoc!(
+        r#"
+        apiVersion: core.oam.dev/v1beta1
+        kind: Application
+        metadata:
+          name: {app_name}
+        spec:
+          components:
+          - name: {app_name}
+            type: wasm
+            properties:
+              inputWasm: {wasm}
+              files:
+              - sourcePath: ./data/config.json
+                targetPath: /config.json
+            traits:
+            - type: worker
+              properties:
+                name: cart-1
+                env:
+                  CURRENCY: EUR
+          - name: {app_name}-api
+            type: api-definition
+            properties:
+              definition: api.yaml
+            traits:
+            - type: api-deployment
+              properties:
+                host: app-host-{app_name}
+        "#,
+        wasm = wasm.display()
+    );
+    let path = dir.join("golem.yaml");
+    fs::write(&path, manifest)?;
+
+    Ok(path)
+}
+
+fn app_deploy(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let app_name = format!("app-deploy-{}", name.to_lowercase().replace('_', "-"));
+    let manifest = make_app_manifest(deps, &app_name)?;
+    let cfg = &cli.config;
+
+    let result: AppDeployResult = cli.run(&[
+        "app",
+        "deploy",
+        &cfg.arg('f', "manifest"),
+        manifest.to_str().unwrap(),
+    ])?;
+
+    assert!(result.resources.len() == 4);
+    assert!(
+        result
+            .resources
+            .iter()
+            .all(|r| r.action == AppResourceAction::Create
+                && r.status == AppResourceStatus::Succeeded)
+    );
+
+    let redeployed: AppDeployResult = cli.run(&[
+        "app",
+        "deploy",
+        &cfg.arg('f', "manifest"),
+        manifest.to_str().unwrap(),
+    ])?;
+
+    assert!(redeployed.resources.len() == 4);
+    assert!(redeployed
+        .resources
+        .iter()
+        .all(|r| r.action == AppResourceAction::Unchanged));
+
+    Ok(())
+}
+
+fn app_deploy_dry_run(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let app_name = format!("app-dry-run-{}", name.to_lowercase().replace('_', "-"));
+    let manifest = make_app_manifest(deps, &app_name)?;
+    let cfg = &cli.config;
+
+    let result: AppDeployResult = cli.run(&[
+        "app",
+        "deploy",
+        &cfg.arg('f', "manifest"),
+        manifest.to_str().unwrap(),
+        "--dry-run",
+    ])?;
+
+    assert!(result.dry_run);
+    assert!(result
+        .resources
+        .iter()
+        .all(|r| r.action == AppResourceAction::Create && r.status == AppResourceStatus::Planned));
+
+    let components: Vec<ComponentView> = cli.run(&[
+        "component",
+        "list",
+        &cfg.arg('c', "component-name"),
+        &app_name,
+    ])?;
+
+    assert!(components.is_empty());
+
+    Ok(())
+}
+
+fn app_deploy_changed_initial_files(
+    (deps, name, cli): (&EnvBasedTestDependencies, String, CliLive),
+) -> Result<(), anyhow::Error> {
+    let app_name = format!("app-files-{}", name.to_lowercase().replace('_', "-"));
+    let manifest = make_app_manifest(deps, &app_name)?;
+    let cfg = &cli.config;
+
+    let _: AppDeployResult = cli.run(&[
+        "app",
+        "deploy",
+        &cfg.arg('f', "manifest"),
+        manifest.to_str().unwrap(),
+    ])?;
+
+    // Same size, different content
+    fs::write(
+        manifest.parent().unwrap().join("data/config.json"),
+        r#"{"currency": "USD"}"#,
+    )?;
+
+    let redeployed: AppDeployResult = cli.run(&[
+        "app",
+        "deploy",
+        &cfg.arg('f', "manifest"),
+        manifest.to_str().unwrap(),
+    ])?;
+
+    let component = redeployed
+        .resources
+        .iter()
+        .find(|r| r.kind == AppResourceKind::Component)
+        .unwrap();
+    assert!(component.action == AppResourceAction::Update);
+    assert!(component.status == AppResourceStatus::Succeeded);
+
+    Ok(())
+}
@@ -57,12 +57,17 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
             ),
             ("PromiseId", "golem_common::model::PromiseId"),
             ("ShardId", "golem_common::model::ShardId"),
+            (
+                "TopicSubscription",
+                "golem_common::model::pubsub::TopicSubscription",
+            ),
             (
                 "TypeAnnotatedValue",
                 "golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue",
             ),
             ("WorkerFilter", "golem_common::model::WorkerFilter"),
             ("WorkerId", "golem_common::model::WorkerId"),
+            ("WorkerQuotas", "golem_common::model::quotas::WorkerQuotas"),
             ("WorkerStatus", "golem_common::model::WorkerStatus"),
             (
                 "PublicOplogEntry",
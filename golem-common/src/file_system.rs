@@ -1,4 +1,4 @@
-use std::{collections::HashSet, fmt::Display, path::{Path, PathBuf}};
+use std::{collections::{BTreeMap, HashSet}, fmt::Display, path::{Path, PathBuf}};
 
 use async_zip::{tokio::{read::seek::ZipFileReader, write::ZipFileWriter}, Compression, ZipEntryBuilder};
 use serde::{Deserialize, Serialize};
@@ -67,6 +67,36 @@ impl PackagedFiles {
 
         Ok(())
     }
+
+    /// The contents of the archived files by their paths, for comparing archives by their
+    /// contents instead of their encoding
+    pub async fn entries(&self) -> std::io::Result<BTreeMap<String, Vec<u8>>> {
+        let mut zip_reader =  ZipFileReader::with_tokio(std::io::Cursor::new(self.data.as_slice()))
+            .await
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        let mut entries = BTreeMap::new();
+        let entry_count = zip_reader.file().entries().len();
+        for index in 0..entry_count {
+            let mut entry_reader = zip_reader.reader_with_entry(index)
+                .await
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+            let filename = entry_reader.entry().filename()
+                .as_str()
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?
+                .to_string();
+
+            let mut buf = vec![];
+            entry_reader.read_to_end_checked(&mut buf)
+                .await
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+            entries.insert(filename, buf);
+        }
+
+        Ok(entries)
+    }
 }
 
 impl std::fmt::Debug for PackagedFiles {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use golem_api_grpc::proto::golem::apidefinition;
 use golem_api_grpc::proto::golem::common;
 use golem_api_grpc::proto::golem::component;
@@ -67,6 +69,22 @@ pub fn proto_invocation_context_parent_worker_id_string(
     proto_worker_id_string(&invocation_context.as_ref().and_then(|c| c.parent.clone()))
 }
 
+/// Invocation context of an external caller, carrying only its W3C trace context headers
+pub fn trace_invocation_context(
+    traceparent: Option<String>,
+    tracestate: Option<String>,
+) -> Option<worker::InvocationContext> {
+    traceparent.map(|traceparent| worker::InvocationContext {
+        parent: None,
+        args: Vec::new(),
+        env: HashMap::new(),
+        trace_context: Some(worker::TraceContext {
+            traceparent,
+            tracestate,
+        }),
+    })
+}
+
 pub enum ProtoApiDefinitionKind {
     Golem,
     OpenAPI,
@@ -15,6 +15,7 @@
 use bincode::{Decode, Encode};
 use std::fmt::{self, Display, Formatter};
 
+use crate::model::quotas::WorkerQuotas;
 use crate::SafeDisplay;
 use golem_wasm_ast::analysis::AnalysedFunctionParameter;
 use golem_wasm_ast::core::Mem;
@@ -32,6 +33,10 @@ pub struct ComponentMetadata {
     pub exports: Vec<AnalysedExport>,
     pub producers: Vec<Producers>,
     pub memories: Vec<LinearMemory>,
+    /// Quotas applying to all the workers of the component
+    #[serde(default)]
+    #[oai(default)]
+    pub quotas: WorkerQuotas,
 }
 
 impl ComponentMetadata {
@@ -263,6 +268,7 @@ impl From<RawComponentMetadata> for ComponentMetadata {
             exports,
             producers,
             memories,
+            quotas: WorkerQuotas::default(),
         }
     }
 }
@@ -289,6 +295,7 @@ impl TryFrom<golem_api_grpc::proto::golem::component::ComponentMetadata> for Com
                 .into_iter()
                 .map(|memory| memory.into())
                 .collect(),
+            quotas: value.quotas.map(|quotas| quotas.into()).unwrap_or_default(),
         })
     }
 }
@@ -311,6 +318,7 @@ impl From<ComponentMetadata> for golem_api_grpc::proto::golem::component::Compon
                 .into_iter()
                 .map(|memory| memory.into())
                 .collect(),
+            quotas: Some(value.quotas.into()),
         }
     }
 }
@@ -0,0 +1,268 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use golem_wasm_ast::analysis::analysed_type::{field, list, option, record, str, tuple, u16};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{IntoValue, Value};
+
+/// The exported function name which the worker executor invokes by creating the
+/// `incoming-request` and `response-outparam` resources itself, taking an
+/// [`IncomingHttpRequest`] value as the only parameter and producing an
+/// [`OutgoingHttpResponse`] value as the result.
+pub const INCOMING_HTTP_HANDLER_FUNCTION: &str = "wasi:http/incoming-handler.{handle}";
+
+/// The maximum size of the request and response bodies passed to and from an incoming-handler.
+///
+/// Bodies are not streamed through the invocation: they are read as a whole, up to this size, and
+/// stored in the worker's oplog as part of the invocation's parameters and result.
+pub const MAX_HTTP_HANDLER_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+pub type HttpFields = Vec<(String, Vec<u8>)>;
+
+/// A HTTP request forwarded as a whole to a worker's `wasi:http/incoming-handler` export.
+///
+/// All the binary content, the body and the field values, is represented as base64 encoded
+/// strings in the invocation's parameters, so it takes a reasonable amount of space in the oplog.
+/// Request trailers are not forwarded by the API gateway.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncomingHttpRequest {
+    pub method: String,
+    pub uri: String,
+    pub headers: HttpFields,
+    pub body: Vec<u8>,
+}
+
+/// The response a worker's `wasi:http/incoming-handler` export set on its `response-outparam`,
+/// represented the same way as [`IncomingHttpRequest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutgoingHttpResponse {
+    pub status: u16,
+    pub headers: HttpFields,
+    pub body: Vec<u8>,
+    /// The trailers sent by the worker after the body, if any
+    pub trailers: Option<HttpFields>,
+}
+
+impl IntoValue for IncomingHttpRequest {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            Value::String(self.method),
+            Value::String(self.uri),
+            fields_into_value(self.headers),
+            bytes_into_value(&self.body),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("method", str()),
+            field("uri", str()),
+            field("headers", fields_type()),
+            field("body", str()),
+        ])
+    }
+}
+
+impl TryFrom<Value> for IncomingHttpRequest {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Record(fields) if fields.len() == 4 => {
+                let mut fields = fields.into_iter();
+                let method = string_from_value(fields.next().unwrap(), "method")?;
+                let uri = string_from_value(fields.next().unwrap(), "uri")?;
+                let headers = fields_from_value(fields.next().unwrap())?;
+                let body = bytes_from_value(fields.next().unwrap(), "body")?;
+                Ok(Self {
+                    method,
+                    uri,
+                    headers,
+                    body,
+                })
+            }
+            _ => Err("Expected an incoming HTTP request record".to_string()),
+        }
+    }
+}
+
+impl IntoValue for OutgoingHttpResponse {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            Value::U16(self.status),
+            fields_into_value(self.headers),
+            bytes_into_value(&self.body),
+            Value::Option(
+                self.trailers
+                    .map(|trailers| Box::new(fields_into_value(trailers))),
+            ),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("status", u16()),
+            field("headers", fields_type()),
+            field("body", str()),
+            field("trailers", option(fields_type())),
+        ])
+    }
+}
+
+impl TryFrom<Value> for OutgoingHttpResponse {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Record(fields) if fields.len() == 4 => {
+                let mut fields = fields.into_iter();
+                let status = match fields.next().unwrap() {
+                    Value::U16(status) => status,
+                    _ => Err("Expected a u16 status code".to_string())?,
+                };
+                let headers = fields_from_value(fields.next().unwrap())?;
+                let body = bytes_from_value(fields.next().unwrap(), "body")?;
+                let trailers = match fields.next().unwrap() {
+                    Value::Option(None) => None,
+                    Value::Option(Some(trailers)) => Some(fields_from_value(*trailers)?),
+                    _ => Err("Expected optional trailers".to_string())?,
+                };
+                Ok(Self {
+                    status,
+                    headers,
+                    body,
+                    trailers,
+                })
+            }
+            _ => Err("Expected an outgoing HTTP response record".to_string()),
+        }
+    }
+}
+
+fn fields_type() -> AnalysedType {
+    list(tuple(vec![str(), str()]))
+}
+
+fn fields_into_value(fields: HttpFields) -> Value {
+    Value::List(
+        fields
+            .into_iter()
+            .map(|(name, value)| Value::Tuple(vec![Value::String(name), bytes_into_value(&value)]))
+            .collect(),
+    )
+}
+
+fn bytes_into_value(bytes: &[u8]) -> Value {
+    Value::String(BASE64_STANDARD.encode(bytes))
+}
+
+fn bytes_from_value(value: Value, name: &str) -> Result<Vec<u8>, String> {
+    let encoded = string_from_value(value, name)?;
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|err| format!("Expected a base64 encoded {name}: {err}"))
+}
+
+fn string_from_value(value: Value, name: &str) -> Result<String, String> {
+    match value {
+        Value::String(s) => Ok(s),
+        _ => Err(format!("Expected a string for {name}")),
+    }
+}
+
+fn fields_from_value(value: Value) -> Result<HttpFields, String> {
+    match value {
+        Value::List(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::Tuple(pair) if pair.len() == 2 => {
+                    let mut pair = pair.into_iter();
+                    let name = string_from_value(pair.next().unwrap(), "field name")?;
+                    let value = bytes_from_value(pair.next().unwrap(), "field value")?;
+                    Ok((name, value))
+                }
+                _ => Err("Expected a (name, value) field tuple".to_string()),
+            })
+            .collect(),
+        _ => Err("Expected a list of fields".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::http_handler::{IncomingHttpRequest, OutgoingHttpResponse};
+    use golem_wasm_rpc::{IntoValue, Value};
+
+    #[test]
+    fn incoming_request_value_roundtrip() {
+        let request = IncomingHttpRequest {
+            method: "POST".to_string(),
+            uri: "/items?id=1".to_string(),
+            headers: vec![("content-type".to_string(), b"text/plain".to_vec())],
+            body: b"hello".to_vec(),
+        };
+        let result = IncomingHttpRequest::try_from(request.clone().into_value());
+        assert_eq!(result, Ok(request));
+    }
+
+    #[test]
+    fn outgoing_response_value_roundtrip() {
+        let response = OutgoingHttpResponse {
+            status: 201,
+            headers: vec![("x-checksum".to_string(), vec![0, 255])],
+            body: vec![0, 1, 2, 255],
+            trailers: Some(vec![("x-status".to_string(), b"done".to_vec())]),
+        };
+        let result = OutgoingHttpResponse::try_from(response.clone().into_value());
+        assert_eq!(result, Ok(response));
+    }
+
+    #[test]
+    fn body_and_field_values_are_base64_encoded() {
+        let response = OutgoingHttpResponse {
+            status: 200,
+            headers: vec![("etag".to_string(), b"hi".to_vec())],
+            body: b"hello".to_vec(),
+            trailers: None,
+        };
+        let value = response.into_value();
+        assert_eq!(
+            value,
+            Value::Record(vec![
+                Value::U16(200),
+                Value::List(vec![Value::Tuple(vec![
+                    Value::String("etag".to_string()),
+                    Value::String("aGk=".to_string()),
+                ])]),
+                Value::String("aGVsbG8=".to_string()),
+                Value::Option(None),
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_body_is_rejected() {
+        let value = Value::Record(vec![
+            Value::U16(200),
+            Value::List(vec![]),
+            Value::String("not base64!".to_string()),
+            Value::Option(None),
+        ]);
+        assert!(OutgoingHttpResponse::try_from(value).is_err());
+    }
+}
@@ -24,7 +24,9 @@ use crate::config::RetryConfig;
 use crate::model::oplog::{
     IndexedResourceKey, OplogEntry, OplogIndex, TimestampedUpdateDescription, WorkerResourceId,
 };
+use crate::model::quotas::WorkerQuotas;
 use crate::model::regions::DeletedRegions;
+use crate::model::trace_context::TraceContext;
 use crate::newtype_uuid;
 use bincode::de::read::Reader;
 use bincode::de::{BorrowDecoder, Decoder};
@@ -50,10 +52,14 @@ use uuid::{uuid, Uuid};
 pub mod component_constraint;
 pub mod component_metadata;
 pub mod exports;
+pub mod http_handler;
 pub mod lucene;
 pub mod oplog;
 pub mod public_oplog;
+pub mod pubsub;
+pub mod quotas;
 pub mod regions;
+pub mod trace_context;
 pub mod trim_date;
 
 use crate::uri::oss::urn::WorkerUrn;
@@ -553,6 +559,23 @@ pub enum ScheduledAction {
         last_oplog_index: OplogIndex,
         next_after: Duration,
     },
+    /// Enqueues an invocation of a scheduled invocation registered for the given worker.
+    /// The invocation's details are stored separately, so cancelling it only requires
+    /// removing the registration.
+    Invoke {
+        owned_worker_id: OwnedWorkerId,
+        id: ScheduledInvocationId,
+        scheduled_at: Timestamp,
+    },
+    /// Retries delivering the undelivered messages of a topic to one of the given worker's
+    /// subscriptions, scheduling itself again after `retry_after` while some of them still
+    /// could not be delivered
+    DeliverTopicMessages {
+        owned_worker_id: OwnedWorkerId,
+        topic: String,
+        function_name: String,
+        retry_after: Duration,
+    },
 }
 
 impl ScheduledAction {
@@ -565,6 +588,12 @@ impl ScheduledAction {
             ScheduledAction::ArchiveOplog {
                 owned_worker_id, ..
             } => owned_worker_id.clone(),
+            ScheduledAction::Invoke {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
+            ScheduledAction::DeliverTopicMessages {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
         }
     }
 }
@@ -580,11 +609,25 @@ impl Display for ScheduledAction {
             } => {
                 write!(f, "archive[{}]", owned_worker_id)
             }
+            ScheduledAction::Invoke {
+                owned_worker_id,
+                id,
+                ..
+            } => {
+                write!(f, "invoke[{}/{}]", owned_worker_id, id)
+            }
+            ScheduledAction::DeliverTopicMessages {
+                owned_worker_id,
+                topic,
+                ..
+            } => {
+                write!(f, "deliver[{}/{}]", owned_worker_id, topic)
+            }
         }
     }
 }
 
-#[derive(Debug, Encode, Decode)]
+#[derive(Clone, Debug, Encode, Decode)]
 pub struct ScheduleId {
     pub timestamp: i64,
     pub action: ScheduledAction,
@@ -596,6 +639,346 @@ impl Display for ScheduleId {
     }
 }
 
+newtype_uuid!(
+    ScheduledInvocationId,
+    golem_api_grpc::proto::golem::worker::ScheduledInvocationId
+);
+
+/// Describes when a scheduled invocation has to be performed
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum InvocationSchedule {
+    /// A single invocation at the given point in time
+    Once { at: Timestamp },
+    /// Recurring invocations with a fixed period, the first one happening one period after scheduling
+    Interval { period: Duration },
+    /// Recurring invocations at every point in time matching a cron expression (including the seconds field)
+    Cron { expression: String },
+}
+
+impl InvocationSchedule {
+    /// The latest point in time an invocation can be scheduled at (9999-12-31T23:59:59.999Z)
+    const MAX_MILLIS: u64 = 253_402_300_799_999;
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            InvocationSchedule::Once { at } => Self::check_range(*at).map(|_| ()),
+            InvocationSchedule::Interval { period } => {
+                if period.is_zero() {
+                    Err("Interval of a scheduled invocation must be positive".to_string())
+                } else if period.as_millis() > Self::MAX_MILLIS as u128 {
+                    Err("Interval of a scheduled invocation is too long".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            InvocationSchedule::Cron { expression } => Self::parse_cron(expression).map(|_| ()),
+        }
+    }
+
+    /// Creates a schedule of a single invocation at the given number of milliseconds since the
+    /// Unix epoch
+    pub fn once_at_millis(millis: u64) -> Result<Self, String> {
+        if millis <= Self::MAX_MILLIS {
+            Ok(InvocationSchedule::Once {
+                at: Timestamp::from(millis),
+            })
+        } else {
+            Err("Scheduled time is out of range".to_string())
+        }
+    }
+
+    pub fn is_recurring(&self) -> bool {
+        !matches!(self, InvocationSchedule::Once { .. })
+    }
+
+    /// Gets the point in time of the first invocation, if the schedule is registered at `now`.
+    /// A single invocation in the past happens immediately.
+    pub fn first(&self, now: Timestamp) -> Result<Option<Timestamp>, String> {
+        match self {
+            InvocationSchedule::Once { at } => Ok(Some(Self::check_range(*at)?.max(now))),
+            _ => self.next_after(now, now),
+        }
+    }
+
+    /// Gets the point in time of the next invocation following the one at `previous`.
+    /// Returns `None` if there are no more invocations.
+    ///
+    /// Invocations which are not after `now` are skipped, so a recurring invocation which was
+    /// missed several times (for example because no executor was running) is only performed
+    /// once, at `previous`, and then continues with its next occurrence in the future.
+    pub fn next_after(
+        &self,
+        previous: Timestamp,
+        now: Timestamp,
+    ) -> Result<Option<Timestamp>, String> {
+        match self {
+            InvocationSchedule::Once { .. } => Ok(None),
+            InvocationSchedule::Interval { period } => {
+                let period = period.as_millis() as u64;
+                let previous = previous.to_millis();
+                let now = now.to_millis();
+                let missed = now.saturating_sub(previous) / period;
+                let next = missed
+                    .checked_add(1)
+                    .and_then(|count| count.checked_mul(period))
+                    .and_then(|delay| delay.checked_add(previous))
+                    .filter(|next| *next <= Self::MAX_MILLIS)
+                    .ok_or("Next scheduled invocation is out of range".to_string())?;
+                Ok(Some(Timestamp::from(next)))
+            }
+            InvocationSchedule::Cron { expression } => {
+                let schedule = Self::parse_cron(expression)?;
+                let after = chrono::DateTime::<chrono::Utc>::from_timestamp_millis(
+                    previous.max(now).to_millis() as i64,
+                )
+                .ok_or("Timestamp out of range".to_string())?;
+                match schedule.after(&after).next() {
+                    Some(next) => Ok(Some(Self::check_range(Timestamp::from(
+                        next.timestamp_millis() as u64,
+                    ))?)),
+                    None => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// Converts a point in time of the schedule to a [chrono::DateTime], failing if it is before
+    /// the Unix epoch or after the year 9999
+    pub fn to_date_time(timestamp: Timestamp) -> Result<chrono::DateTime<chrono::Utc>, String> {
+        Self::check_range(timestamp).and_then(|timestamp| {
+            chrono::DateTime::<chrono::Utc>::from_timestamp_millis(timestamp.to_millis() as i64)
+                .ok_or(format!("Scheduled time {timestamp} is out of range"))
+        })
+    }
+
+    fn check_range(timestamp: Timestamp) -> Result<Timestamp, String> {
+        // Points in time before the Unix epoch wrap around to large values
+        if timestamp.to_millis() <= Self::MAX_MILLIS {
+            Ok(timestamp)
+        } else {
+            Err(format!("Scheduled time {timestamp} is out of range"))
+        }
+    }
+
+    fn parse_cron(expression: &str) -> Result<cron::Schedule, String> {
+        cron::Schedule::from_str(expression)
+            .map_err(|err| format!("Invalid cron expression {expression}: {err}"))
+    }
+}
+
+impl Display for InvocationSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvocationSchedule::Once { at } => write!(f, "once at {at}"),
+            InvocationSchedule::Interval { period } => write!(f, "every {}ms", period.as_millis()),
+            InvocationSchedule::Cron { expression } => write!(f, "cron {expression}"),
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::InvocationSchedule> for InvocationSchedule {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::InvocationSchedule,
+    ) -> Result<Self, Self::Error> {
+        use golem_api_grpc::proto::golem::worker::invocation_schedule::Schedule;
+
+        match value.schedule.ok_or("Missing schedule".to_string())? {
+            Schedule::Once(at) => {
+                let seconds = u64::try_from(at.seconds)
+                    .map_err(|_| "Scheduled time is out of range".to_string())?;
+                InvocationSchedule::once_at_millis(
+                    seconds
+                        .saturating_mul(1000)
+                        .saturating_add(at.nanos.max(0) as u64 / 1_000_000),
+                )
+            }
+            Schedule::IntervalMillis(millis) => Ok(InvocationSchedule::Interval {
+                period: Duration::from_millis(millis),
+            }),
+            Schedule::Cron(expression) => Ok(InvocationSchedule::Cron { expression }),
+        }
+    }
+}
+
+impl From<InvocationSchedule> for golem_api_grpc::proto::golem::worker::InvocationSchedule {
+    fn from(value: InvocationSchedule) -> Self {
+        use golem_api_grpc::proto::golem::worker::invocation_schedule::Schedule;
+
+        let schedule = match value {
+            InvocationSchedule::Once { at } => Schedule::Once(at.into()),
+            InvocationSchedule::Interval { period } => {
+                Schedule::IntervalMillis(period.as_millis() as u64)
+            }
+            InvocationSchedule::Cron { expression } => Schedule::Cron(expression),
+        };
+        Self {
+            schedule: Some(schedule),
+        }
+    }
+}
+
+/// An invocation of an exported function registered to be performed by the scheduler,
+/// once or repeatedly
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct ScheduledInvocation {
+    pub id: ScheduledInvocationId,
+    pub owned_worker_id: OwnedWorkerId,
+    pub full_function_name: String,
+    pub function_input: Vec<golem_wasm_rpc::Value>,
+    pub schedule: InvocationSchedule,
+    pub next_invocation_at: Timestamp,
+}
+
+impl ScheduledInvocation {
+    /// The idempotency key used for the invocation happening at `scheduled_at`. Deriving it
+    /// from the schedule makes re-firing the same occurrence (after a crash or a shard
+    /// reassignment) a no-op.
+    pub fn idempotency_key(&self, scheduled_at: &Timestamp) -> IdempotencyKey {
+        let name = format!("scheduled-at-{}", scheduled_at.to_millis());
+        IdempotencyKey::from_uuid(Uuid::new_v5(&self.id.0, name.as_bytes()))
+    }
+}
+
+impl From<ScheduledInvocation> for golem_api_grpc::proto::golem::worker::ScheduledInvocation {
+    fn from(value: ScheduledInvocation) -> Self {
+        Self {
+            id: Some(value.id.into()),
+            worker_id: Some(value.owned_worker_id.worker_id.into()),
+            function_name: value.full_function_name,
+            input: value.function_input.into_iter().map(|v| v.into()).collect(),
+            schedule: Some(value.schedule.into()),
+            next_invocation_at: Some(value.next_invocation_at.into()),
+        }
+    }
+}
+
+newtype_uuid!(
+    DeadLetteredInvocationId,
+    golem_api_grpc::proto::golem::worker::DeadLetteredInvocationId
+);
+
+/// Per-component policy controlling what happens with an invocation which failed permanently,
+/// after exhausting all its retries. By default the worker fails, blocking all its pending
+/// invocations; with dead-lettering enabled the failed invocation gets recorded and skipped instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct DeadLetterPolicy {
+    pub enabled: bool,
+}
+
+impl From<golem_api_grpc::proto::golem::worker::DeadLetterPolicy> for DeadLetterPolicy {
+    fn from(value: golem_api_grpc::proto::golem::worker::DeadLetterPolicy) -> Self {
+        Self {
+            enabled: value.enabled,
+        }
+    }
+}
+
+impl From<DeadLetterPolicy> for golem_api_grpc::proto::golem::worker::DeadLetterPolicy {
+    fn from(value: DeadLetterPolicy) -> Self {
+        Self {
+            enabled: value.enabled,
+        }
+    }
+}
+
+/// An invocation which failed permanently and was set aside by the worker's dead-letter policy,
+/// so it can be inspected and later retried or discarded
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct DeadLetteredInvocation {
+    pub id: DeadLetteredInvocationId,
+    pub owned_worker_id: OwnedWorkerId,
+    pub idempotency_key: IdempotencyKey,
+    pub component_version: ComponentVersion,
+    pub full_function_name: String,
+    pub function_input: Vec<golem_wasm_rpc::Value>,
+    pub error: String,
+    pub failed_at: Timestamp,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct RevertToOplogIndex {
+    pub last_oplog_index: OplogIndex,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct RevertLastInvocations {
+    pub number_of_invocations: u64,
+}
+
+/// Describes which part of a worker's history gets reverted
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Union)]
+#[oai(discriminator_name = "type", one_of = true)]
+#[serde(tag = "type")]
+pub enum RevertWorkerTarget {
+    /// Reverts the worker to the state right after the given oplog index
+    RevertToOplogIndex(RevertToOplogIndex),
+    /// Reverts the given number of the worker's most recent invocations
+    RevertLastInvocations(RevertLastInvocations),
+}
+
+impl Display for RevertWorkerTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevertWorkerTarget::RevertToOplogIndex(target) => {
+                write!(f, "to-oplog-index[{}]", target.last_oplog_index)
+            }
+            RevertWorkerTarget::RevertLastInvocations(target) => {
+                write!(f, "last-invocations[{}]", target.number_of_invocations)
+            }
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::RevertWorkerTarget> for RevertWorkerTarget {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::RevertWorkerTarget,
+    ) -> Result<Self, Self::Error> {
+        use golem_api_grpc::proto::golem::worker::revert_worker_target::Target;
+
+        match value.target {
+            Some(Target::RevertToOplogIndex(target)) => {
+                Ok(RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+                    last_oplog_index: OplogIndex::from_u64(target.last_oplog_index),
+                }))
+            }
+            Some(Target::RevertLastInvocations(target)) => Ok(
+                RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                    number_of_invocations: target.number_of_invocations,
+                }),
+            ),
+            None => Err("Missing field: target".to_string()),
+        }
+    }
+}
+
+impl From<RevertWorkerTarget> for golem_api_grpc::proto::golem::worker::RevertWorkerTarget {
+    fn from(value: RevertWorkerTarget) -> Self {
+        use golem_api_grpc::proto::golem::worker::revert_worker_target::Target;
+
+        let target = match value {
+            RevertWorkerTarget::RevertToOplogIndex(target) => Target::RevertToOplogIndex(
+                golem_api_grpc::proto::golem::worker::RevertToOplogIndex {
+                    last_oplog_index: target.last_oplog_index.into(),
+                },
+            ),
+            RevertWorkerTarget::RevertLastInvocations(target) => Target::RevertLastInvocations(
+                golem_api_grpc::proto::golem::worker::RevertLastInvocations {
+                    number_of_invocations: target.number_of_invocations,
+                },
+            ),
+        };
+        Self {
+            target: Some(target),
+        }
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -976,6 +1359,8 @@ pub struct WorkerMetadata {
     pub account_id: AccountId,
     pub created_at: Timestamp,
     pub parent: Option<WorkerId>,
+    /// Quotas defined for this specific worker when it was created, overriding the component's quotas
+    pub quotas: WorkerQuotas,
     pub last_known_status: WorkerStatusRecord,
 }
 
@@ -988,6 +1373,7 @@ impl WorkerMetadata {
             account_id,
             created_at: Timestamp::now_utc(),
             parent: None,
+            quotas: WorkerQuotas::default(),
             last_known_status: WorkerStatusRecord::default(),
         }
     }
@@ -1177,7 +1563,8 @@ impl From<WorkerStatus> for i32 {
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
 pub enum WorkerInvocation {
-    ExportedFunction {
+    /// Original 1.0 version, without trace context
+    ExportedFunctionV1 {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<golem_wasm_rpc::Value>,
@@ -1185,26 +1572,39 @@ pub enum WorkerInvocation {
     ManualUpdate {
         target_version: ComponentVersion,
     },
+    ExportedFunction {
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<golem_wasm_rpc::Value>,
+        trace_context: Option<TraceContext>,
+    },
 }
 
 impl WorkerInvocation {
     pub fn is_idempotency_key(&self, key: &IdempotencyKey) -> bool {
-        match self {
-            Self::ExportedFunction {
-                idempotency_key, ..
-            } => idempotency_key == key,
-            _ => false,
+        match self.idempotency_key() {
+            Some(idempotency_key) => idempotency_key == key,
+            None => false,
         }
     }
 
     pub fn idempotency_key(&self) -> Option<&IdempotencyKey> {
         match self {
-            Self::ExportedFunction {
+            Self::ExportedFunctionV1 {
+                idempotency_key, ..
+            }
+            | Self::ExportedFunction {
                 idempotency_key, ..
             } => Some(idempotency_key),
             _ => None,
         }
     }
+    pub fn trace_context(&self) -> Option<&TraceContext> {
+        match self {
+            Self::ExportedFunction { trace_context, .. } => trace_context.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
@@ -2415,6 +2815,34 @@ impl TryFrom<WorkerEvent> for golem_api_grpc::proto::golem::worker::LogEvent {
     }
 }
 
+/// A status change of a worker, as emitted when watching the workers of a component
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerStatusChange {
+    pub timestamp: Timestamp,
+    pub worker_id: WorkerId,
+    pub component_version: ComponentVersion,
+    pub status: WorkerStatus,
+    pub previous_status: WorkerStatus,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerStatusChange> for WorkerStatusChange {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::WorkerStatusChange,
+    ) -> Result<Self, Self::Error> {
+        let metadata = value.metadata.ok_or("Missing metadata")?;
+        Ok(WorkerStatusChange {
+            timestamp: value.timestamp.ok_or("Missing timestamp")?.into(),
+            worker_id: metadata.worker_id.ok_or("Missing worker id")?.try_into()?,
+            component_version: metadata.component_version,
+            status: metadata.status.try_into()?,
+            previous_status: value.previous_status.try_into()?,
+        })
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Enum)]
 #[repr(i32)]
 pub enum ComponentType {
@@ -2506,14 +2934,15 @@ mod tests {
 
     use std::collections::HashSet;
     use std::str::FromStr;
-    use std::time::SystemTime;
+    use std::time::{Duration, SystemTime};
     use std::vec;
 
     use crate::model::oplog::OplogIndex;
+    use crate::model::quotas::WorkerQuotas;
     use crate::model::{
-        AccountId, ComponentId, FilterComparator, IdempotencyKey, ShardId, StringFilterComparator,
-        TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata, WorkerStatus,
-        WorkerStatusRecord,
+        AccountId, ComponentId, FilterComparator, IdempotencyKey, InvocationSchedule, ShardId,
+        StringFilterComparator, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
+        WorkerStatus, WorkerStatusRecord,
     };
     use bincode::{Decode, Encode};
     use poem_openapi::types::ToJSON;
@@ -2696,6 +3125,7 @@ mod tests {
             },
             created_at: Timestamp::now_utc(),
             parent: None,
+            quotas: WorkerQuotas::default(),
             last_known_status: WorkerStatusRecord {
                 component_version: 1,
                 ..WorkerStatusRecord::default()
@@ -2851,4 +3281,53 @@ mod tests {
         let deserialized: IdempotencyKey = serde_json::from_str(&serialized).unwrap();
         assert_eq!(key, deserialized);
     }
+
+    #[test]
+    fn invocation_schedule_out_of_range() {
+        let before_epoch = Timestamp::from_str("1960-01-01T00:00:00Z").unwrap();
+        assert!(InvocationSchedule::Once { at: before_epoch }
+            .validate()
+            .is_err());
+        assert!(InvocationSchedule::Interval {
+            period: Duration::from_secs(u64::MAX)
+        }
+        .validate()
+        .is_err());
+
+        let at = Timestamp::from_str("2030-01-01T00:00:00Z").unwrap();
+        assert!(InvocationSchedule::Once { at }.validate().is_ok());
+        assert!(InvocationSchedule::to_date_time(at).is_ok());
+        assert!(InvocationSchedule::to_date_time(before_epoch).is_err());
+    }
+
+    #[test]
+    fn invocation_schedule_skips_missed_intervals() {
+        let schedule = InvocationSchedule::Interval {
+            period: Duration::from_secs(60),
+        };
+        let previous = Timestamp::from(1_000_000);
+
+        assert_eq!(
+            schedule.next_after(previous, previous).unwrap(),
+            Some(Timestamp::from(1_060_000))
+        );
+        // Five periods got missed, the next invocation keeps the original phase
+        assert_eq!(
+            schedule
+                .next_after(previous, Timestamp::from(1_000_000 + 5 * 60_000 + 1))
+                .unwrap(),
+            Some(Timestamp::from(1_000_000 + 6 * 60_000))
+        );
+    }
+
+    #[test]
+    fn invocation_schedule_once_in_the_past_happens_immediately() {
+        let now = Timestamp::from(2_000_000);
+        let schedule = InvocationSchedule::Once {
+            at: Timestamp::from(1_000_000),
+        };
+
+        assert_eq!(schedule.first(now).unwrap(), Some(now));
+        assert_eq!(schedule.next_after(now, now).unwrap(), None);
+    }
 }
@@ -29,7 +29,9 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::config::RetryConfig;
+use crate::model::quotas::{QuotaKind, WorkerQuotas};
 use crate::model::regions::OplogRegion;
+use crate::model::trace_context::TraceContext;
 use crate::model::{
     AccountId, ComponentVersion, IdempotencyKey, Timestamp, WorkerId, WorkerInvocation,
 };
@@ -269,7 +271,8 @@ pub enum LogLevel {
 
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
 pub enum OplogEntry {
-    Create {
+    /// The worker was created (original 1.0 version)
+    CreateV1 {
         timestamp: Timestamp,
         worker_id: WorkerId,
         component_version: ComponentVersion,
@@ -287,8 +290,8 @@ pub enum OplogEntry {
         response: OplogPayload,
         wrapped_function_type: WrappedFunctionType,
     },
-    /// The worker has been invoked
-    ExportedFunctionInvoked {
+    /// The worker has been invoked (original 1.0 version)
+    ExportedFunctionInvokedV1 {
         timestamp: Timestamp,
         function_name: String,
         request: OplogPayload,
@@ -404,6 +407,38 @@ pub enum OplogEntry {
         response: OplogPayload,
         wrapped_function_type: WrappedFunctionType,
     },
+    /// The worker's file system was changed through the API
+    ChangeFileSystem {
+        timestamp: Timestamp,
+        change: FileSystemChange,
+    },
+    /// The worker has been invoked
+    ExportedFunctionInvoked {
+        timestamp: Timestamp,
+        function_name: String,
+        request: OplogPayload,
+        idempotency_key: IdempotencyKey,
+        trace_context: Option<TraceContext>,
+    },
+    /// The worker was created
+    Create {
+        timestamp: Timestamp,
+        worker_id: WorkerId,
+        component_version: ComponentVersion,
+        args: Vec<String>,
+        env: Vec<(String, String)>,
+        account_id: AccountId,
+        parent: Option<WorkerId>,
+        component_size: u64,
+        initial_total_linear_memory_size: u64,
+        quotas: WorkerQuotas,
+    },
+    /// Snapshot of the worker's state taken with its `save-snapshot` export. Recovery starts by
+    /// loading the latest snapshot instead of replaying the entries before it
+    Snapshot {
+        timestamp: Timestamp,
+        payload: OplogPayload,
+    },
 }
 
 impl OplogEntry {
@@ -416,6 +451,7 @@ impl OplogEntry {
         parent: Option<WorkerId>,
         component_size: u64,
         initial_total_linear_memory_size: u64,
+        quotas: WorkerQuotas,
     ) -> OplogEntry {
         OplogEntry::Create {
             timestamp: Timestamp::now_utc(),
@@ -427,6 +463,7 @@ impl OplogEntry {
             parent,
             component_size,
             initial_total_linear_memory_size,
+            quotas,
         }
     }
 
@@ -581,6 +618,20 @@ impl OplogEntry {
         }
     }
 
+    pub fn change_file_system(change: FileSystemChange) -> OplogEntry {
+        OplogEntry::ChangeFileSystem {
+            timestamp: Timestamp::now_utc(),
+            change,
+        }
+    }
+
+    pub fn snapshot(payload: OplogPayload) -> OplogEntry {
+        OplogEntry::Snapshot {
+            timestamp: Timestamp::now_utc(),
+            payload,
+        }
+    }
+
     pub fn is_end_atomic_region(&self, idx: OplogIndex) -> bool {
         matches!(self, OplogEntry::EndAtomicRegion { begin_index, .. } if *begin_index == idx)
     }
@@ -613,6 +664,9 @@ impl OplogEntry {
     }
 
     /// True if the oplog entry is a "hint" that should be skipped during replay
+    ///
+    /// File system changes are hints too, they are applied by the replay state while skipping them.
+    /// Snapshots are only loaded when the recovery starts from them.
     pub fn is_hint(&self) -> bool {
         matches!(
             self,
@@ -630,14 +684,16 @@ impl OplogEntry {
                 | OplogEntry::DescribeResource { .. }
                 | OplogEntry::Log { .. }
                 | OplogEntry::Restart { .. }
+                | OplogEntry::ChangeFileSystem { .. }
+                | OplogEntry::Snapshot { .. }
         )
     }
 
     pub fn timestamp(&self) -> Timestamp {
         match self {
-            OplogEntry::Create { timestamp, .. }
+            OplogEntry::CreateV1 { timestamp, .. }
             | OplogEntry::ImportedFunctionInvokedV1 { timestamp, .. }
-            | OplogEntry::ExportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::ExportedFunctionInvokedV1 { timestamp, .. }
             | OplogEntry::ExportedFunctionCompleted { timestamp, .. }
             | OplogEntry::Suspend { timestamp }
             | OplogEntry::Error { timestamp, .. }
@@ -660,7 +716,55 @@ impl OplogEntry {
             | OplogEntry::DescribeResource { timestamp, .. }
             | OplogEntry::Log { timestamp, .. }
             | OplogEntry::Restart { timestamp }
-            | OplogEntry::ImportedFunctionInvoked { timestamp, .. } => *timestamp,
+            | OplogEntry::ImportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::ChangeFileSystem { timestamp, .. }
+            | OplogEntry::ExportedFunctionInvoked { timestamp, .. }
+            | OplogEntry::Create { timestamp, .. }
+            | OplogEntry::Snapshot { timestamp, .. } => *timestamp,
+        }
+    }
+
+    /// Returns mutable references to all the payloads stored in this entry
+    pub fn payloads_mut(&mut self) -> Vec<&mut OplogPayload> {
+        match self {
+            OplogEntry::ImportedFunctionInvokedV1 { response, .. } => vec![response],
+            OplogEntry::ExportedFunctionInvokedV1 { request, .. } => vec![request],
+            OplogEntry::ExportedFunctionInvoked { request, .. } => vec![request],
+            OplogEntry::ExportedFunctionCompleted { response, .. } => vec![response],
+            OplogEntry::PendingUpdate {
+                description: UpdateDescription::SnapshotBased { payload, .. },
+                ..
+            } => vec![payload],
+            OplogEntry::ImportedFunctionInvoked {
+                request, response, ..
+            } => vec![request, response],
+            OplogEntry::ChangeFileSystem {
+                change: FileSystemChange::WriteFile { content, .. },
+                ..
+            } => vec![content],
+            OplogEntry::Snapshot { payload, .. } => vec![payload],
+            _ => vec![],
+        }
+    }
+}
+
+/// A change of the writable part of a worker's file system, made from outside the worker
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum FileSystemChange {
+    /// Creates or overwrites a file, creating its missing parent directories
+    WriteFile { path: String, content: OplogPayload },
+    /// Deletes a file, or a directory with all its contents
+    DeleteFile { path: String },
+    /// Creates a directory and its missing parents
+    MakeDirectory { path: String },
+}
+
+impl FileSystemChange {
+    pub fn path(&self) -> &str {
+        match self {
+            FileSystemChange::WriteFile { path, .. }
+            | FileSystemChange::DeleteFile { path }
+            | FileSystemChange::MakeDirectory { path } => path,
         }
     }
 }
@@ -734,6 +838,11 @@ pub enum WorkerError {
     InvalidRequest(String),
     StackOverflow,
     OutOfMemory,
+    /// One of the worker's quotas was exceeded
+    QuotaExceeded {
+        quota: QuotaKind,
+        limit: u64,
+    },
 }
 
 impl WorkerError {
@@ -748,6 +857,9 @@ impl WorkerError {
             WorkerError::InvalidRequest(message) => format!("{message}{error_logs}"),
             WorkerError::StackOverflow => format!("Stack overflow{error_logs}"),
             WorkerError::OutOfMemory => format!("Out of memory{error_logs}"),
+            WorkerError::QuotaExceeded { quota, limit } => {
+                format!("Exceeded the worker's {quota} quota of {limit}{error_logs}")
+            }
         }
     }
 }
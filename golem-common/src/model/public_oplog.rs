@@ -21,7 +21,7 @@ use golem_api_grpc::proto::golem::worker::{oplog_entry, worker_invocation, wrapp
 use golem_wasm_ast::analysis::{AnalysedType, NameOptionTypePair};
 use golem_wasm_rpc::{Value, ValueAndType};
 use poem_openapi::types::{ParseFromParameter, ParseResult};
-use poem_openapi::{Object, Union};
+use poem_openapi::{Enum, Object, Union};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
@@ -164,6 +164,8 @@ pub struct ExportedFunctionInvokedParameters {
     pub function_name: String,
     pub request: Vec<ValueAndType>,
     pub idempotency_key: IdempotencyKey,
+    pub traceparent: Option<String>,
+    pub tracestate: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
@@ -257,6 +259,26 @@ pub struct LogParameters {
     pub message: String,
 }
 
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Enum)]
+pub enum FileSystemChangeKind {
+    WriteFile,
+    DeleteFile,
+    MakeDirectory,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct ChangeFileSystemParameters {
+    pub timestamp: Timestamp,
+    pub kind: FileSystemChangeKind,
+    pub path: String,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Deserialize, Object)]
+pub struct SnapshotParameters {
+    pub timestamp: Timestamp,
+    pub payload: Vec<u8>,
+}
+
 /// A mirror of the core `OplogEntry` type, without the undefined arbitrary payloads.
 ///
 /// Instead, it encodes all payloads with wasm-rpc `Value` types. This makes this the base type
@@ -328,6 +350,10 @@ pub enum PublicOplogEntry {
     Log(LogParameters),
     /// Marks the point where the worker was restarted from clean initial state
     Restart(TimestampParameter),
+    /// The worker's file system was changed through the API
+    ChangeFileSystem(ChangeFileSystemParameters),
+    /// Snapshot of the worker's state, recovery starts from the latest one
+    Snapshot(SnapshotParameters),
 }
 
 impl PublicOplogEntry {
@@ -401,6 +427,9 @@ impl PublicOplogEntry {
                         .iter()
                         .any(|v| Self::match_value(v, &[], query_path, query))
                     || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
+                    || params.traceparent.as_ref().is_some_and(|traceparent| {
+                        Self::string_match(traceparent, &[], query_path, query)
+                    })
             }
             PublicOplogEntry::ExportedFunctionCompleted(params) => {
                 Self::string_match("exportedfunctioncompleted", &[], query_path, query)
@@ -541,6 +570,14 @@ impl PublicOplogEntry {
             PublicOplogEntry::Restart(_params) => {
                 Self::string_match("restart", &[], query_path, query)
             }
+            PublicOplogEntry::ChangeFileSystem(params) => {
+                Self::string_match("changefilesystem", &[], query_path, query)
+                    || Self::string_match("change-file-system", &[], query_path, query)
+                    || Self::string_match(&params.path, &[], query_path, query)
+            }
+            PublicOplogEntry::Snapshot(_params) => {
+                Self::string_match("snapshot", &[], query_path, query)
+            }
         }
     }
 
@@ -779,6 +816,8 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                         .idempotency_key
                         .ok_or("Missing idempotency_key field")?
                         .into(),
+                    traceparent: exported_function_invoked.traceparent,
+                    tracestate: exported_function_invoked.tracestate,
                 }),
             ),
             oplog_entry::Entry::ExportedFunctionCompleted(exported_function_completed) => Ok(
@@ -970,6 +1009,22 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                     timestamp: restart.timestamp.ok_or("Missing timestamp field")?.into(),
                 }))
             }
+            oplog_entry::Entry::ChangeFileSystem(change_file_system) => Ok(
+                PublicOplogEntry::ChangeFileSystem(ChangeFileSystemParameters {
+                    timestamp: change_file_system
+                        .timestamp
+                        .ok_or("Missing timestamp field")?
+                        .into(),
+                    kind: change_file_system.kind().into(),
+                    path: change_file_system.path,
+                }),
+            ),
+            oplog_entry::Entry::Snapshot(snapshot) => {
+                Ok(PublicOplogEntry::Snapshot(SnapshotParameters {
+                    timestamp: snapshot.timestamp.ok_or("Missing timestamp field")?.into(),
+                    payload: snapshot.payload,
+                }))
+            }
         }
     }
 }
@@ -1033,6 +1088,8 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                                 })
                                 .collect::<Result<Vec<_>, _>>()?,
                             idempotency_key: Some(exported_function_invoked.idempotency_key.into()),
+                            traceparent: exported_function_invoked.traceparent,
+                            tracestate: exported_function_invoked.tracestate,
                         },
                     )),
                 }
@@ -1265,6 +1322,29 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                     )),
                 }
             }
+            PublicOplogEntry::ChangeFileSystem(change_file_system) => {
+                let kind: golem_api_grpc::proto::golem::worker::FileSystemChangeKind =
+                    change_file_system.kind.into();
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::ChangeFileSystem(
+                        golem_api_grpc::proto::golem::worker::ChangeFileSystemParameters {
+                            timestamp: Some(change_file_system.timestamp.into()),
+                            kind: kind as i32,
+                            path: change_file_system.path,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::Snapshot(snapshot) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(oplog_entry::Entry::Snapshot(
+                        golem_api_grpc::proto::golem::worker::SnapshotParameters {
+                            timestamp: Some(snapshot.timestamp.into()),
+                            payload: snapshot.payload,
+                        },
+                    )),
+                }
+            }
         })
     }
 }
@@ -1398,6 +1478,38 @@ impl From<LogLevel> for golem_api_grpc::proto::golem::worker::OplogLogLevel {
     }
 }
 
+impl From<golem_api_grpc::proto::golem::worker::FileSystemChangeKind> for FileSystemChangeKind {
+    fn from(value: golem_api_grpc::proto::golem::worker::FileSystemChangeKind) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::worker::FileSystemChangeKind::WriteFile => {
+                FileSystemChangeKind::WriteFile
+            }
+            golem_api_grpc::proto::golem::worker::FileSystemChangeKind::DeleteFile => {
+                FileSystemChangeKind::DeleteFile
+            }
+            golem_api_grpc::proto::golem::worker::FileSystemChangeKind::MakeDirectory => {
+                FileSystemChangeKind::MakeDirectory
+            }
+        }
+    }
+}
+
+impl From<FileSystemChangeKind> for golem_api_grpc::proto::golem::worker::FileSystemChangeKind {
+    fn from(value: FileSystemChangeKind) -> Self {
+        match value {
+            FileSystemChangeKind::WriteFile => {
+                golem_api_grpc::proto::golem::worker::FileSystemChangeKind::WriteFile
+            }
+            FileSystemChangeKind::DeleteFile => {
+                golem_api_grpc::proto::golem::worker::FileSystemChangeKind::DeleteFile
+            }
+            FileSystemChangeKind::MakeDirectory => {
+                golem_api_grpc::proto::golem::worker::FileSystemChangeKind::MakeDirectory
+            }
+        }
+    }
+}
+
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerInvocation> for PublicWorkerInvocation {
     type Error = String;
 
@@ -1571,14 +1683,15 @@ impl From<OplogCursor> for golem_api_grpc::proto::golem::worker::OplogCursor {
 mod tests {
 
     use super::{
-        ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, Empty,
-        EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
-        ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
+        ChangeFileSystemParameters, ChangeRetryPolicyParameters, CreateParameters,
+        DescribeResourceParameters, Empty, EndRegionParameters, ErrorParameters,
+        ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+        ExportedFunctionParameters, FailedUpdateParameters, FileSystemChangeKind,
         GrowMemoryParameters, ImportedFunctionInvokedParameters, JumpParameters, LogParameters,
         PendingUpdateParameters, PendingWorkerInvocationParameters, PublicOplogEntry,
         PublicRetryConfig, PublicUpdateDescription, PublicWorkerInvocation,
         PublicWrappedFunctionType, ResourceParameters, SnapshotBasedUpdateParameters,
-        SuccessfulUpdateParameters, TimestampParameter,
+        SnapshotParameters, SuccessfulUpdateParameters, TimestampParameter,
     };
     use crate::model::oplog::{LogLevel, OplogIndex, WorkerResourceId};
     use crate::model::regions::OplogRegion;
@@ -1661,6 +1774,10 @@ mod tests {
                 },
             ],
             idempotency_key: IdempotencyKey::new("idempotency_key".to_string()),
+            traceparent: Some(
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+            ),
+            tracestate: None,
         });
         let serialized = entry.to_json_string();
         let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
@@ -1973,4 +2090,27 @@ mod tests {
         let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
         assert_eq!(entry, deserialized);
     }
+
+    #[test]
+    fn change_file_system_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::ChangeFileSystem(ChangeFileSystemParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            kind: FileSystemChangeKind::WriteFile,
+            path: "/config/app.toml".to_string(),
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
+
+    #[test]
+    fn snapshot_serialization_poem_serde_equivalence() {
+        let entry = PublicOplogEntry::Snapshot(SnapshotParameters {
+            timestamp: rounded_ts(Timestamp::now_utc()),
+            payload: "test".as_bytes().to_vec(),
+        });
+        let serialized = entry.to_json_string();
+        let deserialized: PublicOplogEntry = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(entry, deserialized);
+    }
 }
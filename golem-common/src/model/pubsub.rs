@@ -0,0 +1,112 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{IdempotencyKey, Timestamp, WorkerId};
+
+/// Subscription of a worker to a topic. Topics are identified by their names within an account.
+///
+/// Every message published to the topic gets delivered to the worker by enqueuing an invocation
+/// of `function_name`, with the topic's name and the message's payload as its two parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TopicSubscription {
+    pub topic: String,
+    pub worker_id: WorkerId,
+    pub function_name: String,
+}
+
+impl TopicSubscription {
+    /// Idempotency key of the invocation delivering a given message to the subscriber, so
+    /// messages delivered more than once are only processed once by the worker
+    pub fn delivery_idempotency_key(&self, message_id: u64) -> IdempotencyKey {
+        IdempotencyKey::new(format!(
+            "topic:{}:{}:{}:{}",
+            self.topic, message_id, self.worker_id, self.function_name
+        ))
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::TopicSubscription> for TopicSubscription {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::TopicSubscription,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            topic: value.topic,
+            worker_id: value.worker_id.ok_or("Missing worker_id")?.try_into()?,
+            function_name: value.function_name,
+        })
+    }
+}
+
+impl From<TopicSubscription> for golem_api_grpc::proto::golem::worker::TopicSubscription {
+    fn from(value: TopicSubscription) -> Self {
+        Self {
+            topic: value.topic,
+            worker_id: Some(value.worker_id.into()),
+            function_name: value.function_name,
+        }
+    }
+}
+
+/// A message published to a topic, identified by its increasing id within the topic
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TopicMessage {
+    pub payload: Vec<u8>,
+    pub published_at: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::pubsub::TopicSubscription;
+    use crate::model::{ComponentId, WorkerId};
+
+    #[test]
+    fn delivery_idempotency_keys_are_unique_per_message_and_subscriber() {
+        let worker_id = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "worker-1".to_string(),
+        };
+        let subscription = TopicSubscription {
+            topic: "orders".to_string(),
+            worker_id: worker_id.clone(),
+            function_name: "api/on-order".to_string(),
+        };
+        let other_subscription = TopicSubscription {
+            function_name: "api/on-any-event".to_string(),
+            ..subscription.clone()
+        };
+
+        assert_eq!(
+            subscription.delivery_idempotency_key(1),
+            subscription.delivery_idempotency_key(1)
+        );
+        assert_ne!(
+            subscription.delivery_idempotency_key(1),
+            subscription.delivery_idempotency_key(2)
+        );
+        assert_ne!(
+            subscription.delivery_idempotency_key(1),
+            other_subscription.delivery_idempotency_key(1)
+        );
+    }
+}
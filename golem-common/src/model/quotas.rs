@@ -0,0 +1,146 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+
+use bincode::{Decode, Encode};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// Resource quotas of a worker. Every quota is optional, a missing value means the worker is only
+/// limited by the executor-wide limits.
+///
+/// Quotas can be defined for all the workers of a component in its metadata, and individual
+/// workers can override them when they get created.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize, Object,
+)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct WorkerQuotas {
+    /// Maximum amount of fuel a single invocation can consume
+    pub max_fuel_per_invocation: Option<u64>,
+    /// Maximum size of the worker's linear memories in bytes
+    pub max_memory: Option<u64>,
+    /// Maximum number of entries in the worker's oplog
+    pub max_oplog_entries: Option<u64>,
+    /// Maximum total size of the worker's oplog entries in bytes
+    pub max_oplog_bytes: Option<u64>,
+    /// Maximum number of outgoing HTTP requests the worker can initiate in any one minute window
+    pub max_outgoing_http_calls_per_minute: Option<u32>,
+}
+
+impl WorkerQuotas {
+    pub fn is_empty(&self) -> bool {
+        self == &WorkerQuotas::default()
+    }
+
+    /// Combines these quotas with the ones defined for a specific worker, where the worker's
+    /// quotas take precedence
+    pub fn with_overrides(&self, overrides: &WorkerQuotas) -> WorkerQuotas {
+        WorkerQuotas {
+            max_fuel_per_invocation: overrides
+                .max_fuel_per_invocation
+                .or(self.max_fuel_per_invocation),
+            max_memory: overrides.max_memory.or(self.max_memory),
+            max_oplog_entries: overrides.max_oplog_entries.or(self.max_oplog_entries),
+            max_oplog_bytes: overrides.max_oplog_bytes.or(self.max_oplog_bytes),
+            max_outgoing_http_calls_per_minute: overrides
+                .max_outgoing_http_calls_per_minute
+                .or(self.max_outgoing_http_calls_per_minute),
+        }
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::common::WorkerQuotas> for WorkerQuotas {
+    fn from(value: golem_api_grpc::proto::golem::common::WorkerQuotas) -> Self {
+        Self {
+            max_fuel_per_invocation: value.max_fuel_per_invocation,
+            max_memory: value.max_memory,
+            max_oplog_entries: value.max_oplog_entries,
+            max_oplog_bytes: value.max_oplog_bytes,
+            max_outgoing_http_calls_per_minute: value.max_outgoing_http_calls_per_minute,
+        }
+    }
+}
+
+impl From<WorkerQuotas> for golem_api_grpc::proto::golem::common::WorkerQuotas {
+    fn from(value: WorkerQuotas) -> Self {
+        Self {
+            max_fuel_per_invocation: value.max_fuel_per_invocation,
+            max_memory: value.max_memory,
+            max_oplog_entries: value.max_oplog_entries,
+            max_oplog_bytes: value.max_oplog_bytes,
+            max_outgoing_http_calls_per_minute: value.max_outgoing_http_calls_per_minute,
+        }
+    }
+}
+
+/// Identifies one of the quotas of `WorkerQuotas`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, Serialize, Deserialize)]
+pub enum QuotaKind {
+    FuelPerInvocation,
+    Memory,
+    OplogEntries,
+    OplogBytes,
+    OutgoingHttpCallsPerMinute,
+}
+
+impl Display for QuotaKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuotaKind::FuelPerInvocation => write!(f, "fuel per invocation"),
+            QuotaKind::Memory => write!(f, "memory"),
+            QuotaKind::OplogEntries => write!(f, "oplog entries"),
+            QuotaKind::OplogBytes => write!(f, "oplog bytes"),
+            QuotaKind::OutgoingHttpCallsPerMinute => write!(f, "outgoing HTTP calls per minute"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::quotas::WorkerQuotas;
+
+    #[test]
+    fn worker_quotas_override_component_quotas() {
+        let component_quotas = WorkerQuotas {
+            max_fuel_per_invocation: Some(1000),
+            max_memory: Some(1024 * 1024),
+            max_oplog_entries: None,
+            max_oplog_bytes: Some(4096),
+            max_outgoing_http_calls_per_minute: Some(10),
+        };
+        let worker_quotas = WorkerQuotas {
+            max_memory: Some(2 * 1024 * 1024),
+            max_oplog_entries: Some(100),
+            ..WorkerQuotas::default()
+        };
+
+        assert_eq!(
+            component_quotas.with_overrides(&worker_quotas),
+            WorkerQuotas {
+                max_fuel_per_invocation: Some(1000),
+                max_memory: Some(2 * 1024 * 1024),
+                max_oplog_entries: Some(100),
+                max_oplog_bytes: Some(4096),
+                max_outgoing_http_calls_per_minute: Some(10),
+            }
+        );
+        assert!(WorkerQuotas::default().is_empty());
+        assert!(!worker_quotas.is_empty());
+    }
+}
@@ -32,7 +32,7 @@ pub struct OplogRegion {
 
 impl OplogRegion {
     pub fn contains(&self, target: OplogIndex) -> bool {
-        target > self.end && target <= self.start
+        target >= self.start && target <= self.end
     }
 
     pub fn union(&self, other: &OplogRegion) -> Option<OplogRegion> {
@@ -296,4 +296,19 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    pub fn is_in_deleted_region() {
+        let deleted_regions =
+            DeletedRegionsBuilder::from_regions(vec![oplog_region(2, 8), oplog_region(20, 22)])
+                .build();
+
+        assert!(!deleted_regions.is_in_deleted_region(OplogIndex::from_u64(1)));
+        assert!(deleted_regions.is_in_deleted_region(OplogIndex::from_u64(2)));
+        assert!(deleted_regions.is_in_deleted_region(OplogIndex::from_u64(5)));
+        assert!(deleted_regions.is_in_deleted_region(OplogIndex::from_u64(8)));
+        assert!(!deleted_regions.is_in_deleted_region(OplogIndex::from_u64(9)));
+        assert!(deleted_regions.is_in_deleted_region(OplogIndex::from_u64(22)));
+        assert!(!deleted_regions.is_in_deleted_region(OplogIndex::from_u64(23)));
+    }
 }
@@ -0,0 +1,188 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+const SAMPLED_FLAG: u8 = 0x01;
+
+/// W3C trace context of a single invocation.
+///
+/// Every invocation is a span of its own: `span_id` is generated when the context is received, and
+/// `parent_span_id` is the span of the caller, taken from its `traceparent` header.
+/// See https://www.w3.org/TR/trace-context/
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct TraceContext {
+    pub trace_id: u128,
+    pub parent_span_id: Option<u64>,
+    pub span_id: u64,
+    pub trace_flags: u8,
+    pub trace_state: Option<String>,
+}
+
+impl TraceContext {
+    /// Starts a new span for an invocation made by the caller described by the given headers
+    pub fn from_headers(traceparent: &str, tracestate: Option<&str>) -> Result<Self, String> {
+        let parts: Vec<&str> = traceparent.trim().split('-').collect();
+
+        let (version, trace_id, parent_id, flags) = match parts.as_slice() {
+            [version, trace_id, parent_id, flags, ..] => (*version, *trace_id, *parent_id, *flags),
+            _ => return Err(format!("Invalid traceparent: {traceparent}")),
+        };
+
+        // Future versions may append fields, but version 00 has exactly four of them
+        if version.len() != 2 || version == "ff" || (version == "00" && parts.len() != 4) {
+            return Err(format!("Unsupported traceparent version: {version}"));
+        }
+
+        let trace_id = parse_hex_id(trace_id, 32, "trace id")?;
+        let parent_span_id = parse_hex_id(parent_id, 16, "parent id")? as u64;
+        let trace_flags = parse_hex_id(flags, 2, "trace flags")? as u8;
+
+        Ok(Self {
+            trace_id,
+            parent_span_id: Some(parent_span_id),
+            span_id: new_span_id(),
+            trace_flags,
+            trace_state: tracestate
+                .map(|state| state.trim().to_string())
+                .filter(|state| !state.is_empty()),
+        })
+    }
+
+    /// Starts a new span for an invocation made from within this span
+    pub fn child(&self) -> Self {
+        Self {
+            trace_id: self.trace_id,
+            parent_span_id: Some(self.span_id),
+            span_id: new_span_id(),
+            trace_flags: self.trace_flags,
+            trace_state: self.trace_state.clone(),
+        }
+    }
+
+    /// The `traceparent` header identifying this span as the parent of outgoing calls
+    pub fn traceparent(&self) -> String {
+        format!(
+            "00-{:032x}-{:016x}-{:02x}",
+            self.trace_id, self.span_id, self.trace_flags
+        )
+    }
+
+    pub fn tracestate(&self) -> Option<&str> {
+        self.trace_state.as_deref()
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.trace_flags & SAMPLED_FLAG != 0
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::TraceContext> for TraceContext {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::TraceContext,
+    ) -> Result<Self, Self::Error> {
+        Self::from_headers(&value.traceparent, value.tracestate.as_deref())
+    }
+}
+
+impl From<TraceContext> for golem_api_grpc::proto::golem::worker::TraceContext {
+    fn from(value: TraceContext) -> Self {
+        Self {
+            traceparent: value.traceparent(),
+            tracestate: value.trace_state,
+        }
+    }
+}
+
+fn parse_hex_id(value: &str, length: usize, name: &str) -> Result<u128, String> {
+    let is_lower_hex = value
+        .chars()
+        .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+
+    if value.len() != length || !is_lower_hex {
+        return Err(format!("Invalid {name} in traceparent: {value}"));
+    }
+
+    match u128::from_str_radix(value, 16) {
+        Ok(0) if length > 2 => Err(format!("All-zero {name} in traceparent")),
+        Ok(id) => Ok(id),
+        Err(_) => Err(format!("Invalid {name} in traceparent: {value}")),
+    }
+}
+
+fn new_span_id() -> u64 {
+    loop {
+        let id = rand::random::<u64>();
+        if id != 0 {
+            return id;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::trace_context::TraceContext;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn from_headers() {
+        let context = TraceContext::from_headers(TRACEPARENT, Some("congo=t61rcWkgMzE")).unwrap();
+
+        assert_eq!(context.trace_id, 0x4bf92f3577b34da6a3ce929d0e0e4736);
+        assert_eq!(context.parent_span_id, Some(0x00f067aa0ba902b7));
+        assert_ne!(context.span_id, 0x00f067aa0ba902b7);
+        assert!(context.is_sampled());
+        assert_eq!(context.tracestate(), Some("congo=t61rcWkgMzE"));
+    }
+
+    #[test]
+    fn invalid_headers() {
+        for traceparent in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+        ] {
+            assert!(
+                TraceContext::from_headers(traceparent, None).is_err(),
+                "{traceparent}"
+            );
+        }
+    }
+
+    #[test]
+    fn child_continues_the_trace() {
+        let context = TraceContext::from_headers(TRACEPARENT, None).unwrap();
+        let child = context.child();
+        let received = TraceContext::from_headers(&context.traceparent(), None).unwrap();
+
+        assert_eq!(child.trace_id, context.trace_id);
+        assert_eq!(child.parent_span_id, Some(context.span_id));
+        assert_eq!(received.trace_id, context.trace_id);
+        assert_eq!(received.parent_span_id, Some(context.span_id));
+        assert_eq!(received.trace_flags, context.trace_flags);
+    }
+}
@@ -24,7 +24,7 @@ use fred::cmd;
 use fred::prelude::{RedisPool as FredRedisPool, *};
 use fred::types::{
     InfoKind, Limit, MultipleKeys, MultipleOrderedPairs, MultipleValues, MultipleZaddValues,
-    Ordering, RedisKey, RedisMap, XCap, ZRange, ZSort, XID,
+    Ordering, RedisKey, RedisMap, Str, XCap, ZRange, ZSort, XID,
 };
 use tracing::{debug, Level};
 
@@ -665,6 +665,24 @@ impl<'a> RedisLabelledApi<'a> {
         )
     }
 
+    /// Runs a Lua script atomically, with the given keys prefixed like in all the other commands
+    pub async fn eval<R, S, K, V>(&self, script: S, keys: Vec<K>, args: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        S: Into<Str> + Send,
+        K: AsRef<str>,
+        V: TryInto<MultipleValues> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        let keys = keys
+            .iter()
+            .map(|k| self.prefixed_key(k))
+            .collect::<Vec<_>>();
+        self.record(start, "EVAL", self.pool.eval(script, keys, args).await)
+    }
+
     pub async fn transaction<R, F, Fu>(&self, func: F) -> RedisResult<R>
     where
         R: FromRedis,
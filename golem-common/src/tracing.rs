@@ -20,22 +20,31 @@ use std::sync::Arc;
 
 use figment::providers::Serialized;
 use figment::Figment;
+use opentelemetry::trace::{
+    SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState, TracerProvider,
+};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
 use serde::{Deserialize, Serialize};
-use tracing::{error, info};
+use tracing::{error, info, Span};
 use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::Layer;
 use tracing_subscriber::Registry;
 
 use crate::config::env_config_provider;
+use crate::model::trace_context::TraceContext;
 use crate::tracing::format::JsonFlattenSpanFormatter;
 
 pub enum Output {
     Stdout,
     File,
     TracingConsole,
+    Otlp,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -133,6 +142,24 @@ impl OutputConfig {
     }
 }
 
+// Exports spans to an OpenTelemetry collector using OTLP over HTTP
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OtlpConfig {
+    pub enabled: bool,
+    pub endpoint: String,
+    pub service_name: String,
+}
+
+impl OtlpConfig {
+    pub fn disabled(service_name: &str) -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://localhost:4318".to_string(),
+            service_name: service_name.to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TracingConfig {
     pub stdout: OutputConfig,
@@ -141,6 +168,7 @@ pub struct TracingConfig {
     pub file_name: Option<String>,
     pub file_truncate: bool,
     pub console: bool,
+    pub otlp: OtlpConfig,
     pub dtor_friendly: bool,
 }
 
@@ -156,6 +184,7 @@ impl TracingConfig {
             file_name: Some(format!("{}.log", name)),
             file_truncate: true,
             console: false,
+            otlp: OtlpConfig::disabled(name),
             dtor_friendly: false,
         }
     }
@@ -213,6 +242,7 @@ impl Default for TracingConfig {
             file_name: None,
             file_truncate: true,
             console: false,
+            otlp: OtlpConfig::disabled("golem"),
             dtor_friendly: false,
         }
     }
@@ -391,6 +421,10 @@ where
         );
     }
 
+    if config.otlp.enabled {
+        layers.push(make_otlp_layer(&config.otlp, make_filter(Output::Otlp)));
+    }
+
     tracing_subscriber::registry().with(layers).init();
 
     std::panic::set_hook({
@@ -416,6 +450,73 @@ pub fn init_tracing_with_default_debug_env_filter(config: &TracingConfig) {
     init_tracing(config, filter::for_all_outputs::default_debug_env());
 }
 
+/// Makes the OTLP exported span of `span` continue the trace described by `trace_context`,
+/// using its span id. Does nothing if OTLP export is not enabled.
+pub fn set_span_trace_context(span: &Span, trace_context: &TraceContext) {
+    span.with_subscriber(|(id, dispatch)| {
+        let Some(registry) = dispatch.downcast_ref::<Registry>() else {
+            return;
+        };
+        let Some(span_ref) = registry.span(id) else {
+            return;
+        };
+        let mut extensions = span_ref.extensions_mut();
+        let Some(otel_data) = extensions.get_mut::<tracing_opentelemetry::OtelData>() else {
+            return;
+        };
+
+        let trace_id = TraceId::from_bytes(trace_context.trace_id.to_be_bytes());
+        otel_data.builder.trace_id = Some(trace_id);
+        otel_data.builder.span_id = Some(SpanId::from_bytes(trace_context.span_id.to_be_bytes()));
+
+        if let Some(parent_span_id) = trace_context.parent_span_id {
+            let trace_state = trace_context
+                .tracestate()
+                .and_then(|state| state.parse::<TraceState>().ok())
+                .unwrap_or_default();
+
+            otel_data.parent_cx =
+                opentelemetry::Context::new().with_remote_span_context(SpanContext::new(
+                    trace_id,
+                    SpanId::from_bytes(parent_span_id.to_be_bytes()),
+                    TraceFlags::new(trace_context.trace_flags),
+                    true,
+                    trace_state,
+                ));
+        }
+    });
+}
+
+fn make_otlp_layer(
+    config: &OtlpConfig,
+    filter: filter::Boxed,
+) -> Box<dyn Layer<Registry> + Send + Sync> {
+    // The batch exporter runs on its own thread, as tracing is initialized before the runtime
+    let provider = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(&config.endpoint),
+        )
+        .with_trace_config(opentelemetry_sdk::trace::Config::default().with_resource(
+            Resource::new(vec![KeyValue::new(
+                "service.name",
+                config.service_name.clone(),
+            )]),
+        ))
+        .install_batch(opentelemetry_sdk::runtime::TokioCurrentThread)
+        .unwrap_or_else(|err| panic!("cannot create OTLP exporter: {}", err));
+
+    let tracer = provider.tracer(config.service_name.clone());
+    opentelemetry::global::set_tracer_provider(provider);
+
+    tracing_opentelemetry::layer()
+        .with_tracer(tracer)
+        .with_filter(filter)
+        .boxed()
+}
+
 #[allow(clippy::collapsible_else_if)]
 fn make_layer<W>(
     config: &OutputConfig,
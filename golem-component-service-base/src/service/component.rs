@@ -27,6 +27,7 @@ use golem_api_grpc::proto::golem::component::v1::component_error;
 use golem_common::file_system::{PackagedFileSet, PackagedFiles};
 use golem_common::model::component_constraint::FunctionConstraintCollection;
 use golem_common::model::component_metadata::{ComponentMetadata, ComponentProcessingError};
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{ComponentId, ComponentType, FileSystemPermission};
 use golem_common::SafeDisplay;
 use golem_service_base::model::{ComponentName, VersionedComponentId};
@@ -163,6 +164,7 @@ pub trait ComponentService<Namespace> {
         data: Vec<u8>,
         namespace: &Namespace,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component<Namespace>, ComponentError>;
 
     async fn update(
@@ -172,6 +174,7 @@ pub trait ComponentService<Namespace> {
         component_type: Option<ComponentType>,
         namespace: &Namespace,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component<Namespace>, ComponentError>;
 
     async fn download(
@@ -428,6 +431,7 @@ where
         data: Vec<u8>,
         namespace: &Namespace,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component<Namespace>, ComponentError> {
         info!(namespace = %namespace, "Create component");
 
@@ -435,13 +439,14 @@ where
             .await?
             .map_or(Ok(()), |id| Err(ComponentError::AlreadyExists(id)))?;
 
-        let component = Component::new(
+        let mut component = Component::new(
             component_id,
             component_name,
             component_type,
             &data,
             namespace,
         )?;
+        component.metadata.quotas = quotas.unwrap_or_default();
 
         let (files_ro, files_rw) = initial_files.split();
 
@@ -478,6 +483,7 @@ where
         component_type: Option<ComponentType>,
         namespace: &Namespace,
         initial_files: PackagedFileSet,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Component<Namespace>, ComponentError> {
         info!(namespace = %namespace, "Update component");
 
@@ -527,7 +533,10 @@ where
 
         let component = Component {
             component_size,
-            metadata,
+            metadata: ComponentMetadata {
+                quotas: quotas.unwrap_or_else(|| next_component.metadata.quotas.clone()),
+                ..metadata
+            },
             created_at,
             component_type: component_type.unwrap_or(next_component.component_type),
             ..next_component
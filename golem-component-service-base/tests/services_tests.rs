@@ -7,6 +7,7 @@ use golem_service_base::config::ComponentStoreLocalConfig;
 use golem_service_base::db;
 
 use golem_common::model::component_constraint::FunctionConstraintCollection;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{ComponentId, ComponentType};
 use golem_common::SafeDisplay;
 use golem_component_service_base::model::Component;
@@ -151,6 +152,7 @@ async fn test_component_constraint_incompatible_updates(
             get_component_data("shopping-cart"),
             &DefaultNamespace::default(),
             PackagedFileSet::empty(),
+            None,
         )
         .await
         .unwrap();
@@ -183,6 +185,7 @@ async fn test_component_constraint_incompatible_updates(
             None,
             &DefaultNamespace::default(),
             PackagedFileSet::empty(),
+            None,
         )
         .await
         .unwrap_err()
@@ -236,7 +239,11 @@ async fn test_services(component_repo: Arc<dyn ComponentRepo + Sync + Send>) {
             ComponentType::Durable,
             get_component_data("shopping-cart"),
             &DefaultNamespace::default(),
-                PackagedFileSet::empty()
+            PackagedFileSet::empty(),
+            Some(WorkerQuotas {
+                max_memory: Some(64 * 1024 * 1024),
+                ..WorkerQuotas::default()
+            }),
         )
         .await
         .unwrap();
@@ -248,7 +255,8 @@ async fn test_services(component_repo: Arc<dyn ComponentRepo + Sync + Send>) {
             ComponentType::Durable,
             get_component_data("rust-echo"),
             &DefaultNamespace::default(),
-                PackagedFileSet::empty(),
+            PackagedFileSet::empty(),
+            None,
         )
         .await
         .unwrap();
@@ -336,7 +344,8 @@ async fn test_services(component_repo: Arc<dyn ComponentRepo + Sync + Send>) {
             get_component_data("shopping-cart"),
             None,
             &DefaultNamespace::default(),
-                PackagedFileSet::empty(),
+            PackagedFileSet::empty(),
+            None,
         )
         .await
         .unwrap();
@@ -350,6 +359,10 @@ async fn test_services(component_repo: Arc<dyn ComponentRepo + Sync + Send>) {
         .unwrap();
     assert!(component1_result.is_some());
     assert_eq!(component1_result.unwrap(), component1v2);
+    assert_eq!(
+        component1v2.metadata.quotas.max_memory,
+        Some(64 * 1024 * 1024)
+    );
 
     let component1_result = component_service
         .get(
@@ -14,7 +14,8 @@
 
 use futures_util::TryStreamExt;
 use golem_common::file_system::PackagedFileSet;
-use golem_common::model::{ComponentId, ComponentType};
+use golem_common::model::quotas::WorkerQuotas;
+use golem_common::model::{ComponentId, ComponentType, FileSystemPermission};
 use golem_component_service_base::service::component::{
     ComponentError as ComponentServiceError, ComponentService,
 };
@@ -25,7 +26,7 @@ use poem::error::ReadBodyError;
 use poem::Body;
 use poem_openapi::param::{Path, Query};
 use poem_openapi::payload::{Binary, Json};
-use poem_openapi::types::multipart::Upload;
+use poem_openapi::types::multipart::{JsonField, Upload};
 use poem_openapi::*;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -70,6 +71,7 @@ pub struct UploadPayload {
     component: Upload,
     files_ro: Option<Upload>,
     files_rw: Option<Upload>,
+    quotas: Option<JsonField<WorkerQuotas>>,
 }
 
 #[derive(ApiRequest)]
@@ -83,6 +85,7 @@ pub struct UpdatePayload {
     component: Upload,
     files_ro: Option<Upload>,
     files_rw: Option<Upload>,
+    quotas: Option<JsonField<WorkerQuotas>>,
 }
 
 type Result<T> = std::result::Result<T, ComponentError>;
@@ -197,6 +200,7 @@ impl ComponentApi {
                     data,
                     &DefaultNamespace::default(),
                     initial_files,
+                    payload.quotas.map(|quotas| quotas.0),
                 )
                 .instrument(record.span.clone())
                 .await
@@ -229,6 +233,7 @@ impl ComponentApi {
         let response = {
             let mut files_ro = vec![];
             let mut files_rw = vec![];
+            let mut quotas = None;
             let data;
             
             match request_body {
@@ -242,6 +247,7 @@ impl ComponentApi {
                     if let Some(f_rw) = multipart.files_rw {
                         files_rw = f_rw.into_vec().await?;
                     }
+                    quotas = multipart.quotas.map(|quotas| quotas.0);
                     
                     data = multipart.component.into_vec().await?;
                 }
@@ -256,6 +262,7 @@ impl ComponentApi {
                     component_type.0,
                     &DefaultNamespace::default(),
                     initial_files,
+                    quotas,
                 )
                 .instrument(record.span.clone())
                 .await
@@ -297,6 +304,44 @@ impl ComponentApi {
         record.result(response)
     }
 
+    /// Download the initial files of a component
+    ///
+    /// Downloads the archive of the read-only or read-write initial files of a specific version of the component.
+    #[oai(
+        path = "/:component_id/initial-files",
+        method = "get",
+        operation_id = "download_initial_files"
+    )]
+    async fn download_initial_files(
+        &self,
+        component_id: Path<ComponentId>,
+        version: Query<Option<u64>>,
+        permission: Query<FileSystemPermission>,
+    ) -> Result<Binary<Body>> {
+        let record = recorded_http_api_request!(
+            "download_initial_files",
+            component_id = component_id.0.to_string(),
+            version = version.0.map(|v| v.to_string())
+        );
+        let response = self
+            .component_service
+            .download_initial_files_stream(
+                &component_id.0,
+                version.0,
+                &DefaultNamespace::default(),
+                &permission.0,
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|bytes| {
+                Binary(Body::from_bytes_stream(bytes.map_err(|e| {
+                    std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+                })))
+            });
+        record.result(response)
+    }
+
     /// Get the metadata for all component versions
     ///
     /// Each component can have multiple versions. Every time a new WASM is uploaded for a given component id, that creates a new version.
@@ -207,7 +207,8 @@ impl ComponentGrpcApi {
                 component_type,
                 data,
                 &DefaultNamespace::default(),
-                initial_files
+                initial_files,
+                request.quotas.map(|quotas| quotas.into()),
             )
             .await?;
         Ok(result.into())
@@ -239,6 +240,7 @@ impl ComponentGrpcApi {
                 component_type, 
                 &DefaultNamespace::default(),
                 initial_files,
+                request.quotas.map(|quotas| quotas.into()),
             ).await?;
         Ok(result.into())
     }
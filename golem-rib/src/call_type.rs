@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{DynamicParsedFunctionName, ParsedFunctionName};
+use crate::{BuiltinFunction, DynamicParsedFunctionName, ParsedFunctionName, VariableId};
 use bincode::{Decode, Encode};
 use std::convert::TryFrom;
 use std::fmt::Display;
@@ -22,6 +22,9 @@ pub enum CallType {
     Function(DynamicParsedFunctionName),
     VariantConstructor(String),
     EnumConstructor(String),
+    Builtin(BuiltinFunction),
+    // A call to a function defined in the Rib script using `let`
+    LocalFunction(VariableId),
 }
 
 impl CallType {
@@ -43,6 +46,8 @@ impl Display for CallType {
             CallType::Function(parsed_fn_name) => write!(f, "{}", parsed_fn_name),
             CallType::VariantConstructor(name) => write!(f, "{}", name),
             CallType::EnumConstructor(name) => write!(f, "{}", name),
+            CallType::Builtin(function) => write!(f, "{}", function),
+            CallType::LocalFunction(variable_id) => write!(f, "{}", variable_id.name()),
         }
     }
 }
@@ -61,6 +66,14 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::CallType> for CallType {
             golem_api_grpc::proto::golem::rib::call_type::Name::EnumConstructor(name) => {
                 Ok(CallType::EnumConstructor(name))
             }
+            golem_api_grpc::proto::golem::rib::call_type::Name::Builtin(name) => {
+                BuiltinFunction::from_name(&name)
+                    .map(CallType::Builtin)
+                    .ok_or(format!("Unknown builtin function {}", name))
+            }
+            golem_api_grpc::proto::golem::rib::call_type::Name::LocalFunction(name) => {
+                Ok(CallType::LocalFunction(VariableId::global(name)))
+            }
         }
     }
 }
@@ -83,6 +96,18 @@ impl From<CallType> for golem_api_grpc::proto::golem::rib::CallType {
                     golem_api_grpc::proto::golem::rib::call_type::Name::EnumConstructor(name),
                 ),
             },
+            CallType::Builtin(function) => golem_api_grpc::proto::golem::rib::CallType {
+                name: Some(golem_api_grpc::proto::golem::rib::call_type::Name::Builtin(
+                    function.name().to_string(),
+                )),
+            },
+            CallType::LocalFunction(variable_id) => golem_api_grpc::proto::golem::rib::CallType {
+                name: Some(
+                    golem_api_grpc::proto::golem::rib::call_type::Name::LocalFunction(
+                        variable_id.name(),
+                    ),
+                ),
+            },
         }
     }
 }
@@ -84,9 +84,10 @@ impl From<RibByteCode> for ProtoRibByteCode {
 
 mod internal {
     use crate::compiler::desugar::desugar_pattern_match;
+    use crate::parser::type_name::TypeName;
     use crate::{
-        AnalysedTypeWithUnit, DynamicParsedFunctionReference, Expr, FunctionReferenceType,
-        InferredType, InstructionId, RibIR, VariableId,
+        AnalysedTypeWithUnit, BuiltinFunction, DynamicParsedFunctionReference, Expr,
+        FunctionReferenceType, InferredType, InstructionId, RibIR, VariableId,
     };
     use golem_wasm_ast::analysis::AnalysedType;
     use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -109,6 +110,12 @@ mod internal {
             Expr::Throw(msg, _) => {
                 instructions.push(RibIR::Throw(msg.to_string()));
             }
+            Expr::Import(import, _) => {
+                return Err(format!(
+                    "Unresolved import of Rib module `{}`. Imports should be resolved before compiling Rib",
+                    import
+                ));
+            }
             Expr::Identifier(variable_id, _) => {
                 instructions.push(RibIR::LoadVar(variable_id.clone()));
             }
@@ -240,6 +247,17 @@ mod internal {
                     stack.push(ExprState::from_expr(expr));
                 }
             }
+            // The body of a function is skipped, until the function is called
+            Expr::Let(variable_id, _, inner_expr, _)
+                if matches!(inner_expr.deref(), Expr::Function { .. }) =>
+            {
+                if let Expr::Function {
+                    parameters, body, ..
+                } = inner_expr.deref()
+                {
+                    handle_function(instruction_id, stack, variable_id, parameters, body);
+                }
+            }
             Expr::Let(variable_id, _, inner_expr, _) => {
                 stack.push(ExprState::from_expr(inner_expr.deref()));
                 instructions.push(RibIR::AssignVar(variable_id.clone()));
@@ -297,6 +315,20 @@ mod internal {
                 )?));
             }
 
+            // Builtin functions taking a lambda are compiled to a loop, with the lambda inlined
+            Expr::Call(CallType::Builtin(function), arguments, inferred_type)
+                if function.takes_lambda() =>
+            {
+                let analysed_type = convert_to_analysed_type(expr, inferred_type)?;
+                handle_builtin_with_lambda(
+                    instruction_id,
+                    stack,
+                    function,
+                    arguments,
+                    &analysed_type,
+                )?
+            }
+
             Expr::Call(call_type, arguments, inferred_type) => {
                 for expr in arguments.iter().rev() {
                     stack.push(ExprState::from_expr(expr));
@@ -444,6 +476,15 @@ mod internal {
                             convert_to_analysed_type(expr, inferred_type)?,
                         ));
                     }
+                    CallType::Builtin(function) => {
+                        instructions.push(RibIR::InvokeBuiltin(
+                            *function,
+                            convert_to_analysed_type(expr, inferred_type)?,
+                        ));
+                    }
+                    CallType::LocalFunction(variable_id) => {
+                        instructions.push(RibIR::CallLocalFunction(variable_id.clone()));
+                    }
                 }
             }
 
@@ -523,6 +564,20 @@ mod internal {
                 init_value_expr,
                 yield_expr,
             ),
+
+            Expr::Lambda { .. } => {
+                return Err(format!(
+                    "Invalid Rib {}. Lambdas can only be passed to `filter` and `map`",
+                    expr
+                ));
+            }
+
+            Expr::Function { .. } => {
+                return Err(format!(
+                    "Invalid Rib {}. Functions should be assigned to a variable using `let`, or passed to `filter` and `map`",
+                    expr
+                ));
+            }
         }
 
         Ok(())
@@ -599,6 +654,121 @@ mod internal {
         stack.push(ExprState::from_ir(RibIR::SinkToList))
     }
 
+    // Arguments are pushed to the stack in order, and are therefore assigned
+    // to the parameters in reverse order
+    fn handle_function(
+        instruction_id: &mut InstructionId,
+        stack: &mut Vec<ExprState>,
+        function: &VariableId,
+        parameters: &[(VariableId, TypeName)],
+        body: &Expr,
+    ) {
+        let end_label = instruction_id.increment_mut();
+
+        stack.push(ExprState::from_ir(RibIR::Jump(end_label.clone())));
+
+        stack.push(ExprState::from_ir(RibIR::FunctionStart(function.clone())));
+
+        for (parameter, _) in parameters.iter().rev() {
+            stack.push(ExprState::from_ir(RibIR::AssignVar(parameter.clone())));
+        }
+
+        stack.push(ExprState::from_expr(body));
+
+        stack.push(ExprState::from_ir(RibIR::Return));
+
+        stack.push(ExprState::from_ir(RibIR::Label(end_label)));
+    }
+
+    fn handle_builtin_with_lambda(
+        instruction_id: &mut InstructionId,
+        stack: &mut Vec<ExprState>,
+        function: &BuiltinFunction,
+        arguments: &[Expr],
+        sink_type: &AnalysedType,
+    ) -> Result<(), String> {
+        match (function, arguments) {
+            (
+                BuiltinFunction::Map,
+                [list_expr, Expr::Lambda {
+                    parameter, body, ..
+                }],
+            ) => {
+                handle_list_comprehension(
+                    instruction_id,
+                    stack,
+                    list_expr,
+                    body,
+                    parameter,
+                    sink_type,
+                );
+                Ok(())
+            }
+            (
+                BuiltinFunction::Filter,
+                [list_expr, Expr::Lambda {
+                    parameter, body, ..
+                }],
+            ) => {
+                handle_filter(instruction_id, stack, list_expr, body, parameter, sink_type);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Invalid call to `{}`. Expected a list and a lambda",
+                function
+            )),
+        }
+    }
+
+    // Same as a list comprehension, except that an element is pushed to the sink
+    // only if the predicate is true
+    fn handle_filter(
+        instruction_id: &mut InstructionId,
+        stack: &mut Vec<ExprState>,
+        iterable_expr: &Expr,
+        predicate_expr: &Expr,
+        variable_id: &VariableId,
+        sink_type: &AnalysedType,
+    ) {
+        stack.push(ExprState::from_expr(iterable_expr));
+
+        stack.push(ExprState::from_ir(RibIR::ListToIterator));
+
+        stack.push(ExprState::from_ir(RibIR::CreateSink(sink_type.clone())));
+
+        let loop_start_label = instruction_id.increment_mut();
+
+        stack.push(ExprState::from_ir(RibIR::Label(loop_start_label.clone())));
+
+        let exit_label = instruction_id.increment_mut();
+
+        stack.push(ExprState::from_ir(RibIR::IsEmpty));
+
+        stack.push(ExprState::from_ir(RibIR::JumpIfFalse(exit_label.clone())));
+
+        stack.push(ExprState::from_ir(RibIR::AdvanceIterator));
+
+        stack.push(ExprState::from_ir(RibIR::AssignVar(variable_id.clone())));
+
+        let skip_label = instruction_id.increment_mut();
+
+        stack.push(ExprState::from_expr(predicate_expr));
+
+        stack.push(ExprState::from_ir(RibIR::JumpIfFalse(skip_label.clone())));
+
+        stack.push(ExprState::from_ir(RibIR::LoadVar(variable_id.clone())));
+
+        stack.push(ExprState::from_ir(RibIR::PushToSink));
+
+        stack.push(ExprState::from_ir(RibIR::Label(skip_label)));
+
+        stack.push(ExprState::from_ir(RibIR::Jump(loop_start_label)));
+
+        stack.push(ExprState::from_ir(RibIR::Label(exit_label)));
+
+        stack.push(ExprState::from_ir(RibIR::SinkToList))
+    }
+
     fn handle_list_reduce(
         instruction_id: &mut InstructionId,
         stack: &mut Vec<ExprState>,
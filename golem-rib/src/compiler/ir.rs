@@ -12,14 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AnalysedTypeWithUnit, ParsedFunctionSite, VariableId};
+use crate::{AnalysedTypeWithUnit, BuiltinFunction, ParsedFunctionSite, VariableId};
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem::rib::rib_ir::Instruction;
 use golem_api_grpc::proto::golem::rib::{
     And, CallInstruction, ConcatInstruction, CreateFunctionNameInstruction, EqualTo, GetTag,
-    GreaterThan, GreaterThanOrEqualTo, IsEmpty, JumpInstruction, LessThan, LessThanOrEqualTo,
-    Negate, Or, PushListInstruction, PushNoneInstruction, PushTupleInstruction,
-    RibIr as ProtoRibIR,
+    GreaterThan, GreaterThanOrEqualTo, InvokeBuiltinInstruction, IsEmpty, JumpInstruction,
+    LessThan, LessThanOrEqualTo, Negate, Or, PushListInstruction, PushNoneInstruction,
+    PushTupleInstruction, Return, RibIr as ProtoRibIR,
 };
 use golem_wasm_ast::analysis::{AnalysedType, TypeStr};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -56,6 +56,10 @@ pub enum RibIR {
     Deconstruct,
     CreateFunctionName(ParsedFunctionSite, FunctionReferenceType),
     InvokeFunction(usize, AnalysedTypeWithUnit),
+    InvokeBuiltin(BuiltinFunction, AnalysedType),
+    FunctionStart(VariableId), // Marks the beginning of the body of a function defined in Rib
+    CallLocalFunction(VariableId),
+    Return,
     PushVariant(String, AnalysedType), // There is no arg size since the type of each variant case is only 1 from beginning
     PushEnum(String, AnalysedType),
     Throw(String),
@@ -291,6 +295,17 @@ impl TryFrom<ProtoRibIR> for RibIR {
                     .try_into()
                     .map_err(|_| "Failed to convert LoadVar".to_string())?,
             )),
+            Instruction::FunctionStart(value) => {
+                Ok(RibIR::FunctionStart(value.try_into().map_err(|_| {
+                    "Failed to convert FunctionStart".to_string()
+                })?))
+            }
+            Instruction::CallLocalFunction(value) => {
+                Ok(RibIR::CallLocalFunction(value.try_into().map_err(
+                    |_| "Failed to convert CallLocalFunction".to_string(),
+                )?))
+            }
+            Instruction::Return(_) => Ok(RibIR::Return),
             Instruction::CreateAndPushRecord(value) => {
                 Ok(RibIR::CreateAndPushRecord((&value).try_into().map_err(
                     |_| "Failed to convert CreateAndPushRecord".to_string(),
@@ -470,6 +485,21 @@ impl TryFrom<ProtoRibIR> for RibIR {
             Instruction::AdvanceIterator(_) => Ok(RibIR::AdvanceIterator),
             Instruction::SinkToList(_) => Ok(RibIR::SinkToList),
             Instruction::PushToSink(_) => Ok(RibIR::PushToSink),
+            Instruction::InvokeBuiltin(invoke_builtin) => {
+                let function = BuiltinFunction::from_name(&invoke_builtin.function).ok_or(
+                    format!("Unknown builtin function {}", invoke_builtin.function),
+                )?;
+
+                let return_type = invoke_builtin
+                    .return_type
+                    .ok_or("Missing return_type for builtin function".to_string())
+                    .and_then(|t| {
+                        (&t).try_into()
+                            .map_err(|_| "Failed to convert AnalysedType".to_string())
+                    })?;
+
+                Ok(RibIR::InvokeBuiltin(function, return_type))
+            }
         }
     }
 }
@@ -487,6 +517,9 @@ impl From<RibIR> for ProtoRibIR {
             RibIR::Or => Instruction::Or(Or {}),
             RibIR::AssignVar(value) => Instruction::AssignVar(value.into()),
             RibIR::LoadVar(value) => Instruction::LoadVar(value.into()),
+            RibIR::FunctionStart(value) => Instruction::FunctionStart(value.into()),
+            RibIR::CallLocalFunction(value) => Instruction::CallLocalFunction(value.into()),
+            RibIR::Return => Instruction::Return(Return {}),
             RibIR::CreateAndPushRecord(value) => Instruction::CreateAndPushRecord((&value).into()),
             RibIR::Plus(value) => Instruction::Plus((&value).into()),
             RibIR::Minus(value) => Instruction::Minus((&value).into()),
@@ -602,6 +635,12 @@ impl From<RibIR> for ProtoRibIR {
             RibIR::SinkToList => {
                 Instruction::SinkToList(golem_api_grpc::proto::golem::rib::SinkToList {})
             }
+            RibIR::InvokeBuiltin(function, return_type) => {
+                Instruction::InvokeBuiltin(InvokeBuiltinInstruction {
+                    function: function.name().to_string(),
+                    return_type: Some((&return_type).into()),
+                })
+            }
         };
 
         ProtoRibIR {
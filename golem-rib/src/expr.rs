@@ -17,8 +17,8 @@ use crate::parser::rib_expr::rib_program;
 use crate::parser::type_name::TypeName;
 use crate::type_registry::FunctionTypeRegistry;
 use crate::{
-    from_string, text, type_checker, type_inference, DynamicParsedFunctionName, InferredType,
-    ParsedFunctionName, VariableId,
+    from_string, rib_module, text, type_checker, type_inference, DynamicParsedFunctionName,
+    InferredType, ParsedFunctionName, RibImport, RibModule, VariableId,
 };
 use bincode::{Decode, Encode};
 use combine::stream::position;
@@ -82,6 +82,23 @@ pub enum Expr {
         init_value_expr: Box<Expr>,
         inferred_type: InferredType,
     },
+    // The inferred type is the type of the body
+    Lambda {
+        parameter: VariableId,
+        body: Box<Expr>,
+        inferred_type: InferredType,
+    },
+    // A function such as `fn(x: u64) -> u64 { x + 1 }`, which is bound to a name using `let`.
+    // The inferred type is the return type of the function
+    Function {
+        parameters: Vec<(VariableId, TypeName)>,
+        return_type: Option<TypeName>,
+        body: Box<Expr>,
+        inferred_type: InferredType,
+    },
+    // An import of a Rib module such as `import helpers@1;`, which is replaced
+    // by the definitions of the module before the Rib script is compiled
+    Import(RibImport, InferredType),
 }
 
 impl Expr {
@@ -390,6 +407,44 @@ impl Expr {
         )
     }
 
+    pub fn typed_lambda(parameter: VariableId, body: Expr, inferred_type: InferredType) -> Self {
+        Expr::Lambda {
+            parameter,
+            body: Box::new(body),
+            inferred_type,
+        }
+    }
+
+    pub fn lambda(parameter: VariableId, body: Expr) -> Self {
+        Expr::typed_lambda(parameter, body, InferredType::Unknown)
+    }
+
+    pub fn typed_function(
+        parameters: Vec<(VariableId, TypeName)>,
+        return_type: Option<TypeName>,
+        body: Expr,
+        inferred_type: InferredType,
+    ) -> Self {
+        Expr::Function {
+            parameters,
+            return_type,
+            body: Box::new(body),
+            inferred_type,
+        }
+    }
+
+    pub fn function(
+        parameters: Vec<(VariableId, TypeName)>,
+        return_type: Option<TypeName>,
+        body: Expr,
+    ) -> Self {
+        Expr::typed_function(parameters, return_type, body, InferredType::Unknown)
+    }
+
+    pub fn import(module_name: impl AsRef<str>, version: Option<u64>) -> Self {
+        Expr::Import(RibImport::new(module_name, version), InferredType::Unknown)
+    }
+
     pub fn literal(value: impl AsRef<str>) -> Self {
         Expr::Literal(value.as_ref().to_string(), InferredType::Str)
     }
@@ -532,6 +587,9 @@ impl Expr {
             | Expr::Or(_, _, inferred_type)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Function { inferred_type, .. }
+            | Expr::Import(_, inferred_type)
             | Expr::Call(_, _, inferred_type) => inferred_type.clone(),
         }
     }
@@ -561,8 +619,10 @@ impl Expr {
         self.bind_variables_of_list_reduce();
         self.bind_variables_of_pattern_match();
         self.bind_variables_of_let_assignment();
+        self.bind_local_functions().map_err(|x| vec![x])?;
         self.infer_variants(function_type_registry);
         self.infer_enums(function_type_registry);
+        self.infer_builtin_functions(function_type_registry);
 
         Ok(())
     }
@@ -575,6 +635,7 @@ impl Expr {
         self.infer_all_identifiers()?;
         let expr = self.pull_types_up()?;
         *self = expr;
+        self.infer_local_function_calls();
         self.infer_global_inputs();
         Ok(())
     }
@@ -601,6 +662,30 @@ impl Expr {
         type_inference::bind_variables_of_list_reduce(self);
     }
 
+    // Calls to the functions defined in the Rib script are resolved to the corresponding definitions,
+    // and the types of the parameters are bound to the arguments of these calls
+    pub fn bind_local_functions(&mut self) -> Result<(), String> {
+        type_inference::bind_local_functions(self)
+    }
+
+    pub fn infer_local_function_calls(&mut self) {
+        type_inference::infer_local_function_calls(self);
+    }
+
+    pub fn imports(&self) -> Vec<RibImport> {
+        rib_module::imports(self)
+    }
+
+    pub fn imports_mut(&mut self) -> Vec<&mut RibImport> {
+        rib_module::imports_mut(self)
+    }
+
+    // Imports of Rib modules are replaced by the definitions of these modules,
+    // which makes the Rib script self-contained before it is compiled
+    pub fn resolve_imports(&mut self, modules: &[RibModule]) -> Result<(), String> {
+        rib_module::resolve_imports(self, modules)
+    }
+
     pub fn infer_call_arguments_type(
         &mut self,
         function_type_registry: &FunctionTypeRegistry,
@@ -681,6 +766,9 @@ impl Expr {
             | Expr::Or(_, _, inferred_type)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Function { inferred_type, .. }
+            | Expr::Import(_, inferred_type)
             | Expr::Call(_, _, inferred_type) => {
                 if new_inferred_type != InferredType::Unknown {
                     *inferred_type = inferred_type.merge(new_inferred_type);
@@ -729,6 +817,9 @@ impl Expr {
             | Expr::GetTag(_, inferred_type)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Function { inferred_type, .. }
+            | Expr::Import(_, inferred_type)
             | Expr::Call(_, _, inferred_type) => {
                 if new_inferred_type != InferredType::Unknown {
                     *inferred_type = new_inferred_type;
@@ -745,6 +836,10 @@ impl Expr {
         type_inference::infer_variants(self, function_type_registry);
     }
 
+    pub fn infer_builtin_functions(&mut self, function_type_registry: &FunctionTypeRegistry) {
+        type_inference::infer_builtin_functions(self, function_type_registry);
+    }
+
     pub fn visit_children_bottom_up<'a>(&'a self, queue: &mut VecDeque<&'a Expr>) {
         type_inference::visit_children_bottom_up(self, queue);
     }
@@ -1239,6 +1334,36 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                     yield_expr,
                 )
             }
+            golem_api_grpc::proto::golem::rib::expr::Expr::Lambda(lambda) => {
+                let body = lambda.body.ok_or("Missing lambda body")?;
+                let body = (*body).try_into()?;
+                let parameter = VariableId::list_comprehension_identifier(lambda.parameter);
+                Expr::lambda(parameter, body)
+            }
+            golem_api_grpc::proto::golem::rib::expr::Expr::Function(function) => {
+                let parameters = function
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| {
+                        let type_name = parameter
+                            .type_name
+                            .ok_or("Missing type of function parameter")?;
+                        Ok((
+                            VariableId::list_comprehension_identifier(parameter.name),
+                            TypeName::try_from(type_name)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                let return_type = function.return_type.map(TypeName::try_from).transpose()?;
+                let body = function.body.ok_or("Missing function body")?;
+                Expr::function(parameters, return_type, (*body).try_into()?)
+            }
+            golem_api_grpc::proto::golem::rib::expr::Expr::Import(
+                golem_api_grpc::proto::golem::rib::ImportExpr {
+                    module_name,
+                    version,
+                },
+            ) => Expr::import(module_name, version),
             golem_api_grpc::proto::golem::rib::expr::Expr::Call(expr) => {
                 let params: Vec<Expr> = expr
                     .params
@@ -1279,6 +1404,9 @@ impl TryFrom<golem_api_grpc::proto::golem::rib::Expr> for Expr {
                             golem_api_grpc::proto::golem::rib::call_type::Name::EnumConstructor(
                                 name,
                             ) => Expr::call(DynamicParsedFunctionName::parse(name)?, params),
+                            golem_api_grpc::proto::golem::rib::call_type::Name::Builtin(
+                                name,
+                            ) => Expr::call(DynamicParsedFunctionName::parse(name)?, params),
                         }
                     }
                     (_, _) => Err("Missing both call type (and legacy invocation type)")?,
@@ -1561,6 +1689,43 @@ impl From<Expr> for golem_api_grpc::proto::golem::rib::Expr {
                     yield_expr: Some(Box::new((*yield_expr).into())),
                 }),
             )),
+
+            Expr::Lambda {
+                parameter, body, ..
+            } => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Lambda(
+                Box::new(golem_api_grpc::proto::golem::rib::LambdaExpr {
+                    parameter: parameter.name(),
+                    body: Some(Box::new((*body).into())),
+                }),
+            )),
+
+            Expr::Function {
+                parameters,
+                return_type,
+                body,
+                ..
+            } => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Function(
+                Box::new(golem_api_grpc::proto::golem::rib::FunctionExpr {
+                    parameters: parameters
+                        .into_iter()
+                        .map(|(variable_id, type_name)| {
+                            golem_api_grpc::proto::golem::rib::FunctionParameter {
+                                name: variable_id.name(),
+                                type_name: Some(type_name.into()),
+                            }
+                        })
+                        .collect(),
+                    return_type: return_type.map(|t| t.into()),
+                    body: Some(Box::new((*body).into())),
+                }),
+            )),
+
+            Expr::Import(import, _) => Some(golem_api_grpc::proto::golem::rib::expr::Expr::Import(
+                golem_api_grpc::proto::golem::rib::ImportExpr {
+                    module_name: import.module_name,
+                    version: import.version,
+                },
+            )),
         };
 
         golem_api_grpc::proto::golem::rib::Expr { expr }
@@ -953,6 +953,17 @@ impl DynamicParsedFunctionName {
         self.function.raw_resource_params_mut()
     }
 
+    // A function without an interface, that has the name of a Rib builtin function.
+    // Whether it really is the builtin depends on the functions exported by the component.
+    pub fn builtin_function(&self) -> Option<BuiltinFunction> {
+        match (&self.site, &self.function) {
+            (ParsedFunctionSite::Global, DynamicParsedFunctionReference::Function { function }) => {
+                BuiltinFunction::from_name(function)
+            }
+            _ => None,
+        }
+    }
+
     //
     pub fn to_parsed_function_name(&self) -> ParsedFunctionName {
         ParsedFunctionName {
@@ -969,6 +980,128 @@ impl Display for DynamicParsedFunctionName {
     }
 }
 
+// Functions of the Rib standard library, such as `to-upper(x)` or `len(xs)`.
+// They are called like worker functions without an interface, but evaluated by the interpreter.
+// A function exported by the component with the same name always takes precedence,
+// which is why a call is resolved to a builtin only during type inference.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Encode, Decode)]
+pub enum BuiltinFunction {
+    Len,
+    Split,
+    Trim,
+    ToUpper,
+    ToLower,
+    Contains,
+    StartsWith,
+    EndsWith,
+    Head,
+    Filter,
+    Map,
+    Sort,
+    Join,
+    Min,
+    Max,
+    Abs,
+    ParseU64,
+    ParseS64,
+    ParseF64,
+    ToString,
+}
+
+impl BuiltinFunction {
+    pub fn all() -> Vec<BuiltinFunction> {
+        vec![
+            BuiltinFunction::Len,
+            BuiltinFunction::Split,
+            BuiltinFunction::Trim,
+            BuiltinFunction::ToUpper,
+            BuiltinFunction::ToLower,
+            BuiltinFunction::Contains,
+            BuiltinFunction::StartsWith,
+            BuiltinFunction::EndsWith,
+            BuiltinFunction::Head,
+            BuiltinFunction::Filter,
+            BuiltinFunction::Map,
+            BuiltinFunction::Sort,
+            BuiltinFunction::Join,
+            BuiltinFunction::Min,
+            BuiltinFunction::Max,
+            BuiltinFunction::Abs,
+            BuiltinFunction::ParseU64,
+            BuiltinFunction::ParseS64,
+            BuiltinFunction::ParseF64,
+            BuiltinFunction::ToString,
+        ]
+    }
+
+    pub fn from_name(name: &str) -> Option<BuiltinFunction> {
+        BuiltinFunction::all()
+            .into_iter()
+            .find(|function| function.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltinFunction::Len => "len",
+            BuiltinFunction::Split => "split",
+            BuiltinFunction::Trim => "trim",
+            BuiltinFunction::ToUpper => "to-upper",
+            BuiltinFunction::ToLower => "to-lower",
+            BuiltinFunction::Contains => "contains",
+            BuiltinFunction::StartsWith => "starts-with",
+            BuiltinFunction::EndsWith => "ends-with",
+            BuiltinFunction::Head => "head",
+            BuiltinFunction::Filter => "filter",
+            BuiltinFunction::Map => "map",
+            BuiltinFunction::Sort => "sort",
+            BuiltinFunction::Join => "join",
+            BuiltinFunction::Min => "min",
+            BuiltinFunction::Max => "max",
+            BuiltinFunction::Abs => "abs",
+            BuiltinFunction::ParseU64 => "parse-u64",
+            BuiltinFunction::ParseS64 => "parse-s64",
+            BuiltinFunction::ParseF64 => "parse-f64",
+            BuiltinFunction::ToString => "to-string",
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        match self {
+            BuiltinFunction::Len
+            | BuiltinFunction::Trim
+            | BuiltinFunction::ToUpper
+            | BuiltinFunction::ToLower
+            | BuiltinFunction::Head
+            | BuiltinFunction::Sort
+            | BuiltinFunction::Abs
+            | BuiltinFunction::ParseU64
+            | BuiltinFunction::ParseS64
+            | BuiltinFunction::ParseF64
+            | BuiltinFunction::ToString => 1,
+            BuiltinFunction::Split
+            | BuiltinFunction::Contains
+            | BuiltinFunction::StartsWith
+            | BuiltinFunction::EndsWith
+            | BuiltinFunction::Filter
+            | BuiltinFunction::Map
+            | BuiltinFunction::Join
+            | BuiltinFunction::Min
+            | BuiltinFunction::Max => 2,
+        }
+    }
+
+    // Whether the last argument of the function is a lambda (`|x| expr`)
+    pub fn takes_lambda(&self) -> bool {
+        matches!(self, BuiltinFunction::Filter | BuiltinFunction::Map)
+    }
+}
+
+impl Display for BuiltinFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 impl Serialize for ParsedFunctionName {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let function_name = self.to_string();
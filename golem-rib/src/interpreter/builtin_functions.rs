@@ -0,0 +1,285 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interpreter::literal::{CoercedNumericValue, GetLiteralValue};
+use crate::interpreter::stack::InterpreterStack;
+use crate::BuiltinFunction;
+use golem_wasm_ast::analysis::analysed_type::str;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+use golem_wasm_rpc::protobuf::TypedList;
+use std::cmp::Ordering;
+
+// Builtin functions are pure, and are evaluated directly on the arguments in the stack.
+// `filter` and `map` are never invoked, as they are compiled to loops.
+pub(crate) fn run_builtin_function(
+    function: &BuiltinFunction,
+    result_type: &AnalysedType,
+    interpreter_stack: &mut InterpreterStack,
+) -> Result<(), String> {
+    // Arguments are pushed in order, and are therefore popped in reverse order
+    let mut args = interpreter_stack.try_pop_n_val(function.arity())?;
+    args.reverse();
+
+    let result = match (function, args.as_slice()) {
+        (BuiltinFunction::Len, [value]) => match value {
+            TypeAnnotatedValue::Str(value) => TypeAnnotatedValue::U64(value.chars().count() as u64),
+            TypeAnnotatedValue::List(list) => TypeAnnotatedValue::U64(list.values.len() as u64),
+            _ => return Err(internal::invalid_argument(function, value)),
+        },
+
+        (BuiltinFunction::Split, [value, separator]) => {
+            let value = internal::get_str(function, value)?;
+            let separator = internal::get_str(function, separator)?;
+
+            internal::list(
+                value
+                    .split(separator)
+                    .map(|part| TypeAnnotatedValue::Str(part.to_string()))
+                    .collect(),
+                &str(),
+            )
+        }
+
+        (BuiltinFunction::Trim, [value]) => {
+            TypeAnnotatedValue::Str(internal::get_str(function, value)?.trim().to_string())
+        }
+
+        (BuiltinFunction::ToUpper, [value]) => {
+            TypeAnnotatedValue::Str(internal::get_str(function, value)?.to_uppercase())
+        }
+
+        (BuiltinFunction::ToLower, [value]) => {
+            TypeAnnotatedValue::Str(internal::get_str(function, value)?.to_lowercase())
+        }
+
+        (BuiltinFunction::Contains, [value, pattern]) => TypeAnnotatedValue::Bool(
+            internal::get_str(function, value)?.contains(internal::get_str(function, pattern)?),
+        ),
+
+        (BuiltinFunction::StartsWith, [value, prefix]) => TypeAnnotatedValue::Bool(
+            internal::get_str(function, value)?.starts_with(internal::get_str(function, prefix)?),
+        ),
+
+        (BuiltinFunction::EndsWith, [value, suffix]) => TypeAnnotatedValue::Bool(
+            internal::get_str(function, value)?.ends_with(internal::get_str(function, suffix)?),
+        ),
+
+        (BuiltinFunction::Head, [list]) => {
+            let values = internal::get_list_values(function, list)?;
+            internal::option(values.into_iter().next(), result_type)?
+        }
+
+        (BuiltinFunction::Sort, [list]) => {
+            let TypeAnnotatedValue::List(typed_list) = list else {
+                return Err(internal::invalid_argument(function, list));
+            };
+
+            let mut values = internal::get_list_values(function, list)?
+                .into_iter()
+                .map(|value| {
+                    value
+                        .get_literal()
+                        .map(|literal| (literal, value.clone()))
+                        .ok_or(internal::invalid_argument(function, &value))
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+            values.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+            TypeAnnotatedValue::List(TypedList {
+                values: values
+                    .into_iter()
+                    .map(|(_, value)| golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                        type_annotated_value: Some(value),
+                    })
+                    .collect(),
+                typ: typed_list.typ.clone(),
+            })
+        }
+
+        (BuiltinFunction::Join, [list, separator]) => {
+            let separator = internal::get_str(function, separator)?;
+
+            let values = internal::get_list_values(function, list)?
+                .iter()
+                .map(|value| internal::get_str(function, value).map(|s| s.to_string()))
+                .collect::<Result<Vec<_>, String>>()?;
+
+            TypeAnnotatedValue::Str(values.join(separator))
+        }
+
+        (BuiltinFunction::Min | BuiltinFunction::Max, [left, right]) => {
+            let left_number = internal::get_number(function, left)?;
+            let right_number = internal::get_number(function, right)?;
+
+            let pick_left = match function {
+                BuiltinFunction::Min => left_number <= right_number,
+                _ => left_number >= right_number,
+            };
+
+            if pick_left {
+                left.clone()
+            } else {
+                right.clone()
+            }
+        }
+
+        (BuiltinFunction::Abs, [value]) => {
+            let absolute = match internal::get_number(function, value)? {
+                CoercedNumericValue::PosInt(value) => CoercedNumericValue::PosInt(value),
+                CoercedNumericValue::NegInt(value) => CoercedNumericValue::NegInt(
+                    value
+                        .checked_abs()
+                        .ok_or(format!("Overflow in `{}` of {}", function, value))?,
+                ),
+                CoercedNumericValue::Float(value) => CoercedNumericValue::Float(value.abs()),
+            };
+
+            absolute.cast_to(result_type).ok_or(format!(
+                "Failed to convert the result of `{}` to {:?}",
+                function, result_type
+            ))?
+        }
+
+        (BuiltinFunction::ParseU64, [value]) => {
+            let parsed = internal::get_str(function, value)?.trim().parse::<u64>();
+            internal::option(parsed.ok().map(TypeAnnotatedValue::U64), result_type)?
+        }
+
+        (BuiltinFunction::ParseS64, [value]) => {
+            let parsed = internal::get_str(function, value)?.trim().parse::<i64>();
+            internal::option(parsed.ok().map(TypeAnnotatedValue::S64), result_type)?
+        }
+
+        (BuiltinFunction::ParseF64, [value]) => {
+            let parsed = internal::get_str(function, value)?.trim().parse::<f64>();
+            internal::option(parsed.ok().map(TypeAnnotatedValue::F64), result_type)?
+        }
+
+        (BuiltinFunction::ToString, [value]) => match value {
+            TypeAnnotatedValue::Str(_) => value.clone(),
+            _ => TypeAnnotatedValue::Str(
+                value
+                    .get_literal()
+                    .ok_or(internal::invalid_argument(function, value))?
+                    .as_string(),
+            ),
+        },
+
+        (function, _) => {
+            return Err(format!(
+                "Internal Error: Unable to evaluate `{}` with {} arguments",
+                function,
+                args.len()
+            ))
+        }
+    };
+
+    interpreter_stack.push_val(result);
+
+    Ok(())
+}
+
+mod internal {
+    use crate::interpreter::literal::{CoercedNumericValue, GetLiteralValue};
+    use crate::BuiltinFunction;
+    use golem_wasm_ast::analysis::AnalysedType;
+    use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+    use golem_wasm_rpc::protobuf::{TypedList, TypedOption};
+
+    pub(crate) fn invalid_argument(
+        function: &BuiltinFunction,
+        value: &TypeAnnotatedValue,
+    ) -> String {
+        format!("Invalid argument to `{}`: {:?}", function, value)
+    }
+
+    pub(crate) fn get_str<'a>(
+        function: &BuiltinFunction,
+        value: &'a TypeAnnotatedValue,
+    ) -> Result<&'a str, String> {
+        match value {
+            TypeAnnotatedValue::Str(value) => Ok(value.as_str()),
+            _ => Err(invalid_argument(function, value)),
+        }
+    }
+
+    pub(crate) fn get_number(
+        function: &BuiltinFunction,
+        value: &TypeAnnotatedValue,
+    ) -> Result<CoercedNumericValue, String> {
+        value
+            .get_literal()
+            .and_then(|literal| literal.get_number())
+            .ok_or(invalid_argument(function, value))
+    }
+
+    pub(crate) fn get_list_values(
+        function: &BuiltinFunction,
+        value: &TypeAnnotatedValue,
+    ) -> Result<Vec<TypeAnnotatedValue>, String> {
+        match value {
+            TypeAnnotatedValue::List(typed_list) => typed_list
+                .values
+                .iter()
+                .map(|value| {
+                    value
+                        .type_annotated_value
+                        .clone()
+                        .ok_or(format!("Invalid list element in `{}`", function))
+                })
+                .collect(),
+            _ => Err(invalid_argument(function, value)),
+        }
+    }
+
+    pub(crate) fn list(
+        values: Vec<TypeAnnotatedValue>,
+        elem_type: &AnalysedType,
+    ) -> TypeAnnotatedValue {
+        TypeAnnotatedValue::List(TypedList {
+            values: values
+                .into_iter()
+                .map(|value| golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                    type_annotated_value: Some(value),
+                })
+                .collect(),
+            typ: Some(golem_wasm_ast::analysis::protobuf::Type::from(elem_type)),
+        })
+    }
+
+    pub(crate) fn option(
+        value: Option<TypeAnnotatedValue>,
+        option_type: &AnalysedType,
+    ) -> Result<TypeAnnotatedValue, String> {
+        let AnalysedType::Option(type_option) = option_type else {
+            return Err(format!(
+                "Internal Error: Expected option type. But obtained {:?}",
+                option_type
+            ));
+        };
+
+        Ok(TypeAnnotatedValue::Option(Box::new(TypedOption {
+            typ: Some(golem_wasm_ast::analysis::protobuf::Type::from(
+                type_option.inner.as_ref(),
+            )),
+            value: value.map(|value| {
+                Box::new(golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                    type_annotated_value: Some(value),
+                })
+            }),
+        })))
+    }
+}
@@ -24,6 +24,16 @@ use std::sync::Arc;
 pub struct InterpreterEnv {
     pub env: HashMap<EnvironmentKey, RibInterpreterStackValue>,
     pub call_worker_function_async: RibFunctionInvoke,
+    call_frames: Vec<CallFrame>,
+}
+
+// A call to a function defined in the Rib script.
+// Variables assigned during the call (parameters, and the variables in the body)
+// are restored to their values before the call, when the function returns,
+// which allows a function to call itself.
+struct CallFrame {
+    return_position: usize,
+    shadowed: HashMap<EnvironmentKey, Option<RibInterpreterStackValue>>,
 }
 
 impl Debug for InterpreterEnv {
@@ -48,6 +58,7 @@ impl Default for InterpreterEnv {
         InterpreterEnv {
             env: HashMap::new(),
             call_worker_function_async: internal::default_worker_invoke_async(),
+            call_frames: vec![],
         }
     }
 }
@@ -77,6 +88,7 @@ impl InterpreterEnv {
         InterpreterEnv {
             env,
             call_worker_function_async: internal::default_worker_invoke_async(),
+            call_frames: vec![],
         }
     }
 
@@ -87,7 +99,40 @@ impl InterpreterEnv {
     }
 
     pub fn insert(&mut self, key: EnvironmentKey, value: RibInterpreterStackValue) {
-        self.env.insert(key, value);
+        let previous = self.env.insert(key.clone(), value);
+
+        if let Some(call_frame) = self.call_frames.last_mut() {
+            call_frame.shadowed.entry(key).or_insert(previous);
+        }
+    }
+
+    pub fn call_depth(&self) -> usize {
+        self.call_frames.len()
+    }
+
+    pub fn push_call_frame(&mut self, return_position: usize) {
+        self.call_frames.push(CallFrame {
+            return_position,
+            shadowed: HashMap::new(),
+        });
+    }
+
+    // Returns the position to continue from after the call
+    pub fn pop_call_frame(&mut self) -> Option<usize> {
+        let call_frame = self.call_frames.pop()?;
+
+        for (key, previous) in call_frame.shadowed {
+            match previous {
+                Some(value) => {
+                    self.env.insert(key, value);
+                }
+                None => {
+                    self.env.remove(&key);
+                }
+            }
+        }
+
+        Some(call_frame.return_position)
     }
 
     pub fn lookup(&self, key: &EnvironmentKey) -> Option<&RibInterpreterStackValue> {
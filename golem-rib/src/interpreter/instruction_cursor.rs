@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{InstructionId, RibByteCode, RibIR};
+use crate::{InstructionId, RibByteCode, RibIR, VariableId};
 
 pub struct RibByteCodeCursor {
     byte_code: RibByteCode,
@@ -49,4 +49,25 @@ impl RibByteCodeCursor {
 
         None
     }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn move_to_position(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    pub fn move_to_function(&mut self, function: &VariableId) -> Option<()> {
+        let index = self
+            .byte_code
+            .instructions
+            .iter()
+            .position(|instruction| {
+                matches!(instruction, RibIR::FunctionStart(variable_id) if variable_id == function)
+            })?;
+
+        self.position = index + 1;
+        Some(())
+    }
 }
@@ -16,10 +16,11 @@ pub use env::RibFunctionInvoke;
 pub use interpreter_input::*;
 pub use interpreter_result::*;
 pub use literal::*;
+pub use rib_interpreter::{Interpreter, DEFAULT_MAX_CALL_DEPTH};
 
-use crate::interpreter::rib_interpreter::Interpreter;
 use crate::RibByteCode;
 
+mod builtin_functions;
 mod env;
 mod instruction_cursor;
 mod interpreter_input;
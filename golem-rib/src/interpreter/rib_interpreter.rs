@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::interpreter::builtin_functions::run_builtin_function;
 use crate::interpreter::env::{InterpreterEnv, RibFunctionInvoke};
 use crate::interpreter::instruction_cursor::RibByteCodeCursor;
 use crate::interpreter::stack::InterpreterStack;
 use crate::{RibByteCode, RibIR, RibInput, RibResult};
 
+// The maximum number of nested calls to the functions defined in a Rib script
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1000;
+
 pub struct Interpreter {
     pub input: RibInput,
     pub invoke: RibFunctionInvoke,
+    pub max_call_depth: usize,
 }
 
 impl Default for Interpreter {
@@ -27,6 +32,7 @@ impl Default for Interpreter {
         Interpreter {
             input: RibInput::default(),
             invoke: internal::default_worker_invoke_async(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
         }
     }
 }
@@ -36,6 +42,7 @@ impl Interpreter {
         Interpreter {
             input: input.clone(),
             invoke,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
         }
     }
 
@@ -45,9 +52,15 @@ impl Interpreter {
         Interpreter {
             input: input.clone(),
             invoke: internal::default_worker_invoke_async(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
         }
     }
 
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+
     pub async fn run(&mut self, instructions0: RibByteCode) -> Result<RibResult, String> {
         let mut byte_code_cursor = RibByteCodeCursor::from_rib_byte_code(instructions0);
         let mut stack = InterpreterStack::new();
@@ -172,6 +185,30 @@ impl Interpreter {
                         .await?;
                 }
 
+                RibIR::InvokeBuiltin(function, analysed_type) => {
+                    run_builtin_function(&function, &analysed_type, &mut stack)?;
+                }
+
+                RibIR::CallLocalFunction(function) => {
+                    internal::run_call_local_function_instruction(
+                        function,
+                        self.max_call_depth,
+                        &mut byte_code_cursor,
+                        &mut interpreter_env,
+                    )?;
+                }
+
+                RibIR::Return => {
+                    let return_position = interpreter_env
+                        .pop_call_frame()
+                        .ok_or("Internal error. Return outside a function".to_string())?;
+
+                    byte_code_cursor.move_to_position(return_position);
+                }
+
+                // Reached only by a call, as the body of the function is otherwise skipped
+                RibIR::FunctionStart(_) => {}
+
                 RibIR::PushVariant(variant_name, analysed_type) => {
                     internal::run_variant_construction_instruction(
                         variant_name,
@@ -341,6 +378,28 @@ mod internal {
         Ok(())
     }
 
+    pub(crate) fn run_call_local_function_instruction(
+        function: VariableId,
+        max_call_depth: usize,
+        instruction_stack: &mut RibByteCodeCursor,
+        interpreter_env: &mut InterpreterEnv,
+    ) -> Result<(), String> {
+        if interpreter_env.call_depth() >= max_call_depth {
+            return Err(format!(
+                "Maximum call stack depth of {} exceeded when calling `{}`",
+                max_call_depth, function
+            ));
+        }
+
+        // The arguments are already in the stack, and are assigned to the parameters within the function
+        interpreter_env.push_call_frame(instruction_stack.position());
+
+        instruction_stack.move_to_function(&function).ok_or(format!(
+            "Internal Error: Failed to find the definition of function `{}`",
+            function
+        ))
+    }
+
     pub(crate) fn run_jump_if_false_instruction(
         instruction_id: InstructionId,
         instruction_stack: &mut RibByteCodeCursor,
@@ -1542,6 +1601,270 @@ mod interpreter_tests {
         }
     }
 
+    mod builtin_function_interpreter_tests {
+        use crate::interpreter::rib_interpreter::Interpreter;
+        use crate::{compiler, Expr};
+        use golem_wasm_ast::analysis::analysed_type::{list, option, str, u64};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+        use test_r::test;
+
+        #[test]
+        async fn test_string_functions() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let name = "  Foo  ";
+          to-upper(trim(name))
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            assert_eq!(result, TypeAnnotatedValue::Str("FOO".to_string()));
+        }
+
+        #[test]
+        async fn test_split_and_join() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let path = "a,b,c";
+          join(split(path, ","), "/")
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            assert_eq!(result, TypeAnnotatedValue::Str("a/b/c".to_string()));
+        }
+
+        #[test]
+        async fn test_len() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let x = ["foo", "bar"];
+          len(x)
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            assert_eq!(result, TypeAnnotatedValue::U64(2));
+        }
+
+        #[test]
+        async fn test_filter_and_map() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let x = ["apple", "banana", "avocado"];
+          let y = filter(x, |fruit| starts-with(fruit, "a"));
+          map(y, |fruit| to-upper(fruit))
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            let expected = r#"["APPLE", "AVOCADO"]"#;
+            let expected_type_annotated_value =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(str()), expected).unwrap();
+
+            assert_eq!(result, expected_type_annotated_value);
+        }
+
+        #[test]
+        async fn test_parse_u64() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let x = "12";
+          parse-u64(x)
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            let expected_type_annotated_value =
+                golem_wasm_rpc::type_annotated_value_from_str(&option(u64()), "some(12)").unwrap();
+
+            assert_eq!(result, expected_type_annotated_value);
+        }
+    }
+
+    mod local_function_interpreter_tests {
+        use crate::interpreter::rib_interpreter::Interpreter;
+        use crate::{compiler, Expr};
+        use golem_wasm_ast::analysis::analysed_type::{list, str};
+        use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
+        use test_r::test;
+
+        #[test]
+        async fn test_function_call() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let add = fn(x: u64, y: u64) -> u64 { x + y };
+          add(1, 2)
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            assert_eq!(result, TypeAnnotatedValue::U64(3));
+        }
+
+        #[test]
+        async fn test_recursive_function_call() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let factorial = fn(n: u64) -> u64 { if n == 0 then 1 else n * factorial(n - 1) };
+          factorial(5)
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            assert_eq!(result, TypeAnnotatedValue::U64(120));
+        }
+
+        #[test]
+        async fn test_closure_in_list_comprehension() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let greeting = "hello";
+          let greet = fn(name: str) -> str { "${greeting} ${name}" };
+          let names = ["foo", "bar"];
+          for name in names {
+            yield greet(name);
+          }
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            let expected = r#"["hello foo", "hello bar"]"#;
+            let expected_type_annotated_value =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(str()), expected).unwrap();
+
+            assert_eq!(result, expected_type_annotated_value);
+        }
+
+        #[test]
+        async fn test_anonymous_function() {
+            let mut interpreter = Interpreter::default();
+
+            let rib_expr = r#"
+          let x = ["foo", "bar"];
+          map(x, fn(s: str) -> str { to-upper(s) })
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter
+                .run(compiled.byte_code)
+                .await
+                .unwrap()
+                .get_val()
+                .unwrap();
+
+            let expected = r#"["FOO", "BAR"]"#;
+            let expected_type_annotated_value =
+                golem_wasm_rpc::type_annotated_value_from_str(&list(str()), expected).unwrap();
+
+            assert_eq!(result, expected_type_annotated_value);
+        }
+
+        #[test]
+        async fn test_max_call_depth() {
+            let mut interpreter = Interpreter::default().with_max_call_depth(10);
+
+            let rib_expr = r#"
+          let forever = fn(n: u64) -> u64 { forever(n + 1) };
+          forever(0)
+          "#;
+
+            let expr = Expr::from_text(rib_expr).unwrap();
+
+            let compiled = compiler::compile(&expr, &vec![]).unwrap();
+
+            let result = interpreter.run(compiled.byte_code).await;
+
+            assert_eq!(
+                result.map(|_| ()),
+                Err("Maximum call stack depth of 10 exceeded when calling `forever`".to_string())
+            );
+        }
+    }
+
     mod pattern_match_interpreter_tests {
         use test_r::test;
 
@@ -18,6 +18,7 @@ pub use function_name::*;
 pub use inferred_type::*;
 pub use interpreter::*;
 pub use parser::type_name::TypeName;
+pub use rib_module::*;
 pub use text::*;
 pub use type_inference::*;
 pub use type_registry::*;
@@ -30,6 +31,7 @@ mod function_name;
 mod inferred_type;
 mod interpreter;
 mod parser;
+mod rib_module;
 mod text;
 mod type_checker;
 mod type_inference;
@@ -0,0 +1,131 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::parser::char::{alpha_num, char, spaces, string};
+use combine::{attempt, not_followed_by, optional, sep_by, ParseError, Parser};
+
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+use crate::parser::multi_line_code_block::multi_line_block;
+use crate::parser::type_name::{parse_type_name, TypeName};
+use crate::VariableId;
+
+// A function such as `fn(x: u64, y: u64) -> u64 { x + y }`.
+// The types of the parameters are mandatory, while the return type can be inferred
+pub fn function<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    attempt(
+        string("fn")
+            .skip(not_followed_by(alpha_num().or(char('-')).or(char('_'))))
+            .skip(spaces())
+            .skip(char('(')),
+    )
+    .with(
+        (
+            spaces().with(sep_by(parameter(), char(',').skip(spaces()))),
+            char(')').skip(spaces()),
+            optional(
+                string("->")
+                    .skip(spaces())
+                    .with(parse_type_name())
+                    .skip(spaces()),
+            ),
+            multi_line_block(),
+        )
+            .map(
+                |(parameters, _, return_type, body): (Vec<(VariableId, TypeName)>, _, _, _)| {
+                    Expr::function(parameters, return_type, body)
+                },
+            ),
+    )
+    .message("Unable to parse function")
+}
+
+fn parameter<Input>() -> impl Parser<Input, Output = (VariableId, TypeName)>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    (
+        identifier_text()
+            .skip(spaces())
+            .map(VariableId::list_comprehension_identifier),
+        char(':').skip(spaces()),
+        parse_type_name().skip(spaces()),
+    )
+        .map(|(variable_id, _, type_name)| (variable_id, type_name))
+        .message("Unable to parse function parameter")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use super::*;
+    use crate::parser::rib_expr::rib_expr;
+    use crate::DynamicParsedFunctionName;
+
+    #[test]
+    fn test_function() {
+        let input = "fn(x: u64, y: str) -> u64 { x }";
+        let result = rib_expr().easy_parse(input);
+        let x = VariableId::list_comprehension_identifier("x");
+        let y = VariableId::list_comprehension_identifier("y");
+        assert_eq!(
+            result,
+            Ok((
+                Expr::function(
+                    vec![(x, TypeName::U64), (y, TypeName::Str)],
+                    Some(TypeName::U64),
+                    Expr::identifier("x")
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_function_without_return_type() {
+        let input = "let f = fn(x: u64) { x + 1 }; f(2)";
+        let result = Expr::from_text(input);
+        let x = VariableId::list_comprehension_identifier("x");
+        assert_eq!(
+            result,
+            Ok(Expr::expr_block(vec![
+                Expr::let_binding(
+                    "f",
+                    Expr::function(
+                        vec![(x, TypeName::U64)],
+                        None,
+                        Expr::plus(Expr::identifier("x"), Expr::untyped_number(1f64))
+                    )
+                ),
+                Expr::call(
+                    DynamicParsedFunctionName::parse("f").unwrap(),
+                    vec![Expr::untyped_number(2f64)]
+                )
+            ]))
+        );
+    }
+}
@@ -21,6 +21,7 @@ use crate::parser::errors::RibParseError;
 
 const RESERVED_KEYWORDS: &[&str] = &[
     "if", "then", "else", "match", "ok", "some", "err", "none", "let", "for", "yield", "reduce",
+    "fn", "import",
 ];
 
 pub fn identifier<Input>() -> impl Parser<Input, Output = Expr>
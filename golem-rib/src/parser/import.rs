@@ -0,0 +1,89 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::parser::char::{alpha_num, char, digit, spaces, string};
+use combine::{attempt, many1, not_followed_by, optional, ParseError, Parser};
+
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+
+// An import of a Rib module such as `import helpers;` or `import helpers@1;`.
+// Without a version, the latest version of the module is imported
+pub fn import<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    attempt(
+        string("import")
+            .skip(not_followed_by(alpha_num().or(char('-')).or(char('_'))))
+            .skip(spaces()),
+    )
+    .with(
+        (
+            identifier_text().skip(spaces()),
+            optional(char('@').skip(spaces()).with(many1(digit()))).skip(spaces()),
+        )
+            .and_then(|(module_name, version): (String, Option<String>)| {
+                match version.map(|version| version.parse::<u64>()).transpose() {
+                    Ok(version) => Ok(Expr::import(module_name, version)),
+                    Err(_) => Err(RibParseError::Message(
+                        "Invalid version of the imported module".to_string(),
+                    )),
+                }
+            }),
+    )
+    .message("Unable to parse import")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use super::*;
+    use crate::parser::rib_expr::rib_expr;
+
+    #[test]
+    fn test_import() {
+        let input = "import helpers";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(result, Ok((Expr::import("helpers", None), "")));
+    }
+
+    #[test]
+    fn test_import_with_version() {
+        let input = "import string-helpers@2";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(result, Ok((Expr::import("string-helpers", Some(2)), "")));
+    }
+
+    #[test]
+    fn test_import_in_rib_program() {
+        let input = "import helpers@1; let x = 1; x";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::expr_block(vec![
+                Expr::import("helpers", Some(1)),
+                Expr::let_binding("x", Expr::untyped_number(1f64)),
+                Expr::identifier("x")
+            ]))
+        );
+    }
+}
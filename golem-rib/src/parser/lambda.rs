@@ -0,0 +1,96 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use combine::parser::char::{char, spaces};
+use combine::{ParseError, Parser};
+
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::identifier::identifier_text;
+use crate::parser::rib_expr::rib_expr;
+use crate::VariableId;
+
+// A lambda such as `|x| x > 1`, passed to builtin functions such as `filter` and `map`
+pub fn lambda<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+{
+    spaces()
+        .with(
+            (
+                char('|').skip(spaces()),
+                identifier_text()
+                    .skip(spaces())
+                    .map(VariableId::list_comprehension_identifier),
+                char('|').skip(spaces()),
+                rib_expr(),
+            )
+                .map(|(_, parameter, _, body)| Expr::lambda(parameter, body)),
+        )
+        .message("Unable to parse lambda")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use combine::EasyParser;
+
+    use super::*;
+    use crate::DynamicParsedFunctionName;
+
+    #[test]
+    fn test_lambda() {
+        let input = "|x| x > 1";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::lambda(
+                    VariableId::list_comprehension_identifier("x"),
+                    Expr::greater_than(Expr::identifier("x"), Expr::untyped_number(1f64))
+                ),
+                ""
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lambda_as_function_argument() {
+        let input = "filter(xs, |x| starts-with(x, \"a\"))";
+        let result = rib_expr().easy_parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Expr::call(
+                    DynamicParsedFunctionName::parse("filter").unwrap(),
+                    vec![
+                        Expr::identifier("xs"),
+                        Expr::lambda(
+                            VariableId::list_comprehension_identifier("x"),
+                            Expr::call(
+                                DynamicParsedFunctionName::parse("starts-with").unwrap(),
+                                vec![Expr::identifier("x"), Expr::literal("a")]
+                            )
+                        )
+                    ]
+                ),
+                ""
+            ))
+        );
+    }
+}
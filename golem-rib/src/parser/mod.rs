@@ -18,7 +18,10 @@ pub(crate) mod call;
 mod cond;
 mod errors;
 mod flag;
+mod function;
 mod identifier;
+mod import;
+mod lambda;
 mod let_binding;
 mod list_aggregation;
 mod list_comprehension;
@@ -89,7 +89,10 @@ mod internal {
     use crate::parser::cond::conditional;
     use crate::parser::errors::RibParseError;
     use crate::parser::flag::flag;
+    use crate::parser::function::function;
     use crate::parser::identifier::identifier;
+    use crate::parser::import::import;
+    use crate::parser::lambda::lambda;
     use crate::parser::let_binding::let_binding;
     use crate::parser::literal::literal;
     use crate::parser::multi_line_code_block::multi_line_block;
@@ -128,6 +131,9 @@ mod internal {
                 selection_expr(),
                 flag_or_record(),
                 multi_line_block(),
+                lambda(),
+                function(),
+                import(),
                 tuple(),
                 sequence(),
                 boolean_literal(),
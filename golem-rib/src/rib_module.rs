@@ -0,0 +1,295 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Expr;
+use bincode::{Decode, Encode};
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+// An import of a Rib module, such as `import helpers@1;`.
+// An import without a version refers to the latest version of the module
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct RibImport {
+    pub module_name: String,
+    pub version: Option<u64>,
+}
+
+impl RibImport {
+    pub fn new(module_name: impl AsRef<str>, version: Option<u64>) -> Self {
+        RibImport {
+            module_name: module_name.as_ref().to_string(),
+            version,
+        }
+    }
+}
+
+impl Display for RibImport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.version {
+            Some(version) => write!(f, "{}@{}", self.module_name, version),
+            None => write!(f, "{}", self.module_name),
+        }
+    }
+}
+
+// A Rib module is a named and versioned list of definitions, i.e, values and functions
+// bound using `let`, that can be shared by Rib scripts using `import`.
+// A module is self-contained: it cannot import other modules, and it is type checked
+// without any global variables or worker functions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RibModule {
+    pub name: String,
+    pub version: u64,
+    pub definitions: Vec<Expr>,
+}
+
+impl RibModule {
+    pub fn from_expr(name: impl AsRef<str>, version: u64, expr: Expr) -> Result<Self, String> {
+        let name = name.as_ref().to_string();
+
+        if !internal::is_valid_module_name(&name) {
+            return Err(format!(
+                "Invalid module name `{}`. A module name starts with a letter, followed by letters, digits, `-` or `_`",
+                name
+            ));
+        }
+
+        let definitions = match expr {
+            Expr::ExprBlock(exprs, _) => exprs,
+            expr => vec![expr],
+        };
+
+        for definition in definitions.iter() {
+            match definition {
+                Expr::Import(import, _) => {
+                    return Err(format!(
+                        "Rib module `{}` cannot import other modules, but imports `{}`",
+                        name, import
+                    ))
+                }
+                Expr::Let(_, _, _, _) => {}
+                expr => {
+                    return Err(format!(
+                        "Rib module `{}` can only contain `let` definitions, but found `{}`",
+                        name, expr
+                    ))
+                }
+            }
+        }
+
+        Ok(RibModule {
+            name,
+            version,
+            definitions,
+        })
+    }
+
+    pub fn from_text(name: impl AsRef<str>, version: u64, input: &str) -> Result<Self, String> {
+        RibModule::from_expr(name, version, Expr::from_text(input)?)
+    }
+
+    pub fn to_expr(&self) -> Expr {
+        Expr::expr_block(self.definitions.clone())
+    }
+
+    pub fn is_imported_by(&self, import: &RibImport) -> bool {
+        self.name == import.module_name && import.version.map_or(true, |v| v == self.version)
+    }
+}
+
+pub(crate) fn imports(expr: &Expr) -> Vec<RibImport> {
+    let mut imports = vec![];
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        if let Expr::Import(import, _) = expr {
+            imports.push(import.clone());
+        }
+
+        expr.visit_children_bottom_up(&mut queue);
+    }
+
+    imports
+}
+
+pub(crate) fn imports_mut(expr: &mut Expr) -> Vec<&mut RibImport> {
+    let mut imports = vec![];
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_front() {
+        match expr {
+            Expr::Import(import, _) => imports.push(import),
+            expr => expr.visit_children_mut_top_down(&mut queue),
+        }
+    }
+
+    imports
+}
+
+// Imports are allowed only at the top level of a Rib script, and each of them is replaced
+// by the definitions of the imported module. An import without a version is resolved
+// to the latest of the given versions of the module.
+pub(crate) fn resolve_imports(expr: &mut Expr, modules: &[RibModule]) -> Result<(), String> {
+    let exprs = match &*expr {
+        Expr::ExprBlock(exprs, _) => exprs.clone(),
+        single_expr => vec![single_expr.clone()],
+    };
+
+    let mut resolved = vec![];
+
+    for expr in exprs {
+        match expr {
+            Expr::Import(import, _) => {
+                let module = modules
+                    .iter()
+                    .filter(|module| module.is_imported_by(&import))
+                    .max_by_key(|module| module.version)
+                    .ok_or(format!("Rib module `{}` not found", import))?;
+
+                resolved.extend(module.definitions.iter().cloned());
+            }
+            expr => {
+                if let Some(import) = imports(&expr).first() {
+                    return Err(format!(
+                        "Invalid import of `{}`. Modules can only be imported at the top level of a Rib script",
+                        import
+                    ));
+                }
+
+                resolved.push(expr);
+            }
+        }
+    }
+
+    *expr = if resolved.len() == 1 {
+        resolved.pop().unwrap()
+    } else {
+        Expr::expr_block(resolved)
+    };
+
+    Ok(())
+}
+
+mod internal {
+    pub(crate) fn is_valid_module_name(name: &str) -> bool {
+        let mut chars = name.chars();
+
+        match chars.next() {
+            Some(first) if first.is_ascii_alphabetic() => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{Expr, RibImport, RibModule};
+
+    #[test]
+    fn test_rib_module_from_text() {
+        let module = RibModule::from_text(
+            "helpers",
+            1,
+            r#"
+              let prefix = "user-";
+              let user_name = fn(id: u64) -> str { "${prefix}${id}" }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(module.definitions.len(), 2);
+    }
+
+    #[test]
+    fn test_rib_module_with_invalid_definition() {
+        let result = RibModule::from_text("helpers", 1, "let x = 1; x");
+
+        assert_eq!(
+            result,
+            Err(
+                "Rib module `helpers` can only contain `let` definitions, but found `x`"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_rib_module_with_import() {
+        let result = RibModule::from_text("helpers", 1, "import other; let x = 1");
+
+        assert_eq!(
+            result,
+            Err(
+                "Rib module `helpers` cannot import other modules, but imports `other`".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_resolve_imports() {
+        let modules = vec![
+            RibModule::from_text("helpers", 1, "let x = 1").unwrap(),
+            RibModule::from_text("helpers", 2, "let x = 2").unwrap(),
+        ];
+
+        let mut expr = Expr::from_text("import helpers@1; x").unwrap();
+        expr.resolve_imports(&modules).unwrap();
+
+        assert_eq!(expr, Expr::from_text("let x = 1; x").unwrap());
+
+        let mut expr = Expr::from_text("import helpers; x").unwrap();
+        expr.resolve_imports(&modules).unwrap();
+
+        assert_eq!(expr, Expr::from_text("let x = 2; x").unwrap());
+    }
+
+    #[test]
+    fn test_resolve_nested_import() {
+        let modules = vec![RibModule::from_text("helpers", 1, "let x = 1").unwrap()];
+
+        let mut expr = Expr::from_text("if true then { import helpers; x } else 0").unwrap();
+        let result = expr.resolve_imports(&modules);
+
+        assert_eq!(
+            result,
+            Err("Invalid import of `helpers`. Modules can only be imported at the top level of a Rib script".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_missing_import() {
+        let mut expr = Expr::from_text("import helpers@3; x").unwrap();
+        let result = expr.resolve_imports(&[]);
+
+        assert_eq!(result, Err("Rib module `helpers@3` not found".to_string()));
+    }
+
+    #[test]
+    fn test_pin_imports() {
+        let mut expr = Expr::from_text("import helpers; x").unwrap();
+
+        for import in expr.imports_mut() {
+            import.version = Some(4);
+        }
+
+        assert_eq!(expr.imports(), vec![RibImport::new("helpers", Some(4))]);
+        assert_eq!(expr.to_string(), "import helpers@4;\nx");
+    }
+}
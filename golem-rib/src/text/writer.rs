@@ -296,6 +296,10 @@ impl<W: Write> Writer<W> {
                 self.write_str(msg)?;
                 self.write_str(")")
             }
+            Expr::Import(import, _) => {
+                self.write_str("import ")?;
+                self.write_display(import)
+            }
             Expr::GetTag(expr, _) => {
                 self.write_str("get_tag(")?;
                 self.write_expr(expr)?;
@@ -349,6 +353,40 @@ impl<W: Write> Writer<W> {
                 internal::write_yield_block(self, yield_expr)?;
                 self.write_display(" } ")
             }
+
+            Expr::Lambda {
+                parameter, body, ..
+            } => {
+                self.write_display("|")?;
+                self.write_display(parameter.to_string())?;
+                self.write_display("| ")?;
+                self.write_expr(body)
+            }
+
+            Expr::Function {
+                parameters,
+                return_type,
+                body,
+                ..
+            } => {
+                self.write_str("fn(")?;
+                for (idx, (parameter, type_name)) in parameters.iter().enumerate() {
+                    if idx != 0 {
+                        self.write_str(", ")?;
+                    }
+                    self.write_str(parameter.name())?;
+                    self.write_str(": ")?;
+                    self.write_display(type_name)?;
+                }
+                self.write_str(")")?;
+                if let Some(return_type) = return_type {
+                    self.write_str(" -> ")?;
+                    self.write_display(return_type)?;
+                }
+                self.write_str(" { ")?;
+                self.write_expr(body)?;
+                self.write_str(" }")
+            }
         }
     }
 
@@ -1,4 +1,6 @@
+use crate::call_type::CallType;
 use crate::type_checker::{Path, TypeMismatchError, UnResolvedTypesError};
+use crate::type_inference::builtin_function_types::check_builtin_function_call;
 use crate::{Expr, FunctionTypeRegistry, RegistryKey, TypeName};
 use golem_wasm_ast::analysis::AnalysedType;
 use std::collections::VecDeque;
@@ -19,6 +21,17 @@ pub fn check_type_errors_in_function_call(
 
     while let Some(expr) = queue.pop_front() {
         match expr {
+            Expr::Call(CallType::Builtin(function), args, ..) => {
+                check_builtin_function_call(function, args)
+                    .map_err(|error| FunctionCallTypeError::InvalidBuiltinFunctionCall { error })?;
+
+                queue.extend(args.iter_mut());
+            }
+            // The arguments of local functions are typed from the parameters of the function,
+            // and any mismatch is a failure to unify the types of the arguments
+            Expr::Call(CallType::LocalFunction(_), args, ..) => {
+                queue.extend(args.iter_mut());
+            }
             Expr::Call(call_type, args, ..) => {
                 internal::check_type_mismatch_in_function_call(call_type, args, type_registry)?;
             }
@@ -49,6 +62,9 @@ pub enum FunctionCallTypeError {
         unresolved_error: UnResolvedTypesError,
         expected_type: AnalysedType,
     },
+    InvalidBuiltinFunctionCall {
+        error: String,
+    },
 }
 
 impl Display for FunctionCallTypeError {
@@ -110,6 +126,10 @@ impl Display for FunctionCallTypeError {
                     unresolved_error
                 )
             }
+
+            FunctionCallTypeError::InvalidBuiltinFunctionCall { error } => {
+                write!(f, "{}", error)
+            }
         }
     }
 }
@@ -136,6 +136,15 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
             }
             Expr::Unwrap(_, _) => {}
             Expr::Throw(_, _) => {}
+            Expr::Import(import, _) => {
+                return Err(UnResolvedTypesError::new(expr).with_additional_message(
+                    format!(
+                        "Rib module `{}` should be resolved before the Rib script is compiled",
+                        import
+                    )
+                    .as_str(),
+                ));
+            }
             Expr::GetTag(_, _) => {}
             Expr::ListComprehension {
                 iterable_expr,
@@ -152,6 +161,7 @@ pub fn check_unresolved_types(expr: &Expr) -> Result<(), UnResolvedTypesError> {
                 init_value_expr,
                 yield_expr,
             )?,
+            Expr::Lambda { body, .. } | Expr::Function { body, .. } => queue.push_back(body),
         }
     }
 
@@ -0,0 +1,128 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::call_type::CallType;
+use crate::{Expr, FunctionTypeRegistry};
+use std::collections::VecDeque;
+
+// Initially every call is a call to a (worker) function. Calls to functions
+// that are not exported by the component, but are part of the Rib standard library,
+// are converted to builtin function calls.
+// An anonymous function such as `fn(x: u64) -> u64 { x + 1 }` passed to `filter` or `map`
+// is converted to a lambda, with the parameter and the body typed as per the function.
+pub fn infer_builtin_functions(expr: &mut Expr, function_type_registry: &FunctionTypeRegistry) {
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        if let Expr::Call(call_type, args, _) = expr {
+            if let CallType::Function(parsed_function_name) = call_type {
+                let builtin_function = parsed_function_name.builtin_function();
+
+                if let Some(builtin_function) = builtin_function {
+                    if function_type_registry.get(call_type).is_none() {
+                        if builtin_function.takes_lambda() {
+                            if let Some(last) = args.last_mut() {
+                                internal::function_to_lambda(last);
+                            }
+                        }
+
+                        *call_type = CallType::Builtin(builtin_function);
+                    }
+                }
+            }
+        }
+
+        expr.visit_children_mut_bottom_up(&mut queue);
+    }
+}
+
+mod internal {
+    use crate::type_inference::local_function_resolution::bind_function_parameter;
+    use crate::{Expr, InferredType};
+
+    pub(crate) fn function_to_lambda(expr: &mut Expr) {
+        if let Expr::Function {
+            parameters,
+            return_type,
+            body,
+            ..
+        } = expr
+        {
+            if let [(parameter, type_name)] = parameters.as_slice() {
+                let parameter = parameter.clone();
+                let mut body = body.clone();
+
+                bind_function_parameter(&parameter, &type_name.clone().into(), &mut body);
+
+                let inferred_type = match return_type {
+                    Some(return_type) => {
+                        let return_type: InferredType = return_type.clone().into();
+                        body.add_infer_type_mut(return_type.clone());
+                        return_type
+                    }
+                    None => InferredType::Unknown,
+                };
+
+                *expr = Expr::Lambda {
+                    parameter,
+                    body,
+                    inferred_type,
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::call_type::CallType;
+    use crate::{BuiltinFunction, Expr, FunctionTypeRegistry, InferredType};
+
+    #[test]
+    fn test_infer_builtin_functions() {
+        let mut expr = Expr::from_text(r#"to-upper(trim(request.headers.name))"#).unwrap();
+
+        expr.infer_builtin_functions(&FunctionTypeRegistry::empty());
+
+        let Expr::Call(call_type, args, _) = expr else {
+            panic!("Expected a function call")
+        };
+
+        assert_eq!(call_type, CallType::Builtin(BuiltinFunction::ToUpper));
+        assert!(matches!(
+            args.first(),
+            Some(Expr::Call(CallType::Builtin(BuiltinFunction::Trim), _, _))
+        ));
+    }
+
+    #[test]
+    fn test_infer_builtin_functions_with_anonymous_function() {
+        let mut expr = Expr::from_text(r#"map([1, 2], fn(x: u64) -> u64 { x + 1 })"#).unwrap();
+
+        expr.infer_builtin_functions(&FunctionTypeRegistry::empty());
+
+        let Expr::Call(call_type, args, _) = expr else {
+            panic!("Expected a function call")
+        };
+
+        assert_eq!(call_type, CallType::Builtin(BuiltinFunction::Map));
+        assert!(matches!(
+            args.last(),
+            Some(Expr::Lambda { inferred_type, .. }) if *inferred_type == InferredType::U64
+        ));
+    }
+}
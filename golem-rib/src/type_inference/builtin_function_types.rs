@@ -0,0 +1,278 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The typing rules of the Rib standard library.
+// Unlike worker functions, builtin functions have no entry in the function type registry,
+// and many of them are generic (Ex: `head` returns an `option<T>` given a `list<T>`),
+// and therefore their types are derived from the types of the arguments
+// and the result, in every phase of type inference.
+use crate::type_inference::kind::{GetTypeKind, TypeKind};
+use crate::type_refinement::precise_types::{ListType, OptionalType};
+use crate::type_refinement::TypeRefinement;
+use crate::{BuiltinFunction, Expr, InferredType, VariableId};
+use std::collections::VecDeque;
+
+// The type of an argument that is the same in every call to the function
+pub(crate) fn builtin_argument_type(
+    function: &BuiltinFunction,
+    index: usize,
+) -> Option<InferredType> {
+    match (function, index) {
+        (
+            BuiltinFunction::Split
+            | BuiltinFunction::Contains
+            | BuiltinFunction::StartsWith
+            | BuiltinFunction::EndsWith,
+            0 | 1,
+        ) => Some(InferredType::Str),
+        (
+            BuiltinFunction::Trim
+            | BuiltinFunction::ToUpper
+            | BuiltinFunction::ToLower
+            | BuiltinFunction::ParseU64
+            | BuiltinFunction::ParseS64
+            | BuiltinFunction::ParseF64,
+            0,
+        ) => Some(InferredType::Str),
+        (BuiltinFunction::Join, 0) => Some(InferredType::List(Box::new(InferredType::Str))),
+        (BuiltinFunction::Join, 1) => Some(InferredType::Str),
+        _ => None,
+    }
+}
+
+// The result type of the function, if it doesn't depend on the arguments
+pub(crate) fn builtin_result_type(function: &BuiltinFunction) -> Option<InferredType> {
+    match function {
+        BuiltinFunction::Len => Some(InferredType::U64),
+        BuiltinFunction::Split => Some(InferredType::List(Box::new(InferredType::Str))),
+        BuiltinFunction::Trim
+        | BuiltinFunction::ToUpper
+        | BuiltinFunction::ToLower
+        | BuiltinFunction::Join
+        | BuiltinFunction::ToString => Some(InferredType::Str),
+        BuiltinFunction::Contains | BuiltinFunction::StartsWith | BuiltinFunction::EndsWith => {
+            Some(InferredType::Bool)
+        }
+        BuiltinFunction::ParseU64 => Some(InferredType::Option(Box::new(InferredType::U64))),
+        BuiltinFunction::ParseS64 => Some(InferredType::Option(Box::new(InferredType::S64))),
+        BuiltinFunction::ParseF64 => Some(InferredType::Option(Box::new(InferredType::F64))),
+        BuiltinFunction::Head
+        | BuiltinFunction::Filter
+        | BuiltinFunction::Map
+        | BuiltinFunction::Sort
+        | BuiltinFunction::Min
+        | BuiltinFunction::Max
+        | BuiltinFunction::Abs => None,
+    }
+}
+
+// Given the result type of a call, update the types of its arguments.
+// The parameter of a lambda is the element of the list the lambda is applied to.
+pub(crate) fn push_down_builtin_types(
+    function: &BuiltinFunction,
+    args: &mut [Expr],
+    result_type: &InferredType,
+) {
+    match (function, args) {
+        (BuiltinFunction::Map | BuiltinFunction::Filter, [list, lambda]) => {
+            if let Expr::Lambda {
+                parameter,
+                body,
+                inferred_type,
+            } = lambda
+            {
+                if let Some(list_type) = ListType::refine(&list.inferred_type()) {
+                    internal::bind_parameter_type(parameter, &list_type.inner_type(), body);
+                }
+
+                let lambda_type = if *function == BuiltinFunction::Filter {
+                    Some(InferredType::Bool)
+                } else {
+                    ListType::refine(result_type).map(|list_type| list_type.inner_type())
+                };
+
+                if let Some(lambda_type) = lambda_type {
+                    *inferred_type = inferred_type.merge(lambda_type);
+                }
+            }
+
+            if *function == BuiltinFunction::Filter {
+                list.add_infer_type_mut(result_type.clone());
+            }
+        }
+
+        (BuiltinFunction::Sort, [list]) => list.add_infer_type_mut(result_type.clone()),
+
+        (BuiltinFunction::Head, [list]) => {
+            if let Some(optional_type) = OptionalType::refine(result_type) {
+                list.add_infer_type_mut(InferredType::List(Box::new(optional_type.inner_type())));
+            }
+        }
+
+        (BuiltinFunction::Min | BuiltinFunction::Max | BuiltinFunction::Abs, args) => {
+            for arg in args {
+                arg.add_infer_type_mut(result_type.clone());
+            }
+        }
+
+        _ => {}
+    }
+}
+
+// Given the types of the arguments, find the result type of a call
+pub(crate) fn pull_up_builtin_type(
+    function: &BuiltinFunction,
+    args: &[Expr],
+) -> Option<InferredType> {
+    let inferred_type = match (function, args) {
+        (BuiltinFunction::Head, [list]) => ListType::refine(&list.inferred_type())
+            .map(|list_type| InferredType::Option(Box::new(list_type.inner_type())))?,
+        (BuiltinFunction::Sort | BuiltinFunction::Filter, [list, ..]) => list.inferred_type(),
+        (BuiltinFunction::Map, [_, lambda]) if !lambda.inferred_type().is_unknown() => {
+            InferredType::List(Box::new(lambda.inferred_type()))
+        }
+        (BuiltinFunction::Min | BuiltinFunction::Max | BuiltinFunction::Abs, args) => {
+            args.iter().fold(InferredType::Unknown, |acc, arg| {
+                acc.merge(arg.inferred_type())
+            })
+        }
+        _ => builtin_result_type(function)?,
+    };
+
+    if inferred_type.is_unknown() {
+        None
+    } else {
+        Some(inferred_type)
+    }
+}
+
+pub(crate) fn check_builtin_function_call(
+    function: &BuiltinFunction,
+    args: &[Expr],
+) -> Result<(), String> {
+    if args.len() != function.arity() {
+        return Err(format!(
+            "Invalid number of arguments in `{}`. Expected {}, but provided {}",
+            function,
+            function.arity(),
+            args.len()
+        ));
+    }
+
+    for (index, arg) in args.iter().enumerate() {
+        let expects_lambda = function.takes_lambda() && index == args.len() - 1;
+
+        match arg {
+            Expr::Lambda { body, .. } if expects_lambda => {
+                let body_kind = body.inferred_type().get_type_kind();
+
+                if *function == BuiltinFunction::Filter
+                    && body_kind != TypeKind::Unknown
+                    && body_kind != TypeKind::Boolean
+                {
+                    return Err(format!(
+                        "Invalid argument in `{}`: `{}`. The lambda should return a boolean, but returns a {}",
+                        function, arg, body_kind
+                    ));
+                }
+            }
+
+            Expr::Lambda { .. } => {
+                return Err(format!(
+                    "Invalid argument in `{}`: `{}`. Lambdas can only be passed to `filter` and `map`",
+                    function, arg
+                ));
+            }
+
+            _ if expects_lambda => {
+                return Err(format!(
+                    "Invalid argument in `{}`: `{}`. Expected a lambda, such as `|x| x`",
+                    function, arg
+                ));
+            }
+
+            _ => {
+                let kind = arg.inferred_type().get_type_kind();
+                let expected_kinds = internal::expected_argument_kinds(function, index);
+
+                if kind != TypeKind::Unknown && !expected_kinds.contains(&kind) {
+                    let expected = expected_kinds
+                        .iter()
+                        .map(|kind| kind.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" or ");
+
+                    return Err(format!(
+                        "Invalid argument in `{}`: `{}`. Expected type `{}`, but provided argument is a `{}`",
+                        function, arg, expected, kind
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+mod internal {
+    use super::*;
+
+    pub(crate) fn expected_argument_kinds(
+        function: &BuiltinFunction,
+        index: usize,
+    ) -> Vec<TypeKind> {
+        match (function, index) {
+            (BuiltinFunction::Len, _) => vec![TypeKind::Str, TypeKind::List],
+            (
+                BuiltinFunction::Head
+                | BuiltinFunction::Filter
+                | BuiltinFunction::Map
+                | BuiltinFunction::Sort,
+                _,
+            ) => vec![TypeKind::List],
+            (BuiltinFunction::Join, 0) => vec![TypeKind::List],
+            (BuiltinFunction::Min | BuiltinFunction::Max | BuiltinFunction::Abs, _) => {
+                vec![TypeKind::Number]
+            }
+            (BuiltinFunction::ToString, _) => vec![
+                TypeKind::Str,
+                TypeKind::Number,
+                TypeKind::Boolean,
+                TypeKind::Char,
+            ],
+            (_, _) => vec![TypeKind::Str],
+        }
+    }
+
+    // The identifiers referring to the parameter of the lambda within its body
+    pub(crate) fn bind_parameter_type(
+        parameter: &VariableId,
+        parameter_type: &InferredType,
+        body: &mut Expr,
+    ) {
+        let mut queue = VecDeque::new();
+        queue.push_back(body);
+
+        while let Some(expr) = queue.pop_back() {
+            match expr {
+                Expr::Identifier(variable_id, inferred_type) => {
+                    if variable_id == parameter {
+                        *inferred_type = inferred_type.merge(parameter_type.clone());
+                    }
+                }
+                _ => expr.visit_children_mut_bottom_up(&mut queue),
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::call_type::CallType;
 use crate::type_registry::FunctionTypeRegistry;
 use crate::Expr;
 use std::collections::VecDeque;
@@ -31,6 +32,12 @@ pub fn infer_call_arguments_type(
                     args,
                     inferred_type,
                 )?;
+
+                // Arguments of builtin and local functions are often calls themselves,
+                // Ex: `to-upper(trim(request.path.user))`
+                if let CallType::Builtin(_) | CallType::LocalFunction(_) = parsed_fn_name {
+                    queue.extend(args.iter_mut());
+                }
             }
             _ => expr.visit_children_mut_bottom_up(&mut queue),
         }
@@ -41,6 +48,9 @@ pub fn infer_call_arguments_type(
 
 mod internal {
     use crate::call_type::CallType;
+    use crate::type_inference::builtin_function_types::{
+        builtin_argument_type, builtin_result_type, check_builtin_function_call,
+    };
     use crate::type_inference::kind::GetTypeKind;
     use crate::{
         DynamicParsedFunctionName, Expr, FunctionTypeRegistry, InferredType, RegistryKey,
@@ -103,6 +113,26 @@ mod internal {
                 )
                 .map_err(|e| e.to_string())
             }
+
+            CallType::Builtin(function) => {
+                check_builtin_function_call(function, args)?;
+
+                for (index, arg) in args.iter_mut().enumerate() {
+                    if let Some(argument_type) = builtin_argument_type(function, index) {
+                        arg.add_infer_type_mut(argument_type);
+                    }
+                }
+
+                if let Some(result_type) = builtin_result_type(function) {
+                    *function_result_inferred_type =
+                        function_result_inferred_type.merge(result_type);
+                }
+
+                Ok(())
+            }
+
+            // The arguments of local functions are typed when the calls are bound to the functions
+            CallType::LocalFunction(_) => Ok(()),
         }
     }
 
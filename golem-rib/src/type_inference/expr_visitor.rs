@@ -105,6 +105,8 @@ pub fn visit_children_bottom_up_mut<'a>(expr: &'a mut Expr, queue: &mut VecDeque
             queue.push_back(yield_expr);
         }
 
+        Expr::Lambda { body, .. } | Expr::Function { body, .. } => queue.push_back(body),
+
         Expr::GetTag(exr, _) => {
             queue.push_back(&mut *exr);
         }
@@ -116,6 +118,7 @@ pub fn visit_children_bottom_up_mut<'a>(expr: &'a mut Expr, queue: &mut VecDeque
         Expr::Boolean(_, _) => {}
         Expr::Option(None, _) => {}
         Expr::Throw(_, _) => {}
+        Expr::Import(_, _) => {}
     }
 }
 
@@ -217,6 +220,8 @@ pub fn visit_children_bottom_up<'a>(expr: &'a Expr, queue: &mut VecDeque<&'a Exp
             queue.push_back(init_value_expr);
             queue.push_back(yield_expr);
         }
+
+        Expr::Lambda { body, .. } | Expr::Function { body, .. } => queue.push_back(body),
         Expr::GetTag(expr, _) => {
             queue.push_back(expr);
         }
@@ -228,6 +233,7 @@ pub fn visit_children_bottom_up<'a>(expr: &'a Expr, queue: &mut VecDeque<&'a Exp
         Expr::Boolean(_, _) => {}
         Expr::Option(None, _) => {}
         Expr::Throw(_, _) => {}
+        Expr::Import(_, _) => {}
     }
 }
 
@@ -355,6 +361,7 @@ pub fn visit_children_mut_top_down<'a>(expr: &'a mut Expr, queue: &mut VecDeque<
             queue.push_front(init_value_expr);
             queue.push_front(yield_expr);
         }
+        Expr::Lambda { body, .. } | Expr::Function { body, .. } => queue.push_front(body),
 
         Expr::Unwrap(expr, _) => queue.push_front(&mut *expr),
         Expr::Literal(_, _) => {}
@@ -364,6 +371,7 @@ pub fn visit_children_mut_top_down<'a>(expr: &'a mut Expr, queue: &mut VecDeque<
         Expr::Boolean(_, _) => {}
         Expr::Option(None, _) => {}
         Expr::Throw(_, _) => {}
+        Expr::Import(_, _) => {}
     }
 }
 
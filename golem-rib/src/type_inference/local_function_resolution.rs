@@ -0,0 +1,329 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::call_type::CallType;
+use crate::{Expr, InferredType, VariableId};
+use std::collections::{HashMap, VecDeque};
+
+// Initially every call is a call to a (worker) function. Calls to functions defined
+// using `let f = fn(x: u64) -> u64 { ... }` are converted to local function calls.
+// Similar to let bindings, a call refers to the latest definition of the function
+// preceding the call, and a function can call itself.
+// Functions are monomorphic: the types of the parameters are mandatory, and they are
+// bound to the parameters within the body, and to the arguments of every call.
+pub fn bind_local_functions(expr: &mut Expr) -> Result<(), String> {
+    let mut functions: HashMap<String, internal::LocalFunction> = HashMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_front(expr);
+
+    while let Some(expr) = queue.pop_front() {
+        match expr {
+            Expr::Let(variable_id, _, function, _)
+                if matches!(function.as_ref(), Expr::Function { .. }) =>
+            {
+                if let Expr::Function {
+                    parameters,
+                    return_type,
+                    body,
+                    inferred_type,
+                } = function.as_mut()
+                {
+                    let function_name = variable_id.name();
+
+                    if return_type.is_none() && internal::calls_function(body, &function_name) {
+                        return Err(format!(
+                            "Recursive function `{}` should specify its return type",
+                            function_name
+                        ));
+                    }
+
+                    for (parameter, type_name) in parameters.iter() {
+                        bind_function_parameter(parameter, &type_name.clone().into(), body);
+                    }
+
+                    if let Some(return_type) = return_type {
+                        let return_type: InferredType = return_type.clone().into();
+                        body.add_infer_type_mut(return_type.clone());
+                        *inferred_type = inferred_type.merge(return_type);
+                    }
+
+                    functions.insert(
+                        function_name,
+                        internal::LocalFunction {
+                            variable_id: variable_id.clone(),
+                            parameter_types: parameters
+                                .iter()
+                                .map(|(_, type_name)| type_name.clone().into())
+                                .collect(),
+                        },
+                    );
+
+                    queue.push_front(body);
+                }
+            }
+
+            Expr::Call(call_type, args, _) => {
+                let local_function = internal::local_function_name(call_type)
+                    .and_then(|function_name| functions.get(&function_name));
+
+                if let Some(local_function) = local_function {
+                    if args.len() != local_function.parameter_types.len() {
+                        return Err(format!(
+                            "Invalid number of arguments in `{}`. Expected {}, but provided {}",
+                            call_type,
+                            local_function.parameter_types.len(),
+                            args.len()
+                        ));
+                    }
+
+                    for (arg, parameter_type) in
+                        args.iter_mut().zip(local_function.parameter_types.iter())
+                    {
+                        arg.add_infer_type_mut(parameter_type.clone());
+                    }
+
+                    *call_type = CallType::LocalFunction(local_function.variable_id.clone());
+                }
+
+                expr.visit_children_mut_top_down(&mut queue);
+            }
+
+            _ => expr.visit_children_mut_top_down(&mut queue),
+        }
+    }
+
+    Ok(())
+}
+
+// The result of a call to a local function is the return type of the function
+pub fn infer_local_function_calls(expr: &mut Expr) {
+    let return_types = internal::return_types_of_local_functions(expr);
+
+    let mut queue = VecDeque::new();
+    queue.push_back(expr);
+
+    while let Some(expr) = queue.pop_back() {
+        if let Expr::Call(CallType::LocalFunction(variable_id), _, inferred_type) = expr {
+            if let Some(return_type) = return_types.get(variable_id) {
+                if !return_type.is_unknown() {
+                    *inferred_type = inferred_type.merge(return_type.clone());
+                }
+            }
+        }
+
+        expr.visit_children_mut_bottom_up(&mut queue);
+    }
+}
+
+// Parameters shadow the variables of the enclosing scope, and are shadowed by the variables
+// defined in the body, the parameters of nested functions and lambdas, and the variables of list comprehensions
+pub(crate) fn bind_function_parameter(
+    parameter: &VariableId,
+    parameter_type: &InferredType,
+    body: &mut Expr,
+) {
+    let variables_in_body = internal::variables_defined_in(body);
+
+    let mut queue = VecDeque::new();
+    queue.push_back(body);
+
+    while let Some(expr) = queue.pop_back() {
+        match expr {
+            Expr::Identifier(variable_id, inferred_type) => {
+                if variable_id.name() == parameter.name()
+                    && !variable_id.is_match_binding()
+                    && !variables_in_body.contains(variable_id)
+                {
+                    *variable_id = parameter.clone();
+                    *inferred_type = inferred_type.merge(parameter_type.clone());
+                }
+            }
+
+            Expr::Function { parameters, .. }
+                if parameters
+                    .iter()
+                    .any(|(variable_id, _)| variable_id.name() == parameter.name()) => {}
+
+            Expr::Lambda {
+                parameter: lambda_parameter,
+                ..
+            } if lambda_parameter.name() == parameter.name() => {}
+
+            Expr::ListComprehension {
+                iterated_variable,
+                iterable_expr,
+                ..
+            } if iterated_variable.name() == parameter.name() => {
+                queue.push_back(iterable_expr);
+            }
+
+            _ => expr.visit_children_mut_bottom_up(&mut queue),
+        }
+    }
+}
+
+mod internal {
+    use crate::call_type::CallType;
+    use crate::{
+        DynamicParsedFunctionReference, Expr, InferredType, ParsedFunctionSite, VariableId,
+    };
+    use std::collections::{HashMap, HashSet, VecDeque};
+
+    pub(crate) struct LocalFunction {
+        pub(crate) variable_id: VariableId,
+        pub(crate) parameter_types: Vec<InferredType>,
+    }
+
+    // Only a call to a global function without an interface, such as `f(1)`,
+    // can be a call to a local function
+    pub(crate) fn local_function_name(call_type: &CallType) -> Option<String> {
+        match call_type {
+            CallType::Function(function_name) => {
+                match (&function_name.site, &function_name.function) {
+                    (
+                        ParsedFunctionSite::Global,
+                        DynamicParsedFunctionReference::Function { function },
+                    ) => Some(function.clone()),
+                    _ => None,
+                }
+            }
+            CallType::LocalFunction(variable_id) => Some(variable_id.name()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn calls_function(body: &Expr, function_name: &str) -> bool {
+        let mut queue = VecDeque::new();
+        queue.push_back(body);
+
+        while let Some(expr) = queue.pop_back() {
+            if let Expr::Call(call_type, _, _) = expr {
+                if local_function_name(call_type).as_deref() == Some(function_name) {
+                    return true;
+                }
+            }
+
+            expr.visit_children_bottom_up(&mut queue);
+        }
+
+        false
+    }
+
+    pub(crate) fn variables_defined_in(body: &Expr) -> HashSet<VariableId> {
+        let mut variables = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(body);
+
+        while let Some(expr) = queue.pop_back() {
+            if let Expr::Let(variable_id, _, _, _) = expr {
+                variables.insert(variable_id.clone());
+            }
+
+            expr.visit_children_bottom_up(&mut queue);
+        }
+
+        variables
+    }
+
+    pub(crate) fn return_types_of_local_functions(
+        expr: &Expr,
+    ) -> HashMap<VariableId, InferredType> {
+        let mut return_types = HashMap::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(expr);
+
+        while let Some(expr) = queue.pop_back() {
+            if let Expr::Let(variable_id, _, function, _) = expr {
+                if let Expr::Function { inferred_type, .. } = function.as_ref() {
+                    return_types.insert(variable_id.clone(), inferred_type.clone());
+                }
+            }
+
+            expr.visit_children_bottom_up(&mut queue);
+        }
+
+        return_types
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::call_type::CallType;
+    use crate::{Expr, InferredType, VariableId};
+
+    #[test]
+    fn test_bind_local_functions() {
+        let rib_expr = r#"
+          let f = fn(x: u64) -> u64 { x + 1 };
+          f(2)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+
+        expr.bind_variables_of_let_assignment();
+        expr.bind_local_functions().unwrap();
+
+        let Expr::ExprBlock(exprs, _) = expr else {
+            panic!("Expected a block")
+        };
+
+        let Some(Expr::Call(call_type, args, _)) = exprs.last() else {
+            panic!("Expected a function call")
+        };
+
+        assert_eq!(
+            call_type,
+            &CallType::LocalFunction(VariableId::local("f", 0))
+        );
+        assert_eq!(args[0].inferred_type().unify(), Ok(InferredType::U64));
+    }
+
+    #[test]
+    fn test_bind_local_functions_invalid_arguments() {
+        let rib_expr = r#"
+          let f = fn(x: u64) -> u64 { x + 1 };
+          f(2, 3)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+
+        expr.bind_variables_of_let_assignment();
+        let result = expr.bind_local_functions();
+
+        assert_eq!(
+            result,
+            Err("Invalid number of arguments in `f`. Expected 1, but provided 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bind_recursive_function_without_return_type() {
+        let rib_expr = r#"
+          let f = fn(x: u64) { if x == 0 then 0 else f(x - 1) };
+          f(2)
+        "#;
+
+        let mut expr = Expr::from_text(rib_expr).unwrap();
+
+        expr.bind_variables_of_let_assignment();
+        let result = expr.bind_local_functions();
+
+        assert_eq!(
+            result,
+            Err("Recursive function `f` should specify its return type".to_string())
+        );
+    }
+}
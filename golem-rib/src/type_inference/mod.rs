@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use builtin_function_resolution::*;
 pub use call_arguments_inference::*;
 pub use enum_resolution::*;
 pub use expr_visitor::*;
@@ -19,6 +20,7 @@ pub use global_input_inference::*;
 pub use identifier_inference::*;
 pub use inference_fix_point::*;
 pub use inferred_expr::*;
+pub use local_function_resolution::*;
 pub use rib_input_type::*;
 pub(crate) use type_binding::*;
 pub use type_pull_up::*;
@@ -31,6 +33,8 @@ pub use variable_binding_list_reduce::*;
 pub use variable_binding_pattern_match::*;
 pub use variant_resolution::*;
 
+mod builtin_function_resolution;
+pub(crate) mod builtin_function_types;
 mod call_arguments_inference;
 mod expr_visitor;
 mod identifier_inference;
@@ -48,6 +52,7 @@ mod global_input_inference;
 mod inference_fix_point;
 mod inferred_expr;
 pub(crate) mod kind;
+mod local_function_resolution;
 mod type_binding;
 mod variable_binding_list_comprehension;
 mod variable_binding_list_reduce;
@@ -78,6 +78,9 @@ mod internal {
             | Expr::Or(_, _, inferred_type)
             | Expr::ListComprehension { inferred_type, .. }
             | Expr::ListReduce { inferred_type, .. }
+            | Expr::Lambda { inferred_type, .. }
+            | Expr::Function { inferred_type, .. }
+            | Expr::Import(_, inferred_type)
             | Expr::Call(_, _, inferred_type) => {
                 *inferred_type = new_type;
             }
@@ -282,7 +282,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 internal::handle_unwrap(expr, inferred_type, &mut inferred_type_stack);
             }
 
-            Expr::Throw(_, _) => {
+            Expr::Throw(_, _) | Expr::Import(_, _) => {
                 inferred_type_stack.push_front(expr.clone());
             }
 
@@ -322,6 +322,25 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
                 inferred_type,
                 &mut inferred_type_stack,
             ),
+
+            Expr::Lambda {
+                parameter,
+                body,
+                inferred_type,
+            } => internal::handle_lambda(parameter, body, inferred_type, &mut inferred_type_stack),
+
+            Expr::Function {
+                parameters,
+                return_type,
+                body,
+                inferred_type,
+            } => internal::handle_function(
+                parameters,
+                return_type,
+                body,
+                inferred_type,
+                &mut inferred_type_stack,
+            ),
         }
     }
 
@@ -333,6 +352,7 @@ pub fn type_pull_up(expr: &Expr) -> Result<Expr, String> {
 mod internal {
     use crate::call_type::CallType;
 
+    use crate::type_inference::builtin_function_types::pull_up_builtin_type;
     use crate::type_refinement::precise_types::{ListType, RecordType};
     use crate::type_refinement::TypeRefinement;
     use crate::{Expr, InferredType, MatchArm, VariableId};
@@ -376,6 +396,42 @@ mod internal {
         ))
     }
 
+    pub(crate) fn handle_lambda(
+        parameter: &VariableId,
+        current_body: &Expr,
+        current_lambda_type: &InferredType,
+        inferred_type_stack: &mut VecDeque<Expr>,
+    ) {
+        let body = inferred_type_stack
+            .pop_front()
+            .unwrap_or(current_body.clone());
+
+        let lambda_type = current_lambda_type.merge(body.inferred_type());
+
+        inferred_type_stack.push_front(Expr::typed_lambda(parameter.clone(), body, lambda_type))
+    }
+
+    pub(crate) fn handle_function(
+        parameters: &[(VariableId, crate::parser::type_name::TypeName)],
+        return_type: &Option<crate::parser::type_name::TypeName>,
+        current_body: &Expr,
+        current_function_type: &InferredType,
+        inferred_type_stack: &mut VecDeque<Expr>,
+    ) {
+        let body = inferred_type_stack
+            .pop_front()
+            .unwrap_or(current_body.clone());
+
+        let function_type = current_function_type.merge(body.inferred_type());
+
+        inferred_type_stack.push_front(Expr::typed_function(
+            parameters.to_vec(),
+            return_type.clone(),
+            body,
+            function_type,
+        ))
+    }
+
     pub(crate) fn handle_list_reduce(
         reduce_variable: &VariableId,
         iterated_variable: &VariableId,
@@ -801,6 +857,31 @@ mod internal {
                 );
                 inferred_type_stack.push_front(new_call);
             }
+
+            CallType::Builtin(function) => {
+                let new_inferred_type = match pull_up_builtin_type(function, &new_arg_exprs) {
+                    Some(result_type) => inferred_type.merge(result_type),
+                    None => inferred_type.clone(),
+                };
+
+                let new_call = Expr::Call(
+                    CallType::Builtin(*function),
+                    new_arg_exprs,
+                    new_inferred_type,
+                );
+                inferred_type_stack.push_front(new_call);
+            }
+
+            // The return type of a local function is pulled up from its definition
+            // during the inference of local function calls
+            CallType::LocalFunction(variable_id) => {
+                let new_call = Expr::Call(
+                    CallType::LocalFunction(variable_id.clone()),
+                    new_arg_exprs,
+                    inferred_type.clone(),
+                );
+                inferred_type_stack.push_front(new_call);
+            }
         }
     }
 
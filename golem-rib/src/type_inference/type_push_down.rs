@@ -132,6 +132,20 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
                 queue.push_back(yield_expr);
             }
 
+            Expr::Lambda {
+                body,
+                inferred_type,
+                ..
+            }
+            | Expr::Function {
+                body,
+                inferred_type,
+                ..
+            } => {
+                body.add_infer_type_mut(inferred_type.clone());
+                queue.push_back(body);
+            }
+
             _ => expr.visit_children_mut_bottom_up(&mut queue),
         }
     }
@@ -141,6 +155,7 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), String> {
 
 mod internal {
     use crate::call_type::CallType;
+    use crate::type_inference::builtin_function_types::push_down_builtin_types;
     use crate::type_refinement::precise_types::*;
     use crate::type_refinement::TypeRefinement;
     use crate::{ArmPattern, Expr, InferredType, VariableId};
@@ -387,6 +402,15 @@ mod internal {
                     }
                 }
             }
+            // For builtin functions, the type of the arguments is often decided by
+            // the return type, such as the list given to `sort`
+            CallType::Builtin(function) => {
+                push_down_builtin_types(function, expressions, inferred_type);
+
+                for expr in expressions {
+                    queue.push_back(expr);
+                }
+            }
             _ => {
                 for expr in expressions {
                     queue.push_back(expr);
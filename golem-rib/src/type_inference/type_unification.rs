@@ -200,6 +200,47 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 }
             }
 
+            Expr::Lambda {
+                body,
+                inferred_type,
+                ..
+            } => {
+                queue.push(body);
+
+                let unified_inferred_type = inferred_type.unify();
+
+                match unified_inferred_type {
+                    Ok(unified_type) => *inferred_type = unified_type,
+                    Err(e) => {
+                        errors.push(format!("Unable to resolve the type of lambda {}", expr_str));
+
+                        errors.push(e)
+                    }
+                }
+            }
+
+            Expr::Function {
+                body,
+                inferred_type,
+                ..
+            } => {
+                queue.push(body);
+
+                let unified_inferred_type = inferred_type.unify();
+
+                match unified_inferred_type {
+                    Ok(unified_type) => *inferred_type = unified_type,
+                    Err(e) => {
+                        errors.push(format!(
+                            "Unable to resolve the return type of function {}",
+                            expr_str
+                        ));
+
+                        errors.push(e)
+                    }
+                }
+            }
+
             Expr::PatternMatch(expr, arms, inferred_type) => {
                 queue.push(expr);
                 for arm in arms.iter_mut().rev() {
@@ -307,6 +348,7 @@ pub fn unify_types(expr: &mut Expr) -> Result<(), Vec<String>> {
                 }
             }
             Expr::Boolean(_, _) => {}
+            Expr::Import(_, _) => {}
             Expr::Concat(exprs, _) => {
                 queue.extend(exprs);
             }
@@ -34,6 +34,14 @@ pub fn bind_variables_of_list_comprehension(expr: &mut Expr) {
 
                 internal::process_yield_expr(iterated_variable, yield_expr)
             }
+            Expr::Lambda {
+                parameter, body, ..
+            } => {
+                *parameter = VariableId::list_comprehension_identifier(parameter.name());
+
+                internal::process_yield_expr(parameter, body);
+                queue.push_front(body);
+            }
             _ => {
                 expr.visit_children_mut_top_down(&mut queue);
             }
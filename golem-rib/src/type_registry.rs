@@ -72,6 +72,7 @@ impl FunctionTypeRegistry {
             CallType::EnumConstructor(enum_name) => self
                 .types
                 .get(&RegistryKey::FunctionName(enum_name.clone())),
+            CallType::Builtin(_) | CallType::LocalFunction(_) => None,
         }
     }
 
@@ -274,6 +275,8 @@ impl RegistryKey {
                 RegistryKey::FunctionName(variant_name.clone())
             }
             CallType::EnumConstructor(enum_name) => RegistryKey::FunctionName(enum_name.clone()),
+            CallType::Builtin(function) => RegistryKey::FunctionName(function.name().to_string()),
+            CallType::LocalFunction(variable_id) => RegistryKey::FunctionName(variable_id.name()),
             CallType::Function(function_name) => match function_name.site.interface_name() {
                 None => {
                     RegistryKey::FunctionName(function_name.function_name_with_prefix_identifiers())
@@ -4,7 +4,9 @@ use poem_openapi::Tags;
 pub enum ApiTags {
     ApiDeployment,
     ApiDefinition,
+    RibModule,
     Component,
     Worker,
+    Topic,
     HealthCheck,
 }
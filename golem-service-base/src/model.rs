@@ -18,8 +18,12 @@ use golem_api_grpc::proto::golem::worker::OplogEntryWithIndex;
 use golem_common::model::component_metadata::ComponentMetadata;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{
-    ComponentId, ComponentType, ComponentVersion, FileSystemPermission, PromiseId, ScanCursor, ShardId, Timestamp, WorkerFilter, WorkerId, WorkerStatus
+    ComponentId, ComponentType, ComponentVersion, DeadLetteredInvocationId, FileSystemPermission,
+    PromiseId, ScanCursor, ScheduledInvocationId, ShardId, Timestamp, WorkerFilter, WorkerId,
+    WorkerStatus,
 };
 use golem_common::SafeDisplay;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
@@ -36,6 +40,7 @@ pub struct WorkerCreationRequest {
     pub name: String,
     pub args: Vec<String>,
     pub env: HashMap<String, String>,
+    pub quotas: Option<WorkerQuotas>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
@@ -1011,6 +1016,225 @@ pub struct ResumeResponse {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct UpdateWorkerResponse {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Union)]
+#[serde(rename_all = "camelCase")]
+#[oai(discriminator_name = "type", one_of = true, rename_all = "camelCase")]
+pub enum InvocationSchedule {
+    Once(OnceSchedule),
+    Interval(IntervalSchedule),
+    Cron(CronSchedule),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct OnceSchedule {
+    pub at: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct IntervalSchedule {
+    pub period_millis: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct CronSchedule {
+    pub expression: String,
+}
+
+impl From<InvocationSchedule> for golem_common::model::InvocationSchedule {
+    fn from(value: InvocationSchedule) -> Self {
+        match value {
+            InvocationSchedule::Once(OnceSchedule { at }) => Self::Once { at },
+            InvocationSchedule::Interval(IntervalSchedule { period_millis }) => Self::Interval {
+                period: std::time::Duration::from_millis(period_millis),
+            },
+            InvocationSchedule::Cron(CronSchedule { expression }) => Self::Cron { expression },
+        }
+    }
+}
+
+impl From<golem_common::model::InvocationSchedule> for InvocationSchedule {
+    fn from(value: golem_common::model::InvocationSchedule) -> Self {
+        match value {
+            golem_common::model::InvocationSchedule::Once { at } => Self::Once(OnceSchedule { at }),
+            golem_common::model::InvocationSchedule::Interval { period } => {
+                Self::Interval(IntervalSchedule {
+                    period_millis: period.as_millis() as u64,
+                })
+            }
+            golem_common::model::InvocationSchedule::Cron { expression } => {
+                Self::Cron(CronSchedule { expression })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ScheduleInvocationRequest {
+    pub function: String,
+    pub params: Vec<TypeAnnotatedValue>,
+    pub schedule: InvocationSchedule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ScheduledInvocation {
+    pub schedule_id: ScheduledInvocationId,
+    pub worker_id: WorkerId,
+    pub function: String,
+    pub schedule: InvocationSchedule,
+    pub next_invocation_at: Timestamp,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::ScheduledInvocation> for ScheduledInvocation {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::ScheduledInvocation,
+    ) -> Result<Self, Self::Error> {
+        let schedule: golem_common::model::InvocationSchedule = value
+            .schedule
+            .ok_or("Missing field: schedule")?
+            .try_into()?;
+        Ok(Self {
+            schedule_id: value.id.ok_or("Missing field: id")?.try_into()?,
+            worker_id: value
+                .worker_id
+                .ok_or("Missing field: worker_id")?
+                .try_into()?,
+            function: value.function_name,
+            schedule: schedule.into(),
+            next_invocation_at: value
+                .next_invocation_at
+                .ok_or("Missing field: next_invocation_at")?
+                .into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ScheduledInvocationsResponse {
+    pub invocations: Vec<ScheduledInvocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct CancelScheduledInvocationResponse {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct DeadLetteredInvocation {
+    pub dead_letter_id: DeadLetteredInvocationId,
+    pub worker_id: WorkerId,
+    pub idempotency_key: String,
+    pub function: String,
+    pub params: Vec<TypeAnnotatedValue>,
+    pub error: String,
+    pub failed_at: Timestamp,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::DeadLetteredInvocation>
+    for DeadLetteredInvocation
+{
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::DeadLetteredInvocation,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            dead_letter_id: value.id.ok_or("Missing field: id")?.try_into()?,
+            worker_id: value
+                .worker_id
+                .ok_or("Missing field: worker_id")?
+                .try_into()?,
+            idempotency_key: value
+                .idempotency_key
+                .ok_or("Missing field: idempotency_key")?
+                .value,
+            function: value.function_name,
+            params: value
+                .input
+                .into_iter()
+                .map(|param| {
+                    param
+                        .type_annotated_value
+                        .ok_or("Missing field: type_annotated_value".to_string())
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            error: value.error,
+            failed_at: value.failed_at.ok_or("Missing field: failed_at")?.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+pub struct DeadLetteredInvocationsResponse {
+    pub invocations: Vec<DeadLetteredInvocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct RetryDeadLetteredInvocationResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct DiscardDeadLetteredInvocationResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct TopicSubscriptionsResponse {
+    pub subscriptions: Vec<TopicSubscription>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SubscribeToTopicRequest {
+    pub topic: String,
+    pub function_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct SubscribeToTopicResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct UnsubscribeFromTopicResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PublishToTopicResponse {
+    pub message_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ForkWorkerRequest {
+    pub target_worker_name: String,
+    pub oplog_index_cutoff: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct ForkWorkerResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct RevertWorkerResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct PutFileResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct DeleteFileResponse {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct MakeDirectoryResponse {}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -1242,6 +1466,8 @@ pub struct WorkerMetadata {
     pub component_size: u64,
     pub total_linear_memory_size: u64,
     pub owned_resources: HashMap<u64, ResourceMetadata>,
+    pub quotas: WorkerQuotas,
+    pub quota_usage: WorkerQuotaUsage,
 }
 
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMetadata {
@@ -1272,6 +1498,8 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMet
                 .into_iter()
                 .map(|(k, v)| v.try_into().map(|v| (k, v)))
                 .collect::<Result<HashMap<_, _>, _>>()?,
+            quotas: value.quotas.map(|q| q.into()).unwrap_or_default(),
+            quota_usage: value.quota_usage.map(|u| u.into()).unwrap_or_default(),
         })
     }
 }
@@ -1299,6 +1527,8 @@ impl From<WorkerMetadata> for golem_api_grpc::proto::golem::worker::WorkerMetada
                 .into_iter()
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
+            quotas: Some(value.quotas.into()),
+            quota_usage: Some(value.quota_usage.into()),
         }
     }
 }
@@ -1441,6 +1671,43 @@ impl From<ResourceMetadata> for golem_api_grpc::proto::golem::worker::ResourceMe
     }
 }
 
+/// Resource usage of a worker, to be compared with its quotas. The optional values are only
+/// tracked while the worker is loaded and has a quota set for them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct WorkerQuotaUsage {
+    pub memory: u64,
+    pub oplog_entries: u64,
+    pub oplog_bytes: Option<u64>,
+    pub fuel_consumed_by_last_invocation: Option<u64>,
+    pub outgoing_http_calls_in_last_minute: Option<u32>,
+}
+
+impl From<golem_api_grpc::proto::golem::worker::WorkerQuotaUsage> for WorkerQuotaUsage {
+    fn from(value: golem_api_grpc::proto::golem::worker::WorkerQuotaUsage) -> Self {
+        Self {
+            memory: value.memory,
+            oplog_entries: value.oplog_entries,
+            oplog_bytes: value.oplog_bytes,
+            fuel_consumed_by_last_invocation: value.fuel_consumed_by_last_invocation,
+            outgoing_http_calls_in_last_minute: value.outgoing_http_calls_in_last_minute,
+        }
+    }
+}
+
+impl From<WorkerQuotaUsage> for golem_api_grpc::proto::golem::worker::WorkerQuotaUsage {
+    fn from(value: WorkerQuotaUsage) -> Self {
+        Self {
+            memory: value.memory,
+            oplog_entries: value.oplog_entries,
+            oplog_bytes: value.oplog_bytes,
+            fuel_consumed_by_last_invocation: value.fuel_consumed_by_last_invocation,
+            outgoing_http_calls_in_last_minute: value.outgoing_http_calls_in_last_minute,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
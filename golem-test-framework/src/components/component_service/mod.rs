@@ -172,6 +172,7 @@ pub trait ComponentService {
                 project_id: None,
                 component_name: name.to_string(),
                 component_type: Some(component_type as i32),
+                quotas: None,
             })),
         }];
 
@@ -276,6 +277,7 @@ pub trait ComponentService {
                 UpdateComponentRequestHeader {
                     component_id: Some(component_id.clone().into()),
                     component_type: Some(component_type as i32),
+                    quotas: None,
                 },
             )),
         }];
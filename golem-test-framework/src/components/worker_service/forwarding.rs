@@ -27,11 +27,12 @@ use golem_api_grpc::proto::golem::worker::v1::{
     InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
     InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest,
     InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest,
+    LaunchNewWorkerSuccessResponse, PutFileRequest, PutFileResponse, ResumeWorkerRequest,
+    ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest,
     SearchOplogResponse, SearchOplogSuccessResponse, UpdateWorkerRequest, UpdateWorkerResponse,
-    WorkerError,
+    WatchWorkersRequest, WorkerError,
 };
-use golem_api_grpc::proto::golem::worker::{InvokeResult, LogEvent, WorkerId};
+use golem_api_grpc::proto::golem::worker::{InvokeResult, LogEvent, WorkerId, WorkerStatusChange};
 use golem_api_grpc::proto::golem::workerexecutor::v1::CreateWorkerRequest;
 use golem_api_grpc::proto::golem::{worker, workerexecutor};
 use golem_common::model::AccountId;
@@ -106,6 +107,7 @@ impl WorkerService for ForwardingWorkerService {
                     component_version: latest_component_version,
                     args: request.args.clone(),
                     env: request.env.clone(),
+                    quotas: request.quotas.clone(),
                     account_id: Some(
                         AccountId {
                             value: "test-account".to_string(),
@@ -438,6 +440,40 @@ impl WorkerService for ForwardingWorkerService {
         Ok(result)
     }
 
+    async fn watch_workers(
+        &self,
+        request: WatchWorkersRequest,
+    ) -> crate::Result<Streaming<WorkerStatusChange>> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .watch_workers(workerexecutor::v1::WatchWorkersRequest {
+                    component_id: request.component_id.clone(),
+                    filter: request.filter.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        Ok(result)
+    }
+
     async fn resume_worker(
         &self,
         request: ResumeWorkerRequest,
@@ -600,6 +636,108 @@ impl WorkerService for ForwardingWorkerService {
         }
     }
 
+    async fn revert_worker(
+        &self,
+        request: RevertWorkerRequest,
+    ) -> crate::Result<RevertWorkerResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .revert_worker(workerexecutor::v1::RevertWorkerRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    target: request.target.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor revert-worker call"
+            )),
+            Some(workerexecutor::v1::revert_worker_response::Result::Success(_)) => {
+                Ok(RevertWorkerResponse {
+                    result: Some(worker::v1::revert_worker_response::Result::Success(
+                        Empty {},
+                    )),
+                })
+            }
+            Some(workerexecutor::v1::revert_worker_response::Result::Failure(error)) => {
+                Ok(RevertWorkerResponse {
+                    result: Some(worker::v1::revert_worker_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
+    async fn put_file(&self, request: PutFileRequest) -> crate::Result<PutFileResponse> {
+        let mut retry_count = Self::RETRY_COUNT;
+        let result = loop {
+            let result = self
+                .worker_executor
+                .client()
+                .await?
+                .put_file(workerexecutor::v1::PutFileRequest {
+                    worker_id: request.worker_id.clone(),
+                    account_id: Some(
+                        AccountId {
+                            value: "test-account".to_string(),
+                        }
+                        .into(),
+                    ),
+                    path: request.path.clone(),
+                    content: request.content.clone(),
+                })
+                .await;
+
+            if Self::should_retry(&mut retry_count, &result) {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                continue;
+            } else {
+                break result;
+            }
+        };
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor put-file call"
+            )),
+            Some(workerexecutor::v1::put_file_response::Result::Success(_)) => {
+                Ok(PutFileResponse {
+                    result: Some(worker::v1::put_file_response::Result::Success(Empty {})),
+                })
+            }
+            Some(workerexecutor::v1::put_file_response::Result::Failure(error)) => {
+                Ok(PutFileResponse {
+                    result: Some(worker::v1::put_file_response::Result::Error(WorkerError {
+                        error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                    })),
+                })
+            }
+        }
+    }
+
     async fn get_oplog(&self, request: GetOplogRequest) -> crate::Result<GetOplogResponse> {
         let mut retry_count = Self::RETRY_COUNT;
         let result = loop {
@@ -24,9 +24,9 @@ use tracing::Level;
 
 use golem_api_grpc::proto::golem::worker::v1::worker_service_client::WorkerServiceClient;
 use golem_api_grpc::proto::golem::worker::v1::{
-    ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse, GetFileRequest, GetFileResponse, GetFilesRequest, GetFilesResponse, GetOplogRequest, GetOplogResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse, InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse
+    ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse, GetFileRequest, GetFileResponse, GetFilesRequest, GetFilesResponse, GetOplogRequest, GetOplogResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse, InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse, PutFileRequest, PutFileResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse, WatchWorkersRequest
 };
-use golem_api_grpc::proto::golem::worker::LogEvent;
+use golem_api_grpc::proto::golem::worker::{LogEvent, WorkerStatusChange};
 
 use crate::components::component_service::ComponentService;
 use crate::components::rdb::Rdb;
@@ -143,6 +143,18 @@ pub trait WorkerService {
             .into_inner())
     }
 
+    async fn watch_workers(
+        &self,
+        request: WatchWorkersRequest,
+    ) -> crate::Result<Streaming<WorkerStatusChange>> {
+        Ok(self
+            .client()
+            .await?
+            .watch_workers(request)
+            .await?
+            .into_inner())
+    }
+
     async fn resume_worker(
         &self,
         request: ResumeWorkerRequest,
@@ -195,6 +207,18 @@ pub trait WorkerService {
             .into_inner())
     }
 
+    async fn revert_worker(
+        &self,
+        request: RevertWorkerRequest,
+    ) -> crate::Result<RevertWorkerResponse> {
+        Ok(self
+            .client()
+            .await?
+            .revert_worker(request)
+            .await?
+            .into_inner())
+    }
+
     async fn get_files(&self, request: GetFilesRequest) -> crate::Result<GetFilesResponse> {
         Ok(self.client().await?.get_files(request).await?.into_inner())
     }
@@ -203,6 +227,10 @@ pub trait WorkerService {
         Ok(self.client().await?.get_file(request).await?.into_inner())
     }
 
+    async fn put_file(&self, request: PutFileRequest) -> crate::Result<PutFileResponse> {
+        Ok(self.client().await?.put_file(request).await?.into_inner())
+    }
+
     fn private_host(&self) -> String;
     fn private_http_port(&self) -> u16;
     fn private_grpc_port(&self) -> u16;
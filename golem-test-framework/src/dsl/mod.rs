@@ -20,7 +20,7 @@ use async_trait::async_trait;
 use golem_api_grpc::proto::golem::worker::update_record::Update;
 use golem_api_grpc::proto::golem::worker::v1::worker_error::Error;
 use golem_api_grpc::proto::golem::worker::v1::{
-    get_files_response, get_oplog_response, get_worker_metadata_response, get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response, invoke_response, launch_new_worker_response, resume_worker_response, search_oplog_response, update_worker_response, worker_execution_error, ConnectWorkerRequest, DeleteWorkerRequest, GetFileRequest, GetFileResponse, GetFilesRequest, GetOplogRequest, GetWorkerMetadataRequest, GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitRequest, InvokeRequest, LaunchNewWorkerRequest, ResumeWorkerRequest, SearchOplogRequest, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError, WorkerExecutionError
+    get_files_response, get_oplog_response, get_worker_metadata_response, get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response, invoke_response, launch_new_worker_response, put_file_response, resume_worker_response, revert_worker_response, search_oplog_response, update_worker_response, worker_execution_error, ConnectWorkerRequest, DeleteWorkerRequest, GetFileRequest, GetFileResponse, GetFilesRequest, GetOplogRequest, GetWorkerMetadataRequest, GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitRequest, InvokeRequest, LaunchNewWorkerRequest, PutFileRequest, PutFileResponse, ResumeWorkerRequest, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError, WorkerExecutionError
 };
 use golem_api_grpc::proto::golem::worker::{
     log_event, InvokeParameters, LogEvent, StdErrLog, StdOutLog, UpdateMode,
@@ -32,9 +32,9 @@ use golem_common::model::oplog::{
 use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_common::model::regions::DeletedRegions;
 use golem_common::model::{
-    ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord, IdempotencyKey, ScanCursor,
-    SuccessfulUpdateRecord, TargetWorkerId, WorkerFilter, WorkerId, WorkerMetadata,
-    WorkerResourceDescription, WorkerStatusRecord,
+    ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord, IdempotencyKey,
+    RevertWorkerTarget, ScanCursor, SuccessfulUpdateRecord, TargetWorkerId, WorkerFilter, WorkerId,
+    WorkerMetadata, WorkerResourceDescription, WorkerStatusRecord,
 };
 use golem_service_base::model::{FileSystemNode, PublicOplogEntryWithIndex};
 use golem_wasm_rpc::Value;
@@ -168,6 +168,7 @@ pub trait TestDsl {
         worker_id: &WorkerId,
         target_version: ComponentVersion,
     ) -> crate::Result<()>;
+    async fn revert(&self, worker_id: &WorkerId, target: RevertWorkerTarget) -> crate::Result<()>;
     async fn get_oplog(
         &self,
         worker_id: &WorkerId,
@@ -189,6 +190,13 @@ pub trait TestDsl {
         worker_id: &WorkerId,
         path: &Path,
     ) -> crate::Result<Streaming<GetFileResponse>>;
+
+    async fn put_file(
+        &self,
+        worker_id: &WorkerId,
+        path: &Path,
+        content: Vec<u8>,
+    ) -> crate::Result<()>;
 }
 
 #[async_trait]
@@ -295,6 +303,7 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
                 name: name.to_string(),
                 args,
                 env,
+                quotas: None,
             })
             .await?;
 
@@ -793,6 +802,26 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
         }
     }
 
+    async fn revert(&self, worker_id: &WorkerId, target: RevertWorkerTarget) -> crate::Result<()> {
+        let response = self
+            .worker_service()
+            .revert_worker(RevertWorkerRequest {
+                worker_id: Some(worker_id.clone().into()),
+                target: Some(target.into()),
+            })
+            .await?;
+
+        match response {
+            RevertWorkerResponse {
+                result: Some(revert_worker_response::Result::Success(_)),
+            } => Ok(()),
+            RevertWorkerResponse {
+                result: Some(revert_worker_response::Result::Error(error)),
+            } => Err(anyhow!("Failed to revert worker: {error:?}")),
+            _ => Err(anyhow!("Failed to revert worker: unknown error")),
+        }
+    }
+
     async fn get_oplog(
         &self,
         worker_id: &WorkerId,
@@ -929,6 +958,32 @@ impl<T: TestDependencies + Send + Sync> TestDsl for T {
 
         Ok(response)
     }
+
+    async fn put_file(
+        &self,
+        worker_id: &WorkerId,
+        path: &Path,
+        content: Vec<u8>,
+    ) -> crate::Result<()> {
+        let response = self
+            .worker_service()
+            .put_file(PutFileRequest {
+                worker_id: Some(worker_id.clone().into()),
+                path: path.to_string_lossy().into_owned(),
+                content,
+            })
+            .await?;
+
+        match response {
+            PutFileResponse {
+                result: Some(put_file_response::Result::Success(_)),
+            } => Ok(()),
+            PutFileResponse {
+                result: Some(put_file_response::Result::Error(error)),
+            } => Err(anyhow!("Failed to put file: {error:?}")),
+            _ => Err(anyhow!("Failed to put file: unknown error")),
+        }
+    }
 }
 
 pub fn stdout_events(events: impl Iterator<Item = LogEvent>) -> Vec<String> {
@@ -1147,6 +1202,11 @@ pub fn to_worker_metadata(
                 .expect("no created_at")
                 .clone()
                 .into(),
+            quotas: metadata
+                .quotas
+                .clone()
+                .map(|quotas| quotas.into())
+                .unwrap_or_default(),
             last_known_status: WorkerStatusRecord {
                 oplog_idx: OplogIndex::default(),
                 status: metadata.status.try_into().expect("invalid status"),
@@ -1369,6 +1429,7 @@ pub trait TestDslUnsafe {
     async fn simulated_crash(&self, worker_id: &WorkerId);
     async fn auto_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
     async fn manual_update_worker(&self, worker_id: &WorkerId, target_version: ComponentVersion);
+    async fn revert(&self, worker_id: &WorkerId, target: RevertWorkerTarget);
     async fn get_oplog(&self, worker_id: &WorkerId, from: OplogIndex) -> Vec<PublicOplogEntry>;
     async fn search_oplog(
         &self,
@@ -1384,6 +1445,7 @@ pub trait TestDslUnsafe {
         worker_id: &WorkerId,
         path: &Path,
     ) -> Streaming<GetFileResponse>;
+    async fn put_file(&self, worker_id: &WorkerId, path: &Path, content: Vec<u8>);
 }
 
 #[async_trait]
@@ -1595,6 +1657,12 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .expect("Failed to update worker")
     }
 
+    async fn revert(&self, worker_id: &WorkerId, target: RevertWorkerTarget) {
+        <T as TestDsl>::revert(self, worker_id, target)
+            .await
+            .expect("Failed to revert worker")
+    }
+
     async fn get_oplog(&self, worker_id: &WorkerId, from: OplogIndex) -> Vec<PublicOplogEntry> {
         <T as TestDsl>::get_oplog(self, worker_id, from)
             .await
@@ -1629,4 +1697,10 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .await
             .expect("Failed to get file")
     }
+
+    async fn put_file(&self, worker_id: &WorkerId, path: &Path, content: Vec<u8>) {
+        <T as TestDsl>::put_file(self, worker_id, path, content)
+            .await
+            .expect("Failed to put file")
+    }
 }
@@ -4,14 +4,45 @@ use std::path::Path;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let golem_wit_root = find_package_root("golem-wit");
+    let manifest_dir = var_os("CARGO_MANIFEST_DIR").unwrap();
     let out_dir = var_os("OUT_DIR").unwrap();
     let target_file = Path::new(&out_dir).join("preview2_mod.rs");
 
-    std::fs::write(target_file.clone(), preview2_mod_gen(&golem_wit_root)).unwrap();
+    // The golem:ext package (see wit/golem-ext.wit) is not part of the pinned golem-wit release,
+    // so it gets added to a copy of its WIT definitions
+    let wit_root = Path::new(&out_dir).join("wit");
+    if wit_root.exists() {
+        std::fs::remove_dir_all(&wit_root)?;
+    }
+    copy_dir(&Path::new(&golem_wit_root).join("wit"), &wit_root)?;
+    copy_dir(
+        &Path::new(&manifest_dir).join("wit"),
+        &wit_root.join("deps").join("golem-ext"),
+    )?;
+
+    std::fs::write(
+        target_file.clone(),
+        preview2_mod_gen(&wit_root.to_string_lossy()),
+    )
+    .unwrap();
 
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=wit");
+
+    Ok(())
+}
 
+fn copy_dir(source: &Path, target: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(target)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target.join(entry.file_name()))?;
+        } else {
+            std::fs::copy(entry.path(), target.join(entry.file_name()))?;
+        }
+    }
     Ok(())
 }
 
@@ -50,14 +81,18 @@ fn find_package_root(name: &str) -> String {
     package.manifest_path.parent().unwrap().to_string()
 }
 
-fn preview2_mod_gen(golem_wit_path: &str) -> String {
+fn preview2_mod_gen(wit_path: &str) -> String {
     format!(
         r#"wasmtime::component::bindgen!({{
-        path: "{golem_wit_path}/wit",
+        path: "{wit_path}",
         interfaces: "
           import golem:api/host@0.2.0;
           import golem:api/host@1.1.0-rc1;
           import golem:api/oplog@1.1.0-rc1;
+          import golem:ext/scheduling@0.1.0;
+          import golem:ext/promise@0.1.0;
+          import golem:ext/pubsub@0.1.0;
+          import golem:ext/lock@0.1.0;
 
           import wasi:blobstore/blobstore;
           import wasi:blobstore/container;
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::SystemTime;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use wasmtime::component::Resource;
 
-use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::serialized::{SerializableDateTime, SerializableError};
 use crate::durable_host::{Durability, DurableWorkerCtx};
 use crate::metrics::wasm::record_host_function_call;
 use crate::services::oplog::CommitLevel;
@@ -23,6 +26,8 @@ use crate::workerctx::WorkerCtx;
 use golem_common::model::oplog::WrappedFunctionType;
 use wasmtime_wasi::bindings::clocks::monotonic_clock::{Duration, Host, Instant, Pollable};
 
+pub(crate) const SLEEP_DEADLINE: &str = "golem monotonic_clock::sleep_deadline";
+
 #[async_trait]
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     async fn now(&mut self) -> anyhow::Result<Instant> {
@@ -69,8 +74,40 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         )
         .await?;
         self.state.oplog.commit(CommitLevel::DurableOnly).await;
-        let when = now.saturating_add(when);
-        Host::subscribe_instant(&mut self.as_wasi_view(), when).await
+        let pollable =
+            Host::subscribe_instant(&mut self.as_wasi_view(), now.saturating_add(when)).await?;
+
+        // Long sleeps suspend the worker, so their deadline is persisted as wall clock time to
+        // survive the worker's recovery
+        if std::time::Duration::from_nanos(when) >= self.state.config.suspend.suspend_after
+            && (self.state.is_live()
+                || self
+                    .state
+                    .replay_state
+                    .is_next_imported_function(SLEEP_DEADLINE)
+                    .await)
+        {
+            let deadline =
+                Durability::<Ctx, Duration, SerializableDateTime, SerializableError>::wrap(
+                    self,
+                    WrappedFunctionType::ReadLocal,
+                    SLEEP_DEADLINE,
+                    when,
+                    |_| {
+                        Box::pin(async move {
+                            Ok::<_, anyhow::Error>(
+                                SystemTime::now() + std::time::Duration::from_nanos(when),
+                            )
+                        })
+                    },
+                )
+                .await?;
+            self.state
+                .sleep_deadlines
+                .insert(pollable.rep(), DateTime::<Utc>::from(deadline));
+        }
+
+        Ok(pollable)
     }
 }
 
@@ -56,6 +56,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
         offset: Filesize,
     ) -> Result<Resource<OutputStream>, FsError> {
         record_host_function_call("filesystem::types::descriptor", "write_via_stream");
+        self.state.file_system_modified = true;
         HostDescriptor::write_via_stream(&mut self.as_wasi_view(), self_, offset)
     }
 
@@ -64,6 +65,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
         self_: Resource<Descriptor>,
     ) -> Result<Resource<OutputStream>, FsError> {
         record_host_function_call("filesystem::types::descriptor", "append_via_stream");
+        self.state.file_system_modified = true;
         HostDescriptor::append_via_stream(&mut self.as_wasi_view(), self_)
     }
 
@@ -119,6 +121,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "set_size");
+        self.state.file_system_modified = true;
         HostDescriptor::set_size(&mut self.as_wasi_view(), self_, size).await
     }
 
@@ -167,6 +170,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "write");
+        self.state.file_system_modified = true;
         HostDescriptor::write(&mut self.as_wasi_view(), self_, buffer, offset).await
     }
 
@@ -212,6 +216,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "create_directory_at");
+        self.state.file_system_modified = true;
         HostDescriptor::create_directory_at(&mut self.as_wasi_view(), self_, path).await
     }
 
@@ -365,6 +370,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "link_at");
+        self.state.file_system_modified = true;
         HostDescriptor::link_at(
             &mut self.as_wasi_view(),
             self_,
@@ -389,6 +395,11 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "open_at");
+        if open_flags.intersects(OpenFlags::CREATE | OpenFlags::TRUNCATE)
+            || flags.contains(DescriptorFlags::WRITE)
+        {
+            self.state.file_system_modified = true;
+        }
         HostDescriptor::open_at(
             &mut self.as_wasi_view(),
             self_,
@@ -423,6 +434,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "remove_directory_at");
+        self.state.file_system_modified = true;
         HostDescriptor::remove_directory_at(&mut self.as_wasi_view(), self_, path.clone()).await
     }
 
@@ -438,6 +450,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "rename_at");
+        self.state.file_system_modified = true;
         HostDescriptor::rename_at(
             &mut self.as_wasi_view(),
             self_,
@@ -459,6 +472,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "symlink_at");
+        self.state.file_system_modified = true;
         HostDescriptor::symlink_at(&mut self.as_wasi_view(), self_, old_path, new_path.clone())
             .await
     }
@@ -473,6 +487,7 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
             .await
             .map_err(FsError::trap)?;
         record_host_function_call("filesystem::types::descriptor", "unlink_file_at");
+        self.state.file_system_modified = true;
         HostDescriptor::unlink_file_at(&mut self.as_wasi_view(), self_, path.clone()).await
     }
 
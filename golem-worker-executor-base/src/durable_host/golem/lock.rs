@@ -0,0 +1,292 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use golem_common::model::oplog::{OplogIndex, WrappedFunctionType};
+use tracing::debug;
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::error::GolemError;
+use crate::metrics::wasm::record_host_function_call;
+use crate::model::InterruptKind;
+use crate::preview2::golem;
+use crate::preview2::golem::ext::lock::Host;
+use crate::services::lock;
+use crate::services::lock::LockAcquisition;
+use crate::workerctx::WorkerCtx;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn acquire_lock(&mut self, name: String, lease: u64) -> anyhow::Result<()> {
+        let oplog_idx = golem::api0_2_0::host::Host::get_oplog_index(self).await?;
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "acquire_lock");
+        self.acquire_permit(oplog_idx, name, 1, Duration::from_nanos(lease))
+            .await
+    }
+
+    async fn try_acquire_lock(&mut self, name: String, lease: u64) -> anyhow::Result<bool> {
+        let oplog_idx = golem::api0_2_0::host::Host::get_oplog_index(self).await?;
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "try_acquire_lock");
+        self.try_acquire_permit(oplog_idx, name, 1, Duration::from_nanos(lease))
+            .await
+    }
+
+    async fn release_lock(&mut self, name: String) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "release_lock");
+        self.release_permit(name).await
+    }
+
+    async fn acquire_semaphore(
+        &mut self,
+        name: String,
+        permits: u64,
+        lease: u64,
+    ) -> anyhow::Result<()> {
+        let oplog_idx = golem::api0_2_0::host::Host::get_oplog_index(self).await?;
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "acquire_semaphore");
+        self.acquire_permit(oplog_idx, name, permits, Duration::from_nanos(lease))
+            .await
+    }
+
+    async fn try_acquire_semaphore(
+        &mut self,
+        name: String,
+        permits: u64,
+        lease: u64,
+    ) -> anyhow::Result<bool> {
+        let oplog_idx = golem::api0_2_0::host::Host::get_oplog_index(self).await?;
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "try_acquire_semaphore");
+        self.try_acquire_permit(oplog_idx, name, permits, Duration::from_nanos(lease))
+            .await
+    }
+
+    async fn release_semaphore(&mut self, name: String) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "release_semaphore");
+        self.release_permit(name).await
+    }
+
+    async fn renew_lease(&mut self, name: String, lease: u64) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::lock", "renew_lease");
+        let lease = Duration::from_nanos(lease);
+
+        let renewed = Durability::<Ctx, (String, u64), bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_renew_semaphore_lease",
+            (name.clone(), lease.as_millis() as u64),
+            |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .lock_service
+                        .renew(&ctx.owned_worker_id, &name, lease)
+                        .await
+                })
+            },
+        )
+        .await?;
+        Ok(renewed)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
+    async fn acquire_lock(&mut self, name: String, lease: u64) -> anyhow::Result<()> {
+        (*self).acquire_lock(name, lease).await
+    }
+
+    async fn try_acquire_lock(&mut self, name: String, lease: u64) -> anyhow::Result<bool> {
+        (*self).try_acquire_lock(name, lease).await
+    }
+
+    async fn release_lock(&mut self, name: String) -> anyhow::Result<bool> {
+        (*self).release_lock(name).await
+    }
+
+    async fn acquire_semaphore(
+        &mut self,
+        name: String,
+        permits: u64,
+        lease: u64,
+    ) -> anyhow::Result<()> {
+        (*self).acquire_semaphore(name, permits, lease).await
+    }
+
+    async fn try_acquire_semaphore(
+        &mut self,
+        name: String,
+        permits: u64,
+        lease: u64,
+    ) -> anyhow::Result<bool> {
+        (*self).try_acquire_semaphore(name, permits, lease).await
+    }
+
+    async fn release_semaphore(&mut self, name: String) -> anyhow::Result<bool> {
+        (*self).release_semaphore(name).await
+    }
+
+    async fn renew_lease(&mut self, name: String, lease: u64) -> anyhow::Result<bool> {
+        (*self).renew_lease(name, lease).await
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Takes a permit of a semaphore, or suspends the worker until it can take one.
+    ///
+    /// Every attempt is persisted with its outcome. A blocked attempt registers a new promise as
+    /// a waiter, which gets completed when a permit is released or when the earliest lease of
+    /// the current holders expires. When the worker gets resumed, the blocked attempts are
+    /// replayed and it tries to take a permit again.
+    async fn acquire_permit(
+        &mut self,
+        oplog_idx: u64,
+        name: String,
+        permits: u64,
+        lease: Duration,
+    ) -> anyhow::Result<()> {
+        let acquired_at = OplogIndex::from_u64(oplog_idx);
+        loop {
+            let replaying = self.state.is_replay();
+            let semaphore = name.clone();
+            let acquired = Durability::<Ctx, (String, u64, u64), bool, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "golem_acquire_semaphore",
+                (name.clone(), permits, lease.as_millis() as u64),
+                |ctx| {
+                    Box::pin(async move {
+                        // The promise of an earlier attempt may already be completed, so every
+                        // attempt waits on a new one
+                        let waiter = ctx
+                            .public_state
+                            .promise_service
+                            .create(
+                                &ctx.owned_worker_id.worker_id,
+                                ctx.state.current_oplog_index().await,
+                            )
+                            .await;
+                        let account_id = ctx.owned_worker_id.account_id.clone();
+                        // Registering before trying, so a release in between is not missed
+                        ctx.state
+                            .lock_service
+                            .add_waiter(&account_id, &semaphore, &waiter)
+                            .await?;
+                        match ctx
+                            .state
+                            .lock_service
+                            .try_acquire(
+                                &ctx.owned_worker_id,
+                                acquired_at,
+                                &semaphore,
+                                permits,
+                                lease,
+                            )
+                            .await?
+                        {
+                            LockAcquisition::Acquired => {
+                                ctx.state
+                                    .lock_service
+                                    .remove_waiter(&account_id, &semaphore, &waiter)
+                                    .await?;
+                                Ok::<bool, GolemError>(true)
+                            }
+                            LockAcquisition::Blocked { until } => {
+                                if let Some(until) = until {
+                                    ctx.state.complete_promise_at(waiter, until).await;
+                                }
+                                Ok(false)
+                            }
+                        }
+                    })
+                },
+            )
+            .await?;
+
+            if acquired {
+                return Ok(());
+            } else if !replaying {
+                debug!("Suspending worker until semaphore {name} gets released");
+                return Err(InterruptKind::Suspend.into());
+            }
+            // The worker got resumed after this attempt was blocked, so it tries again
+        }
+    }
+
+    async fn try_acquire_permit(
+        &mut self,
+        oplog_idx: u64,
+        name: String,
+        permits: u64,
+        lease: Duration,
+    ) -> anyhow::Result<bool> {
+        let acquired_at = OplogIndex::from_u64(oplog_idx);
+        let acquired = Durability::<Ctx, (String, u64, u64), bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_try_acquire_semaphore",
+            (name.clone(), permits, lease.as_millis() as u64),
+            |ctx| {
+                Box::pin(async move {
+                    let acquisition = ctx
+                        .state
+                        .lock_service
+                        .try_acquire(&ctx.owned_worker_id, acquired_at, &name, permits, lease)
+                        .await?;
+                    Ok::<bool, GolemError>(acquisition == LockAcquisition::Acquired)
+                })
+            },
+        )
+        .await?;
+        Ok(acquired)
+    }
+
+    async fn release_permit(&mut self, name: String) -> anyhow::Result<bool> {
+        let released = Durability::<Ctx, String, bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_release_semaphore",
+            name.clone(),
+            |ctx| {
+                Box::pin(async move {
+                    let released = ctx
+                        .state
+                        .lock_service
+                        .release(&ctx.owned_worker_id, &name)
+                        .await?;
+                    if released {
+                        lock::wake_waiters(
+                            &ctx.state.lock_service,
+                            &ctx.state.scheduler_service,
+                            &ctx.owned_worker_id.account_id,
+                            &name,
+                        )
+                        .await?;
+                    }
+                    Ok::<bool, GolemError>(released)
+                })
+            },
+        )
+        .await?;
+        Ok(released)
+    }
+}
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod lock;
+pub mod promise;
+pub mod pubsub;
+pub mod scheduling;
 pub mod v11;
 
 use anyhow::anyhow;
@@ -258,11 +262,11 @@ impl<Ctx: WorkerCtx> golem::api0_2_0::host::Host for DurableWorkerCtx<Ctx> {
         } else if self
             .state
             .replay_state
-            .is_in_deleted_region(jump_target)
+            .is_in_deleted_region(OplogIndex::from_u64(oplog_idx))
             .await
         {
             Err(anyhow!(
-                "Attempted to jump to a deleted region in oplog to index {jump_target} from {jump_source}"
+                "Attempted to jump to a deleted region in oplog to index {oplog_idx} from {jump_source}"
             ))
         } else if self.state.is_live() {
             let jump = OplogRegion {
@@ -344,7 +348,7 @@ impl<Ctx: WorkerCtx> golem::api0_2_0::host::Host for DurableWorkerCtx<Ctx> {
                     debug!("Worker's atomic operation starting at {} is not committed, ignoring persisted entries",  begin_index);
 
                     // We need to jump to the end of the oplog
-                    self.state.replay_state.switch_to_live();
+                    let file_system_changes = self.state.replay_state.switch_to_live().await;
 
                     // But this is not enough, because if the retried transactional block succeeds,
                     // and later we replay it, we need to skip the first attempt and only replay the second.
@@ -361,6 +365,14 @@ impl<Ctx: WorkerCtx> golem::api0_2_0::host::Host for DurableWorkerCtx<Ctx> {
                         .oplog
                         .add_and_commit(OplogEntry::jump(deleted_region))
                         .await;
+
+                    // File system changes made through the API in the deleted region are kept
+                    for change in file_system_changes {
+                        self.state
+                            .oplog
+                            .add_and_commit(OplogEntry::change_file_system(change))
+                            .await;
+                    }
                 }
             }
 
@@ -561,6 +573,15 @@ impl<Ctx: WorkerCtx> golem::api0_2_0::host::Host for DurableWorkerCtx<Ctx> {
     }
 }
 
+fn is_suspend<T>(result: &anyhow::Result<T>) -> bool {
+    match result {
+        Err(err) => {
+            err.root_cause().downcast_ref::<InterruptKind>() == Some(&InterruptKind::Suspend)
+        }
+        Ok(_) => false,
+    }
+}
+
 #[async_trait]
 impl<Ctx: WorkerCtx> HostGetWorkers for &mut DurableWorkerCtx<Ctx> {
     async fn new(
@@ -0,0 +1,256 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use chrono::Utc;
+use golem_common::model::oplog::{OplogIndex, WrappedFunctionType};
+use golem_common::model::{PromiseId, ScheduleId};
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::durable_host::golem::is_suspend;
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::metrics::wasm::record_host_function_call;
+use crate::model::InterruptKind;
+use crate::preview2::golem;
+use crate::preview2::golem::api1_1_0_rc1::host::PromiseId as HostPromiseId;
+use crate::preview2::golem::ext::promise::Host;
+use crate::workerctx::WorkerCtx;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn await_promise_with_timeout(
+        &mut self,
+        promise_id: HostPromiseId,
+        timeout: u64,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let oplog_idx = golem::api0_2_0::host::Host::get_oplog_index(self).await?;
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::promise", "await_promise_with_timeout");
+        let promise_id: PromiseId = promise_id.into();
+        let timeout = Duration::from_nanos(timeout);
+        let timer = self
+            .public_state
+            .promise_service
+            .create(
+                &self.owned_worker_id.worker_id,
+                OplogIndex::from_u64(oplog_idx),
+            )
+            .await;
+        let deadline = chrono::Duration::from_std(timeout)?;
+
+        // The timer's schedule is persisted, so it can be cancelled after a replay too
+        let schedule_id = Durability::<Ctx, (PromiseId, u64), ScheduleId, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteLocal,
+            "golem_schedule_promise_timeout",
+            (timer.clone(), timeout.as_millis() as u64),
+            |ctx| {
+                let timer = timer.clone();
+                Box::pin(async move {
+                    Ok(ctx
+                        .state
+                        .complete_promise_at(timer, Utc::now() + deadline)
+                        .await)
+                })
+            },
+        )
+        .await?;
+
+        let result = self
+            .await_promises(
+                "golem_await_promise_with_timeout",
+                vec![promise_id, timer.clone()],
+                promise_or_timeout,
+            )
+            .await?;
+
+        self.state.scheduler_service.cancel(schedule_id).await;
+        self.public_state.promise_service.delete(timer).await;
+        Ok(result)
+    }
+
+    async fn await_any_promise(
+        &mut self,
+        promise_ids: Vec<HostPromiseId>,
+    ) -> anyhow::Result<(u64, Vec<u8>)> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::promise", "await_any_promise");
+        if promise_ids.is_empty() {
+            return Err(anyhow!("Cannot await any of an empty list of promises"));
+        }
+
+        let promise_ids = promise_ids.into_iter().map(PromiseId::from).collect();
+        self.await_promises("golem_await_any_promise", promise_ids, first_completed)
+            .await
+    }
+
+    async fn await_all_promises(
+        &mut self,
+        promise_ids: Vec<HostPromiseId>,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::promise", "await_all_promises");
+        let promise_ids = promise_ids.into_iter().map(PromiseId::from).collect();
+        self.await_promises("golem_await_all_promises", promise_ids, all_completed)
+            .await
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
+    async fn await_promise_with_timeout(
+        &mut self,
+        promise_id: HostPromiseId,
+        timeout: u64,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        (*self)
+            .await_promise_with_timeout(promise_id, timeout)
+            .await
+    }
+
+    async fn await_any_promise(
+        &mut self,
+        promise_ids: Vec<HostPromiseId>,
+    ) -> anyhow::Result<(u64, Vec<u8>)> {
+        (*self).await_any_promise(promise_ids).await
+    }
+
+    async fn await_all_promises(
+        &mut self,
+        promise_ids: Vec<HostPromiseId>,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        (*self).await_all_promises(promise_ids).await
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Polls the given promises and passes their current results to `check`, which either
+    /// returns the awaited result or `None` if the worker has to be suspended until some of the
+    /// promises get completed.
+    ///
+    /// Only the final result is persisted, so when the worker gets resumed the promises are
+    /// polled again.
+    async fn await_promises<Success>(
+        &mut self,
+        function_name: &str,
+        promise_ids: Vec<PromiseId>,
+        check: fn(Vec<Option<Vec<u8>>>) -> Option<Success>,
+    ) -> anyhow::Result<Success>
+    where
+        Success: Encode + Decode + Clone + Debug + Send + Sync,
+    {
+        Durability::<Ctx, Vec<PromiseId>, Success, SerializableError>::wrap_conditionally(
+            self,
+            WrappedFunctionType::ReadLocal,
+            function_name,
+            promise_ids.clone(),
+            |ctx| {
+                Box::pin(async move {
+                    let mut results = Vec::with_capacity(promise_ids.len());
+                    for promise_id in &promise_ids {
+                        results.push(
+                            ctx.public_state
+                                .promise_service
+                                .poll(promise_id.clone())
+                                .await?,
+                        );
+                    }
+                    match check(results) {
+                        Some(result) => Ok(result),
+                        None => {
+                            debug!(
+                                "Suspending worker until some of {} get completed",
+                                promise_ids.iter().map(|id| id.to_string()).join(", ")
+                            );
+                            Err(InterruptKind::Suspend.into())
+                        }
+                    }
+                })
+            },
+            |result| !is_suspend(result), // We must not persist the suspend signal
+        )
+        .await
+    }
+}
+
+/// Result of a promise awaited together with its timer, `Some(None)` meaning a timeout
+fn promise_or_timeout(mut results: Vec<Option<Vec<u8>>>) -> Option<Option<Vec<u8>>> {
+    let timer = results.pop().flatten();
+    match (results.pop().flatten(), timer) {
+        (Some(result), _) => Some(Some(result)),
+        (None, Some(_)) => Some(None),
+        (None, None) => None,
+    }
+}
+
+fn first_completed(results: Vec<Option<Vec<u8>>>) -> Option<(u64, Vec<u8>)> {
+    results
+        .into_iter()
+        .enumerate()
+        .find_map(|(idx, result)| result.map(|result| (idx as u64, result)))
+}
+
+fn all_completed(results: Vec<Option<Vec<u8>>>) -> Option<Vec<Vec<u8>>> {
+    results.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::{all_completed, first_completed, promise_or_timeout};
+
+    #[test]
+    fn promise_completed_before_timeout() {
+        assert_eq!(
+            promise_or_timeout(vec![Some(vec![1]), None]),
+            Some(Some(vec![1]))
+        );
+        assert_eq!(
+            promise_or_timeout(vec![Some(vec![1]), Some(vec![])]),
+            Some(Some(vec![1]))
+        );
+    }
+
+    #[test]
+    fn promise_timed_out() {
+        assert_eq!(promise_or_timeout(vec![None, Some(vec![])]), Some(None));
+        assert_eq!(promise_or_timeout(vec![None, None]), None);
+    }
+
+    #[test]
+    fn first_completed_promise_wins() {
+        assert_eq!(first_completed(vec![None, None]), None);
+        assert_eq!(
+            first_completed(vec![None, Some(vec![2]), Some(vec![3])]),
+            Some((1, vec![2]))
+        );
+    }
+
+    #[test]
+    fn all_promises_have_to_complete() {
+        assert_eq!(all_completed(vec![Some(vec![1]), None]), None);
+        assert_eq!(
+            all_completed(vec![Some(vec![1]), Some(vec![2])]),
+            Some(vec![vec![1], vec![2]])
+        );
+    }
+}
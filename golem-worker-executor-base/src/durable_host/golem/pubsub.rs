@@ -0,0 +1,137 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::pubsub::TopicSubscription;
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::error::GolemError;
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem::ext::pubsub::Host;
+use crate::services::pubsub;
+use crate::workerctx::WorkerCtx;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn publish(&mut self, topic: String, payload: Vec<u8>) -> anyhow::Result<u64> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::pubsub", "publish");
+
+        let message_id = Durability::<Ctx, (String, Vec<u8>), u64, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_publish_to_topic",
+            (topic.clone(), payload.clone()),
+            |ctx| {
+                Box::pin(async move {
+                    let account_id = ctx.owned_worker_id.account_id.clone();
+                    let message_id = ctx
+                        .state
+                        .pubsub_service
+                        .publish(&account_id, &topic, payload)
+                        .await?;
+                    pubsub::deliver(
+                        &ctx.state.pubsub_service,
+                        &ctx.state.rpc,
+                        &ctx.state.scheduler_service,
+                        &account_id,
+                        &topic,
+                        ctx.state.config.scheduler.topic_redelivery_interval,
+                    )
+                    .await?;
+                    Ok::<u64, GolemError>(message_id)
+                })
+            },
+        )
+        .await?;
+        Ok(message_id)
+    }
+
+    async fn subscribe(&mut self, topic: String, function_name: String) -> anyhow::Result<()> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::pubsub", "subscribe");
+
+        let subscription = TopicSubscription {
+            topic,
+            worker_id: self.owned_worker_id.worker_id.clone(),
+            function_name,
+        };
+        Durability::<Ctx, (String, String), (), SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_subscribe_to_topic",
+            (
+                subscription.topic.clone(),
+                subscription.function_name.clone(),
+            ),
+            |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .pubsub_service
+                        .subscribe(&ctx.owned_worker_id.account_id, &subscription)
+                        .await
+                })
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, topic: String, function_name: String) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::pubsub", "unsubscribe");
+
+        let subscription = TopicSubscription {
+            topic,
+            worker_id: self.owned_worker_id.worker_id.clone(),
+            function_name,
+        };
+        let removed = Durability::<Ctx, (String, String), bool, SerializableError>::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_unsubscribe_from_topic",
+            (
+                subscription.topic.clone(),
+                subscription.function_name.clone(),
+            ),
+            |ctx| {
+                Box::pin(async move {
+                    ctx.state
+                        .pubsub_service
+                        .unsubscribe(&ctx.owned_worker_id.account_id, &subscription)
+                        .await
+                })
+            },
+        )
+        .await?;
+        Ok(removed)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
+    async fn publish(&mut self, topic: String, payload: Vec<u8>) -> anyhow::Result<u64> {
+        (*self).publish(topic, payload).await
+    }
+
+    async fn subscribe(&mut self, topic: String, function_name: String) -> anyhow::Result<()> {
+        (*self).subscribe(topic, function_name).await
+    }
+
+    async fn unsubscribe(&mut self, topic: String, function_name: String) -> anyhow::Result<bool> {
+        (*self).unsubscribe(topic, function_name).await
+    }
+}
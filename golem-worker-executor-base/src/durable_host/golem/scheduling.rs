@@ -0,0 +1,156 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::{InvocationSchedule, OwnedWorkerId, ScheduledInvocationId, WorkerId};
+use golem_wasm_rpc::Value;
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::error::GolemError;
+use crate::metrics::wasm::record_host_function_call;
+use crate::preview2::golem::api1_1_0_rc1::host::{Uuid, WorkerId as HostWorkerId};
+use crate::preview2::golem::ext::scheduling::{Host, InvocationSchedule as HostInvocationSchedule};
+use crate::preview2::golem::rpc::types::WitValue;
+use crate::workerctx::WorkerCtx;
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn schedule_invocation(
+        &mut self,
+        worker_id: HostWorkerId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        schedule: HostInvocationSchedule,
+    ) -> anyhow::Result<Result<Uuid, String>> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::scheduling", "schedule_invocation");
+
+        let worker_id: WorkerId = worker_id.into();
+        let schedule = match to_invocation_schedule(schedule) {
+            Ok(schedule) => schedule,
+            Err(err) => return Ok(Err(err)),
+        };
+        let function_input: Vec<Value> = function_params
+            .into_iter()
+            .map(|param| golem_wasm_rpc::WitValue::from(param).into())
+            .collect();
+        let owned_worker_id = OwnedWorkerId::new(&self.owned_worker_id.account_id, &worker_id);
+
+        let result = Durability::<
+            Ctx,
+            (WorkerId, String, String),
+            ScheduledInvocationId,
+            SerializableError,
+        >::wrap(
+            self,
+            WrappedFunctionType::WriteRemote,
+            "golem_schedule_invocation",
+            (worker_id, function_name.clone(), schedule.to_string()),
+            |ctx| {
+                Box::pin(async move {
+                    let invocation = ctx
+                        .state
+                        .scheduler_service
+                        .schedule_invocation(
+                            &owned_worker_id,
+                            function_name,
+                            function_input,
+                            schedule,
+                        )
+                        .await?;
+                    Ok::<ScheduledInvocationId, GolemError>(invocation.id)
+                })
+            },
+        )
+        .await;
+
+        match result {
+            Ok(id) => Ok(Ok(id.0.into())),
+            Err(GolemError::InvalidRequest { details }) => Ok(Err(details)),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn cancel_invocation(
+        &mut self,
+        worker_id: HostWorkerId,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        let _permit = self.begin_async_host_function().await?;
+        record_host_function_call("golem::ext::scheduling", "cancel_invocation");
+
+        let worker_id: WorkerId = worker_id.into();
+        let id = ScheduledInvocationId(uuid::Uuid::from_u64_pair(id.high_bits, id.low_bits));
+        let owned_worker_id = OwnedWorkerId::new(&self.owned_worker_id.account_id, &worker_id);
+
+        let cancelled =
+            Durability::<Ctx, (WorkerId, ScheduledInvocationId), bool, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "golem_cancel_scheduled_invocation",
+                (worker_id, id.clone()),
+                |ctx| {
+                    Box::pin(async move {
+                        ctx.state
+                            .scheduler_service
+                            .cancel_invocation(&owned_worker_id, &id)
+                            .await
+                    })
+                },
+            )
+            .await?;
+        Ok(cancelled)
+    }
+}
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
+    async fn schedule_invocation(
+        &mut self,
+        worker_id: HostWorkerId,
+        function_name: String,
+        function_params: Vec<WitValue>,
+        schedule: HostInvocationSchedule,
+    ) -> anyhow::Result<Result<Uuid, String>> {
+        (*self)
+            .schedule_invocation(worker_id, function_name, function_params, schedule)
+            .await
+    }
+
+    async fn cancel_invocation(
+        &mut self,
+        worker_id: HostWorkerId,
+        id: Uuid,
+    ) -> anyhow::Result<bool> {
+        (*self).cancel_invocation(worker_id, id).await
+    }
+}
+
+fn to_invocation_schedule(value: HostInvocationSchedule) -> Result<InvocationSchedule, String> {
+    match value {
+        HostInvocationSchedule::Once(at) => InvocationSchedule::once_at_millis(
+            at.seconds
+                .saturating_mul(1000)
+                .saturating_add(at.nanoseconds as u64 / 1_000_000),
+        ),
+        HostInvocationSchedule::Interval(nanos) => Ok(InvocationSchedule::Interval {
+            period: Duration::from_nanos(nanos),
+        }),
+        HostInvocationSchedule::Cron(expression) => Ok(InvocationSchedule::Cron { expression }),
+    }
+}
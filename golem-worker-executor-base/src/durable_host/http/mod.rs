@@ -14,9 +14,12 @@
 
 use crate::durable_host::{DurableWorkerCtx, HttpRequestCloseOwner};
 use crate::error::GolemError;
-use crate::workerctx::WorkerCtx;
+use crate::workerctx::{HttpIncomingRequest, HttpRequestHandling, HttpResponseSender, WorkerCtx};
 use golem_common::model::oplog::WrappedFunctionType;
 use tracing::warn;
+use wasmtime::component::Resource;
+use wasmtime_wasi_http::types::{HostIncomingRequest, HostResponseOutparam};
+use wasmtime_wasi_http::WasiHttpView;
 
 pub mod outgoing_http;
 
@@ -85,3 +88,19 @@ pub(crate) fn continue_http_request<Ctx: WorkerCtx>(
         warn!("No matching HTTP request is associated with resource handle. Handle: {}, open requests: {:?}", current_handle, ctx.state.open_http_requests);
     }
 }
+
+impl<Ctx: WorkerCtx> HttpRequestHandling for DurableWorkerCtx<Ctx> {
+    fn new_incoming_http_request(
+        &mut self,
+        request: HttpIncomingRequest,
+    ) -> anyhow::Result<Resource<HostIncomingRequest>> {
+        self.as_wasi_http_view().new_incoming_request(request)
+    }
+
+    fn new_http_response_outparam(
+        &mut self,
+        sender: HttpResponseSender,
+    ) -> anyhow::Result<Resource<HostResponseOutparam>> {
+        self.as_wasi_http_view().new_response_outparam(sender)
+    }
+}
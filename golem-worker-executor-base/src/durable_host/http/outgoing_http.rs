@@ -14,6 +14,7 @@
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use http::{HeaderMap, HeaderName, HeaderValue};
 use std::collections::HashMap;
 use wasmtime::component::Resource;
 use wasmtime_wasi_http::bindings::http::types;
@@ -22,10 +23,12 @@ use wasmtime_wasi_http::types::{HostFutureIncomingResponse, HostOutgoingRequest}
 use wasmtime_wasi_http::{HttpError, HttpResult};
 
 use golem_common::model::oplog::WrappedFunctionType;
+use golem_common::model::trace_context::{TraceContext, TRACEPARENT_HEADER, TRACESTATE_HEADER};
 
 use crate::durable_host::http::serialized::SerializableHttpRequest;
 use crate::durable_host::{DurableWorkerCtx, HttpRequestCloseOwner, HttpRequestState};
 use crate::metrics::wasm::record_host_function_call;
+use crate::services::HasWorker;
 use crate::workerctx::WorkerCtx;
 
 #[async_trait]
@@ -41,6 +44,14 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             .map_err(HttpError::trap)?;
         record_host_function_call("http::outgoing_handler", "handle");
 
+        if self.state.is_live() {
+            self.public_state
+                .worker()
+                .quota_tracker()
+                .record_outgoing_http_call()
+                .map_err(|err| HttpError::trap(anyhow!(err)))?;
+        }
+
         // Durability is handled by the WasiHttpView send_request method and the follow-up calls to await/poll the response future
         let begin_index = self
             .state
@@ -48,6 +59,11 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             .await
             .map_err(|err| HttpError::trap(anyhow!(err)))?;
 
+        if let Some(trace_context) = self.state.get_current_trace_context() {
+            let host_request = self.table().get_mut(&request)?;
+            inject_trace_context(&mut host_request.headers, &trace_context);
+        }
+
         let host_request = self.table().get(&request)?;
         let uri = format!(
             "{}{}",
@@ -114,3 +130,19 @@ impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
         (*self).handle(request, options).await
     }
 }
+
+/// Propagates the trace context of the current invocation to the called service,
+/// unless the worker has already set the trace context headers itself
+fn inject_trace_context(headers: &mut HeaderMap, trace_context: &TraceContext) {
+    if headers.contains_key(TRACEPARENT_HEADER) {
+        return;
+    }
+
+    if let Ok(traceparent) = HeaderValue::from_str(&trace_context.traceparent()) {
+        headers.insert(HeaderName::from_static(TRACEPARENT_HEADER), traceparent);
+    }
+
+    if let Some(Ok(tracestate)) = trace_context.tracestate().map(HeaderValue::from_str) {
+        headers.insert(HeaderName::from_static(TRACESTATE_HEADER), tracestate);
+    }
+}
@@ -390,13 +390,16 @@ impl<Ctx: WorkerCtx> HostIncomingBody for DurableWorkerCtx<Ctx> {
         let result = HostIncomingBody::stream(&mut self.as_wasi_http_view(), self_);
 
         if let Ok(Ok(resource)) = &result {
-            let stream_handle = resource.rep();
-            continue_http_request(
-                self,
-                handle,
-                stream_handle,
-                HttpRequestCloseOwner::InputStreamClosed,
-            );
+            // Bodies of requests passed to an exported incoming-handler are not tracked
+            if self.state.open_http_requests.contains_key(&handle) {
+                let stream_handle = resource.rep();
+                continue_http_request(
+                    self,
+                    handle,
+                    stream_handle,
+                    HttpRequestCloseOwner::InputStreamClosed,
+                );
+            }
         }
 
         result
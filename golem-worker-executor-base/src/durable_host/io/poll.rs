@@ -42,6 +42,7 @@ impl<Ctx: WorkerCtx> HostPollable for DurableWorkerCtx<Ctx> {
 
     fn drop(&mut self, rep: Resource<Pollable>) -> anyhow::Result<()> {
         record_host_function_call("io::poll:pollable", "drop");
+        self.state.sleep_deadlines.remove(&rep.rep());
         HostPollable::drop(&mut self.as_wasi_view(), rep)
     }
 }
@@ -52,19 +53,48 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::poll", "poll");
 
+        let pollables: Vec<u32> = in_.iter().map(|pollable| pollable.rep()).collect();
         let result = Durability::<Ctx, (), Vec<u32>, SerializableError>::wrap_conditionally(
             self,
             WrappedFunctionType::ReadLocal,
             "golem io::poll::poll",
             (),
-            |ctx| Box::pin(async move { Host::poll(&mut ctx.as_wasi_view(), in_).await }),
+            |ctx| {
+                Box::pin(async move {
+                    // Long sleeps are over when their persisted deadline is reached, as the
+                    // monotonic clock restarts when the worker gets recovered
+                    let now = Utc::now();
+                    let elapsed_sleeps: Vec<u32> = in_
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, pollable)| {
+                            ctx.state
+                                .sleep_deadlines
+                                .get(&pollable.rep())
+                                .is_some_and(|deadline| *deadline <= now)
+                        })
+                        .map(|(idx, _)| idx as u32)
+                        .collect();
+                    if elapsed_sleeps.is_empty() {
+                        Host::poll(&mut ctx.as_wasi_view(), in_).await
+                    } else {
+                        Ok(elapsed_sleeps)
+                    }
+                })
+            },
             |result| is_suspend_for_sleep(result).is_none(), // We must not persist the suspend signal
         )
         .await;
 
         match is_suspend_for_sleep(&result) {
             Some(duration) => {
-                self.state.sleep_until(Utc::now() + duration).await?;
+                let mut wake_up = Utc::now() + duration;
+                for pollable in &pollables {
+                    if let Some(deadline) = self.state.sleep_deadlines.get(pollable) {
+                        wake_up = wake_up.min(*deadline);
+                    }
+                }
+                self.state.sleep_until(wake_up).await?;
                 Err(InterruptKind::Suspend.into())
             }
             None => result,
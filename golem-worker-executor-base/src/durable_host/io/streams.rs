@@ -15,12 +15,13 @@
 use anyhow::anyhow;
 use async_trait::async_trait;
 use wasmtime::component::Resource;
-use wasmtime_wasi::{ResourceTable, StreamError};
+use wasmtime_wasi::StreamError;
 
 use crate::durable_host::http::serialized::SerializableHttpRequest;
 use crate::durable_host::http::{end_http_request, end_http_request_sync};
 use crate::durable_host::io::{ManagedStdErr, ManagedStdOut};
-use crate::durable_host::serialized::SerializableStreamError;
+use crate::durable_host::serialized::{SerializableIpSocketAddress, SerializableStreamError};
+use crate::durable_host::sockets::get_socket_remote_address;
 use crate::durable_host::{Durability, DurableWorkerCtx, HttpRequestCloseOwner};
 use crate::error::GolemError;
 use crate::metrics::wasm::record_host_function_call;
@@ -41,7 +42,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<Vec<u8>, StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::input_stream", "read");
-        if is_incoming_http_body_stream(self.table(), &self_) {
+        if is_incoming_http_body_stream(self, &self_) {
             let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
@@ -61,6 +62,19 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 .await;
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, Vec<u8>, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::read",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::read(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::read(&mut self.as_wasi_view(), self_, len).await
         }
@@ -73,7 +87,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<Vec<u8>, StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::input_stream", "blocking_read");
-        if is_incoming_http_body_stream(self.table(), &self_) {
+        if is_incoming_http_body_stream(self, &self_) {
             let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
@@ -94,6 +108,19 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 .await;
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, Vec<u8>, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::blocking_read",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::blocking_read(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::blocking_read(&mut self.as_wasi_view(), self_, len).await
         }
@@ -102,7 +129,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
     async fn skip(&mut self, self_: Resource<InputStream>, len: u64) -> Result<u64, StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::input_stream", "skip");
-        if is_incoming_http_body_stream(self.table(), &self_) {
+        if is_incoming_http_body_stream(self, &self_) {
             let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
@@ -122,6 +149,19 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 .await;
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, u64, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::skip",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::skip(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::skip(&mut self.as_wasi_view(), self_, len).await
         }
@@ -134,7 +174,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
     ) -> Result<u64, StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::input_stream", "blocking_skip");
-        if is_incoming_http_body_stream(self.table(), &self_) {
+        if is_incoming_http_body_stream(self, &self_) {
             let handle = self_.rep();
             let begin_idx = get_http_request_begin_idx(self, handle)?;
 
@@ -155,6 +195,19 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 .await;
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, u64, SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::tcp::input_stream::blocking_skip",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostInputStream::blocking_skip(&mut ctx.as_wasi_view(), self_, len).await
+                    })
+                },
+            )
+            .await
         } else {
             HostInputStream::blocking_skip(&mut self.as_wasi_view(), self_, len).await
         }
@@ -168,7 +221,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
     fn drop(&mut self, rep: Resource<InputStream>) -> anyhow::Result<()> {
         record_host_function_call("io::streams::input_stream", "drop");
 
-        if is_incoming_http_body_stream(self.table(), &rep) {
+        if is_incoming_http_body_stream(self, &rep) {
             let handle = rep.rep();
             if let Some(state) = self.state.open_http_requests.get(&handle) {
                 if state.close_owner == HttpRequestCloseOwner::InputStreamClosed {
@@ -176,6 +229,7 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 }
             }
         }
+        self.state.open_sockets.remove(&rep.rep());
 
         HostInputStream::drop(&mut self.as_wasi_view(), rep)
     }
@@ -208,6 +262,19 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         if let Some(event) = event {
             self.emit_log_event(event).await;
             Ok::<(), StreamError>(())
+        } else if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, (), SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "sockets::tcp::output_stream::write",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostOutputStream::write(&mut ctx.as_wasi_view(), self_, contents).await
+                    })
+                },
+            )
+            .await
         } else {
             // Non-stdout writes are non-persistent and always executed
             HostOutputStream::write(&mut self.as_wasi_view(), self_, contents).await
@@ -228,13 +295,43 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
     async fn flush(&mut self, self_: Resource<OutputStream>) -> Result<(), StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::output_stream", "flush");
-        HostOutputStream::flush(&mut self.as_wasi_view(), self_).await
+        if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, (), SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "sockets::tcp::output_stream::flush",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostOutputStream::flush(&mut ctx.as_wasi_view(), self_).await
+                    })
+                },
+            )
+            .await
+        } else {
+            HostOutputStream::flush(&mut self.as_wasi_view(), self_).await
+        }
     }
 
     async fn blocking_flush(&mut self, self_: Resource<OutputStream>) -> Result<(), StreamError> {
         let _permit = self.begin_async_host_function().await?;
         record_host_function_call("io::streams::output_stream", "blocking_flush");
-        HostOutputStream::blocking_flush(&mut self.as_wasi_view(), self_).await
+        if let Some(remote_address) = get_socket_remote_address(self, self_.rep()) {
+            Durability::<Ctx, SerializableIpSocketAddress, (), SerializableStreamError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "sockets::tcp::output_stream::blocking_flush",
+                remote_address,
+                |ctx| {
+                    Box::pin(async move {
+                        HostOutputStream::blocking_flush(&mut ctx.as_wasi_view(), self_).await
+                    })
+                },
+            )
+            .await
+        } else {
+            HostOutputStream::blocking_flush(&mut self.as_wasi_view(), self_).await
+        }
     }
 
     fn subscribe(&mut self, self_: Resource<OutputStream>) -> anyhow::Result<Resource<Pollable>> {
@@ -290,6 +387,7 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
 
     fn drop(&mut self, rep: Resource<OutputStream>) -> anyhow::Result<()> {
         record_host_function_call("io::streams::output_stream", "drop");
+        self.state.open_sockets.remove(&rep.rep());
         HostOutputStream::drop(&mut self.as_wasi_view(), rep)
     }
 }
@@ -426,8 +524,17 @@ impl<Ctx: WorkerCtx> Host for &mut DurableWorkerCtx<Ctx> {
     }
 }
 
-fn is_incoming_http_body_stream(table: &ResourceTable, stream: &Resource<InputStream>) -> bool {
-    let stream = table.get::<InputStream>(stream).unwrap();
+/// Checks if the stream is the body of a response to an outgoing HTTP request. Bodies of requests
+/// passed to an exported incoming-handler are part of the invocation's input, so they are not
+/// associated with an open request and reading them does not need to be persisted.
+fn is_incoming_http_body_stream<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    stream: &Resource<InputStream>,
+) -> bool {
+    if !ctx.state.open_http_requests.contains_key(&stream.rep()) {
+        return false;
+    }
+    let stream = ctx.table().get::<InputStream>(stream).unwrap();
     match stream {
         InputStream::Host(host_input_stream) => {
             host_input_stream
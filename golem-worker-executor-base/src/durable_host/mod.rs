@@ -19,7 +19,7 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Add;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{Duration, Instant};
 
@@ -32,7 +32,9 @@ use crate::model::{
 use crate::services::blob_store::BlobStoreService;
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
+use crate::services::lock::LockService;
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
 use crate::services::{worker_enumeration, HasAll, HasConfig, HasOplog, HasWorker};
@@ -49,8 +51,9 @@ use golem_common::model::oplog::{
     WorkerResourceId, WrappedFunctionType,
 };
 use golem_common::model::regions::{DeletedRegions, OplogRegion};
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{
-    AccountId, ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord, IdempotencyKey, OwnedWorkerId, ScanCursor, ScheduledAction, SuccessfulUpdateRecord, Timestamp, WorkerEvent, WorkerFilter, WorkerId, WorkerMetadata, WorkerResourceDescription, WorkerStatus, WorkerStatusRecord
+    AccountId, ComponentId, ComponentType, ComponentVersion, FailedUpdateRecord, IdempotencyKey, OwnedWorkerId, PromiseId, ScanCursor, ScheduleId, ScheduledAction, SuccessfulUpdateRecord, Timestamp, WorkerEvent, WorkerFilter, WorkerId, WorkerMetadata, WorkerResourceDescription, WorkerStatus, WorkerStatusRecord
 };
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::wasmtime::ResourceStore;
@@ -96,6 +99,7 @@ mod sync_helper;
 
 use crate::durable_host::http::serialized::SerializableHttpRequest;
 use crate::durable_host::replay_state::ReplayState;
+use crate::durable_host::serialized::SerializableIpSocketAddress;
 use crate::durable_host::sync_helper::{SyncHelper, SyncHelperPermit};
 use crate::function_result_interpreter::interpret_function_results;
 use crate::services::component::{ComponentMetadata, ComponentService};
@@ -132,6 +136,8 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Weak<Worker<Ctx>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         component_service: Arc<dyn ComponentService + Send + Sync>,
@@ -200,13 +206,15 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 event_service,
                 invocation_queue,
                 oplog: oplog.clone(),
-                directories,
+                directories: directories.clone(),
             },
             state: PrivateDurableWorkerState::new(
                 oplog_service,
                 oplog,
                 promise_service,
                 scheduler_service,
+                pubsub_service,
+                lock_service,
                 worker_service,
                 worker_enumeration_service,
                 key_value_service,
@@ -220,6 +228,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 last_oplog_index,
                 component_metadata,
                 worker_config.total_linear_memory_size,
+                directories,
             )
             .await,
             execution_status,
@@ -300,6 +309,77 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         self.public_state.worker().update_status(status).await;
     }
 
+    /// Sets aside the current, permanently failed invocation if the component's dead-letter
+    /// policy is enabled, by recording it in the dead-letter store and skipping its region of the
+    /// oplog. Returns `true` if the invocation got dead-lettered and the worker can continue with
+    /// its pending invocations.
+    async fn try_dead_letter_invocation(
+        &mut self,
+        trap_type: &TrapType,
+        error: &WorkerError,
+        error_oplog_idx: OplogIndex,
+    ) -> bool {
+        if !self.state.is_live()
+            || self.component_metadata().component_type != ComponentType::Durable
+        {
+            return false;
+        }
+        let Some(idempotency_key) = self.state.get_current_idempotency_key() else {
+            return false;
+        };
+
+        let stderr = self.public_state.event_service.get_last_invocation_errors();
+        let deleted_regions = self.state.replay_state.deleted_regions().await;
+        let dead_lettered = self
+            .public_state
+            .worker()
+            .dead_letter_invocation(&idempotency_key, error.to_string(&stderr), &deleted_regions)
+            .await;
+
+        match dead_lettered {
+            Ok(Some(invocation_start)) => {
+                // The error entry is kept out of the skipped region, as it is the recorded result
+                // of the invocation. The entry skipping the invocation cannot be part of the region
+                // it skips then, so a second jump skips both jump entries.
+                let invocation = OplogRegion {
+                    start: invocation_start,
+                    end: error_oplog_idx.previous(),
+                };
+                let jumps = OplogRegion {
+                    start: error_oplog_idx.next(),
+                    end: error_oplog_idx.next().next(),
+                };
+                for jump in [invocation, jumps] {
+                    self.state
+                        .replay_state
+                        .add_deleted_region(jump.clone())
+                        .await;
+                    self.state
+                        .oplog
+                        .add_and_commit(OplogEntry::jump(jump))
+                        .await;
+                }
+
+                self.public_state
+                    .worker()
+                    .store_dead_lettered_invocation_failure(
+                        &idempotency_key,
+                        trap_type,
+                        error_oplog_idx,
+                    )
+                    .await;
+                self.state.current_idempotency_key = None;
+                self.store_worker_status(WorkerStatus::Running).await;
+                true
+            }
+            Ok(None) => false,
+            Err(err) => {
+                warn!("Failed to dead-letter invocation {idempotency_key}: {err}");
+                false
+            }
+        }
+    }
+
     pub fn rpc(&self) -> Arc<dyn Rpc + Send + Sync> {
         self.state.rpc.clone()
     }
@@ -321,6 +401,10 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             // In live mode we need to try to get more memory permits and if we can't,
             // we fail the worker, unload it from memory and schedule a retry.
             // let current_size = self.update_worker_status();
+            self.public_state
+                .worker()
+                .quota_tracker()
+                .check_memory(self.state.total_linear_memory_size + delta)?;
             self.state
                 .oplog
                 .add_and_commit(OplogEntry::grow_memory(delta))
@@ -614,6 +698,193 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> DurableWorkerCtx<Ctx> {
             }
         }
     }
+
+    /// Loads the worker's state from a snapshot if the recovery starts from one. It is the case
+    /// when everything preceding a snapshot entry got skipped as a deleted region.
+    async fn load_snapshot(
+        instance: &Instance,
+        store: &mut (impl AsContextMut<Data = Ctx> + Send),
+    ) -> Result<(), GolemError> {
+        let state = &mut store.as_context_mut().data_mut().durable_ctx_mut().state;
+        // The snapshot-based updates are loading their own snapshot at the end of the recovery
+        if state.is_live() || state.replay_state.deleted_regions().await.is_overridden() {
+            return Ok(());
+        }
+        let oplog = state.oplog.clone();
+        let next_idx = state.replay_state.last_replayed_index().next();
+
+        let OplogEntry::Snapshot { payload, .. } = oplog.read(next_idx).await else {
+            return Ok(());
+        };
+        let (snapshot_idx, _) = store
+            .as_context_mut()
+            .data_mut()
+            .durable_ctx_mut()
+            .state
+            .replay_state
+            .get_oplog_entry()
+            .await;
+        debug!("Loading the snapshot at oplog index {snapshot_idx}");
+
+        let data = oplog
+            .download_payload(&payload)
+            .await
+            .map_err(|error| GolemError::runtime(format!("Failed to load snapshot: {error}")))?;
+
+        store
+            .as_context_mut()
+            .data_mut()
+            .durable_ctx_mut()
+            .set_current_idempotency_key(IdempotencyKey::fresh())
+            .await;
+        store
+            .as_context_mut()
+            .data_mut()
+            .begin_call_snapshotting_function();
+        let load_result = invoke_worker(
+            "golem:api/load-snapshot@0.2.0.{load}".to_string(),
+            vec![Value::List(data.iter().map(|b| Value::U8(*b)).collect())],
+            store,
+            instance,
+        )
+        .await;
+        store
+            .as_context_mut()
+            .data_mut()
+            .end_call_snapshotting_function();
+
+        let failed = match load_result {
+            Ok(InvokeResult::Succeeded { output, .. }) => match output.as_slice() {
+                [Value::Result(Err(Some(error)))] => match &**error {
+                    Value::String(error) => Some(error.clone()),
+                    _ => {
+                        Some("Unexpected result value from the snapshot load function".to_string())
+                    }
+                },
+                [_] => None,
+                _ => Some("Unexpected result value from the snapshot load function".to_string()),
+            },
+            Ok(InvokeResult::Failed { error, .. }) => {
+                let stderr = store
+                    .as_context()
+                    .data()
+                    .get_public_state()
+                    .event_service()
+                    .get_last_invocation_errors();
+                Some(error.to_string(&stderr))
+            }
+            Ok(other) => Some(format!("{other:?}")),
+            Err(error) => Some(error.to_string()),
+        };
+
+        match failed {
+            Some(error) => Err(GolemError::runtime(format!(
+                "Failed to load snapshot: {error}"
+            ))),
+            None => {
+                store
+                    .as_context_mut()
+                    .data_mut()
+                    .durable_ctx_mut()
+                    .state
+                    .last_snapshot = (snapshot_idx, Instant::now());
+                Ok(())
+            }
+        }
+    }
+
+    /// Saves the worker's state with its `save-snapshot` export and stores it in the oplog.
+    /// The oplog entries preceding the snapshot are not replayed anymore.
+    async fn take_snapshot(
+        instance: &Instance,
+        store: &mut (impl AsContextMut<Data = Ctx> + Send),
+    ) -> Result<OplogIndex, String> {
+        store
+            .as_context_mut()
+            .data_mut()
+            .durable_ctx_mut()
+            .set_current_idempotency_key(IdempotencyKey::fresh())
+            .await;
+        store
+            .as_context_mut()
+            .data_mut()
+            .begin_call_snapshotting_function();
+        let save_result = invoke_worker(
+            "golem:api/save-snapshot@0.2.0.{save}".to_string(),
+            vec![],
+            store,
+            instance,
+        )
+        .await;
+        store
+            .as_context_mut()
+            .data_mut()
+            .end_call_snapshotting_function();
+
+        let data = match save_result {
+            Ok(InvokeResult::Succeeded { output, .. }) => {
+                Worker::<Ctx>::decode_snapshot_result(output)
+                    .ok_or_else(|| "invalid snapshot result".to_string())?
+            }
+            Ok(InvokeResult::Failed { error, .. }) => {
+                let stderr = store
+                    .as_context()
+                    .data()
+                    .get_public_state()
+                    .event_service()
+                    .get_last_invocation_errors();
+                return Err(error.to_string(&stderr));
+            }
+            Ok(other) => return Err(format!("{other:?}")),
+            Err(error) => return Err(error.to_string()),
+        };
+
+        let ctx = store.as_context_mut().data_mut().durable_ctx_mut();
+        let payload = ctx.state.oplog.upload_payload(&data).await?;
+        let snapshot_idx = ctx
+            .state
+            .oplog
+            .add_and_commit(OplogEntry::snapshot(payload))
+            .await;
+        ctx.state.last_snapshot = (snapshot_idx, Instant::now());
+        if snapshot_idx > OplogIndex::INITIAL.next() {
+            let compacted = OplogIndex::INITIAL.next()..=snapshot_idx.previous();
+            let changes = ctx
+                .state
+                .replay_state
+                .file_system_changes(compacted.clone())
+                .await;
+            ctx.state
+                .replay_state
+                .add_deleted_region(OplogRegion::from_index_range(compacted))
+                .await;
+            // The file system changes made through the API are not part of the snapshot
+            for (_, change) in changes {
+                ctx.state
+                    .oplog
+                    .add_and_commit(OplogEntry::change_file_system(change))
+                    .await;
+            }
+        }
+        ctx.update_worker_status(|_| {}).await;
+
+        Ok(snapshot_idx)
+    }
+}
+
+/// True if the component exports both the `save-snapshot` and `load-snapshot` interfaces
+fn exports_snapshot_functions(component_metadata: &ComponentMetadata) -> bool {
+    [
+        "golem:api/save-snapshot@0.2.0.{save}",
+        "golem:api/load-snapshot@0.2.0.{load}",
+    ]
+    .iter()
+    .all(|name| {
+        matches!(
+            exports::function_by_name(&component_metadata.exports, name),
+            Ok(Some(_))
+        )
+    })
 }
 
 #[async_trait]
@@ -626,6 +897,14 @@ impl<Ctx: WorkerCtx> InvocationManagement for DurableWorkerCtx<Ctx> {
         self.state.get_current_idempotency_key()
     }
 
+    async fn set_current_trace_context(&mut self, trace_context: Option<TraceContext>) {
+        self.state.set_current_trace_context(trace_context)
+    }
+
+    async fn get_current_trace_context(&self) -> Option<TraceContext> {
+        self.state.get_current_trace_context()
+    }
+
     fn is_live(&self) -> bool {
         self.state.is_live()
     }
@@ -783,6 +1062,7 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                     self.get_current_idempotency_key().await.ok_or(anyhow!(
                         "No active invocation key is associated with the worker"
                     ))?,
+                    self.get_current_trace_context().await,
                 )
                 .await
                 .unwrap_or_else(|err| {
@@ -843,6 +1123,18 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
             None
         };
 
+        if let (WorkerStatus::Failed, TrapType::Error(error), Some(oplog_idx)) =
+            (&updated_worker_status, trap_type, oplog_idx)
+        {
+            if self
+                .try_dead_letter_invocation(trap_type, error, oplog_idx)
+                .await
+            {
+                // Continuing with the next pending invocation
+                return RetryDecision::Immediate;
+            }
+        }
+
         self.store_worker_status(updated_worker_status.clone())
             .await;
 
@@ -1128,7 +1420,8 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                 .durable_ctx_mut()
                 .state
                 .replay_state
-                .switch_to_live();
+                .switch_to_live()
+                .await;
 
             // Appending a Restart marker
             store
@@ -1152,8 +1445,12 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                 .get_out_of_deleted_region()
                 .await;
 
+            // Recovery continues from the latest snapshot, if there is one
+            let snapshot_result = Self::load_snapshot(instance, store).await;
+
             let result = loop {
-                let cont = store.as_context().data().durable_ctx().state.is_replay();
+                let cont = snapshot_result.is_ok()
+                    && store.as_context().data().durable_ctx().state.is_replay();
 
                 if cont {
                     let oplog_entry = store
@@ -1167,7 +1464,12 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                     match oplog_entry {
                         Err(error) => break Err(error),
                         Ok(None) => break Ok(RetryDecision::None),
-                        Ok(Some((function_name, function_input, idempotency_key))) => {
+                        Ok(Some((
+                            function_name,
+                            function_input,
+                            idempotency_key,
+                            trace_context,
+                        ))) => {
                             debug!("Replaying function {function_name}");
                             let span = span!(Level::INFO, "replaying", function = function_name);
                             store
@@ -1175,6 +1477,11 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                                 .data_mut()
                                 .set_current_idempotency_key(idempotency_key)
                                 .await;
+                            store
+                                .as_context_mut()
+                                .data_mut()
+                                .set_current_trace_context(trace_context)
+                                .await;
 
                             let full_function_name = function_name.to_string();
                             let invoke_result = invoke_worker(
@@ -1321,6 +1628,7 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                     break Ok(RetryDecision::None);
                 }
             };
+            let result = snapshot_result.and(result);
             record_resume_worker(start.elapsed());
             record_number_of_replayed_functions(count);
 
@@ -1338,6 +1646,51 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
         }
     }
 
+    async fn snapshot_if_needed(
+        instance: &Instance,
+        store: &mut (impl AsContextMut<Data = Ctx> + Send),
+    ) {
+        let (config, oplog, last_snapshot, can_take_snapshot) = {
+            let state = &store.as_context().data().durable_ctx().state;
+            (
+                state.config.snapshot.clone(),
+                state.oplog.clone(),
+                state.last_snapshot,
+                state.is_live()
+                    && !state.file_system_modified
+                    && state.component_metadata.component_type == ComponentType::Durable
+                    && exports_snapshot_functions(&state.component_metadata),
+            )
+        };
+
+        if config.enabled && can_take_snapshot {
+            let (last_snapshot_idx, last_snapshot_at) = last_snapshot;
+            let current_idx = oplog.current_oplog_index().await;
+            let entries_since_snapshot =
+                u64::from(current_idx).saturating_sub(u64::from(last_snapshot_idx));
+
+            if entries_since_snapshot >= config.max_oplog_entries_between_snapshots
+                || last_snapshot_at.elapsed() >= config.max_time_between_snapshots
+            {
+                match Self::take_snapshot(instance, store).await {
+                    Ok(snapshot_idx) => {
+                        debug!("Took a snapshot of the worker at oplog index {snapshot_idx}");
+                    }
+                    Err(error) => {
+                        // Not retrying until one of the limits is reached again
+                        warn!("Failed to take a snapshot of the worker: {error}");
+                        store
+                            .as_context_mut()
+                            .data_mut()
+                            .durable_ctx_mut()
+                            .state
+                            .last_snapshot = (current_idx, Instant::now());
+                    }
+                }
+            }
+        }
+    }
+
     async fn record_last_known_limits<T: HasAll<Ctx> + Send + Sync>(
         _this: &T,
         _account_id: &AccountId,
@@ -1389,6 +1742,7 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                         None,
                         None,
                         None,
+                        None,
                     )
                     .await?;
                 }
@@ -1507,7 +1861,8 @@ pub(crate) async fn recover_stderr_logs<T: HasOplogService + HasConfig>(
                     break;
                 }
             }
-            Some((_, OplogEntry::ExportedFunctionInvoked { .. })) => break,
+            Some((_, OplogEntry::ExportedFunctionInvokedV1 { .. }))
+            | Some((_, OplogEntry::ExportedFunctionInvoked { .. })) => break,
             _ => {}
         }
         if idx > OplogIndex::INITIAL {
@@ -1541,11 +1896,23 @@ struct HttpRequestState {
     pub request: SerializableHttpRequest,
 }
 
+/// State associated with TCP connections, on top of the underlying wasi-sockets implementation
+#[derive(Debug, Clone)]
+struct SocketConnectionState {
+    /// The address the socket is connected to, to be included in the oplog
+    pub remote_address: SerializableIpSocketAddress,
+    /// True if the connection was initiated during replay. Such connections only exist in the oplog,
+    /// so they fail with a connection reset error as soon as the worker tries to use them in live mode.
+    pub initiated_in_replay: bool,
+}
+
 pub struct PrivateDurableWorkerState {
     oplog_service: Arc<dyn OplogService + Send + Sync>,
     oplog: Arc<dyn Oplog + Send + Sync>,
     promise_service: Arc<dyn PromiseService + Send + Sync>,
     scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+    pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+    lock_service: Arc<dyn LockService + Send + Sync>,
     worker_service: Arc<dyn WorkerService + Send + Sync>,
     worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService + Send + Sync>,
     key_value_service: Arc<dyn KeyValueService + Send + Sync>,
@@ -1554,6 +1921,7 @@ pub struct PrivateDurableWorkerState {
     config: Arc<GolemConfig>,
     owned_worker_id: OwnedWorkerId,
     current_idempotency_key: Option<IdempotencyKey>,
+    current_trace_context: Option<TraceContext>,
     rpc: Arc<dyn Rpc + Send + Sync>,
     worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
     resources: HashMap<WorkerResourceId, ResourceAny>,
@@ -1567,6 +1935,12 @@ pub struct PrivateDurableWorkerState {
     /// State of ongoing http requests, key is the resource id it is most recently associated with (one state object can belong to multiple resources, but just one at once)
     open_http_requests: HashMap<u32, HttpRequestState>,
 
+    /// State of TCP connections, key is the resource id of the socket or one of its streams
+    open_sockets: HashMap<u32, SocketConnectionState>,
+
+    /// Persisted wall clock deadlines of long sleeps, key is the resource id of their pollable
+    sleep_deadlines: HashMap<u32, DateTime<Utc>>,
+
     snapshotting_mode: Option<PersistenceLevel>,
 
     indexed_resources: HashMap<IndexedResourceKey, WorkerResourceId>,
@@ -1574,6 +1948,13 @@ pub struct PrivateDurableWorkerState {
 
     total_linear_memory_size: u64,
     sync_helper: SyncHelper,
+
+    /// Oplog index and time of the last snapshot taken or loaded
+    last_snapshot: (OplogIndex, Instant),
+
+    /// Set when the worker modifies its own file system. These changes are only restored by
+    /// replaying the oplog, so such workers are never compacted by snapshots.
+    file_system_modified: bool,
 }
 
 impl PrivateDurableWorkerState {
@@ -1582,6 +1963,8 @@ impl PrivateDurableWorkerState {
         oplog: Arc<dyn Oplog + Send + Sync>,
         promise_service: Arc<dyn PromiseService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         worker_service: Arc<dyn WorkerService + Send + Sync>,
         worker_enumeration_service: Arc<
             dyn worker_enumeration::WorkerEnumerationService + Send + Sync,
@@ -1597,6 +1980,7 @@ impl PrivateDurableWorkerState {
         last_oplog_index: OplogIndex,
         component_metadata: ComponentMetadata,
         total_linear_memory_size: u64,
+        directories: FileSystemDirectories,
     ) -> Self {
         let replay_state = ReplayState::new(
             owned_worker_id.clone(),
@@ -1604,6 +1988,7 @@ impl PrivateDurableWorkerState {
             oplog.clone(),
             deleted_regions,
             last_oplog_index,
+            directories,
         )
         .await;
         Self {
@@ -1611,6 +1996,8 @@ impl PrivateDurableWorkerState {
             oplog: oplog.clone(),
             promise_service,
             scheduler_service,
+            pubsub_service,
+            lock_service,
             worker_service,
             worker_enumeration_service,
             key_value_service,
@@ -1619,6 +2006,7 @@ impl PrivateDurableWorkerState {
             config,
             owned_worker_id,
             current_idempotency_key: None,
+            current_trace_context: None,
             rpc,
             worker_proxy,
             resources: HashMap::new(),
@@ -1628,12 +2016,16 @@ impl PrivateDurableWorkerState {
             assume_idempotence: true,
             open_function_table: HashMap::new(),
             open_http_requests: HashMap::new(),
+            open_sockets: HashMap::new(),
+            sleep_deadlines: HashMap::new(),
             snapshotting_mode: None,
             indexed_resources: HashMap::new(),
             component_metadata,
             total_linear_memory_size,
             sync_helper: SyncHelper::new(oplog.clone(), replay_state.clone()),
             replay_state,
+            last_snapshot: (OplogIndex::INITIAL, Instant::now()),
+            file_system_modified: false,
         }
     }
 
@@ -1665,7 +2057,7 @@ impl PrivateDurableWorkerState {
                         .await;
                     if end_index.is_none() {
                         // Must switch to live mode before failing to be able to commit an Error entry
-                        self.replay_state.switch_to_live();
+                        self.replay_state.switch_to_live().await;
                         Err(GolemError::runtime(
                             "Non-idempotent remote write operation was not completed, cannot retry",
                         ))
@@ -1686,7 +2078,7 @@ impl PrivateDurableWorkerState {
                         .await;
                     if end_index.is_none() {
                         // We need to jump to the end of the oplog
-                        self.replay_state.switch_to_live();
+                        let file_system_changes = self.replay_state.switch_to_live().await;
 
                         // But this is not enough, because if the retried batched write operation succeeds,
                         // and later we replay it, we need to skip the first attempt and only replay the second.
@@ -1701,6 +2093,13 @@ impl PrivateDurableWorkerState {
                         self.oplog
                             .add_and_commit(OplogEntry::jump(deleted_region))
                             .await;
+
+                        // File system changes made through the API in the deleted region are kept
+                        for change in file_system_changes {
+                            self.oplog
+                                .add_and_commit(OplogEntry::change_file_system(change))
+                                .await;
+                        }
                     }
 
                     Ok(begin_index)
@@ -1799,7 +2198,18 @@ impl PrivateDurableWorkerState {
                 self.current_oplog_index().await,
             )
             .await;
+        self.complete_promise_at(promise_id, when).await;
+
+        Ok(())
+    }
 
+    /// Schedules the completion of the given promise, which also resumes the worker if it is
+    /// suspended waiting for it
+    pub async fn complete_promise_at(
+        &self,
+        promise_id: PromiseId,
+        when: DateTime<Utc>,
+    ) -> ScheduleId {
         let schedule_id = self
             .scheduler_service
             .schedule(
@@ -1815,8 +2225,7 @@ impl PrivateDurableWorkerState {
             when.to_rfc3339(),
             schedule_id
         );
-
-        Ok(())
+        schedule_id
     }
 
     pub fn get_current_idempotency_key(&self) -> Option<IdempotencyKey> {
@@ -1827,6 +2236,14 @@ impl PrivateDurableWorkerState {
         self.current_idempotency_key = Some(invocation_key);
     }
 
+    pub fn get_current_trace_context(&self) -> Option<TraceContext> {
+        self.current_trace_context.clone()
+    }
+
+    pub fn set_current_trace_context(&mut self, trace_context: Option<TraceContext>) {
+        self.current_trace_context = trace_context;
+    }
+
     /// Counts the number of Error entries that are at the end of the oplog. This equals to the number of retries that have been attempted.
     /// It also returns the last error stored in these entries.
     pub async fn trailing_error_count(&self) -> u64 {
@@ -2090,4 +2507,137 @@ impl FileSystemDirectories {
 
         Ok(node)
     }
-}
\ No newline at end of file
+
+    /// Creates or overwrites a file in the writable part of the file system
+    pub async fn write_file(&self, path: &Path, content: Vec<u8>) -> std::io::Result<()> {
+        let path = self.writable_path(path)?;
+        let dir = self.open_rw_dir().await?;
+        tokio::task::spawn_blocking(move || {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                dir.create_dir_all(parent)?;
+            }
+            dir.write(&path, content)
+        })
+        .await?
+    }
+
+    /// Deletes a file, or a directory with all its contents, in the writable part of the file system
+    pub async fn delete(&self, path: &Path) -> std::io::Result<()> {
+        let path = self.writable_path(path)?;
+        if path.as_os_str().is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "The root directory cannot be deleted",
+            ));
+        }
+        let dir = self.open_rw_dir().await?;
+        tokio::task::spawn_blocking(move || {
+            if dir.symlink_metadata(&path)?.is_dir() {
+                dir.remove_dir_all(&path)
+            } else {
+                dir.remove_file(&path)
+            }
+        })
+        .await?
+    }
+
+    /// Creates a directory and its missing parents in the writable part of the file system
+    pub async fn make_directory(&self, path: &Path) -> std::io::Result<()> {
+        let path = self.writable_path(path)?;
+        let dir = self.open_rw_dir().await?;
+        tokio::task::spawn_blocking(move || dir.create_dir_all(&path)).await?
+    }
+
+    /// Makes the path relative to the writable directory, rejecting paths in the read-only region.
+    /// The path is normalized first, so `.` and `..` components cannot be used to reach it.
+    fn writable_path(&self, path: &Path) -> std::io::Result<PathBuf> {
+        let mut normalized = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::RootDir | Component::CurDir => {}
+                Component::Normal(name) => normalized.push(name),
+                Component::ParentDir => {
+                    if !normalized.pop() {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            format!("{} is outside of the worker's file system", path.display()),
+                        ));
+                    }
+                }
+                Component::Prefix(_) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("{} is not a valid path", path.display()),
+                    ))
+                }
+            }
+        }
+
+        if self.dir_ro.is_some() && normalized.starts_with(READ_ONLY_FILES_PATH) {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                format!("/{READ_ONLY_FILES_PATH} is read-only"),
+            ))
+        } else {
+            Ok(normalized)
+        }
+    }
+
+    async fn open_rw_dir(&self) -> std::io::Result<cap_std::fs::Dir> {
+        let dir = tokio::fs::File::open(self.dir_rw.path()).await?;
+        Ok(cap_std::fs::Dir::from_std_file(dir.into_std().await))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    use golem_common::file_system::READ_ONLY_FILES_PATH;
+
+    use crate::durable_host::FileSystemDirectories;
+
+    fn directories() -> FileSystemDirectories {
+        FileSystemDirectories {
+            dir_ro: Some(Arc::new(tempfile::tempdir().unwrap())),
+            dir_rw: Arc::new(tempfile::tempdir().unwrap()),
+        }
+    }
+
+    #[test]
+    fn writable_path_is_normalized() {
+        let directories = directories();
+
+        assert_eq!(
+            directories.writable_path(Path::new("/a/./b/../c")).unwrap(),
+            PathBuf::from("a/c")
+        );
+        assert_eq!(
+            directories.writable_path(Path::new("/")).unwrap(),
+            PathBuf::new()
+        );
+        assert!(directories.writable_path(Path::new("../a")).is_err());
+    }
+
+    #[test]
+    fn writable_path_rejects_the_read_only_region() {
+        let directories = directories();
+
+        for path in [
+            format!("/{READ_ONLY_FILES_PATH}/x"),
+            format!("./{READ_ONLY_FILES_PATH}/x"),
+            format!("a/../{READ_ONLY_FILES_PATH}/x"),
+            format!("/a/b/../../{READ_ONLY_FILES_PATH}"),
+        ] {
+            let result = directories.writable_path(Path::new(&path));
+            assert_eq!(
+                result.map_err(|err| err.kind()),
+                Err(std::io::ErrorKind::PermissionDenied),
+                "{path}"
+            );
+        }
+    }
+}
@@ -12,20 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::durable_host::FileSystemDirectories;
 use crate::error::GolemError;
 use crate::services::oplog::{Oplog, OplogOps, OplogService};
-use golem_common::model::oplog::{AtomicOplogIndex, LogLevel, OplogEntry, OplogIndex};
+use golem_common::model::oplog::{
+    AtomicOplogIndex, FileSystemChange, LogLevel, OplogEntry, OplogIndex,
+};
 use golem_common::model::regions::{DeletedRegions, OplogRegion};
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{IdempotencyKey, OwnedWorkerId};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::Value;
 use metrohash::MetroHash128;
 use std::collections::HashSet;
 use std::hash::Hasher;
+use std::ops::RangeInclusive;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::debug;
+use tracing::{debug, warn};
+
+/// Function name, input, idempotency key and trace context of an invocation found in the oplog
+pub type ReplayedInvocation = (String, Vec<Value>, IdempotencyKey, Option<TraceContext>);
 
 #[derive(Clone)]
 pub struct ReplayState {
@@ -37,6 +46,8 @@ pub struct ReplayState {
     last_replayed_index: AtomicOplogIndex,
     internal: Arc<RwLock<InternalReplayState>>,
     has_seen_logs: Arc<AtomicBool>,
+    /// Used to re-apply the file system changes made through the API
+    directories: FileSystemDirectories,
 }
 
 #[derive(Clone)]
@@ -54,6 +65,7 @@ impl ReplayState {
         oplog: Arc<dyn Oplog + Send + Sync>,
         deleted_regions: DeletedRegions,
         last_oplog_index: OplogIndex,
+        directories: FileSystemDirectories,
     ) -> Self {
         let next_deleted_region = deleted_regions.find_next_deleted_region(OplogIndex::NONE);
         let mut result = Self {
@@ -68,13 +80,74 @@ impl ReplayState {
                 log_hashes: HashSet::new(),
             })),
             has_seen_logs: Arc::new(AtomicBool::new(false)),
+            directories,
         };
         result.move_replay_idx(OplogIndex::INITIAL).await; // By this we handle initial deleted regions applied by manual updates correctly
         result
     }
 
-    pub fn switch_to_live(&mut self) {
-        self.last_replayed_index.set(self.replay_target.get());
+    /// Skips the rest of the oplog, applying the file system changes made through the API in
+    /// the skipped part, as the file system has to reflect them in live mode.
+    ///
+    /// Returns the applied changes, so callers turning the skipped part into a deleted region
+    /// can record them again.
+    pub async fn switch_to_live(&mut self) -> Vec<FileSystemChange> {
+        let replay_target = self.replay_target.get();
+        let start = self.last_replayed_index.get().next();
+
+        let mut changes = Vec::new();
+        for (idx, change) in self.file_system_changes(start..=replay_target).await {
+            if let Err(err) = self.apply_file_system_change(&change).await {
+                warn!(
+                    "Failed to apply the file system change of {} at {idx}: {err}",
+                    change.path()
+                );
+            }
+            changes.push(change);
+        }
+
+        self.last_replayed_index.set(replay_target);
+        changes
+    }
+
+    /// Collects the file system changes made through the API in the given range of the oplog,
+    /// skipping the deleted regions
+    pub async fn file_system_changes(
+        &self,
+        range: RangeInclusive<OplogIndex>,
+    ) -> Vec<(OplogIndex, FileSystemChange)> {
+        let deleted_regions = self.deleted_regions().await;
+        let (mut start, end) = range.into_inner();
+        let mut changes = Vec::new();
+
+        const CHUNK_SIZE: u64 = 1024;
+        while start <= end {
+            if let Some(region_end) = deleted_region_end(&deleted_regions, start) {
+                start = region_end.next();
+                continue;
+            }
+
+            let entries = self
+                .oplog_service
+                .read(&self.owned_worker_id, start, CHUNK_SIZE)
+                .await;
+            if entries.is_empty() {
+                break;
+            }
+            for (idx, entry) in &entries {
+                if *idx > end {
+                    break;
+                }
+                if let OplogEntry::ChangeFileSystem { change, .. } = entry {
+                    if deleted_region_end(&deleted_regions, *idx).is_none() {
+                        changes.push((*idx, change.clone()));
+                    }
+                }
+            }
+            start = start.range_end(entries.len() as u64).next();
+        }
+
+        changes
     }
 
     pub fn last_replayed_index(&self) -> OplogIndex {
@@ -187,9 +260,36 @@ impl ReplayState {
         let oplog_entry = oplog_entries.into_iter().next().unwrap();
         self.move_replay_idx(read_idx).await;
 
+        if let OplogEntry::ChangeFileSystem { change, .. } = &oplog_entry {
+            if let Err(err) = self.apply_file_system_change(change).await {
+                warn!(
+                    "Failed to replay the file system change of {} at {read_idx}: {err}",
+                    change.path()
+                );
+            }
+        }
+
         oplog_entry
     }
 
+    /// File system changes made through the API are applied at the same point of the replay
+    /// where they were made originally, as the worker's own file system operations are not recorded.
+    async fn apply_file_system_change(&self, change: &FileSystemChange) -> Result<(), String> {
+        match change {
+            FileSystemChange::WriteFile { path, content } => {
+                let content = self.oplog.download_payload(content).await?;
+                self.directories
+                    .write_file(Path::new(path), content.to_vec())
+                    .await
+            }
+            FileSystemChange::DeleteFile { path } => self.directories.delete(Path::new(path)).await,
+            FileSystemChange::MakeDirectory { path } => {
+                self.directories.make_directory(Path::new(path)).await
+            }
+        }
+        .map_err(|err| err.to_string())
+    }
+
     async fn move_replay_idx(&mut self, new_idx: OplogIndex) {
         self.last_replayed_index.set(new_idx);
         self.get_out_of_deleted_region().await;
@@ -233,18 +333,71 @@ impl ReplayState {
         None
     }
 
+    /// Checks if the next non-hint entry to be replayed is an invocation of the given imported
+    /// function, without consuming it. Used for replaying host functions which are only recorded
+    /// since a newer executor version.
+    pub async fn is_next_imported_function(&self, function_name: &str) -> bool {
+        let deleted_regions = self.deleted_regions().await;
+        let replay_target = self.replay_target.get();
+        let mut start = self.last_replayed_index.get().next();
+
+        const CHUNK_SIZE: u64 = 1024;
+        while start <= replay_target {
+            if let Some(region_end) = deleted_region_end(&deleted_regions, start) {
+                start = region_end.next();
+                continue;
+            }
+
+            let entries = self
+                .oplog_service
+                .read(&self.owned_worker_id, start, CHUNK_SIZE)
+                .await;
+            if entries.is_empty() {
+                break;
+            }
+            for (idx, entry) in &entries {
+                if *idx > replay_target || deleted_region_end(&deleted_regions, *idx).is_some() {
+                    // Stopping at the replay target, or skipping the deleted region
+                    start = *idx;
+                    break;
+                }
+                if !entry.is_hint() {
+                    return matches!(
+                        entry,
+                        OplogEntry::ImportedFunctionInvoked { function_name: name, .. }
+                            if name == function_name
+                    );
+                }
+                start = idx.next();
+            }
+        }
+
+        false
+    }
+
     pub async fn get_oplog_entry_exported_function_invoked(
         &mut self,
-    ) -> Result<Option<(String, Vec<Value>, IdempotencyKey)>, GolemError> {
+    ) -> Result<Option<ReplayedInvocation>, GolemError> {
         loop {
             if self.is_replay() {
                 let (_, oplog_entry) = self.get_oplog_entry().await;
                 match &oplog_entry {
-                    OplogEntry::ExportedFunctionInvoked {
+                    OplogEntry::ExportedFunctionInvokedV1 {
+                        function_name,
+                        idempotency_key,
+                        ..
+                    }
+                    | OplogEntry::ExportedFunctionInvoked {
                         function_name,
                         idempotency_key,
                         ..
                     } => {
+                        let trace_context = match &oplog_entry {
+                            OplogEntry::ExportedFunctionInvoked { trace_context, .. } => {
+                                trace_context.clone()
+                            }
+                            _ => None,
+                        };
                         let request: Vec<golem_wasm_rpc::protobuf::Val> = self
                             .oplog
                             .get_payload_of_entry(&oplog_entry)
@@ -262,6 +415,7 @@ impl ReplayState {
                             function_name.to_string(),
                             request,
                             idempotency_key.clone(),
+                            trace_context,
                         )));
                     }
                     entry if entry.is_hint() => {}
@@ -344,3 +498,182 @@ impl ReplayState {
             .collect()
     }
 }
+
+/// The last index of the deleted region containing the given index, if there is one
+fn deleted_region_end(deleted_regions: &DeletedRegions, idx: OplogIndex) -> Option<OplogIndex> {
+    deleted_regions
+        .regions()
+        .find(|region| region.start <= idx && idx <= region.end)
+        .map(|region| region.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use golem_common::model::oplog::{LogLevel, OplogEntry, OplogPayload, WrappedFunctionType};
+    use golem_common::model::regions::{DeletedRegions, OplogRegion};
+    use golem_common::model::{
+        AccountId, ComponentId, ComponentType, OwnedWorkerId, Timestamp, WorkerId,
+    };
+
+    use crate::durable_host::clocks::monotonic_clock::SLEEP_DEADLINE;
+    use crate::durable_host::replay_state::ReplayState;
+    use crate::durable_host::FileSystemDirectories;
+    use crate::services::oplog::{CommitLevel, OplogService, PrimaryOplogService};
+    use crate::storage::blob::memory::InMemoryBlobStorage;
+    use crate::storage::indexed::memory::InMemoryIndexedStorage;
+
+    const NOW: &str = "monotonic_clock::now";
+    const POLL: &str = "golem io::poll::poll";
+
+    fn imported_function_invoked(function_name: &str) -> OplogEntry {
+        OplogEntry::ImportedFunctionInvoked {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: OplogPayload::Inline(vec![]),
+            response: OplogPayload::Inline(vec![]),
+            wrapped_function_type: WrappedFunctionType::ReadLocal,
+        }
+    }
+
+    /// Replays an oplog starting with an entry at the initial index followed by the given ones
+    async fn replay_state(
+        entries: Vec<OplogEntry>,
+        deleted_regions: DeletedRegions,
+    ) -> ReplayState {
+        let oplog_service: Arc<dyn OplogService + Send + Sync> = Arc::new(
+            PrimaryOplogService::new(
+                Arc::new(InMemoryIndexedStorage::new()),
+                Arc::new(InMemoryBlobStorage::new()),
+                1,
+                1024,
+            )
+            .await,
+        );
+        let owned_worker_id = OwnedWorkerId::new(
+            &AccountId {
+                value: "user1".to_string(),
+            },
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "sleeper".to_string(),
+            },
+        );
+        let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+        let oplog = oplog_service
+            .open(&owned_worker_id, last_oplog_index, ComponentType::Durable)
+            .await;
+
+        oplog.add(OplogEntry::nop()).await;
+        for entry in entries {
+            oplog.add(entry).await;
+        }
+        oplog.commit(CommitLevel::Always).await;
+        let last_oplog_index = oplog.current_oplog_index().await;
+
+        ReplayState::new(
+            owned_worker_id,
+            oplog_service,
+            oplog,
+            deleted_regions,
+            last_oplog_index,
+            FileSystemDirectories {
+                dir_ro: None,
+                dir_rw: Arc::new(tempfile::tempdir().unwrap()),
+            },
+        )
+        .await
+    }
+
+    fn function_name(entry: &OplogEntry) -> &str {
+        match entry {
+            OplogEntry::ImportedFunctionInvoked { function_name, .. } => function_name,
+            _ => panic!("Unexpected oplog entry {entry:?}"),
+        }
+    }
+
+    #[test]
+    async fn sleep_recorded_without_deadline() {
+        let mut replay_state = replay_state(
+            vec![
+                imported_function_invoked(NOW),
+                imported_function_invoked(POLL),
+            ],
+            DeletedRegions::new(),
+        )
+        .await;
+
+        let (_, entry) = replay_state.get_oplog_entry().await;
+        assert_eq!(function_name(&entry), NOW);
+
+        assert!(!replay_state.is_next_imported_function(SLEEP_DEADLINE).await);
+
+        let (_, entry) = replay_state.get_oplog_entry().await;
+        assert_eq!(function_name(&entry), POLL);
+        assert!(replay_state.is_live());
+        assert!(!replay_state.is_next_imported_function(POLL).await);
+    }
+
+    #[test]
+    async fn sleep_recorded_with_deadline() {
+        let mut replay_state = replay_state(
+            vec![
+                imported_function_invoked(NOW),
+                OplogEntry::log(LogLevel::Info, "".to_string(), "sleeping".to_string()),
+                imported_function_invoked(SLEEP_DEADLINE),
+                imported_function_invoked(POLL),
+            ],
+            DeletedRegions::new(),
+        )
+        .await;
+
+        let (_, entry) = replay_state.get_oplog_entry().await;
+        assert_eq!(function_name(&entry), NOW);
+
+        // Peeking does not consume the entry
+        assert!(replay_state.is_next_imported_function(SLEEP_DEADLINE).await);
+        assert!(replay_state.is_next_imported_function(SLEEP_DEADLINE).await);
+
+        let (_, entry) = replay_state.get_oplog_entry().await;
+        assert_eq!(function_name(&entry), SLEEP_DEADLINE);
+        let (_, entry) = replay_state.get_oplog_entry().await;
+        assert_eq!(function_name(&entry), POLL);
+    }
+
+    #[test]
+    async fn sleep_deadline_as_last_entry() {
+        let mut replay_state = replay_state(
+            vec![
+                imported_function_invoked(NOW),
+                imported_function_invoked(SLEEP_DEADLINE),
+            ],
+            DeletedRegions::new(),
+        )
+        .await;
+
+        let _ = replay_state.get_oplog_entry().await;
+        assert!(replay_state.is_next_imported_function(SLEEP_DEADLINE).await);
+    }
+
+    #[test]
+    async fn sleep_deadline_in_deleted_region() {
+        let mut replay_state = replay_state(
+            vec![
+                imported_function_invoked(NOW),
+                imported_function_invoked(SLEEP_DEADLINE),
+                imported_function_invoked(POLL),
+            ],
+            DeletedRegions::from_regions(vec![OplogRegion::from_range(3..=3)]),
+        )
+        .await;
+
+        let _ = replay_state.get_oplog_entry().await;
+        assert!(!replay_state.is_next_imported_function(SLEEP_DEADLINE).await);
+        assert!(replay_state.is_next_imported_function(POLL).await);
+    }
+}
@@ -20,6 +20,10 @@ use bincode::{Decode, Encode};
 use std::ops::Add;
 use std::time::{Duration, SystemTime};
 use wasmtime_wasi::bindings::sockets::ip_name_lookup::IpAddress;
+use wasmtime_wasi::bindings::sockets::network::{
+    IpSocketAddress, Ipv4SocketAddress, Ipv6SocketAddress,
+};
+use wasmtime_wasi::bindings::sockets::udp::IncomingDatagram;
 use wasmtime_wasi::bindings::{filesystem, sockets};
 use wasmtime_wasi::{FsError, SocketError, StreamError};
 
@@ -526,6 +530,114 @@ impl From<SerializableIpAddresses> for Vec<IpAddress> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub enum SerializableIpSocketAddress {
+    IPv4 {
+        address: [u8; 4],
+        port: u16,
+    },
+    IPv6 {
+        address: [u16; 8],
+        port: u16,
+        flow_info: u32,
+        scope_id: u32,
+    },
+}
+
+impl From<IpSocketAddress> for SerializableIpSocketAddress {
+    fn from(value: IpSocketAddress) -> Self {
+        match value {
+            IpSocketAddress::Ipv4(socket_address) => SerializableIpSocketAddress::IPv4 {
+                address: [
+                    socket_address.address.0,
+                    socket_address.address.1,
+                    socket_address.address.2,
+                    socket_address.address.3,
+                ],
+                port: socket_address.port,
+            },
+            IpSocketAddress::Ipv6(socket_address) => SerializableIpSocketAddress::IPv6 {
+                address: [
+                    socket_address.address.0,
+                    socket_address.address.1,
+                    socket_address.address.2,
+                    socket_address.address.3,
+                    socket_address.address.4,
+                    socket_address.address.5,
+                    socket_address.address.6,
+                    socket_address.address.7,
+                ],
+                port: socket_address.port,
+                flow_info: socket_address.flow_info,
+                scope_id: socket_address.scope_id,
+            },
+        }
+    }
+}
+
+impl From<SerializableIpSocketAddress> for IpSocketAddress {
+    fn from(value: SerializableIpSocketAddress) -> Self {
+        match value {
+            SerializableIpSocketAddress::IPv4 { address, port } => {
+                IpSocketAddress::Ipv4(Ipv4SocketAddress {
+                    port,
+                    address: (address[0], address[1], address[2], address[3]),
+                })
+            }
+            SerializableIpSocketAddress::IPv6 {
+                address,
+                port,
+                flow_info,
+                scope_id,
+            } => IpSocketAddress::Ipv6(Ipv6SocketAddress {
+                port,
+                flow_info,
+                address: (
+                    address[0], address[1], address[2], address[3], address[4], address[5],
+                    address[6], address[7],
+                ),
+                scope_id,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SerializableIncomingDatagram {
+    pub data: Vec<u8>,
+    pub remote_address: SerializableIpSocketAddress,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct SerializableIncomingDatagrams(pub Vec<SerializableIncomingDatagram>);
+
+impl From<Vec<IncomingDatagram>> for SerializableIncomingDatagrams {
+    fn from(value: Vec<IncomingDatagram>) -> Self {
+        SerializableIncomingDatagrams(
+            value
+                .into_iter()
+                .map(|datagram| SerializableIncomingDatagram {
+                    data: datagram.data,
+                    remote_address: datagram.remote_address.into(),
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<SerializableIncomingDatagrams> for Vec<IncomingDatagram> {
+    fn from(value: SerializableIncomingDatagrams) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|datagram| IncomingDatagram {
+                data: datagram.data,
+                remote_address: datagram.remote_address.into(),
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
 pub struct SerializableFileTimes {
     pub data_access_timestamp: Option<SerializableDateTime>,
@@ -538,7 +650,7 @@ mod tests {
 
     use crate::durable_host::serialized::{
         SerializableDateTime, SerializableError, SerializableIpAddress, SerializableIpAddresses,
-        SerializableStreamError,
+        SerializableIpSocketAddress, SerializableStreamError,
     };
     use crate::error::GolemError;
     use crate::model::InterruptKind;
@@ -550,7 +662,9 @@ mod tests {
     use std::ops::Add;
     use std::time::{Duration, SystemTime};
     use uuid::Uuid;
-    use wasmtime_wasi::bindings::sockets::network::IpAddress;
+    use wasmtime_wasi::bindings::sockets::network::{
+        IpAddress, IpSocketAddress, Ipv4SocketAddress, Ipv6SocketAddress,
+    };
     use wasmtime_wasi::bindings::{filesystem, sockets};
     use wasmtime_wasi::{FsError, SocketError, StreamError};
 
@@ -729,6 +843,13 @@ mod tests {
         }
     }
 
+    fn ipsocketaddress_strat() -> impl Strategy<Value = IpSocketAddress> {
+        prop_oneof! {
+            (any::<(u8, u8, u8, u8)>(), any::<u16>()).prop_map(|(address, port)| IpSocketAddress::Ipv4(Ipv4SocketAddress { port, address })),
+            (any::<(u16, u16, u16, u16, u16, u16, u16, u16)>(), any::<u16>(), any::<u32>(), any::<u32>()).prop_map(|(address, port, flow_info, scope_id)| IpSocketAddress::Ipv6(Ipv6SocketAddress { port, flow_info, address, scope_id })),
+        }
+    }
+
     proptest! {
         #[test]
         fn roundtrip_wall_clock_datetime(value in datetime_strat()) {
@@ -844,5 +965,25 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn roundtrip_ipsocketaddress(value in ipsocketaddress_strat()) {
+            let serialized: SerializableIpSocketAddress = value.into();
+            let result: IpSocketAddress = serialized.into();
+
+            match (value, result) {
+                (IpSocketAddress::Ipv4(value), IpSocketAddress::Ipv4(result)) => {
+                    prop_assert_eq!(value.port, result.port);
+                    prop_assert_eq!(value.address, result.address);
+                },
+                (IpSocketAddress::Ipv6(value), IpSocketAddress::Ipv6(result)) => {
+                    prop_assert_eq!(value.port, result.port);
+                    prop_assert_eq!(value.flow_info, result.flow_info);
+                    prop_assert_eq!(value.address, result.address);
+                    prop_assert_eq!(value.scope_id, result.scope_id);
+                },
+                _ => prop_assert!(false),
+            }
+        }
     }
 }
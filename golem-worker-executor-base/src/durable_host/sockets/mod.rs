@@ -19,3 +19,107 @@ pub mod tcp;
 pub mod tcp_create_socket;
 pub mod udp;
 pub mod udp_create_socket;
+
+use std::any::Any;
+use std::future::Future;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use wasmtime::component::Resource;
+use wasmtime_wasi::bindings::sockets::tcp::{InputStream, OutputStream};
+use wasmtime_wasi::{HostInputStream, HostOutputStream, StreamError, StreamResult, Subscribe};
+
+use crate::durable_host::serialized::SerializableIpSocketAddress;
+use crate::durable_host::DurableWorkerCtx;
+use crate::workerctx::WorkerCtx;
+
+/// The write budget reported by the underlying TCP streams when they are ready to accept data
+const TCP_WRITE_BUDGET: usize = 1024 * 1024 * 1024;
+
+/// Input side of a TCP connection that was established during replay.
+///
+/// While replaying, every read is answered from the oplog and this stream is never touched.
+/// If the worker keeps using the connection after switching to live mode, it gets a connection reset
+/// error, as the connection does not exist anymore.
+pub(crate) struct ReplayedTcpInputStream;
+
+#[async_trait]
+impl Subscribe for ReplayedTcpInputStream {
+    async fn ready(&mut self) {}
+}
+
+#[async_trait]
+impl HostInputStream for ReplayedTcpInputStream {
+    fn read(&mut self, _size: usize) -> StreamResult<Bytes> {
+        Err(connection_reset())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Output side of a TCP connection that was established during replay, see [ReplayedTcpInputStream]
+pub(crate) struct ReplayedTcpOutputStream;
+
+#[async_trait]
+impl Subscribe for ReplayedTcpOutputStream {
+    async fn ready(&mut self) {}
+}
+
+#[async_trait]
+impl HostOutputStream for ReplayedTcpOutputStream {
+    fn write(&mut self, _bytes: Bytes) -> StreamResult<()> {
+        Err(connection_reset())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        Err(connection_reset())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        Ok(TCP_WRITE_BUDGET)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn connection_reset() -> StreamError {
+    StreamError::LastOperationFailed(anyhow!(std::io::Error::from(
+        std::io::ErrorKind::ConnectionReset
+    )))
+}
+
+pub(crate) fn create_replayed_tcp_streams<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+) -> anyhow::Result<(Resource<InputStream>, Resource<OutputStream>)> {
+    let input_stream: InputStream = InputStream::Host(Box::new(ReplayedTcpInputStream));
+    let output_stream: OutputStream = Box::new(ReplayedTcpOutputStream);
+    let input_stream = ctx.table().push(input_stream)?;
+    let output_stream = ctx.table().push(output_stream)?;
+    Ok((input_stream, output_stream))
+}
+
+/// Runs a durable socket operation to completion from a sync host function.
+///
+/// The pinned wasmtime fork generates the TCP connect and the UDP datagram functions as sync
+/// functions, while reading and writing the oplog is async. The runtime moves the other tasks of
+/// the current thread to another thread while the operation runs, so they are not blocked by it.
+pub(crate) fn block_on_durable<F: Future>(future: F) -> F::Output {
+    tokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(future))
+}
+
+/// Gets the remote address of the TCP connection the given socket or stream handle belongs to,
+/// if it is a durable TCP connection
+pub(crate) fn get_socket_remote_address<Ctx: WorkerCtx>(
+    ctx: &DurableWorkerCtx<Ctx>,
+    handle: u32,
+) -> Option<SerializableIpSocketAddress> {
+    ctx.state
+        .open_sockets
+        .get(&handle)
+        .map(|state| state.remote_address.clone())
+}
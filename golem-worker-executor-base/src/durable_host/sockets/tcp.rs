@@ -15,9 +15,13 @@
 use async_trait::async_trait;
 use wasmtime::component::Resource;
 
-use crate::durable_host::DurableWorkerCtx;
+use crate::durable_host::serialized::{SerializableError, SerializableIpSocketAddress};
+use crate::durable_host::sockets::{block_on_durable, create_replayed_tcp_streams};
+use crate::durable_host::{Durability, DurableWorkerCtx, SocketConnectionState};
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::WrappedFunctionType;
+use wasmtime_wasi::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::bindings::sockets::tcp::{
     Duration, Host, HostTcpSocket, InputStream, IpAddressFamily, IpSocketAddress, Network,
     OutputStream, Pollable, ShutdownType, TcpSocket,
@@ -48,7 +52,43 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         remote_address: IpSocketAddress,
     ) -> Result<(), SocketError> {
         record_host_function_call("sockets::tcp", "start_connect");
-        HostTcpSocket::start_connect(&mut self.as_wasi_view(), self_, network, remote_address)
+        block_on_durable(async move {
+            let _permit = self.begin_async_host_function().await?;
+
+            let handle = self_.rep();
+            let initiated_in_replay = self.state.is_replay();
+
+            // During replay the connection is not established, only its recorded results are used
+            let result =
+                Durability::<Ctx, SerializableIpSocketAddress, (), SerializableError>::wrap(
+                    self,
+                    WrappedFunctionType::WriteRemote,
+                    "sockets::tcp::start_connect",
+                    remote_address.into(),
+                    |ctx| {
+                        Box::pin(async move {
+                            HostTcpSocket::start_connect(
+                                &mut ctx.as_wasi_view(),
+                                self_,
+                                network,
+                                remote_address,
+                            )
+                        })
+                    },
+                )
+                .await;
+
+            if result.is_ok() {
+                self.state.open_sockets.insert(
+                    handle,
+                    SocketConnectionState {
+                        remote_address: remote_address.into(),
+                        initiated_in_replay,
+                    },
+                );
+            }
+            result
+        })
     }
 
     fn finish_connect(
@@ -56,7 +96,52 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         self_: Resource<TcpSocket>,
     ) -> Result<(Resource<InputStream>, Resource<OutputStream>), SocketError> {
         record_host_function_call("sockets::tcp", "finish_connect");
-        HostTcpSocket::finish_connect(&mut self.as_wasi_view(), self_)
+        block_on_durable(async move {
+            let _permit = self.begin_async_host_function().await?;
+
+            let Some(connection) = self.state.open_sockets.get(&self_.rep()).cloned() else {
+                // No connection was started, the underlying implementation reports the proper error
+                return HostTcpSocket::finish_connect(&mut self.as_wasi_view(), self_);
+            };
+
+            let initiated_in_replay = connection.initiated_in_replay;
+            let result =
+                Durability::<Ctx, SerializableIpSocketAddress, (), SerializableError>::custom_wrap(
+                    self,
+                    WrappedFunctionType::WriteRemote,
+                    "sockets::tcp::finish_connect",
+                    connection.remote_address.clone(),
+                    |ctx| {
+                        Box::pin(async move {
+                            if initiated_in_replay {
+                                // The connection attempt was started before the worker got
+                                // recovered, so it does not exist on the network
+                                Err(ErrorCode::ConnectionReset.into())
+                            } else {
+                                HostTcpSocket::finish_connect(&mut ctx.as_wasi_view(), self_)
+                            }
+                        })
+                    },
+                    |_, _| Ok(()),
+                    |ctx, _| {
+                        Box::pin(async move {
+                            create_replayed_tcp_streams(ctx).map_err(SocketError::trap)
+                        })
+                    },
+                )
+                .await;
+
+            if let Ok((input_stream, output_stream)) = &result {
+                // The streams' I/O is persisted in the oplog, see the durable_host::io::streams module
+                self.state
+                    .open_sockets
+                    .insert(input_stream.rep(), connection.clone());
+                self.state
+                    .open_sockets
+                    .insert(output_stream.rep(), connection);
+            }
+            result
+        })
     }
 
     fn start_listen(&mut self, self_: Resource<TcpSocket>) -> Result<(), SocketError> {
@@ -232,6 +317,7 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
 
     fn drop(&mut self, rep: Resource<TcpSocket>) -> anyhow::Result<()> {
         record_host_function_call("sockets::tcp", "drop");
+        self.state.open_sockets.remove(&rep.rep());
         HostTcpSocket::drop(&mut self.as_wasi_view(), rep)
     }
 }
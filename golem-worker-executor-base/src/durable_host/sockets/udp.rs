@@ -15,9 +15,14 @@
 use async_trait::async_trait;
 use wasmtime::component::Resource;
 
-use crate::durable_host::DurableWorkerCtx;
+use crate::durable_host::serialized::{
+    SerializableError, SerializableIncomingDatagrams, SerializableIpSocketAddress,
+};
+use crate::durable_host::sockets::block_on_durable;
+use crate::durable_host::{Durability, DurableWorkerCtx};
 use crate::metrics::wasm::record_host_function_call;
 use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::WrappedFunctionType;
 use wasmtime_wasi::bindings::sockets::udp::{
     Host, HostIncomingDatagramStream, HostOutgoingDatagramStream, HostUdpSocket, IncomingDatagram,
     IncomingDatagramStream, IpAddressFamily, IpSocketAddress, Network, OutgoingDatagram,
@@ -137,7 +142,25 @@ impl<Ctx: WorkerCtx> HostIncomingDatagramStream for DurableWorkerCtx<Ctx> {
         max_results: u64,
     ) -> Result<Vec<IncomingDatagram>, SocketError> {
         record_host_function_call("sockets::udp", "receive");
-        HostIncomingDatagramStream::receive(&mut self.as_wasi_view(), self_, max_results)
+        block_on_durable(async move {
+            let _permit = self.begin_async_host_function().await?;
+            Durability::<Ctx, u64, SerializableIncomingDatagrams, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::ReadRemote,
+                "sockets::udp::incoming_datagram_stream::receive",
+                max_results,
+                |ctx| {
+                    Box::pin(async move {
+                        HostIncomingDatagramStream::receive(
+                            &mut ctx.as_wasi_view(),
+                            self_,
+                            max_results,
+                        )
+                    })
+                },
+            )
+            .await
+        })
     }
 
     fn subscribe(
@@ -166,7 +189,27 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
         datagrams: Vec<OutgoingDatagram>,
     ) -> Result<u64, SocketError> {
         record_host_function_call("sockets::udp", "send");
-        HostOutgoingDatagramStream::send(&mut self.as_wasi_view(), self_, datagrams)
+        block_on_durable(async move {
+            let _permit = self.begin_async_host_function().await?;
+
+            // During replay the datagrams are not sent again, only the recorded results are used
+            let remote_addresses: Vec<Option<SerializableIpSocketAddress>> = datagrams
+                .iter()
+                .map(|datagram| datagram.remote_address.map(|address| address.into()))
+                .collect();
+            Durability::<Ctx, Vec<Option<SerializableIpSocketAddress>>, u64, SerializableError>::wrap(
+                self,
+                WrappedFunctionType::WriteRemote,
+                "sockets::udp::outgoing_datagram_stream::send",
+                remote_addresses,
+                |ctx| {
+                    Box::pin(async move {
+                        HostOutgoingDatagramStream::send(&mut ctx.as_wasi_view(), self_, datagrams)
+                    })
+                },
+            )
+            .await
+        })
     }
 
     fn subscribe(
@@ -31,6 +31,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use golem_common::model::exports::function_by_name;
 use golem_common::model::oplog::{OplogEntry, WrappedFunctionType};
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{ComponentId, IdempotencyKey, OwnedWorkerId, TargetWorkerId, WorkerId};
 use golem_common::uri::oss::urn::{WorkerFunctionUrn, WorkerOrFunctionUrn};
 use golem_wasm_rpc::golem::rpc::types::{
@@ -88,6 +89,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         record_host_function_call("golem::rpc::wasm-rpc", "invoke-and-await");
         let args = self.get_arguments().await?;
         let env = self.get_environment().await?;
+        let trace_context = self.state.get_current_trace_context();
 
         let _permit = self.begin_async_host_function().await?;
 
@@ -155,6 +157,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                             ctx.worker_id(),
                             &args,
                             &env,
+                            trace_context.as_ref(),
                         )
                         .await
                 })
@@ -218,6 +221,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         record_host_function_call("golem::rpc::wasm-rpc", "invoke");
         let args = self.get_arguments().await?;
         let env = self.get_environment().await?;
+        let trace_context = self.state.get_current_trace_context();
 
         let _permit = self.begin_async_host_function().await?;
 
@@ -279,6 +283,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                             ctx.worker_id(),
                             &args,
                             &env,
+                            trace_context.as_ref(),
                         )
                         .await
                 })
@@ -304,6 +309,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
         record_host_function_call("golem::rpc::wasm-rpc", "async-invoke-and-await");
         let args = self.get_arguments().await?;
         let env = self.get_environment().await?;
+        let trace_context = self.state.get_current_trace_context();
 
         let _permit = self.begin_async_host_function().await?;
         let begin_index = self
@@ -367,6 +373,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                         &worker_id,
                         &args,
                         &env,
+                        trace_context.as_ref(),
                     )
                     .await)
             });
@@ -382,6 +389,7 @@ impl<Ctx: WorkerCtx> HostWasmRpc for DurableWorkerCtx<Ctx> {
                     self_worker_id: worker_id,
                     args,
                     env,
+                    trace_context,
                     function_name,
                     function_params,
                     idempotency_key,
@@ -443,6 +451,7 @@ enum FutureInvokeResultState {
         self_worker_id: WorkerId,
         args: Vec<String>,
         env: Vec<(String, String)>,
+        trace_context: Option<TraceContext>,
         function_name: String,
         function_params: Vec<WitValue>,
         idempotency_key: IdempotencyKey,
@@ -554,6 +563,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                             self_worker_id,
                             args,
                             env,
+                            trace_context,
                             function_name,
                             function_params,
                             idempotency_key,
@@ -570,6 +580,7 @@ impl<Ctx: WorkerCtx> HostFutureInvokeResult for DurableWorkerCtx<Ctx> {
                                 &self_worker_id,
                                 &args,
                                 &env,
+                                trace_context.as_ref(),
                             )
                             .await)
                     });
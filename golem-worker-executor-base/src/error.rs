@@ -19,6 +19,7 @@ use std::fmt::{Display, Formatter};
 use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem;
 use golem_common::metrics::api::TraceErrorKind;
+use golem_common::model::quotas::QuotaKind;
 use golem_common::model::{ComponentId, PromiseId, ShardId, WorkerId};
 use golem_wasm_rpc::wasmtime::EncodingError;
 use tonic::Status;
@@ -802,3 +803,22 @@ impl Display for WorkerOutOfMemory {
 }
 
 impl Error for WorkerOutOfMemory {}
+
+/// Raised as a trap when a worker exceeds one of its quotas
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorkerQuotaExceeded {
+    pub quota: QuotaKind,
+    pub limit: u64,
+}
+
+impl Display for WorkerQuotaExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Worker exceeded its {} quota of {}",
+            self.quota, self.limit
+        )
+    }
+}
+
+impl Error for WorkerQuotaExceeded {}
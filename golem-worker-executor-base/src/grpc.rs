@@ -17,6 +17,7 @@ use futures_util::Stream;
 use gethostname::gethostname;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::protobuf::Val;
+use golem_wasm_rpc::ValueAndType;
 use std::cmp::min;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
@@ -38,18 +39,23 @@ use golem_api_grpc::proto::golem::common::ResourceLimits as GrpcResourceLimits;
 use golem_api_grpc::proto::golem::worker::{Cursor, ResourceMetadata, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    ConnectWorkerRequest, DeleteWorkerRequest, GetFileRequest, GetFileResponse, GetFilesRequest, GetFilesResponse, GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse, InvokeAndAwaitWorkerRequest, InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess, SearchOplogRequest, SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse
+    CancelScheduledInvocationRequest, CancelScheduledInvocationResponse, ConnectWorkerRequest, DeleteFileRequest, DeleteFileResponse, DeleteWorkerRequest, DiscardDeadLetteredInvocationRequest, DiscardDeadLetteredInvocationResponse, ForkWorkerRequest, ForkWorkerResponse, GetDeadLetterPolicyRequest, GetDeadLetterPolicyResponse, GetDeadLetteredInvocationsRequest, GetDeadLetteredInvocationsResponse, GetFileRequest, GetFileResponse, GetFilesRequest, GetFilesResponse, GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataResponse, GetScheduledInvocationsRequest, GetScheduledInvocationsResponse, GetTopicSubscriptionsRequest, GetTopicSubscriptionsResponse, GetWorkerTopicSubscriptionsRequest, GetWorkersMetadataRequest, GetWorkersMetadataResponse, InvokeAndAwaitWorkerRequest, InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess, MakeDirectoryRequest, MakeDirectoryResponse, PublishToTopicRequest, PublishToTopicResponse, PutFileRequest, PutFileResponse, RetryDeadLetteredInvocationRequest, RetryDeadLetteredInvocationResponse, RevertWorkerRequest, RevertWorkerResponse, ScheduleInvocationRequest, ScheduleInvocationResponse, SearchOplogRequest, SearchOplogResponse, SetDeadLetterPolicyRequest, SetDeadLetterPolicyResponse, SubscribeToTopicRequest, SubscribeToTopicResponse, UnsubscribeFromTopicRequest, UnsubscribeFromTopicResponse, UpdateWorkerRequest, UpdateWorkerResponse, WatchWorkersRequest
 };
 use golem_common::grpc::{
     proto_account_id_string, proto_component_id_string, proto_idempotency_key_string,
     proto_promise_id_string, proto_target_worker_id_string, proto_worker_id_string,
 };
 use golem_common::metrics::api::record_new_grpc_api_active_stream;
+use golem_common::model::exports::function_by_name;
 use golem_common::model::oplog::{OplogIndex, UpdateDescription};
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{
-    AccountId, ComponentId, ComponentType, IdempotencyKey, OwnedWorkerId, ScanCursor, ShardId,
-    TargetWorkerId, TimestampedWorkerInvocation, WorkerEvent, WorkerFilter, WorkerId,
-    WorkerInvocation, WorkerMetadata, WorkerStatus, WorkerStatusRecord,
+    AccountId, ComponentId, ComponentType, DeadLetterPolicy, DeadLetteredInvocation,
+    DeadLetteredInvocationId, IdempotencyKey, InvocationSchedule, OwnedWorkerId,
+    RevertWorkerTarget, ScanCursor, ScheduledInvocationId, ShardId, TargetWorkerId,
+    TimestampedWorkerInvocation, WorkerEvent, WorkerFilter, WorkerId, WorkerInvocation,
+    WorkerMetadata, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::{model as common_model, recorded_grpc_api_request};
 
@@ -58,12 +64,14 @@ use crate::model::public_oplog::{
 };
 use crate::model::{InterruptKind, LastError};
 use crate::services::events::Event;
+use crate::services::lock;
+use crate::services::pubsub;
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
-    All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplogService, HasPromiseService, HasRunningWorkerEnumerationService, HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerService, UsesAllDeps
+    All, HasActiveWorkers, HasAll, HasComponentService, HasDeadLetterService, HasEvents, HasLockService, HasOplogService, HasPromiseService, HasPubSubService, HasRpc, HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerService, UsesAllDeps
 };
-use crate::worker::Worker;
+use crate::worker::{calculate_last_known_status, Worker};
 use crate::workerctx::{FileSystemNode, WorkerCtx};
 
 pub enum GrpcError<E> {
@@ -271,6 +279,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             Some(env),
             Some(component_version),
             None,
+            request.quotas.map(|quotas| quotas.into()),
         )
         .await?;
 
@@ -350,8 +359,16 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             // By making sure the worker is in memory. If it was suspended because of waiting
             // for a promise, replaying that call will now not suspend as the promise has been
             // completed, and the worker will continue running.
-            Worker::get_or_create_running(&self.services, &owned_worker_id, None, None, None, None)
-                .await?;
+            Worker::get_or_create_running(
+                &self.services,
+                &owned_worker_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
         }
 
         let success = golem::workerexecutor::v1::CompletePromiseSuccess { completed };
@@ -388,9 +405,16 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         };
 
         if should_interrupt {
-            let worker =
-                Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
-                    .await?;
+            let worker = Worker::get_or_create_suspended(
+                self,
+                &owned_worker_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
 
             if let Some(mut await_interrupted) =
                 worker.set_interrupting(InterruptKind::Interrupt).await
@@ -402,6 +426,18 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
 
         Ctx::on_worker_deleted(self, &worker_id).await?;
+        self.pubsub_service()
+            .remove_worker_subscriptions(&owned_worker_id)
+            .await?;
+        for name in self.lock_service().remove_worker(&owned_worker_id).await? {
+            lock::wake_waiters(
+                &self.lock_service(),
+                &self.scheduler_service(),
+                &owned_worker_id.account_id,
+                &name,
+            )
+            .await?;
+        }
         self.worker_service().remove(&owned_worker_id).await;
         self.active_workers().remove(&worker_id);
 
@@ -461,26 +497,47 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             }
             WorkerStatus::Suspended => {
                 debug!("Marking suspended worker as interrupted");
-                let worker =
-                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
-                        .await?;
+                let worker = Worker::get_or_create_suspended(
+                    self,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
                 worker.set_interrupting(InterruptKind::Interrupt).await;
                 // Explicitly drop from the active worker cache - this will drop websocket connections etc.
                 self.active_workers().remove(&worker_id);
             }
             WorkerStatus::Retrying => {
                 debug!("Marking worker scheduled to be retried as interrupted");
-                let worker =
-                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
-                        .await?;
+                let worker = Worker::get_or_create_suspended(
+                    self,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
                 worker.set_interrupting(InterruptKind::Interrupt).await;
                 // Explicitly drop from the active worker cache - this will drop websocket connections etc.
                 self.active_workers().remove(&worker_id);
             }
             WorkerStatus::Running => {
-                let worker =
-                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
-                        .await?;
+                let worker = Worker::get_or_create_suspended(
+                    self,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
                 worker
                     .set_interrupting(if request.recover_immediately {
                         InterruptKind::Restart
@@ -536,6 +593,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                     None,
                     None,
                     None,
+                    None,
                 )
                 .await?;
                 Ok(())
@@ -595,7 +653,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
         let values = worker
-            .invoke_and_await(idempotency_key, full_function_name, function_input)
+            .invoke_and_await(
+                idempotency_key,
+                full_function_name,
+                function_input,
+                request.trace_context(),
+            )
             .await?;
 
         Ok(values)
@@ -651,6 +714,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             request.env(),
             None,
             request.parent(),
+            None,
         )
         .await
     }
@@ -675,7 +739,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
         worker
-            .invoke(idempotency_key, full_function_name, function_input)
+            .invoke(
+                idempotency_key,
+                full_function_name,
+                function_input,
+                request.trace_context(),
+            )
             .await?;
 
         Ok(())
@@ -750,11 +819,21 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         let last_error_and_retry_count =
             Ctx::get_last_error_and_retry_count(self, &owned_worker_id).await;
 
-        Ok(Self::create_proto_metadata(
-            metadata,
-            latest_status,
-            last_error_and_retry_count,
-        ))
+        let mut result =
+            Self::create_proto_metadata(metadata, latest_status, last_error_and_retry_count);
+
+        if let Some(worker) = self.active_workers().try_get(&worker_id) {
+            let usage = worker.quota_tracker().usage();
+            if let Some(quota_usage) = result.quota_usage.as_mut() {
+                quota_usage.oplog_bytes = usage.oplog_bytes;
+                quota_usage.fuel_consumed_by_last_invocation =
+                    usage.fuel_consumed_by_last_invocation;
+                quota_usage.outgoing_http_calls_in_last_minute =
+                    usage.outgoing_http_calls_in_last_minute;
+            }
+        }
+
+        Ok(result)
     }
 
     async fn get_running_workers_metadata_internal(
@@ -921,6 +1000,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                             None,
                             Some(worker_status.component_version),
                             None,
+                            None,
                         )
                         .await?;
 
@@ -954,6 +1034,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                             None,
                             None,
                             None,
+                            None,
                         )
                         .await?;
 
@@ -981,9 +1062,16 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 // This is in a race condition with other worker invocations, so the whole update
                 // process need to be initiated through the worker's invocation queue.
 
-                let worker =
-                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
-                        .await?;
+                let worker = Worker::get_or_create_suspended(
+                    self,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
                 worker.enqueue_manual_update(request.target_version).await;
             }
         }
@@ -1019,10 +1107,17 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 })?;
 
             if worker_status.status != WorkerStatus::Interrupted {
-                let event_service =
-                    Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None)
-                        .await?
-                        .event_service();
+                let event_service = Worker::get_or_create_suspended(
+                    self,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?
+                .event_service();
 
                 let receiver = event_service.receiver();
 
@@ -1042,6 +1137,78 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
+    async fn watch_workers_internal(
+        &self,
+        request: WatchWorkersRequest,
+    ) -> Result<<Self as WorkerExecutor>::WatchWorkersStream, GolemError> {
+        let component_id: ComponentId = request
+            .component_id
+            .and_then(|t| t.try_into().ok())
+            .ok_or(GolemError::invalid_request("Invalid component id"))?;
+
+        let account_id: AccountId = request
+            .account_id
+            .map(|t| t.into())
+            .ok_or(GolemError::invalid_request("Invalid account id"))?;
+
+        let filter: Option<WorkerFilter> = match request.filter {
+            Some(f) => Some(f.try_into().map_err(GolemError::invalid_request)?),
+            _ => None,
+        };
+
+        info!("Client started watching workers");
+
+        // Only the status changes of the workers running on this executor are emitted,
+        // the worker service merges the streams of all the executors
+        let subscription = self.events().subscribe();
+        let stream = futures::stream::unfold(subscription, move |mut subscription| {
+            let component_id = component_id.clone();
+            let account_id = account_id.clone();
+            let filter = filter.clone();
+            async move {
+                let result = subscription
+                    .wait_for(|event| match event {
+                        Event::WorkerStatusChanged {
+                            metadata,
+                            previous_status,
+                            timestamp,
+                        } if metadata.worker_id.component_id == component_id
+                            && metadata.account_id == account_id
+                            && filter.as_ref().map_or(true, |f| f.matches(metadata)) =>
+                        {
+                            Some(golem::worker::WorkerStatusChange {
+                                metadata: Some(Self::create_proto_metadata(
+                                    metadata.clone(),
+                                    metadata.last_known_status.clone(),
+                                    None,
+                                )),
+                                previous_status: Into::<golem::worker::WorkerStatus>::into(
+                                    previous_status.clone(),
+                                )
+                                .into(),
+                                timestamp: Some((*timestamp).into()),
+                            })
+                        }
+                        _ => None,
+                    })
+                    .await;
+
+                match result {
+                    Ok(change) => Some((Ok(change), subscription)),
+                    Err(RecvError::Lagged(count)) => Some((
+                        Err(Status::resource_exhausted(format!(
+                            "Worker executor is overloaded, {count} worker status changes were dropped"
+                        ))),
+                        subscription,
+                    )),
+                    Err(RecvError::Closed) => None,
+                }
+            }
+        });
+
+        Ok(Box::pin(stream))
+    }
+
     async fn get_oplog_internal(
         &self,
         request: GetOplogRequest,
@@ -1230,15 +1397,9 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         self.validate_worker_status(&owned_worker_id, &metadata)
             .await?;
 
-        let worker = Worker::get_or_create_running(
-            self,
-            &owned_worker_id,
-            None,
-            None,
-            None,
-            None,
-        )
-        .await?;
+        let worker =
+            Worker::get_or_create_running(self, &owned_worker_id, None, None, None, None, None)
+                .await?;
 
         let node = worker.get_file_system_node(Path::new("/"))
             .await?;
@@ -1250,22 +1411,15 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(FileSystemNode::get_files_grpc(read_dir))
     }
 
-    async fn get_file_internal(
+    async fn existing_owned_worker_id(
         &self,
-        request: GetFileRequest,
-    ) -> Result<<Self as WorkerExecutor>::GetFileStream, GolemError> {
-        let GetFileRequest {
-            worker_id,
-            account_id,
-            path,
-        } = request;
-
-        let worker_id = worker_id
-            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        worker_id: Option<golem::worker::WorkerId>,
+        account_id: Option<golem::common::AccountId>,
+    ) -> Result<OwnedWorkerId, GolemError> {
+        let worker_id = worker_id.ok_or(GolemError::invalid_request("worker_id not found"))?;
         let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
 
-        let account_id = account_id
-            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id = account_id.ok_or(GolemError::invalid_request("account_id not found"))?;
         let account_id: AccountId = account_id.into();
 
         let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
@@ -1273,161 +1427,1199 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         self.ensure_worker_belongs_to_this_executor(&worker_id)?;
 
         let metadata = self.worker_service().get(&owned_worker_id).await;
-        self.validate_worker_status(&owned_worker_id, &metadata)
+        if metadata.is_none() {
+            return Err(GolemError::worker_not_found(worker_id));
+        }
+
+        Ok(owned_worker_id)
+    }
+
+    async fn schedule_invocation_internal(
+        &self,
+        request: ScheduleInvocationRequest,
+    ) -> Result<golem::worker::ScheduledInvocation, GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
             .await?;
 
-        let worker = Worker::get_or_create_running(
-            self,
-            &owned_worker_id,
-            None,
-            None,
-            None,
-            None,
-        )
-        .await?;
+        let function_input = request
+            .input
+            .into_iter()
+            .map(|val| val.try_into())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|msg| GolemError::ValueMismatch { details: msg })?;
 
-        let node = worker.get_file_system_node(Path::new(&path))
+        let schedule: InvocationSchedule = request
+            .schedule
+            .ok_or(GolemError::invalid_request("schedule not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let invocation = self
+            .scheduler_service()
+            .schedule_invocation(&owned_worker_id, request.name, function_input, schedule)
             .await?;
 
-        Ok(node.get_file_grpc())
+        Ok(invocation.into())
     }
 
-    fn create_proto_metadata(
-        metadata: WorkerMetadata,
-        latest_status: WorkerStatusRecord,
-        last_error_and_retry_count: Option<LastError>,
-    ) -> golem::worker::WorkerMetadata {
-        let mut updates = Vec::new();
+    async fn cancel_scheduled_invocation_internal(
+        &self,
+        request: CancelScheduledInvocationRequest,
+    ) -> Result<(), GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
 
-        for pending_invocation in &latest_status.pending_invocations {
-            if let TimestampedWorkerInvocation {
-                timestamp,
-                invocation: WorkerInvocation::ManualUpdate { target_version },
-            } = pending_invocation
-            {
-                updates.push(golem::worker::UpdateRecord {
-                    timestamp: Some((*timestamp).into()),
-                    target_version: *target_version,
-                    update: Some(golem::worker::update_record::Update::Pending(
-                        golem::worker::PendingUpdate {},
-                    )),
-                });
-            }
-        }
-        for pending_update in &latest_status.pending_updates {
-            updates.push(golem::worker::UpdateRecord {
-                timestamp: Some(pending_update.timestamp.into()),
-                target_version: *pending_update.description.target_version(),
-                update: Some(golem::worker::update_record::Update::Pending(
-                    golem::worker::PendingUpdate {},
-                )),
-            });
-        }
-        for successful_update in &latest_status.successful_updates {
-            updates.push(golem::worker::UpdateRecord {
-                timestamp: Some(successful_update.timestamp.into()),
-                target_version: successful_update.target_version,
-                update: Some(golem::worker::update_record::Update::Successful(
-                    golem::worker::SuccessfulUpdate {},
-                )),
-            });
-        }
-        for failed_update in &latest_status.failed_updates {
-            updates.push(golem::worker::UpdateRecord {
-                timestamp: Some(failed_update.timestamp.into()),
-                target_version: failed_update.target_version,
-                update: Some(golem::worker::update_record::Update::Failed(
-                    golem::worker::FailedUpdate {
-                        details: failed_update.details.clone(),
-                    },
-                )),
-            });
-        }
-        updates.sort_by_key(|record| {
-            record.timestamp.as_ref().unwrap().seconds * 1_000_000_000
-                + record.timestamp.as_ref().unwrap().nanos as i64
-        });
+        let schedule_id: ScheduledInvocationId = request
+            .schedule_id
+            .ok_or(GolemError::invalid_request("schedule_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
 
-        let mut owned_resources = HashMap::new();
-        for (resource_id, resource) in metadata.last_known_status.owned_resources {
-            owned_resources.insert(
-                resource_id.0,
-                ResourceMetadata {
-                    created_at: Some(resource.created_at.into()),
-                    indexed: resource.indexed_resource_key.map(|t| t.into()),
-                },
-            );
+        if self
+            .scheduler_service()
+            .cancel_invocation(&owned_worker_id, &schedule_id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(GolemError::invalid_request(format!(
+                "Scheduled invocation {schedule_id} not found"
+            )))
         }
+    }
 
-        golem::worker::WorkerMetadata {
-            worker_id: Some(metadata.worker_id.into()),
-            args: metadata.args.clone(),
-            env: HashMap::from_iter(metadata.env.iter().cloned()),
-            account_id: Some(metadata.account_id.into()),
-            component_version: latest_status.component_version,
-            status: Into::<golem::worker::WorkerStatus>::into(latest_status.status).into(),
-            retry_count: last_error_and_retry_count
-                .as_ref()
-                .map(|last_error| last_error.retry_count)
-                .unwrap_or_default(),
+    async fn get_scheduled_invocations_internal(
+        &self,
+        request: GetScheduledInvocationsRequest,
+    ) -> Result<Vec<golem::worker::ScheduledInvocation>, GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
 
-            pending_invocation_count: latest_status.pending_invocations.len() as u64,
-            updates,
-            created_at: Some(metadata.created_at.into()),
-            last_error: last_error_and_retry_count
-                .map(|last_error| last_error.error.to_string(&last_error.stderr)),
-            component_size: metadata.last_known_status.component_size,
-            total_linear_memory_size: metadata.last_known_status.total_linear_memory_size,
-            owned_resources,
-        }
+        let invocations = self
+            .scheduler_service()
+            .get_scheduled_invocations(&owned_worker_id)
+            .await?;
+
+        Ok(invocations.into_iter().map(|i| i.into()).collect())
     }
-}
 
-impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static> UsesAllDeps
-    for WorkerExecutorImpl<Ctx, Svcs>
-{
-    type Ctx = Ctx;
+    async fn get_dead_lettered_invocations_internal(
+        &self,
+        request: GetDeadLetteredInvocationsRequest,
+    ) -> Result<Vec<golem::worker::DeadLetteredInvocation>, GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
 
-    fn all(&self) -> &All<Ctx> {
-        self.services.all()
+        let invocations = self.dead_letter_service().get_all(&owned_worker_id).await?;
+
+        let mut result = Vec::new();
+        for invocation in invocations {
+            result.push(
+                self.dead_lettered_invocation_to_protobuf(invocation)
+                    .await?,
+            );
+        }
+        Ok(result)
     }
-}
 
-#[tonic::async_trait]
-impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static>
-    WorkerExecutor for WorkerExecutorImpl<Ctx, Svcs>
-{
-    async fn create_worker(
+    /// Annotates the stored input of a dead-lettered invocation with the parameter types of the
+    /// invoked function, as of the component version the invocation failed with
+    async fn dead_lettered_invocation_to_protobuf(
         &self,
-        request: Request<golem::workerexecutor::v1::CreateWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::CreateWorkerResponse>, Status> {
-        let request = request.into_inner();
+        invocation: DeadLetteredInvocation,
+    ) -> Result<golem::worker::DeadLetteredInvocation, GolemError> {
+        let metadata = self
+            .component_service()
+            .get_metadata(
+                &invocation.owned_worker_id.worker_id.component_id,
+                Some(invocation.component_version),
+            )
+            .await?;
+        let function = function_by_name(&metadata.exports, &invocation.full_function_name)
+            .map_err(GolemError::runtime)?
+            .ok_or(GolemError::runtime(format!(
+                "Dead-lettered function {} not found in component version {}",
+                invocation.full_function_name, invocation.component_version
+            )))?;
+        let input = function
+            .parameters
+            .iter()
+            .zip(invocation.function_input)
+            .map(|(param, value)| {
+                ValueAndType::new(value, param.typ.clone())
+                    .try_into()
+                    .map_err(|errors: Vec<String>| {
+                        GolemError::runtime(format!(
+                            "Failed to convert dead-lettered invocation input: {}",
+                            errors.join(", ")
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(golem::worker::DeadLetteredInvocation {
+            id: Some(invocation.id.into()),
+            worker_id: Some(invocation.owned_worker_id.worker_id.into()),
+            idempotency_key: Some(invocation.idempotency_key.into()),
+            function_name: invocation.full_function_name,
+            input,
+            error: invocation.error,
+            failed_at: Some(invocation.failed_at.into()),
+        })
+    }
+
+    async fn retry_dead_lettered_invocation_internal(
+        &self,
+        request: RetryDeadLetteredInvocationRequest,
+    ) -> Result<(), GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
+
+        let dead_letter_id: DeadLetteredInvocationId = request
+            .dead_letter_id
+            .ok_or(GolemError::invalid_request("dead_letter_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let invocation = self
+            .dead_letter_service()
+            .get(&owned_worker_id, &dead_letter_id)
+            .await?
+            .ok_or(GolemError::invalid_request(format!(
+                "Dead-lettered invocation {dead_letter_id} not found"
+            )))?;
+
+        let worker =
+            Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None, None)
+                .await?;
+        worker.retry_dead_lettered_invocation(&invocation).await?;
+
+        self.dead_letter_service()
+            .remove(&owned_worker_id, &dead_letter_id)
+            .await?;
+        Ok(())
+    }
+
+    async fn discard_dead_lettered_invocation_internal(
+        &self,
+        request: DiscardDeadLetteredInvocationRequest,
+    ) -> Result<(), GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
+
+        let dead_letter_id: DeadLetteredInvocationId = request
+            .dead_letter_id
+            .ok_or(GolemError::invalid_request("dead_letter_id not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        if self
+            .dead_letter_service()
+            .remove(&owned_worker_id, &dead_letter_id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(GolemError::invalid_request(format!(
+                "Dead-lettered invocation {dead_letter_id} not found"
+            )))
+        }
+    }
+
+    async fn get_dead_letter_policy_internal(
+        &self,
+        request: GetDeadLetterPolicyRequest,
+    ) -> Result<golem::worker::DeadLetterPolicy, GolemError> {
+        let component_id: ComponentId = request
+            .component_id
+            .and_then(|t| t.try_into().ok())
+            .ok_or(GolemError::invalid_request("Invalid component id"))?;
+
+        let policy = self.dead_letter_service().get_policy(&component_id).await?;
+
+        Ok(policy.into())
+    }
+
+    async fn set_dead_letter_policy_internal(
+        &self,
+        request: SetDeadLetterPolicyRequest,
+    ) -> Result<(), GolemError> {
+        let component_id: ComponentId = request
+            .component_id
+            .and_then(|t| t.try_into().ok())
+            .ok_or(GolemError::invalid_request("Invalid component id"))?;
+
+        let policy: DeadLetterPolicy = request
+            .policy
+            .ok_or(GolemError::invalid_request("policy not found"))?
+            .into();
+
+        self.dead_letter_service()
+            .set_policy(&component_id, policy)
+            .await
+    }
+
+    async fn topic_subscription_of_existing_worker(
+        &self,
+        subscription: Option<golem::worker::TopicSubscription>,
+        account_id: Option<golem::common::AccountId>,
+    ) -> Result<(OwnedWorkerId, TopicSubscription), GolemError> {
+        let subscription: TopicSubscription = subscription
+            .ok_or(GolemError::invalid_request("subscription not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+        let owned_worker_id = self
+            .existing_owned_worker_id(Some(subscription.worker_id.clone().into()), account_id)
+            .await?;
+        Ok((owned_worker_id, subscription))
+    }
+
+    async fn subscribe_to_topic_internal(
+        &self,
+        request: SubscribeToTopicRequest,
+    ) -> Result<(), GolemError> {
+        let (owned_worker_id, subscription) = self
+            .topic_subscription_of_existing_worker(request.subscription, request.account_id)
+            .await?;
+
+        self.pubsub_service()
+            .subscribe(&owned_worker_id.account_id, &subscription)
+            .await
+    }
+
+    async fn unsubscribe_from_topic_internal(
+        &self,
+        request: UnsubscribeFromTopicRequest,
+    ) -> Result<(), GolemError> {
+        let (owned_worker_id, subscription) = self
+            .topic_subscription_of_existing_worker(request.subscription, request.account_id)
+            .await?;
+
+        if self
+            .pubsub_service()
+            .unsubscribe(&owned_worker_id.account_id, &subscription)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(GolemError::invalid_request(format!(
+                "Worker is not subscribed to topic {} with {}",
+                subscription.topic, subscription.function_name
+            )))
+        }
+    }
+
+    async fn get_topic_subscriptions_internal(
+        &self,
+        request: GetTopicSubscriptionsRequest,
+    ) -> Result<Vec<golem::worker::TopicSubscription>, GolemError> {
+        let account_id: AccountId = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?
+            .into();
+
+        let subscriptions = self
+            .pubsub_service()
+            .get_subscriptions(&account_id, &request.topic)
+            .await?;
+
+        Ok(subscriptions.into_iter().map(|s| s.into()).collect())
+    }
+
+    async fn get_worker_topic_subscriptions_internal(
+        &self,
+        request: GetWorkerTopicSubscriptionsRequest,
+    ) -> Result<Vec<golem::worker::TopicSubscription>, GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
+
+        let subscriptions = self
+            .pubsub_service()
+            .get_worker_subscriptions(&owned_worker_id)
+            .await?;
+
+        Ok(subscriptions.into_iter().map(|s| s.into()).collect())
+    }
+
+    async fn publish_to_topic_internal(
+        &self,
+        request: PublishToTopicRequest,
+    ) -> Result<u64, GolemError> {
+        let account_id: AccountId = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?
+            .into();
+
+        let message_id = self
+            .pubsub_service()
+            .publish(&account_id, &request.topic, request.payload)
+            .await?;
+        pubsub::deliver(
+            &self.pubsub_service(),
+            &self.rpc(),
+            &self.scheduler_service(),
+            &account_id,
+            &request.topic,
+            self.config().scheduler.topic_redelivery_interval,
+        )
+        .await?;
+
+        Ok(message_id)
+    }
+
+    async fn fork_worker_internal(&self, request: ForkWorkerRequest) -> Result<(), GolemError> {
+        let source_worker_id = request
+            .source_worker_id
+            .ok_or(GolemError::invalid_request("source_worker_id not found"))?;
+        let source_worker_id: WorkerId = source_worker_id
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let target_worker_id = request
+            .target_worker_id
+            .ok_or(GolemError::invalid_request("target_worker_id not found"))?;
+        let target_worker_id: WorkerId = target_worker_id
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        let account_id = request
+            .account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        if target_worker_id.component_id != source_worker_id.component_id {
+            return Err(GolemError::invalid_request(
+                "The source and target workers must belong to the same component",
+            ));
+        }
+
+        // The forked worker is created by the executor owning the target worker's shard, the
+        // source oplog is read directly from the shared oplog storage
+        self.ensure_worker_belongs_to_this_executor(&target_worker_id)?;
+
+        let source_owned_worker_id = OwnedWorkerId::new(&account_id, &source_worker_id);
+        let source_metadata = self.worker_service().get(&source_owned_worker_id).await;
+        if source_metadata.is_none() {
+            return Err(GolemError::worker_not_found(source_worker_id));
+        }
+
+        let target_owned_worker_id = OwnedWorkerId::new(&account_id, &target_worker_id);
+        if self.oplog_service().exists(&target_owned_worker_id).await {
+            return Err(GolemError::worker_already_exists(target_worker_id));
+        }
+
+        let last_index = self
+            .oplog_service()
+            .get_last_index(&source_owned_worker_id)
+            .await;
+        let oplog_index_cutoff = OplogIndex::from_u64(request.oplog_index_cutoff);
+        if oplog_index_cutoff < OplogIndex::INITIAL || oplog_index_cutoff > last_index {
+            return Err(GolemError::invalid_request(format!(
+                "Oplog index {oplog_index_cutoff} is out of range, the source worker's last oplog index is {last_index}"
+            )));
+        }
+
+        // Entries skipped by a jump are never replayed, so the forked worker could not be
+        // restored to the state at such a cutoff
+        let source_status =
+            calculate_last_known_status(self, &source_owned_worker_id, &source_metadata).await?;
+        if source_status
+            .deleted_regions
+            .is_in_deleted_region(oplog_index_cutoff)
+        {
+            return Err(GolemError::invalid_request(format!(
+                "Cannot fork at oplog index {oplog_index_cutoff} because it is in a skipped region"
+            )));
+        }
+
+        let component_version = find_component_version_at(
+            self.oplog_service(),
+            &source_owned_worker_id,
+            oplog_index_cutoff,
+        )
+        .await?;
+        let component_metadata = self
+            .component_service()
+            .get_metadata(
+                &source_owned_worker_id.component_id(),
+                Some(component_version),
+            )
+            .await?;
+
+        self.oplog_service()
+            .fork(
+                &source_owned_worker_id,
+                &target_owned_worker_id,
+                oplog_index_cutoff,
+                component_metadata.component_type,
+            )
+            .await
+    }
+
+    async fn revert_worker_internal(&self, request: RevertWorkerRequest) -> Result<(), GolemError> {
+        let owned_worker_id = self
+            .existing_owned_worker_id(request.worker_id, request.account_id)
+            .await?;
+
+        let target: RevertWorkerTarget = request
+            .target
+            .ok_or(GolemError::invalid_request("target not found"))?
+            .try_into()
+            .map_err(GolemError::invalid_request)?;
+
+        // Rejecting obviously invalid requests before interrupting the worker
+        match &target {
+            RevertWorkerTarget::RevertToOplogIndex(target) => {
+                let last_index = self.oplog_service().get_last_index(&owned_worker_id).await;
+                if target.last_oplog_index < OplogIndex::INITIAL
+                    || target.last_oplog_index >= last_index
+                {
+                    return Err(GolemError::invalid_request(format!(
+                        "Cannot revert to oplog index {}, the last oplog index is {last_index}",
+                        target.last_oplog_index
+                    )));
+                }
+            }
+            RevertWorkerTarget::RevertLastInvocations(target) => {
+                if target.number_of_invocations == 0 {
+                    return Err(GolemError::invalid_request(
+                        "The number of invocations to revert must be positive",
+                    ));
+                }
+            }
+        }
+
+        let worker =
+            Worker::get_or_create_suspended(self, &owned_worker_id, None, None, None, None, None)
+                .await?;
+        if let Some(mut await_interrupted) = worker.set_interrupting(InterruptKind::Interrupt).await
+        {
+            await_interrupted.recv().await.map_err(|_| {
+                GolemError::runtime("The worker got dropped while interrupting it for reverting")
+            })?;
+        }
+        worker.stop().await;
+
+        let result = worker.revert(target).await;
+
+        // Dropping the in-memory state, so the worker gets recovered from its truncated history
+        self.active_workers().remove(&owned_worker_id.worker_id);
+        result?;
+
+        Worker::get_or_create_running(self, &owned_worker_id, None, None, None, None, None).await?;
+
+        Ok(())
+    }
+
+    async fn get_file_internal(
+        &self,
+        request: GetFileRequest,
+    ) -> Result<<Self as WorkerExecutor>::GetFileStream, GolemError> {
+        let GetFileRequest {
+            worker_id,
+            account_id,
+            path,
+        } = request;
+
+        let worker_id = worker_id
+            .ok_or(GolemError::invalid_request("worker_id not found"))?;
+        let worker_id: WorkerId = worker_id.try_into().map_err(GolemError::invalid_request)?;
+
+        let account_id = account_id
+            .ok_or(GolemError::invalid_request("account_id not found"))?;
+        let account_id: AccountId = account_id.into();
+
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        self.validate_worker_status(&owned_worker_id, &metadata)
+            .await?;
+
+        let worker =
+            Worker::get_or_create_running(self, &owned_worker_id, None, None, None, None, None)
+                .await?;
+
+        let node = worker.get_file_system_node(Path::new(&path))
+            .await?;
+
+        Ok(node.get_file_grpc())
+    }
+
+    async fn put_file_internal(&self, request: PutFileRequest) -> Result<(), GolemError> {
+        let worker = self
+            .file_system_worker(request.worker_id, request.account_id)
+            .await?;
+        worker.write_file(request.path, request.content).await
+    }
+
+    async fn delete_file_internal(&self, request: DeleteFileRequest) -> Result<(), GolemError> {
+        let worker = self
+            .file_system_worker(request.worker_id, request.account_id)
+            .await?;
+        worker.delete_file(request.path).await
+    }
+
+    async fn make_directory_internal(
+        &self,
+        request: MakeDirectoryRequest,
+    ) -> Result<(), GolemError> {
+        let worker = self
+            .file_system_worker(request.worker_id, request.account_id)
+            .await?;
+        worker.make_directory(request.path).await
+    }
+
+    /// The worker's file system only exists while it is running
+    async fn file_system_worker(
+        &self,
+        worker_id: Option<golem::worker::WorkerId>,
+        account_id: Option<golem::common::AccountId>,
+    ) -> Result<Arc<Worker<Ctx>>, GolemError> {
+        let owned_worker_id = self.existing_owned_worker_id(worker_id, account_id).await?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        self.validate_worker_status(&owned_worker_id, &metadata)
+            .await?;
+
+        Worker::get_or_create_running(self, &owned_worker_id, None, None, None, None, None).await
+    }
+
+    fn create_proto_metadata(
+        metadata: WorkerMetadata,
+        latest_status: WorkerStatusRecord,
+        last_error_and_retry_count: Option<LastError>,
+    ) -> golem::worker::WorkerMetadata {
+        let mut updates = Vec::new();
+
+        for pending_invocation in &latest_status.pending_invocations {
+            if let TimestampedWorkerInvocation {
+                timestamp,
+                invocation: WorkerInvocation::ManualUpdate { target_version },
+            } = pending_invocation
+            {
+                updates.push(golem::worker::UpdateRecord {
+                    timestamp: Some((*timestamp).into()),
+                    target_version: *target_version,
+                    update: Some(golem::worker::update_record::Update::Pending(
+                        golem::worker::PendingUpdate {},
+                    )),
+                });
+            }
+        }
+        for pending_update in &latest_status.pending_updates {
+            updates.push(golem::worker::UpdateRecord {
+                timestamp: Some(pending_update.timestamp.into()),
+                target_version: *pending_update.description.target_version(),
+                update: Some(golem::worker::update_record::Update::Pending(
+                    golem::worker::PendingUpdate {},
+                )),
+            });
+        }
+        for successful_update in &latest_status.successful_updates {
+            updates.push(golem::worker::UpdateRecord {
+                timestamp: Some(successful_update.timestamp.into()),
+                target_version: successful_update.target_version,
+                update: Some(golem::worker::update_record::Update::Successful(
+                    golem::worker::SuccessfulUpdate {},
+                )),
+            });
+        }
+        for failed_update in &latest_status.failed_updates {
+            updates.push(golem::worker::UpdateRecord {
+                timestamp: Some(failed_update.timestamp.into()),
+                target_version: failed_update.target_version,
+                update: Some(golem::worker::update_record::Update::Failed(
+                    golem::worker::FailedUpdate {
+                        details: failed_update.details.clone(),
+                    },
+                )),
+            });
+        }
+        updates.sort_by_key(|record| {
+            record.timestamp.as_ref().unwrap().seconds * 1_000_000_000
+                + record.timestamp.as_ref().unwrap().nanos as i64
+        });
+
+        let mut owned_resources = HashMap::new();
+        for (resource_id, resource) in metadata.last_known_status.owned_resources {
+            owned_resources.insert(
+                resource_id.0,
+                ResourceMetadata {
+                    created_at: Some(resource.created_at.into()),
+                    indexed: resource.indexed_resource_key.map(|t| t.into()),
+                },
+            );
+        }
+
+        golem::worker::WorkerMetadata {
+            worker_id: Some(metadata.worker_id.into()),
+            args: metadata.args.clone(),
+            env: HashMap::from_iter(metadata.env.iter().cloned()),
+            account_id: Some(metadata.account_id.into()),
+            component_version: latest_status.component_version,
+            status: Into::<golem::worker::WorkerStatus>::into(latest_status.status).into(),
+            retry_count: last_error_and_retry_count
+                .as_ref()
+                .map(|last_error| last_error.retry_count)
+                .unwrap_or_default(),
+
+            pending_invocation_count: latest_status.pending_invocations.len() as u64,
+            updates,
+            created_at: Some(metadata.created_at.into()),
+            last_error: last_error_and_retry_count
+                .map(|last_error| last_error.error.to_string(&last_error.stderr)),
+            component_size: metadata.last_known_status.component_size,
+            total_linear_memory_size: metadata.last_known_status.total_linear_memory_size,
+            owned_resources,
+            quotas: Some(metadata.quotas.into()),
+            quota_usage: Some(golem::worker::WorkerQuotaUsage {
+                memory: metadata.last_known_status.total_linear_memory_size,
+                oplog_entries: u64::from(latest_status.oplog_idx),
+                oplog_bytes: None,
+                fuel_consumed_by_last_invocation: None,
+                outgoing_http_calls_in_last_minute: None,
+            }),
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static> UsesAllDeps
+    for WorkerExecutorImpl<Ctx, Svcs>
+{
+    type Ctx = Ctx;
+
+    fn all(&self) -> &All<Ctx> {
+        self.services.all()
+    }
+}
+
+#[tonic::async_trait]
+impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static>
+    WorkerExecutor for WorkerExecutorImpl<Ctx, Svcs>
+{
+    async fn create_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::CreateWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::CreateWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "create_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            component_version = request.component_version,
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self
+            .create_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::CreateWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::create_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::CreateWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::create_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_and_await_worker(
+        &self,
+        request: Request<InvokeAndAwaitWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_and_await_worker",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self.invoke_and_await_worker_internal_proto(&request).instrument(record.span.clone()).await {
+            Ok(output) => {
+                let result = InvokeAndAwaitWorkerSuccess { output };
+
+                record.succeed(Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Success(result),
+                        ),
+                    },
+                )))
+            }
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_and_await_worker_typed(
+        &self,
+        request: Request<InvokeAndAwaitWorkerRequest>,
+    ) -> Result<Response<InvokeAndAwaitWorkerResponseTyped>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_and_await_worker_json_typed",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self.invoke_and_await_worker_internal_typed(&request).instrument(record.span.clone()).await {
+            Ok(type_annotated_value) => {
+                let result = golem::workerexecutor::v1::InvokeAndAwaitWorkerSuccessTyped {
+                    output: Some(golem_wasm_rpc::protobuf::TypeAnnotatedValue {
+                        type_annotated_value: Some(type_annotated_value),
+                    })
+                };
+
+                record.succeed(Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Success(result),
+                        ),
+                    },
+                )))
+            }
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::InvokeWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::InvokeWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_worker",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            function = request.name,
+            account_id = proto_account_id_string(&request.account_id)
+        );
+
+        match self
+            .invoke_worker_internal(&request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::InvokeWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::invoke_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    type ConnectWorkerStream = ResponseStream;
+
+    async fn connect_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::ConnectWorkerRequest>,
+    ) -> ResponseResult<Self::ConnectWorkerStream> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "connect_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            account_id = proto_account_id_string(&request.account_id)
+        );
+
+        self.connect_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+    }
+
+    type WatchWorkersStream = BoxStream<'static, Result<golem::worker::WorkerStatusChange, Status>>;
+
+    async fn watch_workers(
+        &self,
+        request: Request<WatchWorkersRequest>,
+    ) -> ResponseResult<Self::WatchWorkersStream> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "watch_workers",
+            component_id = proto_component_id_string(&request.component_id),
+            account_id = proto_account_id_string(&request.account_id)
+        );
+
+        let result = self
+            .watch_workers_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(stream) => record.succeed(Ok(Response::new(stream))),
+            Err(err) => record.fail(Err(err.clone().into()), &err),
+        }
+    }
+
+    async fn delete_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::DeleteWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::DeleteWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_worker",
+            worker_id = proto_worker_id_string(&request.worker_id)
+        );
+
+        match self
+            .delete_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::DeleteWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::delete_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::DeleteWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::delete_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn complete_promise(
+        &self,
+        request: Request<golem::workerexecutor::v1::CompletePromiseRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::CompletePromiseResponse>, Status> {
+        let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "create_worker",
+            "complete_promise",
+            promise_id = proto_promise_id_string(&request.promise_id)
+        );
+
+        match self
+            .complete_promise_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(success) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::CompletePromiseResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::complete_promise_response::Result::Success(
+                            success,
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::CompletePromiseResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::complete_promise_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn interrupt_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::InterruptWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::InterruptWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "interrupt_worker",
             worker_id = proto_worker_id_string(&request.worker_id),
-            component_version = request.component_version,
-            account_id = proto_account_id_string(&request.account_id),
         );
 
         match self
-            .create_worker_internal(request)
+            .interrupt_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::InterruptWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::interrupt_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InterruptWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::interrupt_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn revoke_shards(
+        &self,
+        request: Request<golem::workerexecutor::v1::RevokeShardsRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::RevokeShardsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("revoke_shards",);
+
+        match self
+            .revoke_shards_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::RevokeShardsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::revoke_shards_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::RevokeShardsResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::revoke_shards_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn assign_shards(
+        &self,
+        request: Request<golem::workerexecutor::v1::AssignShardsRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::AssignShardsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("assign_shards",);
+
+        match self
+            .assign_shards_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::AssignShardsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::assign_shards_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::AssignShardsResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::assign_shards_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_worker_metadata(
+        &self,
+        request: Request<golem::workerexecutor::v1::GetWorkerMetadataRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::GetWorkerMetadataResponse>, Status> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "get_worker_metadata",
+            worker_id = proto_worker_id_string(&request.worker_id)
+        );
+
+        let result = self
+            .get_worker_metadata_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(result) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Success(
+                            result,
+                        ),
+                    ),
+                },
+            ))),
+            Err(err @ GolemError::WorkerNotFound { .. }) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn resume_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::ResumeWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::ResumeWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "resume_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        match self
+            .resume_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::ResumeWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::resume_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::ResumeWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::resume_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_running_workers_metadata(
+        &self,
+        request: Request<GetRunningWorkersMetadataRequest>,
+    ) -> Result<Response<GetRunningWorkersMetadataResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_running_workers_metadata",
+            component_id = proto_component_id_string(&request.component_id),
+        );
+
+        let result = self
+            .get_running_workers_metadata_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::CreateWorkerResponse {
+            .await;
+        match result {
+            Ok(workers) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::GetRunningWorkersMetadataResponse {
                     result: Some(
-                        golem::workerexecutor::v1::create_worker_response::Result::Success(
-                            golem::common::Empty {},
+                        golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Success(
+                            golem::workerexecutor::v1::GetRunningWorkersMetadataSuccessResponse {
+                                workers
+                            }
                         ),
                     ),
                 },
             ))),
             Err(err) => record.fail(
                 Ok(Response::new(
-                    golem::workerexecutor::v1::CreateWorkerResponse {
+                    GetRunningWorkersMetadataResponse {
                         result: Some(
-                            golem::workerexecutor::v1::create_worker_response::Result::Failure(
+                            golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Failure(
                                 err.clone().into(),
                             ),
                         ),
@@ -1438,496 +2630,624 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn invoke_and_await_worker(
+    async fn get_workers_metadata(
         &self,
-        request: Request<InvokeAndAwaitWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse>, Status> {
+        request: Request<GetWorkersMetadataRequest>,
+    ) -> Result<Response<GetWorkersMetadataResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_and_await_worker",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
-            account_id = proto_account_id_string(&request.account_id),
+            "get_workers_metadata",
+            component_id = proto_component_id_string(&request.component_id),
         );
 
-        match self.invoke_and_await_worker_internal_proto(&request).instrument(record.span.clone()).await {
-            Ok(output) => {
-                let result = InvokeAndAwaitWorkerSuccess { output };
-
-                record.succeed(Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Success(result),
+        let result = self
+            .get_workers_metadata_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok((cursor, workers)) => {
+                record.succeed(Ok(Response::new(GetWorkersMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Success(
+                            golem::workerexecutor::v1::GetWorkersMetadataSuccessResponse {
+                                workers,
+                                cursor,
+                            },
                         ),
-                    },
-                )))
+                    ),
+                })))
             }
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetWorkersMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn invoke_and_await_worker_typed(
+    async fn update_worker(
         &self,
-        request: Request<InvokeAndAwaitWorkerRequest>,
-    ) -> Result<Response<InvokeAndAwaitWorkerResponseTyped>, Status> {
+        request: Request<UpdateWorkerRequest>,
+    ) -> Result<Response<UpdateWorkerResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_and_await_worker_json_typed",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
-            account_id = proto_account_id_string(&request.account_id),
+            "update_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            target_version = request.target_version,
         );
 
-        match self.invoke_and_await_worker_internal_typed(&request).instrument(record.span.clone()).await {
-            Ok(type_annotated_value) => {
-                let result = golem::workerexecutor::v1::InvokeAndAwaitWorkerSuccessTyped {
-                    output: Some(golem_wasm_rpc::protobuf::TypeAnnotatedValue {
-                        type_annotated_value: Some(type_annotated_value),
-                    })
-                };
+        match self
+            .update_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(UpdateWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::update_worker_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(UpdateWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::update_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
 
-                record.succeed(Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Success(result),
+    async fn get_oplog(
+        &self,
+        request: Request<GetOplogRequest>,
+    ) -> Result<Response<GetOplogResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_oplog",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .get_oplog_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetOplogResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_oplog_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )))
-            }
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_files(
+        &self,
+        request: Request<GetFilesRequest>,
+    ) -> Result<Response<GetFilesResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_files",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .get_files_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
+                Ok(Response::new(GetFilesResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_files_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    type GetFileStream = BoxStream<'static, Result<GetFileResponse, Status>>;
+
+    async fn get_file(
+        &self,
+        request: Request<GetFileRequest>,
+    ) -> ResponseResult<Self::GetFileStream> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "get_file",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .get_file_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Err(err) => {
+                record.fail((), &err);
+                Ok(Response::new(Box::pin(futures::stream::once(async move {
+                    Ok(GetFileResponse {
                         result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Failure(
-                                err.clone().into(),
+                            golem::workerexecutor::v1::get_file_response::Result::Failure(
+                                err.into(),
                             ),
                         ),
-                    },
-                )),
+                    })
+                }))))
+            }
+        }   
+    }
+
+    async fn schedule_invocation(
+        &self,
+        request: Request<ScheduleInvocationRequest>,
+    ) -> Result<Response<ScheduleInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "schedule_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            function = request.name,
+        );
+
+        let result = self
+            .schedule_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(invocation) => record.succeed(Ok(Response::new(ScheduleInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::schedule_invocation_response::Result::Success(
+                        invocation,
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ScheduleInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::schedule_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn cancel_scheduled_invocation(
+        &self,
+        request: Request<CancelScheduledInvocationRequest>,
+    ) -> Result<Response<CancelScheduledInvocationResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "cancel_scheduled_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .cancel_scheduled_invocation_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(CancelScheduledInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::cancel_scheduled_invocation_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(CancelScheduledInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::cancel_scheduled_invocation_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn invoke_worker(
+    async fn get_scheduled_invocations(
         &self,
-        request: Request<golem::workerexecutor::v1::InvokeWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::InvokeWorkerResponse>, Status> {
+        request: Request<GetScheduledInvocationsRequest>,
+    ) -> Result<Response<GetScheduledInvocationsResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_worker",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            function = request.name,
-            account_id = proto_account_id_string(&request.account_id)
+            "get_scheduled_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self
-            .invoke_worker_internal(&request)
+        let result = self
+            .get_scheduled_invocations_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::InvokeWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::invoke_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+        match result {
+            Ok(invocations) => record.succeed(Ok(Response::new(GetScheduledInvocationsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_scheduled_invocations_response::Result::Success(
+                        golem::workerexecutor::v1::GetScheduledInvocationsSuccessResponse {
+                            invocations,
+                        },
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetScheduledInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_scheduled_invocations_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    type ConnectWorkerStream = ResponseStream;
-
-    async fn connect_worker(
+    async fn fork_worker(
         &self,
-        request: Request<golem::workerexecutor::v1::ConnectWorkerRequest>,
-    ) -> ResponseResult<Self::ConnectWorkerStream> {
+        request: Request<ForkWorkerRequest>,
+    ) -> Result<Response<ForkWorkerResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "connect_worker",
-            worker_id = proto_worker_id_string(&request.worker_id),
-            account_id = proto_account_id_string(&request.account_id)
+            "fork_worker",
+            worker_id = proto_worker_id_string(&request.source_worker_id),
+            target_worker_id = proto_worker_id_string(&request.target_worker_id),
+            oplog_index_cutoff = request.oplog_index_cutoff,
         );
 
-        self.connect_worker_internal(request)
+        let result = self
+            .fork_worker_internal(request)
             .instrument(record.span.clone())
-            .await
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(ForkWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::fork_worker_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ForkWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::fork_worker_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
     }
 
-    async fn delete_worker(
+    async fn revert_worker(
         &self,
-        request: Request<golem::workerexecutor::v1::DeleteWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::DeleteWorkerResponse>, Status> {
+        request: Request<RevertWorkerRequest>,
+    ) -> Result<Response<RevertWorkerResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "delete_worker",
-            worker_id = proto_worker_id_string(&request.worker_id)
+            "revert_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self
-            .delete_worker_internal(request)
+        let result = self
+            .revert_worker_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::DeleteWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::delete_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(RevertWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::revert_worker_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::DeleteWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::delete_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(RevertWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::revert_worker_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn complete_promise(
+    async fn put_file(
         &self,
-        request: Request<golem::workerexecutor::v1::CompletePromiseRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::CompletePromiseResponse>, Status> {
+        request: Request<PutFileRequest>,
+    ) -> Result<Response<PutFileResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "complete_promise",
-            promise_id = proto_promise_id_string(&request.promise_id)
+            "put_file",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            path = request.path,
         );
 
-        match self
-            .complete_promise_internal(request)
+        let result = self
+            .put_file_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(success) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::CompletePromiseResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::complete_promise_response::Result::Success(
-                            success,
-                        ),
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(PutFileResponse {
+                result: Some(
+                    golem::workerexecutor::v1::put_file_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::CompletePromiseResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::complete_promise_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(PutFileResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::put_file_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn interrupt_worker(
+    async fn delete_file(
         &self,
-        request: Request<golem::workerexecutor::v1::InterruptWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::InterruptWorkerResponse>, Status> {
+        request: Request<DeleteFileRequest>,
+    ) -> Result<Response<DeleteFileResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "interrupt_worker",
+            "delete_file",
             worker_id = proto_worker_id_string(&request.worker_id),
+            path = request.path,
         );
 
-        match self
-            .interrupt_worker_internal(request)
+        let result = self
+            .delete_file_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::InterruptWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::interrupt_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(DeleteFileResponse {
+                result: Some(
+                    golem::workerexecutor::v1::delete_file_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InterruptWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::interrupt_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(DeleteFileResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::delete_file_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn revoke_shards(
+    async fn make_directory(
         &self,
-        request: Request<golem::workerexecutor::v1::RevokeShardsRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::RevokeShardsResponse>, Status> {
+        request: Request<MakeDirectoryRequest>,
+    ) -> Result<Response<MakeDirectoryResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!("revoke_shards",);
+        let record = recorded_grpc_api_request!(
+            "make_directory",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            path = request.path,
+        );
 
-        match self
-            .revoke_shards_internal(request)
+        let result = self
+            .make_directory_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::RevokeShardsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::revoke_shards_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(MakeDirectoryResponse {
+                result: Some(
+                    golem::workerexecutor::v1::make_directory_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::RevokeShardsResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::revoke_shards_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(MakeDirectoryResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::make_directory_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn assign_shards(
+    async fn search_oplog(
         &self,
-        request: Request<golem::workerexecutor::v1::AssignShardsRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::AssignShardsResponse>, Status> {
+        request: Request<SearchOplogRequest>,
+    ) -> Result<Response<SearchOplogResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!("assign_shards",);
+        let record = recorded_grpc_api_request!(
+            "search_oplog",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
 
-        match self
-            .assign_shards_internal(request)
+        let result = self
+            .search_oplog_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::AssignShardsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::assign_shards_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
-                    ),
-                },
-            ))),
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::AssignShardsResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::assign_shards_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(SearchOplogResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::search_oplog_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn get_worker_metadata(
+    async fn get_dead_lettered_invocations(
         &self,
-        request: Request<golem::workerexecutor::v1::GetWorkerMetadataRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::GetWorkerMetadataResponse>, Status> {
+        request: Request<GetDeadLetteredInvocationsRequest>,
+    ) -> Result<Response<GetDeadLetteredInvocationsResponse>, Status> {
         let request = request.into_inner();
-
         let record = recorded_grpc_api_request!(
-            "get_worker_metadata",
-            worker_id = proto_worker_id_string(&request.worker_id)
+            "get_dead_lettered_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .get_worker_metadata_internal(request)
+            .get_dead_lettered_invocations_internal(request)
             .instrument(record.span.clone())
             .await;
         match result {
-            Ok(result) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::GetWorkerMetadataResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Success(
-                            result,
-                        ),
+            Ok(invocations) => record.succeed(Ok(Response::new(GetDeadLetteredInvocationsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_dead_lettered_invocations_response::Result::Success(
+                        golem::workerexecutor::v1::GetDeadLetteredInvocationsSuccessResponse {
+                            invocations,
+                        },
                     ),
-                },
-            ))),
-            Err(err @ GolemError::WorkerNotFound { .. }) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetDeadLetteredInvocationsResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
+                        golem::workerexecutor::v1::get_dead_lettered_invocations_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
-                },
-            ))),
-            Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::GetWorkerMetadataResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
-                                err.clone().into(),
-                            ),
-                        ),
-                    },
-                )),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn resume_worker(
+    async fn retry_dead_lettered_invocation(
         &self,
-        request: Request<golem::workerexecutor::v1::ResumeWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::ResumeWorkerResponse>, Status> {
+        request: Request<RetryDeadLetteredInvocationRequest>,
+    ) -> Result<Response<RetryDeadLetteredInvocationResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "resume_worker",
+            "retry_dead_lettered_invocation",
             worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self
-            .resume_worker_internal(request)
+        let result = self
+            .retry_dead_lettered_invocation_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::ResumeWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::resume_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(RetryDeadLetteredInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::retry_dead_lettered_invocation_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::ResumeWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::resume_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(RetryDeadLetteredInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::retry_dead_lettered_invocation_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn get_running_workers_metadata(
+    async fn discard_dead_lettered_invocation(
         &self,
-        request: Request<GetRunningWorkersMetadataRequest>,
-    ) -> Result<Response<GetRunningWorkersMetadataResponse>, Status> {
+        request: Request<DiscardDeadLetteredInvocationRequest>,
+    ) -> Result<Response<DiscardDeadLetteredInvocationResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "get_running_workers_metadata",
-            component_id = proto_component_id_string(&request.component_id),
+            "discard_dead_lettered_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .get_running_workers_metadata_internal(request)
+            .discard_dead_lettered_invocation_internal(request)
             .instrument(record.span.clone())
             .await;
         match result {
-            Ok(workers) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::GetRunningWorkersMetadataResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Success(
-                            golem::workerexecutor::v1::GetRunningWorkersMetadataSuccessResponse {
-                                workers
-                            }
-                        ),
+            Ok(_) => record.succeed(Ok(Response::new(DiscardDeadLetteredInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::discard_dead_lettered_invocation_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    GetRunningWorkersMetadataResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(DiscardDeadLetteredInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::discard_dead_lettered_invocation_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn get_workers_metadata(
+    async fn get_dead_letter_policy(
         &self,
-        request: Request<GetWorkersMetadataRequest>,
-    ) -> Result<Response<GetWorkersMetadataResponse>, Status> {
+        request: Request<GetDeadLetterPolicyRequest>,
+    ) -> Result<Response<GetDeadLetterPolicyResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "get_workers_metadata",
+            "get_dead_letter_policy",
             component_id = proto_component_id_string(&request.component_id),
         );
 
         let result = self
-            .get_workers_metadata_internal(request)
+            .get_dead_letter_policy_internal(request)
             .instrument(record.span.clone())
             .await;
         match result {
-            Ok((cursor, workers)) => {
-                record.succeed(Ok(Response::new(GetWorkersMetadataResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Success(
-                            golem::workerexecutor::v1::GetWorkersMetadataSuccessResponse {
-                                workers,
-                                cursor,
-                            },
-                        ),
+            Ok(policy) => record.succeed(Ok(Response::new(GetDeadLetterPolicyResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_dead_letter_policy_response::Result::Success(
+                        policy,
                     ),
-                })))
-            }
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(GetWorkersMetadataResponse {
+                Ok(Response::new(GetDeadLetterPolicyResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Failure(
+                        golem::workerexecutor::v1::get_dead_letter_policy_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -1937,33 +3257,32 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn update_worker(
+    async fn set_dead_letter_policy(
         &self,
-        request: Request<UpdateWorkerRequest>,
-    ) -> Result<Response<UpdateWorkerResponse>, Status> {
+        request: Request<SetDeadLetterPolicyRequest>,
+    ) -> Result<Response<SetDeadLetterPolicyResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "update_worker",
-            worker_id = proto_worker_id_string(&request.worker_id),
-            target_version = request.target_version,
+            "set_dead_letter_policy",
+            component_id = proto_component_id_string(&request.component_id),
         );
 
-        match self
-            .update_worker_internal(request)
+        let result = self
+            .set_dead_letter_policy_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(UpdateWorkerResponse {
+            .await;
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(SetDeadLetterPolicyResponse {
                 result: Some(
-                    golem::workerexecutor::v1::update_worker_response::Result::Success(
+                    golem::workerexecutor::v1::set_dead_letter_policy_response::Result::Success(
                         golem::common::Empty {},
                     ),
                 ),
             }))),
             Err(err) => record.fail(
-                Ok(Response::new(UpdateWorkerResponse {
+                Ok(Response::new(SetDeadLetterPolicyResponse {
                     result: Some(
-                        golem::workerexecutor::v1::update_worker_response::Result::Failure(
+                        golem::workerexecutor::v1::set_dead_letter_policy_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -1973,26 +3292,37 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn get_oplog(
+    async fn subscribe_to_topic(
         &self,
-        request: Request<GetOplogRequest>,
-    ) -> Result<Response<GetOplogResponse>, Status> {
+        request: Request<SubscribeToTopicRequest>,
+    ) -> Result<Response<SubscribeToTopicResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "get_oplog",
-            worker_id = proto_worker_id_string(&request.worker_id),
+            "subscribe_to_topic",
+            worker_id = proto_worker_id_string(
+                &request
+                    .subscription
+                    .as_ref()
+                    .and_then(|s| s.worker_id.clone())
+            ),
         );
 
         let result = self
-            .get_oplog_internal(request)
+            .subscribe_to_topic_internal(request)
             .instrument(record.span.clone())
             .await;
         match result {
-            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Ok(_) => record.succeed(Ok(Response::new(SubscribeToTopicResponse {
+                result: Some(
+                    golem::workerexecutor::v1::subscribe_to_topic_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(GetOplogResponse {
+                Ok(Response::new(SubscribeToTopicResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_oplog_response::Result::Failure(
+                        golem::workerexecutor::v1::subscribe_to_topic_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2002,26 +3332,37 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn get_files(
+    async fn unsubscribe_from_topic(
         &self,
-        request: Request<GetFilesRequest>,
-    ) -> Result<Response<GetFilesResponse>, Status> {
+        request: Request<UnsubscribeFromTopicRequest>,
+    ) -> Result<Response<UnsubscribeFromTopicResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "get_files",
-            worker_id = proto_worker_id_string(&request.worker_id),
+            "unsubscribe_from_topic",
+            worker_id = proto_worker_id_string(
+                &request
+                    .subscription
+                    .as_ref()
+                    .and_then(|s| s.worker_id.clone())
+            ),
         );
 
         let result = self
-            .get_files_internal(request)
+            .unsubscribe_from_topic_internal(request)
             .instrument(record.span.clone())
             .await;
         match result {
-            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Ok(_) => record.succeed(Ok(Response::new(UnsubscribeFromTopicResponse {
+                result: Some(
+                    golem::workerexecutor::v1::unsubscribe_from_topic_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(GetFilesResponse {
+                Ok(Response::new(UnsubscribeFromTopicResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_files_response::Result::Failure(
+                        golem::workerexecutor::v1::unsubscribe_from_topic_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2031,60 +3372,100 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    type GetFileStream = BoxStream<'static, Result<GetFileResponse, Status>>;
+    async fn get_topic_subscriptions(
+        &self,
+        request: Request<GetTopicSubscriptionsRequest>,
+    ) -> Result<Response<GetTopicSubscriptionsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("get_topic_subscriptions", topic = request.topic);
 
-    async fn get_file(
+        let result = self
+            .get_topic_subscriptions_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(subscriptions) => record.succeed(Ok(Response::new(GetTopicSubscriptionsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_topic_subscriptions_response::Result::Success(
+                        golem::workerexecutor::v1::GetTopicSubscriptionsSuccessResponse {
+                            subscriptions,
+                        },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetTopicSubscriptionsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_topic_subscriptions_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_worker_topic_subscriptions(
         &self,
-        request: Request<GetFileRequest>,
-    ) -> ResponseResult<Self::GetFileStream> {
+        request: Request<GetWorkerTopicSubscriptionsRequest>,
+    ) -> Result<Response<GetTopicSubscriptionsResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "get_file",
+            "get_worker_topic_subscriptions",
             worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .get_file_internal(request)
+            .get_worker_topic_subscriptions_internal(request)
             .instrument(record.span.clone())
             .await;
-
         match result {
-            Ok(response) => record.succeed(Ok(Response::new(response))),
-            Err(err) => {
-                record.fail((), &err);
-                Ok(Response::new(Box::pin(futures::stream::once(async move {
-                    Ok(GetFileResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::get_file_response::Result::Failure(
-                                err.into(),
-                            ),
+            Ok(subscriptions) => record.succeed(Ok(Response::new(GetTopicSubscriptionsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_topic_subscriptions_response::Result::Success(
+                        golem::workerexecutor::v1::GetTopicSubscriptionsSuccessResponse {
+                            subscriptions,
+                        },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetTopicSubscriptionsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_topic_subscriptions_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    })
-                }))))
-            }
-        }   
+                    ),
+                })),
+                &err,
+            ),
+        }
     }
 
-    async fn search_oplog(
+    async fn publish_to_topic(
         &self,
-        request: Request<SearchOplogRequest>,
-    ) -> Result<Response<SearchOplogResponse>, Status> {
+        request: Request<PublishToTopicRequest>,
+    ) -> Result<Response<PublishToTopicResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!(
-            "search_oplog",
-            worker_id = proto_worker_id_string(&request.worker_id),
-        );
+        let record = recorded_grpc_api_request!("publish_to_topic", topic = request.topic);
 
         let result = self
-            .search_oplog_internal(request)
+            .publish_to_topic_internal(request)
             .instrument(record.span.clone())
             .await;
         match result {
-            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Ok(message_id) => record.succeed(Ok(Response::new(PublishToTopicResponse {
+                result: Some(
+                    golem::workerexecutor::v1::publish_to_topic_response::Result::Success(
+                        golem::workerexecutor::v1::PublishToTopicSuccessResponse { message_id },
+                    ),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(SearchOplogResponse {
+                Ok(Response::new(PublishToTopicResponse {
                     result: Some(
-                        golem::workerexecutor::v1::search_oplog_response::Result::Failure(
+                        golem::workerexecutor::v1::publish_to_topic_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2105,6 +3486,7 @@ trait GrpcInvokeRequest {
     fn args(&self) -> Option<Vec<String>>;
     fn env(&self) -> Option<Vec<(String, String)>>;
     fn parent(&self) -> Option<WorkerId>;
+    fn trace_context(&self) -> Option<TraceContext>;
 }
 
 impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeWorkerRequest {
@@ -2157,6 +3539,15 @@ impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeWorkerRequest {
                 .and_then(|worker_id| worker_id.clone().try_into().ok())
         })
     }
+
+    fn trace_context(&self) -> Option<TraceContext> {
+        // An invalid trace context of the caller is ignored, as the W3C specification suggests
+        self.context.as_ref().and_then(|ctx| {
+            ctx.trace_context
+                .as_ref()
+                .and_then(|trace_context| trace_context.clone().try_into().ok())
+        })
+    }
 }
 
 impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeAndAwaitWorkerRequest {
@@ -2209,6 +3600,14 @@ impl GrpcInvokeRequest for golem::workerexecutor::v1::InvokeAndAwaitWorkerReques
                 .and_then(|worker_id| worker_id.clone().try_into().ok())
         })
     }
+
+    fn trace_context(&self) -> Option<TraceContext> {
+        self.context.as_ref().and_then(|ctx| {
+            ctx.trace_context
+                .as_ref()
+                .and_then(|trace_context| trace_context.clone().try_into().ok())
+        })
+    }
 }
 
 pub trait UriBackConversion {
@@ -12,19 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bytes::Bytes;
+use golem_common::model::http_handler::{
+    HttpFields, IncomingHttpRequest, OutgoingHttpResponse, INCOMING_HTTP_HANDLER_FUNCTION,
+    MAX_HTTP_HANDLER_BODY_SIZE,
+};
 use golem_common::model::oplog::{WorkerError, WorkerResourceId};
 use golem_common::model::WorkerStatus;
 use golem_wasm_rpc::wasmtime::{decode_param, encode_output, type_to_analysed_type};
-use golem_wasm_rpc::Value;
+use golem_wasm_rpc::{IntoValue, Value};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use http_body::Frame;
+use http_body_util::{BodyExt, Limited, StreamBody};
 use rib::{ParsedFunctionName, ParsedFunctionReference};
+use tokio::sync::oneshot;
 use tracing::{debug, error};
 use wasmtime::component::{Func, Val};
 use wasmtime::{AsContextMut, StoreContextMut};
+use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::body::HyperOutgoingBody;
 
 use crate::error::GolemError;
 use crate::metrics::wasm::{record_invocation, record_invocation_consumption};
 use crate::model::{InterruptKind, TrapType};
-use crate::workerctx::{PublicWorkerIo, WorkerCtx};
+use crate::services::HasWorker;
+use crate::workerctx::{HttpIncomingRequest, PublicWorkerIo, WorkerCtx};
 
 /// Invokes a function on a worker.
 ///
@@ -199,6 +211,9 @@ async fn invoke_or_fail<Ctx: WorkerCtx>(
     }
 
     let mut call_result = match function {
+        Some(function) if is_http_handler_invocation(&full_function_name, &function_input) => {
+            invoke_http_handler(&mut store, function, &function_input, &full_function_name).await
+        }
         Some(function) => invoke(&mut store, function, &function_input, &full_function_name).await,
         None => {
             // Special function: drop
@@ -366,6 +381,137 @@ async fn invoke<Ctx: WorkerCtx>(
     }
 }
 
+/// Invocations of the incoming HTTP handler are passing the whole request as a single value
+/// instead of the `incoming-request` and `response-outparam` resource handles.
+fn is_http_handler_invocation(full_function_name: &str, function_input: &[Value]) -> bool {
+    full_function_name == INCOMING_HTTP_HANDLER_FUNCTION
+        && matches!(function_input, [Value::Record(_)])
+}
+
+/// Invokes an exported `wasi:http/incoming-handler` by creating the request and the
+/// response-outparam resources from the `IncomingHttpRequest` input value. The response set by
+/// the worker is returned as an `OutgoingHttpResponse` value.
+async fn invoke_http_handler<Ctx: WorkerCtx>(
+    store: &mut impl AsContextMut<Data = Ctx>,
+    function: Func,
+    function_input: &[Value],
+    raw_function_name: &str,
+) -> Result<InvokeResult, GolemError> {
+    let mut store = store.as_context_mut();
+
+    let request = match function_input {
+        [value] => IncomingHttpRequest::try_from(value.clone())
+            .map_err(|details| GolemError::ValueMismatch { details })?,
+        _ => {
+            return Err(GolemError::ParamTypeMismatch {
+                details: format!("expected 1, got {} parameters", function_input.len()),
+            })
+        }
+    };
+    let request = to_hyper_request(request)?;
+
+    let incoming_request = store.data_mut().new_incoming_http_request(request)?;
+    let (sender, receiver) = oneshot::channel();
+    let response_outparam = store.data_mut().new_http_response_outparam(sender)?;
+
+    let params = vec![
+        Val::Resource(incoming_request.try_into_resource_any(&mut store)?),
+        Val::Resource(response_outparam.try_into_resource_any(&mut store)?),
+    ];
+
+    // The response body is consumed while the handler is running, as the worker may not be able
+    // to finish writing it until it gets read.
+    let response = tokio::spawn(collect_http_response(receiver));
+
+    let (result, consumed_fuel) =
+        call_exported_function(&mut store, function, params, raw_function_name).await?;
+
+    match result {
+        Ok(_) => match response.await {
+            Ok(Ok(response)) => Ok(InvokeResult::from_success(
+                consumed_fuel,
+                vec![response.into_value()],
+            )),
+            Ok(Err(err)) => Ok(InvokeResult::Failed {
+                consumed_fuel,
+                error: WorkerError::Unknown(err),
+            }),
+            Err(err) => Ok(InvokeResult::Failed {
+                consumed_fuel,
+                error: WorkerError::Unknown(format!("Failed to collect HTTP response: {err}")),
+            }),
+        },
+        Err(err) => {
+            response.abort();
+            Ok(InvokeResult::from_error::<Ctx>(consumed_fuel, &err))
+        }
+    }
+}
+
+fn to_hyper_request(request: IncomingHttpRequest) -> Result<HttpIncomingRequest, GolemError> {
+    let mut builder = hyper::Request::builder()
+        .method(request.method.as_str())
+        .uri(request.uri.as_str());
+    if let Some(headers) = builder.headers_mut() {
+        *headers = to_header_map(request.headers)?;
+    }
+
+    if request.body.len() > MAX_HTTP_HANDLER_BODY_SIZE {
+        return Err(GolemError::invalid_request(format!(
+            "The HTTP request body is larger than {MAX_HTTP_HANDLER_BODY_SIZE} bytes"
+        )));
+    }
+    let frames = vec![Ok::<_, ErrorCode>(Frame::data(Bytes::from(request.body)))];
+    let body = StreamBody::new(futures::stream::iter(frames)).boxed();
+
+    builder
+        .body(body)
+        .map_err(|err| GolemError::invalid_request(format!("Invalid HTTP request: {err}")))
+}
+
+fn to_header_map(fields: HttpFields) -> Result<HeaderMap, GolemError> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in fields {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|err| GolemError::invalid_request(format!("Invalid HTTP header: {err}")))?;
+        let value = HeaderValue::from_bytes(&value)
+            .map_err(|err| GolemError::invalid_request(format!("Invalid HTTP header: {err}")))?;
+        headers.append(name, value);
+    }
+    Ok(headers)
+}
+
+fn from_header_map(headers: &HeaderMap) -> HttpFields {
+    headers
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+        .collect()
+}
+
+async fn collect_http_response(
+    receiver: oneshot::Receiver<Result<hyper::Response<HyperOutgoingBody>, ErrorCode>>,
+) -> Result<OutgoingHttpResponse, String> {
+    let response = receiver
+        .await
+        .map_err(|_| "The HTTP handler did not set a response".to_string())?
+        .map_err(|err| format!("The HTTP handler responded with an error: {err:?}"))?;
+
+    let (parts, body) = response.into_parts();
+    let collected = Limited::new(body, MAX_HTTP_HANDLER_BODY_SIZE)
+        .collect()
+        .await
+        .map_err(|err| format!("Failed to read the HTTP response body: {err}"))?;
+    let trailers = collected.trailers().map(from_header_map);
+    let body = collected.to_bytes();
+
+    Ok(OutgoingHttpResponse {
+        status: parts.status.as_u16(),
+        headers: from_header_map(&parts.headers),
+        body: body.to_vec(),
+        trailers,
+    })
+}
+
 async fn drop_resource<Ctx: WorkerCtx>(
     store: &mut impl AsContextMut<Data = Ctx>,
     parsed_function_name: &ParsedFunctionName,
@@ -440,6 +586,13 @@ async fn call_exported_function<Ctx: WorkerCtx>(
 ) -> Result<(anyhow::Result<Vec<Val>>, i64), GolemError> {
     let mut store = store.as_context_mut();
 
+    let quota_tracker = store.data().get_public_state().worker().quota_tracker();
+    if store.data().is_live() {
+        if let Err(err) = quota_tracker.check_oplog() {
+            return Ok((Err(err.into()), 0));
+        }
+    }
+
     store.data_mut().borrow_fuel().await?;
 
     let idempotency_key = store.data().get_current_idempotency_key().await;
@@ -457,6 +610,7 @@ async fn call_exported_function<Ctx: WorkerCtx>(
         .map(|_| Val::Bool(false))
         .collect();
 
+    quota_tracker.start_invocation(store.get_fuel().unwrap_or(0));
     let result = function.call_async(&mut store, &params, &mut results).await;
     let result = if result.is_ok() {
         function.post_return_async(&mut store).await.map_err(|e| {
@@ -472,6 +626,7 @@ async fn call_exported_function<Ctx: WorkerCtx>(
         .data_mut()
         .return_fuel(current_fuel_level as i64)
         .await?;
+    quota_tracker.finish_invocation(consumed_fuel_for_call.max(0) as u64);
 
     if consumed_fuel_for_call > 0 {
         debug!(
@@ -22,6 +22,7 @@ pub mod invocation;
 pub mod metrics;
 pub mod model;
 pub mod preview2;
+pub mod quotas;
 pub mod services;
 pub mod storage;
 pub mod wasi_host;
@@ -36,16 +37,19 @@ use crate::http_server::HttpServerImpl;
 use crate::services::active_workers::ActiveWorkers;
 use crate::services::blob_store::{BlobStoreService, DefaultBlobStoreService};
 use crate::services::component::ComponentService;
+use crate::services::dead_letter::{DeadLetterService, DeadLetterServiceDefault};
 use crate::services::events::Events;
 use crate::services::golem_config::{
     BlobStorageConfig, GolemConfig, IndexedStorageConfig, KeyValueStorageConfig,
 };
 use crate::services::key_value::{DefaultKeyValueService, KeyValueService};
+use crate::services::lock::{LockService, LockServiceDefault};
 use crate::services::oplog::{
     BlobOplogArchiveService, CompressedOplogArchiveService, MultiLayerOplogService,
     OplogArchiveService, OplogService, PrimaryOplogService,
 };
 use crate::services::promise::{DefaultPromiseService, PromiseService};
+use crate::services::pubsub::{PubSubService, PubSubServiceDefault};
 use crate::services::scheduler::{SchedulerService, SchedulerServiceDefault};
 use crate::services::shard::{ShardService, ShardServiceDefault};
 use crate::services::shard_manager::ShardManagerService;
@@ -60,12 +64,15 @@ use crate::services::{component, shard_manager, All};
 use crate::storage::blob::s3::S3BlobStorage;
 use crate::storage::blob::sqlite::SqliteBlobStorage;
 use crate::storage::blob::BlobStorage;
+use crate::storage::indexed::postgres::PostgresIndexedStorage;
 use crate::storage::indexed::redis::RedisIndexedStorage;
 use crate::storage::indexed::sqlite::SqliteIndexedStorage;
 use crate::storage::indexed::IndexedStorage;
 use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+use crate::storage::keyvalue::postgres::PostgresKeyValueStorage;
 use crate::storage::keyvalue::redis::RedisKeyValueStorage;
 use crate::storage::keyvalue::KeyValueStorage;
+use crate::storage::postgres::PostgresPool;
 use crate::workerctx::WorkerCtx;
 use anyhow::anyhow;
 use async_trait::async_trait;
@@ -119,6 +126,9 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        dead_letter_service: Arc<dyn DeadLetterService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
     ) -> anyhow::Result<All<Ctx>>;
@@ -175,9 +185,10 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             "Worker executor is running",
         );
 
-        let (redis, sqlite, key_value_storage): (
+        let (redis, sqlite, postgres, key_value_storage): (
             Option<RedisPool>,
             Option<SqlitePool>,
+            Option<PostgresPool>,
             Arc<dyn KeyValueStorage + Send + Sync>,
         ) = match &golem_config.key_value_storage {
             KeyValueStorageConfig::Redis(redis) => {
@@ -187,11 +198,11 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                     .map_err(|err| anyhow!(err))?;
                 let key_value_storage: Arc<dyn KeyValueStorage + Send + Sync> =
                     Arc::new(RedisKeyValueStorage::new(pool.clone()));
-                (Some(pool), None, key_value_storage)
+                (Some(pool), None, None, key_value_storage)
             }
             KeyValueStorageConfig::InMemory => {
                 info!("Using in-memory key-value storage");
-                (None, None, Arc::new(InMemoryKeyValueStorage::new()))
+                (None, None, None, Arc::new(InMemoryKeyValueStorage::new()))
             }
             KeyValueStorageConfig::Sqlite(sqlite) => {
                 info!("Using Sqlite for key-value storage at {}", sqlite.database);
@@ -203,7 +214,22 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                         .await
                         .map_err(|err| anyhow!(err))?,
                 );
-                (None, Some(pool), key_value_storage)
+                (None, Some(pool), None, key_value_storage)
+            }
+            KeyValueStorageConfig::Postgres(postgres) => {
+                info!(
+                    "Using Postgres for key-value storage at {}:{}/{}",
+                    postgres.host, postgres.port, postgres.database
+                );
+                let pool = PostgresPool::configured(postgres)
+                    .await
+                    .map_err(|err| anyhow!(err))?;
+                let key_value_storage: Arc<dyn KeyValueStorage + Send + Sync> = Arc::new(
+                    PostgresKeyValueStorage::new(pool.clone())
+                        .await
+                        .map_err(|err| anyhow!(err))?,
+                );
+                (None, None, Some(pool), key_value_storage)
             }
         };
 
@@ -243,6 +269,31 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                             .map_err(|err| anyhow!(err))?,
                     )
                 }
+                IndexedStorageConfig::KVStorePostgres => {
+                    info!("Using the same Postgres for indexed-storage");
+                    let postgres = postgres.clone().expect(
+                        "Postgres must be configured as key-value storage for KVStorePostgres",
+                    );
+                    Arc::new(
+                        PostgresIndexedStorage::new(postgres.clone())
+                            .await
+                            .map_err(|err| anyhow!(err))?,
+                    )
+                }
+                IndexedStorageConfig::Postgres(postgres) => {
+                    info!(
+                        "Using Postgres for indexed storage at {}:{}/{}",
+                        postgres.host, postgres.port, postgres.database
+                    );
+                    let pool = PostgresPool::configured(postgres)
+                        .await
+                        .map_err(|err| anyhow!(err))?;
+                    Arc::new(
+                        PostgresIndexedStorage::new(pool.clone())
+                            .await
+                            .map_err(|err| anyhow!(err))?,
+                    )
+                }
                 IndexedStorageConfig::InMemory => {
                     info!("Using in-memory indexed storage");
                     Arc::new(storage::indexed::memory::InMemoryIndexedStorage::new())
@@ -346,6 +397,7 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                     oplog_archives,
                     golem_config.oplog.entry_count_limit,
                     golem_config.oplog.max_operations_before_commit_ephemeral,
+                    golem_config.snapshot.retention.clone(),
                 ))
             }
         };
@@ -388,6 +440,11 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
 
         let blob_store_service = Arc::new(DefaultBlobStoreService::new(blob_storage.clone()));
 
+        let pubsub_service = Arc::new(PubSubServiceDefault::new(
+            indexed_storage.clone(),
+            key_value_storage.clone(),
+        ));
+
         let scheduler_service = SchedulerServiceDefault::new(
             key_value_storage.clone(),
             shard_service.clone(),
@@ -395,9 +452,15 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
             lazy_worker_activator.clone(),
             oplog_service.clone(),
             worker_service.clone(),
+            pubsub_service.clone(),
             golem_config.scheduler.refresh_interval,
         );
 
+        let dead_letter_service =
+            Arc::new(DeadLetterServiceDefault::new(key_value_storage.clone()));
+
+        let lock_service = Arc::new(LockServiceDefault::new(key_value_storage.clone()));
+
         let worker_proxy: Arc<dyn WorkerProxy + Send + Sync> = Arc::new(RemoteWorkerProxy::new(
             golem_config.public_worker_api.uri(),
             golem_config
@@ -430,6 +493,9 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
                 lazy_worker_activator.clone(),
                 oplog_service,
                 scheduler_service,
+                dead_letter_service,
+                pubsub_service,
+                lock_service,
                 worker_proxy,
                 events,
             )
@@ -117,6 +117,11 @@ pub mod workers {
             &["api"]
         )
         .unwrap();
+        static ref SCHEDULED_INVOCATIONS_TOTAL: Counter = register_counter!(
+            "scheduled_invocations_total",
+            "Number of invocations enqueued by the scheduler"
+        )
+        .unwrap();
     }
 
     pub fn record_worker_call(api_name: &'static str) {
@@ -124,6 +129,10 @@ pub mod workers {
             .with_label_values(&[api_name])
             .inc();
     }
+
+    pub fn record_scheduled_invocation() {
+        SCHEDULED_INVOCATIONS_TOTAL.inc();
+    }
 }
 
 pub mod promises {
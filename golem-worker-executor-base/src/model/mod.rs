@@ -29,7 +29,7 @@ use golem_common::model::{
     ComponentType, ShardAssignment, ShardId, Timestamp, WorkerId, WorkerStatusRecord,
 };
 
-use crate::error::{GolemError, WorkerOutOfMemory};
+use crate::error::{GolemError, WorkerOutOfMemory, WorkerQuotaExceeded};
 use crate::workerctx::WorkerCtx;
 
 pub trait ShardAssignmentCheck {
@@ -246,11 +246,19 @@ impl TrapType {
                     Some(&Trap::StackOverflow) => TrapType::Error(WorkerError::StackOverflow),
                     _ => match error.root_cause().downcast_ref::<WorkerOutOfMemory>() {
                         Some(_) => TrapType::Error(WorkerError::OutOfMemory),
-                        None => match error.root_cause().downcast_ref::<GolemError>() {
-                            Some(GolemError::InvalidRequest { details }) => {
-                                TrapType::Error(WorkerError::InvalidRequest(details.clone()))
+                        None => match error.root_cause().downcast_ref::<WorkerQuotaExceeded>() {
+                            Some(WorkerQuotaExceeded { quota, limit }) => {
+                                TrapType::Error(WorkerError::QuotaExceeded {
+                                    quota: *quota,
+                                    limit: *limit,
+                                })
                             }
-                            _ => TrapType::Error(WorkerError::Unknown(format!("{:#}", error))),
+                            None => match error.root_cause().downcast_ref::<GolemError>() {
+                                Some(GolemError::InvalidRequest { details }) => {
+                                    TrapType::Error(WorkerError::InvalidRequest(details.clone()))
+                                }
+                                _ => TrapType::Error(WorkerError::Unknown(format!("{:#}", error))),
+                            },
                         },
                     },
                 },
@@ -20,8 +20,9 @@ use crate::durable_host::http::serialized::{
     SerializableResponseHeaders, SerializableTlsAlertReceivedPayload,
 };
 use crate::durable_host::serialized::{
-    SerializableDateTime, SerializableError, SerializableFileTimes, SerializableIpAddress,
-    SerializableIpAddresses, SerializableStreamError,
+    SerializableDateTime, SerializableError, SerializableFileTimes, SerializableIncomingDatagram,
+    SerializableIncomingDatagrams, SerializableIpAddress, SerializableIpAddresses,
+    SerializableIpSocketAddress, SerializableStreamError,
 };
 use crate::durable_host::wasm_rpc::serialized::{
     SerializableInvokeRequest, SerializableInvokeResult,
@@ -37,19 +38,21 @@ use bincode::Decode;
 use golem_api_grpc::proto::golem::worker::UpdateMode;
 use golem_common::model::exports::{find_resource_site, function_by_name};
 use golem_common::model::lucene::Query;
-use golem_common::model::oplog::{OplogEntry, OplogIndex, UpdateDescription};
+use golem_common::model::oplog::{FileSystemChange, OplogEntry, OplogIndex, UpdateDescription};
 use golem_common::model::public_oplog::{
-    ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, Empty,
-    EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
-    ExportedFunctionInvokedParameters, ExportedFunctionParameters, FailedUpdateParameters,
-    GrowMemoryParameters, ImportedFunctionInvokedParameters, JumpParameters, LogParameters,
-    ManualUpdateParameters, PendingUpdateParameters, PendingWorkerInvocationParameters,
-    PublicOplogEntry, PublicUpdateDescription, PublicWorkerInvocation, ResourceParameters,
-    SnapshotBasedUpdateParameters, SuccessfulUpdateParameters, TimestampParameter,
+    ChangeFileSystemParameters, ChangeRetryPolicyParameters, CreateParameters,
+    DescribeResourceParameters, Empty, EndRegionParameters, ErrorParameters,
+    ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+    ExportedFunctionParameters, FailedUpdateParameters, FileSystemChangeKind, GrowMemoryParameters,
+    ImportedFunctionInvokedParameters, JumpParameters, LogParameters, ManualUpdateParameters,
+    PendingUpdateParameters, PendingWorkerInvocationParameters, PublicOplogEntry,
+    PublicUpdateDescription, PublicWorkerInvocation, ResourceParameters,
+    SnapshotBasedUpdateParameters, SnapshotParameters, SuccessfulUpdateParameters,
+    TimestampParameter,
 };
 use golem_common::model::{
-    ComponentId, ComponentVersion, IdempotencyKey, OwnedWorkerId, PromiseId, ShardId, WorkerId,
-    WorkerInvocation,
+    ComponentId, ComponentVersion, IdempotencyKey, OwnedWorkerId, PromiseId, ScheduleId,
+    ScheduledInvocationId, ShardId, WorkerId, WorkerInvocation,
 };
 use golem_common::serialization::try_deserialize as core_try_deserialize;
 use golem_wasm_ast::analysis::analysed_type::{
@@ -63,7 +66,7 @@ use golem_wasm_rpc::{
 };
 use rib::{ParsedFunctionName, ParsedFunctionReference};
 use std::collections::HashMap;
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr, SocketAddrV4, SocketAddrV6};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -196,9 +199,14 @@ pub async fn find_component_version_at(
             .next()
             .map(|(_, v)| v.clone());
 
-        if let Some(OplogEntry::Create {
-            component_version, ..
-        }) = entry
+        if let Some(
+            OplogEntry::CreateV1 {
+                component_version, ..
+            }
+            | OplogEntry::Create {
+                component_version, ..
+            },
+        ) = entry
         {
             initial_component_version = component_version;
         } else if let Some(OplogEntry::SuccessfulUpdate { target_version, .. }) = entry {
@@ -231,7 +239,18 @@ impl PublicOplogEntryOps for PublicOplogEntry {
         component_version: ComponentVersion,
     ) -> Result<Self, String> {
         match value {
-            OplogEntry::Create {
+            OplogEntry::CreateV1 {
+                timestamp,
+                worker_id,
+                component_version,
+                args,
+                env,
+                account_id,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+            }
+            | OplogEntry::Create {
                 timestamp,
                 worker_id,
                 component_version,
@@ -241,6 +260,7 @@ impl PublicOplogEntryOps for PublicOplogEntry {
                 parent,
                 component_size,
                 initial_total_linear_memory_size,
+                ..
             } => Ok(PublicOplogEntry::Create(CreateParameters {
                 timestamp,
                 worker_id,
@@ -299,11 +319,33 @@ impl PublicOplogEntryOps for PublicOplogEntry {
                     },
                 ))
             }
+            OplogEntry::ExportedFunctionInvokedV1 {
+                timestamp,
+                function_name,
+                request,
+                idempotency_key,
+            } => {
+                Self::from_oplog_entry(
+                    OplogEntry::ExportedFunctionInvoked {
+                        timestamp,
+                        function_name,
+                        request,
+                        idempotency_key,
+                        trace_context: None,
+                    },
+                    oplog_service,
+                    components,
+                    owned_worker_id,
+                    component_version,
+                )
+                .await
+            }
             OplogEntry::ExportedFunctionInvoked {
                 timestamp,
                 function_name,
                 request,
                 idempotency_key,
+                trace_context,
             } => {
                 let payload_bytes = oplog_service
                     .download_payload(owned_worker_id, &request)
@@ -338,6 +380,8 @@ impl PublicOplogEntryOps for PublicOplogEntry {
                         function_name,
                         request,
                         idempotency_key,
+                        traceparent: trace_context.as_ref().map(|context| context.traceparent()),
+                        tracestate: trace_context.and_then(|context| context.trace_state),
                     },
                 ))
             }
@@ -427,10 +471,16 @@ impl PublicOplogEntryOps for PublicOplogEntry {
                 invocation,
             } => {
                 let invocation = match invocation {
-                    WorkerInvocation::ExportedFunction {
+                    WorkerInvocation::ExportedFunctionV1 {
+                        idempotency_key,
+                        full_function_name,
+                        function_input,
+                    }
+                    | WorkerInvocation::ExportedFunction {
                         idempotency_key,
                         full_function_name,
                         function_input,
+                        ..
                     } => {
                         let metadata = components
                             .get_metadata(
@@ -608,6 +658,29 @@ impl PublicOplogEntryOps for PublicOplogEntry {
             OplogEntry::Restart { timestamp } => {
                 Ok(PublicOplogEntry::Restart(TimestampParameter { timestamp }))
             }
+            OplogEntry::ChangeFileSystem { timestamp, change } => {
+                let kind = match &change {
+                    FileSystemChange::WriteFile { .. } => FileSystemChangeKind::WriteFile,
+                    FileSystemChange::DeleteFile { .. } => FileSystemChangeKind::DeleteFile,
+                    FileSystemChange::MakeDirectory { .. } => FileSystemChangeKind::MakeDirectory,
+                };
+                Ok(PublicOplogEntry::ChangeFileSystem(
+                    ChangeFileSystemParameters {
+                        timestamp,
+                        kind,
+                        path: change.path().to_string(),
+                    },
+                ))
+            }
+            OplogEntry::Snapshot { timestamp, payload } => {
+                let bytes = oplog_service
+                    .download_payload(owned_worker_id, &payload)
+                    .await?;
+                Ok(PublicOplogEntry::Snapshot(SnapshotParameters {
+                    timestamp,
+                    payload: bytes.to_vec(),
+                }))
+            }
         }
     }
 }
@@ -746,6 +819,10 @@ fn encode_host_function_request_as_value(
         "monotonic_clock::resolution" => no_payload(),
         "monotonic_clock::now" => no_payload(),
         "monotonic_clock::subscribe_duration" => no_payload(),
+        "golem monotonic_clock::sleep_deadline" => {
+            let payload: u64 = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "wall_clock::now" => no_payload(),
         "wall_clock::resolution" => no_payload(),
         "golem_delete_promise" => {
@@ -756,6 +833,44 @@ fn encode_host_function_request_as_value(
             let payload: PromiseId = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem_schedule_promise_timeout" => {
+            let payload: (PromiseId, u64) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_await_promise_with_timeout"
+        | "golem_await_any_promise"
+        | "golem_await_all_promises" => {
+            let payload: Vec<PromiseId> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_schedule_invocation" => {
+            let payload: (WorkerId, String, String) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_cancel_scheduled_invocation" => {
+            let payload: (WorkerId, ScheduledInvocationId) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_publish_to_topic" => {
+            let payload: (String, Vec<u8>) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_subscribe_to_topic" | "golem_unsubscribe_from_topic" => {
+            let payload: (String, String) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_try_acquire_semaphore" | "golem_acquire_semaphore" => {
+            let payload: (String, u64, u64) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_renew_semaphore_lease" => {
+            let payload: (String, u64) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_release_semaphore" => {
+            let payload: String = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "golem::api::update-worker" => {
             let payload: (WorkerId, ComponentVersion, UpdateMode) = try_deserialize(bytes)?;
             Ok(ValueAndType::new(
@@ -787,6 +902,50 @@ fn encode_host_function_request_as_value(
             let payload: SerializableHttpRequest = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "sockets::tcp::input_stream::read" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::input_stream::blocking_read" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::input_stream::skip" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::input_stream::blocking_skip" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::output_stream::write" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::output_stream::flush" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::output_stream::blocking_flush" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::start_connect" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::finish_connect" => {
+            let payload: SerializableIpSocketAddress = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::udp::incoming_datagram_stream::receive" => {
+            let payload: u64 = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::udp::outgoing_datagram_stream::send" => {
+            let payload: Vec<Option<SerializableIpSocketAddress>> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "golem keyvalue::eventual::delete" => {
             let payload: (String, String) = try_deserialize(bytes)?;
             Ok(bucket_and_key(payload.0, payload.1))
@@ -1039,6 +1198,10 @@ fn encode_host_function_response_as_value(
             let payload: Result<u64, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem monotonic_clock::sleep_deadline" => {
+            let payload: Result<SerializableDateTime, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "wall_clock::now" => {
             let payload: Result<SerializableDateTime, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
@@ -1055,6 +1218,49 @@ fn encode_host_function_response_as_value(
             let payload: Result<bool, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem_schedule_promise_timeout" => {
+            let payload: Result<ScheduleId, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.map(|id| id.to_string()).into_value_and_type())
+        }
+        "golem_await_promise_with_timeout" => {
+            let payload: Result<Option<Vec<u8>>, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_await_any_promise" => {
+            let payload: Result<(u64, Vec<u8>), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_await_all_promises" => {
+            let payload: Result<Vec<Vec<u8>>, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_schedule_invocation" => {
+            let payload: Result<ScheduledInvocationId, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_cancel_scheduled_invocation" => {
+            let payload: Result<bool, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_publish_to_topic" => {
+            let payload: Result<u64, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_subscribe_to_topic" => {
+            let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_unsubscribe_from_topic" => {
+            let payload: Result<bool, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem_try_acquire_semaphore"
+        | "golem_acquire_semaphore"
+        | "golem_release_semaphore"
+        | "golem_renew_semaphore_lease" => {
+            let payload: Result<bool, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "golem::api::update-worker" => {
             let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
@@ -1075,6 +1281,51 @@ fn encode_host_function_response_as_value(
             let payload: Result<u64, SerializableStreamError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "sockets::tcp::input_stream::read" => {
+            let payload: Result<Vec<u8>, SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::input_stream::blocking_read" => {
+            let payload: Result<Vec<u8>, SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::input_stream::skip" => {
+            let payload: Result<u64, SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::input_stream::blocking_skip" => {
+            let payload: Result<u64, SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::output_stream::write" => {
+            let payload: Result<(), SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::output_stream::flush" => {
+            let payload: Result<(), SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::output_stream::blocking_flush" => {
+            let payload: Result<(), SerializableStreamError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::start_connect" => {
+            let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::tcp::finish_connect" => {
+            let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::udp::incoming_datagram_stream::receive" => {
+            let payload: Result<SerializableIncomingDatagrams, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "sockets::udp::outgoing_datagram_stream::send" => {
+            let payload: Result<u64, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "golem keyvalue::eventual::delete" => {
             let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
@@ -1917,10 +2168,7 @@ impl IntoValue for GolemError {
                 unit_case("PreviousInvocationExited"),
                 case("Unknown", record(vec![field("details", str())])),
                 unit_case("ShardingNotReady"),
-                case(
-                    "FileSystem",
-                    record(vec![field("details", str())]),
-                ),
+                case("FileSystem", record(vec![field("details", str())])),
             ])
         }
         get_type(true)
@@ -2038,6 +2286,53 @@ impl IntoValue for SerializableIpAddress {
     }
 }
 
+impl IntoValue for SerializableIpSocketAddress {
+    fn into_value(self) -> Value {
+        let addr = match self {
+            SerializableIpSocketAddress::IPv4 { address, port } => {
+                SocketAddr::V4(SocketAddrV4::new(address.into(), port))
+            }
+            SerializableIpSocketAddress::IPv6 {
+                address,
+                port,
+                flow_info,
+                scope_id,
+            } => SocketAddr::V6(SocketAddrV6::new(address.into(), port, flow_info, scope_id)),
+        };
+        Value::String(addr.to_string())
+    }
+
+    fn get_type() -> AnalysedType {
+        str()
+    }
+}
+
+impl IntoValue for SerializableIncomingDatagrams {
+    fn into_value(self) -> Value {
+        Value::List(self.0.into_iter().map(|v| v.into_value()).collect())
+    }
+
+    fn get_type() -> AnalysedType {
+        list(SerializableIncomingDatagram::get_type())
+    }
+}
+
+impl IntoValue for SerializableIncomingDatagram {
+    fn into_value(self) -> Value {
+        Value::Record(vec![
+            self.data.into_value(),
+            self.remote_address.into_value(),
+        ])
+    }
+
+    fn get_type() -> AnalysedType {
+        record(vec![
+            field("data", list(u8())),
+            field("remote_address", SerializableIpSocketAddress::get_type()),
+        ])
+    }
+}
+
 impl IntoValue for SerializableFileTimes {
     fn into_value(self) -> Value {
         Value::Record(vec![
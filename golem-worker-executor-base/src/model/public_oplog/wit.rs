@@ -16,14 +16,15 @@ use crate::model::public_oplog::{PublicOplogEntry, PublicUpdateDescription};
 use crate::preview2::golem::api1_1_0_rc1::oplog;
 use crate::preview2::wasi::clocks::wall_clock::Datetime;
 use golem_common::model::public_oplog::{
-    ChangeRetryPolicyParameters, CreateParameters, DescribeResourceParameters, EndRegionParameters,
-    ErrorParameters, ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
+    ChangeFileSystemParameters, ChangeRetryPolicyParameters, CreateParameters,
+    DescribeResourceParameters, EndRegionParameters, ErrorParameters,
+    ExportedFunctionCompletedParameters, ExportedFunctionInvokedParameters,
     ExportedFunctionParameters, FailedUpdateParameters, GrowMemoryParameters,
     ImportedFunctionInvokedParameters, JumpParameters, LogParameters, ManualUpdateParameters,
     PendingUpdateParameters, PendingWorkerInvocationParameters, PublicRetryConfig,
     PublicWorkerInvocation, PublicWrappedFunctionType, ResourceParameters,
-    SnapshotBasedUpdateParameters, SuccessfulUpdateParameters, TimestampParameter,
-    WriteRemoteBatchedParameters,
+    SnapshotBasedUpdateParameters, SnapshotParameters, SuccessfulUpdateParameters,
+    TimestampParameter, WriteRemoteBatchedParameters,
 };
 use golem_common::model::Timestamp;
 
@@ -71,6 +72,7 @@ impl From<PublicOplogEntry> for oplog::OplogEntry {
                 function_name,
                 request,
                 idempotency_key,
+                ..
             }) => Self::ExportedFunctionInvoked(oplog::ExportedFunctionInvokedParameters {
                 timestamp: timestamp.into(),
                 function_name,
@@ -218,6 +220,27 @@ impl From<PublicOplogEntry> for oplog::OplogEntry {
             PublicOplogEntry::Restart(TimestampParameter { timestamp }) => {
                 Self::Restart(timestamp.into())
             }
+            // The golem:api oplog-entry variant has no case for file system changes yet,
+            // so they are exposed to workers as log entries
+            PublicOplogEntry::ChangeFileSystem(ChangeFileSystemParameters {
+                timestamp,
+                kind,
+                path,
+            }) => Self::Log(oplog::LogParameters {
+                timestamp: timestamp.into(),
+                level: golem_common::model::oplog::LogLevel::Info.into(),
+                context: "file-system".to_string(),
+                message: format!("{kind:?} {path}"),
+            }),
+            // Snapshots are not part of the golem:api oplog-entry variant either
+            PublicOplogEntry::Snapshot(SnapshotParameters { timestamp, payload }) => {
+                Self::Log(oplog::LogParameters {
+                    timestamp: timestamp.into(),
+                    level: golem_common::model::oplog::LogLevel::Info.into(),
+                    context: "snapshot".to_string(),
+                    message: format!("Snapshot of {} bytes", payload.len()),
+                })
+            }
         }
     }
 }
@@ -28,6 +28,12 @@ impl From<golem_wasm_rpc::WitValue> for golem::rpc::types::WitValue {
     }
 }
 
+impl From<golem::rpc::types::WitValue> for golem_wasm_rpc::WitValue {
+    fn from(value: golem::rpc::types::WitValue) -> Self {
+        unsafe { mem::transmute(value) }
+    }
+}
+
 impl From<golem_wasm_rpc::Value> for golem::rpc::types::WitValue {
     fn from(value: golem_wasm_rpc::Value) -> Self {
         let wit_value: golem_wasm_rpc::WitValue = value.into();
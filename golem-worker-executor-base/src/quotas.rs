@@ -0,0 +1,289 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use golem_common::model::quotas::{QuotaKind, WorkerQuotas};
+
+use crate::error::WorkerQuotaExceeded;
+
+const OUTGOING_HTTP_CALL_WINDOW: Duration = Duration::from_secs(60);
+
+/// Tracks the resource usage of a worker loaded in this executor and checks it against the
+/// worker's effective quotas (the component's quotas overridden by the worker's own ones).
+///
+/// The checks are cheap and synchronous so they can be done from the epoch deadline callback.
+/// Usage which is expensive to track (the oplog's size in bytes and the outgoing HTTP calls) is
+/// only tracked if there is a quota set for it.
+pub struct QuotaTracker {
+    quotas: WorkerQuotas,
+    oplog_entries: AtomicU64,
+    oplog_bytes: AtomicU64,
+    invocation_start_fuel_level: Mutex<Option<u64>>,
+    last_invocation_fuel: Mutex<Option<u64>>,
+    outgoing_http_calls: Mutex<VecDeque<Instant>>,
+}
+
+/// Snapshot of the usage tracked by a `QuotaTracker`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuotaUsage {
+    pub oplog_bytes: Option<u64>,
+    pub fuel_consumed_by_last_invocation: Option<u64>,
+    pub outgoing_http_calls_in_last_minute: Option<u32>,
+}
+
+impl QuotaTracker {
+    pub fn new(quotas: WorkerQuotas, oplog_entries: u64, oplog_bytes: u64) -> Self {
+        Self {
+            quotas,
+            oplog_entries: AtomicU64::new(oplog_entries),
+            oplog_bytes: AtomicU64::new(oplog_bytes),
+            invocation_start_fuel_level: Mutex::new(None),
+            last_invocation_fuel: Mutex::new(None),
+            outgoing_http_calls: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn quotas(&self) -> &WorkerQuotas {
+        &self.quotas
+    }
+
+    /// True if the oplog entries have to be counted
+    pub fn meters_oplog(&self) -> bool {
+        self.quotas.max_oplog_entries.is_some() || self.meters_oplog_bytes()
+    }
+
+    /// True if the serialized size of the oplog entries has to be counted
+    pub fn meters_oplog_bytes(&self) -> bool {
+        self.quotas.max_oplog_bytes.is_some()
+    }
+
+    pub fn record_oplog_entry(&self, size: u64) {
+        self.oplog_entries.fetch_add(1, Ordering::AcqRel);
+        self.oplog_bytes.fetch_add(size, Ordering::AcqRel);
+    }
+
+    /// Marks the beginning of an invocation, with the store's fuel level at that point
+    pub fn start_invocation(&self, fuel_level: u64) {
+        *self.invocation_start_fuel_level.lock().unwrap() = Some(fuel_level);
+    }
+
+    pub fn finish_invocation(&self, consumed_fuel: u64) {
+        *self.invocation_start_fuel_level.lock().unwrap() = None;
+        *self.last_invocation_fuel.lock().unwrap() = Some(consumed_fuel);
+    }
+
+    /// Checks the fuel consumed by the current invocation, given the store's current fuel level
+    pub fn check_fuel(&self, fuel_level: u64) -> Result<(), WorkerQuotaExceeded> {
+        if let Some(limit) = self.quotas.max_fuel_per_invocation {
+            if let Some(start) = *self.invocation_start_fuel_level.lock().unwrap() {
+                if start.saturating_sub(fuel_level) > limit {
+                    return Err(WorkerQuotaExceeded {
+                        quota: QuotaKind::FuelPerInvocation,
+                        limit,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn check_memory(&self, total_linear_memory_size: u64) -> Result<(), WorkerQuotaExceeded> {
+        match self.quotas.max_memory {
+            Some(limit) if total_linear_memory_size > limit => Err(WorkerQuotaExceeded {
+                quota: QuotaKind::Memory,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn check_oplog(&self) -> Result<(), WorkerQuotaExceeded> {
+        if let Some(limit) = self.quotas.max_oplog_entries {
+            if self.oplog_entries.load(Ordering::Acquire) > limit {
+                return Err(WorkerQuotaExceeded {
+                    quota: QuotaKind::OplogEntries,
+                    limit,
+                });
+            }
+        }
+        if let Some(limit) = self.quotas.max_oplog_bytes {
+            if self.oplog_bytes.load(Ordering::Acquire) > limit {
+                return Err(WorkerQuotaExceeded {
+                    quota: QuotaKind::OplogBytes,
+                    limit,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Records an outgoing HTTP call, failing if it would exceed the calls per minute quota
+    pub fn record_outgoing_http_call(&self) -> Result<(), WorkerQuotaExceeded> {
+        self.record_outgoing_http_call_at(Instant::now())
+    }
+
+    fn record_outgoing_http_call_at(&self, now: Instant) -> Result<(), WorkerQuotaExceeded> {
+        if let Some(limit) = self.quotas.max_outgoing_http_calls_per_minute {
+            let mut calls = self.outgoing_http_calls.lock().unwrap();
+            Self::drop_expired_calls(&mut calls, now);
+            if calls.len() >= limit as usize {
+                return Err(WorkerQuotaExceeded {
+                    quota: QuotaKind::OutgoingHttpCallsPerMinute,
+                    limit: limit as u64,
+                });
+            }
+            calls.push_back(now);
+        }
+        Ok(())
+    }
+
+    pub fn usage(&self) -> QuotaUsage {
+        QuotaUsage {
+            oplog_bytes: if self.meters_oplog_bytes() {
+                Some(self.oplog_bytes.load(Ordering::Acquire))
+            } else {
+                None
+            },
+            fuel_consumed_by_last_invocation: *self.last_invocation_fuel.lock().unwrap(),
+            outgoing_http_calls_in_last_minute: if self
+                .quotas
+                .max_outgoing_http_calls_per_minute
+                .is_some()
+            {
+                let mut calls = self.outgoing_http_calls.lock().unwrap();
+                Self::drop_expired_calls(&mut calls, Instant::now());
+                Some(calls.len() as u32)
+            } else {
+                None
+            },
+        }
+    }
+
+    fn drop_expired_calls(calls: &mut VecDeque<Instant>, now: Instant) {
+        while let Some(oldest) = calls.front() {
+            if now.duration_since(*oldest) >= OUTGOING_HTTP_CALL_WINDOW {
+                calls.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::time::{Duration, Instant};
+
+    use golem_common::model::quotas::{QuotaKind, WorkerQuotas};
+
+    use crate::error::WorkerQuotaExceeded;
+    use crate::quotas::QuotaTracker;
+
+    #[test]
+    fn fuel_is_checked_per_invocation() {
+        let tracker = QuotaTracker::new(
+            WorkerQuotas {
+                max_fuel_per_invocation: Some(1000),
+                ..WorkerQuotas::default()
+            },
+            1,
+            0,
+        );
+
+        assert!(tracker.check_fuel(0).is_ok());
+
+        tracker.start_invocation(10_000);
+        assert!(tracker.check_fuel(9_000).is_ok());
+        assert_eq!(
+            tracker.check_fuel(8_999),
+            Err(WorkerQuotaExceeded {
+                quota: QuotaKind::FuelPerInvocation,
+                limit: 1000
+            })
+        );
+        tracker.finish_invocation(1001);
+
+        assert!(tracker.check_fuel(0).is_ok());
+        assert_eq!(tracker.usage().fuel_consumed_by_last_invocation, Some(1001));
+    }
+
+    #[test]
+    fn oplog_usage_is_checked() {
+        let tracker = QuotaTracker::new(
+            WorkerQuotas {
+                max_oplog_entries: Some(3),
+                max_oplog_bytes: Some(100),
+                ..WorkerQuotas::default()
+            },
+            2,
+            50,
+        );
+
+        tracker.record_oplog_entry(10);
+        assert!(tracker.check_oplog().is_ok());
+        assert_eq!(tracker.usage().oplog_bytes, Some(60));
+
+        tracker.record_oplog_entry(10);
+        assert_eq!(
+            tracker.check_oplog(),
+            Err(WorkerQuotaExceeded {
+                quota: QuotaKind::OplogEntries,
+                limit: 3
+            })
+        );
+    }
+
+    #[test]
+    fn outgoing_http_calls_are_limited_per_minute() {
+        let tracker = QuotaTracker::new(
+            WorkerQuotas {
+                max_outgoing_http_calls_per_minute: Some(2),
+                ..WorkerQuotas::default()
+            },
+            1,
+            0,
+        );
+
+        let start = Instant::now();
+        assert!(tracker.record_outgoing_http_call_at(start).is_ok());
+        assert!(tracker
+            .record_outgoing_http_call_at(start + Duration::from_secs(30))
+            .is_ok());
+        assert!(tracker
+            .record_outgoing_http_call_at(start + Duration::from_secs(59))
+            .is_err());
+        assert!(tracker
+            .record_outgoing_http_call_at(start + Duration::from_secs(60))
+            .is_ok());
+    }
+
+    #[test]
+    fn no_quotas_means_no_limits() {
+        let tracker = QuotaTracker::new(WorkerQuotas::default(), 1_000_000, 0);
+
+        tracker.start_invocation(u64::MAX);
+        assert!(tracker.check_fuel(0).is_ok());
+        assert!(tracker.check_memory(u64::MAX).is_ok());
+        assert!(tracker.check_oplog().is_ok());
+        assert!(tracker.record_outgoing_http_call().is_ok());
+        assert!(!tracker.meters_oplog());
+        assert_eq!(tracker.usage().outgoing_http_calls_in_last_minute, None);
+    }
+}
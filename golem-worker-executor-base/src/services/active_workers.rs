@@ -19,6 +19,7 @@ use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore, TryAcquireError};
 use tracing::{debug, Instrument};
 
 use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{OwnedWorkerId, WorkerId};
 
 use crate::error::GolemError;
@@ -59,6 +60,7 @@ impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
         worker_env: Option<Vec<(String, String)>>,
         component_version: Option<u64>,
         parent: Option<WorkerId>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Arc<Worker<Ctx>>, GolemError>
     where
         T: HasAll<Ctx> + Clone + Send + Sync + 'static,
@@ -78,6 +80,7 @@ impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
                             worker_env,
                             component_version,
                             parent,
+                            quotas,
                         )
                         .in_current_span()
                         .await?,
@@ -87,6 +90,11 @@ impl<Ctx: WorkerCtx> ActiveWorkers<Ctx> {
             .await
     }
 
+    /// Gets a worker if it is already loaded in this executor
+    pub fn try_get(&self, worker_id: &WorkerId) -> Option<Arc<Worker<Ctx>>> {
+        self.workers.try_get(worker_id)
+    }
+
     pub fn remove(&self, worker_id: &WorkerId) {
         self.workers.remove(worker_id);
     }
@@ -38,6 +38,7 @@ use golem_common::config::RetryConfig;
 use golem_common::file_system::PackagedFiles;
 use golem_common::metrics::external_calls::record_external_call_response_size_bytes;
 use golem_common::model::component_metadata::RawComponentMetadata;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{ComponentId, ComponentType, ComponentVersion, FileSystemPermission};
 use golem_common::retries::with_retries;
 use golem_wasm_ast::analysis::AnalysedExport;
@@ -59,6 +60,8 @@ pub struct ComponentMetadata {
     pub memories: Vec<LinearMemory>,
     pub exports: Vec<AnalysedExport>,
     pub component_type: ComponentType,
+    /// Quotas applying to all the workers of this component version
+    pub quotas: WorkerQuotas,
 }
 
 /// Service for downloading a specific Golem component from the Golem Component API
@@ -548,6 +551,12 @@ async fn get_metadata_via_grpc(
                         .as_ref()
                         .map(|metadata| metadata.memories.clone())
                         .unwrap_or_default(),
+                    quotas: component
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.quotas.clone())
+                        .map(|quotas| quotas.into())
+                        .unwrap_or_default(),
                     exports: component
                         .metadata
                         .map(|metadata| {
@@ -956,6 +965,7 @@ impl ComponentServiceLocalFileSystem {
             memories,
             exports,
             component_type: *component_type,
+            quotas: WorkerQuotas::default(),
         })
     }
 
@@ -0,0 +1,322 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::error::GolemError;
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use golem_common::model::{
+    ComponentId, DeadLetterPolicy, DeadLetteredInvocation, DeadLetteredInvocationId, OwnedWorkerId,
+};
+
+/// Stores invocations which failed permanently on workers of components with an enabled
+/// dead-letter policy, and the per-component policies themselves.
+#[async_trait]
+pub trait DeadLetterService {
+    async fn get_policy(&self, component_id: &ComponentId) -> Result<DeadLetterPolicy, GolemError>;
+
+    async fn set_policy(
+        &self,
+        component_id: &ComponentId,
+        policy: DeadLetterPolicy,
+    ) -> Result<(), GolemError>;
+
+    async fn add(&self, invocation: &DeadLetteredInvocation) -> Result<(), GolemError>;
+
+    async fn get(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &DeadLetteredInvocationId,
+    ) -> Result<Option<DeadLetteredInvocation>, GolemError>;
+
+    /// Gets all the dead-lettered invocations of a worker, ordered by the time they failed
+    async fn get_all(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<DeadLetteredInvocation>, GolemError>;
+
+    /// Removes a dead-lettered invocation, returning `false` if it did not exist.
+    async fn remove(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &DeadLetteredInvocationId,
+    ) -> Result<bool, GolemError>;
+}
+
+#[derive(Clone)]
+pub struct DeadLetterServiceDefault {
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+}
+
+impl DeadLetterServiceDefault {
+    pub fn new(key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>) -> Self {
+        Self { key_value_storage }
+    }
+
+    fn policy_key(component_id: &ComponentId) -> String {
+        format!("component:dead-letter-policy:{}", component_id)
+    }
+
+    fn dead_letter_key(owned_worker_id: &OwnedWorkerId, id: &DeadLetteredInvocationId) -> String {
+        format!(
+            "worker:dead-letter:{}:{}",
+            owned_worker_id.worker_id.to_redis_key(),
+            id
+        )
+    }
+
+    fn dead_letters_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!(
+            "worker:dead-letters:{}",
+            owned_worker_id.worker_id.to_redis_key()
+        )
+    }
+}
+
+#[async_trait]
+impl DeadLetterService for DeadLetterServiceDefault {
+    async fn get_policy(&self, component_id: &ComponentId) -> Result<DeadLetterPolicy, GolemError> {
+        let policy: Option<DeadLetterPolicy> = self
+            .key_value_storage
+            .with_entity("dead_letter", "get_policy", "dead_letter_policy")
+            .get(
+                KeyValueStorageNamespace::Worker,
+                &Self::policy_key(component_id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get dead-letter policy: {err}"))
+            })?;
+        Ok(policy.unwrap_or_default())
+    }
+
+    async fn set_policy(
+        &self,
+        component_id: &ComponentId,
+        policy: DeadLetterPolicy,
+    ) -> Result<(), GolemError> {
+        self.key_value_storage
+            .with_entity("dead_letter", "set_policy", "dead_letter_policy")
+            .set(
+                KeyValueStorageNamespace::Worker,
+                &Self::policy_key(component_id),
+                &policy,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to set dead-letter policy: {err}")))
+    }
+
+    async fn add(&self, invocation: &DeadLetteredInvocation) -> Result<(), GolemError> {
+        self.key_value_storage
+            .with_entity("dead_letter", "add", "dead_lettered_invocation")
+            .set(
+                KeyValueStorageNamespace::Worker,
+                &Self::dead_letter_key(&invocation.owned_worker_id, &invocation.id),
+                invocation,
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to store dead-lettered invocation: {err}"))
+            })?;
+        self.key_value_storage
+            .with_entity("dead_letter", "add", "dead_lettered_invocation_id")
+            .add_to_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::dead_letters_key(&invocation.owned_worker_id),
+                &invocation.id,
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to store dead-lettered invocation: {err}"))
+            })
+    }
+
+    async fn get(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &DeadLetteredInvocationId,
+    ) -> Result<Option<DeadLetteredInvocation>, GolemError> {
+        self.key_value_storage
+            .with_entity("dead_letter", "get", "dead_lettered_invocation")
+            .get(
+                KeyValueStorageNamespace::Worker,
+                &Self::dead_letter_key(owned_worker_id, id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get dead-lettered invocation: {err}"))
+            })
+    }
+
+    async fn get_all(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<DeadLetteredInvocation>, GolemError> {
+        let ids: Vec<DeadLetteredInvocationId> = self
+            .key_value_storage
+            .with_entity("dead_letter", "get_all", "dead_lettered_invocation_id")
+            .members_of_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::dead_letters_key(owned_worker_id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get dead-lettered invocations: {err}"))
+            })?;
+        let keys = ids
+            .iter()
+            .map(|id| Self::dead_letter_key(owned_worker_id, id))
+            .collect();
+        let invocations: Vec<Option<DeadLetteredInvocation>> = self
+            .key_value_storage
+            .with_entity("dead_letter", "get_all", "dead_lettered_invocation")
+            .get_many(KeyValueStorageNamespace::Worker, keys)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get dead-lettered invocations: {err}"))
+            })?;
+        let mut result: Vec<DeadLetteredInvocation> = invocations.into_iter().flatten().collect();
+        result.sort_by_key(|invocation| invocation.failed_at);
+        Ok(result)
+    }
+
+    async fn remove(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &DeadLetteredInvocationId,
+    ) -> Result<bool, GolemError> {
+        let existing = self.get(owned_worker_id, id).await?;
+        if existing.is_none() {
+            return Ok(false);
+        }
+
+        self.key_value_storage
+            .with("dead_letter", "remove")
+            .del(
+                KeyValueStorageNamespace::Worker,
+                &Self::dead_letter_key(owned_worker_id, id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to remove dead-lettered invocation: {err}"))
+            })?;
+        self.key_value_storage
+            .with_entity("dead_letter", "remove", "dead_lettered_invocation_id")
+            .remove_from_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::dead_letters_key(owned_worker_id),
+                id,
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to remove dead-lettered invocation: {err}"))
+            })?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::services::dead_letter::{DeadLetterService, DeadLetterServiceDefault};
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use golem_common::model::{
+        AccountId, ComponentId, DeadLetterPolicy, DeadLetteredInvocation, DeadLetteredInvocationId,
+        IdempotencyKey, OwnedWorkerId, Timestamp, WorkerId,
+    };
+
+    fn owned_worker_id(component_id: &ComponentId) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            &AccountId {
+                value: "test-account".to_string(),
+            },
+            &WorkerId {
+                component_id: component_id.clone(),
+                worker_name: "worker-1".to_string(),
+            },
+        )
+    }
+
+    fn dead_letter(owned_worker_id: &OwnedWorkerId, failed_at: u64) -> DeadLetteredInvocation {
+        DeadLetteredInvocation {
+            id: DeadLetteredInvocationId::new_v4(),
+            owned_worker_id: owned_worker_id.clone(),
+            idempotency_key: IdempotencyKey::fresh(),
+            component_version: 0,
+            full_function_name: "golem:it/api.{fail}".to_string(),
+            function_input: vec![golem_wasm_rpc::Value::U64(failed_at)],
+            error: "error".to_string(),
+            failed_at: Timestamp::from(failed_at),
+        }
+    }
+
+    #[test]
+    async fn policy_is_disabled_by_default() {
+        let svc = DeadLetterServiceDefault::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let component_id = ComponentId(Uuid::new_v4());
+
+        assert_eq!(
+            svc.get_policy(&component_id).await.unwrap(),
+            DeadLetterPolicy { enabled: false }
+        );
+
+        svc.set_policy(&component_id, DeadLetterPolicy { enabled: true })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            svc.get_policy(&component_id).await.unwrap(),
+            DeadLetterPolicy { enabled: true }
+        );
+        assert_eq!(
+            svc.get_policy(&ComponentId(Uuid::new_v4())).await.unwrap(),
+            DeadLetterPolicy { enabled: false }
+        );
+    }
+
+    #[test]
+    async fn add_get_and_remove() {
+        let svc = DeadLetterServiceDefault::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let owned_worker_id = owned_worker_id(&ComponentId(Uuid::new_v4()));
+
+        let second = dead_letter(&owned_worker_id, 2000);
+        let first = dead_letter(&owned_worker_id, 1000);
+        svc.add(&second).await.unwrap();
+        svc.add(&first).await.unwrap();
+
+        assert_eq!(
+            svc.get_all(&owned_worker_id).await.unwrap(),
+            vec![first.clone(), second.clone()]
+        );
+        assert_eq!(
+            svc.get(&owned_worker_id, &first.id).await.unwrap(),
+            Some(first.clone())
+        );
+
+        assert!(svc.remove(&owned_worker_id, &first.id).await.unwrap());
+        assert!(!svc.remove(&owned_worker_id, &first.id).await.unwrap());
+
+        assert_eq!(svc.get_all(&owned_worker_id).await.unwrap(), vec![second]);
+        assert_eq!(svc.get(&owned_worker_id, &first.id).await.unwrap(), None);
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::error::GolemError;
-use golem_common::model::{IdempotencyKey, WorkerId};
+use golem_common::model::{IdempotencyKey, Timestamp, WorkerId, WorkerMetadata, WorkerStatus};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use tokio::sync::broadcast::error::RecvError;
 
@@ -83,4 +83,11 @@ pub enum Event {
         worker_id: WorkerId,
         result: Result<(), GolemError>,
     },
+    /// Published every time the status record of a worker is updated. The metadata
+    /// contains the new status record.
+    WorkerStatusChanged {
+        metadata: WorkerMetadata,
+        previous_status: WorkerStatus,
+        timestamp: Timestamp,
+    },
 }
@@ -24,7 +24,8 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use golem_common::config::{
-    ConfigExample, ConfigLoader, DbSqliteConfig, HasConfigExamples, RedisConfig, RetryConfig,
+    ConfigExample, ConfigLoader, DbPostgresConfig, DbSqliteConfig, HasConfigExamples, RedisConfig,
+    RetryConfig,
 };
 use golem_common::tracing::TracingConfig;
 
@@ -43,6 +44,7 @@ pub struct GolemConfig {
     pub compiled_component_service: CompiledComponentServiceConfig,
     pub shard_manager_service: ShardManagerServiceConfig,
     pub oplog: OplogConfig,
+    pub snapshot: SnapshotConfig,
     pub suspend: SuspendConfig,
     pub active_workers: ActiveWorkersConfig,
     pub scheduler: SchedulerConfig,
@@ -223,6 +225,8 @@ impl WorkerServiceGrpcConfig {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SuspendConfig {
+    /// Sleeps longer than this suspend the worker, persisting their deadline and scheduling
+    /// the worker's resumption instead of keeping it in memory
     #[serde(with = "humantime_serde")]
     pub suspend_after: Duration,
 }
@@ -238,6 +242,9 @@ pub struct ActiveWorkersConfig {
 pub struct SchedulerConfig {
     #[serde(with = "humantime_serde")]
     pub refresh_interval: Duration,
+    /// Delay before retrying the delivery of topic messages which could not be delivered to a subscriber
+    #[serde(with = "humantime_serde")]
+    pub topic_redelivery_interval: Duration,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -252,11 +259,35 @@ pub struct OplogConfig {
     pub archive_interval: Duration,
 }
 
+/// Automatic snapshots of workers exporting the `save-snapshot` and `load-snapshot` interfaces.
+/// A snapshot is taken after an invocation when either of the limits is reached, and the recovery
+/// of the worker starts from its latest snapshot. Workers writing to their own file system are not
+/// snapshotted, as only replaying their oplog restores those files.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotConfig {
+    pub enabled: bool,
+    pub max_oplog_entries_between_snapshots: u64,
+    #[serde(with = "humantime_serde")]
+    pub max_time_between_snapshots: Duration,
+    pub retention: SnapshotRetention,
+}
+
+/// Defines what happens with the oplog entries preceding a snapshot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapshotRetention {
+    /// Keeps every oplog entry
+    KeepAll,
+    /// Drops the entries preceding the latest snapshot when they are moved to the archive
+    /// layers, except the ones needed for looking up the results of past invocations
+    DropCompacted,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "config")]
 pub enum KeyValueStorageConfig {
     Redis(RedisConfig),
     Sqlite(DbSqliteConfig),
+    Postgres(DbPostgresConfig),
     InMemory,
 }
 
@@ -267,6 +298,8 @@ pub enum IndexedStorageConfig {
     Redis(RedisConfig),
     KVStoreSqlite,
     Sqlite(DbSqliteConfig),
+    KVStorePostgres,
+    Postgres(DbPostgresConfig),
     InMemory,
 }
 
@@ -344,6 +377,7 @@ impl Default for GolemConfig {
             compiled_component_service: CompiledComponentServiceConfig::default(),
             shard_manager_service: ShardManagerServiceConfig::default(),
             oplog: OplogConfig::default(),
+            snapshot: SnapshotConfig::default(),
             suspend: SuspendConfig::default(),
             scheduler: SchedulerConfig::default(),
             active_workers: ActiveWorkersConfig::default(),
@@ -497,6 +531,17 @@ impl Default for OplogConfig {
     }
 }
 
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_oplog_entries_between_snapshots: 1024,
+            max_time_between_snapshots: Duration::from_secs(60 * 60),
+            retention: SnapshotRetention::KeepAll,
+        }
+    }
+}
+
 impl Default for SuspendConfig {
     fn default() -> Self {
         Self {
@@ -518,6 +563,7 @@ impl Default for SchedulerConfig {
     fn default() -> Self {
         Self {
             refresh_interval: Duration::from_secs(2),
+            topic_redelivery_interval: Duration::from_secs(10),
         }
     }
 }
@@ -0,0 +1,769 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+use crate::error::GolemError;
+use crate::services::scheduler::SchedulerService;
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::{
+    AccountId, OwnedWorkerId, PromiseId, ScheduledAction, Timestamp, WorkerId,
+};
+
+/// Named counting semaphores shared by the workers of an account. A lock is a semaphore with a
+/// single permit.
+///
+/// Every permit is held with a lease, which the holder can renew, so permits of workers which
+/// failed while holding them become available again once their lease expires. The permits of
+/// deleted workers are released right away. Workers blocked on a semaphore register a promise as
+/// a waiter, which gets completed when a permit is released.
+#[async_trait]
+pub trait LockService {
+    /// Tries to take one of the `permits` permits of the semaphore for the worker, leased for
+    /// the given duration. The number of permits is fixed by the first use of the semaphore.
+    ///
+    /// `acquired_at` is the oplog index of the worker's acquiring call. If a permit is already
+    /// held for the same call, it is the same acquisition retried after the worker got recovered,
+    /// so the held permit is returned instead of taking another one.
+    async fn try_acquire(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        acquired_at: OplogIndex,
+        name: &str,
+        permits: u64,
+        lease: Duration,
+    ) -> Result<LockAcquisition, GolemError>;
+
+    /// Releases a permit held by the worker, returning `false` if it does not hold any (or its
+    /// lease expired and got taken over by another worker)
+    async fn release(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<bool, GolemError>;
+
+    /// Extends the lease of a permit held by the worker to the given duration from now,
+    /// returning `false` if it does not hold any (or its lease expired and got taken over by
+    /// another worker)
+    async fn renew(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+        lease: Duration,
+    ) -> Result<bool, GolemError>;
+
+    /// Releases all the permits held by a worker and removes its waiters, used when the worker
+    /// gets deleted. Returns the names of the semaphores which had permits released.
+    async fn remove_worker(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<String>, GolemError>;
+
+    async fn add_waiter(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+        promise_id: &PromiseId,
+    ) -> Result<(), GolemError>;
+
+    async fn remove_waiter(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+        promise_id: &PromiseId,
+    ) -> Result<(), GolemError>;
+
+    async fn get_waiters(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+    ) -> Result<Vec<PromiseId>, GolemError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockAcquisition {
+    Acquired,
+    /// All the permits are held by other workers. `until` is the earliest expiration of their
+    /// leases, if known.
+    Blocked {
+        until: Option<DateTime<Utc>>,
+    },
+}
+
+/// Wakes up the workers waiting for a semaphore by completing their waiter promises.
+///
+/// The completions go through the scheduler, so the waiting workers get resumed by the executor
+/// owning them. The woken waiters are removed, as the woken workers try to acquire the semaphore
+/// again and the ones not getting a permit register as waiters again.
+pub async fn wake_waiters(
+    lock_service: &Arc<dyn LockService + Send + Sync>,
+    scheduler_service: &Arc<dyn SchedulerService + Send + Sync>,
+    account_id: &AccountId,
+    name: &str,
+) -> Result<(), GolemError> {
+    for promise_id in lock_service.get_waiters(account_id, name).await? {
+        debug!(
+            "Waking up {} waiting for semaphore {name}",
+            promise_id.worker_id
+        );
+        scheduler_service
+            .schedule(
+                Utc::now(),
+                ScheduledAction::CompletePromise {
+                    account_id: account_id.clone(),
+                    promise_id: promise_id.clone(),
+                },
+            )
+            .await;
+        lock_service
+            .remove_waiter(account_id, name, &promise_id)
+            .await?;
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct LockServiceDefault {
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+}
+
+impl LockServiceDefault {
+    pub fn new(key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>) -> Self {
+        Self { key_value_storage }
+    }
+
+    fn permits_key(account_id: &AccountId, name: &str) -> String {
+        format!("account:semaphore:{}:{}", account_id, name)
+    }
+
+    fn permit_key(account_id: &AccountId, name: &str, slot: u64) -> String {
+        format!("account:semaphore-permit:{}:{}:{}", account_id, name, slot)
+    }
+
+    fn worker_semaphores_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!(
+            "worker:semaphores:{}",
+            owned_worker_id.worker_id.to_redis_key()
+        )
+    }
+
+    fn waiters_key(account_id: &AccountId, name: &str) -> String {
+        format!("account:semaphore-waiters:{}:{}", account_id, name)
+    }
+
+    /// Gets the number of permits of a semaphore, defining it with `permits` if it does not
+    /// exist yet
+    async fn permits(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+        permits: u64,
+    ) -> Result<u64, GolemError> {
+        if permits == 0 {
+            return Err(GolemError::runtime(format!(
+                "Semaphore {name} must have at least one permit"
+            )));
+        }
+
+        let key = Self::permits_key(account_id, name);
+        self.key_value_storage
+            .with_entity("lock", "permits", "semaphore")
+            .set_if_not_exists(KeyValueStorageNamespace::Worker, &key, &permits)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to define semaphore: {err}")))?;
+        let existing: u64 = self
+            .key_value_storage
+            .with_entity("lock", "permits", "semaphore")
+            .get(KeyValueStorageNamespace::Worker, &key)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get semaphore: {err}")))?
+            .unwrap_or(permits);
+
+        if existing == permits {
+            Ok(permits)
+        } else {
+            Err(GolemError::runtime(format!(
+                "Semaphore {name} has {existing} permits, cannot use it with {permits}"
+            )))
+        }
+    }
+
+    async fn get_lease(&self, key: &str) -> Result<Option<PermitLease>, GolemError> {
+        self.key_value_storage
+            .with_entity("lock", "get_lease", "permit_lease")
+            .get(KeyValueStorageNamespace::Worker, key)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get semaphore permit: {err}")))
+    }
+
+    async fn claim(&self, key: &str, lease: &PermitLease) -> Result<bool, GolemError> {
+        self.key_value_storage
+            .with_entity("lock", "claim", "permit_lease")
+            .set_if_not_exists(KeyValueStorageNamespace::Worker, key, lease)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to claim semaphore permit: {err}")))
+    }
+
+    /// Replaces a lease with a new one if it did not change since it was read, returning
+    /// whether it got replaced
+    async fn replace(
+        &self,
+        key: &str,
+        current: &PermitLease,
+        lease: &PermitLease,
+    ) -> Result<bool, GolemError> {
+        self.key_value_storage
+            .with_entity("lock", "replace", "permit_lease")
+            .compare_and_set(KeyValueStorageNamespace::Worker, key, current, lease)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to replace semaphore permit: {err}"))
+            })
+    }
+
+    /// Gets the number of permits of an existing semaphore
+    async fn existing_permits(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+    ) -> Result<u64, GolemError> {
+        let permits: Option<u64> = self
+            .key_value_storage
+            .with_entity("lock", "existing_permits", "semaphore")
+            .get(
+                KeyValueStorageNamespace::Worker,
+                &Self::permits_key(account_id, name),
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get semaphore: {err}")))?;
+        Ok(permits.unwrap_or_default())
+    }
+
+    /// Records that a worker is using a semaphore, so its permits and waiters can be removed
+    /// when the worker gets deleted
+    async fn add_worker_semaphore(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<(), GolemError> {
+        self.key_value_storage
+            .with_entity("lock", "add_worker_semaphore", "semaphore_name")
+            .add_to_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::worker_semaphores_key(owned_worker_id),
+                &name.to_string(),
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to record semaphore use: {err}")))
+    }
+}
+
+#[async_trait]
+impl LockService for LockServiceDefault {
+    async fn try_acquire(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        acquired_at: OplogIndex,
+        name: &str,
+        permits: u64,
+        lease: Duration,
+    ) -> Result<LockAcquisition, GolemError> {
+        let account_id = &owned_worker_id.account_id;
+        let permits = self.permits(account_id, name, permits).await?;
+
+        let now = Timestamp::now_utc();
+        let lease = PermitLease {
+            worker_id: owned_worker_id.worker_id.clone(),
+            acquired_at,
+            expires_at: Timestamp::from(now.to_millis() + lease.as_millis() as u64),
+        };
+
+        self.add_worker_semaphore(owned_worker_id, name).await?;
+
+        // The worker may have been restarted after claiming a permit but before recording it
+        // in its oplog, in which case the permit is already taken by this call
+        for slot in 0..permits {
+            let key = Self::permit_key(account_id, name, slot);
+            if let Some(current) = self.get_lease(&key).await? {
+                if current.worker_id == lease.worker_id
+                    && current.acquired_at == acquired_at
+                    && self.replace(&key, &current, &lease).await?
+                {
+                    return Ok(LockAcquisition::Acquired);
+                }
+            }
+        }
+
+        let mut until: Option<Timestamp> = None;
+        for slot in 0..permits {
+            let key = Self::permit_key(account_id, name, slot);
+            let current = match self.get_lease(&key).await? {
+                None if self.claim(&key, &lease).await? => return Ok(LockAcquisition::Acquired),
+                None => self.get_lease(&key).await?,
+                Some(current) if current.expires_at <= now => {
+                    // Only one of the workers racing for the expired lease can replace it
+                    if self.replace(&key, &current, &lease).await? {
+                        return Ok(LockAcquisition::Acquired);
+                    }
+                    self.get_lease(&key).await?
+                }
+                Some(current) => Some(current),
+            };
+
+            if let Some(current) = current {
+                until = Some(match until {
+                    Some(until) => until.min(current.expires_at),
+                    None => current.expires_at,
+                });
+            }
+        }
+
+        Ok(LockAcquisition::Blocked {
+            until: until
+                .and_then(|until| DateTime::from_timestamp_millis(until.to_millis() as i64)),
+        })
+    }
+
+    async fn release(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+    ) -> Result<bool, GolemError> {
+        let account_id = &owned_worker_id.account_id;
+        let permits = self.existing_permits(account_id, name).await?;
+
+        for slot in 0..permits {
+            let key = Self::permit_key(account_id, name, slot);
+            if let Some(lease) = self.get_lease(&key).await? {
+                if lease.worker_id == owned_worker_id.worker_id {
+                    self.key_value_storage
+                        .with("lock", "release")
+                        .del(KeyValueStorageNamespace::Worker, &key)
+                        .await
+                        .map_err(|err| {
+                            GolemError::runtime(format!(
+                                "Failed to release semaphore permit: {err}"
+                            ))
+                        })?;
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    async fn renew(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        name: &str,
+        lease: Duration,
+    ) -> Result<bool, GolemError> {
+        let account_id = &owned_worker_id.account_id;
+        let permits = self.existing_permits(account_id, name).await?;
+
+        let now = Timestamp::now_utc();
+        for slot in 0..permits {
+            let key = Self::permit_key(account_id, name, slot);
+            if let Some(current) = self.get_lease(&key).await? {
+                let renewed = PermitLease {
+                    expires_at: Timestamp::from(now.to_millis() + lease.as_millis() as u64),
+                    ..current.clone()
+                };
+                // An expired lease may already be taken over, so it cannot be renewed anymore
+                if current.worker_id == owned_worker_id.worker_id
+                    && current.expires_at > now
+                    && self.replace(&key, &current, &renewed).await?
+                {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    async fn remove_worker(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<String>, GolemError> {
+        let account_id = &owned_worker_id.account_id;
+        let key = Self::worker_semaphores_key(owned_worker_id);
+        let names: Vec<String> = self
+            .key_value_storage
+            .with_entity("lock", "remove_worker", "semaphore_name")
+            .members_of_set(KeyValueStorageNamespace::Worker, &key)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get worker semaphores: {err}"))
+            })?;
+
+        let mut released = Vec::new();
+        for name in names {
+            for promise_id in self.get_waiters(account_id, &name).await? {
+                if promise_id.worker_id == owned_worker_id.worker_id {
+                    self.remove_waiter(account_id, &name, &promise_id).await?;
+                }
+            }
+            if self.release(owned_worker_id, &name).await? {
+                while self.release(owned_worker_id, &name).await? {}
+                released.push(name);
+            }
+        }
+
+        self.key_value_storage
+            .with("lock", "remove_worker")
+            .del(KeyValueStorageNamespace::Worker, &key)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to remove worker semaphores: {err}"))
+            })?;
+        Ok(released)
+    }
+
+    async fn add_waiter(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+        promise_id: &PromiseId,
+    ) -> Result<(), GolemError> {
+        self.add_worker_semaphore(&OwnedWorkerId::new(account_id, &promise_id.worker_id), name)
+            .await?;
+        self.key_value_storage
+            .with_entity("lock", "add_waiter", "promise_id")
+            .add_to_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::waiters_key(account_id, name),
+                promise_id,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to add semaphore waiter: {err}")))
+    }
+
+    async fn remove_waiter(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+        promise_id: &PromiseId,
+    ) -> Result<(), GolemError> {
+        self.key_value_storage
+            .with_entity("lock", "remove_waiter", "promise_id")
+            .remove_from_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::waiters_key(account_id, name),
+                promise_id,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to remove semaphore waiter: {err}")))
+    }
+
+    async fn get_waiters(
+        &self,
+        account_id: &AccountId,
+        name: &str,
+    ) -> Result<Vec<PromiseId>, GolemError> {
+        self.key_value_storage
+            .with_entity("lock", "get_waiters", "promise_id")
+            .members_of_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::waiters_key(account_id, name),
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get semaphore waiters: {err}")))
+    }
+}
+
+/// A permit of a semaphore held by a worker
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+struct PermitLease {
+    worker_id: WorkerId,
+    /// The oplog index of the worker's call which took the permit
+    acquired_at: OplogIndex,
+    expires_at: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use uuid::Uuid;
+
+    use crate::services::lock::{LockAcquisition, LockService, LockServiceDefault};
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, PromiseId, WorkerId};
+
+    fn worker(worker_name: &str) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            &AccountId {
+                value: "test-account".to_string(),
+            },
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: worker_name.to_string(),
+            },
+        )
+    }
+
+    fn svc() -> LockServiceDefault {
+        LockServiceDefault::new(Arc::new(InMemoryKeyValueStorage::new()))
+    }
+
+    const LEASE: Duration = Duration::from_secs(60);
+    const CALL: OplogIndex = OplogIndex::INITIAL;
+
+    #[test]
+    async fn lock_is_exclusive_until_released() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let worker2 = worker("worker-2");
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert!(matches!(
+            svc.try_acquire(&worker2, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Blocked { until: Some(_) }
+        ));
+        assert!(!svc.release(&worker2, "lock").await.unwrap());
+
+        assert!(svc.release(&worker1, "lock").await.unwrap());
+        assert!(!svc.release(&worker1, "lock").await.unwrap());
+        assert_eq!(
+            svc.try_acquire(&worker2, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+    }
+
+    #[test]
+    async fn semaphore_has_a_fixed_number_of_permits() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let worker2 = worker("worker-2");
+        let worker3 = worker("worker-3");
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert_eq!(
+            svc.try_acquire(&worker2, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert!(matches!(
+            svc.try_acquire(&worker3, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Blocked { .. }
+        ));
+        assert!(svc
+            .try_acquire(&worker3, CALL, "pool", 3, LEASE)
+            .await
+            .is_err());
+
+        assert!(svc.release(&worker1, "pool").await.unwrap());
+        assert_eq!(
+            svc.try_acquire(&worker3, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+    }
+
+    #[test]
+    async fn retried_acquisition_gets_the_same_permit() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let worker2 = worker("worker-2");
+        let next_call = OplogIndex::from_u64(10);
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert_eq!(
+            svc.try_acquire(&worker2, CALL, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert!(matches!(
+            svc.try_acquire(&worker1, next_call, "pool", 2, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Blocked { .. }
+        ));
+
+        assert!(svc.release(&worker1, "pool").await.unwrap());
+        assert!(!svc.release(&worker1, "pool").await.unwrap());
+    }
+
+    #[test]
+    async fn expired_leases_are_taken_over() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let worker2 = worker("worker-2");
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "lock", 1, Duration::ZERO)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert_eq!(
+            svc.try_acquire(&worker2, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert!(!svc.release(&worker1, "lock").await.unwrap());
+        assert!(svc.release(&worker2, "lock").await.unwrap());
+    }
+
+    #[test]
+    async fn leases_are_renewed_until_taken_over() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let worker2 = worker("worker-2");
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert!(svc.renew(&worker1, "lock", LEASE).await.unwrap());
+        assert!(!svc.renew(&worker2, "lock", LEASE).await.unwrap());
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "expiring", 1, Duration::ZERO)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert_eq!(
+            svc.try_acquire(&worker2, CALL, "expiring", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert!(!svc.renew(&worker1, "expiring", LEASE).await.unwrap());
+        assert!(svc.renew(&worker2, "expiring", LEASE).await.unwrap());
+    }
+
+    #[test]
+    async fn removed_workers_release_their_permits_and_waiters() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let worker2 = worker("worker-2");
+        let waiter1 = PromiseId {
+            worker_id: worker1.worker_id.clone(),
+            oplog_idx: OplogIndex::from_u64(3),
+        };
+        let waiter2 = PromiseId {
+            worker_id: worker2.worker_id.clone(),
+            oplog_idx: OplogIndex::from_u64(5),
+        };
+
+        assert_eq!(
+            svc.try_acquire(&worker1, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        svc.add_waiter(&worker1.account_id, "other", &waiter1)
+            .await
+            .unwrap();
+        svc.add_waiter(&worker2.account_id, "other", &waiter2)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            svc.remove_worker(&worker1).await.unwrap(),
+            vec!["lock".to_string()]
+        );
+
+        assert_eq!(
+            svc.try_acquire(&worker2, CALL, "lock", 1, LEASE)
+                .await
+                .unwrap(),
+            LockAcquisition::Acquired
+        );
+        assert_eq!(
+            svc.get_waiters(&worker1.account_id, "other").await.unwrap(),
+            vec![waiter2]
+        );
+    }
+
+    #[test]
+    async fn waiters_are_tracked_per_semaphore() {
+        let svc = svc();
+        let worker1 = worker("worker-1");
+        let promise_id = PromiseId {
+            worker_id: worker1.worker_id.clone(),
+            oplog_idx: OplogIndex::from_u64(3),
+        };
+
+        svc.add_waiter(&worker1.account_id, "lock", &promise_id)
+            .await
+            .unwrap();
+        assert_eq!(
+            svc.get_waiters(&worker1.account_id, "lock").await.unwrap(),
+            vec![promise_id.clone()]
+        );
+        assert!(svc
+            .get_waiters(&worker1.account_id, "other")
+            .await
+            .unwrap()
+            .is_empty());
+
+        svc.remove_waiter(&worker1.account_id, "lock", &promise_id)
+            .await
+            .unwrap();
+        assert!(svc
+            .get_waiters(&worker1.account_id, "lock")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
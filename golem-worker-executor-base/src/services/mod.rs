@@ -23,11 +23,14 @@ pub mod active_workers;
 pub mod blob_store;
 pub mod compiled_component;
 pub mod component;
+pub mod dead_letter;
 pub mod events;
 pub mod golem_config;
 pub mod key_value;
+pub mod lock;
 pub mod oplog;
 pub mod promise;
+pub mod pubsub;
 pub mod rpc;
 pub mod scheduler;
 pub mod shard;
@@ -106,6 +109,18 @@ pub trait HasSchedulerService {
     fn scheduler_service(&self) -> Arc<dyn scheduler::SchedulerService + Send + Sync>;
 }
 
+pub trait HasDeadLetterService {
+    fn dead_letter_service(&self) -> Arc<dyn dead_letter::DeadLetterService + Send + Sync>;
+}
+
+pub trait HasPubSubService {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService + Send + Sync>;
+}
+
+pub trait HasLockService {
+    fn lock_service(&self) -> Arc<dyn lock::LockService + Send + Sync>;
+}
+
 pub trait HasExtraDeps<Ctx: WorkerCtx> {
     fn extra_deps(&self) -> Ctx::ExtraDeps;
 }
@@ -145,6 +160,9 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasOplogService
     + HasRpc
     + HasSchedulerService
+    + HasDeadLetterService
+    + HasPubSubService
+    + HasLockService
     + HasWorkerActivator
     + HasWorkerProxy
     + HasEvents
@@ -170,6 +188,9 @@ impl<
             + HasOplogService
             + HasRpc
             + HasSchedulerService
+            + HasDeadLetterService
+            + HasPubSubService
+            + HasLockService
             + HasWorkerActivator
             + HasWorkerProxy
             + HasEvents
@@ -202,6 +223,9 @@ pub struct All<Ctx: WorkerCtx> {
     oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
     rpc: Arc<dyn rpc::Rpc + Send + Sync>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    dead_letter_service: Arc<dyn dead_letter::DeadLetterService + Send + Sync>,
+    pubsub_service: Arc<dyn pubsub::PubSubService + Send + Sync>,
+    lock_service: Arc<dyn lock::LockService + Send + Sync>,
     worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
     worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
     events: Arc<Events>,
@@ -228,6 +252,9 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             oplog_service: self.oplog_service.clone(),
             rpc: self.rpc.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            dead_letter_service: self.dead_letter_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            lock_service: self.lock_service.clone(),
             worker_activator: self.worker_activator.clone(),
             worker_proxy: self.worker_proxy.clone(),
             events: self.events.clone(),
@@ -260,6 +287,9 @@ impl<Ctx: WorkerCtx> All<Ctx> {
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         rpc: Arc<dyn rpc::Rpc + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        dead_letter_service: Arc<dyn dead_letter::DeadLetterService + Send + Sync>,
+        pubsub_service: Arc<dyn pubsub::PubSubService + Send + Sync>,
+        lock_service: Arc<dyn lock::LockService + Send + Sync>,
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         worker_proxy: Arc<dyn worker_proxy::WorkerProxy + Send + Sync>,
         events: Arc<Events>,
@@ -283,6 +313,9 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             oplog_service,
             rpc,
             scheduler_service,
+            dead_letter_service,
+            pubsub_service,
+            lock_service,
             worker_activator,
             worker_proxy,
             events,
@@ -309,6 +342,9 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             this.oplog_service(),
             this.rpc(),
             this.scheduler_service(),
+            this.dead_letter_service(),
+            this.pubsub_service(),
+            this.lock_service(),
             this.worker_activator(),
             this.worker_proxy(),
             this.events(),
@@ -433,6 +469,24 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasSchedulerService for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasDeadLetterService for T {
+    fn dead_letter_service(&self) -> Arc<dyn dead_letter::DeadLetterService + Send + Sync> {
+        self.all().dead_letter_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasPubSubService for T {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService + Send + Sync> {
+        self.all().pubsub_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasLockService for T {
+    fn lock_service(&self) -> Arc<dyn lock::LockService + Send + Sync> {
+        self.all().lock_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWorkerActivator for T {
     fn worker_activator(&self) -> Arc<dyn WorkerActivator + Send + Sync> {
         self.all().worker_activator.clone()
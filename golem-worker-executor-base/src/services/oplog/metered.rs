@@ -0,0 +1,126 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::OwnedWorkerId;
+use golem_common::serialization::serialize;
+
+use crate::quotas::QuotaTracker;
+use crate::services::oplog::{CommitLevel, Oplog, OplogService};
+
+/// Oplog wrapper counting the added entries in a worker's `QuotaTracker`, used when the worker
+/// has oplog quotas
+pub struct MeteredOplog {
+    inner: Arc<dyn Oplog + Send + Sync>,
+    quota_tracker: Arc<QuotaTracker>,
+}
+
+impl MeteredOplog {
+    pub fn new(inner: Arc<dyn Oplog + Send + Sync>, quota_tracker: Arc<QuotaTracker>) -> Self {
+        Self {
+            inner,
+            quota_tracker,
+        }
+    }
+
+    /// Calculates the total serialized size of a worker's existing oplog entries
+    pub async fn size_of_existing_entries(
+        oplog_service: &Arc<dyn OplogService + Send + Sync>,
+        owned_worker_id: &OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+    ) -> u64 {
+        const PAGE_SIZE: u64 = 1024;
+
+        let mut size = 0;
+        let mut current = OplogIndex::INITIAL;
+        while current <= last_oplog_index {
+            let entries = oplog_service
+                .read(owned_worker_id, current, PAGE_SIZE)
+                .await;
+            if entries.is_empty() {
+                break;
+            }
+            for entry in entries.values() {
+                size += entry_size(entry);
+            }
+            current = current.range_end(PAGE_SIZE).next();
+        }
+        size
+    }
+}
+
+impl Debug for MeteredOplog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MeteredOplog")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Oplog for MeteredOplog {
+    async fn add(&self, entry: OplogEntry) {
+        let size = if self.quota_tracker.meters_oplog_bytes() {
+            entry_size(&entry)
+        } else {
+            0
+        };
+        self.inner.add(entry).await;
+        self.quota_tracker.record_oplog_entry(size);
+    }
+
+    async fn drop_prefix(&self, last_dropped_id: OplogIndex) {
+        self.inner.drop_prefix(last_dropped_id).await
+    }
+
+    async fn commit(&self, level: CommitLevel) {
+        self.inner.commit(level).await
+    }
+
+    async fn current_oplog_index(&self) -> OplogIndex {
+        self.inner.current_oplog_index().await
+    }
+
+    async fn wait_for_replicas(&self, replicas: u8, timeout: Duration) -> bool {
+        self.inner.wait_for_replicas(replicas, timeout).await
+    }
+
+    async fn read(&self, oplog_index: OplogIndex) -> OplogEntry {
+        self.inner.read(oplog_index).await
+    }
+
+    async fn length(&self) -> u64 {
+        self.inner.length().await
+    }
+
+    async fn upload_payload(&self, data: &[u8]) -> Result<OplogPayload, String> {
+        self.inner.upload_payload(data).await
+    }
+
+    async fn download_payload(&self, payload: &OplogPayload) -> Result<Bytes, String> {
+        self.inner.download_payload(payload).await
+    }
+}
+
+fn entry_size(entry: &OplogEntry) -> u64 {
+    serialize(entry)
+        .map(|bytes| bytes.len() as u64)
+        .unwrap_or_default()
+}
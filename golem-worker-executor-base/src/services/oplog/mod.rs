@@ -29,11 +29,13 @@ use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode};
 use golem_common::model::oplog::{
     OplogEntry, OplogIndex, OplogPayload, UpdateDescription, WrappedFunctionType,
 };
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{
     AccountId, ComponentId, ComponentType, ComponentVersion, IdempotencyKey, OwnedWorkerId,
     ScanCursor, Timestamp, WorkerId,
 };
 use golem_common::serialization::{serialize, try_deserialize};
+pub use metered::MeteredOplog;
 pub use multilayer::{MultiLayerOplog, MultiLayerOplogService, OplogArchiveService};
 pub use primary::PrimaryOplogService;
 use tracing::Instrument;
@@ -43,6 +45,7 @@ use crate::error::GolemError;
 mod blob;
 mod compressed;
 mod ephemeral;
+mod metered;
 mod multilayer;
 mod primary;
 
@@ -147,6 +150,99 @@ pub trait OplogService: Debug {
         owned_worker_id: &OwnedWorkerId,
         payload: &OplogPayload,
     ) -> Result<Bytes, String>;
+
+    /// Creates a new oplog for `target_worker_id` containing a copy of the source worker's oplog
+    /// up to and including `last_idx`.
+    ///
+    /// The prefix is read with `read_prefix`, so in case of a multi-layer oplog all the layers are
+    /// considered. Externally stored payloads are copied to the target worker's namespace.
+    async fn fork(
+        &self,
+        source_worker_id: &OwnedWorkerId,
+        target_worker_id: &OwnedWorkerId,
+        last_idx: OplogIndex,
+        component_type: ComponentType,
+    ) -> Result<(), GolemError> {
+        let mut entries = self
+            .read_prefix(source_worker_id, last_idx)
+            .await
+            .into_values();
+
+        let initial_entry = match entries.next() {
+            Some(OplogEntry::CreateV1 {
+                timestamp,
+                worker_id: _,
+                component_version,
+                args,
+                env,
+                account_id,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+            }) => OplogEntry::CreateV1 {
+                timestamp,
+                worker_id: target_worker_id.worker_id(),
+                component_version,
+                args,
+                env,
+                account_id,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+            },
+            Some(OplogEntry::Create {
+                timestamp,
+                worker_id: _,
+                component_version,
+                args,
+                env,
+                account_id,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+                quotas,
+            }) => OplogEntry::Create {
+                timestamp,
+                worker_id: target_worker_id.worker_id(),
+                component_version,
+                args,
+                env,
+                account_id,
+                parent,
+                component_size,
+                initial_total_linear_memory_size,
+                quotas,
+            },
+            Some(entry) => {
+                return Err(GolemError::unknown(format!(
+                    "Unexpected initial oplog entry in {source_worker_id}: {entry:?}"
+                )))
+            }
+            None => return Err(GolemError::worker_not_found(source_worker_id.worker_id())),
+        };
+
+        let oplog = self
+            .create(target_worker_id, initial_entry, component_type)
+            .await;
+        for mut entry in entries {
+            for payload in entry.payloads_mut() {
+                if let OplogPayload::External { .. } = payload {
+                    let data = self
+                        .download_payload(source_worker_id, payload)
+                        .await
+                        .map_err(GolemError::runtime)?;
+                    *payload = self
+                        .upload_payload(target_worker_id, &data)
+                        .await
+                        .map_err(GolemError::runtime)?;
+                }
+            }
+            oplog.add(entry).await;
+        }
+        oplog.commit(CommitLevel::Always).await;
+
+        Ok(())
+    }
 }
 
 /// Level of commit guarantees
@@ -243,6 +339,7 @@ pub trait OplogOps: Oplog {
         function_name: String,
         request: &R,
         idempotency_key: IdempotencyKey,
+        trace_context: Option<TraceContext>,
     ) -> Result<OplogEntry, String> {
         let serialized_request = serialize(request)?.to_vec();
 
@@ -252,6 +349,7 @@ pub trait OplogOps: Oplog {
             function_name,
             request: payload,
             idempotency_key,
+            trace_context,
         };
         self.add(entry.clone()).await;
         Ok(entry)
@@ -299,6 +397,10 @@ pub trait OplogOps: Oplog {
                 let response_bytes: Bytes = self.download_payload(response).await?;
                 try_deserialize(&response_bytes)
             }
+            OplogEntry::ExportedFunctionInvokedV1 { request, .. } => {
+                let response_bytes: Bytes = self.download_payload(request).await?;
+                try_deserialize(&response_bytes)
+            }
             OplogEntry::ExportedFunctionInvoked { request, .. } => {
                 let response_bytes: Bytes = self.download_payload(request).await?;
                 try_deserialize(&response_bytes)
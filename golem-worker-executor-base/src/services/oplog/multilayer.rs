@@ -27,6 +27,7 @@ use tokio::sync::oneshot::Sender;
 use tracing::{debug, error, info, warn, Instrument};
 
 use crate::error::GolemError;
+use crate::services::golem_config::SnapshotRetention;
 use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
 use golem_common::model::{AccountId, ComponentId, ComponentType, OwnedWorkerId, ScanCursor};
 
@@ -120,6 +121,7 @@ pub struct MultiLayerOplogService {
 
     entry_count_limit: u64,
     max_operations_before_commit_ephemeral: u64,
+    snapshot_retention: SnapshotRetention,
 }
 
 impl MultiLayerOplogService {
@@ -128,6 +130,7 @@ impl MultiLayerOplogService {
         lower: NEVec<Arc<dyn OplogArchiveService + Send + Sync>>,
         entry_count_limit: u64,
         max_operations_before_commit_ephemeral: u64,
+        snapshot_retention: SnapshotRetention,
     ) -> Self {
         Self {
             primary,
@@ -135,6 +138,7 @@ impl MultiLayerOplogService {
             oplogs: OpenOplogs::new("multi-layer oplog"),
             entry_count_limit,
             max_operations_before_commit_ephemeral,
+            snapshot_retention,
         }
     }
 }
@@ -147,6 +151,7 @@ impl Clone for MultiLayerOplogService {
             oplogs: self.oplogs.clone(),
             entry_count_limit: self.entry_count_limit,
             max_operations_before_commit_ephemeral: self.max_operations_before_commit_ephemeral,
+            snapshot_retention: self.snapshot_retention.clone(),
         }
     }
 }
@@ -567,8 +572,10 @@ impl MultiLayerOplog {
                     debug!("Reading entries from oplog layer {source}");
 
                     let transfer = BackgroundTransferBetweenLowers::new(
+                        owned_worker_id.clone(),
                         source,
                         last_transferred_idx,
+                        multi_layer_oplog_service.clone(),
                         lower.clone(),
                     );
                     let result = transfer.run().await;
@@ -763,15 +770,23 @@ trait BackgroundTransfer {
     async fn append_target(&self, entries: Vec<(OplogIndex, OplogEntry)>);
     async fn drop_source_prefix(&self, last_dropped_id: OplogIndex);
 
+    /// Index of the latest snapshot whose preceding entries can be dropped, or `OplogIndex::INITIAL`
+    async fn last_snapshot_idx(&self, from: OplogIndex) -> OplogIndex;
+
     async fn run(&self) -> Result<(), String> {
         let entries: Vec<_> = self.read_source().await;
-        match entries.last() {
-            Some(last_entry) => {
+        match (entries.first(), entries.last()) {
+            (Some(first_entry), Some(last_entry)) => {
                 let last_dropped_id = last_entry.0;
+                let last_snapshot_idx = self.last_snapshot_idx(first_entry.0).await;
+                let entries = entries
+                    .into_iter()
+                    .map(|(idx, entry)| (idx, compact(idx, entry, last_snapshot_idx)))
+                    .collect();
                 self.append_target(entries).await;
                 self.drop_source_prefix(last_dropped_id).await;
             }
-            None => {
+            _ => {
                 warn!("No entries to transfer from the primary oplog");
             }
         }
@@ -779,6 +794,84 @@ trait BackgroundTransfer {
     }
 }
 
+/// Finds the last `Snapshot` entry of the worker's oplog at or after `from`, returning
+/// `OplogIndex::INITIAL` if there is none or snapshots are kept in the archives.
+///
+/// The oplog itself is scanned instead of tracking the snapshots as they are added, so the
+/// result does not depend on the oplog being open since the snapshot was written.
+async fn find_last_snapshot_idx(
+    multi_layer_oplog_service: &MultiLayerOplogService,
+    owned_worker_id: &OwnedWorkerId,
+    from: OplogIndex,
+) -> OplogIndex {
+    const CHUNK_SIZE: u64 = 1024;
+
+    if multi_layer_oplog_service.snapshot_retention != SnapshotRetention::DropCompacted {
+        return OplogIndex::INITIAL;
+    }
+
+    let from: u64 = from.into();
+    let mut end: u64 = multi_layer_oplog_service
+        .get_last_index(owned_worker_id)
+        .await
+        .into();
+    while end >= from.max(1) {
+        let start = end.saturating_sub(CHUNK_SIZE - 1).max(from).max(1);
+        let entries = multi_layer_oplog_service
+            .read(
+                owned_worker_id,
+                OplogIndex::from_u64(start),
+                end - start + 1,
+            )
+            .await;
+        if let Some((idx, _)) = entries
+            .iter()
+            .rev()
+            .find(|(_, entry)| matches!(entry, OplogEntry::Snapshot { .. }))
+        {
+            return *idx;
+        }
+        end = start - 1;
+    }
+    OplogIndex::INITIAL
+}
+
+/// Entries preceding a snapshot are not needed for replaying the worker, but some of them are
+/// still used for calculating its status from the oplog: the initial `Create` entry, the
+/// invocations and their results which are looked up by idempotency keys, the pending
+/// invocations and updates, and the entries describing the retry policy, memory and resources.
+/// The rest of them are replaced by `NoOp` entries so the archives keep a contiguous range of
+/// indices.
+fn compact(idx: OplogIndex, entry: OplogEntry, last_snapshot_idx: OplogIndex) -> OplogEntry {
+    if idx > OplogIndex::INITIAL
+        && idx < last_snapshot_idx
+        && !matches!(
+            entry,
+            OplogEntry::ExportedFunctionInvokedV1 { .. }
+                | OplogEntry::ExportedFunctionInvoked { .. }
+                | OplogEntry::ExportedFunctionCompleted { .. }
+                | OplogEntry::Error { .. }
+                | OplogEntry::Interrupted { .. }
+                | OplogEntry::Exited { .. }
+                | OplogEntry::PendingWorkerInvocation { .. }
+                | OplogEntry::PendingUpdate { .. }
+                | OplogEntry::SuccessfulUpdate { .. }
+                | OplogEntry::FailedUpdate { .. }
+                | OplogEntry::ChangeRetryPolicy { .. }
+                | OplogEntry::GrowMemory { .. }
+                | OplogEntry::CreateResource { .. }
+                | OplogEntry::DropResource { .. }
+                | OplogEntry::DescribeResource { .. }
+        )
+    {
+        OplogEntry::NoOp {
+            timestamp: entry.timestamp(),
+        }
+    } else {
+        entry
+    }
+}
+
 /// Wraps an open oplog archive to track the number of items written and automatically
 /// scheduling transfers to lower levels when the limit is reached
 #[derive(Debug)]
@@ -899,25 +992,35 @@ impl BackgroundTransfer for BackgroundTransferFromPrimary {
     async fn drop_source_prefix(&self, last_dropped_id: OplogIndex) {
         self.primary.drop_prefix(last_dropped_id).await
     }
+
+    async fn last_snapshot_idx(&self, from: OplogIndex) -> OplogIndex {
+        find_last_snapshot_idx(&self.multi_layer_oplog_service, &self.owned_worker_id, from).await
+    }
 }
 
 struct BackgroundTransferBetweenLowers {
+    owned_worker_id: OwnedWorkerId,
     last_transferred_idx: OplogIndex,
+    multi_layer_oplog_service: MultiLayerOplogService,
     source_layer: Arc<dyn OplogArchive + Send + Sync>,
     target_layer: Arc<dyn OplogArchive + Send + Sync>,
 }
 
 impl BackgroundTransferBetweenLowers {
     pub fn new(
+        owned_worker_id: OwnedWorkerId,
         source: usize,
         last_transferred_idx: OplogIndex,
+        multi_layer_oplog_service: MultiLayerOplogService,
         lower: NEVec<Arc<dyn OplogArchive + Send + Sync>>,
     ) -> Self {
         let source_layer = lower[source].clone();
         let target_layer = lower[source + 1].clone();
 
         Self {
+            owned_worker_id,
             last_transferred_idx,
+            multi_layer_oplog_service,
             source_layer,
             target_layer,
         }
@@ -941,4 +1044,8 @@ impl BackgroundTransfer for BackgroundTransferBetweenLowers {
     async fn drop_source_prefix(&self, last_dropped_id: OplogIndex) {
         self.source_layer.drop_prefix(last_dropped_id).await
     }
+
+    async fn last_snapshot_idx(&self, from: OplogIndex) -> OplogIndex {
+        find_last_snapshot_idx(&self.multi_layer_oplog_service, &self.owned_worker_id, from).await
+    }
 }
@@ -22,11 +22,13 @@ use uuid::Uuid;
 
 use golem_common::config::RedisConfig;
 use golem_common::model::oplog::WorkerError;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::regions::OplogRegion;
 use golem_common::model::ComponentId;
 use golem_common::redis::RedisPool;
 use golem_common::tracing::{init_tracing, TracingConfig};
 
+use crate::services::golem_config::SnapshotRetention;
 use crate::services::oplog::compressed::CompressedOplogArchiveService;
 use crate::services::oplog::multilayer::OplogArchiveService;
 use crate::storage::blob::memory::InMemoryBlobStorage;
@@ -58,6 +60,27 @@ fn rounded_ts(ts: Timestamp) -> Timestamp {
 
 fn rounded(entry: OplogEntry) -> OplogEntry {
     match entry {
+        OplogEntry::CreateV1 {
+            timestamp,
+            worker_id,
+            component_version,
+            args,
+            env,
+            account_id,
+            parent,
+            component_size,
+            initial_total_linear_memory_size,
+        } => OplogEntry::CreateV1 {
+            timestamp: rounded_ts(timestamp),
+            worker_id,
+            component_version,
+            args,
+            env,
+            account_id,
+            parent,
+            component_size,
+            initial_total_linear_memory_size,
+        },
         OplogEntry::Create {
             timestamp,
             worker_id,
@@ -68,6 +91,7 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
             parent,
             component_size,
             initial_total_linear_memory_size,
+            quotas,
         } => OplogEntry::Create {
             timestamp: rounded_ts(timestamp),
             worker_id,
@@ -78,6 +102,7 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
             parent,
             component_size,
             initial_total_linear_memory_size,
+            quotas,
         },
         OplogEntry::ImportedFunctionInvokedV1 {
             timestamp,
@@ -103,16 +128,29 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
             response,
             wrapped_function_type,
         },
+        OplogEntry::ExportedFunctionInvokedV1 {
+            timestamp,
+            function_name,
+            request,
+            idempotency_key,
+        } => OplogEntry::ExportedFunctionInvokedV1 {
+            timestamp: rounded_ts(timestamp),
+            function_name,
+            request,
+            idempotency_key,
+        },
         OplogEntry::ExportedFunctionInvoked {
             timestamp,
             function_name,
             request,
             idempotency_key,
+            trace_context,
         } => OplogEntry::ExportedFunctionInvoked {
             timestamp: rounded_ts(timestamp),
             function_name,
             request,
             idempotency_key,
+            trace_context,
         },
         OplogEntry::ExportedFunctionCompleted {
             timestamp,
@@ -237,6 +275,14 @@ fn rounded(entry: OplogEntry) -> OplogEntry {
         OplogEntry::Restart { timestamp } => OplogEntry::Restart {
             timestamp: rounded_ts(timestamp),
         },
+        OplogEntry::ChangeFileSystem { timestamp, change } => OplogEntry::ChangeFileSystem {
+            timestamp: rounded_ts(timestamp),
+            change,
+        },
+        OplogEntry::Snapshot { timestamp, payload } => OplogEntry::Snapshot {
+            timestamp: rounded_ts(timestamp),
+            payload,
+        },
     }
 }
 
@@ -305,6 +351,7 @@ async fn open_add_and_read_back_ephemeral(_tracing: &Tracing) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         10,
         10,
+        SnapshotRetention::KeepAll,
     ));
 
     let account_id = AccountId {
@@ -387,6 +434,7 @@ async fn entries_with_small_payload(_tracing: &Tracing) {
                 "f2".to_string(),
                 &"request".to_string(),
                 IdempotencyKey::fresh(),
+                None,
             )
             .await
             .unwrap(),
@@ -501,6 +549,7 @@ async fn entries_with_large_payload(_tracing: &Tracing) {
                 "f2".to_string(),
                 &large_payload2,
                 IdempotencyKey::fresh(),
+                None,
             )
             .await
             .unwrap(),
@@ -652,6 +701,7 @@ async fn multilayer_transfers_entries_after_limit_reached(
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         10,
         10,
+        SnapshotRetention::KeepAll,
     ));
 
     let account_id = AccountId {
@@ -776,6 +826,7 @@ async fn read_from_archive_impl(use_blob: bool) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         10,
         10,
+        SnapshotRetention::KeepAll,
     ));
     let account_id = AccountId {
         value: "user1".to_string(),
@@ -870,6 +921,7 @@ async fn read_initial_from_archive_impl(use_blob: bool) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         10,
         10,
+        SnapshotRetention::KeepAll,
     ));
     let account_id = AccountId {
         value: "user1".to_string(),
@@ -896,6 +948,7 @@ async fn read_initial_from_archive_impl(use_blob: bool) {
         parent: None,
         component_size: 0,
         initial_total_linear_memory_size: 0,
+        quotas: WorkerQuotas::default(),
     });
 
     let oplog = oplog_service
@@ -1010,6 +1063,7 @@ async fn write_after_archive_impl(use_blob: bool, reopen: Reopen) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         10,
         10,
+        SnapshotRetention::KeepAll,
     ));
     let account_id = AccountId {
         value: "user1".to_string(),
@@ -1078,6 +1132,7 @@ async fn write_after_archive_impl(use_blob: bool, reopen: Reopen) {
             nev![secondary_layer.clone(), tertiary_layer.clone()],
             10,
             10,
+            SnapshotRetention::KeepAll,
         ));
         let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
         oplog_service
@@ -1138,6 +1193,7 @@ async fn write_after_archive_impl(use_blob: bool, reopen: Reopen) {
             nev![secondary_layer.clone(), tertiary_layer.clone()],
             10,
             10,
+            SnapshotRetention::KeepAll,
         ));
         let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
         oplog_service
@@ -1241,6 +1297,7 @@ async fn empty_layer_gets_deleted_impl(use_blob: bool) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         10,
         10,
+        SnapshotRetention::KeepAll,
     ));
     let account_id = AccountId {
         value: "user1".to_string(),
@@ -1346,6 +1403,7 @@ async fn scheduled_archive_impl(use_blob: bool) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         1000, // no transfer will occur by reaching limit in this test
         10,
+        SnapshotRetention::KeepAll,
     ));
     let account_id = AccountId {
         value: "user1".to_string(),
@@ -1450,6 +1508,85 @@ async fn scheduled_archive_impl(use_blob: bool) {
     assert_eq!(last_oplog_index_2, last_oplog_index_3);
 }
 
+#[test]
+async fn entries_preceding_snapshot_are_compacted_when_archived(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+        CompressedOplogArchiveService::new(indexed_storage.clone(), 1),
+    );
+    let tertiary_layer: Arc<dyn OplogArchiveService + Send + Sync> =
+        Arc::new(BlobOplogArchiveService::new(blob_storage.clone(), 2));
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone(), tertiary_layer.clone()],
+        1000, // no transfer will occur by reaching limit in this test
+        10,
+        SnapshotRetention::DropCompacted,
+    ));
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let worker_id = WorkerId {
+        component_id: ComponentId(Uuid::new_v4()),
+        worker_name: "test".to_string(),
+    };
+    let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+    let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+    let oplog = oplog_service
+        .open(&owned_worker_id, last_oplog_index, ComponentType::Durable)
+        .await;
+
+    let entry1 = rounded(OplogEntry::suspend());
+    let entry2 = rounded(OplogEntry::suspend());
+    let entry3 = rounded(OplogEntry::exited());
+    let entry4 = rounded(OplogEntry::grow_memory(100));
+    let entry5 = rounded(OplogEntry::failed_update(2, None));
+    let entry6 = rounded(OplogEntry::snapshot(OplogPayload::Inline(vec![1, 2, 3])));
+    let entry7 = rounded(OplogEntry::suspend());
+
+    for entry in [
+        &entry1, &entry2, &entry3, &entry4, &entry5, &entry6, &entry7,
+    ] {
+        oplog.add(entry.clone()).await;
+    }
+    oplog.commit(CommitLevel::Always).await;
+
+    // The snapshot is found in the oplog even if it was written before the oplog was reopened
+    drop(oplog);
+    let last_oplog_index = oplog_service.get_last_index(&owned_worker_id).await;
+    let oplog = oplog_service
+        .open(&owned_worker_id, last_oplog_index, ComponentType::Durable)
+        .await;
+
+    MultiLayerOplog::try_archive_blocking(&oplog).await;
+
+    let entries = oplog_service
+        .read(&owned_worker_id, OplogIndex::INITIAL, 7)
+        .await
+        .into_values()
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        entries,
+        vec![
+            entry1,
+            OplogEntry::NoOp {
+                timestamp: entry2.timestamp()
+            },
+            entry3,
+            entry4,
+            entry5,
+            entry6,
+            entry7
+        ]
+    );
+}
+
 #[test]
 async fn multilayer_scan_for_component(_tracing: &Tracing) {
     let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
@@ -1468,6 +1605,7 @@ async fn multilayer_scan_for_component(_tracing: &Tracing) {
         nev![secondary_layer.clone(), tertiary_layer.clone()],
         1000, // no transfer will occur by reaching limit in this test
         10,
+        SnapshotRetention::KeepAll,
     ));
     let account_id = AccountId {
         value: "user1".to_string(),
@@ -1492,6 +1630,7 @@ async fn multilayer_scan_for_component(_tracing: &Tracing) {
             None,
             100,
             100,
+            WorkerQuotas::default(),
         );
 
         let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
@@ -1545,3 +1684,128 @@ async fn multilayer_scan_for_component(_tracing: &Tracing) {
 
     assert_eq!(result.len(), 100);
 }
+
+#[test]
+async fn fork_copies_prefix_from_all_layers(_tracing: &Tracing) {
+    let indexed_storage = Arc::new(InMemoryIndexedStorage::new());
+    let blob_storage = Arc::new(InMemoryBlobStorage::new());
+    let primary_oplog_service = Arc::new(
+        PrimaryOplogService::new(indexed_storage.clone(), blob_storage.clone(), 1, 100).await,
+    );
+    let secondary_layer: Arc<dyn OplogArchiveService + Send + Sync> = Arc::new(
+        CompressedOplogArchiveService::new(indexed_storage.clone(), 1),
+    );
+    let oplog_service = Arc::new(MultiLayerOplogService::new(
+        primary_oplog_service.clone(),
+        nev![secondary_layer.clone()],
+        10,
+        10,
+        SnapshotRetention::KeepAll,
+    ));
+    let account_id = AccountId {
+        value: "user1".to_string(),
+    };
+    let component_id = ComponentId(Uuid::new_v4());
+    let source_worker_id = WorkerId {
+        component_id: component_id.clone(),
+        worker_name: "source".to_string(),
+    };
+    let target_worker_id = WorkerId {
+        component_id,
+        worker_name: "target".to_string(),
+    };
+    let owned_source_worker_id = OwnedWorkerId::new(&account_id, &source_worker_id);
+    let owned_target_worker_id = OwnedWorkerId::new(&account_id, &target_worker_id);
+
+    let create_entry = rounded(OplogEntry::create(
+        source_worker_id.clone(),
+        1,
+        Vec::new(),
+        Vec::new(),
+        account_id.clone(),
+        None,
+        100,
+        100,
+        WorkerQuotas::default(),
+    ));
+    let oplog = oplog_service
+        .create(
+            &owned_source_worker_id,
+            create_entry.clone(),
+            ComponentType::Durable,
+        )
+        .await;
+
+    let large_payload = vec![0u8; 1024 * 1024];
+    let invoked_entry = rounded(
+        oplog
+            .add_exported_function_invoked(
+                "f1".to_string(),
+                &large_payload,
+                IdempotencyKey::fresh(),
+                None,
+            )
+            .await
+            .unwrap(),
+    );
+
+    let timestamp = Timestamp::now_utc();
+    let entries: Vec<OplogEntry> = (0..100)
+        .map(|i| {
+            rounded(OplogEntry::Error {
+                timestamp,
+                error: WorkerError::Unknown(i.to_string()),
+            })
+        })
+        .collect();
+    for entry in &entries {
+        oplog.add(entry.clone()).await;
+    }
+    oplog.commit(CommitLevel::Always).await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let secondary_length = secondary_layer
+        .open(&owned_source_worker_id)
+        .await
+        .length()
+        .await;
+    check!(secondary_length > 0);
+
+    // Create + invocation + the first 50 errors
+    let last_idx = OplogIndex::from_u64(52);
+    oplog_service
+        .fork(
+            &owned_source_worker_id,
+            &owned_target_worker_id,
+            last_idx,
+            ComponentType::Durable,
+        )
+        .await
+        .unwrap();
+
+    check!(oplog_service.get_last_index(&owned_target_worker_id).await == last_idx);
+
+    let forked = oplog_service
+        .read_prefix(&owned_target_worker_id, last_idx)
+        .await
+        .into_values()
+        .collect::<Vec<_>>();
+    check!(forked.len() == 52);
+
+    match &forked[0] {
+        OplogEntry::Create { worker_id, .. } => check!(worker_id == &target_worker_id),
+        entry => panic!("Unexpected initial entry: {entry:?}"),
+    }
+    check!(forked[2..] == entries[..50]);
+
+    let forked_oplog = oplog_service
+        .open(&owned_target_worker_id, last_idx, ComponentType::Durable)
+        .await;
+    check!(forked[1] != invoked_entry);
+    let payload = forked_oplog
+        .get_payload_of_entry::<Vec<u8>>(&forked[1])
+        .await
+        .unwrap()
+        .unwrap();
+    check!(payload == large_payload);
+}
@@ -275,6 +275,7 @@ pub enum RedisPromiseState {
 #[cfg(test)]
 pub struct PromiseServiceMock {
     completed: Arc<Mutex<HashSet<PromiseId>>>,
+    deleted: Arc<Mutex<HashSet<PromiseId>>>,
 }
 
 #[cfg(test)]
@@ -289,6 +290,7 @@ impl PromiseServiceMock {
     pub fn new() -> Self {
         Self {
             completed: Arc::new(Mutex::new(HashSet::new())),
+            deleted: Arc::new(Mutex::new(HashSet::new())),
         }
     }
 
@@ -313,11 +315,14 @@ impl PromiseService for PromiseServiceMock {
     }
 
     async fn complete(&self, promise_id: PromiseId, _data: Vec<u8>) -> Result<bool, GolemError> {
+        if self.deleted.lock().await.contains(&promise_id) {
+            return Err(GolemError::PromiseNotFound { promise_id });
+        }
         self.completed.lock().await.insert(promise_id);
         Ok(true)
     }
 
-    async fn delete(&self, _promise_id: PromiseId) {
-        unimplemented!()
+    async fn delete(&self, promise_id: PromiseId) {
+        self.deleted.lock().await.insert(promise_id);
     }
 }
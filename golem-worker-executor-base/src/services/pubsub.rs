@@ -0,0 +1,682 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Add;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use golem_wasm_rpc::Value;
+use tracing::{debug, warn};
+
+use crate::error::GolemError;
+use crate::services::rpc::Rpc;
+use crate::services::scheduler::SchedulerService;
+use crate::storage::indexed::{IndexedStorage, IndexedStorageLabelledApi, IndexedStorageNamespace};
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use golem_common::model::pubsub::{TopicMessage, TopicSubscription};
+use golem_common::model::{AccountId, OwnedWorkerId, ScheduledAction, Timestamp};
+
+/// Named topics within an account, which workers can subscribe to.
+///
+/// Published messages are stored in the indexed storage until they are delivered to every
+/// subscriber of the topic. Each subscription has a cursor pointing to the last message
+/// delivered to it, so messages which could not be delivered are retried with the next delivery
+/// attempt of the topic.
+#[async_trait]
+pub trait PubSubService {
+    /// Subscribes a worker to a topic. The subscription only receives the messages published
+    /// after it was created.
+    async fn subscribe(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<(), GolemError>;
+
+    /// Removes a subscription, returning `false` if it did not exist
+    async fn unsubscribe(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<bool, GolemError>;
+
+    async fn get_subscriptions(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscription>, GolemError>;
+
+    async fn get_worker_subscriptions(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<TopicSubscription>, GolemError>;
+
+    /// Removes all the subscriptions of a worker, used when the worker gets deleted
+    async fn remove_worker_subscriptions(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), GolemError>;
+
+    /// Stores a message published to a topic, returning its id. The message still has to be
+    /// delivered with `deliver`.
+    async fn publish(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+        payload: Vec<u8>,
+    ) -> Result<u64, GolemError>;
+
+    /// Gets the messages not yet delivered to a subscription, ordered by their ids
+    async fn undelivered_messages(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<Vec<(u64, TopicMessage)>, GolemError>;
+
+    /// Marks every message of a subscription up to the given id as delivered, and drops the
+    /// messages which got delivered to all the subscribers of the topic
+    async fn mark_delivered(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+        message_id: u64,
+    ) -> Result<(), GolemError>;
+}
+
+/// Delivers the undelivered messages of a topic to all its subscribers, by enqueuing an
+/// invocation on each subscriber worker.
+///
+/// Delivery is at-least-once: the cursor of a subscription is only moved after the invocation
+/// got enqueued, and redelivered messages have the same idempotency key as the original ones.
+/// If enqueuing fails for a subscriber, the rest of its messages are redelivered by the scheduler
+/// after `redelivery_interval`.
+pub async fn deliver(
+    pubsub_service: &Arc<dyn PubSubService + Send + Sync>,
+    rpc: &Arc<dyn Rpc + Send + Sync>,
+    scheduler_service: &Arc<dyn SchedulerService + Send + Sync>,
+    account_id: &AccountId,
+    topic: &str,
+    redelivery_interval: Duration,
+) -> Result<(), GolemError> {
+    for subscription in pubsub_service.get_subscriptions(account_id, topic).await? {
+        let owned_worker_id = OwnedWorkerId::new(account_id, &subscription.worker_id);
+        for (message_id, message) in pubsub_service
+            .undelivered_messages(account_id, &subscription)
+            .await?
+        {
+            let params = delivery_params(topic, message)
+                .into_iter()
+                .map(|value| value.into())
+                .collect();
+            match rpc
+                .invoke(
+                    &owned_worker_id,
+                    Some(subscription.delivery_idempotency_key(message_id)),
+                    subscription.function_name.clone(),
+                    params,
+                    &subscription.worker_id,
+                    &[],
+                    &[],
+                    None,
+                )
+                .await
+            {
+                Ok(()) => {
+                    debug!(
+                        "Delivered message {message_id} of topic {topic} to {}",
+                        subscription.worker_id
+                    );
+                    pubsub_service
+                        .mark_delivered(account_id, &subscription, message_id)
+                        .await?;
+                }
+                Err(err) => {
+                    warn!(
+                        "Failed to deliver message {message_id} of topic {topic} to {}: {err}",
+                        subscription.worker_id
+                    );
+                    scheduler_service
+                        .schedule(
+                            Utc::now().add(redelivery_interval),
+                            ScheduledAction::DeliverTopicMessages {
+                                owned_worker_id: owned_worker_id.clone(),
+                                topic: topic.to_string(),
+                                function_name: subscription.function_name.clone(),
+                                retry_after: redelivery_interval,
+                            },
+                        )
+                        .await;
+                    break;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parameters of the invocation delivering a message to a subscriber: the topic's name and the
+/// message's payload
+pub fn delivery_params(topic: &str, message: TopicMessage) -> Vec<Value> {
+    vec![
+        Value::String(topic.to_string()),
+        Value::List(message.payload.into_iter().map(Value::U8).collect()),
+    ]
+}
+
+#[derive(Clone)]
+pub struct PubSubServiceDefault {
+    indexed_storage: Arc<dyn IndexedStorage + Send + Sync>,
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+}
+
+impl PubSubServiceDefault {
+    /// Number of attempts for choosing the id of a published message, in case other publishers
+    /// are appending to the same topic concurrently
+    const MAX_PUBLISH_ATTEMPTS: usize = 5;
+
+    pub fn new(
+        indexed_storage: Arc<dyn IndexedStorage + Send + Sync>,
+        key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+    ) -> Self {
+        Self {
+            indexed_storage,
+            key_value_storage,
+        }
+    }
+
+    fn topic_key(account_id: &AccountId, topic: &str) -> String {
+        format!("{}:{}", account_id, topic)
+    }
+
+    fn subscriptions_key(account_id: &AccountId, topic: &str) -> String {
+        format!("account:topic-subscriptions:{}:{}", account_id, topic)
+    }
+
+    fn worker_subscriptions_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!(
+            "worker:topic-subscriptions:{}",
+            owned_worker_id.worker_id.to_redis_key()
+        )
+    }
+
+    fn cursor_key(account_id: &AccountId, subscription: &TopicSubscription) -> String {
+        format!(
+            "account:topic-cursor:{}:{}:{}:{}",
+            account_id,
+            subscription.topic,
+            subscription.worker_id.to_redis_key(),
+            subscription.function_name
+        )
+    }
+
+    async fn last_message_id(&self, account_id: &AccountId, topic: &str) -> Result<u64, String> {
+        Ok(self
+            .indexed_storage
+            .with_entity("pubsub", "last_message_id", "topic_message")
+            .last_id(
+                IndexedStorageNamespace::Topic,
+                &Self::topic_key(account_id, topic),
+            )
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn cursor(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<Option<u64>, GolemError> {
+        self.key_value_storage
+            .with_entity("pubsub", "cursor", "topic_cursor")
+            .get(
+                KeyValueStorageNamespace::Worker,
+                &Self::cursor_key(account_id, subscription),
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get topic cursor: {err}")))
+    }
+}
+
+#[async_trait]
+impl PubSubService for PubSubServiceDefault {
+    async fn subscribe(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<(), GolemError> {
+        let last_message_id = self
+            .last_message_id(account_id, &subscription.topic)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to subscribe to topic: {err}")))?;
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "topic_cursor")
+            .set_if_not_exists(
+                KeyValueStorageNamespace::Worker,
+                &Self::cursor_key(account_id, subscription),
+                &last_message_id,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to subscribe to topic: {err}")))?;
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "topic_subscription")
+            .add_to_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::subscriptions_key(account_id, &subscription.topic),
+                subscription,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to subscribe to topic: {err}")))?;
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "topic_subscription")
+            .add_to_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::worker_subscriptions_key(&OwnedWorkerId::new(
+                    account_id,
+                    &subscription.worker_id,
+                )),
+                subscription,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to subscribe to topic: {err}")))
+    }
+
+    async fn unsubscribe(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<bool, GolemError> {
+        if self.cursor(account_id, subscription).await?.is_none() {
+            return Ok(false);
+        }
+
+        self.key_value_storage
+            .with_entity("pubsub", "unsubscribe", "topic_subscription")
+            .remove_from_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::subscriptions_key(account_id, &subscription.topic),
+                subscription,
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to unsubscribe from topic: {err}"))
+            })?;
+        self.key_value_storage
+            .with_entity("pubsub", "unsubscribe", "topic_subscription")
+            .remove_from_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::worker_subscriptions_key(&OwnedWorkerId::new(
+                    account_id,
+                    &subscription.worker_id,
+                )),
+                subscription,
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to unsubscribe from topic: {err}"))
+            })?;
+        self.key_value_storage
+            .with("pubsub", "unsubscribe")
+            .del(
+                KeyValueStorageNamespace::Worker,
+                &Self::cursor_key(account_id, subscription),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to unsubscribe from topic: {err}"))
+            })?;
+        Ok(true)
+    }
+
+    async fn get_subscriptions(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscription>, GolemError> {
+        self.key_value_storage
+            .with_entity("pubsub", "get_subscriptions", "topic_subscription")
+            .members_of_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::subscriptions_key(account_id, topic),
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get topic subscriptions: {err}")))
+    }
+
+    async fn get_worker_subscriptions(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<TopicSubscription>, GolemError> {
+        self.key_value_storage
+            .with_entity("pubsub", "get_worker_subscriptions", "topic_subscription")
+            .members_of_set(
+                KeyValueStorageNamespace::Worker,
+                &Self::worker_subscriptions_key(owned_worker_id),
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to get topic subscriptions: {err}")))
+    }
+
+    async fn remove_worker_subscriptions(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), GolemError> {
+        for subscription in self.get_worker_subscriptions(owned_worker_id).await? {
+            self.unsubscribe(&owned_worker_id.account_id, &subscription)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn publish(
+        &self,
+        account_id: &AccountId,
+        topic: &str,
+        payload: Vec<u8>,
+    ) -> Result<u64, GolemError> {
+        let message = TopicMessage {
+            payload,
+            published_at: Timestamp::now_utc(),
+        };
+        let key = Self::topic_key(account_id, topic);
+
+        let mut attempt = 0;
+        loop {
+            let result = match self.last_message_id(account_id, topic).await {
+                Ok(last_message_id) => {
+                    let message_id = last_message_id + 1;
+                    self.indexed_storage
+                        .with_entity("pubsub", "publish", "topic_message")
+                        .append(IndexedStorageNamespace::Topic, &key, message_id, &message)
+                        .await
+                        .map(|_| message_id)
+                }
+                Err(err) => Err(err),
+            };
+
+            attempt += 1;
+            match result {
+                Ok(message_id) => break Ok(message_id),
+                Err(err) if attempt >= Self::MAX_PUBLISH_ATTEMPTS => {
+                    break Err(GolemError::runtime(format!(
+                        "Failed to publish message to topic {topic}: {err}"
+                    )))
+                }
+                Err(err) => {
+                    debug!("Retrying publishing message to topic {topic}: {err}");
+                }
+            }
+        }
+    }
+
+    async fn undelivered_messages(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+    ) -> Result<Vec<(u64, TopicMessage)>, GolemError> {
+        let Some(cursor) = self.cursor(account_id, subscription).await? else {
+            return Ok(vec![]);
+        };
+        let last_message_id = self
+            .last_message_id(account_id, &subscription.topic)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to read topic messages: {err}")))?;
+
+        if cursor < last_message_id {
+            self.indexed_storage
+                .with_entity("pubsub", "undelivered_messages", "topic_message")
+                .read(
+                    IndexedStorageNamespace::Topic,
+                    &Self::topic_key(account_id, &subscription.topic),
+                    cursor + 1,
+                    last_message_id,
+                )
+                .await
+                .map_err(|err| GolemError::runtime(format!("Failed to read topic messages: {err}")))
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn mark_delivered(
+        &self,
+        account_id: &AccountId,
+        subscription: &TopicSubscription,
+        message_id: u64,
+    ) -> Result<(), GolemError> {
+        self.key_value_storage
+            .with_entity("pubsub", "mark_delivered", "topic_cursor")
+            .set(
+                KeyValueStorageNamespace::Worker,
+                &Self::cursor_key(account_id, subscription),
+                &message_id,
+            )
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to update topic cursor: {err}")))?;
+
+        // The last message of a topic is always kept, as the ids of the new messages are based on it
+        let last_message_id = self
+            .last_message_id(account_id, &subscription.topic)
+            .await
+            .map_err(|err| GolemError::runtime(format!("Failed to read topic messages: {err}")))?;
+        let mut delivered_to_all = message_id.min(last_message_id.saturating_sub(1));
+        for subscription in self
+            .get_subscriptions(account_id, &subscription.topic)
+            .await?
+        {
+            if let Some(cursor) = self.cursor(account_id, &subscription).await? {
+                delivered_to_all = delivered_to_all.min(cursor);
+            }
+        }
+        if delivered_to_all > 0 {
+            self.indexed_storage
+                .with("pubsub", "mark_delivered")
+                .drop_prefix(
+                    IndexedStorageNamespace::Topic,
+                    &Self::topic_key(account_id, &subscription.topic),
+                    delivered_to_all,
+                )
+                .await
+                .map_err(|err| {
+                    GolemError::runtime(format!("Failed to drop delivered topic messages: {err}"))
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::services::pubsub::{PubSubService, PubSubServiceDefault};
+    use crate::storage::indexed::memory::InMemoryIndexedStorage;
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use golem_common::model::pubsub::TopicSubscription;
+    use golem_common::model::{AccountId, ComponentId, OwnedWorkerId, WorkerId};
+
+    fn account_id() -> AccountId {
+        AccountId {
+            value: "test-account".to_string(),
+        }
+    }
+
+    fn subscription(worker_name: &str) -> TopicSubscription {
+        TopicSubscription {
+            topic: "events".to_string(),
+            worker_id: WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: worker_name.to_string(),
+            },
+            function_name: "golem:it/api.{on-event}".to_string(),
+        }
+    }
+
+    fn svc() -> PubSubServiceDefault {
+        PubSubServiceDefault::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            Arc::new(InMemoryKeyValueStorage::new()),
+        )
+    }
+
+    #[test]
+    async fn subscribe_and_unsubscribe() {
+        let svc = svc();
+        let account_id = account_id();
+        let subscription = subscription("worker-1");
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &subscription.worker_id);
+
+        svc.subscribe(&account_id, &subscription).await.unwrap();
+
+        assert_eq!(
+            svc.get_subscriptions(&account_id, "events").await.unwrap(),
+            vec![subscription.clone()]
+        );
+        assert_eq!(
+            svc.get_worker_subscriptions(&owned_worker_id)
+                .await
+                .unwrap(),
+            vec![subscription.clone()]
+        );
+
+        assert!(svc.unsubscribe(&account_id, &subscription).await.unwrap());
+        assert!(!svc.unsubscribe(&account_id, &subscription).await.unwrap());
+
+        assert!(svc
+            .get_subscriptions(&account_id, "events")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(svc
+            .get_worker_subscriptions(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    async fn removing_worker_subscriptions() {
+        let svc = svc();
+        let account_id = account_id();
+        let other_worker = subscription("worker-2");
+        let subscription = subscription("worker-1");
+        let other_subscription = TopicSubscription {
+            topic: "other-events".to_string(),
+            ..subscription.clone()
+        };
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &subscription.worker_id);
+
+        svc.subscribe(&account_id, &subscription).await.unwrap();
+        svc.subscribe(&account_id, &other_subscription)
+            .await
+            .unwrap();
+        svc.subscribe(&account_id, &other_worker).await.unwrap();
+
+        svc.remove_worker_subscriptions(&owned_worker_id)
+            .await
+            .unwrap();
+
+        assert!(svc
+            .get_worker_subscriptions(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            svc.get_subscriptions(&account_id, "events").await.unwrap(),
+            vec![other_worker]
+        );
+        assert!(svc
+            .get_subscriptions(&account_id, "other-events")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    async fn subscriptions_only_get_messages_published_after_subscribing() {
+        let svc = svc();
+        let account_id = account_id();
+        let early = subscription("worker-1");
+        let late = subscription("worker-2");
+
+        svc.subscribe(&account_id, &early).await.unwrap();
+        let first = svc.publish(&account_id, "events", vec![1]).await.unwrap();
+        svc.subscribe(&account_id, &late).await.unwrap();
+        let second = svc.publish(&account_id, "events", vec![2]).await.unwrap();
+
+        let early_messages = svc.undelivered_messages(&account_id, &early).await.unwrap();
+        assert_eq!(
+            early_messages
+                .iter()
+                .map(|(id, message)| (*id, message.payload.clone()))
+                .collect::<Vec<_>>(),
+            vec![(first, vec![1]), (second, vec![2])]
+        );
+
+        let late_messages = svc.undelivered_messages(&account_id, &late).await.unwrap();
+        assert_eq!(
+            late_messages
+                .iter()
+                .map(|(id, message)| (*id, message.payload.clone()))
+                .collect::<Vec<_>>(),
+            vec![(second, vec![2])]
+        );
+    }
+
+    #[test]
+    async fn delivered_messages_are_not_redelivered() {
+        let svc = svc();
+        let account_id = account_id();
+        let first_subscriber = subscription("worker-1");
+        let second_subscriber = subscription("worker-2");
+
+        svc.subscribe(&account_id, &first_subscriber).await.unwrap();
+        svc.subscribe(&account_id, &second_subscriber)
+            .await
+            .unwrap();
+        let first = svc.publish(&account_id, "events", vec![1]).await.unwrap();
+        let second = svc.publish(&account_id, "events", vec![2]).await.unwrap();
+
+        svc.mark_delivered(&account_id, &first_subscriber, second)
+            .await
+            .unwrap();
+        svc.mark_delivered(&account_id, &second_subscriber, first)
+            .await
+            .unwrap();
+
+        assert!(svc
+            .undelivered_messages(&account_id, &first_subscriber)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            svc.undelivered_messages(&account_id, &second_subscriber)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            vec![second]
+        );
+
+        let third = svc.publish(&account_id, "events", vec![3]).await.unwrap();
+        assert!(third > second);
+    }
+}
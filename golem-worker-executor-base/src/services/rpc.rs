@@ -23,6 +23,7 @@ use golem_wasm_rpc::WitValue;
 use tokio::runtime::Handle;
 use tracing::debug;
 
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{IdempotencyKey, OwnedWorkerId, TargetWorkerId, WorkerId};
 
 use crate::error::GolemError;
@@ -30,13 +31,13 @@ use crate::services::events::Events;
 use crate::services::shard::ShardService;
 use crate::services::worker_proxy::{WorkerProxy, WorkerProxyError};
 use crate::services::{
-    active_workers, blob_store, component, golem_config, key_value, oplog, promise, scheduler,
-    shard, shard_manager, worker, worker_activator, worker_enumeration, HasActiveWorkers,
-    HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps,
-    HasKeyValueService, HasOplogService, HasPromiseService, HasRpc,
-    HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService,
-    HasShardService, HasWasmtimeEngine, HasWorkerActivator, HasWorkerEnumerationService,
-    HasWorkerProxy, HasWorkerService,
+    active_workers, blob_store, component, dead_letter, golem_config, key_value, lock, oplog,
+    promise, pubsub, scheduler, shard, shard_manager, worker, worker_activator, worker_enumeration,
+    HasActiveWorkers, HasBlobStoreService, HasComponentService, HasConfig, HasDeadLetterService,
+    HasEvents, HasExtraDeps, HasKeyValueService, HasLockService, HasOplogService,
+    HasPromiseService, HasPubSubService, HasRpc, HasRunningWorkerEnumerationService,
+    HasSchedulerService, HasShardManagerService, HasShardService, HasWasmtimeEngine,
+    HasWorkerActivator, HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -54,6 +55,7 @@ pub trait Rpc {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        self_trace_context: Option<&TraceContext>,
     ) -> Result<TypeAnnotatedValue, RpcError>;
 
     async fn invoke(
@@ -65,6 +67,7 @@ pub trait Rpc {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        self_trace_context: Option<&TraceContext>,
     ) -> Result<(), RpcError>;
 
     async fn generate_unique_local_worker_id(
@@ -201,6 +204,7 @@ impl Rpc for RemoteInvocationRpc {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        self_trace_context: Option<&TraceContext>,
     ) -> Result<TypeAnnotatedValue, RpcError> {
         Ok(self
             .worker_proxy
@@ -212,6 +216,7 @@ impl Rpc for RemoteInvocationRpc {
                 self_worker_id.clone(),
                 self_args.to_vec(),
                 HashMap::from_iter(self_env.to_vec()),
+                self_trace_context.cloned(),
             )
             .await?)
     }
@@ -225,6 +230,7 @@ impl Rpc for RemoteInvocationRpc {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        self_trace_context: Option<&TraceContext>,
     ) -> Result<(), RpcError> {
         Ok(self
             .worker_proxy
@@ -236,6 +242,7 @@ impl Rpc for RemoteInvocationRpc {
                 self_worker_id.clone(),
                 self_args.to_vec(),
                 HashMap::from_iter(self_env.to_vec()),
+                self_trace_context.cloned(),
             )
             .await?)
     }
@@ -271,6 +278,9 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
     oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
     scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+    dead_letter_service: Arc<dyn dead_letter::DeadLetterService + Send + Sync>,
+    pubsub_service: Arc<dyn pubsub::PubSubService + Send + Sync>,
+    lock_service: Arc<dyn lock::LockService + Send + Sync>,
     worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
     events: Arc<Events>,
     extra_deps: Ctx::ExtraDeps,
@@ -296,6 +306,9 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             blob_store_service: self.blob_store_service.clone(),
             oplog_service: self.oplog_service.clone(),
             scheduler_service: self.scheduler_service.clone(),
+            dead_letter_service: self.dead_letter_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            lock_service: self.lock_service.clone(),
             worker_activator: self.worker_activator.clone(),
             events: self.events.clone(),
             extra_deps: self.extra_deps.clone(),
@@ -387,6 +400,24 @@ impl<Ctx: WorkerCtx> HasSchedulerService for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasDeadLetterService for DirectWorkerInvocationRpc<Ctx> {
+    fn dead_letter_service(&self) -> Arc<dyn dead_letter::DeadLetterService + Send + Sync> {
+        self.dead_letter_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasPubSubService for DirectWorkerInvocationRpc<Ctx> {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService + Send + Sync> {
+        self.pubsub_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasLockService for DirectWorkerInvocationRpc<Ctx> {
+    fn lock_service(&self) -> Arc<dyn lock::LockService + Send + Sync> {
+        self.lock_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasOplogService for DirectWorkerInvocationRpc<Ctx> {
     fn oplog_service(&self) -> Arc<dyn oplog::OplogService + Send + Sync> {
         self.oplog_service.clone()
@@ -452,6 +483,9 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
         blob_store_service: Arc<dyn blob_store::BlobStoreService + Send + Sync>,
         oplog_service: Arc<dyn oplog::OplogService + Send + Sync>,
         scheduler_service: Arc<dyn scheduler::SchedulerService + Send + Sync>,
+        dead_letter_service: Arc<dyn dead_letter::DeadLetterService + Send + Sync>,
+        pubsub_service: Arc<dyn pubsub::PubSubService + Send + Sync>,
+        lock_service: Arc<dyn lock::LockService + Send + Sync>,
         worker_activator: Arc<dyn worker_activator::WorkerActivator + Send + Sync>,
         events: Arc<Events>,
         extra_deps: Ctx::ExtraDeps,
@@ -474,6 +508,9 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             blob_store_service,
             oplog_service,
             scheduler_service,
+            dead_letter_service,
+            pubsub_service,
+            lock_service,
             worker_activator,
             events,
             extra_deps,
@@ -497,6 +534,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        self_trace_context: Option<&TraceContext>,
     ) -> Result<TypeAnnotatedValue, RpcError> {
         let idempotency_key = idempotency_key.unwrap_or(IdempotencyKey::fresh());
 
@@ -519,11 +557,17 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                 Some(self_env.to_vec()),
                 None,
                 Some(self_worker_id.clone()),
+                None,
             )
             .await?;
 
             let result_values = worker
-                .invoke_and_await(idempotency_key, function_name, input_values)
+                .invoke_and_await(
+                    idempotency_key,
+                    function_name,
+                    input_values,
+                    self_trace_context.map(|trace_context| trace_context.child()),
+                )
                 .await?;
 
             Ok(result_values)
@@ -537,6 +581,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                     self_worker_id,
                     self_args,
                     self_env,
+                    self_trace_context,
                 )
                 .await
         }
@@ -551,6 +596,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
         self_worker_id: &WorkerId,
         self_args: &[String],
         self_env: &[(String, String)],
+        self_trace_context: Option<&TraceContext>,
     ) -> Result<(), RpcError> {
         let idempotency_key = idempotency_key.unwrap_or(IdempotencyKey::fresh()); // TODO
 
@@ -573,11 +619,17 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                 Some(self_env.to_vec()),
                 None,
                 Some(self_worker_id.clone()),
+                None,
             )
             .await?;
 
             worker
-                .invoke(idempotency_key, function_name, input_values)
+                .invoke(
+                    idempotency_key,
+                    function_name,
+                    input_values,
+                    self_trace_context.map(|trace_context| trace_context.child()),
+                )
                 .await?;
             Ok(())
         } else {
@@ -590,6 +642,7 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
                     self_worker_id,
                     self_args,
                     self_env,
+                    self_trace_context,
                 )
                 .await
         }
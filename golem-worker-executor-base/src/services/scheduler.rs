@@ -23,23 +23,53 @@ use tokio::task::JoinHandle;
 use tokio::time::Instant;
 use tracing::{error, info, span, warn, Instrument, Level};
 
+use crate::error::GolemError;
 use crate::metrics::oplog::record_scheduled_archive;
 use crate::metrics::promises::record_scheduled_promise_completed;
+use crate::metrics::workers::record_scheduled_invocation;
 use crate::services::oplog::{MultiLayerOplog, OplogService};
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::{self, PubSubService};
 use crate::services::shard::ShardService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_activator::WorkerActivator;
 use crate::storage::keyvalue::{
     KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
 };
-use golem_common::model::{ComponentType, ScheduleId, ScheduledAction};
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::{
+    ComponentType, InvocationSchedule, OwnedWorkerId, ScheduleId, ScheduledAction,
+    ScheduledInvocation, ScheduledInvocationId, Timestamp,
+};
+use golem_wasm_rpc::Value;
 
 #[async_trait]
 pub trait SchedulerService {
     async fn schedule(&self, time: DateTime<Utc>, action: ScheduledAction) -> ScheduleId;
 
     async fn cancel(&self, id: ScheduleId);
+
+    /// Registers an invocation of an exported function to be performed once or repeatedly,
+    /// as described by `schedule`, and schedules its first occurrence.
+    async fn schedule_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        full_function_name: String,
+        function_input: Vec<Value>,
+        schedule: InvocationSchedule,
+    ) -> Result<ScheduledInvocation, GolemError>;
+
+    /// Removes a scheduled invocation, returning `false` if it did not exist.
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &ScheduledInvocationId,
+    ) -> Result<bool, GolemError>;
+
+    async fn get_scheduled_invocations(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<ScheduledInvocation>, GolemError>;
 }
 
 #[derive(Clone)]
@@ -51,6 +81,7 @@ pub struct SchedulerServiceDefault {
     worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
     oplog_service: Arc<dyn OplogService + Send + Sync>,
     worker_service: Arc<dyn WorkerService + Send + Sync>,
+    pubsub_service: Arc<dyn PubSubService + Send + Sync>,
 }
 
 impl SchedulerServiceDefault {
@@ -61,6 +92,7 @@ impl SchedulerServiceDefault {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         worker_service: Arc<dyn WorkerService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
         process_interval: Duration,
     ) -> Arc<Self> {
         let svc = Self {
@@ -71,6 +103,7 @@ impl SchedulerServiceDefault {
             oplog_service,
             worker_service,
             worker_activator,
+            pubsub_service,
         };
         let svc = Arc::new(svc);
         let background_handle = {
@@ -141,83 +174,333 @@ impl SchedulerServiceDefault {
         let mut owned_worker_ids = HashSet::new();
         for (key, action) in matching {
             owned_worker_ids.insert(action.owned_worker_id().clone());
+
+            // A failing action must not prevent processing the remaining ones
+            let description = action.to_string();
+            if let Err(err) = self.process_action(key, action, now).await {
+                error!(err, "Failed to process scheduled action {description}");
+            }
+        }
+
+        for owned_worker_id in owned_worker_ids {
+            let span = span!(
+                Level::INFO,
+                "scheduler",
+                worker_id = owned_worker_id.worker_id.to_string()
+            );
+            self.worker_activator
+                .activate_worker(&owned_worker_id)
+                .instrument(span)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    async fn process_action(
+        &self,
+        key: &str,
+        action: ScheduledAction,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        // Scheduled invocations are only removed once they got enqueued, relying on the
+        // idempotency key to not perform them twice if we fail in between
+        if !matches!(action, ScheduledAction::Invoke { .. }) {
             self.key_value_storage
                 .with_entity("scheduler", "process", "scheduled_action")
                 .remove_from_sorted_set(KeyValueStorageNamespace::Schedule, key, &action)
                 .await?;
+        }
 
-            match action {
-                ScheduledAction::CompletePromise { promise_id, .. } => {
-                    self.promise_service
-                        .complete(promise_id, vec![])
-                        .await
-                        .map_err(|golem_err| format!("{golem_err}"))?;
+        match action {
+            ScheduledAction::CompletePromise { promise_id, .. } => {
+                self.promise_service
+                    .complete(promise_id, vec![])
+                    .await
+                    .map_err(|golem_err| format!("{golem_err}"))?;
 
-                    record_scheduled_promise_completed();
-                }
-                ScheduledAction::ArchiveOplog {
-                    owned_worker_id,
-                    last_oplog_index,
-                    next_after,
-                } => {
-                    if self.oplog_service.exists(&owned_worker_id).await {
-                        let current_last_index =
-                            self.oplog_service.get_last_index(&owned_worker_id).await;
-                        if current_last_index == last_oplog_index {
-                            // We never schedule an archive operation for ephemeral workers, because they immediately write their oplog to the arcchive layer
-                            // So we can assume the component type is Durable here without calculating it from the latest component and worker metadata.
-                            let oplog = self
-                                .oplog_service
-                                .open(&owned_worker_id, last_oplog_index, ComponentType::Durable)
+                record_scheduled_promise_completed();
+            }
+            ScheduledAction::ArchiveOplog {
+                owned_worker_id,
+                last_oplog_index,
+                next_after,
+            } => {
+                if self.oplog_service.exists(&owned_worker_id).await {
+                    let current_last_index =
+                        self.oplog_service.get_last_index(&owned_worker_id).await;
+                    if current_last_index == last_oplog_index {
+                        // We never schedule an archive operation for ephemeral workers, because they immediately write their oplog to the arcchive layer
+                        // So we can assume the component type is Durable here without calculating it from the latest component and worker metadata.
+                        let oplog = self
+                            .oplog_service
+                            .open(&owned_worker_id, last_oplog_index, ComponentType::Durable)
+                            .await;
+
+                        let start = Instant::now();
+                        if let Some(more) = MultiLayerOplog::try_archive(&oplog).await {
+                            record_scheduled_archive(start.elapsed(), more);
+                            if more {
+                                self.schedule(
+                                    now.add(next_after),
+                                    ScheduledAction::ArchiveOplog {
+                                        owned_worker_id,
+                                        last_oplog_index,
+                                        next_after,
+                                    },
+                                )
                                 .await;
-
-                            let start = Instant::now();
-                            if let Some(more) = MultiLayerOplog::try_archive(&oplog).await {
-                                record_scheduled_archive(start.elapsed(), more);
-                                if more {
-                                    self.schedule(
-                                        now.add(next_after),
-                                        ScheduledAction::ArchiveOplog {
-                                            owned_worker_id,
-                                            last_oplog_index,
-                                            next_after,
-                                        },
-                                    )
+                            } else {
+                                info!(
+                                    worker_id = owned_worker_id.to_string(),
+                                    "Deleting cached status of fully archived worker"
+                                );
+                                // The oplog is fully archived, so we can also delete the cached worker status
+                                self.worker_service
+                                    .remove_cached_status(&owned_worker_id)
                                     .await;
-                                } else {
-                                    info!(
-                                        worker_id = owned_worker_id.to_string(),
-                                        "Deleting cached status of fully archived worker"
-                                    );
-                                    // The oplog is fully archived, so we can also delete the cached worker status
-                                    self.worker_service
-                                        .remove_cached_status(&owned_worker_id)
-                                        .await;
-                                }
                             }
                         }
-
-                        // TODO: metrics
                     }
+
+                    // TODO: metrics
                 }
             }
+            ScheduledAction::Invoke {
+                ref owned_worker_id,
+                ref id,
+                scheduled_at,
+            } => {
+                self.fire_invocation(owned_worker_id, id, scheduled_at)
+                    .await?;
+
+                self.key_value_storage
+                    .with_entity("scheduler", "process", "scheduled_action")
+                    .remove_from_sorted_set(KeyValueStorageNamespace::Schedule, key, &action)
+                    .await?;
+            }
+            ScheduledAction::DeliverTopicMessages {
+                owned_worker_id,
+                topic,
+                function_name,
+                retry_after,
+            } => {
+                let subscription = TopicSubscription {
+                    topic,
+                    worker_id: owned_worker_id.worker_id.clone(),
+                    function_name,
+                };
+                self.redeliver_topic_messages(&owned_worker_id, subscription, retry_after, now)
+                    .await?;
+            }
         }
 
-        for owned_worker_id in owned_worker_ids {
-            let span = span!(
-                Level::INFO,
-                "scheduler",
-                worker_id = owned_worker_id.worker_id.to_string()
-            );
-            self.worker_activator
-                .activate_worker(&owned_worker_id)
-                .instrument(span)
+        Ok(())
+    }
+
+    async fn redeliver_topic_messages(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        subscription: TopicSubscription,
+        retry_after: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let account_id = &owned_worker_id.account_id;
+        let messages = self
+            .pubsub_service
+            .undelivered_messages(account_id, &subscription)
+            .await
+            .map_err(|golem_err| format!("{golem_err}"))?;
+
+        for (message_id, message) in messages {
+            let result = self
+                .worker_activator
+                .enqueue_invocation(
+                    owned_worker_id,
+                    subscription.delivery_idempotency_key(message_id),
+                    subscription.function_name.clone(),
+                    pubsub::delivery_params(&subscription.topic, message),
+                )
                 .await;
+
+            match result {
+                Ok(()) => {
+                    self.pubsub_service
+                        .mark_delivered(account_id, &subscription, message_id)
+                        .await
+                        .map_err(|golem_err| format!("{golem_err}"))?;
+                }
+                Err(GolemError::WorkerNotFound { .. }) => {
+                    warn!(
+                        worker_id = owned_worker_id.to_string(),
+                        topic = subscription.topic,
+                        "Dropping topic subscription of a worker that no longer exists"
+                    );
+                    self.pubsub_service
+                        .unsubscribe(account_id, &subscription)
+                        .await
+                        .map_err(|golem_err| format!("{golem_err}"))?;
+                    break;
+                }
+                Err(err) => {
+                    warn!(
+                        worker_id = owned_worker_id.to_string(),
+                        topic = subscription.topic,
+                        "Failed to redeliver message {message_id}: {err}"
+                    );
+                    self.schedule(
+                        now.add(retry_after),
+                        ScheduledAction::DeliverTopicMessages {
+                            owned_worker_id: owned_worker_id.clone(),
+                            topic: subscription.topic,
+                            function_name: subscription.function_name,
+                            retry_after,
+                        },
+                    )
+                    .await;
+                    break;
+                }
+            }
         }
 
         Ok(())
     }
 
+    async fn fire_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &ScheduledInvocationId,
+        scheduled_at: Timestamp,
+    ) -> Result<(), String> {
+        let invocation: Option<ScheduledInvocation> = self
+            .key_value_storage
+            .with_entity("scheduler", "fire_invocation", "scheduled_invocation")
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(owned_worker_id, id),
+            )
+            .await?;
+
+        match invocation {
+            Some(mut invocation) if invocation.next_invocation_at == scheduled_at => {
+                let idempotency_key = invocation.idempotency_key(&scheduled_at);
+                let result = self
+                    .worker_activator
+                    .enqueue_invocation(
+                        owned_worker_id,
+                        idempotency_key,
+                        invocation.full_function_name.clone(),
+                        invocation.function_input.clone(),
+                    )
+                    .await;
+
+                let keep = match result {
+                    Ok(()) => {
+                        record_scheduled_invocation();
+                        true
+                    }
+                    Err(GolemError::WorkerNotFound { .. }) => {
+                        warn!(
+                            worker_id = owned_worker_id.to_string(),
+                            scheduled_invocation_id = id.to_string(),
+                            "Dropping scheduled invocation of a worker that no longer exists"
+                        );
+                        false
+                    }
+                    Err(err) => {
+                        error!(
+                            worker_id = owned_worker_id.to_string(),
+                            scheduled_invocation_id = id.to_string(),
+                            "Failed to enqueue scheduled invocation: {err}"
+                        );
+                        true
+                    }
+                };
+
+                let next = if keep {
+                    invocation
+                        .schedule
+                        .next_after(scheduled_at, Timestamp::now_utc())
+                        .unwrap_or_else(|err| {
+                            error!(
+                                worker_id = owned_worker_id.to_string(),
+                                scheduled_invocation_id = id.to_string(),
+                                "Dropping scheduled invocation: {err}"
+                            );
+                            None
+                        })
+                } else {
+                    None
+                };
+
+                match next {
+                    Some(next) => {
+                        invocation.next_invocation_at = next;
+                        self.store_invocation(&invocation).await?;
+                        self.schedule(
+                            InvocationSchedule::to_date_time(next)?,
+                            ScheduledAction::Invoke {
+                                owned_worker_id: owned_worker_id.clone(),
+                                id: id.clone(),
+                                scheduled_at: next,
+                            },
+                        )
+                        .await;
+                    }
+                    None => {
+                        self.remove_invocation(owned_worker_id, id).await?;
+                    }
+                }
+            }
+            _ => {
+                // Cancelled or already rescheduled
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn store_invocation(&self, invocation: &ScheduledInvocation) -> Result<(), String> {
+        self.key_value_storage
+            .with_entity("scheduler", "store_invocation", "scheduled_invocation")
+            .set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(&invocation.owned_worker_id, &invocation.id),
+                invocation,
+            )
+            .await?;
+        self.key_value_storage
+            .with_entity("scheduler", "store_invocation", "scheduled_invocation_id")
+            .add_to_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocations_key(&invocation.owned_worker_id),
+                &invocation.id,
+            )
+            .await
+    }
+
+    async fn remove_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &ScheduledInvocationId,
+    ) -> Result<(), String> {
+        self.key_value_storage
+            .with("scheduler", "remove_invocation")
+            .del(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(owned_worker_id, id),
+            )
+            .await?;
+        self.key_value_storage
+            .with_entity("scheduler", "remove_invocation", "scheduled_invocation_id")
+            .remove_from_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocations_key(owned_worker_id),
+                id,
+            )
+            .await
+    }
+
     const HOUR_IN_MILLIS: i64 = 1000 * 60 * 60;
 
     fn split_time<Tz: TimeZone>(time: DateTime<Tz>) -> (i64, f64) {
@@ -234,6 +517,24 @@ impl SchedulerServiceDefault {
     fn schedule_key_from_timestamp(timestamp: i64) -> String {
         format!("worker:schedule:{}", timestamp)
     }
+
+    fn scheduled_invocation_key(
+        owned_worker_id: &OwnedWorkerId,
+        id: &ScheduledInvocationId,
+    ) -> String {
+        format!(
+            "worker:scheduled-invocation:{}:{}",
+            owned_worker_id.worker_id.to_redis_key(),
+            id
+        )
+    }
+
+    fn scheduled_invocations_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!(
+            "worker:scheduled-invocations:{}",
+            owned_worker_id.worker_id.to_redis_key()
+        )
+    }
 }
 
 impl Drop for SchedulerServiceDefault {
@@ -286,6 +587,132 @@ impl SchedulerService for SchedulerServiceDefault {
                 )
             });
     }
+
+    async fn schedule_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        full_function_name: String,
+        function_input: Vec<Value>,
+        schedule: InvocationSchedule,
+    ) -> Result<ScheduledInvocation, GolemError> {
+        schedule.validate().map_err(GolemError::invalid_request)?;
+        let first = schedule
+            .first(Timestamp::now_utc())
+            .map_err(GolemError::invalid_request)?
+            .ok_or(GolemError::invalid_request(
+                "The schedule does not define any future invocations",
+            ))?;
+
+        let invocation = ScheduledInvocation {
+            id: ScheduledInvocationId::new_v4(),
+            owned_worker_id: owned_worker_id.clone(),
+            full_function_name,
+            function_input,
+            schedule,
+            next_invocation_at: first,
+        };
+
+        let first_date_time =
+            InvocationSchedule::to_date_time(first).map_err(GolemError::invalid_request)?;
+
+        self.store_invocation(&invocation).await.map_err(|err| {
+            GolemError::runtime(format!("Failed to store scheduled invocation: {err}"))
+        })?;
+        self.schedule(
+            first_date_time,
+            ScheduledAction::Invoke {
+                owned_worker_id: owned_worker_id.clone(),
+                id: invocation.id.clone(),
+                scheduled_at: first,
+            },
+        )
+        .await;
+
+        Ok(invocation)
+    }
+
+    async fn cancel_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        id: &ScheduledInvocationId,
+    ) -> Result<bool, GolemError> {
+        let invocation: Option<ScheduledInvocation> = self
+            .key_value_storage
+            .with_entity("scheduler", "cancel_invocation", "scheduled_invocation")
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocation_key(owned_worker_id, id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get scheduled invocation: {err}"))
+            })?;
+
+        match invocation {
+            Some(invocation) => {
+                let next_invocation_at =
+                    InvocationSchedule::to_date_time(invocation.next_invocation_at)
+                        .map_err(GolemError::runtime)?;
+                let (hours_since_epoch, _) = Self::split_time(next_invocation_at);
+                self.cancel(ScheduleId {
+                    timestamp: hours_since_epoch,
+                    action: ScheduledAction::Invoke {
+                        owned_worker_id: owned_worker_id.clone(),
+                        id: id.clone(),
+                        scheduled_at: invocation.next_invocation_at,
+                    },
+                })
+                .await;
+                self.remove_invocation(owned_worker_id, id)
+                    .await
+                    .map_err(|err| {
+                        GolemError::runtime(format!("Failed to remove scheduled invocation: {err}"))
+                    })?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<ScheduledInvocation>, GolemError> {
+        let ids: Vec<ScheduledInvocationId> = self
+            .key_value_storage
+            .with_entity(
+                "scheduler",
+                "get_scheduled_invocations",
+                "scheduled_invocation_id",
+            )
+            .members_of_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::scheduled_invocations_key(owned_worker_id),
+            )
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get scheduled invocations: {err}"))
+            })?;
+        let keys = ids
+            .iter()
+            .map(|id| Self::scheduled_invocation_key(owned_worker_id, id))
+            .collect();
+        let invocations: Vec<Option<ScheduledInvocation>> = self
+            .key_value_storage
+            .with_entity(
+                "scheduler",
+                "get_scheduled_invocations",
+                "scheduled_invocation",
+            )
+            .get_many(KeyValueStorageNamespace::Schedule, keys)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!("Failed to get scheduled invocations: {err}"))
+            })?;
+        let mut result: Vec<ScheduledInvocation> = invocations.into_iter().flatten().collect();
+        result.sort_by_key(|invocation| invocation.next_invocation_at);
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -304,7 +731,8 @@ mod tests {
     use uuid::Uuid;
 
     use crate::services::oplog::{OplogService, PrimaryOplogService};
-    use crate::services::promise::PromiseServiceMock;
+    use crate::services::promise::{PromiseService, PromiseServiceMock};
+    use crate::services::pubsub::{PubSubService, PubSubServiceDefault};
     use crate::services::scheduler::{SchedulerService, SchedulerServiceDefault};
     use crate::services::shard::{ShardService, ShardServiceDefault};
     use crate::services::worker::{DefaultWorkerService, WorkerService};
@@ -313,8 +741,10 @@ mod tests {
     use crate::storage::indexed::memory::InMemoryIndexedStorage;
     use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
     use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::pubsub::TopicSubscription;
     use golem_common::model::{
-        AccountId, ComponentId, PromiseId, ScheduledAction, ShardId, WorkerId,
+        AccountId, ComponentId, InvocationSchedule, OwnedWorkerId, PromiseId, ScheduledAction,
+        ShardId, Timestamp, WorkerId,
     };
 
     fn serialized_bytes<T: Encode>(entry: &T) -> Vec<u8> {
@@ -357,6 +787,15 @@ mod tests {
         Arc::new(DefaultWorkerService::new(kvs, shard_service, oplog_service))
     }
 
+    fn create_pubsub_service(
+        kvs: Arc<InMemoryKeyValueStorage>,
+    ) -> Arc<dyn PubSubService + Send + Sync> {
+        Arc::new(PubSubServiceDefault::new(
+            Arc::new(InMemoryIndexedStorage::new()),
+            kvs,
+        ))
+    }
+
     #[test]
     pub async fn promises_added_to_expected_buckets() {
         let uuid = Uuid::new_v4();
@@ -395,6 +834,7 @@ mod tests {
         let oplog_service = create_oplog_service_mock().await;
         let worker_service =
             create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
 
         let svc = SchedulerServiceDefault::new(
             kvs.clone(),
@@ -403,6 +843,7 @@ mod tests {
             worker_activator,
             oplog_service,
             worker_service,
+            pubsub_service,
             Duration::from_secs(1000), // not testing process() here
         );
 
@@ -512,6 +953,7 @@ mod tests {
         let oplog_service = create_oplog_service_mock().await;
         let worker_service =
             create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
 
         let svc = SchedulerServiceDefault::new(
             kvs.clone(),
@@ -520,6 +962,7 @@ mod tests {
             worker_activator,
             oplog_service,
             worker_service,
+            pubsub_service,
             Duration::from_secs(1000), // not testing process() here
         );
 
@@ -614,6 +1057,7 @@ mod tests {
         let oplog_service = create_oplog_service_mock().await;
         let worker_service =
             create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
 
         let svc = SchedulerServiceDefault::new(
             kvs.clone(),
@@ -622,6 +1066,7 @@ mod tests {
             worker_activator,
             oplog_service,
             worker_service,
+            pubsub_service,
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -684,6 +1129,172 @@ mod tests {
         assert!(!completed_promises.contains(&p2));
     }
 
+    #[test]
+    pub async fn process_continues_after_failed_action() {
+        let c1: ComponentId = ComponentId(Uuid::new_v4());
+        let i1: WorkerId = WorkerId {
+            component_id: c1.clone(),
+            worker_name: "inst1".to_string(),
+        };
+
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+
+        let p1: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: OplogIndex::from_u64(101),
+        };
+        let p2: PromiseId = PromiseId {
+            worker_id: i1.clone(),
+            oplog_idx: OplogIndex::from_u64(123),
+        };
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_activator = create_worker_activator_mock();
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service.clone(),
+            worker_activator,
+            oplog_service,
+            worker_service,
+            pubsub_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        let _s1 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T10:05:00Z").unwrap(),
+                ScheduledAction::CompletePromise {
+                    promise_id: p1.clone(),
+                    account_id: account_id.clone(),
+                },
+            )
+            .await;
+        let _s2 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T10:06:00Z").unwrap(),
+                ScheduledAction::CompletePromise {
+                    promise_id: p2.clone(),
+                    account_id: account_id.clone(),
+                },
+            )
+            .await;
+
+        // Completing a deleted promise fails
+        promise_service.delete(p1.clone()).await;
+
+        svc.process(DateTime::from_str("2023-07-17T10:15:00Z").unwrap())
+            .await
+            .unwrap();
+
+        let result = kvs
+            .sorted_sets()
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(
+            result,
+            HashMap::from([("Schedule/worker:schedule:469330".to_string(), vec![])])
+        );
+
+        let completed_promises = promise_service.all_completed().await;
+
+        assert!(!completed_promises.contains(&p1));
+        assert!(completed_promises.contains(&p2));
+    }
+
+    #[test]
+    pub async fn deliver_topic_messages_marks_redelivered_messages() {
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+        let owned_worker_id = OwnedWorkerId::new(
+            &account_id,
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "inst1".to_string(),
+            },
+        );
+        let subscription = TopicSubscription {
+            topic: "events".to_string(),
+            worker_id: owned_worker_id.worker_id.clone(),
+            function_name: "golem:it/api.{on-event}".to_string(),
+        };
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_activator = create_worker_activator_mock();
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_activator,
+            oplog_service,
+            worker_service,
+            pubsub_service.clone(),
+            Duration::from_secs(1000), // explicitly calling process for testing
+        );
+
+        pubsub_service
+            .subscribe(&account_id, &subscription)
+            .await
+            .unwrap();
+        pubsub_service
+            .publish(&account_id, "events", vec![1])
+            .await
+            .unwrap();
+        pubsub_service
+            .publish(&account_id, "events", vec![2])
+            .await
+            .unwrap();
+
+        let _s1 = svc
+            .schedule(
+                DateTime::from_str("2023-07-17T10:05:00Z").unwrap(),
+                ScheduledAction::DeliverTopicMessages {
+                    owned_worker_id: owned_worker_id.clone(),
+                    topic: "events".to_string(),
+                    function_name: subscription.function_name.clone(),
+                    retry_after: Duration::from_secs(10),
+                },
+            )
+            .await;
+
+        svc.process(DateTime::from_str("2023-07-17T10:15:00Z").unwrap())
+            .await
+            .unwrap();
+
+        assert!(pubsub_service
+            .undelivered_messages(&account_id, &subscription)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            kvs.sorted_sets()
+                .iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect::<HashMap<_, _>>(),
+            HashMap::from([("Schedule/worker:schedule:469330".to_string(), vec![])])
+        );
+    }
+
     #[test]
     pub async fn process_past_and_current_hours_past_schedules() {
         let c1: ComponentId = ComponentId(Uuid::new_v4());
@@ -721,6 +1332,7 @@ mod tests {
         let oplog_service = create_oplog_service_mock().await;
         let worker_service =
             create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
 
         let svc = SchedulerServiceDefault::new(
             kvs.clone(),
@@ -729,6 +1341,7 @@ mod tests {
             worker_activator,
             oplog_service,
             worker_service,
+            pubsub_service,
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -826,6 +1439,7 @@ mod tests {
         let oplog_service = create_oplog_service_mock().await;
         let worker_service =
             create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
 
         let svc = SchedulerServiceDefault::new(
             kvs.clone(),
@@ -834,6 +1448,7 @@ mod tests {
             worker_activator,
             oplog_service,
             worker_service,
+            pubsub_service,
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -937,6 +1552,7 @@ mod tests {
         let oplog_service = create_oplog_service_mock().await;
         let worker_service =
             create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
 
         let svc = SchedulerServiceDefault::new(
             kvs.clone(),
@@ -945,6 +1561,7 @@ mod tests {
             worker_activator,
             oplog_service,
             worker_service,
+            pubsub_service,
             Duration::from_secs(1000), // explicitly calling process for testing
         );
 
@@ -1000,4 +1617,103 @@ mod tests {
         assert!(completed_promises.contains(&p3));
         assert!(completed_promises.contains(&p2));
     }
+
+    #[test]
+    pub async fn process_scheduled_invocations() {
+        let worker_id = WorkerId {
+            component_id: ComponentId(Uuid::new_v4()),
+            worker_name: "inst1".to_string(),
+        };
+        let account_id = AccountId {
+            value: "test-account".to_string(),
+        };
+        let owned_worker_id = OwnedWorkerId::new(&account_id, &worker_id);
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_activator = create_worker_activator_mock();
+        let oplog_service = create_oplog_service_mock().await;
+        let worker_service =
+            create_worker_service_mock(kvs.clone(), shard_service.clone(), oplog_service.clone());
+        let pubsub_service = create_pubsub_service(kvs.clone());
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_activator,
+            oplog_service,
+            worker_service,
+            pubsub_service,
+            Duration::from_secs(1000), // explicitly calling process() here
+        );
+
+        let once = svc
+            .schedule_invocation(
+                &owned_worker_id,
+                "golem:it/api.{f1}".to_string(),
+                vec![],
+                InvocationSchedule::Once {
+                    at: Timestamp::from(Timestamp::now_utc().to_millis() - 1000),
+                },
+            )
+            .await
+            .unwrap();
+        let recurring = svc
+            .schedule_invocation(
+                &owned_worker_id,
+                "golem:it/api.{f2}".to_string(),
+                vec![],
+                InvocationSchedule::Interval {
+                    period: Duration::from_secs(60 * 60),
+                },
+            )
+            .await
+            .unwrap();
+        let invalid = svc
+            .schedule_invocation(
+                &owned_worker_id,
+                "golem:it/api.{f3}".to_string(),
+                vec![],
+                InvocationSchedule::Cron {
+                    expression: "not a cron expression".to_string(),
+                },
+            )
+            .await;
+
+        assert!(invalid.is_err());
+        assert_eq!(
+            svc.get_scheduled_invocations(&owned_worker_id)
+                .await
+                .unwrap(),
+            vec![once.clone(), recurring.clone()]
+        );
+
+        svc.process(chrono::Utc::now()).await.unwrap();
+
+        // The one-time invocation has been enqueued and removed, the recurring one is not due yet
+        assert_eq!(
+            svc.get_scheduled_invocations(&owned_worker_id)
+                .await
+                .unwrap(),
+            vec![recurring.clone()]
+        );
+
+        assert!(svc
+            .cancel_invocation(&owned_worker_id, &recurring.id)
+            .await
+            .unwrap());
+        assert!(!svc
+            .cancel_invocation(&owned_worker_id, &once.id)
+            .await
+            .unwrap());
+        assert_eq!(
+            svc.get_scheduled_invocations(&owned_worker_id)
+                .await
+                .unwrap(),
+            vec![]
+        );
+    }
 }
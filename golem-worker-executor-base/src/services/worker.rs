@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{
     ComponentType, OwnedWorkerId, ShardId, Timestamp, WorkerId, WorkerMetadata, WorkerStatus,
     WorkerStatusRecord,
@@ -106,6 +107,31 @@ impl DefaultWorkerService {
     fn running_in_shard_key(shard_id: &ShardId) -> String {
         format!("worker:running_in_shard:{shard_id}")
     }
+
+    /// Replaces the initial status of the worker metadata with the last cached one, if any
+    async fn worker_metadata_with_status(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        mut details: WorkerMetadata,
+    ) -> WorkerMetadata {
+        let status_value: Option<WorkerStatusRecord> = self
+            .key_value_storage
+            .with_entity("worker", "get", "worker_status")
+            .get(
+                KeyValueStorageNamespace::Worker,
+                &Self::status_key(&owned_worker_id.worker_id),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to get worker status for {owned_worker_id} from KV storage: {err}")
+            });
+
+        if let Some(status) = status_value {
+            details.last_known_status = status;
+        }
+
+        details
+    }
 }
 
 #[async_trait]
@@ -129,6 +155,7 @@ impl WorkerService for DefaultWorkerService {
             worker_metadata.parent.clone(),
             worker_metadata.last_known_status.component_size,
             worker_metadata.last_known_status.total_linear_memory_size,
+            worker_metadata.quotas.clone(),
         );
         self.oplog_service
             .create(&owned_worker_id, initial_oplog_entry, component_type)
@@ -184,7 +211,7 @@ impl WorkerService for DefaultWorkerService {
             None => None,
             Some((
                 _,
-                OplogEntry::Create {
+                OplogEntry::CreateV1 {
                     worker_id,
                     component_version,
                     args,
@@ -195,40 +222,62 @@ impl WorkerService for DefaultWorkerService {
                     component_size,
                     initial_total_linear_memory_size,
                 },
-            )) => {
-                let mut details = WorkerMetadata {
+            )) => Some(
+                self.worker_metadata_with_status(
+                    owned_worker_id,
+                    WorkerMetadata {
+                        worker_id,
+                        args,
+                        env,
+                        account_id,
+                        created_at: timestamp,
+                        parent,
+                        quotas: WorkerQuotas::default(),
+                        last_known_status: WorkerStatusRecord {
+                            component_version,
+                            component_size,
+                            total_linear_memory_size: initial_total_linear_memory_size,
+                            ..WorkerStatusRecord::default()
+                        },
+                    },
+                )
+                .await,
+            ),
+            Some((
+                _,
+                OplogEntry::Create {
                     worker_id,
+                    component_version,
                     args,
                     env,
                     account_id,
-                    created_at: timestamp,
+                    timestamp,
                     parent,
-                    last_known_status: WorkerStatusRecord {
-                        component_version,
-                        component_size,
-                        total_linear_memory_size: initial_total_linear_memory_size,
-                        ..WorkerStatusRecord::default()
+                    component_size,
+                    initial_total_linear_memory_size,
+                    quotas,
+                },
+            )) => Some(
+                self.worker_metadata_with_status(
+                    owned_worker_id,
+                    WorkerMetadata {
+                        worker_id,
+                        args,
+                        env,
+                        account_id,
+                        created_at: timestamp,
+                        parent,
+                        quotas,
+                        last_known_status: WorkerStatusRecord {
+                            component_version,
+                            component_size,
+                            total_linear_memory_size: initial_total_linear_memory_size,
+                            ..WorkerStatusRecord::default()
+                        },
                     },
-                };
-
-                let status_value: Option<WorkerStatusRecord> = self
-                    .key_value_storage
-                    .with_entity("worker", "get", "worker_status")
-                    .get(
-                        KeyValueStorageNamespace::Worker,
-                        &Self::status_key(&owned_worker_id.worker_id),
-                    )
-                    .await
-                    .unwrap_or_else(|err| {
-                        panic!("failed to get worker status for {owned_worker_id} from KV storage: {err}")
-                    });
-
-                if let Some(status) = status_value {
-                    details.last_known_status = status;
-                }
-
-                Some(details)
-            }
+                )
+                .await,
+            ),
             Some((_, entry)) => {
                 // This should never happen, but there were some issues previously causing a corrupt oplog
                 // leading to this state.
@@ -251,6 +300,7 @@ impl WorkerService for DefaultWorkerService {
                     account_id: owned_worker_id.account_id(),
                     created_at: Timestamp::now_utc(),
                     parent: None,
+                    quotas: WorkerQuotas::default(),
                     last_known_status: WorkerStatusRecord {
                         status: WorkerStatus::Failed,
                         oplog_idx: last_oplog_idx,
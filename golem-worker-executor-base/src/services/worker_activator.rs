@@ -16,9 +16,11 @@ use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
-use golem_common::model::OwnedWorkerId;
+use golem_common::model::{IdempotencyKey, OwnedWorkerId};
+use golem_wasm_rpc::Value;
 use tracing::{error, warn};
 
+use crate::error::GolemError;
 use crate::services::HasAll;
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -28,6 +30,16 @@ use crate::workerctx::WorkerCtx;
 pub trait WorkerActivator {
     /// Makes sure an already existing worker is active in a background task. Returns immediately
     async fn activate_worker(&self, owned_worker_id: &OwnedWorkerId);
+
+    /// Enqueues an invocation on an already existing worker, without waiting for its result.
+    /// Enqueueing an invocation with an idempotency key that is already known by the worker is a no-op.
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+    ) -> Result<(), GolemError>;
 }
 
 pub struct LazyWorkerActivator {
@@ -61,6 +73,31 @@ impl WorkerActivator for LazyWorkerActivator {
             None => warn!("WorkerActivator is disabled, not activating instance"),
         }
     }
+
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+    ) -> Result<(), GolemError> {
+        let maybe_worker_activator = self.worker_activator.lock().unwrap().clone();
+        match maybe_worker_activator {
+            Some(worker_activator) => {
+                worker_activator
+                    .enqueue_invocation(
+                        owned_worker_id,
+                        idempotency_key,
+                        full_function_name,
+                        function_input,
+                    )
+                    .await
+            }
+            None => Err(GolemError::runtime(
+                "WorkerActivator is disabled, cannot enqueue invocation",
+            )),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -93,6 +130,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + Send + Sync + 'static> WorkerActivator
                     None,
                     None,
                     None,
+                    None,
                 )
                 .await
                 {
@@ -104,6 +142,37 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + Send + Sync + 'static> WorkerActivator
             }
         }
     }
+
+    async fn enqueue_invocation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        idempotency_key: IdempotencyKey,
+        full_function_name: String,
+        function_input: Vec<Value>,
+    ) -> Result<(), GolemError> {
+        let metadata = self.all.worker_service().get(owned_worker_id).await;
+        match metadata {
+            Some(_) => {
+                let worker = Worker::get_or_create_suspended(
+                    &self.all,
+                    owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                worker
+                    .invoke(idempotency_key, full_function_name, function_input, None)
+                    .await?;
+                Ok(())
+            }
+            None => Err(GolemError::worker_not_found(
+                owned_worker_id.worker_id.clone(),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -129,4 +198,15 @@ impl WorkerActivator for WorkerActivatorMock {
     async fn activate_worker(&self, _owned_worker_id: &OwnedWorkerId) {
         tracing::info!("WorkerActivatorMock::activate_worker");
     }
+
+    async fn enqueue_invocation(
+        &self,
+        _owned_worker_id: &OwnedWorkerId,
+        _idempotency_key: IdempotencyKey,
+        _full_function_name: String,
+        _function_input: Vec<Value>,
+    ) -> Result<(), GolemError> {
+        tracing::info!("WorkerActivatorMock::enqueue_invocation");
+        Ok(())
+    }
 }
@@ -24,6 +24,7 @@ use golem_api_grpc::proto::golem::worker::v1::{
 };
 use golem_api_grpc::proto::golem::worker::{InvocationContext, InvokeParameters, UpdateMode};
 use golem_common::client::GrpcClient;
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{ComponentVersion, IdempotencyKey, OwnedWorkerId, WorkerId};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::{Value, WitValue};
@@ -47,6 +48,7 @@ pub trait WorkerProxy {
         caller_worker_id: WorkerId,
         caller_args: Vec<String>,
         caller_env: HashMap<String, String>,
+        caller_trace_context: Option<TraceContext>,
     ) -> Result<TypeAnnotatedValue, WorkerProxyError>;
 
     async fn invoke(
@@ -58,6 +60,7 @@ pub trait WorkerProxy {
         caller_worker_id: WorkerId,
         caller_args: Vec<String>,
         caller_env: HashMap<String, String>,
+        caller_trace_context: Option<TraceContext>,
     ) -> Result<(), WorkerProxyError>;
 
     async fn update(
@@ -176,6 +179,7 @@ impl WorkerProxy for RemoteWorkerProxy {
         caller_worker_id: WorkerId,
         caller_args: Vec<String>,
         caller_env: HashMap<String, String>,
+        caller_trace_context: Option<TraceContext>,
     ) -> Result<TypeAnnotatedValue, WorkerProxyError> {
         debug!(
             "Invoking remote worker function {function_name} with parameters {function_params:?}"
@@ -205,6 +209,7 @@ impl WorkerProxy for RemoteWorkerProxy {
                             parent: Some(caller_worker_id.clone().into()),
                             args: caller_args.clone(),
                             env: caller_env.clone(),
+                            trace_context: caller_trace_context.clone().map(|ctx| ctx.into()),
                         }),
                     },
                     &self.access_token,
@@ -244,6 +249,7 @@ impl WorkerProxy for RemoteWorkerProxy {
         caller_worker_id: WorkerId,
         caller_args: Vec<String>,
         caller_env: HashMap<String, String>,
+        caller_trace_context: Option<TraceContext>,
     ) -> Result<(), WorkerProxyError> {
         debug!("Invoking remote worker function {function_name} with parameters {function_params:?} without awaiting for the result");
 
@@ -271,6 +277,7 @@ impl WorkerProxy for RemoteWorkerProxy {
                             parent: Some(caller_worker_id.clone().into()),
                             args: caller_args.clone(),
                             env: caller_env.clone(),
+                            trace_context: caller_trace_context.clone().map(|ctx| ctx.into()),
                         }),
                     },
                     &self.access_token,
@@ -22,11 +22,30 @@ use bytes::Bytes;
 use golem_common::serialization::{deserialize, serialize};
 
 pub mod memory;
+pub mod postgres;
 pub mod redis;
 pub mod sqlite;
 
 pub type ScanCursor = u64;
 
+/// Converts a scan pattern, where `*` and `?` are the only wildcards, to an SQL `LIKE` pattern
+/// using `\` as its escape character
+pub(crate) fn scan_pattern_to_like(pattern: &str) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    for c in pattern.chars() {
+        match c {
+            '*' => result.push('%'),
+            '?' => result.push('_'),
+            '%' | '_' | '\\' => {
+                result.push('\\');
+                result.push(c);
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
 /// Generic indexed storage interface
 ///
 /// The storage holds indexes identified by keys. Each index is a sequence of entries,
@@ -553,4 +572,5 @@ impl<'a, S: ?Sized + IndexedStorage> LabelledEntityIndexedStorage<'a, S> {
 pub enum IndexedStorageNamespace {
     OpLog,
     CompressedOpLog { level: usize },
+    Topic,
 }
@@ -37,6 +37,7 @@ impl RedisIndexedStorage {
             IndexedStorageNamespace::CompressedOpLog { level } => {
                 format!("worker:c{level}-oplog:{key}")
             }
+            IndexedStorageNamespace::Topic => format!("account:topic:{key}"),
         }
     }
 
@@ -17,7 +17,7 @@ use async_trait::async_trait;
 use bytes::Bytes;
 use std::time::Duration;
 
-use super::{IndexedStorage, IndexedStorageNamespace, ScanCursor};
+use super::{scan_pattern_to_like, IndexedStorage, IndexedStorageNamespace, ScanCursor};
 
 #[derive(Debug)]
 pub struct SqliteIndexedStorage {
@@ -60,6 +60,7 @@ impl SqliteIndexedStorage {
             IndexedStorageNamespace::CompressedOpLog { level } => {
                 format!("worker-c{level}-oplog")
             }
+            IndexedStorageNamespace::Topic => "account-topic".to_string(),
         }
     }
 }
@@ -113,9 +114,9 @@ impl IndexedStorage for SqliteIndexedStorage {
         cursor: ScanCursor,
         count: u64,
     ) -> Result<(ScanCursor, Vec<String>), String> {
-        let key = pattern.replace("*", "%").replace("?", "_");
+        let key = scan_pattern_to_like(pattern);
         let query =
-            sqlx::query_as("SELECT key FROM index_storage WHERE namespace = ? AND key LIKE ? ORDER BY key LIMIT ? OFFSET ?;")
+            sqlx::query_as("SELECT key FROM index_storage WHERE namespace = ? AND key LIKE ? ESCAPE '\\' ORDER BY key LIMIT ? OFFSET ?;")
                 .bind(Self::namespace(namespace))
                 .bind(&key)
                 .bind(sqlx::types::Json(count))
@@ -106,6 +106,25 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         }
     }
 
+    async fn compare_and_set(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: &[u8],
+        value: &[u8],
+    ) -> Result<bool, String> {
+        match self.kvs.get_mut(&Self::composite_key(&namespace, key)) {
+            Some(mut current) if current.value() == expected => {
+                *current = value.to_vec();
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
     async fn get(
         &self,
         _svc_name: &'static str,
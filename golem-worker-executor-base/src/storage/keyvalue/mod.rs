@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod memory;
+pub mod postgres;
 pub mod redis;
 pub mod sqlite;
 
@@ -54,6 +55,20 @@ pub trait KeyValueStorage: Debug {
         value: &[u8],
     ) -> Result<bool, String>;
 
+    /// Atomically replaces the value of a key if its current value is `expected`, returning
+    /// whether it got replaced
+    #[allow(clippy::too_many_arguments)]
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: &[u8],
+        value: &[u8],
+    ) -> Result<bool, String>;
+
     async fn get(
         &self,
         svc_name: &'static str,
@@ -326,6 +341,28 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledEntityKeyValueStorage<'a, S> {
             .await
     }
 
+    pub async fn compare_and_set<V: Encode>(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: &V,
+        value: &V,
+    ) -> Result<bool, String> {
+        let expected = serialize(expected)?;
+        let serialized = serialize(value)?;
+        self.storage
+            .compare_and_set(
+                self.svc_name,
+                self.api_name,
+                self.entity_name,
+                namespace,
+                key,
+                &expected,
+                &serialized,
+            )
+            .await
+    }
+
     pub async fn set_many<V: Encode>(
         &self,
         namespace: KeyValueStorageNamespace,
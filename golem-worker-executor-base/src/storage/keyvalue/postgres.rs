@@ -0,0 +1,526 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::storage::postgres::DBValue;
+use crate::storage::{
+    keyvalue::{KeyValueStorage, KeyValueStorageNamespace},
+    postgres::PostgresPool,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct PostgresKeyValueStorage {
+    pool: PostgresPool,
+}
+
+impl PostgresKeyValueStorage {
+    pub async fn new(pool: PostgresPool) -> Result<Self, String> {
+        let result = Self { pool };
+        result.init().await?;
+        Ok(result)
+    }
+
+    async fn init(&self) -> Result<(), String> {
+        self.pool
+            .execute(sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS kv_storage (
+                    key TEXT NOT NULL,              -- The key to store
+                    value BYTEA NOT NULL,           -- The value to store
+                    namespace TEXT NOT NULL,        -- The namespace of the key value
+                    PRIMARY KEY(key, namespace)     -- Avoid duplicate key values in a namespace
+                );
+                "#,
+            ))
+            .await?;
+
+        self.pool
+            .execute(sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS set_storage (
+                    key TEXT NOT NULL,                    -- The set's key
+                    value BYTEA NOT NULL,                 -- The value (element)
+                    namespace TEXT NOT NULL,              -- The namespace of the key value
+                    PRIMARY KEY (key, value, namespace)   -- Ensure uniqueness of values per (set, namespace)
+                );
+                "#,
+            ))
+            .await?;
+        self.pool
+            .execute(sqlx::query(
+                "CREATE INDEX IF NOT EXISTS idx_set_storage_key_namespace ON set_storage (key, namespace);",
+            ))
+            .await?;
+
+        self.pool
+            .execute(sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS sorted_set_storage (
+                    key TEXT NOT NULL,                  -- The sorted set's key
+                    value BYTEA NOT NULL,               -- The value (element)
+                    namespace TEXT NOT NULL,            -- The namespace of the key value
+                    score DOUBLE PRECISION NOT NULL,    -- The score associated with the value
+                    PRIMARY KEY(key, value, namespace)  -- Ensure uniqueness of values per (set, namespace)
+                );
+                "#,
+            ))
+            .await?;
+        self.pool
+            .execute(sqlx::query(
+                "CREATE INDEX IF NOT EXISTS idx_sorted_set_storage_key_namespace ON sorted_set_storage (key, namespace);",
+            ))
+            .await?;
+        self.pool
+            .execute(sqlx::query(
+                "CREATE INDEX IF NOT EXISTS idx_sorted_set_storage_score ON sorted_set_storage (score);",
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    fn namespace(ns: KeyValueStorageNamespace) -> String {
+        match ns {
+            KeyValueStorageNamespace::Worker => "worker".to_string(),
+            KeyValueStorageNamespace::Promise => "promise".to_string(),
+            KeyValueStorageNamespace::Schedule => "schedule".to_string(),
+            KeyValueStorageNamespace::UserDefined { account_id, bucket } => {
+                format!("user-defined:{}:{}", account_id, bucket)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl KeyValueStorage for PostgresKeyValueStorage {
+    async fn set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO kv_storage (key, value, namespace) VALUES ($1, $2, $3)
+            ON CONFLICT (key, namespace) DO UPDATE SET value = excluded.value;
+            "#,
+        )
+        .bind(key)
+        .bind(value)
+        .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn set_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        pairs: &[(&str, &[u8])],
+    ) -> Result<(), String> {
+        let api = self.pool.with(svc_name, api_name);
+        let mut tx = api.begin().await?;
+
+        for (field_key, field_value) in pairs {
+            tx.execute(
+                sqlx::query(
+                    r#"
+                    INSERT INTO kv_storage (key, value, namespace) VALUES ($1, $2, $3)
+                    ON CONFLICT (key, namespace) DO UPDATE SET value = excluded.value;
+                    "#,
+                )
+                .bind(field_key)
+                .bind(field_value)
+                .bind(Self::namespace(namespace.clone())),
+            )
+            .await?;
+        }
+        api.commit(tx).await
+    }
+
+    async fn set_if_not_exists(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<bool, String> {
+        let query = sqlx::query(
+            "INSERT INTO kv_storage (key, value, namespace) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING;",
+        )
+        .bind(key)
+        .bind(value)
+        .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: &[u8],
+        value: &[u8],
+    ) -> Result<bool, String> {
+        let query = sqlx::query(
+            "UPDATE kv_storage SET value = $1 WHERE key = $2 AND namespace = $3 AND value = $4;",
+        )
+        .bind(value)
+        .bind(key)
+        .bind(Self::namespace(namespace))
+        .bind(expected);
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    async fn get(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<Option<Bytes>, String> {
+        let query =
+            sqlx::query_as("SELECT value FROM kv_storage WHERE key = $1 AND namespace = $2;")
+                .bind(key)
+                .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .fetch_optional_as::<DBValue, _>(query)
+            .await
+            .map(|r| r.map(|op| op.into_bytes()))
+    }
+
+    async fn get_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        keys: Vec<String>,
+    ) -> Result<Vec<Option<Bytes>>, String> {
+        let query = sqlx::query_as(
+            "SELECT key, value FROM kv_storage WHERE key = ANY($1) AND namespace = $2;",
+        )
+        .bind(&keys)
+        .bind(Self::namespace(namespace));
+
+        let results: Vec<DBKeyValue> = self.pool.with(svc_name, api_name).fetch_all(query).await?;
+
+        let mut result_map = results
+            .into_iter()
+            .map(|kv| kv.into_pair())
+            .collect::<HashMap<String, Bytes>>();
+
+        let values = keys
+            .into_iter()
+            .map(|key| result_map.remove(&key))
+            .collect::<Vec<Option<Bytes>>>();
+
+        Ok(values)
+    }
+
+    async fn del(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<(), String> {
+        let query = sqlx::query("DELETE FROM kv_storage WHERE key = $1 AND namespace = $2;")
+            .bind(key)
+            .bind(Self::namespace(namespace));
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn del_many(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        keys: Vec<String>,
+    ) -> Result<(), String> {
+        let query = sqlx::query("DELETE FROM kv_storage WHERE key = ANY($1) AND namespace = $2;")
+            .bind(keys)
+            .bind(Self::namespace(namespace));
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn exists(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<bool, String> {
+        let query = sqlx::query("SELECT 1 FROM kv_storage WHERE key = $1 AND namespace = $2;")
+            .bind(key)
+            .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .fetch_optional(query)
+            .await
+            .map(|row| row.is_some())
+    }
+
+    async fn keys(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+    ) -> Result<Vec<String>, String> {
+        let query = sqlx::query_as("SELECT key FROM kv_storage WHERE namespace = $1;")
+            .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .fetch_all::<(String,), _>(query)
+            .await
+            .map(|vec| vec.into_iter().map(|k| k.0).collect::<Vec<String>>())
+    }
+
+    async fn add_to_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        let query = sqlx::query(
+            "INSERT INTO set_storage (namespace, key, value) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING;",
+        )
+        .bind(Self::namespace(namespace))
+        .bind(key)
+        .bind(value);
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn remove_from_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        let query = sqlx::query(
+            "DELETE FROM set_storage WHERE key = $1 AND value = $2 AND namespace = $3;",
+        )
+        .bind(key)
+        .bind(value)
+        .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn members_of_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<Vec<Bytes>, String> {
+        let query =
+            sqlx::query_as("SELECT value FROM set_storage WHERE key = $1 AND namespace = $2;")
+                .bind(key)
+                .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .fetch_all::<DBValue, _>(query)
+            .await
+            .map(|vec| {
+                vec.into_iter()
+                    .map(|k| k.into_bytes())
+                    .collect::<Vec<Bytes>>()
+            })
+    }
+
+    async fn add_to_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        score: f64,
+        value: &[u8],
+    ) -> Result<(), String> {
+        let query = sqlx::query(
+            r#"
+            INSERT INTO sorted_set_storage (key, value, namespace, score) VALUES ($1, $2, $3, $4)
+            ON CONFLICT (key, value, namespace) DO UPDATE SET score = excluded.score;
+            "#,
+        )
+        .bind(key)
+        .bind(value)
+        .bind(Self::namespace(namespace))
+        .bind(score);
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn remove_from_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+    ) -> Result<(), String> {
+        let query = sqlx::query(
+            "DELETE FROM sorted_set_storage WHERE key = $1 AND value = $2 AND namespace = $3;",
+        )
+        .bind(key)
+        .bind(value)
+        .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+    }
+
+    async fn get_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        let query = sqlx::query_as(
+            "SELECT score, value FROM sorted_set_storage WHERE key = $1 AND namespace = $2 ORDER BY score ASC;",
+        )
+        .bind(key)
+        .bind(Self::namespace(namespace));
+
+        self.pool
+            .with(svc_name, api_name)
+            .fetch_all::<DBScoreValue, _>(query)
+            .await
+            .map(|vec| {
+                vec.into_iter()
+                    .map(|k| k.into_pair())
+                    .collect::<Vec<(f64, Bytes)>>()
+            })
+    }
+
+    async fn query_sorted_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> Result<Vec<(f64, Bytes)>, String> {
+        let query = sqlx::query_as(
+            "SELECT score, value FROM sorted_set_storage WHERE key = $1 AND namespace = $2 AND score BETWEEN $3 AND $4 ORDER BY score ASC;",
+        )
+        .bind(key)
+        .bind(Self::namespace(namespace))
+        .bind(min)
+        .bind(max);
+
+        self.pool
+            .with(svc_name, api_name)
+            .fetch_all::<DBScoreValue, _>(query)
+            .await
+            .map(|vec| {
+                vec.into_iter()
+                    .map(|k| k.into_pair())
+                    .collect::<Vec<(f64, Bytes)>>()
+            })
+    }
+}
+
+#[derive(sqlx::FromRow, Debug)]
+struct DBKeyValue {
+    pub key: String,
+    value: Vec<u8>,
+}
+
+impl DBKeyValue {
+    fn into_pair(self) -> (String, Bytes) {
+        (self.key, Bytes::from(self.value))
+    }
+}
+
+#[derive(sqlx::FromRow, Debug)]
+struct DBScoreValue {
+    score: f64,
+    value: Vec<u8>,
+}
+
+impl DBScoreValue {
+    fn into_pair(self) -> (f64, Bytes) {
+        (self.score, Bytes::from(self.value))
+    }
+}
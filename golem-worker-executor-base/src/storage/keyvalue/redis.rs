@@ -32,6 +32,22 @@ impl RedisKeyValueStorage {
         Self { redis }
     }
 
+    const COMPARE_AND_SET: &'static str = r#"
+        if redis.call('GET', KEYS[1]) == ARGV[1] then
+            redis.call('SET', KEYS[1], ARGV[2])
+            return 1
+        end
+        return 0
+    "#;
+
+    const HASH_COMPARE_AND_SET: &'static str = r#"
+        if redis.call('HGET', KEYS[1], ARGV[1]) == ARGV[2] then
+            redis.call('HSET', KEYS[1], ARGV[1], ARGV[3])
+            return 1
+        end
+        return 0
+    "#;
+
     fn use_hash(namespace: &KeyValueStorageNamespace) -> Option<String> {
         match namespace {
             KeyValueStorageNamespace::Worker => None,
@@ -139,6 +155,43 @@ impl KeyValueStorage for RedisKeyValueStorage {
         }
     }
 
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: &[u8],
+        value: &[u8],
+    ) -> Result<bool, String> {
+        record_redis_serialized_size(svc_name, entity_name, value.len());
+
+        let expected = Bytes::copy_from_slice(expected);
+        let value = Bytes::copy_from_slice(value);
+        let result: i64 = match Self::use_hash(&namespace) {
+            Some(ns) => self
+                .redis
+                .with(svc_name, api_name)
+                .eval(
+                    Self::HASH_COMPARE_AND_SET,
+                    vec![ns],
+                    vec![Bytes::copy_from_slice(key.as_bytes()), expected, value],
+                )
+                .await
+                .map_err(|redis_err| redis_err.to_string())?,
+            None => self
+                .redis
+                .with(svc_name, api_name)
+                .eval(Self::COMPARE_AND_SET, vec![key], vec![expected, value])
+                .await
+                .map_err(|redis_err| redis_err.to_string())?,
+        };
+
+        debug!("compare_and_set result: {:?}", result);
+        Ok(result == 1)
+    }
+
     async fn get(
         &self,
         svc_name: &'static str,
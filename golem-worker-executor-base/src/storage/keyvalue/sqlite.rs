@@ -182,6 +182,31 @@ impl KeyValueStorage for SqliteKeyValueStorage {
         api.execute(query).await.map(|_| existing.is_none())
     }
 
+    async fn compare_and_set(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        expected: &[u8],
+        value: &[u8],
+    ) -> Result<bool, String> {
+        let query = sqlx::query(
+            "UPDATE kv_storage SET value = ? WHERE key = ? AND namespace = ? AND value = ?;",
+        )
+        .bind(value)
+        .bind(key)
+        .bind(Self::namespace(namespace))
+        .bind(expected);
+
+        self.pool
+            .with(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
     async fn get(
         &self,
         svc_name: &'static str,
@@ -0,0 +1,218 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use golem_common::config::DbPostgresConfig;
+use golem_common::metrics::db::{record_db_failure, record_db_success};
+use sqlx::postgres::{PgArguments, PgConnectOptions, PgPoolOptions, PgQueryResult, PgRow};
+use sqlx::query::{Query, QueryAs};
+use sqlx::{Error, Executor, FromRow, IntoArguments, Postgres};
+use std::time::Instant;
+
+#[derive(Clone, Debug)]
+pub struct PostgresPool {
+    pool: sqlx::PgPool,
+}
+
+impl PostgresPool {
+    pub async fn new(pool: sqlx::PgPool) -> Result<Self, anyhow::Error> {
+        Ok(Self { pool })
+    }
+
+    pub async fn configured(config: &DbPostgresConfig) -> Result<Self, anyhow::Error> {
+        let conn_options = PgConnectOptions::new()
+            .host(config.host.as_str())
+            .port(config.port)
+            .database(config.database.as_str())
+            .username(config.username.as_str())
+            .password(config.password.as_str());
+
+        let schema = quote_identifier(config.schema.as_deref().unwrap_or("public"));
+        let connection_schema = schema.clone();
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .after_connect(move |conn, _meta| {
+                let sql = format!("SET search_path TO {};", connection_schema);
+                Box::pin(async move {
+                    conn.execute(sqlx::query(&sql)).await?;
+                    Ok(())
+                })
+            })
+            .connect_with(conn_options)
+            .await?;
+
+        pool.execute(sqlx::query(&format!(
+            "CREATE SCHEMA IF NOT EXISTS {};",
+            schema
+        )))
+        .await?;
+
+        PostgresPool::new(pool).await
+    }
+
+    pub async fn execute<'a>(
+        &self,
+        query: Query<'a, Postgres, PgArguments>,
+    ) -> Result<PgQueryResult, String> {
+        query
+            .execute(&self.pool)
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    pub fn with(&self, svc_name: &'static str, api_name: &'static str) -> PostgresLabelledApi {
+        PostgresLabelledApi {
+            svc_name,
+            api_name,
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+/// Quotes a configured name so it can be used as an identifier in SQL statements
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+pub struct PostgresLabelledTransaction {
+    tx: sqlx::Transaction<'static, Postgres>,
+    start: Instant,
+}
+
+impl PostgresLabelledTransaction {
+    pub async fn execute<'a>(
+        &mut self,
+        query: Query<'a, Postgres, PgArguments>,
+    ) -> Result<PgQueryResult, String> {
+        query
+            .execute(&mut *self.tx)
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    async fn commit(self) -> Result<(), Error> {
+        self.tx.commit().await
+    }
+}
+
+pub struct PostgresLabelledApi {
+    svc_name: &'static str,
+    api_name: &'static str,
+    pool: sqlx::PgPool,
+}
+
+impl PostgresLabelledApi {
+    pub async fn execute<'a>(
+        &self,
+        query: Query<'a, Postgres, PgArguments>,
+    ) -> Result<PgQueryResult, String> {
+        let start = Instant::now();
+        self.record(start, query.execute(&self.pool).await)
+    }
+
+    pub async fn fetch_optional<'a, A>(
+        &self,
+        query: Query<'a, Postgres, A>,
+    ) -> Result<Option<PgRow>, String>
+    where
+        A: 'a + IntoArguments<'a, Postgres>,
+    {
+        let start = Instant::now();
+        self.record(start, query.fetch_optional(&self.pool).await)
+    }
+
+    pub async fn fetch_optional_as<'a, O, A>(
+        &self,
+        query_as: QueryAs<'a, Postgres, O, A>,
+    ) -> Result<Option<O>, String>
+    where
+        A: 'a + IntoArguments<'a, Postgres>,
+        O: 'a + Send + Unpin + for<'r> FromRow<'r, PgRow>,
+    {
+        let start = Instant::now();
+        self.record(start, query_as.fetch_optional(&self.pool).await)
+    }
+
+    pub async fn fetch_all<'a, O, A>(
+        &self,
+        query_as: QueryAs<'a, Postgres, O, A>,
+    ) -> Result<Vec<O>, String>
+    where
+        A: 'a + IntoArguments<'a, Postgres>,
+        O: 'a + Send + Unpin + for<'r> FromRow<'r, PgRow>,
+    {
+        let start = Instant::now();
+        self.record(start, query_as.fetch_all(&self.pool).await)
+    }
+
+    pub async fn begin(&self) -> Result<PostgresLabelledTransaction, String> {
+        let tx = self.pool.begin().await.map_err(|err| err.to_string())?;
+        Ok(PostgresLabelledTransaction {
+            tx,
+            start: Instant::now(),
+        })
+    }
+
+    pub async fn commit(&self, tx: PostgresLabelledTransaction) -> Result<(), String> {
+        let start = tx.start;
+        let result = tx.commit().await;
+        self.record(start, result)
+    }
+
+    fn record<R>(&self, start: Instant, result: Result<R, Error>) -> Result<R, String> {
+        let end = Instant::now();
+        match result {
+            Ok(result) => {
+                record_db_success(
+                    "postgres",
+                    self.svc_name,
+                    self.api_name,
+                    end.duration_since(start),
+                );
+                Ok(result)
+            }
+            Err(err) => {
+                record_db_failure("postgres", self.svc_name, self.api_name);
+                Err(err.to_string())
+            }
+        }
+    }
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct DBValue {
+    value: Vec<u8>,
+}
+
+impl DBValue {
+    pub fn into_bytes(self) -> Bytes {
+        Bytes::from(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::storage::postgres::quote_identifier;
+
+    #[test]
+    fn quoted_identifiers_cannot_be_escaped() {
+        assert_eq!(quote_identifier("golem"), "\"golem\"");
+        assert_eq!(
+            quote_identifier("x\"; DROP SCHEMA public; --"),
+            "\"x\"\"; DROP SCHEMA public; --\""
+        );
+    }
+}
@@ -25,33 +25,41 @@ use crate::error::{GolemError, WorkerOutOfMemory};
 use crate::function_result_interpreter::interpret_function_results;
 use crate::invocation::{invoke_worker, InvokeResult};
 use crate::model::{ExecutionStatus, InterruptKind, LookupResult, TrapType, WorkerConfig};
+use crate::quotas::QuotaTracker;
 use crate::services::component::ComponentMetadata;
 use crate::services::events::Event;
-use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
+use crate::services::oplog::{CommitLevel, MeteredOplog, Oplog, OplogOps};
 use crate::services::worker_event::{WorkerEventService, WorkerEventServiceDefault};
 use crate::services::{
-    All, HasActiveWorkers, HasAll, HasBlobStoreService, HasComponentService, HasConfig, HasEvents,
-    HasExtraDeps, HasKeyValueService, HasOplog, HasOplogService, HasPromiseService, HasRpc,
-    HasSchedulerService, HasWasmtimeEngine, HasWorker, HasWorkerEnumerationService, HasWorkerProxy,
-    HasWorkerService, UsesAllDeps,
+    All, HasActiveWorkers, HasAll, HasBlobStoreService, HasComponentService, HasConfig,
+    HasDeadLetterService, HasEvents, HasExtraDeps, HasKeyValueService, HasLockService, HasOplog,
+    HasOplogService, HasPromiseService, HasPubSubService, HasRpc, HasSchedulerService,
+    HasWasmtimeEngine, HasWorker, HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
+    UsesAllDeps,
 };
 use crate::workerctx::{FileSystemNode, PublicWorkerFileSystem, PublicWorkerIo, WorkerCtx};
 use anyhow::anyhow;
 use golem_common::config::RetryConfig;
+use golem_common::model::http_handler::{OutgoingHttpResponse, INCOMING_HTTP_HANDLER_FUNCTION};
 use golem_common::model::oplog::{
-    OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerError,
-    WorkerResourceId,
+    FileSystemChange, OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription,
+    WorkerError, WorkerResourceId,
 };
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::regions::{DeletedRegions, DeletedRegionsBuilder, OplogRegion};
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{exports, ComponentType};
 use golem_common::model::{
-    ComponentVersion, FailedUpdateRecord, IdempotencyKey, OwnedWorkerId, SuccessfulUpdateRecord,
-    Timestamp, TimestampedWorkerInvocation, WorkerId, WorkerInvocation, WorkerMetadata,
+    ComponentVersion, DeadLetteredInvocation, DeadLetteredInvocationId, FailedUpdateRecord,
+    IdempotencyKey, OwnedWorkerId, RevertWorkerTarget, SuccessfulUpdateRecord, Timestamp,
+    TimestampedWorkerInvocation, WorkerId, WorkerInvocation, WorkerMetadata,
     WorkerResourceDescription, WorkerStatus, WorkerStatusRecord,
 };
 use golem_common::retries::get_delay;
+use golem_common::tracing::set_span_trace_context;
+use golem_wasm_ast::analysis::{AnalysedFunction, AnalysedFunctionResult};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
-use golem_wasm_rpc::Value;
+use golem_wasm_rpc::{IntoValue, Value};
 use tokio::sync::broadcast::error::RecvError;
 use tokio::sync::broadcast::Receiver;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
@@ -93,6 +101,7 @@ pub struct Worker<Ctx: WorkerCtx> {
 
     instance: Arc<Mutex<WorkerInstance>>,
     oom_retry_config: RetryConfig,
+    quota_tracker: Arc<QuotaTracker>,
 }
 
 impl<Ctx: WorkerCtx> HasOplog for Worker<Ctx> {
@@ -118,6 +127,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         worker_env: Option<Vec<(String, String)>>,
         component_version: Option<u64>,
         parent: Option<WorkerId>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Arc<Self>, GolemError>
     where
         T: HasAll<Ctx> + Clone + Send + Sync + 'static,
@@ -130,6 +140,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 worker_env,
                 component_version,
                 parent,
+                quotas,
             )
             .await
     }
@@ -142,6 +153,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         worker_env: Option<Vec<(String, String)>>,
         component_version: Option<u64>,
         parent: Option<WorkerId>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Arc<Self>, GolemError>
     where
         T: HasAll<Ctx> + Send + Sync + Clone + 'static,
@@ -153,6 +165,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             worker_env,
             component_version,
             parent,
+            quotas,
         )
         .await?;
         Self::start_if_needed(worker.clone()).await?;
@@ -166,6 +179,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         worker_env: Option<Vec<(String, String)>>,
         component_version: Option<u64>,
         parent: Option<WorkerId>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<Self, GolemError> {
         let worker_metadata = Self::get_or_create_worker_metadata(
             deps,
@@ -174,6 +188,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             worker_args,
             worker_env,
             parent,
+            quotas,
         )
         .await?;
         let initial_component_metadata = deps
@@ -193,6 +208,30 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             )
             .await;
 
+        let quotas = initial_component_metadata
+            .quotas
+            .with_overrides(&worker_metadata.quotas);
+        let initial_oplog_bytes = if quotas.max_oplog_bytes.is_some() {
+            MeteredOplog::size_of_existing_entries(
+                &deps.oplog_service(),
+                &owned_worker_id,
+                last_oplog_index,
+            )
+            .await
+        } else {
+            0
+        };
+        let quota_tracker = Arc::new(QuotaTracker::new(
+            quotas,
+            u64::from(last_oplog_index),
+            initial_oplog_bytes,
+        ));
+        let oplog: Arc<dyn Oplog + Send + Sync> = if quota_tracker.meters_oplog() {
+            Arc::new(MeteredOplog::new(oplog, quota_tracker.clone()))
+        } else {
+            oplog
+        };
+
         let initial_pending_invocations = worker_metadata
             .last_known_status
             .pending_invocations
@@ -253,6 +292,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             initial_worker_metadata: worker_metadata,
             worker_estimate_coefficient: deps.config().memory.worker_estimate_coefficient,
             oom_retry_config: deps.config().memory.oom_retry_config.clone(),
+            quota_tracker,
         })
     }
 
@@ -260,6 +300,10 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         &self.oom_retry_config
     }
 
+    pub fn quota_tracker(&self) -> Arc<QuotaTracker> {
+        self.quota_tracker.clone()
+    }
+
     pub async fn start_if_needed(this: Arc<Worker<Ctx>>) -> Result<bool, GolemError> {
         Self::start_if_needed_internal(this, 0).await
     }
@@ -453,6 +497,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        trace_context: Option<TraceContext>,
     ) -> Result<Option<Result<TypeAnnotatedValue, GolemError>>, GolemError> {
         let output = self.lookup_invocation_result(&idempotency_key).await;
 
@@ -462,8 +507,13 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             LookupResult::Pending => Ok(None),
             LookupResult::New => {
                 // Invoke the function in the background
-                self.enqueue(idempotency_key, full_function_name, function_input)
-                    .await;
+                self.enqueue(
+                    idempotency_key,
+                    full_function_name,
+                    function_input,
+                    trace_context,
+                )
+                .await;
                 Ok(None)
             }
         }
@@ -474,9 +524,15 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        trace_context: Option<TraceContext>,
     ) -> Result<TypeAnnotatedValue, GolemError> {
         match self
-            .invoke(idempotency_key.clone(), full_function_name, function_input)
+            .invoke(
+                idempotency_key.clone(),
+                full_function_name,
+                function_input,
+                trace_context,
+            )
             .await?
         {
             Some(Ok(output)) => Ok(output),
@@ -627,6 +683,26 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 .collect(),
         ]
         .concat();
+        self.store_failed_invocation_results(keys_to_fail, trap_type, oplog_index);
+    }
+
+    /// Associates the failure only with the dead-lettered invocation, as the worker continues
+    /// processing its pending invocations
+    pub async fn store_dead_lettered_invocation_failure(
+        &self,
+        key: &IdempotencyKey,
+        trap_type: &TrapType,
+        oplog_index: OplogIndex,
+    ) {
+        self.store_failed_invocation_results(vec![key], trap_type, oplog_index);
+    }
+
+    fn store_failed_invocation_results(
+        &self,
+        keys_to_fail: Vec<&IdempotencyKey>,
+        trap_type: &TrapType,
+        oplog_index: OplogIndex,
+    ) {
         let mut map = self.invocation_results.write().unwrap();
         for key in keys_to_fail {
             let stderr = self.event_service.get_last_invocation_errors();
@@ -656,6 +732,247 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         map.remove(key);
     }
 
+    /// Reverts the worker's history by adding a jump over the reverted region to the oplog.
+    ///
+    /// The worker must be stopped before calling this method, and it has to be dropped from the
+    /// active workers afterwards, so it gets recovered from the truncated history on next start.
+    pub async fn revert(&self, target: RevertWorkerTarget) -> Result<(), GolemError> {
+        let last_oplog_index = self.oplog.current_oplog_index().await;
+        let status = calculate_last_known_status(
+            self,
+            &self.owned_worker_id,
+            &Some(self.get_metadata().await?),
+        )
+        .await?;
+
+        let last_kept_index = match target {
+            RevertWorkerTarget::RevertToOplogIndex(target) => target.last_oplog_index,
+            RevertWorkerTarget::RevertLastInvocations(target) => self
+                .find_nth_last_invocation(
+                    last_oplog_index,
+                    target.number_of_invocations,
+                    &status.deleted_regions,
+                )
+                .await?
+                .previous(),
+        };
+
+        if last_kept_index < OplogIndex::INITIAL || last_kept_index >= last_oplog_index {
+            return Err(GolemError::invalid_request(format!(
+                "Cannot revert to oplog index {last_kept_index}, the last oplog index is {last_oplog_index}"
+            )));
+        }
+        if status.deleted_regions.is_in_deleted_region(last_kept_index) {
+            return Err(GolemError::invalid_request(format!(
+                "Cannot revert to oplog index {last_kept_index} because it is in a skipped region"
+            )));
+        }
+        self.ensure_no_reverted_updates(last_kept_index, last_oplog_index, &status.deleted_regions)
+            .await?;
+
+        // The jump skips the reverted entries and the jump entry itself during replay
+        let jump = OplogRegion {
+            start: last_kept_index.next(),
+            end: last_oplog_index.next(),
+        };
+        debug!(
+            "Reverting worker {} by skipping {jump}",
+            self.owned_worker_id
+        );
+        self.oplog.add_and_commit(OplogEntry::jump(jump)).await;
+
+        Ok(())
+    }
+
+    /// Records the current invocation in the dead-letter store, if the component's dead-letter
+    /// policy is enabled. Returns the oplog index where the dead-lettered invocation started, so
+    /// the caller can skip its region, or `None` if the invocation was not dead-lettered.
+    pub async fn dead_letter_invocation(
+        &self,
+        idempotency_key: &IdempotencyKey,
+        error: String,
+        deleted_regions: &DeletedRegions,
+    ) -> Result<Option<OplogIndex>, GolemError> {
+        let policy = self
+            .dead_letter_service()
+            .get_policy(&self.owned_worker_id.worker_id.component_id)
+            .await?;
+        if !policy.enabled {
+            return Ok(None);
+        }
+
+        let last_oplog_index = self.oplog.current_oplog_index().await;
+        let start = self
+            .find_nth_last_invocation(last_oplog_index, 1, deleted_regions)
+            .await?;
+        let entry = self
+            .oplog_service()
+            .read(&self.owned_worker_id, start, 1)
+            .await
+            .remove(&start)
+            .ok_or(GolemError::runtime(
+                "Failed to read the dead-lettered invocation",
+            ))?;
+        let full_function_name = match &entry {
+            OplogEntry::ExportedFunctionInvoked {
+                function_name,
+                idempotency_key: key,
+                ..
+            } if key == idempotency_key => function_name.clone(),
+            _ => {
+                warn!("Could not find the start of invocation {idempotency_key} to dead-letter");
+                return Ok(None);
+            }
+        };
+        let function_input: Vec<golem_wasm_rpc::protobuf::Val> = self
+            .oplog
+            .get_payload_of_entry(&entry)
+            .await
+            .map_err(|err| {
+                GolemError::runtime(format!(
+                    "Failed to read dead-lettered invocation input: {err}"
+                ))
+            })?
+            .unwrap_or_default();
+        let function_input = function_input
+            .into_iter()
+            .map(|val| val.try_into())
+            .collect::<Result<Vec<Value>, String>>()
+            .map_err(|err| {
+                GolemError::runtime(format!(
+                    "Failed to decode dead-lettered invocation input: {err}"
+                ))
+            })?;
+        let component_version = self
+            .get_metadata()
+            .await?
+            .last_known_status
+            .component_version;
+
+        let invocation = DeadLetteredInvocation {
+            id: DeadLetteredInvocationId::new_v4(),
+            owned_worker_id: self.owned_worker_id.clone(),
+            idempotency_key: idempotency_key.clone(),
+            component_version,
+            full_function_name,
+            function_input,
+            error,
+            failed_at: Timestamp::now_utc(),
+        };
+        self.dead_letter_service().add(&invocation).await?;
+        info!(
+            "Dead-lettered invocation {} of {} as {}",
+            idempotency_key, invocation.full_function_name, invocation.id
+        );
+
+        Ok(Some(start))
+    }
+
+    /// Enqueues a dead-lettered invocation again, with its original idempotency key
+    pub async fn retry_dead_lettered_invocation(
+        &self,
+        invocation: &DeadLetteredInvocation,
+    ) -> Result<(), GolemError> {
+        // Forgetting the stored failure, otherwise the idempotency key would be considered completed
+        self.store_invocation_resuming(&invocation.idempotency_key)
+            .await;
+        self.invoke(
+            invocation.idempotency_key.clone(),
+            invocation.full_function_name.clone(),
+            invocation.function_input.clone(),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Fails if the entries after `last_kept_index` contain a not yet skipped component update.
+    ///
+    /// The component version and the update history are calculated from these entries, and the
+    /// worker's memory may have been restored from a snapshot taken by an update, so skipping
+    /// them would replay the kept entries with a different component than they were recorded with.
+    async fn ensure_no_reverted_updates(
+        &self,
+        last_kept_index: OplogIndex,
+        last_oplog_index: OplogIndex,
+        deleted_regions: &DeletedRegions,
+    ) -> Result<(), GolemError> {
+        const CHUNK_SIZE: u64 = 100;
+
+        let mut start = last_kept_index.next();
+        while start <= last_oplog_index {
+            let end = start.range_end(CHUNK_SIZE).min(last_oplog_index);
+            let entries = self
+                .oplog_service()
+                .read_range(&self.owned_worker_id, start, end)
+                .await;
+            for (idx, entry) in entries {
+                if matches!(
+                    entry,
+                    OplogEntry::PendingUpdate { .. }
+                        | OplogEntry::SuccessfulUpdate { .. }
+                        | OplogEntry::FailedUpdate { .. }
+                ) && !deleted_regions.is_in_deleted_region(idx)
+                {
+                    return Err(GolemError::invalid_request(format!(
+                        "Cannot revert to oplog index {last_kept_index} because the worker was updated at oplog index {idx}"
+                    )));
+                }
+            }
+            start = end.next();
+        }
+
+        Ok(())
+    }
+
+    /// Finds the oplog index of the `n`th last, not yet skipped exported function invocation
+    async fn find_nth_last_invocation(
+        &self,
+        last_oplog_index: OplogIndex,
+        n: u64,
+        deleted_regions: &DeletedRegions,
+    ) -> Result<OplogIndex, GolemError> {
+        const CHUNK_SIZE: u64 = 100;
+
+        if n == 0 {
+            return Err(GolemError::invalid_request(
+                "The number of invocations to revert must be positive",
+            ));
+        }
+
+        let mut remaining = n;
+        let mut end = last_oplog_index;
+        while end >= OplogIndex::INITIAL {
+            let start = OplogIndex::from_u64(
+                u64::from(end)
+                    .saturating_sub(CHUNK_SIZE - 1)
+                    .max(u64::from(OplogIndex::INITIAL)),
+            );
+            let entries = self
+                .oplog_service()
+                .read_range(&self.owned_worker_id, start, end)
+                .await;
+            for (idx, entry) in entries.into_iter().rev() {
+                if matches!(
+                    entry,
+                    OplogEntry::ExportedFunctionInvokedV1 { .. }
+                        | OplogEntry::ExportedFunctionInvoked { .. }
+                ) && !deleted_regions.is_in_deleted_region(idx)
+                {
+                    remaining -= 1;
+                    if remaining == 0 {
+                        return Ok(idx);
+                    }
+                }
+            }
+            end = start.previous();
+        }
+
+        Err(GolemError::invalid_request(format!(
+            "The worker has fewer than {n} invocations to revert"
+        )))
+    }
+
     pub async fn update_status(&self, status_value: WorkerStatusRecord) {
         // Need to make sure the oplog is committed, because the updated status stores the current
         // last oplog index as reference.
@@ -666,10 +983,24 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             .update_status(&self.owned_worker_id, &status_value, component_type)
             .await;
         // Updating the status in memory
-        self.execution_status
-            .write()
-            .unwrap()
-            .set_last_known_status(status_value);
+        let previous_status = {
+            let mut execution_status = self.execution_status.write().unwrap();
+            let previous_status = execution_status.last_known_status().status.clone();
+            execution_status.set_last_known_status(status_value.clone());
+            previous_status
+        };
+
+        // Status records are also written when only other parts of them change
+        if previous_status != status_value.status {
+            self.events().publish(Event::WorkerStatusChanged {
+                metadata: WorkerMetadata {
+                    last_known_status: status_value,
+                    ..self.initial_worker_metadata.clone()
+                },
+                previous_status,
+                timestamp: Timestamp::now_utc(),
+            });
+        }
     }
 
     /// Gets the estimated memory requirement of the worker
@@ -741,11 +1072,17 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        trace_context: Option<TraceContext>,
     ) {
         match &*self.instance.lock().await {
             WorkerInstance::Running(running) => {
                 running
-                    .enqueue(idempotency_key, full_function_name, function_input)
+                    .enqueue(
+                        idempotency_key,
+                        full_function_name,
+                        function_input,
+                        trace_context,
+                    )
                     .await;
             }
             WorkerInstance::Unloaded | WorkerInstance::WaitingForPermit(_) => {
@@ -754,6 +1091,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     idempotency_key,
                     full_function_name,
                     function_input,
+                    trace_context,
                 };
                 let entry = OplogEntry::pending_worker_invocation(invocation.clone());
                 let timestamped_invocation = TimestampedWorkerInvocation {
@@ -954,6 +1292,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         worker_args: Option<Vec<String>>,
         worker_env: Option<Vec<(String, String)>>,
         parent: Option<WorkerId>,
+        quotas: Option<WorkerQuotas>,
     ) -> Result<WorkerMetadata, GolemError> {
         match this.worker_service().get(owned_worker_id).await {
             None => {
@@ -972,6 +1311,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     account_id: owned_worker_id.account_id(),
                     created_at: Timestamp::now_utc(),
                     parent,
+                    quotas: quotas.unwrap_or_default(),
                     last_known_status: WorkerStatusRecord {
                         component_version: component_metadata.version,
                         component_size: component_metadata.size,
@@ -1001,23 +1341,78 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
     }
 
     pub async fn get_file_system_node(&self, path: &Path) -> std::io::Result<FileSystemNode> {
+        self.file_system_directories().await?.get_node(path).await
+    }
+
+    /// Creates or overwrites a file in the worker's file system, recording the change in the oplog
+    /// so it is re-applied when the worker gets recovered
+    pub async fn write_file(&self, path: String, content: Vec<u8>) -> Result<(), GolemError> {
+        self.file_system_directories()
+            .await
+            .map_err(file_system_error)?
+            .write_file(Path::new(&path), content.clone())
+            .await
+            .map_err(file_system_error)?;
+
+        let content = self
+            .oplog
+            .upload_payload(&content)
+            .await
+            .map_err(GolemError::runtime)?;
+        self.oplog
+            .add_and_commit(OplogEntry::change_file_system(
+                FileSystemChange::WriteFile { path, content },
+            ))
+            .await;
+        Ok(())
+    }
+
+    /// Deletes a file or a directory in the worker's file system, recording the change in the oplog
+    pub async fn delete_file(&self, path: String) -> Result<(), GolemError> {
+        self.file_system_directories()
+            .await
+            .map_err(file_system_error)?
+            .delete(Path::new(&path))
+            .await
+            .map_err(file_system_error)?;
+
+        self.oplog
+            .add_and_commit(OplogEntry::change_file_system(
+                FileSystemChange::DeleteFile { path },
+            ))
+            .await;
+        Ok(())
+    }
+
+    /// Creates a directory in the worker's file system, recording the change in the oplog
+    pub async fn make_directory(&self, path: String) -> Result<(), GolemError> {
+        self.file_system_directories()
+            .await
+            .map_err(file_system_error)?
+            .make_directory(Path::new(&path))
+            .await
+            .map_err(file_system_error)?;
+
+        self.oplog
+            .add_and_commit(OplogEntry::change_file_system(
+                FileSystemChange::MakeDirectory { path },
+            ))
+            .await;
+        Ok(())
+    }
+
+    async fn file_system_directories(&self) -> std::io::Result<FileSystemDirectories> {
         let mut recv = self.directories_recv.clone();
-        let directories = loop {
-            if let Some(directories) = recv
-                .borrow_and_update()
-                .as_ref()
-            {
-                break directories.clone();
+        loop {
+            if let Some(directories) = recv.borrow_and_update().as_ref() {
+                break Ok(directories.clone());
             }
 
             // Context is still initializing
-            recv.changed().await
+            recv.changed()
+                .await
                 .map_err(|_| std::io::ErrorKind::ConnectionAborted)?;
-        };
-
-        directories
-            .get_node(path)
-            .await
+        }
     }
 }
 
@@ -1162,11 +1557,13 @@ impl RunningWorker {
         idempotency_key: IdempotencyKey,
         full_function_name: String,
         function_input: Vec<Value>,
+        trace_context: Option<TraceContext>,
     ) {
         let invocation = WorkerInvocation::ExportedFunction {
             idempotency_key,
             full_function_name,
             function_input,
+            trace_context,
         };
         self.enqueue_worker_invocation(invocation).await;
     }
@@ -1242,6 +1639,8 @@ impl RunningWorker {
             parent.oplog.clone(),
             Arc::downgrade(&parent),
             parent.scheduler_service(),
+            parent.pubsub_service(),
+            parent.lock_service(),
             parent.rpc(),
             parent.worker_proxy(),
             parent.component_service(),
@@ -1262,6 +1661,7 @@ impl RunningWorker {
         let mut store = Store::new(&parent.engine(), context);
         store.set_epoch_deadline(parent.config().limits.epoch_ticks);
         let worker_id_clone = worker_metadata.worker_id.clone();
+        let quota_tracker = parent.quota_tracker();
         store.epoch_deadline_callback(move |mut store| {
             let current_level = store.get_fuel().unwrap_or(0);
             if store.data().is_out_of_fuel(current_level as i64) {
@@ -1269,6 +1669,11 @@ impl RunningWorker {
                 store.data_mut().borrow_fuel_sync();
             }
 
+            if store.data().is_live() {
+                quota_tracker.check_fuel(current_level)?;
+                quota_tracker.check_oplog()?;
+            }
+
             match store.data_mut().check_interrupt() {
                 Some(kind) => Err(kind.into()),
                 None => Ok(UpdateDeadline::Yield(1)),
@@ -1398,11 +1803,18 @@ impl RunningWorker {
                             let mut store_mutex = store.lock().await;
                             let store = store_mutex.deref_mut();
 
+                            let trace_context = message.invocation.trace_context().cloned();
                             match message.invocation {
-                                WorkerInvocation::ExportedFunction {
+                                WorkerInvocation::ExportedFunctionV1 {
+                                    idempotency_key: invocation_key,
+                                    full_function_name,
+                                    function_input,
+                                }
+                                | WorkerInvocation::ExportedFunction {
                                     idempotency_key: invocation_key,
                                     full_function_name,
                                     function_input,
+                                    ..
                                 } => {
                                     let span = span!(
                                         Level::INFO,
@@ -1411,11 +1823,18 @@ impl RunningWorker {
                                         idempotency_key = invocation_key.to_string(),
                                         function = full_function_name
                                     );
+                                    if let Some(trace_context) = &trace_context {
+                                        set_span_trace_context(&span, trace_context);
+                                    }
                                     let do_break = async {
                                         store
                                             .data_mut()
                                             .set_current_idempotency_key(invocation_key)
                                             .await;
+                                        store
+                                            .data_mut()
+                                            .set_current_trace_context(trace_context)
+                                            .await;
 
                                         if let Some(idempotency_key) =
                                             &store.data().get_current_idempotency_key().await
@@ -1455,10 +1874,11 @@ impl RunningWorker {
 
                                                 match function_results {
                                                     Ok(Some(export_function)) => {
-                                                        let function_results = export_function
-                                                            .results
-                                                            .into_iter()
-                                                            .collect();
+                                                        let function_results =
+                                                            expected_function_results(
+                                                                &full_function_name,
+                                                                export_function,
+                                                            );
 
                                                         let result = interpret_function_results(
                                                             output,
@@ -1491,6 +1911,10 @@ impl RunningWorker {
                                                                         RetryDecision::None;
                                                                     true // stop after the invocation
                                                                 } else {
+                                                                    Ctx::snapshot_if_needed(
+                                                                        &instance, store,
+                                                                    )
+                                                                    .await;
                                                                     false // continue processing the queue
                                                                 }
                                                             }
@@ -1753,7 +2177,7 @@ impl RunningWorker {
     }
 
     /// Attempts to interpret the save snapshot result as a byte vector
-    fn decode_snapshot_result(values: Vec<Value>) -> Option<Vec<u8>> {
+    pub(crate) fn decode_snapshot_result(values: Vec<Value>) -> Option<Vec<u8>> {
         if values.len() == 1 {
             if let Value::List(bytes) = &values[0] {
                 let mut result = Vec::new();
@@ -1965,6 +2389,12 @@ where
     }
 }
 
+fn file_system_error(error: std::io::Error) -> GolemError {
+    GolemError::FileSystem {
+        details: error.to_string(),
+    }
+}
+
 fn calculate_latest_worker_status(
     initial: &WorkerStatus,
     default_retry_policy: &RetryConfig,
@@ -1980,6 +2410,9 @@ fn calculate_latest_worker_status(
         }
 
         match entry {
+            OplogEntry::CreateV1 { .. } => {
+                result = WorkerStatus::Idle;
+            }
             OplogEntry::Create { .. } => {
                 result = WorkerStatus::Idle;
             }
@@ -1989,6 +2422,9 @@ fn calculate_latest_worker_status(
             OplogEntry::ImportedFunctionInvoked { .. } => {
                 result = WorkerStatus::Running;
             }
+            OplogEntry::ExportedFunctionInvokedV1 { .. } => {
+                result = WorkerStatus::Running;
+            }
             OplogEntry::ExportedFunctionInvoked { .. } => {
                 result = WorkerStatus::Running;
             }
@@ -2059,6 +2495,8 @@ fn calculate_latest_worker_status(
             OplogEntry::Restart { .. } => {
                 result = WorkerStatus::Idle;
             }
+            OplogEntry::ChangeFileSystem { .. } => {}
+            OplogEntry::Snapshot { .. } => {}
         }
     }
     result
@@ -2069,9 +2507,19 @@ fn calculate_deleted_regions(
     entries: &BTreeMap<OplogIndex, OplogEntry>,
 ) -> DeletedRegions {
     let mut builder = DeletedRegionsBuilder::from_regions(initial.into_regions());
-    for entry in entries.values() {
-        if let OplogEntry::Jump { jump, .. } = entry {
-            builder.add(jump.clone());
+    for (idx, entry) in entries {
+        match entry {
+            OplogEntry::Jump { jump, .. } => {
+                builder.add(jump.clone());
+            }
+            OplogEntry::Snapshot { .. } if *idx > OplogIndex::INITIAL.next() => {
+                // The recovery starts by loading the snapshot, skipping everything but the
+                // initial create entry
+                builder.add(OplogRegion::from_index_range(
+                    OplogIndex::INITIAL.next()..=idx.previous(),
+                ));
+            }
+            _ => {}
         }
     }
     builder.build()
@@ -2107,19 +2555,14 @@ fn calculate_pending_invocations(
                     invocation: invocation.clone(),
                 });
             }
-            OplogEntry::ExportedFunctionInvoked {
+            OplogEntry::ExportedFunctionInvokedV1 {
+                idempotency_key, ..
+            }
+            | OplogEntry::ExportedFunctionInvoked {
                 idempotency_key, ..
             } => {
-                result.retain(|invocation| match invocation {
-                    TimestampedWorkerInvocation {
-                        invocation:
-                            WorkerInvocation::ExportedFunction {
-                                idempotency_key: key,
-                                ..
-                            },
-                        ..
-                    } => key != idempotency_key,
-                    _ => true,
+                result.retain(|invocation| {
+                    !invocation.invocation.is_idempotency_key(idempotency_key)
                 });
             }
             OplogEntry::PendingUpdate {
@@ -2163,6 +2606,11 @@ fn calculate_update_fields(
     let mut component_size = initial_component_size;
     for (oplog_idx, entry) in entries {
         match entry {
+            OplogEntry::CreateV1 {
+                component_version, ..
+            } => {
+                version = *component_version;
+            }
             OplogEntry::Create {
                 component_version, ..
             } => {
@@ -2223,13 +2671,19 @@ fn calculate_invocation_results(
 ) -> (HashMap<IdempotencyKey, OplogIndex>, Option<IdempotencyKey>) {
     let mut invocation_results = invocation_results;
     let mut current_idempotency_key = current_idempotency_key;
+    // Not known if the current invocation started before the given entries
+    let mut current_invocation_start = None;
 
     for (oplog_idx, entry) in entries {
         match entry {
-            OplogEntry::ExportedFunctionInvoked {
+            OplogEntry::ExportedFunctionInvokedV1 {
+                idempotency_key, ..
+            }
+            | OplogEntry::ExportedFunctionInvoked {
                 idempotency_key, ..
             } => {
                 current_idempotency_key = Some(idempotency_key.clone());
+                current_invocation_start = Some(*oplog_idx);
             }
             OplogEntry::ExportedFunctionCompleted { .. } => {
                 if let Some(idempotency_key) = &current_idempotency_key {
@@ -2247,6 +2701,16 @@ fn calculate_invocation_results(
                     invocation_results.insert(idempotency_key.clone(), *oplog_idx);
                 }
             }
+            OplogEntry::Jump { jump, .. } => {
+                // Results recorded in a skipped region are no longer valid, so the same idempotency
+                // keys can be used again for new invocations
+                invocation_results.retain(|_, result_idx| !jump.contains(*result_idx));
+                // A skipped invocation is no longer the current one
+                if current_invocation_start.map_or(true, |start| jump.contains(start)) {
+                    current_idempotency_key = None;
+                    current_invocation_start = None;
+                }
+            }
             _ => {}
         }
     }
@@ -2311,9 +2775,26 @@ pub fn is_worker_error_retriable(
         WorkerError::InvalidRequest(_) => false,
         WorkerError::StackOverflow => false,
         WorkerError::OutOfMemory => true,
+        WorkerError::QuotaExceeded { .. } => false,
     }
 }
 
 fn is_running_worker_idle(running: &RunningWorker) -> bool {
     running.waiting_for_command.load(Ordering::Acquire) && running.queue.read().unwrap().is_empty()
 }
+
+/// The results an invocation's output is interpreted against. The wasi:http incoming-handler
+/// has no results of its own; its response is captured from the response-outparam instead.
+fn expected_function_results(
+    full_function_name: &str,
+    export_function: AnalysedFunction,
+) -> Vec<AnalysedFunctionResult> {
+    if full_function_name == INCOMING_HTTP_HANDLER_FUNCTION {
+        vec![AnalysedFunctionResult {
+            name: None,
+            typ: OutgoingHttpResponse::get_type(),
+        }]
+    } else {
+        export_function.results
+    }
+}
@@ -23,11 +23,17 @@ use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::wasmtime::ResourceStore;
 use golem_wasm_rpc::Value;
 use itertools::Itertools as _;
+use tokio::sync::oneshot;
 use tokio_stream::StreamExt as _;
 use tonic::Status;
+use wasmtime::component::Resource;
 use wasmtime::{AsContextMut, ResourceLimiterAsync};
+use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::body::{HyperIncomingBody, HyperOutgoingBody};
+use wasmtime_wasi_http::types::{HostIncomingRequest, HostResponseOutparam};
 
 use golem_common::model::oplog::WorkerResourceId;
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{
     AccountId, ComponentVersion, IdempotencyKey, OwnedWorkerId, WorkerId, WorkerMetadata,
     WorkerStatus, WorkerStatusRecord,
@@ -43,8 +49,10 @@ use crate::services::blob_store::BlobStoreService;
 use crate::services::component::{ComponentMetadata, ComponentService};
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
+use crate::services::lock::LockService;
 use crate::services::oplog::{Oplog, OplogService};
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rpc::Rpc;
 use crate::services::scheduler::SchedulerService;
 use crate::services::worker::WorkerService;
@@ -67,6 +75,7 @@ pub trait WorkerCtx:
     + ExternalOperations<Self>
     + ResourceStore
     + IndexedResourceStore
+    + HttpRequestHandling
     + UpdateManagement
     + Send
     + Sync
@@ -91,6 +100,8 @@ pub trait WorkerCtx:
     /// - `active_workers`: The service for managing active workers
     /// - `oplog_service`: The service for reading and writing the oplog
     /// - `scheduler_service`: The scheduler implementation responsible for waking up suspended workers
+    /// - `pubsub_service`: The service for publishing to and subscribing to topics
+    /// - `lock_service`: The service for acquiring and releasing locks and semaphores
     /// - `recovery_management`: The service for deciding if a worker should be recovered
     /// - `rpc`: The RPC implementation used for worker to worker communication
     /// - `worker_proyx`: Access to the worker proxy above the worker executor cluster
@@ -115,6 +126,8 @@ pub trait WorkerCtx:
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Weak<Worker<Self>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         component_service: Arc<dyn ComponentService + Send + Sync>,
@@ -201,6 +214,12 @@ pub trait InvocationManagement {
     /// Gets the invocation key associated with the current invocation of the worker.
     async fn get_current_idempotency_key(&self) -> Option<IdempotencyKey>;
 
+    /// Sets the trace context of the current invocation of the worker, if its caller provided one.
+    async fn set_current_trace_context(&mut self, trace_context: Option<TraceContext>);
+
+    /// Gets the trace context of the current invocation of the worker.
+    async fn get_current_trace_context(&self) -> Option<TraceContext>;
+
     /// Returns whether we are in live mode where we are executing new calls.
     fn is_live(&self) -> bool;
 
@@ -319,6 +338,29 @@ pub trait IndexedResourceStore {
     fn drop_indexed_resource(&mut self, resource_name: &str, resource_params: &[String]);
 }
 
+/// A request to be passed to an exported `wasi:http/incoming-handler`
+pub type HttpIncomingRequest = hyper::Request<HyperIncomingBody>;
+
+/// The sending side of a `response-outparam` created by `HttpRequestHandling`
+pub type HttpResponseSender =
+    oneshot::Sender<Result<hyper::Response<HyperOutgoingBody>, ErrorCode>>;
+
+/// Creates the host resources required to directly invoke an exported `wasi:http/incoming-handler`
+/// with a request that has not been made through the worker's own WASI HTTP implementation.
+pub trait HttpRequestHandling {
+    /// Stores the request in the worker's resource table as an `incoming-request`
+    fn new_incoming_http_request(
+        &mut self,
+        request: HttpIncomingRequest,
+    ) -> anyhow::Result<Resource<HostIncomingRequest>>;
+
+    /// Creates a `response-outparam` which sends the response set by the worker to `sender`
+    fn new_http_response_outparam(
+        &mut self,
+        sender: HttpResponseSender,
+    ) -> anyhow::Result<Resource<HostResponseOutparam>>;
+}
+
 /// Operations not requiring an active worker context, but still depending on the
 /// worker context implementation.
 #[async_trait]
@@ -351,6 +393,13 @@ pub trait ExternalOperations<Ctx: WorkerCtx> {
         store: &mut (impl AsContextMut<Data = Ctx> + Send),
     ) -> Result<RetryDecision, GolemError>;
 
+    /// Called after a successful invocation of a live worker. It can be used to take a snapshot
+    /// of the worker's state, so its recovery does not have to replay the whole oplog.
+    async fn snapshot_if_needed(
+        instance: &wasmtime::component::Instance,
+        store: &mut (impl AsContextMut<Data = Ctx> + Send),
+    );
+
     /// Records the last known resource limits of a worker without activating it
     async fn record_last_known_limits<T: HasAll<Ctx> + Send + Sync>(
         this: &T,
@@ -27,20 +27,25 @@ use assert2::check;
 use http_02::{Response, StatusCode};
 use redis::Commands;
 
-use golem_api_grpc::proto::golem::worker::v1::{worker_execution_error, ComponentParseFailed};
+use golem_api_grpc::proto::golem::worker::v1::{
+    worker_execution_error, ComponentParseFailed, WatchWorkersRequest,
+};
 use golem_api_grpc::proto::golem::worker::LogEvent;
 use golem_api_grpc::proto::golem::workerexecutor::v1::CompletePromiseRequest;
 use golem_common::model::{
-    AccountId, ComponentId, FilterComparator, IdempotencyKey, PromiseId, ScanCursor,
-    StringFilterComparator, TargetWorkerId, Timestamp, WorkerFilter, WorkerId, WorkerMetadata,
-    WorkerResourceDescription, WorkerStatus,
+    AccountId, ComponentId, FilterComparator, IdempotencyKey, PromiseId, RevertLastInvocations,
+    RevertToOplogIndex, RevertWorkerTarget, ScanCursor, StringFilterComparator, TargetWorkerId,
+    Timestamp, WorkerFilter, WorkerId, WorkerMetadata, WorkerResourceDescription, WorkerStatus,
+    WorkerStatusChange,
 };
 use golem_wasm_rpc::Value;
+use golem_worker_executor_base::services::golem_config::SnapshotConfig;
 
-use crate::common::{start, TestContext, TestWorkerExecutor};
+use crate::common::{start, start_customized, TestContext, TestWorkerExecutor};
 use crate::compatibility::worker_recovery::save_recovery_golden_file;
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use golem_common::model::oplog::{IndexedResourceKey, OplogIndex, WorkerResourceId};
+use golem_common::model::public_oplog::PublicOplogEntry;
 use golem_test_framework::config::TestDependencies;
 use golem_test_framework::dsl::{
     drain_connection, is_worker_execution_error, stdout_event_matching, stdout_events,
@@ -822,6 +827,67 @@ async fn invoking_with_same_idempotency_key_is_idempotent_after_restart(
     );
 }
 
+#[test]
+#[tracing::instrument]
+async fn recovery_starts_from_latest_snapshot(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let snapshot = SnapshotConfig {
+        enabled: true,
+        max_oplog_entries_between_snapshots: 1,
+        ..Default::default()
+    };
+    let executor = start_customized(deps, &context, None, snapshot.clone())
+        .await
+        .unwrap();
+
+    let component_id = executor.store_unique_component("update-test-v3").await;
+    let worker_id = executor
+        .start_worker(&component_id, "recovery-from-snapshot-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:component/api.{set}",
+            vec![Value::U64(11)],
+        )
+        .await
+        .unwrap();
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:component/api.{set}",
+            vec![Value::U64(22)],
+        )
+        .await
+        .unwrap();
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+
+    drop(executor);
+    let executor = start_customized(deps, &context, None, snapshot)
+        .await
+        .unwrap();
+
+    let result = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
+        .await
+        .unwrap();
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+
+    drop(executor);
+
+    check!(oplog
+        .iter()
+        .any(|entry| matches!(entry, PublicOplogEntry::Snapshot(_))));
+    check!(!metadata.last_known_status.deleted_regions.is_empty());
+    check!(result == vec![Value::U64(22)]);
+}
+
 #[test]
 #[tracing::instrument]
 async fn optional_parameters(
@@ -1159,6 +1225,65 @@ async fn get_workers(
     get_check(&component_id, None, 0, &mut executor).await;
 }
 
+#[test]
+#[tracing::instrument]
+async fn watch_workers(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("option-service").await;
+
+    let mut changes = executor
+        .worker_service()
+        .watch_workers(WatchWorkersRequest {
+            component_id: Some(component_id.clone().into()),
+            filter: Some(
+                WorkerFilter::new_name(StringFilterComparator::Equal, "watched-worker".to_string())
+                    .into(),
+            ),
+        })
+        .await
+        .unwrap();
+
+    let other_worker_id = executor.start_worker(&component_id, "other-worker").await;
+    let worker_id = executor.start_worker(&component_id, "watched-worker").await;
+
+    for worker_id in [&other_worker_id, &worker_id] {
+        let _ = executor
+            .invoke_and_await(
+                worker_id,
+                "golem:it/api.{echo}",
+                vec![Value::Option(Some(Box::new(Value::String(
+                    "Hello".to_string(),
+                ))))],
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut statuses = Vec::new();
+    while !statuses.contains(&WorkerStatus::Idle) {
+        let change: WorkerStatusChange = changes
+            .message()
+            .await
+            .unwrap()
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        check!(change.worker_id == worker_id);
+        statuses.push(change.status);
+    }
+
+    drop(executor);
+
+    check!(statuses.contains(&WorkerStatus::Running));
+}
+
 #[test]
 #[tracing::instrument]
 async fn error_handling_when_worker_is_invoked_with_fewer_than_expected_parameters(
@@ -2722,3 +2847,311 @@ async fn stderr_returned_for_failed_component(
     check!(all[0].1.is_some());
     check!(all[0].1.clone().unwrap().ends_with(&expected_stderr));
 }
+
+#[test]
+#[tracing::instrument]
+async fn revert_last_invocations(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "revert-last-invocations-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    for (product_id, name) in [("G1000", "Golem T-Shirt M"), ("G1001", "Mud Golem")] {
+        let _ = executor
+            .invoke_and_await(
+                &worker_id,
+                "golem:it/api.{add-item}",
+                vec![Value::Record(vec![
+                    Value::String(product_id.to_string()),
+                    Value::String(name.to_string()),
+                    Value::F32(100.0),
+                    Value::U32(1),
+                ])],
+            )
+            .await;
+    }
+
+    let invalid = golem_test_framework::dsl::TestDsl::revert(
+        &executor,
+        &worker_id,
+        RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+            number_of_invocations: 0,
+        }),
+    )
+    .await;
+
+    executor
+        .revert(
+            &worker_id,
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations: 1,
+            }),
+        )
+        .await;
+
+    let contents = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+
+    drop(executor);
+
+    check!(invalid.is_err());
+    check!(
+        contents
+            == Ok(vec![Value::List(vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(1),
+            ])])])
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn revert_to_oplog_index(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "revert-to-oplog-index-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+    let last_index = OplogIndex::from_u64(oplog.len() as u64);
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(1),
+            ])],
+        )
+        .await;
+
+    let beyond_end = golem_test_framework::dsl::TestDsl::revert(
+        &executor,
+        &worker_id,
+        RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+            last_oplog_index: OplogIndex::from_u64(10_000),
+        }),
+    )
+    .await;
+
+    executor
+        .revert(
+            &worker_id,
+            RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+                last_oplog_index: last_index,
+            }),
+        )
+        .await;
+
+    let contents = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+
+    // Invocations after the revert are recorded after the jump and survive a recovery
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1001".to_string()),
+                Value::String("Mud Golem".to_string()),
+                Value::F32(11.0),
+                Value::U32(2),
+            ])],
+        )
+        .await;
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    let contents_after_recovery = executor
+        .invoke_and_await(&worker_id, "golem:it/api.{get-cart-contents}", vec![])
+        .await;
+
+    drop(executor);
+
+    check!(beyond_end.is_err());
+    check!(contents == Ok(vec![Value::List(vec![])]));
+    check!(
+        contents_after_recovery
+            == Ok(vec![Value::List(vec![Value::Record(vec![
+                Value::String("G1001".to_string()),
+                Value::String("Mud Golem".to_string()),
+                Value::F32(11.0),
+                Value::U32(2),
+            ])])])
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn fork_rejects_cutoff_in_reverted_region(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("shopping-cart").await;
+    let worker_id = executor
+        .start_worker(&component_id, "fork-reverted-region-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec![Value::String("test-user-1".to_string())],
+        )
+        .await;
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+    let last_index = OplogIndex::from_u64(oplog.len() as u64);
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{add-item}",
+            vec![Value::Record(vec![
+                Value::String("G1000".to_string()),
+                Value::String("Golem T-Shirt M".to_string()),
+                Value::F32(100.0),
+                Value::U32(1),
+            ])],
+        )
+        .await;
+
+    executor
+        .revert(
+            &worker_id,
+            RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+                last_oplog_index: last_index,
+            }),
+        )
+        .await;
+
+    let in_reverted_region = executor
+        .fork(
+            &worker_id,
+            &WorkerId {
+                component_id: component_id.clone(),
+                worker_name: "fork-reverted-region-2".to_string(),
+            },
+            last_index.next(),
+        )
+        .await;
+
+    let before_reverted_region = executor
+        .fork(
+            &worker_id,
+            &WorkerId {
+                component_id: component_id.clone(),
+                worker_name: "fork-reverted-region-3".to_string(),
+            },
+            last_index,
+        )
+        .await;
+
+    drop(executor);
+
+    check!(in_reverted_region.is_err());
+    check!(before_reverted_region.is_ok());
+}
+
+#[test]
+#[tracing::instrument]
+async fn dead_lettered_invocation_survives_worker_reload(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("failing-component").await;
+    executor.set_dead_letter_policy(&component_id, true).await;
+    let worker_id = executor
+        .start_worker(&component_id, "dead-letter-reload-1")
+        .await;
+    let idempotency_key = IdempotencyKey::fresh();
+
+    let result1 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{add}", vec![Value::U64(5)])
+        .await;
+    let result2 = executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &idempotency_key,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+        )
+        .await;
+    let result3 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{add}", vec![Value::U64(3)])
+        .await;
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    // The worker recovers without the skipped invocation, and the dead-lettered idempotency key
+    // still refers to the failed invocation instead of running it again
+    let result4 = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{get}", vec![])
+        .await;
+    let result5 = executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &idempotency_key,
+            "golem:component/api.{add}",
+            vec![Value::U64(50)],
+        )
+        .await;
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    let dead_letters = executor.get_dead_lettered_invocations(&worker_id).await;
+
+    drop(executor);
+
+    check!(result1.is_ok());
+    check!(result2.is_err());
+    check!(result3.is_ok());
+    check!(result4 == Ok(vec![Value::U64(8)]));
+    check!(result5.is_err());
+    check!(metadata.last_known_status.status != WorkerStatus::Failed);
+    check!(metadata.last_known_status.current_idempotency_key.is_none());
+    check!(dead_letters.len() == 1);
+    check!(dead_letters[0].idempotency_key == Some(idempotency_key.into()));
+}
@@ -21,7 +21,7 @@ use golem_worker_executor_base::services::golem_config::{
     BlobStorageConfig, CompiledComponentServiceConfig, CompiledComponentServiceEnabledConfig,
     ComponentServiceConfig, ComponentServiceLocalConfig, GolemConfig, IndexedStorageConfig,
     KeyValueStorageConfig, LocalFileSystemBlobStorageConfig, MemoryConfig,
-    ShardManagerServiceConfig, WorkerServiceGrpcConfig,
+    ShardManagerServiceConfig, SnapshotConfig, WorkerServiceGrpcConfig,
 };
 
 use golem_worker_executor_base::durable_host::{
@@ -33,9 +33,12 @@ use golem_worker_executor_base::model::{
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
 use golem_worker_executor_base::services::component::{ComponentMetadata, ComponentService};
+use golem_worker_executor_base::services::dead_letter::DeadLetterService;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::lock::LockService;
 use golem_worker_executor_base::services::oplog::{Oplog, OplogService};
 use golem_worker_executor_base::services::promise::PromiseService;
+use golem_worker_executor_base::services::pubsub::PubSubService;
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::shard::ShardService;
 use golem_worker_executor_base::services::shard_manager::ShardManagerService;
@@ -45,8 +48,9 @@ use golem_worker_executor_base::services::worker_event::WorkerEventService;
 use golem_worker_executor_base::services::{All, HasAll, HasConfig, HasOplogService};
 use golem_worker_executor_base::wasi_host::create_linker;
 use golem_worker_executor_base::workerctx::{
-    ExternalOperations, FuelManagement, IndexedResourceStore, InvocationHooks,
-    InvocationManagement, StatusManagement, UpdateManagement, WorkerCtx,
+    ExternalOperations, FuelManagement, HttpIncomingRequest, HttpRequestHandling,
+    HttpResponseSender, IndexedResourceStore, InvocationHooks, InvocationManagement,
+    StatusManagement, UpdateManagement, WorkerCtx,
 };
 use golem_worker_executor_base::Bootstrap;
 
@@ -57,12 +61,17 @@ use tokio::task::JoinHandle;
 use golem::api0_2_0;
 use golem_common::config::RedisConfig;
 
+use golem_api_grpc::proto::golem::worker::v1::WorkerExecutionError;
+use golem_api_grpc::proto::golem::worker::{DeadLetterPolicy, DeadLetteredInvocation};
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
+    fork_worker_response, get_dead_lettered_invocations_response,
     get_running_workers_metadata_response, get_workers_metadata_response,
+    set_dead_letter_policy_response, ForkWorkerRequest, GetDeadLetteredInvocationsRequest,
     GetRunningWorkersMetadataRequest, GetRunningWorkersMetadataSuccessResponse,
-    GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse,
+    GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse, SetDeadLetterPolicyRequest,
 };
-use golem_common::model::oplog::WorkerResourceId;
+use golem_common::model::oplog::{OplogIndex, WorkerResourceId};
+use golem_common::model::trace_context::TraceContext;
 use golem_test_framework::components::component_compilation_service::ComponentCompilationService;
 use golem_test_framework::components::rdb::Rdb;
 use golem_test_framework::components::redis::Redis;
@@ -84,8 +93,9 @@ use golem_worker_executor_base::services::worker_proxy::WorkerProxy;
 use golem_worker_executor_base::worker::{RetryDecision, Worker};
 use tonic::transport::Channel;
 use tracing::{debug, error, info};
-use wasmtime::component::{Instance, Linker, ResourceAny};
+use wasmtime::component::{Instance, Linker, Resource, ResourceAny};
 use wasmtime::{AsContextMut, Engine, ResourceLimiterAsync};
+use wasmtime_wasi_http::types::{HostIncomingRequest, HostResponseOutparam};
 
 pub struct TestWorkerExecutor {
     handle: Option<JoinHandle<Result<(), String>>>,
@@ -172,6 +182,92 @@ impl TestWorkerExecutor {
             }
         }
     }
+
+    pub async fn fork(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+    ) -> Result<(), WorkerExecutionError> {
+        let response = self
+            .client()
+            .await
+            .expect("Failed to get client")
+            .fork_worker(ForkWorkerRequest {
+                source_worker_id: Some(source_worker_id.clone().into()),
+                target_worker_id: Some(target_worker_id.clone().into()),
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+                oplog_index_cutoff: oplog_index_cutoff.into(),
+            })
+            .await
+            .expect("Failed to fork worker")
+            .into_inner();
+
+        match response.result {
+            None => panic!("No response from fork_worker"),
+            Some(fork_worker_response::Result::Success(_)) => Ok(()),
+            Some(fork_worker_response::Result::Failure(error)) => Err(error),
+        }
+    }
+
+    pub async fn set_dead_letter_policy(&self, component_id: &ComponentId, enabled: bool) {
+        let response = self
+            .client()
+            .await
+            .expect("Failed to get client")
+            .set_dead_letter_policy(SetDeadLetterPolicyRequest {
+                component_id: Some(component_id.clone().into()),
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+                policy: Some(DeadLetterPolicy { enabled }),
+            })
+            .await
+            .expect("Failed to set dead-letter policy")
+            .into_inner();
+
+        match response.result {
+            Some(set_dead_letter_policy_response::Result::Success(_)) => {}
+            other => panic!("Failed to set dead-letter policy: {other:?}"),
+        }
+    }
+
+    pub async fn get_dead_lettered_invocations(
+        &self,
+        worker_id: &WorkerId,
+    ) -> Vec<DeadLetteredInvocation> {
+        let response = self
+            .client()
+            .await
+            .expect("Failed to get client")
+            .get_dead_lettered_invocations(GetDeadLetteredInvocationsRequest {
+                worker_id: Some(worker_id.clone().into()),
+                account_id: Some(
+                    AccountId {
+                        value: "test-account".to_string(),
+                    }
+                    .into(),
+                ),
+            })
+            .await
+            .expect("Failed to get dead-lettered invocations")
+            .into_inner();
+
+        match response.result {
+            Some(get_dead_lettered_invocations_response::Result::Success(success)) => {
+                success.invocations
+            }
+            other => panic!("Failed to get dead-lettered invocations: {other:?}"),
+        }
+    }
 }
 
 impl Clone for TestWorkerExecutor {
@@ -281,6 +377,21 @@ pub async fn start_limited(
     deps: &WorkerExecutorTestDependencies,
     context: &TestContext,
     system_memory_override: Option<u64>,
+) -> anyhow::Result<TestWorkerExecutor> {
+    start_customized(
+        deps,
+        context,
+        system_memory_override,
+        SnapshotConfig::default(),
+    )
+    .await
+}
+
+pub async fn start_customized(
+    deps: &WorkerExecutorTestDependencies,
+    context: &TestContext,
+    system_memory_override: Option<u64>,
+    snapshot: SnapshotConfig,
 ) -> anyhow::Result<TestWorkerExecutor> {
     let redis = deps.redis();
     let redis_monitor = deps.redis_monitor();
@@ -317,6 +428,7 @@ pub async fn start_limited(
             system_memory_override,
             ..Default::default()
         },
+        snapshot,
         ..Default::default()
     };
 
@@ -457,6 +569,13 @@ impl ExternalOperations<TestWorkerCtx> for TestWorkerCtx {
         DurableWorkerCtx::<TestWorkerCtx>::prepare_instance(worker_id, instance, store).await
     }
 
+    async fn snapshot_if_needed(
+        instance: &Instance,
+        store: &mut (impl AsContextMut<Data = TestWorkerCtx> + Send),
+    ) {
+        DurableWorkerCtx::<TestWorkerCtx>::snapshot_if_needed(instance, store).await
+    }
+
     async fn record_last_known_limits<T: HasAll<TestWorkerCtx> + Send + Sync>(
         this: &T,
         account_id: &AccountId,
@@ -494,6 +613,16 @@ impl InvocationManagement for TestWorkerCtx {
         self.durable_ctx.get_current_idempotency_key().await
     }
 
+    async fn set_current_trace_context(&mut self, trace_context: Option<TraceContext>) {
+        self.durable_ctx
+            .set_current_trace_context(trace_context)
+            .await
+    }
+
+    async fn get_current_trace_context(&self) -> Option<TraceContext> {
+        self.durable_ctx.get_current_trace_context().await
+    }
+
     fn is_live(&self) -> bool {
         self.durable_ctx.is_live()
     }
@@ -615,6 +744,22 @@ impl UpdateManagement for TestWorkerCtx {
 
 struct ServerBootstrap {}
 
+impl HttpRequestHandling for TestWorkerCtx {
+    fn new_incoming_http_request(
+        &mut self,
+        request: HttpIncomingRequest,
+    ) -> anyhow::Result<Resource<HostIncomingRequest>> {
+        self.durable_ctx.new_incoming_http_request(request)
+    }
+
+    fn new_http_response_outparam(
+        &mut self,
+        sender: HttpResponseSender,
+    ) -> anyhow::Result<Resource<HostResponseOutparam>> {
+        self.durable_ctx.new_http_response_outparam(sender)
+    }
+}
+
 #[async_trait]
 impl WorkerCtx for TestWorkerCtx {
     type PublicState = PublicDurableWorkerState<TestWorkerCtx>;
@@ -633,6 +778,8 @@ impl WorkerCtx for TestWorkerCtx {
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Weak<Worker<TestWorkerCtx>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         component_service: Arc<dyn ComponentService + Send + Sync>,
@@ -654,6 +801,8 @@ impl WorkerCtx for TestWorkerCtx {
             oplog,
             invocation_queue,
             scheduler_service,
+            pubsub_service,
+            lock_service,
             rpc,
             worker_proxy,
             component_service,
@@ -762,6 +911,9 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        dead_letter_service: Arc<dyn DeadLetterService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
     ) -> anyhow::Result<All<TestWorkerCtx>> {
@@ -786,6 +938,9 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             blob_store_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            dead_letter_service.clone(),
+            pubsub_service.clone(),
+            lock_service.clone(),
             worker_activator.clone(),
             events.clone(),
             (),
@@ -808,6 +963,9 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             oplog_service,
             rpc,
             scheduler_service,
+            dead_letter_service,
+            pubsub_service,
+            lock_service,
             worker_activator,
             worker_proxy,
             events.clone(),
@@ -819,6 +977,10 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         let mut linker = create_linker(engine, get_durable_ctx)?;
         api0_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         api1_1_0_rc1::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem::ext::scheduling::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem::ext::promise::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem::ext::pubsub::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem::ext::lock::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem::rpc::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         Ok(linker)
     }
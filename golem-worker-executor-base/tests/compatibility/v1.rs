@@ -265,7 +265,7 @@ pub fn wasm_rpc_value() {
 pub fn timestamped_worker_invocation() {
     let twi1 = TimestampedWorkerInvocation {
         timestamp: Timestamp::from(1724701938466),
-        invocation: WorkerInvocation::ExportedFunction {
+        invocation: WorkerInvocation::ExportedFunctionV1 {
             idempotency_key: IdempotencyKey {
                 value: "idempotency_key".to_string(),
             },
@@ -656,7 +656,7 @@ pub fn log_level() {
 
 #[test]
 pub fn oplog_entry() {
-    let oe1a = OplogEntry::Create {
+    let oe1a = OplogEntry::CreateV1 {
         timestamp: Timestamp::from(1724701938466),
         worker_id: WorkerId {
             component_id: ComponentId(
@@ -677,7 +677,7 @@ pub fn oplog_entry() {
         component_size: 100_000_000,
         initial_total_linear_memory_size: 100_000_000,
     };
-    let oe1b = OplogEntry::Create {
+    let oe1b = OplogEntry::CreateV1 {
         timestamp: Timestamp::from(1724701938466),
         worker_id: WorkerId {
             component_id: ComponentId(
@@ -711,7 +711,7 @@ pub fn oplog_entry() {
         wrapped_function_type: WrappedFunctionType::ReadLocal,
     };
 
-    let oe3 = OplogEntry::ExportedFunctionInvoked {
+    let oe3 = OplogEntry::ExportedFunctionInvokedV1 {
         timestamp: Timestamp::from(1724701938466),
         function_name: "test:pkg/iface.{fn}".to_string(),
         request: OplogPayload::Inline(vec![0, 1, 2, 3, 4]),
@@ -780,7 +780,7 @@ pub fn oplog_entry() {
 
     let oe16 = OplogEntry::PendingWorkerInvocation {
         timestamp: Timestamp::from(1724701938466),
-        invocation: WorkerInvocation::ExportedFunction {
+        invocation: WorkerInvocation::ExportedFunctionV1 {
             idempotency_key: IdempotencyKey {
                 value: "idempotency_key".to_string(),
             },
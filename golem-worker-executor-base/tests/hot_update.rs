@@ -17,6 +17,8 @@ use test_r::{inherit_test_dep, test};
 use crate::{common, LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use assert2::check;
 use async_mutex::Mutex;
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::{RevertToOplogIndex, RevertWorkerTarget};
 use golem_test_framework::dsl::TestDslUnsafe;
 use golem_wasm_rpc::Value;
 use http_02::{Response, StatusCode};
@@ -712,3 +714,60 @@ async fn manual_update_on_idle_with_failing_load(
     check!(metadata.last_known_status.failed_updates.len() == 1);
     check!(metadata.last_known_status.successful_updates.is_empty());
 }
+
+#[test]
+#[tracing::instrument]
+async fn revert_across_update_is_rejected(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = common::TestContext::new(last_unique_id);
+    let executor = common::start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_unique_component("update-test-v1").await;
+    let worker_id = executor
+        .start_worker(&component_id, "revert_across_update_is_rejected")
+        .await;
+    let _ = executor.log_output(&worker_id).await;
+
+    let _ = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{f3}", vec![])
+        .await
+        .unwrap();
+
+    let oplog = executor.get_oplog(&worker_id, OplogIndex::INITIAL).await;
+    let last_index_before_update = OplogIndex::from_u64(oplog.len() as u64);
+
+    let target_version = executor
+        .update_component(&component_id, "update-test-v2")
+        .await;
+    info!("Updated component to version {target_version}");
+
+    executor
+        .auto_update_worker(&worker_id, target_version)
+        .await;
+
+    let _ = executor
+        .invoke_and_await(&worker_id, "golem:component/api.{f4}", vec![])
+        .await
+        .unwrap();
+
+    let result = golem_test_framework::dsl::TestDsl::revert(
+        &executor,
+        &worker_id,
+        RevertWorkerTarget::RevertToOplogIndex(RevertToOplogIndex {
+            last_oplog_index: last_index_before_update,
+        }),
+    )
+    .await;
+
+    let (metadata, _) = executor.get_worker_metadata(&worker_id).await.unwrap();
+    drop(executor);
+
+    // Expectation: the revert would skip the update, so it is rejected and the worker keeps
+    // running the updated component
+    check!(result.is_err());
+    check!(metadata.last_known_status.component_version == target_version);
+    check!(metadata.last_known_status.successful_updates.len() == 1);
+}
@@ -12,18 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use golem_common::config::RedisConfig;
+use golem_common::config::{DbPostgresConfig, RedisConfig};
 use golem_common::redis::RedisPool;
+use golem_test_framework::components::rdb::{DbInfo, Rdb};
 use golem_test_framework::components::redis::Redis;
 use golem_test_framework::components::redis_monitor::RedisMonitor;
 use golem_test_framework::config::TestDependencies;
 use golem_worker_executor_base::storage::indexed::memory::InMemoryIndexedStorage;
+use golem_worker_executor_base::storage::indexed::postgres::PostgresIndexedStorage;
 use golem_worker_executor_base::storage::indexed::redis::RedisIndexedStorage;
 use golem_worker_executor_base::storage::indexed::sqlite::SqliteIndexedStorage;
+use golem_worker_executor_base::storage::postgres::PostgresPool;
 use golem_worker_executor_base::storage::sqlite::SqlitePool;
 use sqlx::sqlite::SqlitePoolOptions;
 
-use crate::WorkerExecutorTestDependencies;
+use crate::{PostgresTestRdb, WorkerExecutorTestDependencies};
 use golem_worker_executor_base::storage::indexed::{IndexedStorage, IndexedStorageNamespace};
 use std::sync::Arc;
 use test_r::inherit_test_dep;
@@ -115,6 +118,39 @@ pub(crate) async fn sqlite_storage(
     SqliteIndexedStorageWrapper { sis }
 }
 
+struct PostgresIndexedStorageWrapper {
+    sis: PostgresIndexedStorage,
+    _rdb: Arc<dyn Rdb + Send + Sync>,
+}
+
+impl GetIndexedStorage for PostgresIndexedStorageWrapper {
+    fn get_indexed_storage(&self) -> &dyn IndexedStorage {
+        &self.sis
+    }
+}
+
+pub(crate) async fn postgres_storage(deps: &PostgresTestRdb) -> impl GetIndexedStorage {
+    let rdb = deps.rdb.clone();
+    let info = match rdb.info() {
+        DbInfo::Postgres(info) => info,
+        _ => panic!("The test rdb must be Postgres"),
+    };
+    let random_schema = format!("test_{}", Uuid::new_v4().simple());
+    let pool = PostgresPool::configured(&DbPostgresConfig {
+        host: "localhost".to_string(),
+        database: info.database_name,
+        username: info.username,
+        password: info.password,
+        port: info.host_port,
+        max_connections: 10,
+        schema: Some(random_schema),
+    })
+    .await
+    .expect("Cannot connect to postgres db");
+    let sis = PostgresIndexedStorage::new(pool).await.unwrap();
+    PostgresIndexedStorageWrapper { sis, _rdb: rdb }
+}
+
 pub fn ns() -> IndexedStorageNamespace {
     IndexedStorageNamespace::OpLog
 }
@@ -124,22 +160,26 @@ pub fn ns2() -> IndexedStorageNamespace {
 }
 
 inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(PostgresTestRdb);
 
 macro_rules! test_indexed_storage {
     ( $name:ident, $init:expr ) => {
+        test_indexed_storage!($name, $init, WorkerExecutorTestDependencies);
+    };
+    ( $name:ident, $init:expr, $deps:ident ) => {
         mod $name {
             use test_r::{inherit_test_dep, test};
 
             use crate::indexed_storage::GetIndexedStorage;
-            use crate::WorkerExecutorTestDependencies;
+            use crate::$deps;
             use assert2::check;
             use golem_worker_executor_base::storage::indexed::ScanCursor;
 
-            inherit_test_dep!(WorkerExecutorTestDependencies);
+            inherit_test_dep!($deps);
 
             #[test]
             #[tracing::instrument]
-            async fn exists_append(deps: &WorkerExecutorTestDependencies) {
+            async fn exists_append(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -160,7 +200,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn namespaces_are_separate(deps: &WorkerExecutorTestDependencies) {
+            async fn namespaces_are_separate(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns1 = crate::indexed_storage::ns();
@@ -180,7 +220,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn can_append_and_get(deps: &WorkerExecutorTestDependencies) {
+            async fn can_append_and_get(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -213,7 +253,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn append_cannot_overwrite(deps: &WorkerExecutorTestDependencies) {
+            async fn append_cannot_overwrite(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -235,7 +275,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn append_can_skip(deps: &WorkerExecutorTestDependencies) {
+            async fn append_can_skip(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -263,7 +303,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn length(deps: &WorkerExecutorTestDependencies) {
+            async fn length(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -291,7 +331,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn scan_empty(deps: &WorkerExecutorTestDependencies) {
+            async fn scan_empty(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -315,7 +355,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn scan_with_no_pattern_single_paged(deps: &WorkerExecutorTestDependencies) {
+            async fn scan_with_no_pattern_single_paged(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -354,7 +394,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn scan_with_no_pattern_paginated(deps: &WorkerExecutorTestDependencies) {
+            async fn scan_with_no_pattern_paginated(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -414,7 +454,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn scan_with_prefix_pattern_single_paged(deps: &WorkerExecutorTestDependencies) {
+            async fn scan_with_prefix_pattern_single_paged(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -459,7 +499,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn scan_with_prefix_pattern_paginated(deps: &WorkerExecutorTestDependencies) {
+            async fn scan_with_prefix_pattern_paginated(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -525,7 +565,43 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn exists_append_delete(deps: &WorkerExecutorTestDependencies) {
+            async fn scan_with_prefix_pattern_containing_sql_wildcards(deps: &$deps) {
+                let test = $init(deps).await;
+                let is = test.get_indexed_storage();
+                let ns = crate::indexed_storage::ns();
+
+                let key1 = "key_%1";
+                let key2 = "keyA%2";
+                let key3 = "key_B3";
+                let value = "value".as_bytes();
+
+                for key in [key1, key2, key3] {
+                    let _ = is
+                        .append("svc", "api", "entity", ns.clone(), key, 1, value)
+                        .await
+                        .unwrap();
+                }
+
+                let mut result: Vec<String> = Vec::new();
+                let mut cursor = ScanCursor::default();
+                loop {
+                    let (next, chunk) = is
+                        .scan("svc", "api", ns.clone(), "key_%*", cursor, 10)
+                        .await
+                        .unwrap();
+                    result.extend(chunk);
+                    cursor = next;
+                    if next == 0 {
+                        break;
+                    }
+                }
+
+                check!(result == vec![key1.to_string()]);
+            }
+
+            #[test]
+            #[tracing::instrument]
+            async fn exists_append_delete(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -547,7 +623,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn delete_is_per_namespace(deps: &WorkerExecutorTestDependencies) {
+            async fn delete_is_per_namespace(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns1 = crate::indexed_storage::ns();
@@ -568,7 +644,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn delete_non_existing(deps: &WorkerExecutorTestDependencies) {
+            async fn delete_non_existing(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -582,7 +658,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn first(deps: &WorkerExecutorTestDependencies) {
+            async fn first(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -614,7 +690,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn last(deps: &WorkerExecutorTestDependencies) {
+            async fn last(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -646,7 +722,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn closest_low(deps: &WorkerExecutorTestDependencies) {
+            async fn closest_low(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -678,7 +754,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn closest_match(deps: &WorkerExecutorTestDependencies) {
+            async fn closest_match(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -710,7 +786,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn closest_mid(deps: &WorkerExecutorTestDependencies) {
+            async fn closest_mid(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -742,7 +818,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn closest_high(deps: &WorkerExecutorTestDependencies) {
+            async fn closest_high(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -774,7 +850,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn drop_prefix_no_match(deps: &WorkerExecutorTestDependencies) {
+            async fn drop_prefix_no_match(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -818,7 +894,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn drop_prefix_partial(deps: &WorkerExecutorTestDependencies) {
+            async fn drop_prefix_partial(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -855,7 +931,7 @@ macro_rules! test_indexed_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn drop_prefix_full(deps: &WorkerExecutorTestDependencies) {
+            async fn drop_prefix_full(deps: &$deps) {
                 let test = $init(deps).await;
                 let is = test.get_indexed_storage();
                 let ns = crate::indexed_storage::ns();
@@ -896,3 +972,8 @@ macro_rules! test_indexed_storage {
 test_indexed_storage!(in_memory, crate::indexed_storage::in_memory_storage);
 test_indexed_storage!(redis, crate::indexed_storage::redis_storage);
 test_indexed_storage!(sqlite, crate::indexed_storage::sqlite_storage);
+test_indexed_storage!(
+    postgres,
+    crate::indexed_storage::postgres_storage,
+    PostgresTestRdb
+);
@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::WorkerExecutorTestDependencies;
-use golem_common::config::RedisConfig;
+use crate::{PostgresTestRdb, WorkerExecutorTestDependencies};
+use golem_common::config::{DbPostgresConfig, RedisConfig};
 use golem_common::model::AccountId;
 use golem_common::redis::RedisPool;
+use golem_test_framework::components::rdb::{DbInfo, Rdb};
 use golem_test_framework::components::redis::Redis;
 use golem_test_framework::components::redis_monitor::RedisMonitor;
 use golem_test_framework::config::TestDependencies;
 use golem_worker_executor_base::storage::keyvalue::memory::InMemoryKeyValueStorage;
+use golem_worker_executor_base::storage::keyvalue::postgres::PostgresKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::redis::RedisKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::sqlite::SqliteKeyValueStorage;
 use golem_worker_executor_base::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+use golem_worker_executor_base::storage::postgres::PostgresPool;
 use golem_worker_executor_base::storage::sqlite::SqlitePool;
 use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
@@ -118,6 +121,39 @@ pub(crate) async fn sqlite_storage(
     SqliteKeyValueStorageWrapper { kvs }
 }
 
+struct PostgresKeyValueStorageWrapper {
+    kvs: PostgresKeyValueStorage,
+    _rdb: Arc<dyn Rdb + Send + Sync>,
+}
+
+impl GetKeyValueStorage for PostgresKeyValueStorageWrapper {
+    fn get_key_value_storage(&self) -> &dyn KeyValueStorage {
+        &self.kvs
+    }
+}
+
+pub(crate) async fn postgres_storage(deps: &PostgresTestRdb) -> impl GetKeyValueStorage {
+    let rdb = deps.rdb.clone();
+    let info = match rdb.info() {
+        DbInfo::Postgres(info) => info,
+        _ => panic!("The test rdb must be Postgres"),
+    };
+    let random_schema = format!("test_{}", Uuid::new_v4().simple());
+    let pool = PostgresPool::configured(&DbPostgresConfig {
+        host: "localhost".to_string(),
+        database: info.database_name,
+        username: info.username,
+        password: info.password,
+        port: info.host_port,
+        max_connections: 10,
+        schema: Some(random_schema),
+    })
+    .await
+    .expect("Cannot connect to postgres db");
+    let kvs = PostgresKeyValueStorage::new(pool).await.unwrap();
+    PostgresKeyValueStorageWrapper { kvs, _rdb: rdb }
+}
+
 pub fn ns() -> KeyValueStorageNamespace {
     KeyValueStorageNamespace::Worker
 }
@@ -130,20 +166,24 @@ pub fn ns2() -> KeyValueStorageNamespace {
 }
 
 inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(PostgresTestRdb);
 
 macro_rules! test_kv_storage {
     ( $name:ident, $init:expr, $ns:expr, $ns2:expr ) => {
+        test_kv_storage!($name, $init, $ns, $ns2, WorkerExecutorTestDependencies);
+    };
+    ( $name:ident, $init:expr, $ns:expr, $ns2:expr, $deps:ident ) => {
         mod $name {
             use test_r::{inherit_test_dep, test};
 
             use crate::key_value_storage::GetKeyValueStorage;
-            use crate::WorkerExecutorTestDependencies;
+            use crate::$deps;
 
-            inherit_test_dep!(WorkerExecutorTestDependencies);
+            inherit_test_dep!($deps);
 
             #[test]
             #[tracing::instrument]
-            async fn get_set_get(deps: &WorkerExecutorTestDependencies) {
+            async fn get_set_get(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -165,7 +205,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn namespaces_are_separate(deps: &WorkerExecutorTestDependencies) {
+            async fn namespaces_are_separate(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns1 = $ns();
@@ -199,7 +239,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn get_set_get_many(deps: &WorkerExecutorTestDependencies) {
+            async fn get_set_get_many(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -248,7 +288,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn set_if_not_exists(deps: &WorkerExecutorTestDependencies) {
+            async fn set_if_not_exists(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -273,7 +313,41 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn del(deps: &WorkerExecutorTestDependencies) {
+            async fn compare_and_set(deps: &$deps) {
+                let test = $init(deps).await;
+                let kvs = test.get_key_value_storage();
+                let ns = $ns();
+
+                let key = "key";
+                let value1 = "value".as_bytes();
+                let value2 = "value2".as_bytes();
+                let value3 = "value3".as_bytes();
+
+                let result1 = kvs
+                    .compare_and_set("test", "api", "entity", ns.clone(), key, value1, value2)
+                    .await
+                    .unwrap();
+                kvs.set("test", "api", "entity", ns.clone(), key, value1)
+                    .await
+                    .unwrap();
+                let result2 = kvs
+                    .compare_and_set("test", "api", "entity", ns.clone(), key, value1, value2)
+                    .await
+                    .unwrap();
+                let result3 = kvs
+                    .compare_and_set("test", "api", "entity", ns.clone(), key, value1, value3)
+                    .await
+                    .unwrap();
+                let result4 = kvs.get("test", "api", "entity", ns, key).await.unwrap();
+                assert_eq!(result1, false);
+                assert_eq!(result2, true);
+                assert_eq!(result3, false);
+                assert_eq!(result4, Some(value2.into()));
+            }
+
+            #[test]
+            #[tracing::instrument]
+            async fn del(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -298,7 +372,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn del_many(deps: &WorkerExecutorTestDependencies) {
+            async fn del_many(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -357,7 +431,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn exists_set_exists(deps: &WorkerExecutorTestDependencies) {
+            async fn exists_set_exists(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -376,7 +450,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn exists_is_per_namespace(deps: &WorkerExecutorTestDependencies) {
+            async fn exists_is_per_namespace(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -398,7 +472,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn keys(deps: &WorkerExecutorTestDependencies) {
+            async fn keys(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns2();
@@ -442,7 +516,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn sets(deps: &WorkerExecutorTestDependencies) {
+            async fn sets(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -544,7 +618,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn sorted_sets(deps: &WorkerExecutorTestDependencies) {
+            async fn sorted_sets(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -662,7 +736,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn add_to_sorted_set_updates_score(deps: &WorkerExecutorTestDependencies) {
+            async fn add_to_sorted_set_updates_score(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -692,7 +766,7 @@ macro_rules! test_kv_storage {
 
             #[test]
             #[tracing::instrument]
-            async fn query_sorted_set(deps: &WorkerExecutorTestDependencies) {
+            async fn query_sorted_set(deps: &$deps) {
                 let test = $init(deps).await;
                 let kvs = test.get_key_value_storage();
                 let ns = $ns();
@@ -788,3 +862,10 @@ test_kv_storage!(
     crate::key_value_storage::ns2,
     crate::key_value_storage::ns
 );
+test_kv_storage!(
+    postgres,
+    crate::key_value_storage::postgres_storage,
+    crate::key_value_storage::ns2,
+    crate::key_value_storage::ns,
+    PostgresTestRdb
+);
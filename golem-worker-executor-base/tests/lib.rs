@@ -24,6 +24,7 @@ use golem_common::tracing::{init_tracing_with_default_debug_env_filter, TracingC
 use golem_test_framework::components::component_compilation_service::ComponentCompilationService;
 use golem_test_framework::components::component_service::filesystem::FileSystemComponentService;
 use golem_test_framework::components::component_service::ComponentService;
+use golem_test_framework::components::rdb::docker_postgres::DockerPostgresRdb;
 use golem_test_framework::components::rdb::Rdb;
 use golem_test_framework::components::redis::provided::ProvidedRedis;
 use golem_test_framework::components::redis::spawned::SpawnedRedis;
@@ -265,6 +266,24 @@ pub fn test_dependencies(_tracing: &Tracing) -> WorkerExecutorTestDependencies {
     WorkerExecutorTestDependencies::new()
 }
 
+/// A PostgreSQL database, only started for the PostgreSQL storage tests
+pub struct PostgresTestRdb {
+    pub rdb: Arc<dyn Rdb + Send + Sync + 'static>,
+}
+
+impl Debug for PostgresTestRdb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PostgresTestRdb")
+    }
+}
+
+#[test_dep]
+pub async fn postgres_test_rdb(_tracing: &Tracing) -> PostgresTestRdb {
+    PostgresTestRdb {
+        rdb: Arc::new(DockerPostgresRdb::new(true, false).await),
+    }
+}
+
 #[derive(Debug)]
 pub struct LastUniqueId {
     pub id: AtomicU16,
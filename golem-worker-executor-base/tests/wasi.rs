@@ -16,6 +16,7 @@ use test_r::{inherit_test_dep, test};
 
 use std::collections::HashMap;
 use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::atomic::AtomicU8;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
@@ -430,6 +431,78 @@ async fn file_write_read(
     );
 }
 
+#[test]
+#[tracing::instrument]
+async fn file_put_is_restored_on_recovery(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+
+    let component_id = executor.store_component("file-service").await;
+    let worker_id = executor
+        .start_worker(&component_id, "file-service-put-1")
+        .await;
+
+    let _ = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{write-file}",
+            vec![
+                Value::String("/testfile.txt".to_string()),
+                Value::String("hello world".to_string()),
+            ],
+        )
+        .await
+        .unwrap();
+
+    // Written through the API after the last invocation, so it is at the end of the oplog
+    executor
+        .put_file(
+            &worker_id,
+            Path::new("/dir/uploaded.txt"),
+            b"uploaded content".to_vec(),
+        )
+        .await;
+
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap();
+
+    let uploaded = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{read-file}",
+            vec![Value::String("/dir/uploaded.txt".to_string())],
+        )
+        .await
+        .unwrap();
+    let written = executor
+        .invoke_and_await(
+            &worker_id,
+            "golem:it/api.{read-file}",
+            vec![Value::String("/testfile.txt".to_string())],
+        )
+        .await
+        .unwrap();
+
+    drop(executor);
+
+    check!(
+        uploaded
+            == vec![Value::Result(Ok(Some(Box::new(Value::String(
+                "uploaded content".to_string()
+            )))))]
+    );
+    check!(
+        written
+            == vec![Value::Result(Ok(Some(Box::new(Value::String(
+                "hello world".to_string()
+            )))))]
+    );
+}
+
 #[test]
 #[tracing::instrument]
 async fn http_client(
@@ -1658,3 +1731,154 @@ async fn ip_address_resolve(
     check!(result1.len() > 0);
     check!(result2.len() > 0);
 }
+
+#[test]
+#[tracing::instrument]
+async fn tcp_connection_reset_after_crash(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+    let host_port = context.host_http_port();
+
+    let listener = std::net::TcpListener::bind(format!("127.0.0.1:{host_port}")).unwrap();
+    let connections = Arc::new(AtomicU8::new(0));
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let (reached_tx, mut reached_rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let connections_clone = connections.clone();
+    let received_clone = received.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = stream.unwrap();
+            connections_clone.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let received = received_clone.clone();
+            let reached_tx = reached_tx.clone();
+            std::thread::spawn(move || {
+                let mut writer = stream.try_clone().unwrap();
+                for line in std::io::BufRead::lines(std::io::BufReader::new(stream)) {
+                    let Ok(line) = line else { break };
+                    received.lock().unwrap().push(line.clone());
+                    if line == "first" {
+                        std::io::Write::write_all(&mut writer, b"first\n").unwrap();
+                    } else {
+                        // Not answering, the worker gets crashed while waiting for the reply
+                        let _ = reached_tx.send(());
+                    }
+                }
+            });
+        }
+    });
+
+    let component_id = executor.store_component("networking").await;
+    let worker_id = executor
+        .start_worker(&component_id, "tcp-connection-reset-1")
+        .await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = spawn(async move {
+        executor_clone
+            .invoke_and_await(
+                &worker_id_clone,
+                "golem:it/api.{tcp-exchange}",
+                vec![
+                    Value::U16(host_port),
+                    Value::List(vec![
+                        Value::String("first".to_string()),
+                        Value::String("second".to_string()),
+                    ]),
+                ],
+            )
+            .await
+    });
+
+    reached_rx.recv().await.unwrap();
+    executor.simulated_crash(&worker_id).await;
+    let result = fiber.await.unwrap().unwrap();
+
+    drop(executor);
+
+    // The connection established before the crash is replayed, not reopened, and the pending
+    // read on it fails with connection-reset once the worker is live again
+    let Value::List(responses) = &result[0] else {
+        panic!("unexpected result: {result:?}")
+    };
+    check!(responses[0] == Value::String("first".to_string()));
+    check!(matches!(&responses[1], Value::String(s) if s.starts_with("error")));
+    check!(connections.load(std::sync::atomic::Ordering::SeqCst) == 1);
+    check!(*received.lock().unwrap() == vec!["first".to_string(), "second".to_string()]);
+}
+
+#[test]
+#[tracing::instrument]
+async fn udp_datagrams_not_resent_after_crash(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap();
+    let host_port = context.host_http_port();
+
+    let socket = std::net::UdpSocket::bind(format!("127.0.0.1:{host_port}")).unwrap();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let (reached_tx, mut reached_rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let received_clone = received.clone();
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 1024];
+        while let Ok((len, from)) = socket.recv_from(&mut buf) {
+            let message = String::from_utf8_lossy(&buf[..len]).to_string();
+            received_clone.lock().unwrap().push(message.clone());
+            if message == "first" {
+                socket.send_to(b"first", from).unwrap();
+            } else {
+                // Not answering, the worker gets crashed while waiting for the reply
+                let _ = reached_tx.send(());
+            }
+        }
+    });
+
+    let component_id = executor.store_component("networking").await;
+    let worker_id = executor
+        .start_worker(&component_id, "udp-datagrams-1")
+        .await;
+
+    let executor_clone = executor.clone();
+    let worker_id_clone = worker_id.clone();
+    let fiber = spawn(async move {
+        executor_clone
+            .invoke_and_await(
+                &worker_id_clone,
+                "golem:it/api.{udp-exchange}",
+                vec![
+                    Value::U16(host_port),
+                    Value::List(vec![
+                        Value::String("first".to_string()),
+                        Value::String("second".to_string()),
+                    ]),
+                ],
+            )
+            .await
+    });
+
+    reached_rx.recv().await.unwrap();
+    executor.simulated_crash(&worker_id).await;
+    let result = fiber.await.unwrap().unwrap();
+
+    drop(executor);
+
+    // Both sends and the first receive are replayed from the oplog, so the server sees every
+    // datagram once and the worker keeps waiting for the reply to the second one
+    check!(
+        result
+            == vec![Value::List(vec![
+                Value::String("first".to_string()),
+                Value::String("timeout".to_string()),
+            ])]
+    );
+    check!(*received.lock().unwrap() == vec!["first".to_string(), "second".to_string()]);
+}
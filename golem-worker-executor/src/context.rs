@@ -19,10 +19,12 @@ use async_trait::async_trait;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::wasmtime::ResourceStore;
 use golem_wasm_rpc::{Uri, Value};
-use wasmtime::component::{Instance, ResourceAny};
+use wasmtime::component::{Instance, Resource, ResourceAny};
 use wasmtime::{AsContextMut, ResourceLimiterAsync};
+use wasmtime_wasi_http::types::{HostIncomingRequest, HostResponseOutparam};
 
 use golem_common::model::oplog::WorkerResourceId;
+use golem_common::model::trace_context::TraceContext;
 use golem_common::model::{
     AccountId, ComponentVersion, IdempotencyKey, OwnedWorkerId, WorkerId, WorkerMetadata,
     WorkerStatus, WorkerStatusRecord,
@@ -39,8 +41,10 @@ use golem_worker_executor_base::services::blob_store::BlobStoreService;
 use golem_worker_executor_base::services::component::{ComponentMetadata, ComponentService};
 use golem_worker_executor_base::services::golem_config::GolemConfig;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::lock::LockService;
 use golem_worker_executor_base::services::oplog::{Oplog, OplogService};
 use golem_worker_executor_base::services::promise::PromiseService;
+use golem_worker_executor_base::services::pubsub::PubSubService;
 use golem_worker_executor_base::services::rpc::Rpc;
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::worker::WorkerService;
@@ -51,8 +55,9 @@ use golem_worker_executor_base::services::{
 };
 use golem_worker_executor_base::worker::{RetryDecision, Worker};
 use golem_worker_executor_base::workerctx::{
-    ExternalOperations, FuelManagement, IndexedResourceStore, InvocationHooks,
-    InvocationManagement, StatusManagement, UpdateManagement, WorkerCtx,
+    ExternalOperations, FuelManagement, HttpIncomingRequest, HttpRequestHandling,
+    HttpResponseSender, IndexedResourceStore, InvocationHooks, InvocationManagement,
+    StatusManagement, UpdateManagement, WorkerCtx,
 };
 
 use crate::services::AdditionalDeps;
@@ -115,6 +120,13 @@ impl ExternalOperations<Context> for Context {
         DurableWorkerCtx::<Context>::prepare_instance(worker_id, instance, store).await
     }
 
+    async fn snapshot_if_needed(
+        instance: &Instance,
+        store: &mut (impl AsContextMut<Data = Context> + Send),
+    ) {
+        DurableWorkerCtx::<Context>::snapshot_if_needed(instance, store).await
+    }
+
     async fn record_last_known_limits<T: HasAll<Context> + Send + Sync>(
         this: &T,
         account_id: &AccountId,
@@ -150,6 +162,16 @@ impl InvocationManagement for Context {
         self.durable_ctx.get_current_idempotency_key().await
     }
 
+    async fn set_current_trace_context(&mut self, trace_context: Option<TraceContext>) {
+        self.durable_ctx
+            .set_current_trace_context(trace_context)
+            .await
+    }
+
+    async fn get_current_trace_context(&self) -> Option<TraceContext> {
+        self.durable_ctx.get_current_trace_context().await
+    }
+
     fn is_live(&self) -> bool {
         self.durable_ctx.is_live()
     }
@@ -278,6 +300,22 @@ impl IndexedResourceStore for Context {
     }
 }
 
+impl HttpRequestHandling for Context {
+    fn new_incoming_http_request(
+        &mut self,
+        request: HttpIncomingRequest,
+    ) -> anyhow::Result<Resource<HostIncomingRequest>> {
+        self.durable_ctx.new_incoming_http_request(request)
+    }
+
+    fn new_http_response_outparam(
+        &mut self,
+        sender: HttpResponseSender,
+    ) -> anyhow::Result<Resource<HostResponseOutparam>> {
+        self.durable_ctx.new_http_response_outparam(sender)
+    }
+}
+
 #[async_trait]
 impl WorkerCtx for Context {
     type PublicState = PublicDurableWorkerState<Context>;
@@ -298,6 +336,8 @@ impl WorkerCtx for Context {
         oplog: Arc<dyn Oplog + Send + Sync>,
         invocation_queue: Weak<Worker<Context>>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         rpc: Arc<dyn Rpc + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         component_service: Arc<dyn ComponentService + Send + Sync>,
@@ -319,6 +359,8 @@ impl WorkerCtx for Context {
             oplog,
             invocation_queue,
             scheduler_service,
+            pubsub_service,
+            lock_service,
             rpc,
             worker_proxy,
             component_service,
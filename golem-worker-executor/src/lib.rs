@@ -21,15 +21,18 @@ use crate::context::Context;
 use crate::services::AdditionalDeps;
 use async_trait::async_trait;
 use golem_worker_executor_base::durable_host::DurableWorkerCtx;
-use golem_worker_executor_base::preview2::golem::{api0_2_0, api1_1_0_rc1};
+use golem_worker_executor_base::preview2::golem::{api0_2_0, api1_1_0_rc1, ext};
 use golem_worker_executor_base::services::active_workers::ActiveWorkers;
 use golem_worker_executor_base::services::blob_store::BlobStoreService;
 use golem_worker_executor_base::services::component::ComponentService;
+use golem_worker_executor_base::services::dead_letter::DeadLetterService;
 use golem_worker_executor_base::services::events::Events;
 use golem_worker_executor_base::services::golem_config::GolemConfig;
 use golem_worker_executor_base::services::key_value::KeyValueService;
+use golem_worker_executor_base::services::lock::LockService;
 use golem_worker_executor_base::services::oplog::OplogService;
 use golem_worker_executor_base::services::promise::PromiseService;
+use golem_worker_executor_base::services::pubsub::PubSubService;
 use golem_worker_executor_base::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
 use golem_worker_executor_base::services::scheduler::SchedulerService;
 use golem_worker_executor_base::services::shard::ShardService;
@@ -79,6 +82,9 @@ impl Bootstrap<Context> for ServerBootstrap {
         worker_activator: Arc<dyn WorkerActivator + Send + Sync>,
         oplog_service: Arc<dyn OplogService + Send + Sync>,
         scheduler_service: Arc<dyn SchedulerService + Send + Sync>,
+        dead_letter_service: Arc<dyn DeadLetterService + Send + Sync>,
+        pubsub_service: Arc<dyn PubSubService + Send + Sync>,
+        lock_service: Arc<dyn LockService + Send + Sync>,
         worker_proxy: Arc<dyn WorkerProxy + Send + Sync>,
         events: Arc<Events>,
     ) -> anyhow::Result<All<Context>> {
@@ -105,6 +111,9 @@ impl Bootstrap<Context> for ServerBootstrap {
             blob_store_service.clone(),
             oplog_service.clone(),
             scheduler_service.clone(),
+            dead_letter_service.clone(),
+            pubsub_service.clone(),
+            lock_service.clone(),
             worker_activator.clone(),
             events.clone(),
             additional_deps.clone(),
@@ -128,6 +137,9 @@ impl Bootstrap<Context> for ServerBootstrap {
             oplog_service,
             rpc,
             scheduler_service,
+            dead_letter_service,
+            pubsub_service,
+            lock_service,
             worker_activator.clone(),
             worker_proxy.clone(),
             events.clone(),
@@ -139,6 +151,10 @@ impl Bootstrap<Context> for ServerBootstrap {
         let mut linker = create_linker(engine, get_durable_ctx)?;
         api0_2_0::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         api1_1_0_rc1::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        ext::scheduling::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        ext::promise::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        ext::pubsub::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        ext::lock::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem::rpc::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         Ok(linker)
     }
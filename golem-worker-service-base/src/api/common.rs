@@ -151,6 +151,7 @@ mod conversion {
     use crate::service::api_definition_validator::ValidationErrors;
     use crate::service::api_deployment::ApiDeploymentError;
     use crate::service::http::http_api_definition_validator::RouteValidationError;
+    use crate::service::rib_module::RibModuleError;
     use golem_api_grpc::proto::golem::common::ErrorsBody;
     use golem_api_grpc::proto::golem::{
         apidefinition,
@@ -183,6 +184,12 @@ mod conversion {
                 ApiDefinitionServiceError::RibCompilationErrors(_) => {
                     ApiEndpointError::bad_request(error)
                 }
+                ApiDefinitionServiceError::SecuritySchemeError(_) => {
+                    ApiEndpointError::bad_request(error)
+                }
+                ApiDefinitionServiceError::RibModuleNotFound(_) => {
+                    ApiEndpointError::bad_request(error)
+                }
                 ApiDefinitionServiceError::InternalRepoError(_) => {
                     ApiEndpointError::internal(error)
                 }
@@ -217,6 +224,16 @@ mod conversion {
         }
     }
 
+    impl From<RibModuleError> for ApiEndpointError {
+        fn from(error: RibModuleError) -> Self {
+            match error {
+                RibModuleError::InvalidRibModule(_) => ApiEndpointError::bad_request(error),
+                RibModuleError::RibModuleNotFound(_) => ApiEndpointError::not_found(error),
+                RibModuleError::InternalRepoError(_) => ApiEndpointError::internal(error),
+            }
+        }
+    }
+
     impl From<ValidationErrors<RouteValidationError>> for ApiEndpointError {
         fn from(error: ValidationErrors<RouteValidationError>) -> Self {
             let error = WorkerServiceErrorsBody::Validation(ValidationErrorsBody {
@@ -281,6 +298,16 @@ mod conversion {
                         errors: vec![error.to_safe_string()],
                     })),
                 },
+                ApiDefinitionServiceError::SecuritySchemeError(_) => ApiDefinitionError {
+                    error: Some(api_definition_error::Error::BadRequest(ErrorsBody {
+                        errors: vec![error.to_safe_string()],
+                    })),
+                },
+                ApiDefinitionServiceError::RibModuleNotFound(_) => ApiDefinitionError {
+                    error: Some(api_definition_error::Error::BadRequest(ErrorsBody {
+                        errors: vec![error.to_safe_string()],
+                    })),
+                },
                 ApiDefinitionServiceError::ComponentNotFoundError(error) => ApiDefinitionError {
                     error: Some(api_definition_error::Error::NotFound(ErrorBody {
                         error: format!(
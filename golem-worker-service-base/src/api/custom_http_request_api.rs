@@ -1,20 +1,23 @@
 use std::future::Future;
 use std::sync::Arc;
 
+use crate::api::WorkerBindingType;
 use crate::api_definition::http::CompiledHttpApiDefinition;
 use crate::service::worker::WorkerService;
 use crate::worker_service_rib_interpreter::{DefaultRibInterpreter, WorkerServiceRibInterpreter};
-use futures_util::FutureExt;
+use bytes::{Bytes, BytesMut};
+use futures_util::{FutureExt, StreamExt};
 use golem_service_base::auth::EmptyAuthCtx;
-use hyper::header::HOST;
+use hyper::header::{HOST, WWW_AUTHENTICATE};
 use poem::http::StatusCode;
 use poem::{Body, Endpoint, Request, Response};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::http::{ApiInputPath, InputHttpRequest};
+use crate::http::http_request::router;
+use crate::http::{cors, ApiInputPath, InputHttpRequest};
 use crate::service::api_definition_lookup::ApiDefinitionsLookup;
 
-use crate::worker_binding::RequestToWorkerBindingResolver;
+use crate::worker_binding::WorkerBindingResolutionError;
 use crate::worker_bridge_execution::WorkerRequestExecutor;
 
 // Executes custom request with the help of worker_request_executor and definition_service
@@ -34,10 +37,14 @@ impl CustomHttpRequestApi {
             dyn ApiDefinitionsLookup<InputHttpRequest, CompiledHttpApiDefinition> + Sync + Send,
         >,
         worker_service: Arc<dyn WorkerService<EmptyAuthCtx> + Sync + Send>,
+        rib_max_call_depth: usize,
     ) -> Self {
-        let evaluator = Arc::new(DefaultRibInterpreter::from_worker_request_executor(
-            worker_request_executor_service.clone(),
-        ));
+        let evaluator = Arc::new(
+            DefaultRibInterpreter::from_worker_request_executor(
+                worker_request_executor_service.clone(),
+            )
+            .with_max_call_depth(rib_max_call_depth),
+        );
 
         Self {
             worker_service_rib_interpreter: evaluator,
@@ -62,28 +69,15 @@ impl CustomHttpRequestApi {
 
         info!("API request host: {}", host);
 
-        let json_request_body: serde_json::Value = if body.is_empty() {
-            serde_json::Value::Null
-        } else {
-            match body.into_json().await {
-                Ok(json_request_body) => json_request_body,
-                Err(err) => {
-                    error!("API request host: {} - error: {}", host, err);
-                    return Response::builder()
-                        .status(StatusCode::BAD_REQUEST)
-                        .body(Body::from_string("Request body parse error".to_string()));
-                }
-            }
-        };
-
-        let input_http_request = InputHttpRequest {
+        // The body is read once the matching route, and so its body size limit, is known
+        let mut input_http_request = InputHttpRequest {
             input_path: ApiInputPath {
                 base_path: uri.path().to_string(),
                 query_path: uri.query().map(|x| x.to_string()),
             },
             headers,
             req_method: req_parts.method,
-            req_body: json_request_body,
+            req_body: Bytes::new(),
         };
 
         let possible_api_definitions = match self
@@ -103,14 +97,93 @@ impl CustomHttpRequestApi {
             }
         };
 
+        // Preflight requests to routes with a CORS policy are answered without invoking any worker
+        if cors::is_preflight_request(&input_http_request) {
+            if let Some(cors) =
+                cors::resolve_preflight_cors(&input_http_request, &possible_api_definitions)
+            {
+                return cors.preflight_response(&input_http_request.headers);
+            }
+        }
+
+        let Some(route) = input_http_request.resolve_route(&possible_api_definitions) else {
+            error!("API request host: {} - no route matches the request", host);
+            return Response::builder()
+                .status(StatusCode::METHOD_NOT_ALLOWED)
+                .finish();
+        };
+
+        let mut response = self
+            .execute_route(&mut input_http_request, &route, body, &host)
+            .await;
+
+        // Every response of a route with a CORS policy carries its headers, errors included,
+        // so browsers let the client read them
+        if let Some(cors) = &route.cors {
+            cors.add_response_headers(&input_http_request.headers, &mut response);
+        }
+
+        response
+    }
+
+    async fn execute_route(
+        &self,
+        input_http_request: &mut InputHttpRequest,
+        route: &router::RouteEntry,
+        body: Body,
+        host: &str,
+    ) -> Response {
+        input_http_request.req_body = match read_body(body, route.body_size_limit()).await {
+            Ok(request_body) => request_body,
+            Err(ReadBodyError::TooLarge(limit)) => {
+                warn!(
+                    "API request host: {} - request body exceeds the limit of {} bytes",
+                    host, limit
+                );
+                return Response::builder()
+                    .status(StatusCode::PAYLOAD_TOO_LARGE)
+                    .body(Body::from_string(format!(
+                        "Request body exceeds the limit of {} bytes",
+                        limit
+                    )));
+            }
+            Err(ReadBodyError::Io(err)) => {
+                error!("API request host: {} - error: {}", host, err);
+                return Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .body(Body::from_string("Request body read error".to_string()));
+            }
+        };
+
         match input_http_request
-            .resolve_worker_binding(possible_api_definitions)
+            .resolve_worker_binding_of_route(route)
             .await
         {
             Ok(resolved_worker_binding) => {
-                resolved_worker_binding
-                    .interpret_response_mapping(&self.worker_service_rib_interpreter, Some(&self.worker_service))
-                    .await
+                if resolved_worker_binding.binding_type == WorkerBindingType::HttpHandler {
+                    resolved_worker_binding
+                        .invoke_http_handler(
+                            input_http_request.to_incoming_http_request(),
+                            &self.worker_service,
+                        )
+                        .await
+                } else {
+                    resolved_worker_binding
+                        .interpret_response_mapping(
+                            &self.worker_service_rib_interpreter,
+                            Some(&self.worker_service),
+                        )
+                        .await
+                }
+            }
+
+            Err(WorkerBindingResolutionError::Unauthorized { error, challenge }) => {
+                warn!("Unauthorized request; error: {}", error);
+
+                Response::builder()
+                    .status(StatusCode::UNAUTHORIZED)
+                    .header(WWW_AUTHENTICATE, challenge)
+                    .finish()
             }
 
             Err(msg) => {
@@ -124,6 +197,30 @@ impl CustomHttpRequestApi {
     }
 }
 
+enum ReadBodyError {
+    TooLarge(u64),
+    Io(std::io::Error),
+}
+
+// Reads the request body, failing as soon as more than `limit` bytes are received
+async fn read_body(body: Body, limit: Option<u64>) -> Result<Bytes, ReadBodyError> {
+    let Some(limit) = limit else {
+        return body.into_bytes().await.map_err(ReadBodyError::Io);
+    };
+
+    let mut stream = body.into_bytes_stream();
+    let mut result = BytesMut::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(ReadBodyError::Io)?;
+        if (result.len() + chunk.len()) as u64 > limit {
+            return Err(ReadBodyError::TooLarge(limit));
+        }
+        result.extend_from_slice(&chunk);
+    }
+
+    Ok(result.freeze())
+}
+
 impl Endpoint for CustomHttpRequestApi {
     type Output = Response;
 
@@ -131,3 +228,26 @@ impl Endpoint for CustomHttpRequestApi {
         self.execute(req).map(Ok)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use super::{read_body, ReadBodyError};
+    use poem::Body;
+
+    #[test]
+    async fn test_read_body_within_limit() {
+        let result = read_body(Body::from_static(b"hello"), Some(5)).await;
+        assert!(matches!(result, Ok(body) if body.as_ref() == b"hello"));
+
+        let result = read_body(Body::from_static(b"hello"), None).await;
+        assert!(matches!(result, Ok(body) if body.as_ref() == b"hello"));
+    }
+
+    #[test]
+    async fn test_read_body_over_limit() {
+        let result = read_body(Body::from_static(b"hello"), Some(4)).await;
+        assert!(matches!(result, Err(ReadBodyError::TooLarge(4))));
+    }
+}
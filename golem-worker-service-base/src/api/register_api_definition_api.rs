@@ -7,7 +7,9 @@ use std::result::Result;
 use std::time::SystemTime;
 
 use crate::api_definition::http::{
-    AllPathPatterns, CompiledHttpApiDefinition, CompiledRoute, MethodPattern,
+    AllPathPatterns, ApiKeyCredential, ApiKeyScheme, BasicCredential, BasicScheme,
+    CompiledHttpApiDefinition, CompiledRoute, HttpCors, HttpSecurityScheme, HttpSecuritySchemeKind,
+    JwtScheme, MethodPattern,
 };
 use crate::api_definition::{ApiDefinitionId, ApiSite, ApiVersion};
 use crate::worker_binding::CompiledGolemWorkerBinding;
@@ -50,6 +52,11 @@ pub struct HttpApiDefinitionRequest {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    #[serde(default)]
+    pub cors: Option<HttpCors>,
+    #[serde(default)]
+    #[oai(default)]
+    pub security_schemes: Vec<HttpSecurityScheme>,
 }
 
 // Mostly this data structures that represents the actual incoming request
@@ -64,6 +71,7 @@ pub struct HttpApiDefinition {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    pub security_schemes: Vec<HttpSecurityScheme>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -77,6 +85,7 @@ pub struct HttpApiDefinitionWithTypeInfo {
     pub routes: Vec<RouteWithTypeInfo>,
     #[serde(default)]
     pub draft: bool,
+    pub security_schemes: Vec<HttpSecurityScheme>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -89,23 +98,38 @@ impl From<CompiledHttpApiDefinition> for HttpApiDefinitionWithTypeInfo {
             version: value.version,
             routes,
             draft: value.draft,
+            security_schemes: value.security_schemes,
             created_at: Some(value.created_at),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
 pub struct Route {
     pub method: MethodPattern,
     pub path: String,
     pub binding: GolemWorkerBinding,
+    pub max_body_size: Option<u64>,
+    #[serde(default)]
+    pub cors: Option<HttpCors>,
+    #[serde(default)]
+    pub security: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
 pub struct RouteWithTypeInfo {
     pub method: MethodPattern,
     pub path: String,
     pub binding: GolemWorkerBindingWithTypeInfo,
+    pub max_body_size: Option<u64>,
+    #[serde(default)]
+    pub cors: Option<HttpCors>,
+    #[serde(default)]
+    pub security: Option<String>,
 }
 
 impl From<CompiledRoute> for RouteWithTypeInfo {
@@ -117,6 +141,9 @@ impl From<CompiledRoute> for RouteWithTypeInfo {
             method,
             path,
             binding,
+            max_body_size: value.max_body_size,
+            cors: value.cors,
+            security: value.security.map(|scheme| scheme.name),
         }
     }
 }
@@ -208,6 +235,7 @@ impl TryFrom<crate::api_definition::http::HttpApiDefinition> for HttpApiDefiniti
             version: value.version,
             routes,
             draft: value.draft,
+            security_schemes: value.security_schemes,
             created_at: Some(value.created_at),
         })
     }
@@ -231,6 +259,8 @@ impl TryInto<crate::api_definition::http::HttpApiDefinitionRequest> for HttpApiD
             version: self.version,
             routes,
             draft: self.draft,
+            cors: self.cors,
+            security_schemes: self.security_schemes,
         })
     }
 }
@@ -246,6 +276,9 @@ impl TryFrom<crate::api_definition::http::Route> for Route {
             method: value.method,
             path,
             binding,
+            max_body_size: value.max_body_size,
+            cors: value.cors,
+            security: value.security,
         })
     }
 }
@@ -261,6 +294,9 @@ impl TryInto<crate::api_definition::http::Route> for Route {
             method: self.method,
             path,
             binding,
+            max_body_size: self.max_body_size,
+            cors: self.cors,
+            security: self.security,
         })
     }
 }
@@ -335,7 +371,17 @@ impl TryFrom<crate::api_definition::http::HttpApiDefinition> for grpc_apidefinit
 
         let id = value.id.0;
 
-        let definition = grpc_apidefinition::HttpApiDefinition { routes };
+        let security_schemes = value
+            .security_schemes
+            .into_iter()
+            .map(grpc_apidefinition::HttpSecurityScheme::from)
+            .collect();
+
+        let definition = grpc_apidefinition::HttpApiDefinition {
+            routes,
+            cors: None,
+            security_schemes,
+        };
 
         let created_at = prost_types::Timestamp::from(SystemTime::from(value.created_at));
 
@@ -357,12 +403,17 @@ impl TryFrom<grpc_apidefinition::ApiDefinition> for crate::api_definition::http:
     type Error = String;
 
     fn try_from(value: grpc_apidefinition::ApiDefinition) -> Result<Self, Self::Error> {
-        let routes = match value.definition.ok_or("definition is missing")? {
-            grpc_apidefinition::api_definition::Definition::Http(http) => http
-                .routes
-                .into_iter()
-                .map(crate::api_definition::http::Route::try_from)
-                .collect::<Result<Vec<crate::api_definition::http::Route>, String>>()?,
+        let (routes, security_schemes) = match value.definition.ok_or("definition is missing")? {
+            grpc_apidefinition::api_definition::Definition::Http(http) => (
+                http.routes
+                    .into_iter()
+                    .map(crate::api_definition::http::Route::try_from)
+                    .collect::<Result<Vec<crate::api_definition::http::Route>, String>>()?,
+                http.security_schemes
+                    .into_iter()
+                    .map(HttpSecurityScheme::try_from)
+                    .collect::<Result<Vec<HttpSecurityScheme>, String>>()?,
+            ),
         };
 
         let id = value.id.ok_or("Api Definition ID is missing")?;
@@ -376,6 +427,7 @@ impl TryFrom<grpc_apidefinition::ApiDefinition> for crate::api_definition::http:
             version: ApiVersion(value.version),
             routes,
             draft: value.draft,
+            security_schemes,
             created_at: created_at.into(),
         };
 
@@ -389,13 +441,20 @@ impl TryFrom<grpc_apidefinition::v1::ApiDefinitionRequest>
     type Error = String;
 
     fn try_from(value: grpc_apidefinition::v1::ApiDefinitionRequest) -> Result<Self, Self::Error> {
-        let routes = match value.definition.ok_or("definition is missing")? {
-            grpc_apidefinition::v1::api_definition_request::Definition::Http(http) => http
-                .routes
-                .into_iter()
-                .map(crate::api_definition::http::Route::try_from)
-                .collect::<Result<Vec<crate::api_definition::http::Route>, String>>()?,
-        };
+        let (routes, cors, security_schemes) =
+            match value.definition.ok_or("definition is missing")? {
+                grpc_apidefinition::v1::api_definition_request::Definition::Http(http) => (
+                    http.routes
+                        .into_iter()
+                        .map(crate::api_definition::http::Route::try_from)
+                        .collect::<Result<Vec<crate::api_definition::http::Route>, String>>()?,
+                    http.cors.map(HttpCors::try_from).transpose()?,
+                    http.security_schemes
+                        .into_iter()
+                        .map(HttpSecurityScheme::try_from)
+                        .collect::<Result<Vec<HttpSecurityScheme>, String>>()?,
+                ),
+            };
 
         let id = value.id.ok_or("Api Definition ID is missing")?;
 
@@ -404,6 +463,8 @@ impl TryFrom<grpc_apidefinition::v1::ApiDefinitionRequest>
             version: ApiVersion(value.version),
             routes,
             draft: value.draft,
+            cors,
+            security_schemes,
         };
 
         Ok(result)
@@ -422,6 +483,9 @@ impl TryFrom<crate::api_definition::http::Route> for grpc_apidefinition::HttpRou
             method: method as i32,
             path,
             binding: Some(binding),
+            max_body_size: value.max_body_size,
+            cors: value.cors.map(grpc_apidefinition::HttpCors::from),
+            security: value.security,
         };
 
         Ok(result)
@@ -439,6 +503,11 @@ impl TryFrom<CompiledRoute> for golem_api_grpc::proto::golem::apidefinition::Com
             method,
             path,
             binding: Some(binding),
+            max_body_size: value.max_body_size,
+            cors: value.cors.map(grpc_apidefinition::HttpCors::from),
+            security: value
+                .security
+                .map(grpc_apidefinition::HttpSecurityScheme::from),
         })
     }
 }
@@ -456,6 +525,140 @@ impl TryFrom<golem_api_grpc::proto::golem::apidefinition::CompiledHttpRoute> for
             method,
             path,
             binding,
+            max_body_size: value.max_body_size,
+            cors: value.cors.map(HttpCors::try_from).transpose()?,
+            security: value
+                .security
+                .map(HttpSecurityScheme::try_from)
+                .transpose()?,
+        })
+    }
+}
+
+impl From<HttpCors> for grpc_apidefinition::HttpCors {
+    fn from(value: HttpCors) -> Self {
+        grpc_apidefinition::HttpCors {
+            allow_origins: value.allow_origins,
+            allow_methods: value
+                .allow_methods
+                .into_iter()
+                .map(|method| grpc_apidefinition::HttpMethod::from(method) as i32)
+                .collect(),
+            allow_headers: value.allow_headers,
+            expose_headers: value.expose_headers,
+            allow_credentials: value.allow_credentials,
+            max_age: value.max_age,
+        }
+    }
+}
+
+impl TryFrom<grpc_apidefinition::HttpCors> for HttpCors {
+    type Error = String;
+
+    fn try_from(value: grpc_apidefinition::HttpCors) -> Result<Self, Self::Error> {
+        let allow_methods = value
+            .allow_methods
+            .into_iter()
+            .map(|method| MethodPattern::try_from(method).map_err(|e| e.to_string()))
+            .collect::<Result<Vec<MethodPattern>, String>>()?;
+
+        Ok(HttpCors {
+            allow_origins: value.allow_origins,
+            allow_methods,
+            allow_headers: value.allow_headers,
+            expose_headers: value.expose_headers,
+            allow_credentials: value.allow_credentials,
+            max_age: value.max_age,
+        })
+    }
+}
+
+impl From<HttpSecurityScheme> for grpc_apidefinition::HttpSecurityScheme {
+    fn from(value: HttpSecurityScheme) -> Self {
+        use grpc_apidefinition::http_security_scheme::Scheme;
+
+        let scheme = match value.scheme {
+            HttpSecuritySchemeKind::ApiKey(api_key) => {
+                Scheme::ApiKey(grpc_apidefinition::ApiKeyScheme {
+                    header: api_key.header,
+                    keys: api_key
+                        .keys
+                        .into_iter()
+                        .map(|key| grpc_apidefinition::ApiKeyCredential {
+                            subject: key.subject,
+                            key_sha256: key.key_sha256,
+                        })
+                        .collect(),
+                })
+            }
+            HttpSecuritySchemeKind::Basic(basic) => {
+                Scheme::Basic(grpc_apidefinition::BasicScheme {
+                    users: basic
+                        .users
+                        .into_iter()
+                        .map(|user| grpc_apidefinition::BasicCredential {
+                            username: user.username,
+                            password_hash: user.password_hash,
+                        })
+                        .collect(),
+                })
+            }
+            HttpSecuritySchemeKind::Jwt(jwt) => Scheme::Jwt(grpc_apidefinition::JwtScheme {
+                jwks_url: jwt.jwks_url,
+                static_key: jwt.static_key,
+                algorithm: jwt.algorithm,
+                issuer: jwt.issuer,
+                audience: jwt.audience,
+            }),
+        };
+
+        grpc_apidefinition::HttpSecurityScheme {
+            name: value.name,
+            scheme: Some(scheme),
+        }
+    }
+}
+
+impl TryFrom<grpc_apidefinition::HttpSecurityScheme> for HttpSecurityScheme {
+    type Error = String;
+
+    fn try_from(value: grpc_apidefinition::HttpSecurityScheme) -> Result<Self, Self::Error> {
+        use grpc_apidefinition::http_security_scheme::Scheme;
+
+        let scheme = match value.scheme.ok_or("security scheme is missing")? {
+            Scheme::ApiKey(api_key) => HttpSecuritySchemeKind::ApiKey(ApiKeyScheme {
+                header: api_key.header,
+                keys: api_key
+                    .keys
+                    .into_iter()
+                    .map(|key| ApiKeyCredential {
+                        subject: key.subject,
+                        key_sha256: key.key_sha256,
+                    })
+                    .collect(),
+            }),
+            Scheme::Basic(basic) => HttpSecuritySchemeKind::Basic(BasicScheme {
+                users: basic
+                    .users
+                    .into_iter()
+                    .map(|user| BasicCredential {
+                        username: user.username,
+                        password_hash: user.password_hash,
+                    })
+                    .collect(),
+            }),
+            Scheme::Jwt(jwt) => HttpSecuritySchemeKind::Jwt(JwtScheme {
+                jwks_url: jwt.jwks_url,
+                static_key: jwt.static_key,
+                algorithm: jwt.algorithm,
+                issuer: jwt.issuer,
+                audience: jwt.audience,
+            }),
+        };
+
+        Ok(HttpSecurityScheme {
+            name: value.name,
+            scheme,
         })
     }
 }
@@ -489,6 +692,8 @@ impl TryFrom<grpc_apidefinition::HttpRoute> for crate::api_definition::http::Rou
             method,
             path,
             binding,
+            max_body_size: value.max_body_size,
+            cors: value.cors.map(HttpCors::try_from).transpose()?,
         };
 
         Ok(result)
@@ -535,7 +740,7 @@ impl TryFrom<grpc_apidefinition::WorkerBinding> for crate::worker_binding::Golem
         let worker_name: Expr = match value.worker_name {
             Some(worker_name) => worker_name.try_into()?,
             None => match binding_type {
-                WorkerBindingType::Default => Err("worker name is missing")?,
+                WorkerBindingType::Default | WorkerBindingType::HttpHandler => Err("worker name is missing")?,
                 // file-server bindings are allowed to be anonymous
                 WorkerBindingType::FileServer => Expr::empty_expr(),
             }
@@ -566,6 +771,7 @@ pub enum WorkerBindingType {
     #[default]
     Default,
     FileServer,
+    HttpHandler,
 }
 
 impl std::fmt::Display for WorkerBindingType {
@@ -573,6 +779,7 @@ impl std::fmt::Display for WorkerBindingType {
         match self {
             WorkerBindingType::Default => write!(f, "Default"),
             WorkerBindingType::FileServer => write!(f, "FileServer"),
+            WorkerBindingType::HttpHandler => write!(f, "HttpHandler"),
         }
     }
 }
@@ -582,6 +789,7 @@ impl From<golem_api_grpc::proto::golem::apidefinition::WorkerBindingType> for Wo
         match value {
             golem_api_grpc::proto::golem::apidefinition::WorkerBindingType::Default => Self::Default,
             golem_api_grpc::proto::golem::apidefinition::WorkerBindingType::FileServer => Self::FileServer,
+            golem_api_grpc::proto::golem::apidefinition::WorkerBindingType::HttpHandler => Self::HttpHandler,
         }
     }
 }
@@ -591,6 +799,7 @@ impl From<WorkerBindingType> for golem_api_grpc::proto::golem::apidefinition::Wo
         match value {
             WorkerBindingType::Default => Self::Default,
             WorkerBindingType::FileServer => Self::FileServer,
+            WorkerBindingType::HttpHandler => Self::HttpHandler,
         }
     }
 }
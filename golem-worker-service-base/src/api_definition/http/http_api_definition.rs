@@ -3,6 +3,7 @@ use std::fmt::{Debug, Display};
 use std::str::FromStr;
 use Iterator;
 
+use crate::api_definition::http::HttpSecurityScheme;
 use crate::api_definition::{ApiDefinitionId, ApiVersion, HasGolemWorkerBindings};
 use crate::parser::path_pattern_parser::PathPatternParser;
 use crate::parser::{GolemParser, ParseError};
@@ -12,7 +13,8 @@ use bincode::{Decode, Encode};
 use derive_more::Display;
 use golem_service_base::model::{Component, VersionedComponentId};
 use golem_wasm_ast::analysis::AnalysedExport;
-use poem_openapi::Enum;
+use poem_openapi::{Enum, Object};
+use rib::RibModule;
 use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
 
@@ -24,6 +26,11 @@ pub struct HttpApiDefinitionRequest {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    // CORS policy of the routes which don't have their own
+    #[serde(default)]
+    pub cors: Option<HttpCors>,
+    #[serde(default)]
+    pub security_schemes: Vec<HttpSecurityScheme>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -34,6 +41,8 @@ pub struct HttpApiDefinition {
     pub routes: Vec<Route>,
     #[serde(default)]
     pub draft: bool,
+    #[serde(default)]
+    pub security_schemes: Vec<HttpSecurityScheme>,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -42,11 +51,24 @@ impl HttpApiDefinition {
         request: HttpApiDefinitionRequest,
         created_at: chrono::DateTime<chrono::Utc>,
     ) -> Self {
+        let routes = match request.cors {
+            Some(cors) => request
+                .routes
+                .into_iter()
+                .map(|route| Route {
+                    cors: route.cors.or_else(|| Some(cors.clone())),
+                    ..route
+                })
+                .collect(),
+            None => request.routes,
+        };
+
         HttpApiDefinition {
             id: request.id,
             version: request.version,
-            routes: request.routes,
+            routes,
             draft: request.draft,
+            security_schemes: request.security_schemes,
             created_at,
         }
     }
@@ -59,6 +81,8 @@ impl From<HttpApiDefinition> for HttpApiDefinitionRequest {
             version: value.version,
             routes: value.routes,
             draft: value.draft,
+            cors: None,
+            security_schemes: value.security_schemes,
         }
     }
 }
@@ -74,6 +98,7 @@ impl From<CompiledHttpApiDefinition> for HttpApiDefinition {
                 .map(Route::from)
                 .collect(),
             draft: compiled_http_api_definition.draft,
+            security_schemes: compiled_http_api_definition.security_schemes,
             created_at: compiled_http_api_definition.created_at,
         }
     }
@@ -90,6 +115,7 @@ pub struct CompiledHttpApiDefinition {
     pub version: ApiVersion,
     pub routes: Vec<CompiledRoute>,
     pub draft: bool,
+    pub security_schemes: Vec<HttpSecurityScheme>,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
@@ -97,11 +123,23 @@ impl CompiledHttpApiDefinition {
     pub fn from_http_api_definition(
         http_api_definition: &HttpApiDefinition,
         metadata_dictionary: &ComponentMetadataDictionary,
+        rib_modules: &[RibModule],
     ) -> Result<Self, RouteCompilationErrors> {
+        for scheme in &http_api_definition.security_schemes {
+            scheme
+                .validate()
+                .map_err(RouteCompilationErrors::SecuritySchemeError)?;
+        }
+
         let mut compiled_routes = vec![];
 
         for route in &http_api_definition.routes {
-            let compiled_route = CompiledRoute::from_route(route, metadata_dictionary)?;
+            let compiled_route = CompiledRoute::from_route(
+                route,
+                metadata_dictionary,
+                rib_modules,
+                &http_api_definition.security_schemes,
+            )?;
             compiled_routes.push(compiled_route);
         }
 
@@ -110,6 +148,7 @@ impl CompiledHttpApiDefinition {
             version: http_api_definition.version.clone(),
             routes: compiled_routes,
             draft: http_api_definition.draft,
+            security_schemes: http_api_definition.security_schemes.clone(),
             created_at: http_api_definition.created_at,
         })
     }
@@ -350,6 +389,14 @@ pub struct Route {
     pub method: MethodPattern,
     pub path: AllPathPatterns,
     pub binding: GolemWorkerBinding,
+    // Maximum size of the request body in bytes, unlimited if not set
+    #[serde(default)]
+    pub max_body_size: Option<u64>,
+    #[serde(default)]
+    pub cors: Option<HttpCors>,
+    // Name of the security scheme callers of the route are authenticated with, public if not set
+    #[serde(default)]
+    pub security: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -357,12 +404,44 @@ pub struct CompiledRoute {
     pub method: MethodPattern,
     pub path: AllPathPatterns,
     pub binding: CompiledGolemWorkerBinding,
+    pub max_body_size: Option<u64>,
+    pub cors: Option<HttpCors>,
+    pub security: Option<HttpSecurityScheme>,
+}
+
+// Cross-origin resource sharing policy of a route. Preflight requests to a route
+// with a policy are answered by the gateway itself, and responses of the route
+// get the corresponding `Access-Control-*` headers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Encode, Decode, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct HttpCors {
+    // Allowed origins, `*` allows any origin
+    pub allow_origins: Vec<String>,
+    // Allowed methods, the method of the route if empty
+    #[serde(default)]
+    #[oai(default)]
+    pub allow_methods: Vec<MethodPattern>,
+    // Allowed request headers, `*` allows any header
+    #[serde(default)]
+    #[oai(default)]
+    pub allow_headers: Vec<String>,
+    // Response headers exposed to the client
+    #[serde(default)]
+    #[oai(default)]
+    pub expose_headers: Vec<String>,
+    #[serde(default)]
+    #[oai(default)]
+    pub allow_credentials: bool,
+    // How long the result of a preflight request can be cached, in seconds
+    pub max_age: Option<u64>,
 }
 
 #[derive(Debug)]
 pub enum RouteCompilationErrors {
     MetadataNotFoundError(VersionedComponentId),
     RibCompilationError(String),
+    SecuritySchemeError(String),
 }
 
 #[derive(Clone, Debug)]
@@ -388,6 +467,8 @@ impl CompiledRoute {
     pub fn from_route(
         route: &Route,
         metadata_dictionary: &ComponentMetadataDictionary,
+        rib_modules: &[RibModule],
+        security_schemes: &[HttpSecurityScheme],
     ) -> Result<Self, RouteCompilationErrors> {
         let metadata = metadata_dictionary
             .metadata
@@ -396,14 +477,35 @@ impl CompiledRoute {
                 route.binding.component_id.clone(),
             ))?;
 
-        let binding =
-            CompiledGolemWorkerBinding::from_golem_worker_binding(&route.binding, metadata)
-                .map_err(RouteCompilationErrors::RibCompilationError)?;
+        let binding = CompiledGolemWorkerBinding::from_golem_worker_binding(
+            &route.binding,
+            metadata,
+            rib_modules,
+        )
+        .map_err(RouteCompilationErrors::RibCompilationError)?;
+
+        let security = route
+            .security
+            .as_ref()
+            .map(|name| {
+                security_schemes
+                    .iter()
+                    .find(|scheme| &scheme.name == name)
+                    .cloned()
+                    .ok_or(RouteCompilationErrors::SecuritySchemeError(format!(
+                        "Security scheme {} of route {} {} is not defined",
+                        name, route.method, route.path
+                    )))
+            })
+            .transpose()?;
 
         Ok(CompiledRoute {
             method: route.method.clone(),
             path: route.path.clone(),
             binding,
+            max_body_size: route.max_body_size,
+            cors: route.cors.clone(),
+            security,
         })
     }
 }
@@ -414,6 +516,9 @@ impl From<CompiledRoute> for Route {
             method: compiled_route.method,
             path: compiled_route.path,
             binding: compiled_route.binding.into(),
+            max_body_size: compiled_route.max_body_size,
+            cors: compiled_route.cors,
+            security: compiled_route.security.map(|scheme| scheme.name),
         }
     }
 }
@@ -712,4 +817,60 @@ mod tests {
             "${ let result = golem:it/api.{do-something}(\"foo\"); {status: if result.user == \"admin\" then 401 else 200 } }",
         );
     }
+
+    #[test]
+    fn test_security_schemes() {
+        let yaml_string = r#"
+          id: users-api
+          version: 0.0.1
+          createdAt: 2024-08-21T07:42:15.696Z
+          securitySchemes:
+          - name: users
+            scheme:
+              type: Jwt
+              jwksUrl: https://auth.golem.cloud/.well-known/jwks.json
+              issuer: https://auth.golem.cloud
+          routes:
+          - method: Get
+            path: foo
+            security: users
+            binding:
+              componentId:
+                version: 0
+                componentId: '15d70aa5-2e23-4ee3-b65c-4e1d702836a3'
+              workerName: 'let user: str = request.auth.sub; "user-${user}"'
+              response: '${ {status: 200} }'
+        "#;
+
+        let original: HttpApiDefinition = serde_yaml::from_str(yaml_string).unwrap();
+
+        let proto: grpc_apidefinition::ApiDefinition = original.clone().try_into().unwrap();
+        let decoded: HttpApiDefinition = proto.try_into().unwrap();
+        assert_eq!(original, decoded);
+
+        let metadata = ComponentMetadataDictionary {
+            metadata: vec![(original.routes[0].binding.component_id.clone(), vec![])]
+                .into_iter()
+                .collect(),
+        };
+
+        let compiled =
+            CompiledHttpApiDefinition::from_http_api_definition(&original, &metadata, &[]).unwrap();
+        assert_eq!(
+            compiled.routes[0].security,
+            Some(original.security_schemes[0].clone())
+        );
+
+        let undefined = HttpApiDefinition {
+            security_schemes: vec![],
+            ..original
+        };
+
+        let result =
+            CompiledHttpApiDefinition::from_http_api_definition(&undefined, &metadata, &[]);
+        assert!(matches!(
+            result,
+            Err(RouteCompilationErrors::SecuritySchemeError(_))
+        ));
+    }
 }
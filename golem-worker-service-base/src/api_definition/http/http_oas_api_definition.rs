@@ -1,9 +1,9 @@
 use async_trait::async_trait;
 use openapiv3::OpenAPI;
-use poem_openapi::types::ParseFromJSON;
+use poem_openapi::types::{ParseFromJSON, ToJSON};
 use poem_openapi::{registry, types};
 
-use crate::api_definition::http::HttpApiDefinitionRequest;
+use crate::api_definition::http::{CompiledHttpApiDefinition, HttpApiDefinitionRequest};
 use crate::api_definition::{ApiDefinitionId, ApiVersion};
 use internal::*;
 
@@ -18,11 +18,76 @@ pub fn get_api_definition(openapi: OpenAPI) -> Result<HttpApiDefinitionRequest,
 
     let routes = get_routes(openapi.paths)?;
 
+    let cors = openapi
+        .extensions
+        .get(GOLEM_CORS_EXTENSION)
+        .map(get_cors)
+        .transpose()?;
+
+    let security_schemes = openapi
+        .extensions
+        .get(GOLEM_SECURITY_SCHEMES_EXTENSION)
+        .map(get_security_schemes)
+        .transpose()?
+        .unwrap_or_default();
+
     Ok(HttpApiDefinitionRequest {
         id: api_definition_id,
         version: api_definition_version,
         routes,
         draft: true,
+        cors,
+        security_schemes,
+    })
+}
+
+// The reverse of `get_api_definition`. Parameter, request body and response schemas are
+// derived from the types the route's Rib expressions were compiled with.
+pub fn get_open_api_definition(definition: &CompiledHttpApiDefinition) -> Result<OpenAPI, String> {
+    let mut paths = openapiv3::Paths::default();
+
+    for route in definition.routes.iter() {
+        let path = export::get_path(&route.path);
+        let operation = export::get_operation(route)?;
+
+        let path_item = paths
+            .paths
+            .entry(path)
+            .or_insert_with(|| openapiv3::ReferenceOr::Item(openapiv3::PathItem::default()));
+
+        if let openapiv3::ReferenceOr::Item(path_item) = path_item {
+            export::set_operation(path_item, &route.method, operation);
+        }
+    }
+
+    let mut extensions = vec![
+        (
+            GOLEM_API_DEFINITION_ID_EXTENSION.to_string(),
+            serde_json::Value::String(definition.id.0.clone()),
+        ),
+        (
+            GOLEM_API_DEFINITION_VERSION.to_string(),
+            serde_json::Value::String(definition.version.0.clone()),
+        ),
+    ];
+
+    if !definition.security_schemes.is_empty() {
+        extensions.push((
+            GOLEM_SECURITY_SCHEMES_EXTENSION.to_string(),
+            serde_json::to_value(&definition.security_schemes).map_err(|err| err.to_string())?,
+        ));
+    }
+
+    Ok(OpenAPI {
+        openapi: "3.0.0".to_string(),
+        info: openapiv3::Info {
+            title: definition.id.0.clone(),
+            version: definition.version.0.clone(),
+            ..Default::default()
+        },
+        paths,
+        extensions: extensions.into_iter().collect(),
+        ..Default::default()
     })
 }
 
@@ -74,15 +139,553 @@ impl ParseFromJSON for JsonOpenApiDefinition {
     }
 }
 
+impl ToJSON for JsonOpenApiDefinition {
+    fn to_json(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(&self.0).ok()
+    }
+}
+
+mod export {
+    use crate::api::WorkerBindingType;
+    use crate::api_definition::http::{
+        AllPathPatterns, CompiledRoute, HttpCors, MethodPattern, PathPattern,
+    };
+    use crate::worker_binding::CompiledGolemWorkerBinding;
+    use golem_wasm_ast::analysis::{AnalysedType, TypeRecord};
+    use hyper::http::Method;
+    use openapiv3::{
+        ArrayType, BooleanType, Header, HeaderStyle, IntegerFormat, IntegerType, MediaType,
+        NumberFormat, NumberType, ObjectType, Operation, Parameter, ParameterData,
+        ParameterSchemaOrContent, PathItem, PathStyle, QueryStyle, ReferenceOr, RequestBody,
+        Response, Responses, Schema, SchemaData, SchemaKind, StatusCode, StringType, Type,
+        VariantOrUnknownOrEmpty,
+    };
+    use rib::{Expr, FunctionTypeRegistry, InferredExpr, RegistryValue};
+    use serde_json::{json, Value};
+
+    use super::internal::{GOLEM_CORS_EXTENSION, GOLEM_WORKER_BRIDGE_EXTENSION};
+
+    const JSON_CONTENT_TYPE: &str = "application/json";
+
+    pub(crate) fn get_path(path: &AllPathPatterns) -> String {
+        if path.path_patterns.is_empty() {
+            "/".to_string()
+        } else {
+            path.path_patterns
+                .iter()
+                .map(|pattern| format!("/{}", pattern))
+                .collect()
+        }
+    }
+
+    pub(crate) fn set_operation(
+        path_item: &mut PathItem,
+        method: &MethodPattern,
+        operation: Operation,
+    ) {
+        let operation = Some(operation);
+        match method {
+            MethodPattern::Get => path_item.get = operation,
+            MethodPattern::Post => path_item.post = operation,
+            MethodPattern::Put => path_item.put = operation,
+            MethodPattern::Delete => path_item.delete = operation,
+            MethodPattern::Options => path_item.options = operation,
+            MethodPattern::Head => path_item.head = operation,
+            MethodPattern::Patch => path_item.patch = operation,
+            MethodPattern::Trace => path_item.trace = operation,
+            // CONNECT is not representable in OpenAPI
+            MethodPattern::Connect => {}
+        }
+    }
+
+    pub(crate) fn get_operation(route: &CompiledRoute) -> Result<Operation, String> {
+        let request_type = get_request_type(&route.binding);
+
+        let mut parameters = vec![];
+
+        for pattern in route.path.path_patterns.iter() {
+            if let PathPattern::Var(var_info) = pattern {
+                parameters.push(ReferenceOr::Item(Parameter::Path {
+                    parameter_data: parameter_data(
+                        &var_info.key_name,
+                        field_type(&request_type, &["path", var_info.key_name.as_str()]),
+                    ),
+                    style: PathStyle::Simple,
+                }));
+            }
+        }
+
+        for query_info in route.path.query_params.iter() {
+            parameters.push(ReferenceOr::Item(Parameter::Query {
+                parameter_data: parameter_data(
+                    &query_info.key_name,
+                    field_type(&request_type, &["path", query_info.key_name.as_str()]),
+                ),
+                allow_reserved: false,
+                style: QueryStyle::Form,
+                allow_empty_value: None,
+            }));
+        }
+
+        if let Some(AnalysedType::Record(headers)) = field_type(&request_type, &["headers"]) {
+            for header in headers.fields.iter() {
+                parameters.push(ReferenceOr::Item(Parameter::Header {
+                    parameter_data: parameter_data(&header.name, Some(header.typ.clone())),
+                    style: HeaderStyle::Simple,
+                }));
+            }
+        }
+
+        let request_body = field_type(&request_type, &["body"]).map(|body_type| {
+            ReferenceOr::Item(RequestBody {
+                content: vec![(JSON_CONTENT_TYPE.to_string(), media_type(&body_type))]
+                    .into_iter()
+                    .collect(),
+                required: true,
+                ..Default::default()
+            })
+        });
+
+        let mut extensions = vec![(
+            GOLEM_WORKER_BRIDGE_EXTENSION.to_string(),
+            get_worker_bridge_extension(route)?,
+        )];
+
+        if let Some(cors) = &route.cors {
+            extensions.push((GOLEM_CORS_EXTENSION.to_string(), get_cors_extension(cors)));
+        }
+
+        Ok(Operation {
+            parameters,
+            request_body,
+            responses: get_responses(&route.binding),
+            extensions: extensions.into_iter().collect(),
+            ..Default::default()
+        })
+    }
+
+    fn get_cors_extension(cors: &HttpCors) -> Value {
+        let mut extension = json!({
+            "allow-origins": cors.allow_origins,
+            "allow-methods": cors
+                .allow_methods
+                .iter()
+                .map(|method| Method::from(method.clone()).to_string())
+                .collect::<Vec<_>>(),
+            "allow-headers": cors.allow_headers,
+            "expose-headers": cors.expose_headers,
+            "allow-credentials": cors.allow_credentials,
+        });
+
+        if let Some(max_age) = cors.max_age {
+            extension["max-age"] = Value::from(max_age);
+        }
+
+        extension
+    }
+
+    fn get_worker_bridge_extension(route: &CompiledRoute) -> Result<Value, String> {
+        let binding = &route.binding;
+
+        let mut extension = json!({
+            "component-id": binding.component_id.component_id.0.to_string(),
+            "component-version": binding.component_id.version,
+            "worker-name": rib::to_string(&binding.worker_name_compiled.worker_name).map_err(|err| err.to_string())?,
+            "response": rib::to_string(&binding.response_compiled.response_rib_expr).map_err(|err| err.to_string())?,
+            "binding-type": binding.binding_type.to_string(),
+        });
+
+        if let Some(idempotency_key) = &binding.idempotency_key_compiled {
+            extension["idempotency-key"] = Value::String(
+                rib::to_string(&idempotency_key.idempotency_key).map_err(|err| err.to_string())?,
+            );
+        }
+
+        if let Some(max_body_size) = route.max_body_size {
+            extension["max-body-size"] = Value::from(max_body_size);
+        }
+
+        if let Some(security) = &route.security {
+            extension["security"] = Value::String(security.name.clone());
+        }
+
+        Ok(extension)
+    }
+
+    // The type of the `request` input merged from all the Rib expressions of the binding
+    fn get_request_type(binding: &CompiledGolemWorkerBinding) -> Option<AnalysedType> {
+        let mut request_types = vec![
+            &binding.worker_name_compiled.rib_input_type_info,
+            &binding.response_compiled.rib_input,
+        ];
+
+        if let Some(idempotency_key) = &binding.idempotency_key_compiled {
+            request_types.push(&idempotency_key.rib_input);
+        }
+
+        request_types
+            .into_iter()
+            .filter_map(|input| input.types.get("request").cloned())
+            .reduce(merge_types)
+    }
+
+    fn merge_types(left: AnalysedType, right: AnalysedType) -> AnalysedType {
+        match (left, right) {
+            (AnalysedType::Record(left), AnalysedType::Record(right)) => {
+                let mut fields = left.fields;
+
+                for field in right.fields {
+                    match fields
+                        .iter_mut()
+                        .find(|existing| existing.name == field.name)
+                    {
+                        Some(existing) => {
+                            existing.typ = merge_types(existing.typ.clone(), field.typ);
+                        }
+                        None => fields.push(field),
+                    }
+                }
+
+                AnalysedType::Record(TypeRecord { fields })
+            }
+            (left, _) => left,
+        }
+    }
+
+    fn field_type(typ: &Option<AnalysedType>, path: &[&str]) -> Option<AnalysedType> {
+        let mut current = typ.clone()?;
+
+        for name in path {
+            let AnalysedType::Record(record) = current else {
+                return None;
+            };
+
+            current = record
+                .fields
+                .into_iter()
+                .find(|field| field.name == *name)?
+                .typ;
+        }
+
+        Some(current)
+    }
+
+    fn get_responses(binding: &CompiledGolemWorkerBinding) -> Responses {
+        let mut responses = Responses::default();
+
+        match binding.binding_type {
+            WorkerBindingType::Default => {
+                let status =
+                    get_status(&binding.response_compiled.response_rib_expr).unwrap_or(200);
+                let response_type = get_response_expr(binding).map(|expr| expr.inferred_type());
+                let response_type = response_type
+                    .as_ref()
+                    .and_then(|typ| AnalysedType::try_from(typ).ok());
+
+                let (body_type, headers) = match response_type {
+                    Some(AnalysedType::Record(record))
+                        if record.fields.iter().any(|field| field.name == "body") =>
+                    {
+                        let response_type = Some(AnalysedType::Record(record));
+                        let headers = match field_type(&response_type, &["headers"]) {
+                            Some(AnalysedType::Record(headers)) => headers.fields,
+                            _ => vec![],
+                        };
+                        (field_type(&response_type, &["body"]), headers)
+                    }
+                    other => (other, vec![]),
+                };
+
+                let response = Response {
+                    description: "Response of the worker binding".to_string(),
+                    headers: headers
+                        .iter()
+                        .map(|header| {
+                            (
+                                header.name.clone(),
+                                ReferenceOr::Item(Header {
+                                    description: None,
+                                    style: HeaderStyle::Simple,
+                                    required: true,
+                                    deprecated: None,
+                                    format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(
+                                        schema(&header.typ),
+                                    )),
+                                    example: None,
+                                    examples: Default::default(),
+                                    extensions: Default::default(),
+                                }),
+                            )
+                        })
+                        .collect(),
+                    content: body_type
+                        .map(|body_type| {
+                            vec![(JSON_CONTENT_TYPE.to_string(), media_type(&body_type))]
+                                .into_iter()
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    ..Default::default()
+                };
+
+                responses
+                    .responses
+                    .insert(StatusCode::Code(status), ReferenceOr::Item(response));
+            }
+            WorkerBindingType::FileServer => {
+                let response = Response {
+                    description: "Content of the file".to_string(),
+                    content: vec![(
+                        "*/*".to_string(),
+                        MediaType {
+                            schema: Some(ReferenceOr::Item(string_schema(Some("binary")))),
+                            ..Default::default()
+                        },
+                    )]
+                    .into_iter()
+                    .collect(),
+                    ..Default::default()
+                };
+
+                responses
+                    .responses
+                    .insert(StatusCode::Code(200), ReferenceOr::Item(response));
+            }
+            WorkerBindingType::HttpHandler => {
+                responses.default = Some(ReferenceOr::Item(Response {
+                    description: "Response of the worker's HTTP handler".to_string(),
+                    ..Default::default()
+                }));
+            }
+        }
+
+        responses
+    }
+
+    // Re-runs type inference on the response mapping with the functions it calls, so the
+    // type of the response is known. Returns None if the types can't be inferred this way.
+    fn get_response_expr(binding: &CompiledGolemWorkerBinding) -> Option<Expr> {
+        let function_calls = binding
+            .response_compiled
+            .worker_calls
+            .as_ref()
+            .map(|worker_calls| worker_calls.function_calls.clone())
+            .unwrap_or_default();
+
+        let registry = FunctionTypeRegistry {
+            types: function_calls
+                .into_iter()
+                .map(|function_call| {
+                    (
+                        function_call.function_key,
+                        RegistryValue::Function {
+                            parameter_types: function_call.parameter_types,
+                            return_types: function_call.return_types,
+                        },
+                    )
+                })
+                .collect(),
+        };
+
+        InferredExpr::from_expr(&binding.response_compiled.response_rib_expr, &registry)
+            .ok()
+            .map(Expr::from)
+    }
+
+    fn get_status(expr: &Expr) -> Option<u16> {
+        match expr {
+            Expr::ExprBlock(exprs, _) => exprs.last().and_then(get_status),
+            Expr::Record(fields, _) => {
+                fields
+                    .iter()
+                    .find(|(name, _)| name == "status")
+                    .and_then(|(_, value)| match value.as_ref() {
+                        Expr::Number(number, _, _) => Some(number.value as u16),
+                        _ => None,
+                    })
+            }
+            _ => None,
+        }
+    }
+
+    fn parameter_data(name: &str, typ: Option<AnalysedType>) -> ParameterData {
+        ParameterData {
+            name: name.to_string(),
+            description: None,
+            required: true,
+            deprecated: None,
+            format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(
+                typ.as_ref().map(schema).unwrap_or(string_schema(None)),
+            )),
+            example: None,
+            examples: Default::default(),
+            explode: None,
+            extensions: Default::default(),
+        }
+    }
+
+    fn media_type(typ: &AnalysedType) -> MediaType {
+        MediaType {
+            schema: Some(ReferenceOr::Item(schema(typ))),
+            ..Default::default()
+        }
+    }
+
+    fn string_schema(format: Option<&str>) -> Schema {
+        type_schema(Type::String(StringType {
+            format: match format {
+                Some(format) => VariantOrUnknownOrEmpty::Unknown(format.to_string()),
+                None => VariantOrUnknownOrEmpty::Empty,
+            },
+            ..Default::default()
+        }))
+    }
+
+    fn integer_schema(format: IntegerFormat, minimum: Option<i64>) -> Schema {
+        type_schema(Type::Integer(IntegerType {
+            format: VariantOrUnknownOrEmpty::Item(format),
+            minimum,
+            ..Default::default()
+        }))
+    }
+
+    fn type_schema(typ: Type) -> Schema {
+        Schema {
+            schema_data: SchemaData::default(),
+            schema_kind: SchemaKind::Type(typ),
+        }
+    }
+
+    fn object_schema(fields: Vec<(String, Schema)>, required: Vec<String>) -> Schema {
+        type_schema(Type::Object(ObjectType {
+            properties: fields
+                .into_iter()
+                .map(|(name, schema)| (name, ReferenceOr::Item(Box::new(schema))))
+                .collect(),
+            required,
+            ..Default::default()
+        }))
+    }
+
+    fn array_schema(items: Option<Schema>) -> Schema {
+        type_schema(Type::Array(ArrayType {
+            items: items.map(|items| ReferenceOr::Item(Box::new(items))),
+            min_items: None,
+            max_items: None,
+            unique_items: false,
+        }))
+    }
+
+    // Follows the JSON representation of values used by the API gateway
+    pub(crate) fn schema(typ: &AnalysedType) -> Schema {
+        match typ {
+            AnalysedType::Bool(_) => type_schema(Type::Boolean(BooleanType::default())),
+            AnalysedType::S8(_) | AnalysedType::S16(_) | AnalysedType::S32(_) => {
+                integer_schema(IntegerFormat::Int32, None)
+            }
+            AnalysedType::U8(_) | AnalysedType::U16(_) | AnalysedType::U32(_) => {
+                integer_schema(IntegerFormat::Int32, Some(0))
+            }
+            AnalysedType::S64(_) => integer_schema(IntegerFormat::Int64, None),
+            AnalysedType::U64(_) => integer_schema(IntegerFormat::Int64, Some(0)),
+            AnalysedType::F32(_) => type_schema(Type::Number(NumberType {
+                format: VariantOrUnknownOrEmpty::Item(NumberFormat::Float),
+                ..Default::default()
+            })),
+            AnalysedType::F64(_) => type_schema(Type::Number(NumberType {
+                format: VariantOrUnknownOrEmpty::Item(NumberFormat::Double),
+                ..Default::default()
+            })),
+            AnalysedType::Chr(_) | AnalysedType::Str(_) | AnalysedType::Handle(_) => {
+                string_schema(None)
+            }
+            AnalysedType::List(list) => array_schema(Some(schema(&list.inner))),
+            AnalysedType::Tuple(_) => array_schema(None),
+            AnalysedType::Option(option) => {
+                let mut schema = schema(&option.inner);
+                schema.schema_data.nullable = true;
+                schema
+            }
+            AnalysedType::Record(record) => object_schema(
+                record
+                    .fields
+                    .iter()
+                    .map(|field| (field.name.clone(), schema(&field.typ)))
+                    .collect(),
+                record
+                    .fields
+                    .iter()
+                    .filter(|field| !matches!(field.typ, AnalysedType::Option(_)))
+                    .map(|field| field.name.clone())
+                    .collect(),
+            ),
+            AnalysedType::Enum(enum_type) => type_schema(Type::String(StringType {
+                enumeration: enum_type.cases.iter().cloned().map(Some).collect(),
+                ..Default::default()
+            })),
+            AnalysedType::Flags(flags) => {
+                array_schema(Some(type_schema(Type::String(StringType {
+                    enumeration: flags.names.iter().cloned().map(Some).collect(),
+                    ..Default::default()
+                }))))
+            }
+            AnalysedType::Variant(variant) => Schema {
+                schema_data: SchemaData::default(),
+                schema_kind: SchemaKind::OneOf {
+                    one_of: variant
+                        .cases
+                        .iter()
+                        .map(|case| {
+                            ReferenceOr::Item(object_schema(
+                                vec![(
+                                    case.name.clone(),
+                                    case.typ.as_ref().map(schema).unwrap_or(type_schema(
+                                        Type::Object(ObjectType::default()),
+                                    )),
+                                )],
+                                vec![case.name.clone()],
+                            ))
+                        })
+                        .collect(),
+                },
+            },
+            AnalysedType::Result(result) => {
+                let case = |name: &str, typ: &Option<Box<AnalysedType>>| {
+                    ReferenceOr::Item(object_schema(
+                        vec![(
+                            name.to_string(),
+                            typ.as_ref()
+                                .map(|typ| schema(typ))
+                                .unwrap_or(type_schema(Type::Object(ObjectType::default()))),
+                        )],
+                        vec![name.to_string()],
+                    ))
+                };
+
+                Schema {
+                    schema_data: SchemaData::default(),
+                    schema_kind: SchemaKind::OneOf {
+                        one_of: vec![case("ok", &result.ok), case("err", &result.err)],
+                    },
+                }
+            }
+        }
+    }
+}
+
 mod internal {
     use crate::api::WorkerBindingType;
-    use crate::api_definition::http::{AllPathPatterns, MethodPattern, Route};
+    use crate::api_definition::http::{
+        AllPathPatterns, HttpCors, HttpSecurityScheme, MethodPattern, QueryInfo, Route,
+    };
     use crate::worker_binding::{GolemWorkerBinding, ResponseMapping};
     use golem_common::model::ComponentId;
-    use openapiv3::{OpenAPI, PathItem, Paths, ReferenceOr};
+    use openapiv3::{OpenAPI, Operation, Parameter, PathItem, Paths, ReferenceOr};
     use poem_openapi::types::ParseFromJSON as _;
     use rib::Expr;
+    use serde::Deserialize;
     use serde_json::Value;
+    use std::str::FromStr;
 
     use golem_service_base::model::VersionedComponentId;
     use uuid::Uuid;
@@ -90,6 +693,23 @@ mod internal {
     pub(crate) const GOLEM_API_DEFINITION_ID_EXTENSION: &str = "x-golem-api-definition-id";
     pub(crate) const GOLEM_API_DEFINITION_VERSION: &str = "x-golem-api-definition-version";
     pub(crate) const GOLEM_WORKER_BRIDGE_EXTENSION: &str = "x-golem-worker-bridge";
+    pub(crate) const GOLEM_CORS_EXTENSION: &str = "x-golem-cors";
+    pub(crate) const GOLEM_SECURITY_SCHEMES_EXTENSION: &str = "x-golem-security-schemes";
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct CorsExtension {
+        allow_origins: Vec<String>,
+        #[serde(default)]
+        allow_methods: Vec<String>,
+        #[serde(default)]
+        allow_headers: Vec<String>,
+        #[serde(default)]
+        expose_headers: Vec<String>,
+        #[serde(default)]
+        allow_credentials: bool,
+        max_age: Option<u64>,
+    }
 
     pub(crate) fn get_root_extension(open_api: &OpenAPI, key_name: &str) -> Result<String, String> {
         open_api
@@ -129,11 +749,11 @@ mod internal {
     }
 
     pub(crate) fn get_route_from_path_item(
-        method: &str,
+        method_name: &str,
         path_item: &PathItem,
         path_pattern: &AllPathPatterns,
     ) -> Result<Route, String> {
-        let method_res = match method {
+        let method_res = match method_name {
             "get" => Ok(MethodPattern::Get),
             "post" => Ok(MethodPattern::Post),
             "put" => Ok(MethodPattern::Put),
@@ -147,29 +767,82 @@ mod internal {
 
         let method = method_res?;
 
-        let worker_bridge_info = path_item
-            .extensions
-            .get(GOLEM_WORKER_BRIDGE_EXTENSION)
+        let operation = path_item
+            .iter()
+            .find(|(name, _)| *name == method_name)
+            .map(|(_, operation)| operation);
+
+        // The extension can be given per operation, or once for all operations of the path
+        let worker_bridge_info = operation
+            .and_then(|operation| operation.extensions.get(GOLEM_WORKER_BRIDGE_EXTENSION))
+            .or(path_item.extensions.get(GOLEM_WORKER_BRIDGE_EXTENSION))
             .ok_or(format!(
                 "No {} extension found",
                 GOLEM_WORKER_BRIDGE_EXTENSION
             ))?;
 
+        let binding_type = get_binding_type(worker_bridge_info)?;
+
+        // The response of an http-handler binding comes from the worker itself
+        let response = if binding_type == WorkerBindingType::HttpHandler
+            && worker_bridge_info.get("response").is_none()
+        {
+            ResponseMapping(Expr::empty_expr())
+        } else {
+            get_response_mapping(worker_bridge_info)?
+        };
+
         let binding = GolemWorkerBinding {
             worker_name: get_worker_id_expr(worker_bridge_info)?,
             component_id: get_component_id(worker_bridge_info)?,
             idempotency_key: get_idempotency_key(worker_bridge_info)?,
-            response: get_response_mapping(worker_bridge_info)?,
-            binding_type: Some(get_binding_type(worker_bridge_info)?),
+            response,
+            binding_type: Some(binding_type),
         };
 
+        let mut path = path_pattern.clone();
+
+        if let Some(operation) = operation {
+            for query_param in get_required_query_params(operation) {
+                if !path.query_params.contains(&query_param) {
+                    path.query_params.push(query_param);
+                }
+            }
+        }
+
+        let cors = operation
+            .and_then(|operation| operation.extensions.get(GOLEM_CORS_EXTENSION))
+            .or(path_item.extensions.get(GOLEM_CORS_EXTENSION))
+            .map(get_cors)
+            .transpose()?;
+
         Ok(Route {
-            path: path_pattern.clone(),
+            path,
             method,
             binding,
+            max_body_size: get_max_body_size(worker_bridge_info)?,
+            cors,
+            security: get_security(worker_bridge_info)?,
         })
     }
 
+    pub(crate) fn get_required_query_params(operation: &Operation) -> Vec<QueryInfo> {
+        operation
+            .parameters
+            .iter()
+            .filter_map(|parameter| match parameter {
+                ReferenceOr::Item(Parameter::Query { parameter_data, .. })
+                    if parameter_data.required =>
+                {
+                    Some(QueryInfo {
+                        key_name: parameter_data.name.clone(),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     pub(crate) fn get_component_id(
         worker_bridge_info: &Value,
     ) -> Result<VersionedComponentId, String> {
@@ -240,6 +913,60 @@ mod internal {
         AllPathPatterns::parse(path).map_err(|err| err.to_string())
     }
 
+    pub(crate) fn get_max_body_size(worker_bridge_info: &Value) -> Result<Option<u64>, String> {
+        if let Some(max_body_size) = worker_bridge_info.get("max-body-size") {
+            Ok(Some(
+                max_body_size.as_u64().ok_or("max-body-size is not a u64")?,
+            ))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Name of the security scheme of the route
+    pub(crate) fn get_security(worker_bridge_info: &Value) -> Result<Option<String>, String> {
+        worker_bridge_info
+            .get("security")
+            .map(|security| {
+                security
+                    .as_str()
+                    .map(|name| name.to_string())
+                    .ok_or("security is not a string".to_string())
+            })
+            .transpose()
+    }
+
+    pub(crate) fn get_security_schemes(
+        security_schemes: &Value,
+    ) -> Result<Vec<HttpSecurityScheme>, String> {
+        serde_json::from_value(security_schemes.clone()).map_err(|err| {
+            format!(
+                "Invalid {} extension: {}",
+                GOLEM_SECURITY_SCHEMES_EXTENSION, err
+            )
+        })
+    }
+
+    pub(crate) fn get_cors(cors: &Value) -> Result<HttpCors, String> {
+        let cors: CorsExtension = serde_json::from_value(cors.clone())
+            .map_err(|err| format!("Invalid {} extension: {}", GOLEM_CORS_EXTENSION, err))?;
+
+        let allow_methods = cors
+            .allow_methods
+            .iter()
+            .map(|method| MethodPattern::from_str(method))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(HttpCors {
+            allow_origins: cors.allow_origins,
+            allow_methods,
+            allow_headers: cors.allow_headers,
+            expose_headers: cors.expose_headers,
+            allow_credentials: cors.allow_credentials,
+            max_age: cors.max_age,
+        })
+    }
+
     pub(crate) fn get_binding_type(worker_bridge_info: &Value) -> Result<WorkerBindingType, String> {
         let binding_type = worker_bridge_info
             .get("binding-type")
@@ -259,10 +986,15 @@ mod tests {
     use test_r::test;
 
     use super::*;
-    use crate::api_definition::http::{AllPathPatterns, MethodPattern, Route};
+    use crate::api_definition::http::{
+        hash_password, AllPathPatterns, BasicCredential, BasicScheme, CompiledRoute,
+        ComponentMetadataDictionary, HttpCors, HttpSecurityScheme, HttpSecuritySchemeKind,
+        MethodPattern, Route,
+    };
     use crate::worker_binding::{GolemWorkerBinding, ResponseMapping};
     use golem_common::model::ComponentId;
-    use openapiv3::PathItem;
+    use golem_wasm_ast::analysis::{AnalysedType, NameTypePair, TypeRecord, TypeStr, TypeU64};
+    use openapiv3::{Parameter, ParameterSchemaOrContent, PathItem, ReferenceOr, StatusCode};
     use rib::Expr;
     use serde_json::json;
     use uuid::Uuid;
@@ -326,8 +1058,163 @@ mod tests {
                         .collect()
                     )),
                     binding_type: None,
-                }
+                },
+                max_body_size: None,
+                cors: None,
+                security: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_route_cors() {
+        let path_item = PathItem {
+            extensions: vec![
+                (
+                    "x-golem-worker-bridge".to_string(),
+                    json!({
+                        "worker-name": "\"worker\"",
+                        "component-id": "00000000-0000-0000-0000-000000000000",
+                        "component-version": 0,
+                        "response": "\"foo\""
+                    }),
+                ),
+                (
+                    "x-golem-cors".to_string(),
+                    json!({
+                        "allow-origins": ["https://app.golem.cloud"],
+                        "allow-methods": ["GET", "post"],
+                        "allow-headers": ["content-type"],
+                        "max-age": 600
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let path_pattern = AllPathPatterns::parse("/test").unwrap();
+
+        let result = get_route_from_path_item("get", &path_item, &path_pattern).unwrap();
+
+        assert_eq!(
+            result.cors,
+            Some(HttpCors {
+                allow_origins: vec!["https://app.golem.cloud".to_string()],
+                allow_methods: vec![MethodPattern::Get, MethodPattern::Post],
+                allow_headers: vec!["content-type".to_string()],
+                expose_headers: vec![],
+                allow_credentials: false,
+                max_age: Some(600),
             })
         );
     }
+
+    #[test]
+    fn test_get_open_api_definition() {
+        let route = Route {
+            path: AllPathPatterns::parse("/users/{id}").unwrap(),
+            method: MethodPattern::Post,
+            binding: GolemWorkerBinding {
+                worker_name: rib::from_string("let id: u64 = request.path.id; \"user-${id}\"")
+                    .unwrap(),
+                component_id: golem_service_base::model::VersionedComponentId {
+                    component_id: ComponentId(Uuid::nil()),
+                    version: 0,
+                },
+                idempotency_key: None,
+                response: ResponseMapping(
+                    rib::from_string(
+                        "let name: str = request.body.name; {status: 201, body: name}",
+                    )
+                    .unwrap(),
+                ),
+                binding_type: None,
+            },
+            max_body_size: Some(1024),
+            cors: None,
+            security: Some("users".to_string()),
+        };
+
+        let security_schemes = vec![HttpSecurityScheme {
+            name: "users".to_string(),
+            scheme: HttpSecuritySchemeKind::Basic(BasicScheme {
+                users: vec![BasicCredential {
+                    username: "admin".to_string(),
+                    password_hash: hash_password(b"admin"),
+                }],
+            }),
+        }];
+
+        let metadata = ComponentMetadataDictionary {
+            metadata: vec![(route.binding.component_id.clone(), vec![])]
+                .into_iter()
+                .collect(),
+        };
+
+        let definition = CompiledHttpApiDefinition {
+            id: ApiDefinitionId("users".to_string()),
+            version: ApiVersion("0.1.0".to_string()),
+            routes: vec![
+                CompiledRoute::from_route(&route, &metadata, &[], &security_schemes).unwrap(),
+            ],
+            draft: true,
+            security_schemes,
+            created_at: Default::default(),
+        };
+
+        let openapi = get_open_api_definition(&definition).unwrap();
+
+        let path_item = match openapi.paths.paths.get("/users/{id}") {
+            Some(ReferenceOr::Item(path_item)) => path_item,
+            _ => panic!("Missing path item"),
+        };
+        let operation = path_item.post.as_ref().unwrap();
+
+        match &operation.parameters[..] {
+            [ReferenceOr::Item(Parameter::Path { parameter_data, .. })] => {
+                assert_eq!(parameter_data.name, "id");
+                assert_eq!(
+                    parameter_data.format,
+                    ParameterSchemaOrContent::Schema(ReferenceOr::Item(export::schema(
+                        &AnalysedType::U64(TypeU64)
+                    )))
+                );
+            }
+            other => panic!("Unexpected parameters: {:?}", other),
+        }
+
+        let request_body = match &operation.request_body {
+            Some(ReferenceOr::Item(request_body)) => request_body,
+            _ => panic!("Missing request body"),
+        };
+        assert_eq!(
+            request_body.content["application/json"].schema,
+            Some(ReferenceOr::Item(export::schema(&AnalysedType::Record(
+                TypeRecord {
+                    fields: vec![NameTypePair {
+                        name: "name".to_string(),
+                        typ: AnalysedType::Str(TypeStr),
+                    }]
+                }
+            ))))
+        );
+
+        assert!(operation
+            .responses
+            .responses
+            .contains_key(&StatusCode::Code(201)));
+
+        // The exported document can be imported back
+        let imported = get_api_definition(openapi).unwrap();
+        assert_eq!(imported.id, definition.id);
+        assert_eq!(imported.version, definition.version);
+        assert_eq!(imported.routes.len(), 1);
+        assert_eq!(imported.routes[0].path, route.path);
+        assert_eq!(imported.routes[0].method, route.method);
+        assert_eq!(imported.routes[0].max_body_size, Some(1024));
+        assert_eq!(imported.routes[0].security, route.security);
+        assert_eq!(imported.security_schemes, definition.security_schemes);
+    }
 }
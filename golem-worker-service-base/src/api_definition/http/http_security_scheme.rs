@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, SaltString};
+use argon2::Argon2;
+use jsonwebtoken::Algorithm;
+use poem_openapi::{Object, Union};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// A named way of authenticating the callers of the routes of an API definition.
+// Routes refer to a scheme by its name, and the claims of a verified caller
+// are available to the Rib expressions of the route as `request.auth`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct HttpSecurityScheme {
+    pub name: String,
+    pub scheme: HttpSecuritySchemeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Union)]
+#[oai(discriminator_name = "type", one_of = true)]
+#[serde(tag = "type")]
+pub enum HttpSecuritySchemeKind {
+    ApiKey(ApiKeyScheme),
+    Basic(BasicScheme),
+    Jwt(JwtScheme),
+}
+
+// An API key sent in a request header. The claims of a caller are `{ sub: <subject> }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiKeyScheme {
+    pub header: String,
+    pub keys: Vec<ApiKeyCredential>,
+}
+
+// Only the hex encoded SHA-256 digest of a key is stored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiKeyCredential {
+    pub subject: String,
+    pub key_sha256: String,
+}
+
+// HTTP basic authentication. The claims of a caller are `{ sub: <username> }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BasicScheme {
+    pub users: Vec<BasicCredential>,
+}
+
+// Only an Argon2 hash of a password is stored, as a PHC string (`$argon2id$v=19$...`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BasicCredential {
+    pub username: String,
+    pub password_hash: String,
+}
+
+// JWT bearer tokens, verified either with the keys of a JWKS fetched from an HTTPS URL
+// or with a static key (a shared secret for HS algorithms, otherwise a PEM encoded public key).
+// The claims of a caller are the claims of the token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct JwtScheme {
+    pub jwks_url: Option<String>,
+    pub static_key: Option<String>,
+    // Required with a static key. With a JWKS, the `alg` of the matching key is used,
+    // and has to be this algorithm when both are set. It is never taken from the token.
+    pub algorithm: Option<String>,
+    pub issuer: Option<String>,
+    pub audience: Option<String>,
+}
+
+impl HttpSecurityScheme {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() {
+            return Err("Security scheme name cannot be empty".to_string());
+        }
+
+        let result = match &self.scheme {
+            HttpSecuritySchemeKind::ApiKey(api_key) => {
+                if http::HeaderName::from_str(&api_key.header).is_err() {
+                    Err(format!("Invalid header name {}", api_key.header))
+                } else {
+                    api_key
+                        .keys
+                        .iter()
+                        .try_for_each(|key| validate_digest(&key.key_sha256))
+                }
+            }
+            HttpSecuritySchemeKind::Basic(basic) => basic
+                .users
+                .iter()
+                .try_for_each(|user| validate_password_hash(&user.password_hash)),
+            HttpSecuritySchemeKind::Jwt(jwt) => jwt.validate(),
+        };
+
+        result.map_err(|err| format!("Invalid security scheme {}: {}", self.name, err))
+    }
+}
+
+impl JwtScheme {
+    pub fn algorithm(&self) -> Result<Option<Algorithm>, String> {
+        self.algorithm
+            .as_ref()
+            .map(|algorithm| {
+                Algorithm::from_str(algorithm)
+                    .map_err(|_| format!("Unknown JWT algorithm {}", algorithm))
+            })
+            .transpose()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let algorithm = self.algorithm()?;
+
+        match (&self.jwks_url, &self.static_key) {
+            (Some(jwks_url), None) => validate_jwks_url(jwks_url),
+            (None, Some(_)) if algorithm.is_none() => {
+                Err("An algorithm is required with a static key".to_string())
+            }
+            (None, Some(_)) => Ok(()),
+            _ => Err("Exactly one of jwksUrl and staticKey is required".to_string()),
+        }
+    }
+}
+
+pub fn sha256_hex(value: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(value))
+}
+
+// Hashes a password with the default Argon2id parameters and a random salt
+pub fn hash_password(password: &[u8]) -> String {
+    Argon2::default()
+        .hash_password(password, &SaltString::generate(&mut OsRng))
+        .expect("Failed to hash password")
+        .to_string()
+}
+
+fn validate_password_hash(hash: &str) -> Result<(), String> {
+    match PasswordHash::new(hash) {
+        Ok(parsed) if parsed.algorithm.as_str().starts_with("argon2") => Ok(()),
+        _ => Err("Passwords have to be Argon2 hashes in the PHC string format".to_string()),
+    }
+}
+
+fn validate_jwks_url(jwks_url: &str) -> Result<(), String> {
+    match url::Url::parse(jwks_url) {
+        Ok(url) if url.scheme() == "https" => Ok(()),
+        _ => Err(format!("{} is not an HTTPS URL", jwks_url)),
+    }
+}
+
+fn validate_digest(digest: &str) -> Result<(), String> {
+    if digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(())
+    } else {
+        Err(format!("{} is not a hex encoded SHA-256 digest", digest))
+    }
+}
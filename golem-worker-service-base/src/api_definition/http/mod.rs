@@ -1,5 +1,7 @@
 pub use http_api_definition::*;
 pub use http_oas_api_definition::*;
+pub use http_security_scheme::*;
 
 mod http_api_definition;
 mod http_oas_api_definition;
+mod http_security_scheme;
@@ -10,6 +10,7 @@ use golem_common::config::{ConfigExample, HasConfigExamples, RetryConfig};
 use golem_common::config::{DbConfig, DbSqliteConfig};
 use golem_common::tracing::TracingConfig;
 use golem_service_base::service::routing_table::RoutingTableConfig;
+use rib::DEFAULT_MAX_CALL_DEPTH;
 
 // The base configuration for the worker service
 // If there are extra configurations for custom services,
@@ -25,6 +26,8 @@ pub struct WorkerServiceBaseConfig {
     pub worker_grpc_port: u16,
     pub routing_table: RoutingTableConfig,
     pub worker_executor_retries: RetryConfig,
+    /// The maximum number of nested function calls in the Rib scripts of API definitions
+    pub rib_max_call_depth: usize,
 }
 
 impl WorkerServiceBaseConfig {
@@ -54,6 +57,7 @@ impl Default for WorkerServiceBaseConfig {
                 multiplier: 10.0,
                 max_jitter_factor: Some(0.15),
             },
+            rib_max_call_depth: DEFAULT_MAX_CALL_DEPTH,
         }
     }
 }
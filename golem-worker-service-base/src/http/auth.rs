@@ -0,0 +1,519 @@
+use crate::api_definition::http::{
+    sha256_hex, ApiKeyScheme, BasicScheme, HttpSecurityScheme, HttpSecuritySchemeKind, JwtScheme,
+};
+use argon2::password_hash::{PasswordHash, PasswordVerifier};
+use argon2::Argon2;
+use base64::Engine;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use lazy_static::lazy_static;
+use poem::http::header::AUTHORIZATION;
+use poem::http::HeaderMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// JWKS are fetched again once they are older than this
+const JWKS_CACHE_TTL: Duration = Duration::from_secs(300);
+
+lazy_static! {
+    static ref JWKS_CLIENT: reqwest::Client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("Failed to create the JWKS client");
+    static ref JWKS_CACHE: Mutex<HashMap<String, (Instant, Arc<JwkSet>)>> =
+        Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthenticationError {
+    MissingCredentials,
+    InvalidCredentials(String),
+}
+
+impl Display for AuthenticationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthenticationError::MissingCredentials => write!(f, "Missing credentials"),
+            AuthenticationError::InvalidCredentials(reason) => {
+                write!(f, "Invalid credentials: {}", reason)
+            }
+        }
+    }
+}
+
+// Verifies the credentials of a request against the security scheme of its route,
+// returning the claims of the caller
+pub async fn authenticate(
+    scheme: &HttpSecurityScheme,
+    headers: &HeaderMap,
+) -> Result<Value, AuthenticationError> {
+    match &scheme.scheme {
+        HttpSecuritySchemeKind::ApiKey(api_key) => authenticate_api_key(api_key, headers),
+        HttpSecuritySchemeKind::Basic(basic) => authenticate_basic(basic, headers).await,
+        HttpSecuritySchemeKind::Jwt(jwt) => authenticate_jwt(jwt, headers).await,
+    }
+}
+
+// The value of the `WWW-Authenticate` header of responses to unauthenticated requests
+pub fn challenge(scheme: &HttpSecurityScheme) -> String {
+    match &scheme.scheme {
+        HttpSecuritySchemeKind::ApiKey(_) => format!("ApiKey realm=\"{}\"", scheme.name),
+        HttpSecuritySchemeKind::Basic(_) => format!("Basic realm=\"{}\"", scheme.name),
+        HttpSecuritySchemeKind::Jwt(_) => format!("Bearer realm=\"{}\"", scheme.name),
+    }
+}
+
+fn authenticate_api_key(
+    scheme: &ApiKeyScheme,
+    headers: &HeaderMap,
+) -> Result<Value, AuthenticationError> {
+    let key = headers
+        .get(scheme.header.as_str())
+        .ok_or(AuthenticationError::MissingCredentials)?
+        .to_str()
+        .map_err(|_| invalid("API key is not a valid string"))?;
+
+    let digest = sha256_hex(key.as_bytes());
+
+    scheme
+        .keys
+        .iter()
+        .find(|credential| credential.key_sha256.eq_ignore_ascii_case(&digest))
+        .map(|credential| subject_claims(&credential.subject))
+        .ok_or(invalid("Unknown API key"))
+}
+
+async fn authenticate_basic(
+    scheme: &BasicScheme,
+    headers: &HeaderMap,
+) -> Result<Value, AuthenticationError> {
+    let encoded = authorization(headers, "Basic")?;
+
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or(invalid("Malformed basic credentials"))?;
+
+    let (username, password) = decoded
+        .split_once(':')
+        .ok_or(invalid("Malformed basic credentials"))?;
+
+    let user = scheme
+        .users
+        .iter()
+        .find(|user| user.username == username)
+        .ok_or(invalid("Unknown user or wrong password"))?;
+
+    // Verifying an Argon2 hash is deliberately slow, so it does not run on the async workers
+    let password_hash = user.password_hash.clone();
+    let password = password.to_string();
+    let verified = tokio::task::spawn_blocking(move || {
+        PasswordHash::new(&password_hash).is_ok_and(|hash| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+    })
+    .await
+    .unwrap_or(false);
+
+    if verified {
+        Ok(subject_claims(&user.username))
+    } else {
+        Err(invalid("Unknown user or wrong password"))
+    }
+}
+
+async fn authenticate_jwt(
+    scheme: &JwtScheme,
+    headers: &HeaderMap,
+) -> Result<Value, AuthenticationError> {
+    let token = authorization(headers, "Bearer")?;
+
+    let header = jsonwebtoken::decode_header(token)
+        .map_err(|err| invalid(format!("Malformed token: {}", err)))?;
+
+    let configured_algorithm = scheme.algorithm().map_err(invalid)?;
+
+    let (key, algorithm) = match (&scheme.static_key, &scheme.jwks_url) {
+        (Some(static_key), _) => {
+            let algorithm =
+                configured_algorithm.ok_or(invalid("No algorithm to verify the token with"))?;
+            (static_decoding_key(static_key, algorithm)?, algorithm)
+        }
+        (None, Some(jwks_url)) => {
+            let jwks = load_jwks(jwks_url).await.map_err(invalid)?;
+
+            // Tokens without a key id can be verified by a JWKS with a single key
+            let jwk = match &header.kid {
+                Some(kid) => jwks.find(kid),
+                None if jwks.keys.len() == 1 => jwks.keys.first(),
+                None => None,
+            }
+            .ok_or(invalid("No matching key for the token"))?;
+
+            let algorithm = jwk_algorithm(jwk, configured_algorithm)?;
+            let key = DecodingKey::from_jwk(jwk).map_err(|err| invalid(err.to_string()))?;
+            (key, algorithm)
+        }
+        (None, None) => Err(invalid("No key to verify the token with"))?,
+    };
+
+    // Tokens signed with any other algorithm than the one of the key are rejected
+    let mut validation = Validation::new(algorithm);
+
+    if let Some(issuer) = &scheme.issuer {
+        validation.set_issuer(&[issuer]);
+    }
+
+    match &scheme.audience {
+        Some(audience) => validation.set_audience(&[audience]),
+        None => validation.validate_aud = false,
+    }
+
+    jsonwebtoken::decode::<Value>(token, &key, &validation)
+        .map(|token_data| token_data.claims)
+        .map_err(|err| invalid(err.to_string()))
+}
+
+fn static_decoding_key(
+    key: &str,
+    algorithm: Algorithm,
+) -> Result<DecodingKey, AuthenticationError> {
+    let result = match algorithm {
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
+            Ok(DecodingKey::from_secret(key.as_bytes()))
+        }
+        Algorithm::RS256
+        | Algorithm::RS384
+        | Algorithm::RS512
+        | Algorithm::PS256
+        | Algorithm::PS384
+        | Algorithm::PS512 => DecodingKey::from_rsa_pem(key.as_bytes()),
+        Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(key.as_bytes()),
+        Algorithm::EdDSA => DecodingKey::from_ed_pem(key.as_bytes()),
+    };
+
+    result.map_err(|err| invalid(format!("Invalid static key: {}", err)))
+}
+
+// The algorithm a key of a JWKS verifies tokens with, which is its `alg` or the configured one
+fn jwk_algorithm(
+    jwk: &Jwk,
+    configured: Option<Algorithm>,
+) -> Result<Algorithm, AuthenticationError> {
+    let key_algorithm = jwk
+        .common
+        .key_algorithm
+        .as_ref()
+        .map(|key_algorithm| {
+            serde_json::to_value(key_algorithm)
+                .ok()
+                .and_then(|value| value.as_str().map(Algorithm::from_str))
+                .and_then(Result::ok)
+                .ok_or(invalid("The key is not a signing key"))
+        })
+        .transpose()?;
+
+    match (key_algorithm, configured) {
+        (Some(key_algorithm), Some(configured)) if key_algorithm != configured => Err(invalid(
+            "The algorithm of the key is not the configured algorithm",
+        )),
+        (Some(algorithm), _) | (None, Some(algorithm)) => Ok(algorithm),
+        (None, None) => Err(invalid("No algorithm to verify the token with")),
+    }
+}
+
+async fn load_jwks(url: &str) -> Result<Arc<JwkSet>, String> {
+    if let Some((fetched_at, jwks)) = JWKS_CACHE.lock().unwrap().get(url) {
+        if fetched_at.elapsed() < JWKS_CACHE_TTL {
+            return Ok(jwks.clone());
+        }
+    }
+
+    let jwks: Arc<JwkSet> = Arc::new(
+        JWKS_CLIENT
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| format!("Failed to fetch JWKS {}: {}", url, err))?
+            .json()
+            .await
+            .map_err(|err| format!("Invalid JWKS {}: {}", url, err))?,
+    );
+
+    JWKS_CACHE
+        .lock()
+        .unwrap()
+        .insert(url.to_string(), (Instant::now(), jwks.clone()));
+
+    Ok(jwks)
+}
+
+fn authorization<'a>(headers: &'a HeaderMap, kind: &str) -> Result<&'a str, AuthenticationError> {
+    let value = headers
+        .get(AUTHORIZATION)
+        .ok_or(AuthenticationError::MissingCredentials)?
+        .to_str()
+        .map_err(|_| invalid("Authorization header is not a valid string"))?;
+
+    match value.split_once(' ') {
+        Some((prefix, credentials)) if prefix.eq_ignore_ascii_case(kind) => Ok(credentials.trim()),
+        _ => Err(AuthenticationError::MissingCredentials),
+    }
+}
+
+fn subject_claims(subject: &str) -> Value {
+    serde_json::json!({ "sub": subject })
+}
+
+fn invalid(reason: impl AsRef<str>) -> AuthenticationError {
+    AuthenticationError::InvalidCredentials(reason.as_ref().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::api_definition::http::{
+        hash_password, sha256_hex, ApiKeyCredential, ApiKeyScheme, BasicCredential, BasicScheme,
+        HttpSecurityScheme, HttpSecuritySchemeKind, JwtScheme,
+    };
+    use crate::http::auth::{authenticate, AuthenticationError};
+    use base64::Engine;
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use poem::http::header::AUTHORIZATION;
+    use poem::http::{HeaderMap, HeaderValue};
+    use serde_json::{json, Value};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    const SECRET: &str = "a-shared-secret";
+
+    fn headers(name: &str, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            poem::http::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+            HeaderValue::from_str(value).unwrap(),
+        );
+        headers
+    }
+
+    fn scheme(scheme: HttpSecuritySchemeKind) -> HttpSecurityScheme {
+        HttpSecurityScheme {
+            name: "test".to_string(),
+            scheme,
+        }
+    }
+
+    fn jwt_scheme(issuer: Option<&str>) -> HttpSecurityScheme {
+        scheme(HttpSecuritySchemeKind::Jwt(JwtScheme {
+            jwks_url: None,
+            static_key: Some(SECRET.to_string()),
+            algorithm: Some("HS256".to_string()),
+            issuer: issuer.map(|issuer| issuer.to_string()),
+            audience: None,
+        }))
+    }
+
+    fn token(claims: &Value) -> String {
+        jsonwebtoken::encode(
+            &Header::default(),
+            claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    // Serves the given JWKS to every request on a local port, returning its URL
+    fn serve_jwks(jwks: Value) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/jwks.json", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request = [0; 4096];
+                let _ = stream.read(&mut request);
+                let body = jwks.to_string();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        });
+        url
+    }
+
+    fn jwks_scheme(jwks_url: String) -> HttpSecurityScheme {
+        scheme(HttpSecuritySchemeKind::Jwt(JwtScheme {
+            jwks_url: Some(jwks_url),
+            static_key: None,
+            algorithm: None,
+            issuer: None,
+            audience: None,
+        }))
+    }
+
+    #[test]
+    async fn api_key() {
+        let scheme = scheme(HttpSecuritySchemeKind::ApiKey(ApiKeyScheme {
+            header: "X-API-Key".to_string(),
+            keys: vec![ApiKeyCredential {
+                subject: "service-a".to_string(),
+                key_sha256: sha256_hex(b"key-a"),
+            }],
+        }));
+
+        let valid = authenticate(&scheme, &headers("x-api-key", "key-a")).await;
+        let invalid = authenticate(&scheme, &headers("x-api-key", "key-b")).await;
+        let missing = authenticate(&scheme, &HeaderMap::new()).await;
+
+        assert_eq!(valid, Ok(json!({ "sub": "service-a" })));
+        assert!(matches!(
+            invalid,
+            Err(AuthenticationError::InvalidCredentials(_))
+        ));
+        assert_eq!(missing, Err(AuthenticationError::MissingCredentials));
+    }
+
+    #[test]
+    async fn basic() {
+        let scheme = scheme(HttpSecuritySchemeKind::Basic(BasicScheme {
+            users: vec![BasicCredential {
+                username: "alice".to_string(),
+                password_hash: hash_password(b"secret"),
+            }],
+        }));
+
+        let credentials = |value: &str| {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(value);
+            headers(AUTHORIZATION.as_str(), &format!("Basic {}", encoded))
+        };
+
+        let valid = authenticate(&scheme, &credentials("alice:secret")).await;
+        let invalid = authenticate(&scheme, &credentials("alice:wrong")).await;
+        let unknown = authenticate(&scheme, &credentials("bob:secret")).await;
+
+        assert_eq!(valid, Ok(json!({ "sub": "alice" })));
+        assert!(matches!(
+            invalid,
+            Err(AuthenticationError::InvalidCredentials(_))
+        ));
+        assert!(matches!(
+            unknown,
+            Err(AuthenticationError::InvalidCredentials(_))
+        ));
+    }
+
+    #[test]
+    async fn jwt_static_key() {
+        let claims = json!({ "sub": "bob", "iss": "golem", "exp": 4102444800u64 });
+        let bearer = format!("Bearer {}", token(&claims));
+
+        let valid = authenticate(
+            &jwt_scheme(Some("golem")),
+            &headers("authorization", &bearer),
+        )
+        .await;
+        let other_issuer = authenticate(
+            &jwt_scheme(Some("other")),
+            &headers("authorization", &bearer),
+        )
+        .await;
+
+        assert_eq!(valid, Ok(claims));
+        assert!(matches!(
+            other_issuer,
+            Err(AuthenticationError::InvalidCredentials(_))
+        ));
+    }
+
+    #[test]
+    async fn jwt_expired() {
+        let claims = json!({ "sub": "bob", "exp": 1000 });
+        let bearer = format!("Bearer {}", token(&claims));
+
+        let result = authenticate(&jwt_scheme(None), &headers("authorization", &bearer)).await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(_))
+        ));
+    }
+
+    #[test]
+    async fn jwt_jwks_url() {
+        let jwks = json!({
+            "keys": [{
+                "kty": "oct",
+                "kid": "key-1",
+                "alg": "HS256",
+                "k": base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(SECRET)
+            }]
+        });
+        let scheme = jwks_scheme(serve_jwks(jwks));
+
+        let claims = json!({ "sub": "carol", "exp": 4102444800u64 });
+        let header = Header {
+            kid: Some("key-1".to_string()),
+            ..Header::default()
+        };
+        let token = jsonwebtoken::encode(
+            &header,
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        let result = authenticate(
+            &scheme,
+            &headers("authorization", &format!("Bearer {}", token)),
+        )
+        .await;
+
+        assert_eq!(result, Ok(claims));
+    }
+
+    #[test]
+    async fn jwt_algorithm_is_not_taken_from_the_token() {
+        let jwks = json!({
+            "keys": [{
+                "kty": "oct",
+                "kid": "key-1",
+                "alg": "HS512",
+                "k": base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(SECRET)
+            }]
+        });
+        let scheme = jwks_scheme(serve_jwks(jwks));
+
+        let claims = json!({ "sub": "carol", "exp": 4102444800u64 });
+        let header = Header {
+            kid: Some("key-1".to_string()),
+            ..Header::new(Algorithm::HS256)
+        };
+        let token = jsonwebtoken::encode(
+            &header,
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        let result = authenticate(
+            &scheme,
+            &headers("authorization", &format!("Bearer {}", token)),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(AuthenticationError::InvalidCredentials(_))
+        ));
+    }
+}
@@ -0,0 +1,317 @@
+use crate::api_definition::http::{CompiledHttpApiDefinition, HttpCors, MethodPattern};
+use crate::http::http_request::router;
+use crate::http::router::RouterPattern;
+use crate::http::InputHttpRequest;
+use poem::http::header::{
+    ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE,
+    ACCESS_CONTROL_REQUEST_HEADERS, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN, VARY,
+};
+use poem::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use poem::Response;
+
+const ANY: &str = "*";
+
+// A preflight request is an OPTIONS request of a browser asking whether
+// the actual request with the given method and headers is allowed
+pub fn is_preflight_request(request: &InputHttpRequest) -> bool {
+    request.req_method == Method::OPTIONS
+        && request.headers.contains_key(ORIGIN)
+        && request.headers.contains_key(ACCESS_CONTROL_REQUEST_METHOD)
+}
+
+// The CORS policy of the route a preflight request asks about
+pub fn resolve_preflight_cors(
+    request: &InputHttpRequest,
+    api_definitions: &[CompiledHttpApiDefinition],
+) -> Option<HttpCors> {
+    let request_method = request
+        .headers
+        .get(ACCESS_CONTROL_REQUEST_METHOD)
+        .and_then(|method| Method::from_bytes(method.as_bytes()).ok())?;
+
+    let routes = api_definitions
+        .iter()
+        .flat_map(|definition| definition.routes.clone())
+        .collect::<Vec<_>>();
+
+    let router = router::build(routes);
+    let path: Vec<&str> = RouterPattern::split(&request.input_path.base_path).collect();
+
+    router
+        .check_path(&request_method, &path)
+        .and_then(|entry| entry.cors.clone())
+}
+
+impl HttpCors {
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allow_origins
+            .iter()
+            .any(|allowed| allowed == ANY || allowed.eq_ignore_ascii_case(origin))
+    }
+
+    pub fn preflight_response(&self, request_headers: &HeaderMap) -> Response {
+        let origin = match header_str(request_headers, &ORIGIN) {
+            Some(origin) if self.allows_origin(origin) => origin,
+            _ => return forbidden(),
+        };
+
+        let request_method = match header_str(request_headers, &ACCESS_CONTROL_REQUEST_METHOD) {
+            Some(method) => method,
+            None => return forbidden(),
+        };
+
+        let allow_methods = if self.allow_methods.is_empty() {
+            request_method.to_string()
+        } else if self
+            .allow_methods
+            .iter()
+            .any(|method| method_name(method).eq_ignore_ascii_case(request_method))
+        {
+            self.allow_methods
+                .iter()
+                .map(method_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            return forbidden();
+        };
+
+        let request_header_names = header_str(request_headers, &ACCESS_CONTROL_REQUEST_HEADERS)
+            .map(|names| {
+                names
+                    .split(',')
+                    .map(|name| name.trim())
+                    .filter(|name| !name.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let allow_any_header = self.allow_headers.iter().any(|name| name == ANY);
+
+        if !allow_any_header
+            && !request_header_names.iter().all(|name| {
+                self.allow_headers
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(name))
+            })
+        {
+            return forbidden();
+        }
+
+        let mut response = Response::builder().status(StatusCode::NO_CONTENT).finish();
+
+        self.add_origin_headers(origin, &mut response);
+        insert_header(&mut response, ACCESS_CONTROL_ALLOW_METHODS, &allow_methods);
+
+        let allow_headers = if allow_any_header {
+            request_header_names.join(", ")
+        } else {
+            self.allow_headers.join(", ")
+        };
+
+        if !allow_headers.is_empty() {
+            insert_header(&mut response, ACCESS_CONTROL_ALLOW_HEADERS, &allow_headers);
+        }
+
+        if let Some(max_age) = self.max_age {
+            insert_header(&mut response, ACCESS_CONTROL_MAX_AGE, &max_age.to_string());
+        }
+
+        response
+    }
+
+    // Adds the CORS headers to the response of an actual (non-preflight) request
+    pub fn add_response_headers(&self, request_headers: &HeaderMap, response: &mut Response) {
+        if let Some(origin) = header_str(request_headers, &ORIGIN) {
+            if self.allows_origin(origin) {
+                self.add_origin_headers(origin, response);
+
+                if !self.expose_headers.is_empty() {
+                    insert_header(
+                        response,
+                        ACCESS_CONTROL_EXPOSE_HEADERS,
+                        &self.expose_headers.join(", "),
+                    );
+                }
+            }
+        }
+    }
+
+    fn add_origin_headers(&self, origin: &str, response: &mut Response) {
+        // A wildcard is not accepted by browsers for requests with credentials
+        if self.allow_origins.iter().any(|allowed| allowed == ANY) && !self.allow_credentials {
+            insert_header(response, ACCESS_CONTROL_ALLOW_ORIGIN, ANY);
+        } else {
+            insert_header(response, ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+            insert_header(response, VARY, ORIGIN.as_str());
+        }
+
+        if self.allow_credentials {
+            insert_header(response, ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        }
+    }
+}
+
+fn method_name(method: &MethodPattern) -> String {
+    Method::from(method.clone()).to_string()
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &HeaderName) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn insert_header(response: &mut Response, name: HeaderName, value: &str) {
+    if let Ok(value) = HeaderValue::from_str(value) {
+        response.headers_mut().insert(name, value);
+    }
+}
+
+fn forbidden() -> Response {
+    Response::builder().status(StatusCode::FORBIDDEN).finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::api_definition::http::{HttpCors, MethodPattern};
+    use poem::http::header::{
+        ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_HEADERS,
+        ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_EXPOSE_HEADERS,
+        ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_HEADERS, ACCESS_CONTROL_REQUEST_METHOD,
+        ORIGIN, VARY,
+    };
+    use poem::http::{HeaderMap, HeaderValue, StatusCode};
+    use poem::Response;
+
+    fn cors() -> HttpCors {
+        HttpCors {
+            allow_origins: vec!["https://app.golem.cloud".to_string()],
+            allow_methods: vec![MethodPattern::Get, MethodPattern::Post],
+            allow_headers: vec!["Content-Type".to_string()],
+            expose_headers: vec!["X-Request-Id".to_string()],
+            allow_credentials: true,
+            max_age: Some(600),
+        }
+    }
+
+    fn headers(values: &[(poem::http::HeaderName, &str)]) -> HeaderMap {
+        values
+            .iter()
+            .map(|(name, value)| (name.clone(), HeaderValue::from_str(value).unwrap()))
+            .collect()
+    }
+
+    fn header(response: &Response, name: poem::http::HeaderName) -> Option<&str> {
+        response
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[test]
+    fn preflight_allowed() {
+        let response = cors().preflight_response(&headers(&[
+            (ORIGIN, "https://app.golem.cloud"),
+            (ACCESS_CONTROL_REQUEST_METHOD, "POST"),
+            (ACCESS_CONTROL_REQUEST_HEADERS, "content-type"),
+        ]));
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.golem.cloud")
+        );
+        assert_eq!(header(&response, VARY), Some("origin"));
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_METHODS),
+            Some("GET, POST")
+        );
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_HEADERS),
+            Some("Content-Type")
+        );
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_CREDENTIALS),
+            Some("true")
+        );
+        assert_eq!(header(&response, ACCESS_CONTROL_MAX_AGE), Some("600"));
+    }
+
+    #[test]
+    fn preflight_forbidden() {
+        let cors = cors();
+
+        let other_origin = cors.preflight_response(&headers(&[
+            (ORIGIN, "https://example.com"),
+            (ACCESS_CONTROL_REQUEST_METHOD, "POST"),
+        ]));
+        let other_method = cors.preflight_response(&headers(&[
+            (ORIGIN, "https://app.golem.cloud"),
+            (ACCESS_CONTROL_REQUEST_METHOD, "DELETE"),
+        ]));
+        let other_header = cors.preflight_response(&headers(&[
+            (ORIGIN, "https://app.golem.cloud"),
+            (ACCESS_CONTROL_REQUEST_METHOD, "GET"),
+            (ACCESS_CONTROL_REQUEST_HEADERS, "authorization"),
+        ]));
+
+        assert_eq!(other_origin.status(), StatusCode::FORBIDDEN);
+        assert_eq!(other_method.status(), StatusCode::FORBIDDEN);
+        assert_eq!(other_header.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn preflight_any_origin_and_header() {
+        let cors = HttpCors {
+            allow_origins: vec!["*".to_string()],
+            allow_methods: vec![],
+            allow_headers: vec!["*".to_string()],
+            expose_headers: vec![],
+            allow_credentials: false,
+            max_age: None,
+        };
+
+        let response = cors.preflight_response(&headers(&[
+            (ORIGIN, "https://example.com"),
+            (ACCESS_CONTROL_REQUEST_METHOD, "PUT"),
+            (ACCESS_CONTROL_REQUEST_HEADERS, "x-custom, authorization"),
+        ]));
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_ORIGIN), Some("*"));
+        assert_eq!(header(&response, VARY), None);
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_METHODS), Some("PUT"));
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_HEADERS),
+            Some("x-custom, authorization")
+        );
+        assert_eq!(header(&response, ACCESS_CONTROL_MAX_AGE), None);
+    }
+
+    #[test]
+    fn response_headers() {
+        let mut response = Response::builder().status(StatusCode::OK).finish();
+
+        cors().add_response_headers(
+            &headers(&[(ORIGIN, "https://app.golem.cloud")]),
+            &mut response,
+        );
+
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://app.golem.cloud")
+        );
+        assert_eq!(
+            header(&response, ACCESS_CONTROL_EXPOSE_HEADERS),
+            Some("X-Request-Id")
+        );
+
+        let mut response = Response::builder().status(StatusCode::OK).finish();
+
+        cors().add_response_headers(&headers(&[(ORIGIN, "https://example.com")]), &mut response);
+
+        assert_eq!(header(&response, ACCESS_CONTROL_ALLOW_ORIGIN), None);
+    }
+}
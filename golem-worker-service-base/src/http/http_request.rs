@@ -1,15 +1,18 @@
 use std::collections::HashMap;
 
+use crate::api_definition::http::CompiledHttpApiDefinition;
 use crate::api_definition::ApiSiteString;
+use crate::http::router::RouterPattern;
+use bytes::Bytes;
+use golem_common::model::http_handler::IncomingHttpRequest;
 use hyper::http::{HeaderMap, Method};
-use serde_json::Value;
 
 #[derive(Clone)]
 pub struct InputHttpRequest {
     pub input_path: ApiInputPath,
     pub headers: HeaderMap,
     pub req_method: Method,
-    pub req_body: Value,
+    pub req_body: Bytes,
 }
 
 impl InputHttpRequest {
@@ -19,6 +22,50 @@ impl InputHttpRequest {
             .and_then(|host| host.to_str().ok())
             .map(|host_str| ApiSiteString(host_str.to_string()))
     }
+
+    // The route matching the request. It is resolved once, before reading the body, so the
+    // body size limit of the route is known while reading it.
+    pub fn resolve_route(
+        &self,
+        api_definitions: &[CompiledHttpApiDefinition],
+    ) -> Option<router::RouteEntry> {
+        let routes = api_definitions
+            .iter()
+            .flat_map(|x| x.routes.clone())
+            .collect::<Vec<_>>();
+
+        let router = router::build(routes);
+        let path: Vec<&str> = RouterPattern::split(&self.input_path.base_path).collect();
+
+        router.check_path(&self.req_method, &path).cloned()
+    }
+
+    // The request as passed to a worker's wasi:http incoming-handler. The body is already
+    // fully read by the gateway, and trailers are not supported.
+    pub fn to_incoming_http_request(&self) -> IncomingHttpRequest {
+        let host = self
+            .get_host()
+            .map(|host| host.0)
+            .unwrap_or("localhost".to_string());
+
+        let uri = match &self.input_path.query_path {
+            Some(query) => format!("http://{}{}?{}", host, self.input_path.base_path, query),
+            None => format!("http://{}{}", host, self.input_path.base_path),
+        };
+
+        let headers = self
+            .headers
+            .iter()
+            .map(|(name, value)| (name.as_str().to_string(), value.as_bytes().to_vec()))
+            .collect();
+
+        IncomingHttpRequest {
+            method: self.req_method.to_string(),
+            uri,
+            headers,
+            body: self.req_body.to_vec(),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -49,12 +96,14 @@ impl ApiInputPath {
 }
 
 pub mod router {
-    use crate::api_definition::http::CompiledRoute;
+    use crate::api::WorkerBindingType;
+    use crate::api_definition::http::{CompiledRoute, HttpCors, HttpSecurityScheme};
     use crate::worker_binding::CompiledGolemWorkerBinding;
     use crate::{
         api_definition::http::{PathPattern, QueryInfo, VarInfo},
         http::router::{Router, RouterPattern},
     };
+    use golem_common::model::http_handler::MAX_HTTP_HANDLER_BODY_SIZE;
 
     #[derive(Debug, Clone)]
     pub struct RouteEntry {
@@ -62,6 +111,22 @@ pub mod router {
         pub path_params: Vec<(VarInfo, usize)>,
         pub query_params: Vec<QueryInfo>,
         pub binding: CompiledGolemWorkerBinding,
+        pub max_body_size: Option<u64>,
+        pub cors: Option<HttpCors>,
+        pub security: Option<HttpSecurityScheme>,
+    }
+
+    impl RouteEntry {
+        // The number of bytes of the request body the gateway reads for this route. Requests
+        // forwarded to an incoming-handler are always capped, as their body is kept in memory.
+        pub fn body_size_limit(&self) -> Option<u64> {
+            if self.binding.binding_type == WorkerBindingType::HttpHandler {
+                let max = MAX_HTTP_HANDLER_BODY_SIZE as u64;
+                Some(self.max_body_size.map_or(max, |limit| limit.min(max)))
+            } else {
+                self.max_body_size
+            }
+        }
     }
 
     pub fn build(routes: Vec<CompiledRoute>) -> Router<RouteEntry> {
@@ -71,6 +136,9 @@ pub mod router {
             let method = route.method.into();
             let path = route.path;
             let binding = route.binding;
+            let max_body_size = route.max_body_size;
+            let cors = route.cors;
+            let security = route.security;
 
             let path_params = path
                 .path_patterns
@@ -86,6 +154,9 @@ pub mod router {
                 path_params,
                 query_params: path.query_params,
                 binding,
+                max_body_size,
+                cors,
+                security,
             };
 
             let path: Vec<RouterPattern> = path
@@ -122,6 +193,7 @@ mod tests {
         DefaultRibInterpreter, EvaluationError, WorkerServiceRibInterpreter,
     };
     use async_trait::async_trait;
+    use bytes::Bytes;
     use golem_common::model::{ComponentId, IdempotencyKey};
     use golem_service_base::model::VersionedComponentId;
     use golem_wasm_ast::analysis::analysed_type::{field, record, str, tuple};
@@ -363,9 +435,12 @@ mod tests {
         api_specification: &HttpApiDefinition,
     ) -> TestResponse {
         let evaluator = get_test_evaluator();
-        let compiled =
-            CompiledHttpApiDefinition::from_http_api_definition(api_specification, &get_metadata())
-                .unwrap();
+        let compiled = CompiledHttpApiDefinition::from_http_api_definition(
+            api_specification,
+            &get_metadata(),
+            &[],
+        )
+        .unwrap();
 
         let resolved_route = api_request
             .resolve_worker_binding(vec![compiled])
@@ -868,6 +943,7 @@ mod tests {
             let compiled_api_spec = CompiledHttpApiDefinition::from_http_api_definition(
                 &api_specification,
                 &get_metadata(),
+                &[],
             )
             .unwrap();
 
@@ -909,6 +985,7 @@ mod tests {
             let compiled_api_spec = CompiledHttpApiDefinition::from_http_api_definition(
                 &api_specification,
                 &get_metadata(),
+                &[],
             )
             .unwrap();
 
@@ -932,6 +1009,87 @@ mod tests {
         test_key(&headers, Some(IdempotencyKey::new("bar".to_string()))).await;
     }
 
+    #[test]
+    async fn test_worker_trace_context_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "traceparent",
+            HeaderValue::from_str("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+                .unwrap(),
+        );
+        headers.insert(
+            "tracestate",
+            HeaderValue::from_str("congo=t61rcWkgMzE").unwrap(),
+        );
+
+        let api_request = get_api_request("/getcartcontent/1", None, &headers, Value::Null);
+
+        let expression = r#"
+            let response = golem:it/api.{get-cart-contents}("foo", "bar");
+            response
+            "#;
+
+        let api_specification: HttpApiDefinition = get_api_spec(
+            "getcartcontent/{cart-id}",
+            "${let x: u64 = request.path.cart-id; \"shopping-cart-${x}\"}",
+            expression,
+        );
+
+        let compiled_api_spec = CompiledHttpApiDefinition::from_http_api_definition(
+            &api_specification,
+            &get_metadata(),
+            &[],
+        )
+        .unwrap();
+
+        let resolved_route = api_request
+            .resolve_worker_binding(vec![compiled_api_spec])
+            .await
+            .unwrap();
+
+        let trace_context = resolved_route
+            .worker_detail
+            .invocation_context
+            .and_then(|context| context.trace_context)
+            .unwrap();
+
+        assert_eq!(
+            trace_context.traceparent,
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+        assert_eq!(
+            trace_context.tracestate,
+            Some("congo=t61rcWkgMzE".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_incoming_http_request() {
+        let mut headers = HeaderMap::new();
+        headers.insert("host", HeaderValue::from_str("example.com").unwrap());
+        headers.insert("x-test", HeaderValue::from_str("foo").unwrap());
+
+        // The body is passed as is, even if it is not valid JSON or UTF-8
+        let body = b"\x00\x01 not json \xff".to_vec();
+        let api_request = InputHttpRequest {
+            input_path: ApiInputPath {
+                base_path: "/foo/1".to_string(),
+                query_path: Some("bar=2".to_string()),
+            },
+            headers,
+            req_method: Method::POST,
+            req_body: Bytes::from(body.clone()),
+        };
+        let incoming_request = api_request.to_incoming_http_request();
+
+        assert_eq!(incoming_request.method, "POST");
+        assert_eq!(incoming_request.uri, "http://example.com/foo/1?bar=2");
+        assert!(incoming_request
+            .headers
+            .contains(&("x-test".to_string(), b"foo".to_vec())));
+        assert_eq!(incoming_request.body, body);
+    }
+
     fn get_api_request(
         base_path: &str,
         query_path: Option<&str>,
@@ -945,7 +1103,7 @@ mod tests {
             },
             headers: headers.clone(),
             req_method: Method::GET,
-            req_body,
+            req_body: Bytes::from(req_body.to_string()),
         }
     }
 
@@ -1,5 +1,7 @@
 pub use http_request::*;
 
+pub mod auth;
+pub mod cors;
 pub mod http_request;
 
 pub mod router;
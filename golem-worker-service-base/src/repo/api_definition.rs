@@ -38,7 +38,7 @@ impl ApiDefinitionRecord {
         definition: CompiledHttpApiDefinition,
         created_at: chrono::DateTime<chrono::Utc>,
     ) -> Result<Self, String> {
-        let data = record_data_serde::serialize(&definition.routes)?;
+        let data = record_data_serde::serialize(&definition.routes, &definition.security_schemes)?;
         Ok(Self {
             namespace: namespace.to_string(),
             id: definition.id.0,
@@ -53,13 +53,14 @@ impl ApiDefinitionRecord {
 impl TryFrom<ApiDefinitionRecord> for CompiledHttpApiDefinition {
     type Error = String;
     fn try_from(value: ApiDefinitionRecord) -> Result<Self, Self::Error> {
-        let routes = record_data_serde::deserialize(&value.data)?;
+        let (routes, security_schemes) = record_data_serde::deserialize(&value.data)?;
 
         Ok(Self {
             id: value.id.into(),
             version: value.version.into(),
             routes,
             draft: value.draft,
+            security_schemes,
             created_at: value.created_at,
         })
     }
@@ -406,7 +407,7 @@ impl ApiDefinitionRepo for DbApiDefinitionRepo<sqlx::Postgres> {
 }
 
 pub mod record_data_serde {
-    use crate::api_definition::http::CompiledRoute;
+    use crate::api_definition::http::{CompiledRoute, HttpSecurityScheme};
     use bytes::{BufMut, Bytes, BytesMut};
     use golem_api_grpc::proto::golem::apidefinition::{
         CompiledHttpApiDefinition, CompiledHttpRoute,
@@ -415,14 +416,22 @@ pub mod record_data_serde {
 
     pub const SERIALIZATION_VERSION_V1: u8 = 1u8;
 
-    pub fn serialize(value: &[CompiledRoute]) -> Result<Bytes, String> {
+    pub fn serialize(
+        value: &[CompiledRoute],
+        security_schemes: &[HttpSecurityScheme],
+    ) -> Result<Bytes, String> {
         let routes: Vec<CompiledHttpRoute> = value
             .iter()
             .cloned()
             .map(CompiledHttpRoute::try_from)
             .collect::<Result<Vec<CompiledHttpRoute>, String>>()?;
 
-        let proto_value: CompiledHttpApiDefinition = CompiledHttpApiDefinition { routes };
+        let security_schemes = security_schemes.iter().cloned().map(|s| s.into()).collect();
+
+        let proto_value: CompiledHttpApiDefinition = CompiledHttpApiDefinition {
+            routes,
+            security_schemes,
+        };
 
         let mut bytes = BytesMut::new();
         bytes.put_u8(SERIALIZATION_VERSION_V1);
@@ -430,7 +439,9 @@ pub mod record_data_serde {
         Ok(bytes.freeze())
     }
 
-    pub fn deserialize(bytes: &[u8]) -> Result<Vec<CompiledRoute>, String> {
+    pub fn deserialize(
+        bytes: &[u8],
+    ) -> Result<(Vec<CompiledRoute>, Vec<HttpSecurityScheme>), String> {
         let (version, data) = bytes.split_at(1);
 
         match version[0] {
@@ -438,13 +449,19 @@ pub mod record_data_serde {
                 let proto_value: CompiledHttpApiDefinition = Message::decode(data)
                     .map_err(|e| format!("Failed to deserialize value: {e}"))?;
 
-                let value = proto_value
+                let routes = proto_value
                     .routes
                     .into_iter()
                     .map(CompiledRoute::try_from)
                     .collect::<Result<Vec<CompiledRoute>, String>>()?;
 
-                Ok(value)
+                let security_schemes = proto_value
+                    .security_schemes
+                    .into_iter()
+                    .map(HttpSecurityScheme::try_from)
+                    .collect::<Result<Vec<HttpSecurityScheme>, String>>()?;
+
+                Ok((routes, security_schemes))
             }
             _ => Err("Unsupported serialization version".to_string()),
         }
@@ -0,0 +1,321 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use conditional_trait_gen::{trait_gen, when};
+use golem_service_base::repo::RepoError;
+use rib::RibModule;
+use sqlx::{Database, Pool};
+use std::fmt::Display;
+use std::ops::Deref;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct RibModuleRecord {
+    pub namespace: String,
+    pub name: String,
+    pub version: i64,
+    pub source: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl RibModuleRecord {
+    pub fn new<Namespace: Display>(
+        namespace: Namespace,
+        name: String,
+        version: u64,
+        source: String,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            name,
+            version: version as i64,
+            source,
+            created_at,
+        }
+    }
+}
+
+impl TryFrom<RibModuleRecord> for RibModule {
+    type Error = String;
+    fn try_from(value: RibModuleRecord) -> Result<Self, Self::Error> {
+        RibModule::from_text(value.name, value.version as u64, value.source.as_str())
+    }
+}
+
+#[async_trait]
+pub trait RibModuleRepo {
+    async fn create(&self, module: &RibModuleRecord) -> Result<(), RepoError>;
+
+    async fn get(
+        &self,
+        namespace: &str,
+        name: &str,
+        version: u64,
+    ) -> Result<Option<RibModuleRecord>, RepoError>;
+
+    async fn get_latest(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<RibModuleRecord>, RepoError>;
+
+    async fn delete(&self, namespace: &str, name: &str, version: u64) -> Result<bool, RepoError>;
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<RibModuleRecord>, RepoError>;
+
+    async fn get_all_versions(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Vec<RibModuleRecord>, RepoError>;
+}
+
+pub struct LoggedRibModuleRepo<Repo: RibModuleRepo> {
+    repo: Repo,
+}
+
+impl<Repo: RibModuleRepo> LoggedRibModuleRepo<Repo> {
+    pub fn new(repo: Repo) -> Self {
+        Self { repo }
+    }
+
+    fn logged_with_name<R>(
+        message: &'static str,
+        namespace: &str,
+        name: &str,
+        result: Result<R, RepoError>,
+    ) -> Result<R, RepoError> {
+        match &result {
+            Ok(_) => debug!(
+                namespace = namespace,
+                rib_module_name = name.to_string(),
+                "{}",
+                message
+            ),
+            Err(error) => error!(
+                namespace = namespace,
+                rib_module_name = name.to_string(),
+                error = error.to_string(),
+                "{message}"
+            ),
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl<Repo: RibModuleRepo + Sync> RibModuleRepo for LoggedRibModuleRepo<Repo> {
+    async fn create(&self, module: &RibModuleRecord) -> Result<(), RepoError> {
+        let result = self.repo.create(module).await;
+        Self::logged_with_name("create", &module.namespace, &module.name, result)
+    }
+
+    async fn get(
+        &self,
+        namespace: &str,
+        name: &str,
+        version: u64,
+    ) -> Result<Option<RibModuleRecord>, RepoError> {
+        let result = self.repo.get(namespace, name, version).await;
+        Self::logged_with_name("get", namespace, name, result)
+    }
+
+    async fn get_latest(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<RibModuleRecord>, RepoError> {
+        let result = self.repo.get_latest(namespace, name).await;
+        Self::logged_with_name("get_latest", namespace, name, result)
+    }
+
+    async fn delete(&self, namespace: &str, name: &str, version: u64) -> Result<bool, RepoError> {
+        let result = self.repo.delete(namespace, name, version).await;
+        Self::logged_with_name("delete", namespace, name, result)
+    }
+
+    async fn get_all(&self, namespace: &str) -> Result<Vec<RibModuleRecord>, RepoError> {
+        let result = self.repo.get_all(namespace).await;
+        Self::logged_with_name("get_all", namespace, "*", result)
+    }
+
+    async fn get_all_versions(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Vec<RibModuleRecord>, RepoError> {
+        let result = self.repo.get_all_versions(namespace, name).await;
+        Self::logged_with_name("get_all_versions", namespace, name, result)
+    }
+}
+
+pub struct DbRibModuleRepo<DB: Database> {
+    db_pool: Arc<Pool<DB>>,
+}
+
+impl<DB: Database> DbRibModuleRepo<DB> {
+    pub fn new(db_pool: Arc<Pool<DB>>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[trait_gen(sqlx::Postgres -> sqlx::Postgres, sqlx::Sqlite)]
+#[async_trait]
+impl RibModuleRepo for DbRibModuleRepo<sqlx::Postgres> {
+    async fn create(&self, module: &RibModuleRecord) -> Result<(), RepoError> {
+        sqlx::query(
+            r#"
+              INSERT INTO rib_modules
+                (namespace, name, version, source, created_at)
+              VALUES
+                ($1, $2, $3, $4, $5)
+               "#,
+        )
+        .bind(module.namespace.clone())
+        .bind(module.name.clone())
+        .bind(module.version)
+        .bind(module.source.clone())
+        .bind(module.created_at)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(())
+    }
+
+    #[when(sqlx::Postgres -> get)]
+    async fn get_postgres(
+        &self,
+        namespace: &str,
+        name: &str,
+        version: u64,
+    ) -> Result<Option<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>("SELECT namespace, name, version, source, created_at::timestamptz FROM rib_modules WHERE namespace = $1 AND name = $2 AND version = $3")
+            .bind(namespace)
+            .bind(name)
+            .bind(version as i64)
+            .fetch_optional(self.db_pool.deref())
+            .await
+            .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get)]
+    async fn get_sqlite(
+        &self,
+        namespace: &str,
+        name: &str,
+        version: u64,
+    ) -> Result<Option<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>("SELECT namespace, name, version, source, created_at FROM rib_modules WHERE namespace = $1 AND name = $2 AND version = $3")
+            .bind(namespace)
+            .bind(name)
+            .bind(version as i64)
+            .fetch_optional(self.db_pool.deref())
+            .await
+            .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Postgres -> get_latest)]
+    async fn get_latest_postgres(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>("SELECT namespace, name, version, source, created_at::timestamptz FROM rib_modules WHERE namespace = $1 AND name = $2 ORDER BY version DESC LIMIT 1")
+            .bind(namespace)
+            .bind(name)
+            .fetch_optional(self.db_pool.deref())
+            .await
+            .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_latest)]
+    async fn get_latest_sqlite(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Option<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>("SELECT namespace, name, version, source, created_at FROM rib_modules WHERE namespace = $1 AND name = $2 ORDER BY version DESC LIMIT 1")
+            .bind(namespace)
+            .bind(name)
+            .fetch_optional(self.db_pool.deref())
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn delete(&self, namespace: &str, name: &str, version: u64) -> Result<bool, RepoError> {
+        let result = sqlx::query(
+            "DELETE FROM rib_modules WHERE namespace = $1 AND name = $2 AND version = $3",
+        )
+        .bind(namespace)
+        .bind(name)
+        .bind(version as i64)
+        .execute(self.db_pool.deref())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[when(sqlx::Postgres -> get_all)]
+    async fn get_all_postgres(&self, namespace: &str) -> Result<Vec<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>(
+            "SELECT namespace, name, version, source, created_at::timestamptz FROM rib_modules WHERE namespace = $1 ORDER BY name, version",
+        )
+        .bind(namespace)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_all)]
+    async fn get_all_sqlite(&self, namespace: &str) -> Result<Vec<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>(
+            "SELECT namespace, name, version, source, created_at FROM rib_modules WHERE namespace = $1 ORDER BY name, version",
+        )
+        .bind(namespace)
+        .fetch_all(self.db_pool.deref())
+        .await
+        .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Postgres -> get_all_versions)]
+    async fn get_all_versions_postgres(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Vec<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>("SELECT namespace, name, version, source, created_at::timestamptz FROM rib_modules WHERE namespace = $1 AND name = $2 ORDER BY version")
+            .bind(namespace)
+            .bind(name)
+            .fetch_all(self.db_pool.deref())
+            .await
+            .map_err(|e| e.into())
+    }
+
+    #[when(sqlx::Sqlite -> get_all_versions)]
+    async fn get_all_versions_sqlite(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<Vec<RibModuleRecord>, RepoError> {
+        sqlx::query_as::<_, RibModuleRecord>("SELECT namespace, name, version, source, created_at FROM rib_modules WHERE namespace = $1 AND name = $2 ORDER BY version")
+            .bind(namespace)
+            .bind(name)
+            .fetch_all(self.db_pool.deref())
+            .await
+            .map_err(|e| e.into())
+    }
+}
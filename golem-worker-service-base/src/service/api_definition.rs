@@ -24,11 +24,13 @@ use crate::api_definition::{ApiDefinitionId, ApiVersion, HasGolemWorkerBindings}
 use crate::repo::api_definition::ApiDefinitionRecord;
 use crate::repo::api_definition::ApiDefinitionRepo;
 use crate::repo::api_deployment::ApiDeploymentRepo;
+use crate::repo::rib_module::RibModuleRepo;
 use async_trait::async_trait;
 use chrono::Utc;
 use golem_common::SafeDisplay;
 use golem_service_base::model::{Component, VersionedComponentId};
 use golem_service_base::repo::RepoError;
+use rib::RibModule;
 use tracing::{error, info};
 
 use super::api_definition_validator::{ApiDefinitionValidatorService, ValidationErrors};
@@ -52,6 +54,10 @@ pub enum ApiDefinitionError<E> {
     ComponentNotFoundError(Vec<VersionedComponentId>),
     #[error("Rib compilation error: {0}")]
     RibCompilationErrors(String),
+    #[error("Security scheme error: {0}")]
+    SecuritySchemeError(String),
+    #[error("Rib module not found: {0}")]
+    RibModuleNotFound(String),
     #[error("API definition not found: {0}")]
     ApiDefinitionNotFound(ApiDefinitionId),
     #[error("API definition is not draft: {0}")]
@@ -80,6 +86,8 @@ impl<E: SafeDisplay + Display> SafeDisplay for ApiDefinitionError<E> {
             ApiDefinitionError::ValidationError(inner) => inner.to_safe_string(),
             ApiDefinitionError::ComponentNotFoundError(_) => self.to_string(),
             ApiDefinitionError::RibCompilationErrors(_) => self.to_string(),
+            ApiDefinitionError::SecuritySchemeError(_) => self.to_string(),
+            ApiDefinitionError::RibModuleNotFound(_) => self.to_string(),
             ApiDefinitionError::ApiDefinitionNotFound(_) => self.to_string(),
             ApiDefinitionError::ApiDefinitionNotDraft(_) => self.to_string(),
             ApiDefinitionError::ApiDefinitionAlreadyExists(_) => self.to_string(),
@@ -102,6 +110,9 @@ impl<E> From<RouteCompilationErrors> for ApiDefinitionError<E> {
                     e
                 ))
             }
+            RouteCompilationErrors::SecuritySchemeError(e) => {
+                ApiDefinitionError::SecuritySchemeError(e)
+            }
         }
     }
 }
@@ -159,6 +170,7 @@ pub struct ApiDefinitionServiceDefault<AuthCtx, ValidationError> {
     pub component_service: Arc<dyn ComponentService<AuthCtx> + Send + Sync>,
     pub definition_repo: Arc<dyn ApiDefinitionRepo + Sync + Send>,
     pub deployment_repo: Arc<dyn ApiDeploymentRepo + Sync + Send>,
+    pub rib_module_repo: Arc<dyn RibModuleRepo + Sync + Send>,
     pub api_definition_validator:
         Arc<dyn ApiDefinitionValidatorService<HttpApiDefinition, ValidationError> + Sync + Send>,
 }
@@ -168,6 +180,7 @@ impl<AuthCtx, ValidationError> ApiDefinitionServiceDefault<AuthCtx, ValidationEr
         component_service: Arc<dyn ComponentService<AuthCtx> + Send + Sync>,
         definition_repo: Arc<dyn ApiDefinitionRepo + Sync + Send>,
         deployment_repo: Arc<dyn ApiDeploymentRepo + Sync + Send>,
+        rib_module_repo: Arc<dyn RibModuleRepo + Sync + Send>,
         api_definition_validator: Arc<
             dyn ApiDefinitionValidatorService<HttpApiDefinition, ValidationError> + Sync + Send,
         >,
@@ -176,10 +189,63 @@ impl<AuthCtx, ValidationError> ApiDefinitionServiceDefault<AuthCtx, ValidationEr
             component_service,
             definition_repo,
             deployment_repo,
+            rib_module_repo,
             api_definition_validator,
         }
     }
 
+    // Imports without a version are pinned to the latest version of the module, so that
+    // the stored API definition keeps referring to the same module when it gets updated
+    async fn resolve_rib_modules(
+        &self,
+        definition: &mut HttpApiDefinition,
+        namespace: &str,
+    ) -> Result<Vec<RibModule>, ApiDefinitionError<ValidationError>> {
+        let mut rib_modules: Vec<RibModule> = vec![];
+
+        for route in definition.routes.iter_mut() {
+            let binding = &mut route.binding;
+
+            let mut imports = binding.worker_name.imports_mut();
+            imports.extend(binding.response.0.imports_mut());
+            if let Some(idempotency_key) = binding.idempotency_key.as_mut() {
+                imports.extend(idempotency_key.imports_mut());
+            }
+
+            for import in imports {
+                let record = match import.version {
+                    Some(version) => {
+                        self.rib_module_repo
+                            .get(namespace, &import.module_name, version)
+                            .await?
+                    }
+                    None => {
+                        self.rib_module_repo
+                            .get_latest(namespace, &import.module_name)
+                            .await?
+                    }
+                }
+                .ok_or(ApiDefinitionError::RibModuleNotFound(import.to_string()))?;
+
+                import.version = Some(record.version as u64);
+
+                if !rib_modules
+                    .iter()
+                    .any(|module| module.is_imported_by(import))
+                {
+                    let rib_module = RibModule::try_from(record).map_err(|e| {
+                        ApiDefinitionError::Internal(format!(
+                            "Failed to convert Rib module record: {e}"
+                        ))
+                    })?;
+                    rib_modules.push(rib_module);
+                }
+            }
+        }
+
+        Ok(rib_modules)
+    }
+
     async fn get_all_components(
         &self,
         definition: &HttpApiDefinition,
@@ -256,7 +322,11 @@ where
             ));
         }
 
-        let definition = HttpApiDefinition::new(definition.clone(), created_at);
+        let mut definition = HttpApiDefinition::new(definition.clone(), created_at);
+
+        let rib_modules = self
+            .resolve_rib_modules(&mut definition, &namespace.to_string())
+            .await?;
 
         let components = self.get_all_components(&definition, auth_ctx).await?;
 
@@ -269,6 +339,7 @@ where
         let compiled_http_api_definition = CompiledHttpApiDefinition::from_http_api_definition(
             &definition,
             &component_metadata_dictionary,
+            &rib_modules,
         )?;
 
         let record = ApiDefinitionRecord::new(
@@ -311,7 +382,11 @@ where
             )),
             Some(record) => Ok(record.created_at),
         }?;
-        let definition = HttpApiDefinition::new(definition.clone(), created_at);
+        let mut definition = HttpApiDefinition::new(definition.clone(), created_at);
+
+        let rib_modules = self
+            .resolve_rib_modules(&mut definition, &namespace.to_string())
+            .await?;
 
         let components = self.get_all_components(&definition, auth_ctx).await?;
 
@@ -324,6 +399,7 @@ where
         let compiled_http_api_definition = CompiledHttpApiDefinition::from_http_api_definition(
             &definition,
             &component_metadata_dictionary,
+            &rib_modules,
         )?;
 
         let record = ApiDefinitionRecord::new(
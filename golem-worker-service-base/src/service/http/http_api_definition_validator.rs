@@ -126,6 +126,9 @@ mod tests {
                     response: ResponseMapping(Expr::literal("sample")),
                     binding_type: None,
                 },
+                max_body_size: None,
+                cors: None,
+                security: None,
             }
         }
 
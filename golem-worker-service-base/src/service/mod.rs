@@ -17,6 +17,7 @@ pub mod api_definition_lookup;
 pub mod api_definition_validator;
 pub mod api_deployment;
 pub mod component;
+pub mod rib_module;
 pub mod worker;
 
 pub mod http;
@@ -0,0 +1,292 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::repo::rib_module::{RibModuleRecord, RibModuleRepo};
+use crate::worker_service_rib_compiler::{DefaultRibCompiler, WorkerServiceRibCompiler};
+use async_trait::async_trait;
+use chrono::Utc;
+use golem_common::SafeDisplay;
+use golem_service_base::repo::RepoError;
+use poem_openapi::Object;
+use rib::RibModule;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+pub type RibModuleResult<T> = Result<T, RibModuleError>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RibModuleRequest {
+    pub name: String,
+    pub source: String,
+}
+
+// A Rib module as stored next to the API definitions. Every update of a module
+// creates a new version, and API definitions importing the module pin one of its versions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RibModuleDefinition {
+    pub name: String,
+    pub version: u64,
+    pub source: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<RibModuleRecord> for RibModuleDefinition {
+    fn from(value: RibModuleRecord) -> Self {
+        Self {
+            name: value.name,
+            version: value.version as u64,
+            source: value.source,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RibModuleError {
+    #[error("Invalid Rib module: {0}")]
+    InvalidRibModule(String),
+    #[error("Rib module not found: {0}")]
+    RibModuleNotFound(String),
+    #[error("Internal repository error: {0}")]
+    InternalRepoError(RepoError),
+}
+
+impl From<RepoError> for RibModuleError {
+    fn from(error: RepoError) -> Self {
+        RibModuleError::InternalRepoError(error)
+    }
+}
+
+impl SafeDisplay for RibModuleError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            RibModuleError::InvalidRibModule(_) => self.to_string(),
+            RibModuleError::RibModuleNotFound(_) => self.to_string(),
+            RibModuleError::InternalRepoError(inner) => inner.to_safe_string(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait RibModuleService<AuthCtx, Namespace> {
+    // Creates the first version of the module, or a new version of an existing module
+    async fn create(
+        &self,
+        request: &RibModuleRequest,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<RibModuleDefinition>;
+
+    async fn get(
+        &self,
+        name: &str,
+        version: u64,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Option<RibModuleDefinition>>;
+
+    async fn get_latest(
+        &self,
+        name: &str,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Option<RibModuleDefinition>>;
+
+    async fn delete(
+        &self,
+        name: &str,
+        version: u64,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<()>;
+
+    async fn get_all(
+        &self,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Vec<RibModuleDefinition>>;
+
+    async fn get_all_versions(
+        &self,
+        name: &str,
+        namespace: &Namespace,
+        auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Vec<RibModuleDefinition>>;
+}
+
+pub struct RibModuleServiceDefault {
+    pub rib_module_repo: Arc<dyn RibModuleRepo + Sync + Send>,
+}
+
+impl RibModuleServiceDefault {
+    pub fn new(rib_module_repo: Arc<dyn RibModuleRepo + Sync + Send>) -> Self {
+        Self { rib_module_repo }
+    }
+}
+
+#[async_trait]
+impl<AuthCtx, Namespace> RibModuleService<AuthCtx, Namespace> for RibModuleServiceDefault
+where
+    AuthCtx: Send + Sync,
+    Namespace: Display + Clone + Send + Sync,
+{
+    async fn create(
+        &self,
+        request: &RibModuleRequest,
+        namespace: &Namespace,
+        _auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<RibModuleDefinition> {
+        info!(namespace = %namespace, "Create Rib module");
+
+        let latest = self
+            .rib_module_repo
+            .get_latest(&namespace.to_string(), &request.name)
+            .await?;
+
+        let version = latest.map_or(0, |record| record.version as u64 + 1);
+
+        // The module is type checked once, and the routes importing it
+        // only reuse the definitions when they are compiled
+        let rib_module = RibModule::from_text(&request.name, version, &request.source)
+            .map_err(RibModuleError::InvalidRibModule)?;
+
+        DefaultRibCompiler::compile_module(&rib_module)
+            .map_err(RibModuleError::InvalidRibModule)?;
+
+        let record = RibModuleRecord::new(
+            namespace.clone(),
+            request.name.clone(),
+            version,
+            request.source.clone(),
+            Utc::now(),
+        );
+
+        self.rib_module_repo.create(&record).await?;
+
+        Ok(record.into())
+    }
+
+    async fn get(
+        &self,
+        name: &str,
+        version: u64,
+        namespace: &Namespace,
+        _auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Option<RibModuleDefinition>> {
+        info!(namespace = %namespace, "Get Rib module");
+
+        let record = self
+            .rib_module_repo
+            .get(&namespace.to_string(), name, version)
+            .await?;
+
+        Ok(record.map(RibModuleDefinition::from))
+    }
+
+    async fn get_latest(
+        &self,
+        name: &str,
+        namespace: &Namespace,
+        _auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Option<RibModuleDefinition>> {
+        info!(namespace = %namespace, "Get latest Rib module");
+
+        let record = self
+            .rib_module_repo
+            .get_latest(&namespace.to_string(), name)
+            .await?;
+
+        Ok(record.map(RibModuleDefinition::from))
+    }
+
+    // Deployed API definitions are not affected, as the routes are compiled with
+    // the definitions of the module, but the module can no longer be imported
+    async fn delete(
+        &self,
+        name: &str,
+        version: u64,
+        namespace: &Namespace,
+        _auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<()> {
+        info!(namespace = %namespace, "Delete Rib module");
+
+        let deleted = self
+            .rib_module_repo
+            .delete(&namespace.to_string(), name, version)
+            .await?;
+
+        if deleted {
+            Ok(())
+        } else {
+            Err(RibModuleError::RibModuleNotFound(format!(
+                "{}@{}",
+                name, version
+            )))
+        }
+    }
+
+    async fn get_all(
+        &self,
+        namespace: &Namespace,
+        _auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Vec<RibModuleDefinition>> {
+        info!(namespace = %namespace, "Get all Rib modules");
+
+        let records = self.rib_module_repo.get_all(&namespace.to_string()).await?;
+
+        Ok(records.into_iter().map(RibModuleDefinition::from).collect())
+    }
+
+    async fn get_all_versions(
+        &self,
+        name: &str,
+        namespace: &Namespace,
+        _auth_ctx: &AuthCtx,
+    ) -> RibModuleResult<Vec<RibModuleDefinition>> {
+        info!(namespace = %namespace, "Get all Rib module versions");
+
+        let records = self
+            .rib_module_repo
+            .get_all_versions(&namespace.to_string(), name)
+            .await?;
+
+        Ok(records.into_iter().map(RibModuleDefinition::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::service::rib_module::RibModuleError;
+    use golem_common::SafeDisplay;
+    use golem_service_base::repo::RepoError;
+
+    #[test]
+    pub fn test_repo_error_to_service_error() {
+        let repo_err = RepoError::Internal("some sql error".to_string());
+        let service_err: RibModuleError = repo_err.into();
+        assert_eq!(
+            service_err.to_safe_string(),
+            "Internal repository error".to_string()
+        );
+    }
+}
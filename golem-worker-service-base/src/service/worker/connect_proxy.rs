@@ -18,8 +18,10 @@ use std::{
 };
 
 use futures::{Sink, SinkExt, Stream, StreamExt};
-use golem_api_grpc::proto::golem::worker::LogEvent;
-use golem_common::model::{WorkerEvent, WorkerId};
+use golem_api_grpc::proto::golem::worker::{
+    LogEvent, WorkerStatusChange as GrpcWorkerStatusChange,
+};
+use golem_common::model::{ComponentId, WorkerEvent, WorkerId, WorkerStatusChange};
 use poem::web::websocket::Message;
 use tonic::Status;
 use tracing::{error, info};
@@ -31,7 +33,7 @@ use tracing::{error, info};
 #[tracing::instrument(skip_all, fields(worker_id = worker_id.to_string()))]
 pub async fn proxy_worker_connection(
     worker_id: WorkerId,
-    mut worker_stream: impl Stream<Item = Result<LogEvent, Status>> + Unpin,
+    worker_stream: impl Stream<Item = Result<LogEvent, Status>> + Unpin,
     websocket_sender: impl Sink<Message, Error = IoError> + Unpin,
     websocket_receiver: impl Stream<Item = IoResult<Message>> + Unpin,
     keep_alive_interval: Duration,
@@ -39,6 +41,59 @@ pub async fn proxy_worker_connection(
 ) -> Result<(), ConnectProxyError> {
     info!("Proxying worker connection");
 
+    proxy_stream(
+        worker_stream,
+        websocket_sender,
+        websocket_receiver,
+        keep_alive_interval,
+        max_pong_timeout,
+        |message: LogEvent| {
+            let message: WorkerEvent = message.try_into().map_err(ConnectProxyError::Proto)?;
+            Ok(serde_json::to_string(&message)?)
+        },
+    )
+    .await
+}
+
+/// Proxies the status changes of the watched workers, listening for either connection to close.
+/// Websocket sink will be closed at the end.
+///
+/// keep_alive_interval: Interval at which Ping messages are sent
+/// max_pong_timeout: Maximum time to wait for a Pong message before considering the connection dead
+#[tracing::instrument(skip_all, fields(component_id = component_id.to_string()))]
+pub async fn proxy_worker_status_changes(
+    component_id: ComponentId,
+    status_stream: impl Stream<Item = Result<GrpcWorkerStatusChange, Status>> + Unpin,
+    websocket_sender: impl Sink<Message, Error = IoError> + Unpin,
+    websocket_receiver: impl Stream<Item = IoResult<Message>> + Unpin,
+    keep_alive_interval: Duration,
+    max_pong_timeout: Duration,
+) -> Result<(), ConnectProxyError> {
+    info!("Proxying worker status changes");
+
+    proxy_stream(
+        status_stream,
+        websocket_sender,
+        websocket_receiver,
+        keep_alive_interval,
+        max_pong_timeout,
+        |message: GrpcWorkerStatusChange| {
+            let message: WorkerStatusChange =
+                message.try_into().map_err(ConnectProxyError::Proto)?;
+            Ok(serde_json::to_string(&message)?)
+        },
+    )
+    .await
+}
+
+async fn proxy_stream<T>(
+    mut worker_stream: impl Stream<Item = Result<T, Status>> + Unpin,
+    websocket_sender: impl Sink<Message, Error = IoError> + Unpin,
+    websocket_receiver: impl Stream<Item = IoResult<Message>> + Unpin,
+    keep_alive_interval: Duration,
+    max_pong_timeout: Duration,
+    to_json: impl Fn(T) -> Result<String, ConnectProxyError>,
+) -> Result<(), ConnectProxyError> {
     let mut websocket = keep_alive::WebSocketKeepAlive::from_sink_and_stream(
         websocket_receiver,
         websocket_sender,
@@ -76,7 +131,7 @@ pub async fn proxy_worker_connection(
 
             worker_message = worker_stream.next() => {
                 if let Some(message) = worker_message {
-                    if let Err(error) = forward_worker_message(message, &mut websocket).await {
+                    if let Err(error) = forward_worker_message(message, &to_json, &mut websocket).await {
                         info!(error=error.to_string(), "Error forwarding message to WebSocket client");
                         break Err(error)
 
@@ -102,15 +157,15 @@ pub async fn proxy_worker_connection(
     result
 }
 
-async fn forward_worker_message<E>(
-    message: Result<LogEvent, tonic::Status>,
+async fn forward_worker_message<T, E>(
+    message: Result<T, tonic::Status>,
+    to_json: &impl Fn(T) -> Result<String, ConnectProxyError>,
     socket: &mut (impl Sink<Message, Error = E> + Unpin),
 ) -> Result<(), ConnectProxyError>
 where
     ConnectProxyError: From<E>,
 {
-    let message: WorkerEvent = message?.try_into().map_err(ConnectProxyError::Proto)?;
-    let msg_json = serde_json::to_string(&message)?;
+    let msg_json = to_json(message?)?;
     socket.send(Message::Text(msg_json)).await?;
     Ok(())
 }
@@ -30,18 +30,23 @@ use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::Wo
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
     CompletePromiseRequest, ConnectWorkerRequest, CreateWorkerRequest, InterruptWorkerRequest,
     InvokeAndAwaitWorkerRequest, ResumeWorkerRequest, SearchOplogResponse, UpdateWorkerRequest,
+    WatchWorkersRequest,
 };
 use golem_common::client::MultiTargetGrpcClient;
 use golem_common::config::RetryConfig;
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
+use golem_common::model::pubsub::TopicSubscription;
+use golem_common::model::quotas::WorkerQuotas;
 use golem_common::model::{
-    AccountId, ComponentId, ComponentVersion, FilterComparator, IdempotencyKey, PromiseId,
-    ScanCursor, TargetWorkerId, WorkerFilter, WorkerId, WorkerStatus,
+    AccountId, ComponentId, ComponentVersion, DeadLetterPolicy, DeadLetteredInvocationId,
+    FilterComparator, IdempotencyKey, InvocationSchedule, PromiseId, RevertWorkerTarget,
+    ScanCursor, ScheduledInvocationId, TargetWorkerId, WorkerFilter, WorkerId, WorkerStatus,
 };
 use golem_service_base::model::{Component, GolemError};
 use golem_service_base::model::{
-    GetFileResponse, GetFilesResponse, GetOplogResponse, GolemErrorUnknown, PublicOplogEntryWithIndex, ResourceLimits, WorkerMetadata
+    DeadLetteredInvocation, GetFileResponse, GetFilesResponse, GetOplogResponse, GolemErrorUnknown,
+    PublicOplogEntryWithIndex, ResourceLimits, ScheduledInvocation, WorkerMetadata,
 };
 use golem_service_base::service::routing_table::{HasRoutingTableService, RoutingTableService};
 
@@ -49,7 +54,7 @@ use crate::service::component::ComponentService;
 
 use super::{
     AllExecutors, CallWorkerExecutorError, ConnectWorkerStream, HasWorkerExecutorClients,
-    RandomExecutor, ResponseMapResult, RoutingLogic, WorkerServiceError,
+    RandomExecutor, ResponseMapResult, RoutingLogic, WatchWorkersStream, WorkerServiceError,
 };
 
 pub type WorkerResult<T> = Result<T, WorkerServiceError>;
@@ -62,6 +67,7 @@ pub trait WorkerService<AuthCtx> {
         component_version: u64,
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
+        quotas: Option<WorkerQuotas>,
         metadata: WorkerRequestMetadata,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<WorkerId>;
@@ -73,6 +79,15 @@ pub trait WorkerService<AuthCtx> {
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<ConnectWorkerStream>;
 
+    /// Subscribes to the status changes of the workers of a component matching the optional filter
+    async fn watch(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<WatchWorkersStream>;
+
     async fn delete(
         &self,
         worker_id: &WorkerId,
@@ -259,6 +274,153 @@ pub trait WorkerService<AuthCtx> {
         metadata: WorkerRequestMetadata,
         auth_ctx: &AuthCtx,
     ) -> WorkerResult<GetFileResponse>;
+
+    async fn put_file(
+        &self,
+        worker_id: &WorkerId,
+        path: &str,
+        content: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn delete_file(
+        &self,
+        worker_id: &WorkerId,
+        path: &str,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn make_directory(
+        &self,
+        worker_id: &WorkerId,
+        path: &str,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    /// Validates the provided list of `TypeAnnotatedValue` parameters, and then registers
+    /// an invocation to be performed by the worker executor's scheduler, once or repeatedly.
+    async fn schedule_invocation(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<TypeAnnotatedValue>,
+        schedule: InvocationSchedule,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ScheduledInvocation>;
+
+    async fn cancel_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        schedule_id: &ScheduledInvocationId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<ScheduledInvocation>>;
+
+    async fn fork_worker(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn revert_worker(
+        &self,
+        worker_id: &WorkerId,
+        target: RevertWorkerTarget,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn get_dead_lettered_invocations(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<DeadLetteredInvocation>>;
+
+    /// Re-enqueues a dead-lettered invocation on its worker, using its original idempotency key
+    async fn retry_dead_lettered_invocation(
+        &self,
+        worker_id: &WorkerId,
+        dead_letter_id: &DeadLetteredInvocationId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn discard_dead_lettered_invocation(
+        &self,
+        worker_id: &WorkerId,
+        dead_letter_id: &DeadLetteredInvocationId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn get_dead_letter_policy(
+        &self,
+        component_id: &ComponentId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<DeadLetterPolicy>;
+
+    async fn set_dead_letter_policy(
+        &self,
+        component_id: &ComponentId,
+        policy: DeadLetterPolicy,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    /// Subscribes a worker to a topic, so the messages published to it get delivered by
+    /// invoking the subscription's function on the worker
+    async fn subscribe_to_topic(
+        &self,
+        subscription: TopicSubscription,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn unsubscribe_from_topic(
+        &self,
+        subscription: TopicSubscription,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()>;
+
+    async fn get_worker_topic_subscriptions(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<TopicSubscription>>;
+
+    async fn get_topic_subscriptions(
+        &self,
+        topic: &str,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<TopicSubscription>>;
+
+    /// Publishes a message to a topic, returning the id of the message
+    async fn publish_to_topic(
+        &self,
+        topic: &str,
+        payload: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> WorkerResult<u64>;
 }
 
 pub struct TypedResult {
@@ -326,6 +488,7 @@ where
         component_version: u64,
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
+        quotas: Option<WorkerQuotas>,
         metadata: WorkerRequestMetadata,
         _auth_ctx: &AuthCtx,
     ) -> WorkerResult<WorkerId> {
@@ -343,6 +506,7 @@ where
                     env: environment_variables.clone(),
                     account_id: metadata.account_id.clone().map(|id| id.into()),
                     account_limits: metadata.limits.clone().map(|id| id.into()),
+                    quotas: quotas.clone().map(|quotas| quotas.into()),
                 }))
             },
             |response| match response.into_inner() {
@@ -397,6 +561,43 @@ where
         Ok(stream)
     }
 
+    async fn watch(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<WatchWorkersStream> {
+        let component_id = component_id.clone();
+        let stream = self
+            .call_worker_executor(
+                AllExecutors,
+                "watch_workers",
+                move |worker_executor_client| {
+                    info!("Watch workers");
+                    let component_id: golem_api_grpc::proto::golem::component::ComponentId =
+                        component_id.clone().into();
+                    Box::pin(worker_executor_client.watch_workers(WatchWorkersRequest {
+                        component_id: Some(component_id),
+                        filter: filter.clone().map(|f| f.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                    }))
+                },
+                |responses| {
+                    Ok(WatchWorkersStream::new(
+                        responses
+                            .into_iter()
+                            .map(|response| response.into_inner())
+                            .collect(),
+                    ))
+                },
+                WorkerServiceError::InternalCallError,
+            )
+            .await?;
+
+        Ok(stream)
+    }
+
     async fn delete(
         &self,
         worker_id: &WorkerId,
@@ -1115,125 +1316,870 @@ where
         
         Ok(response)
     }
-}
 
-impl<AuthCtx> WorkerServiceDefault<AuthCtx>
-where
-    AuthCtx: Send + Sync,
-{
-    async fn try_get_component_for_worker(
+    async fn put_file(
         &self,
         worker_id: &WorkerId,
-        request_metadata: WorkerRequestMetadata,
-        auth_ctx: &AuthCtx,
-    ) -> Result<Component, WorkerServiceError> {
-        match self
-            .get_metadata(worker_id, request_metadata, auth_ctx)
-            .await
-        {
-            Ok(metadata) => {
-                let component_version = metadata.component_version;
-                let component_details = self
-                    .component_service
-                    .get_by_version(&worker_id.component_id, component_version, auth_ctx)
-                    .await?;
-
-                Ok(component_details)
-            }
-            Err(WorkerServiceError::WorkerNotFound(_)) => Ok(self
-                .component_service
-                .get_latest(&worker_id.component_id, auth_ctx)
-                .await?),
-            Err(WorkerServiceError::Golem(GolemError::WorkerNotFound(_))) => Ok(self
-                .component_service
-                .get_latest(&worker_id.component_id, auth_ctx)
-                .await?),
-            Err(other) => Err(other),
-        }
-    }
-
-    async fn find_running_metadata_internal(
-        &self,
-        component_id: &ComponentId,
-        filter: Option<WorkerFilter>,
+        path: &str,
+        content: Vec<u8>,
+        metadata: WorkerRequestMetadata,
         _auth_ctx: &AuthCtx,
-    ) -> WorkerResult<Vec<WorkerMetadata>> {
-        let component_id = component_id.clone();
-        let result = self.call_worker_executor(
-            AllExecutors,
-            "get_running_workers_metadata",
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let path = path.to_string();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "put_file",
             move |worker_executor_client| {
-                let component_id: golem_api_grpc::proto::golem::component::ComponentId =
-                    component_id.clone().into();
-
+                let worker_id = worker_id.clone();
+                let path = path.clone();
+                let content = content.clone();
                 Box::pin(
-                    worker_executor_client.get_running_workers_metadata(
-                        workerexecutor::v1::GetRunningWorkersMetadataRequest {
-                            component_id: Some(component_id),
-                            filter: filter.clone().map(|f| f.into()),
-                        }
-                    )
+                    worker_executor_client.put_file(workerexecutor::v1::PutFileRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        path,
+                        content,
+                    }),
                 )
             },
-            |responses| {
-                responses.into_iter().map(|response| {
-                    match response.into_inner() {
-                        workerexecutor::v1::GetRunningWorkersMetadataResponse {
-                            result:
-                            Some(workerexecutor::v1::get_running_workers_metadata_response::Result::Success(workerexecutor::v1::GetRunningWorkersMetadataSuccessResponse {
-                                                                                                                workers
-                                                                                                            })),
-                        } => {
-                            let workers: Vec<WorkerMetadata> = workers.into_iter().map(|w| w.try_into()).collect::<Result<Vec<_>, _>>().map_err(|_| GolemError::Unknown(GolemErrorUnknown {
-                                details: "Convert response error".to_string(),
-                            }))?;
-                            Ok(workers)
-                        }
-                        workerexecutor::v1::GetRunningWorkersMetadataResponse {
-                            result:
-                            Some(workerexecutor::v1::get_running_workers_metadata_response::Result::Failure(err)),
-                        } => Err(err.into()),
-                        workerexecutor::v1::GetRunningWorkersMetadataResponse { .. } => {
-                            Err("Empty response".into())
-                        }
-                    }
-                }).collect::<Result<Vec<_>, ResponseMapResult>>()
+            |response| match response.into_inner() {
+                workerexecutor::v1::PutFileResponse {
+                    result: Some(workerexecutor::v1::put_file_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::PutFileResponse {
+                    result: Some(workerexecutor::v1::put_file_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::PutFileResponse { .. } => Err("Empty response".into()),
             },
             WorkerServiceError::InternalCallError,
-        ).await?;
-
-        Ok(result.into_iter().flatten().collect())
+        )
+        .await
     }
 
-    async fn find_metadata_internal(
+    async fn delete_file(
         &self,
-        component_id: &ComponentId,
-        filter: Option<WorkerFilter>,
-        cursor: ScanCursor,
-        count: u64,
-        precise: bool,
+        worker_id: &WorkerId,
+        path: &str,
         metadata: WorkerRequestMetadata,
         _auth_ctx: &AuthCtx,
-    ) -> WorkerResult<(Option<ScanCursor>, Vec<WorkerMetadata>)> {
-        let component_id = component_id.clone();
-        let result = self
-            .call_worker_executor(
-                RandomExecutor,
-                "get_workers_metadata",
-                move |worker_executor_client| {
-                    let component_id: golem_api_grpc::proto::golem::component::ComponentId =
-                        component_id.clone().into();
-                    let account_id = metadata.account_id.clone().map(|id| id.into());
-                    Box::pin(worker_executor_client.get_workers_metadata(
-                        workerexecutor::v1::GetWorkersMetadataRequest {
-                            component_id: Some(component_id),
-                            filter: filter.clone().map(|f| f.into()),
-                            cursor: Some(cursor.clone().into()),
-                            count,
-                            precise,
-                            account_id,
-                        },
-                    ))
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let path = path.to_string();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "delete_file",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let path = path.clone();
+                Box::pin(worker_executor_client.delete_file(
+                    workerexecutor::v1::DeleteFileRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        path,
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::DeleteFileResponse {
+                    result: Some(workerexecutor::v1::delete_file_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::DeleteFileResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn make_directory(
+        &self,
+        worker_id: &WorkerId,
+        path: &str,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let path = path.to_string();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "make_directory",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let path = path.clone();
+                Box::pin(worker_executor_client.make_directory(
+                    workerexecutor::v1::MakeDirectoryRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        path,
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::MakeDirectoryResponse {
+                    result: Some(workerexecutor::v1::make_directory_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::MakeDirectoryResponse {
+                    result: Some(workerexecutor::v1::make_directory_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::MakeDirectoryResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn schedule_invocation(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<TypeAnnotatedValue>,
+        schedule: InvocationSchedule,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<ScheduledInvocation> {
+        let params = self.validate_typed_parameters(params)?;
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "schedule_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.schedule_invocation(
+                    workerexecutor::v1::ScheduleInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        name: function_name.clone(),
+                        input: params.clone(),
+                        schedule: Some(schedule.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ScheduleInvocationResponse {
+                    result:
+                        Some(workerexecutor::v1::schedule_invocation_response::Result::Success(
+                            invocation,
+                        )),
+                } => invocation.try_into().map_err(|err: String| {
+                    GolemError::Unknown(GolemErrorUnknown {
+                        details: format!("Unexpected scheduled invocation: {err}"),
+                    })
+                    .into()
+                }),
+                workerexecutor::v1::ScheduleInvocationResponse {
+                    result:
+                        Some(workerexecutor::v1::schedule_invocation_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::ScheduleInvocationResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn cancel_scheduled_invocation(
+        &self,
+        worker_id: &WorkerId,
+        schedule_id: &ScheduledInvocationId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let schedule_id = schedule_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "cancel_scheduled_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.cancel_scheduled_invocation(
+                    workerexecutor::v1::CancelScheduledInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        schedule_id: Some(schedule_id.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::CancelScheduledInvocationResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::cancel_scheduled_invocation_response::Result::Success(
+                                _,
+                            ),
+                        ),
+                } => Ok(()),
+                workerexecutor::v1::CancelScheduledInvocationResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::cancel_scheduled_invocation_response::Result::Failure(
+                                err,
+                            ),
+                        ),
+                } => Err(err.into()),
+                workerexecutor::v1::CancelScheduledInvocationResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn get_scheduled_invocations(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<ScheduledInvocation>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_scheduled_invocations",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.get_scheduled_invocations(
+                    workerexecutor::v1::GetScheduledInvocationsRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetScheduledInvocationsResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::get_scheduled_invocations_response::Result::Success(
+                                response,
+                            ),
+                        ),
+                } => response
+                    .invocations
+                    .into_iter()
+                    .map(|invocation| invocation.try_into())
+                    .collect::<Result<Vec<_>, String>>()
+                    .map_err(|err| {
+                        GolemError::Unknown(GolemErrorUnknown {
+                            details: format!("Unexpected scheduled invocation: {err}"),
+                        })
+                        .into()
+                    }),
+                workerexecutor::v1::GetScheduledInvocationsResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::get_scheduled_invocations_response::Result::Failure(
+                                err,
+                            ),
+                        ),
+                } => Err(err.into()),
+                workerexecutor::v1::GetScheduledInvocationsResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn fork_worker(
+        &self,
+        source_worker_id: &WorkerId,
+        target_worker_id: &WorkerId,
+        oplog_index_cutoff: OplogIndex,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let source_worker_id = source_worker_id.clone();
+        let target_worker_id = target_worker_id.clone();
+        self.call_worker_executor(
+            target_worker_id.clone(),
+            "fork_worker",
+            move |worker_executor_client| {
+                let source_worker_id = source_worker_id.clone();
+                let target_worker_id = target_worker_id.clone();
+                Box::pin(worker_executor_client.fork_worker(
+                    workerexecutor::v1::ForkWorkerRequest {
+                        source_worker_id: Some(source_worker_id.into()),
+                        target_worker_id: Some(target_worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        oplog_index_cutoff: oplog_index_cutoff.into(),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ForkWorkerResponse {
+                    result: Some(workerexecutor::v1::fork_worker_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::ForkWorkerResponse {
+                    result: Some(workerexecutor::v1::fork_worker_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ForkWorkerResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn revert_worker(
+        &self,
+        worker_id: &WorkerId,
+        target: RevertWorkerTarget,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "revert_worker",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let target = target.clone();
+                Box::pin(worker_executor_client.revert_worker(
+                    workerexecutor::v1::RevertWorkerRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        target: Some(target.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::RevertWorkerResponse {
+                    result: Some(workerexecutor::v1::revert_worker_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::RevertWorkerResponse {
+                    result: Some(workerexecutor::v1::revert_worker_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::RevertWorkerResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn get_dead_lettered_invocations(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<DeadLetteredInvocation>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_dead_lettered_invocations",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.get_dead_lettered_invocations(
+                    workerexecutor::v1::GetDeadLetteredInvocationsRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetDeadLetteredInvocationsResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::get_dead_lettered_invocations_response::Result::Success(
+                                response,
+                            ),
+                        ),
+                } => response
+                    .invocations
+                    .into_iter()
+                    .map(|invocation| invocation.try_into())
+                    .collect::<Result<Vec<_>, String>>()
+                    .map_err(|err| {
+                        GolemError::Unknown(GolemErrorUnknown {
+                            details: format!("Unexpected dead-lettered invocation: {err}"),
+                        })
+                        .into()
+                    }),
+                workerexecutor::v1::GetDeadLetteredInvocationsResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::get_dead_lettered_invocations_response::Result::Failure(
+                                err,
+                            ),
+                        ),
+                } => Err(err.into()),
+                workerexecutor::v1::GetDeadLetteredInvocationsResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn retry_dead_lettered_invocation(
+        &self,
+        worker_id: &WorkerId,
+        dead_letter_id: &DeadLetteredInvocationId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let dead_letter_id = dead_letter_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "retry_dead_lettered_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.retry_dead_lettered_invocation(
+                    workerexecutor::v1::RetryDeadLetteredInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        dead_letter_id: Some(dead_letter_id.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::RetryDeadLetteredInvocationResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::retry_dead_lettered_invocation_response::Result::Success(
+                                _,
+                            ),
+                        ),
+                } => Ok(()),
+                workerexecutor::v1::RetryDeadLetteredInvocationResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::retry_dead_lettered_invocation_response::Result::Failure(
+                                err,
+                            ),
+                        ),
+                } => Err(err.into()),
+                workerexecutor::v1::RetryDeadLetteredInvocationResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn discard_dead_lettered_invocation(
+        &self,
+        worker_id: &WorkerId,
+        dead_letter_id: &DeadLetteredInvocationId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        let dead_letter_id = dead_letter_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "discard_dead_lettered_invocation",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.discard_dead_lettered_invocation(
+                    workerexecutor::v1::DiscardDeadLetteredInvocationRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        dead_letter_id: Some(dead_letter_id.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::DiscardDeadLetteredInvocationResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::discard_dead_lettered_invocation_response::Result::Success(
+                                _,
+                            ),
+                        ),
+                } => Ok(()),
+                workerexecutor::v1::DiscardDeadLetteredInvocationResponse {
+                    result:
+                        Some(
+                            workerexecutor::v1::discard_dead_lettered_invocation_response::Result::Failure(
+                                err,
+                            ),
+                        ),
+                } => Err(err.into()),
+                workerexecutor::v1::DiscardDeadLetteredInvocationResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn get_dead_letter_policy(
+        &self,
+        component_id: &ComponentId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<DeadLetterPolicy> {
+        let component_id = component_id.clone();
+        self.call_worker_executor(
+            RandomExecutor,
+            "get_dead_letter_policy",
+            move |worker_executor_client| {
+                let component_id = component_id.clone();
+                Box::pin(worker_executor_client.get_dead_letter_policy(
+                    workerexecutor::v1::GetDeadLetterPolicyRequest {
+                        component_id: Some(component_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetDeadLetterPolicyResponse {
+                    result:
+                        Some(workerexecutor::v1::get_dead_letter_policy_response::Result::Success(
+                            policy,
+                        )),
+                } => Ok(policy.into()),
+                workerexecutor::v1::GetDeadLetterPolicyResponse {
+                    result:
+                        Some(workerexecutor::v1::get_dead_letter_policy_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::GetDeadLetterPolicyResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn set_dead_letter_policy(
+        &self,
+        component_id: &ComponentId,
+        policy: DeadLetterPolicy,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        let component_id = component_id.clone();
+        self.call_worker_executor(
+            RandomExecutor,
+            "set_dead_letter_policy",
+            move |worker_executor_client| {
+                let component_id = component_id.clone();
+                let policy = policy.clone();
+                Box::pin(worker_executor_client.set_dead_letter_policy(
+                    workerexecutor::v1::SetDeadLetterPolicyRequest {
+                        component_id: Some(component_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        policy: Some(policy.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::SetDeadLetterPolicyResponse {
+                    result:
+                        Some(workerexecutor::v1::set_dead_letter_policy_response::Result::Success(
+                            _,
+                        )),
+                } => Ok(()),
+                workerexecutor::v1::SetDeadLetterPolicyResponse {
+                    result:
+                        Some(workerexecutor::v1::set_dead_letter_policy_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::SetDeadLetterPolicyResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn subscribe_to_topic(
+        &self,
+        subscription: TopicSubscription,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        self.call_worker_executor(
+            subscription.worker_id.clone(),
+            "subscribe_to_topic",
+            move |worker_executor_client| {
+                let subscription = subscription.clone();
+                Box::pin(worker_executor_client.subscribe_to_topic(
+                    workerexecutor::v1::SubscribeToTopicRequest {
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        subscription: Some(subscription.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::SubscribeToTopicResponse {
+                    result:
+                        Some(workerexecutor::v1::subscribe_to_topic_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::SubscribeToTopicResponse {
+                    result:
+                        Some(workerexecutor::v1::subscribe_to_topic_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::SubscribeToTopicResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn unsubscribe_from_topic(
+        &self,
+        subscription: TopicSubscription,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<()> {
+        self.call_worker_executor(
+            subscription.worker_id.clone(),
+            "unsubscribe_from_topic",
+            move |worker_executor_client| {
+                let subscription = subscription.clone();
+                Box::pin(worker_executor_client.unsubscribe_from_topic(
+                    workerexecutor::v1::UnsubscribeFromTopicRequest {
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        subscription: Some(subscription.into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::UnsubscribeFromTopicResponse {
+                    result:
+                        Some(workerexecutor::v1::unsubscribe_from_topic_response::Result::Success(
+                            _,
+                        )),
+                } => Ok(()),
+                workerexecutor::v1::UnsubscribeFromTopicResponse {
+                    result:
+                        Some(workerexecutor::v1::unsubscribe_from_topic_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::UnsubscribeFromTopicResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn get_worker_topic_subscriptions(
+        &self,
+        worker_id: &WorkerId,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<TopicSubscription>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_worker_topic_subscriptions",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.get_worker_topic_subscriptions(
+                    workerexecutor::v1::GetWorkerTopicSubscriptionsRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                    },
+                ))
+            },
+            topic_subscriptions_from_response,
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn get_topic_subscriptions(
+        &self,
+        topic: &str,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<TopicSubscription>> {
+        let topic = topic.to_string();
+        self.call_worker_executor(
+            RandomExecutor,
+            "get_topic_subscriptions",
+            move |worker_executor_client| {
+                let topic = topic.clone();
+                Box::pin(worker_executor_client.get_topic_subscriptions(
+                    workerexecutor::v1::GetTopicSubscriptionsRequest {
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        topic,
+                    },
+                ))
+            },
+            topic_subscriptions_from_response,
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn publish_to_topic(
+        &self,
+        topic: &str,
+        payload: Vec<u8>,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<u64> {
+        let topic = topic.to_string();
+        self.call_worker_executor(
+            RandomExecutor,
+            "publish_to_topic",
+            move |worker_executor_client| {
+                let topic = topic.clone();
+                let payload = payload.clone();
+                Box::pin(worker_executor_client.publish_to_topic(
+                    workerexecutor::v1::PublishToTopicRequest {
+                        account_id: metadata.account_id.clone().map(|id| id.into()),
+                        topic,
+                        payload,
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::PublishToTopicResponse {
+                    result:
+                        Some(workerexecutor::v1::publish_to_topic_response::Result::Success(
+                            response,
+                        )),
+                } => Ok(response.message_id),
+                workerexecutor::v1::PublishToTopicResponse {
+                    result:
+                        Some(workerexecutor::v1::publish_to_topic_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::PublishToTopicResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+}
+
+impl<AuthCtx> WorkerServiceDefault<AuthCtx>
+where
+    AuthCtx: Send + Sync,
+{
+    async fn try_get_component_for_worker(
+        &self,
+        worker_id: &WorkerId,
+        request_metadata: WorkerRequestMetadata,
+        auth_ctx: &AuthCtx,
+    ) -> Result<Component, WorkerServiceError> {
+        match self
+            .get_metadata(worker_id, request_metadata, auth_ctx)
+            .await
+        {
+            Ok(metadata) => {
+                let component_version = metadata.component_version;
+                let component_details = self
+                    .component_service
+                    .get_by_version(&worker_id.component_id, component_version, auth_ctx)
+                    .await?;
+
+                Ok(component_details)
+            }
+            Err(WorkerServiceError::WorkerNotFound(_)) => Ok(self
+                .component_service
+                .get_latest(&worker_id.component_id, auth_ctx)
+                .await?),
+            Err(WorkerServiceError::Golem(GolemError::WorkerNotFound(_))) => Ok(self
+                .component_service
+                .get_latest(&worker_id.component_id, auth_ctx)
+                .await?),
+            Err(other) => Err(other),
+        }
+    }
+
+    async fn find_running_metadata_internal(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<Vec<WorkerMetadata>> {
+        let component_id = component_id.clone();
+        let result = self.call_worker_executor(
+            AllExecutors,
+            "get_running_workers_metadata",
+            move |worker_executor_client| {
+                let component_id: golem_api_grpc::proto::golem::component::ComponentId =
+                    component_id.clone().into();
+
+                Box::pin(
+                    worker_executor_client.get_running_workers_metadata(
+                        workerexecutor::v1::GetRunningWorkersMetadataRequest {
+                            component_id: Some(component_id),
+                            filter: filter.clone().map(|f| f.into()),
+                        }
+                    )
+                )
+            },
+            |responses| {
+                responses.into_iter().map(|response| {
+                    match response.into_inner() {
+                        workerexecutor::v1::GetRunningWorkersMetadataResponse {
+                            result:
+                            Some(workerexecutor::v1::get_running_workers_metadata_response::Result::Success(workerexecutor::v1::GetRunningWorkersMetadataSuccessResponse {
+                                                                                                                workers
+                                                                                                            })),
+                        } => {
+                            let workers: Vec<WorkerMetadata> = workers.into_iter().map(|w| w.try_into()).collect::<Result<Vec<_>, _>>().map_err(|_| GolemError::Unknown(GolemErrorUnknown {
+                                details: "Convert response error".to_string(),
+                            }))?;
+                            Ok(workers)
+                        }
+                        workerexecutor::v1::GetRunningWorkersMetadataResponse {
+                            result:
+                            Some(workerexecutor::v1::get_running_workers_metadata_response::Result::Failure(err)),
+                        } => Err(err.into()),
+                        workerexecutor::v1::GetRunningWorkersMetadataResponse { .. } => {
+                            Err("Empty response".into())
+                        }
+                    }
+                }).collect::<Result<Vec<_>, ResponseMapResult>>()
+            },
+            WorkerServiceError::InternalCallError,
+        ).await?;
+
+        Ok(result.into_iter().flatten().collect())
+    }
+
+    async fn find_metadata_internal(
+        &self,
+        component_id: &ComponentId,
+        filter: Option<WorkerFilter>,
+        cursor: ScanCursor,
+        count: u64,
+        precise: bool,
+        metadata: WorkerRequestMetadata,
+        _auth_ctx: &AuthCtx,
+    ) -> WorkerResult<(Option<ScanCursor>, Vec<WorkerMetadata>)> {
+        let component_id = component_id.clone();
+        let result = self
+            .call_worker_executor(
+                RandomExecutor,
+                "get_workers_metadata",
+                move |worker_executor_client| {
+                    let component_id: golem_api_grpc::proto::golem::component::ComponentId =
+                        component_id.clone().into();
+                    let account_id = metadata.account_id.clone().map(|id| id.into());
+                    Box::pin(worker_executor_client.get_workers_metadata(
+                        workerexecutor::v1::GetWorkersMetadataRequest {
+                            component_id: Some(component_id),
+                            filter: filter.clone().map(|f| f.into()),
+                            cursor: Some(cursor.clone().into()),
+                            count,
+                            precise,
+                            account_id,
+                        },
+                    ))
                 },
                 |response| match response.into_inner() {
                     workerexecutor::v1::GetWorkersMetadataResponse {
@@ -1276,6 +2222,31 @@ where
     }
 }
 
+fn topic_subscriptions_from_response(
+    response: tonic::Response<workerexecutor::v1::GetTopicSubscriptionsResponse>,
+) -> Result<Vec<TopicSubscription>, ResponseMapResult> {
+    match response.into_inner() {
+        workerexecutor::v1::GetTopicSubscriptionsResponse {
+            result:
+                Some(workerexecutor::v1::get_topic_subscriptions_response::Result::Success(response)),
+        } => response
+            .subscriptions
+            .into_iter()
+            .map(|subscription| subscription.try_into())
+            .collect::<Result<Vec<_>, String>>()
+            .map_err(|err| {
+                GolemError::Unknown(GolemErrorUnknown {
+                    details: format!("Unexpected topic subscription: {err}"),
+                })
+                .into()
+            }),
+        workerexecutor::v1::GetTopicSubscriptionsResponse {
+            result: Some(workerexecutor::v1::get_topic_subscriptions_response::Result::Failure(err)),
+        } => Err(err.into()),
+        workerexecutor::v1::GetTopicSubscriptionsResponse { .. } => Err("Empty response".into()),
+    }
+}
+
 fn is_filter_with_running_status(filter: &WorkerFilter) -> bool {
     match filter {
         WorkerFilter::Status(f)
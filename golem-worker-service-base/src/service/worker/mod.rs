@@ -17,9 +17,11 @@ pub use connect_stream::*;
 pub use default::*;
 pub use error::*;
 pub use routing_logic::*;
+pub use watch_stream::*;
 
 mod connect_proxy;
 mod connect_stream;
 mod default;
 mod error;
 mod routing_logic;
+mod watch_stream;
@@ -0,0 +1,107 @@
+// Copyright 2024 Golem Cloud
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tonic::{Status, Streaming};
+use tracing::{error, Instrument};
+
+use golem_api_grpc::proto::golem::worker::WorkerStatusChange;
+use golem_common::metrics::api::{
+    record_closed_grpc_api_active_stream, record_new_grpc_api_active_stream,
+};
+
+/// Merges the worker status change streams of all the worker executors into a single stream.
+///
+/// The merged stream ends as soon as any of the executor streams ends, so clients can
+/// reconnect and subscribe to the current set of executors.
+pub struct WatchWorkersStream {
+    receiver: mpsc::Receiver<Result<WorkerStatusChange, Status>>,
+    cancel: CancellationToken,
+}
+
+impl WatchWorkersStream {
+    pub fn new(streams: Vec<Streaming<WorkerStatusChange>>) -> Self {
+        // Create a channel which is Send and Sync.
+        // Streaming is not Sync.
+        let (sender, receiver) = mpsc::channel(32);
+        let executor_count = streams.len();
+        let mut merged = futures::stream::select_all(streams.into_iter().map(|stream| {
+            stream
+                .map(Some)
+                .chain(futures::stream::once(async { None }))
+        }));
+
+        let cancel = CancellationToken::new();
+        let cancel_clone = cancel.clone();
+
+        tokio::spawn(
+            async move {
+                record_new_grpc_api_active_stream();
+
+                if executor_count > 0 {
+                    loop {
+                        tokio::select! {
+                            _ = cancel_clone.cancelled() => {
+                                break;
+                            }
+                            message = merged.next() => {
+                                if let Some(Some(message)) = message {
+                                    if let Err(error) = sender.send(message).await {
+                                        error!(
+                                            error = error.to_string(),
+                                            "Failed to forward WatchWorkersStream"
+                                        );
+                                        break;
+                                    }
+                                } else {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                drop(sender);
+                record_closed_grpc_api_active_stream();
+            }
+            .in_current_span(),
+        );
+
+        Self { receiver, cancel }
+    }
+}
+
+impl Stream for WatchWorkersStream {
+    type Item = Result<WorkerStatusChange, Status>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<WorkerStatusChange, Status>>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for WatchWorkersStream {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
@@ -4,7 +4,7 @@ use crate::worker_service_rib_compiler::{DefaultRibCompiler, WorkerServiceRibCom
 use bincode::{Decode, Encode};
 use golem_service_base::model::VersionedComponentId;
 use golem_wasm_ast::analysis::AnalysedExport;
-use rib::{Expr, RibByteCode, RibInputTypeInfo, WorkerFunctionsInRib};
+use rib::{Expr, RibByteCode, RibInputTypeInfo, RibModule, WorkerFunctionsInRib};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CompiledGolemWorkerBinding {
@@ -19,21 +19,25 @@ impl CompiledGolemWorkerBinding {
     pub fn from_golem_worker_binding(
         golem_worker_binding: &GolemWorkerBinding,
         export_metadata: &[AnalysedExport],
+        rib_modules: &[RibModule],
     ) -> Result<Self, String> {
         let worker_name_compiled = WorkerNameCompiled::from_worker_name(
             &golem_worker_binding.worker_name,
             export_metadata,
+            rib_modules,
         )?;
         let idempotency_key_compiled = match &golem_worker_binding.idempotency_key {
             Some(idempotency_key) => Some(IdempotencyKeyCompiled::from_idempotency_key(
                 idempotency_key,
                 export_metadata,
+                rib_modules,
             )?),
             None => None,
         };
         let response_compiled = ResponseMappingCompiled::from_response_mapping(
             &golem_worker_binding.response,
             export_metadata,
+            rib_modules,
         )?;
 
         Ok(CompiledGolemWorkerBinding {
@@ -57,8 +61,9 @@ impl WorkerNameCompiled {
     pub fn from_worker_name(
         worker_name: &Expr,
         exports: &[AnalysedExport],
+        rib_modules: &[RibModule],
     ) -> Result<Self, String> {
-        let worker_name_compiled = DefaultRibCompiler::compile(worker_name, exports)?;
+        let worker_name_compiled = DefaultRibCompiler::compile(worker_name, exports, rib_modules)?;
 
         Ok(WorkerNameCompiled {
             worker_name: worker_name.clone(),
@@ -79,8 +84,10 @@ impl IdempotencyKeyCompiled {
     pub fn from_idempotency_key(
         idempotency_key: &Expr,
         exports: &[AnalysedExport],
+        rib_modules: &[RibModule],
     ) -> Result<Self, String> {
-        let idempotency_key_compiled = DefaultRibCompiler::compile(idempotency_key, exports)?;
+        let idempotency_key_compiled =
+            DefaultRibCompiler::compile(idempotency_key, exports, rib_modules)?;
 
         Ok(IdempotencyKeyCompiled {
             idempotency_key: idempotency_key.clone(),
@@ -102,8 +109,10 @@ impl ResponseMappingCompiled {
     pub fn from_response_mapping(
         response_mapping: &ResponseMapping,
         exports: &[AnalysedExport],
+        rib_modules: &[RibModule],
     ) -> Result<Self, String> {
-        let response_compiled = DefaultRibCompiler::compile(&response_mapping.0, exports)?;
+        let response_compiled =
+            DefaultRibCompiler::compile(&response_mapping.0, exports, rib_modules)?;
 
         Ok(ResponseMappingCompiled {
             response_rib_expr: response_mapping.0.clone(),
@@ -1,6 +1,8 @@
 use crate::api_definition::http::{QueryInfo, VarInfo};
 
+use bytes::Bytes;
 use http::HeaderMap;
+use mime_guess::mime::{self, Mime};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -13,8 +15,9 @@ impl RequestDetails {
         path_params: &HashMap<VarInfo, &str>,
         query_variable_values: &HashMap<String, String>,
         query_variable_names: &[QueryInfo],
-        request_body: &Value,
+        request_body: RequestBody,
         headers: &HeaderMap,
+        auth: Option<Value>,
     ) -> Result<Self, Vec<String>> {
         Ok(Self::Http(HttpRequestDetails::from_input_http_request(
             path_params,
@@ -22,6 +25,7 @@ impl RequestDetails {
             query_variable_names,
             request_body,
             headers,
+            auth,
         )?))
     }
 
@@ -51,14 +55,21 @@ impl RequestDetails {
 
                 let header_value = Value::Object(header_records);
 
-                Value::Object(serde_json::Map::from_iter(vec![
+                let mut request = serde_json::Map::from_iter(vec![
                     ("path".to_string(), merged_request_path_and_query),
                     (
                         "body".to_string(),
                         http_request_details.request_body.0.clone(),
                     ),
                     ("headers".to_string(), header_value),
-                ]))
+                ]);
+
+                // Claims of the caller, only present on routes with a security scheme
+                if let Some(auth) = &http_request_details.request_auth {
+                    request.insert("auth".to_string(), auth.clone());
+                }
+
+                Value::Object(request)
             }
         }
     }
@@ -70,15 +81,17 @@ pub struct HttpRequestDetails {
     pub request_body: RequestBody,
     pub request_query_values: RequestQueryValues,
     pub request_header_values: RequestHeaderValues,
+    pub request_auth: Option<Value>,
 }
 
 impl HttpRequestDetails {
     pub fn empty() -> HttpRequestDetails {
         HttpRequestDetails {
             request_path_values: RequestPathValues(JsonKeyValues::default()),
-            request_body: RequestBody(Value::Null),
+            request_body: RequestBody::empty(),
             request_query_values: RequestQueryValues(JsonKeyValues::default()),
             request_header_values: RequestHeaderValues(JsonKeyValues::default()),
+            request_auth: None,
         }
     }
 
@@ -95,10 +108,10 @@ impl HttpRequestDetails {
         path_params: &HashMap<VarInfo, &str>,
         query_variable_values: &HashMap<String, String>,
         query_variable_names: &[QueryInfo],
-        request_body: &Value,
+        request_body: RequestBody,
         headers: &HeaderMap,
+        auth: Option<Value>,
     ) -> Result<Self, Vec<String>> {
-        let request_body = RequestBody::from(request_body)?;
         let path_params = RequestPathValues::from(path_params);
         let query_params = RequestQueryValues::from(query_variable_values, query_variable_names)?;
         let header_params = RequestHeaderValues::from(headers)?;
@@ -108,6 +121,7 @@ impl HttpRequestDetails {
             request_body,
             request_query_values: query_params,
             request_header_values: header_params,
+            request_auth: auth,
         })
     }
 }
@@ -182,8 +196,48 @@ impl RequestHeaderValues {
 pub struct RequestBody(Value);
 
 impl RequestBody {
-    fn from(request_body: &Value) -> Result<RequestBody, Vec<String>> {
-        Ok(RequestBody(request_body.clone()))
+    pub fn empty() -> RequestBody {
+        RequestBody(Value::Null)
+    }
+
+    // Decodes the raw request body based on its Content-Type. Bodies without a Content-Type
+    // are expected to be JSON. Form fields end up as a record, text as a string and binary
+    // content (including uploaded files of a multipart form) as a list of bytes.
+    pub async fn from_bytes(headers: &HeaderMap, body: Bytes) -> Result<RequestBody, String> {
+        if body.is_empty() {
+            return Ok(RequestBody::empty());
+        }
+
+        let content_type = headers
+            .get(http::header::CONTENT_TYPE)
+            .map(|value| {
+                value
+                    .to_str()
+                    .map_err(|err| err.to_string())
+                    .and_then(|value| value.parse::<Mime>().map_err(|err| err.to_string()))
+            })
+            .transpose()
+            .map_err(|err| format!("Invalid Content-Type: {}", err))?;
+
+        let value = match content_type {
+            None => internal::json_body(&body)?,
+            Some(content_type) => match (content_type.type_(), content_type.subtype()) {
+                (mime::APPLICATION, mime::JSON) => internal::json_body(&body)?,
+                (_, _) if content_type.suffix() == Some(mime::JSON) => internal::json_body(&body)?,
+                (mime::APPLICATION, mime::WWW_FORM_URLENCODED) => internal::form_body(&body),
+                (mime::APPLICATION, mime::OCTET_STREAM) => internal::bytes_body(&body),
+                (mime::MULTIPART, mime::FORM_DATA) => {
+                    let boundary = content_type
+                        .get_param(mime::BOUNDARY)
+                        .ok_or("Missing boundary in multipart Content-Type")?;
+                    internal::multipart_body(boundary.as_str(), body).await?
+                }
+                (mime::TEXT, _) => internal::text_body(&body)?,
+                _ => Err(format!("Unsupported Content-Type: {}", content_type))?,
+            },
+        };
+
+        Ok(RequestBody(value))
     }
 }
 
@@ -205,6 +259,7 @@ pub struct JsonKeyValue {
 }
 
 mod internal {
+    use bytes::Bytes;
     use rib::{CoercedNumericValue, LiteralValue};
     use serde_json::Value;
 
@@ -226,4 +281,162 @@ mod internal {
             LiteralValue::Bool(value) => Value::Bool(value),
         }
     }
+
+    pub(crate) fn json_body(body: &[u8]) -> Result<Value, String> {
+        serde_json::from_slice(body).map_err(|err| format!("Request body parse error: {}", err))
+    }
+
+    pub(crate) fn text_body(body: &[u8]) -> Result<Value, String> {
+        Ok(Value::String(utf8(body)?.to_string()))
+    }
+
+    fn utf8(body: &[u8]) -> Result<&str, String> {
+        std::str::from_utf8(body).map_err(|err| format!("Request body is not valid UTF-8: {}", err))
+    }
+
+    pub(crate) fn bytes_body(body: &[u8]) -> Value {
+        Value::Array(body.iter().map(|byte| Value::from(*byte)).collect())
+    }
+
+    // Form fields are kept as strings, as their types are not known here
+    pub(crate) fn form_body(body: &[u8]) -> Value {
+        let fields = url::form_urlencoded::parse(body)
+            .map(|(name, value)| (name.to_string(), Value::String(value.into_owned())))
+            .collect();
+
+        Value::Object(fields)
+    }
+
+    pub(crate) async fn multipart_body(boundary: &str, body: Bytes) -> Result<Value, String> {
+        let stream = futures::stream::once(async move { Ok::<Bytes, std::io::Error>(body) });
+        let mut multipart = multer::Multipart::new(stream, boundary);
+
+        let mut fields = serde_json::Map::new();
+
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|err| format!("Request body parse error: {}", err))?
+        {
+            let name = field
+                .name()
+                .ok_or("Multipart field without a name")?
+                .to_string();
+            let is_file = field.file_name().is_some();
+            let content = field
+                .bytes()
+                .await
+                .map_err(|err| format!("Request body parse error: {}", err))?;
+
+            let value = if is_file {
+                bytes_body(&content)
+            } else {
+                Value::String(utf8(&content)?.to_string())
+            };
+
+            fields.insert(name, value);
+        }
+
+        Ok(Value::Object(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::worker_binding::RequestBody;
+    use bytes::Bytes;
+    use http::{HeaderMap, HeaderValue};
+    use serde_json::{json, Value};
+
+    async fn decode(content_type: Option<&str>, body: &'static [u8]) -> Result<Value, String> {
+        let mut headers = HeaderMap::new();
+        if let Some(content_type) = content_type {
+            headers.insert(
+                http::header::CONTENT_TYPE,
+                HeaderValue::from_str(content_type).unwrap(),
+            );
+        }
+
+        RequestBody::from_bytes(&headers, Bytes::from_static(body))
+            .await
+            .map(|body| body.0)
+    }
+
+    #[test]
+    async fn test_json_body() {
+        let result = decode(None, br#"{"id": 1}"#).await;
+        assert_eq!(result, Ok(json!({"id": 1})));
+
+        let result = decode(Some("application/json"), br#"{"id": 1}"#).await;
+        assert_eq!(result, Ok(json!({"id": 1})));
+
+        let result = decode(Some("application/merge-patch+json"), br#"{"id": 1}"#).await;
+        assert_eq!(result, Ok(json!({"id": 1})));
+
+        let result = decode(None, b"").await;
+        assert_eq!(result, Ok(Value::Null));
+
+        assert!(decode(None, b"not json").await.is_err());
+    }
+
+    #[test]
+    async fn test_form_body() {
+        let result = decode(
+            Some("application/x-www-form-urlencoded"),
+            b"name=John+Doe&age=42&code=007",
+        )
+        .await;
+
+        assert_eq!(
+            result,
+            Ok(json!({"name": "John Doe", "age": "42", "code": "007"}))
+        );
+    }
+
+    #[test]
+    async fn test_text_body() {
+        let result = decode(Some("text/plain; charset=utf-8"), b"hello").await;
+        assert_eq!(result, Ok(json!("hello")));
+
+        assert!(decode(Some("text/plain"), b"\xff\xfe").await.is_err());
+    }
+
+    #[test]
+    async fn test_binary_body() {
+        let result = decode(Some("application/octet-stream"), b"\x00\x01\xff").await;
+        assert_eq!(result, Ok(json!([0, 1, 255])));
+    }
+
+    #[test]
+    async fn test_multipart_body() {
+        let body = b"--boundary\r\n\
+            Content-Disposition: form-data; name=\"title\"\r\n\
+            \r\n\
+            report\r\n\
+            --boundary\r\n\
+            Content-Disposition: form-data; name=\"code\"\r\n\
+            \r\n\
+            007\r\n\
+            --boundary\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"a.bin\"\r\n\
+            Content-Type: application/octet-stream\r\n\
+            \r\n\
+            \x01\x02\r\n\
+            --boundary--\r\n";
+
+        let result = decode(Some("multipart/form-data; boundary=boundary"), body).await;
+
+        assert_eq!(
+            result,
+            Ok(json!({"title": "report", "code": "007", "file": [1, 2]}))
+        );
+    }
+
+    #[test]
+    async fn test_unsupported_body() {
+        assert!(decode(Some("image/png"), b"\x89PNG").await.is_err());
+        assert!(decode(Some("multipart/form-data"), b"--x--").await.is_err());
+    }
 }
@@ -1,6 +1,7 @@
 use crate::api::WorkerBindingType;
 use crate::api_definition::http::{CompiledHttpApiDefinition, VarInfo};
 use crate::getter::GetterExt;
+use crate::http::auth::{self, AuthenticationError};
 use crate::http::http_request::router;
 use crate::http::router::RouterPattern;
 use crate::http::InputHttpRequest;
@@ -10,12 +11,20 @@ use crate::worker_service_rib_interpreter::EvaluationError;
 use crate::worker_service_rib_interpreter::WorkerServiceRibInterpreter;
 use async_trait::async_trait;
 use futures::stream;
-use golem_common::model::{IdempotencyKey, WorkerId};
+use golem_api_grpc::proto::golem::worker::InvocationContext;
+use golem_common::grpc::trace_invocation_context;
+use golem_common::model::http_handler::{
+    IncomingHttpRequest, OutgoingHttpResponse, INCOMING_HTTP_HANDLER_FUNCTION,
+    MAX_HTTP_HANDLER_BODY_SIZE,
+};
+use golem_common::model::trace_context::{TRACEPARENT_HEADER, TRACESTATE_HEADER};
+use golem_common::model::{IdempotencyKey, TargetWorkerId, WorkerId};
 use golem_service_base::auth::EmptyAuthCtx;
 use golem_service_base::model::{GetFileResponse, VersionedComponentId};
 use golem_wasm_rpc::json::TypeAnnotatedValueJsonExtensions as _;
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 use golem_wasm_rpc::protobuf::typed_result::ResultValue;
+use golem_wasm_rpc::IntoValue;
 use rib::RibResult;
 use serde_json::Value;
 use std::cell::Cell;
@@ -24,7 +33,9 @@ use std::fmt::Display;
 use std::sync::Arc;
 
 use crate::worker_binding::rib_input_value_resolver::RibInputValueResolver;
-use crate::worker_binding::{RequestDetails, ResponseMappingCompiled, RibInputTypeMismatch};
+use crate::worker_binding::{
+    RequestBody, RequestDetails, ResponseMappingCompiled, RibInputTypeMismatch,
+};
 use crate::worker_bridge_execution::to_response::ToResponse;
 
 // Every type of request (example: InputHttpRequest (which corresponds to a Route)) can have an instance of this resolver,
@@ -39,17 +50,32 @@ pub trait RequestToWorkerBindingResolver<ApiDefinition> {
 }
 
 #[derive(Debug)]
-pub struct WorkerBindingResolutionError(pub String);
+pub enum WorkerBindingResolutionError {
+    Invalid(String),
+    // The caller could not be authenticated with the security scheme of the route,
+    // `challenge` is the value of the `WWW-Authenticate` response header
+    Unauthorized {
+        error: AuthenticationError,
+        challenge: String,
+    },
+}
 
 impl<A: AsRef<str>> From<A> for WorkerBindingResolutionError {
     fn from(message: A) -> Self {
-        WorkerBindingResolutionError(message.as_ref().to_string())
+        WorkerBindingResolutionError::Invalid(message.as_ref().to_string())
     }
 }
 
 impl Display for WorkerBindingResolutionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Worker binding resolution error: {}", self.0)
+        match self {
+            WorkerBindingResolutionError::Invalid(message) => {
+                write!(f, "Worker binding resolution error: {}", message)
+            }
+            WorkerBindingResolutionError::Unauthorized { error, .. } => {
+                write!(f, "Unauthorized request: {}", error)
+            }
+        }
     }
 }
 
@@ -66,6 +92,7 @@ pub struct WorkerDetail {
     pub component_id: VersionedComponentId,
     pub worker_name: String,
     pub idempotency_key: Option<IdempotencyKey>,
+    pub invocation_context: Option<InvocationContext>,
 }
 
 impl WorkerDetail {
@@ -117,6 +144,7 @@ impl ResolvedWorkerBindingFromRequest {
                         &self.worker_detail.worker_name,
                         &self.worker_detail.component_id.component_id,
                         &self.worker_detail.idempotency_key,
+                        &self.worker_detail.invocation_context,
                         &self.compiled_response_mapping.compiled_response.clone(),
                         &rib_input,
                     )
@@ -148,6 +176,66 @@ impl ResolvedWorkerBindingFromRequest {
         }
     }
 
+    pub async fn invoke_http_handler<R>(
+        &self,
+        request: IncomingHttpRequest,
+        worker_service: &Arc<dyn WorkerService<EmptyAuthCtx> + Sync + Send>,
+    ) -> R
+    where
+        OutgoingHttpResponse: ToResponse<R>,
+        String: ToResponse<R>,
+    {
+        match self
+            .invoke_http_handler_internal(request, worker_service)
+            .await
+        {
+            Ok(response) => response.to_response(&self.request_details),
+            Err(err) => err.to_response(&self.request_details),
+        }
+    }
+
+    async fn invoke_http_handler_internal(
+        &self,
+        request: IncomingHttpRequest,
+        worker_service: &Arc<dyn WorkerService<EmptyAuthCtx> + Sync + Send>,
+    ) -> Result<OutgoingHttpResponse, String> {
+        if request.body.len() > MAX_HTTP_HANDLER_BODY_SIZE {
+            return Err(format!(
+                "The request body is larger than the maximum of {MAX_HTTP_HANDLER_BODY_SIZE} bytes supported by HTTP handlers"
+            ));
+        }
+
+        let worker_id = TargetWorkerId {
+            component_id: self.worker_detail.component_id.component_id.clone(),
+            worker_name: Some(self.worker_detail.worker_name.clone()),
+        };
+        let metadata = WorkerRequestMetadata {
+            account_id: None,
+            limits: None,
+        };
+
+        let result = worker_service
+            .invoke_and_await(
+                &worker_id,
+                self.worker_detail.idempotency_key.clone(),
+                INCOMING_HTTP_HANDLER_FUNCTION.to_string(),
+                vec![request.into_value().into()],
+                self.worker_detail.invocation_context.clone(),
+                metadata,
+            )
+            .await
+            .map_err(|err| format!("Failed to invoke the HTTP handler: {err}"))?;
+
+        let response = result
+            .result
+            .into_iter()
+            .next()
+            .ok_or("The HTTP handler did not return a response".to_string())?;
+        let response: golem_wasm_rpc::Value = response.try_into()?;
+
+        OutgoingHttpResponse::try_from(response)
+    }
+
     fn get_file_server_result(worker_response: RibResult) -> FileServerResult<String> {
         Self::get_file_server_result_internal(worker_response)
             .unwrap_or_else(FileServerResult::SimpleErr)
@@ -235,25 +323,31 @@ impl RequestToWorkerBindingResolver<CompiledHttpApiDefinition> for InputHttpRequ
         &self,
         compiled_api_definitions: Vec<CompiledHttpApiDefinition>,
     ) -> Result<ResolvedWorkerBindingFromRequest, WorkerBindingResolutionError> {
-        let compiled_routes = compiled_api_definitions
-            .iter()
-            .flat_map(|x| x.routes.clone())
-            .collect::<Vec<_>>();
-
-        let api_request = self;
-        let router = router::build(compiled_routes);
-        let path: Vec<&str> = RouterPattern::split(&api_request.input_path.base_path).collect();
+        let route = self
+            .resolve_route(&compiled_api_definitions)
+            .ok_or("Failed to resolve route")?;
+
+        self.resolve_worker_binding_of_route(&route).await
+    }
+}
+
+impl InputHttpRequest {
+    // Resolves the worker binding of an already matched route of the request
+    pub async fn resolve_worker_binding_of_route(
+        &self,
+        route: &router::RouteEntry,
+    ) -> Result<ResolvedWorkerBindingFromRequest, WorkerBindingResolutionError> {
+        let path: Vec<&str> = RouterPattern::split(&self.input_path.base_path).collect();
         let request_query_variables = self.input_path.query_components().unwrap_or_default();
-        let request_body = &self.req_body;
         let headers = &self.headers;
 
         let router::RouteEntry {
             path_params,
             query_params,
             binding,
-        } = router
-            .check_path(&api_request.req_method, &path)
-            .ok_or("Failed to resolve route")?;
+            security,
+            ..
+        } = route;
 
         let zipped_path_params: HashMap<VarInfo, &str> = {
             path_params
@@ -262,12 +356,30 @@ impl RequestToWorkerBindingResolver<CompiledHttpApiDefinition> for InputHttpRequ
                 .collect()
         };
 
+        let request_auth = match security {
+            Some(scheme) => Some(auth::authenticate(scheme, headers).await.map_err(|error| {
+                WorkerBindingResolutionError::Unauthorized {
+                    error,
+                    challenge: auth::challenge(scheme),
+                }
+            })?),
+            None => None,
+        };
+
+        // Requests forwarded to an incoming-handler keep their raw body
+        let request_body = if binding.binding_type == WorkerBindingType::HttpHandler {
+            RequestBody::empty()
+        } else {
+            RequestBody::from_bytes(headers, self.req_body.clone()).await?
+        };
+
         let http_request_details = RequestDetails::from(
             &zipped_path_params,
             &request_query_variables,
             query_params,
             request_body,
             headers,
+            request_auth,
         )
         .map_err(|err| format!("Failed to fetch input request details {}", err.join(", ")))?;
 
@@ -315,10 +427,22 @@ impl RequestToWorkerBindingResolver<CompiledHttpApiDefinition> for InputHttpRequ
                     .map(|value| IdempotencyKey::new(value.to_string()))
             };
 
+        let header_value = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|h| h.to_str().ok())
+                .map(|value| value.to_string())
+        };
+        let invocation_context = trace_invocation_context(
+            header_value(TRACEPARENT_HEADER),
+            header_value(TRACESTATE_HEADER),
+        );
+
         let worker_detail = WorkerDetail {
             component_id: component_id.clone(),
             worker_name,
             idempotency_key,
+            invocation_context,
         };
 
         let resolved_binding = ResolvedWorkerBindingFromRequest {
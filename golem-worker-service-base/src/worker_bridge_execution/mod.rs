@@ -1,3 +1,4 @@
+use golem_api_grpc::proto::golem::worker::InvocationContext;
 use golem_common::model::{ComponentId, IdempotencyKey};
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
@@ -13,4 +14,5 @@ pub struct WorkerRequest {
     pub function_name: String,
     pub function_params: Vec<TypeAnnotatedValue>,
     pub idempotency_key: Option<IdempotencyKey>,
+    pub invocation_context: Option<InvocationContext>,
 }
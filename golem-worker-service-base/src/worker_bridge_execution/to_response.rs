@@ -1,7 +1,8 @@
 use crate::worker_binding::{FileServerResult, RequestDetails, RibInputTypeMismatch};
 use crate::worker_service_rib_interpreter::EvaluationError;
 
-use http::StatusCode;
+use golem_common::model::http_handler::OutgoingHttpResponse;
+use http::{HeaderName, HeaderValue, StatusCode};
 use poem::Body;
 use rib::RibResult;
 
@@ -63,6 +64,28 @@ impl ToResponse<poem::Response> for FileServerResult {
     }
 }
 
+// The trailers returned by the handler are not forwarded, the response body is sent as a whole
+impl ToResponse<poem::Response> for OutgoingHttpResponse {
+    fn to_response(&self, _request_details: &RequestDetails) -> poem::Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let mut response = poem::Response::builder()
+            .status(status)
+            .body(Body::from_vec(self.body.clone()));
+
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_bytes(value),
+            ) {
+                response.headers_mut().append(name, value);
+            }
+        }
+
+        response
+    }
+}
+
 mod internal {
     use crate::worker_binding::{FileServerResult, HttpRequestDetails, RequestDetails};
     use crate::worker_bridge_execution::content_type_mapper::{
@@ -1,20 +1,42 @@
 use golem_wasm_ast::analysis::AnalysedExport;
-use rib::{CompilerOutput, Expr};
+use rib::{CompilerOutput, Expr, RibModule};
 
 // A wrapper service over original Rib Compiler concerning
 // the details of the worker bridge.
 pub trait WorkerServiceRibCompiler {
-    fn compile(rib: &Expr, export_metadata: &[AnalysedExport]) -> Result<CompilerOutput, String>;
+    fn compile(
+        rib: &Expr,
+        export_metadata: &[AnalysedExport],
+        rib_modules: &[RibModule],
+    ) -> Result<CompilerOutput, String>;
+
+    fn compile_module(rib_module: &RibModule) -> Result<(), String>;
 }
 
 pub struct DefaultRibCompiler;
 
 impl WorkerServiceRibCompiler for DefaultRibCompiler {
-    fn compile(rib: &Expr, export_metadata: &[AnalysedExport]) -> Result<CompilerOutput, String> {
+    // The imports in the Rib script are replaced by the definitions of the given modules
+    fn compile(
+        rib: &Expr,
+        export_metadata: &[AnalysedExport],
+        rib_modules: &[RibModule],
+    ) -> Result<CompilerOutput, String> {
+        let mut rib = rib.clone();
+        rib.resolve_imports(rib_modules)?;
+
         rib::compile_with_limited_globals(
-            rib,
+            &rib,
             &export_metadata.to_vec(),
             Some(vec!["request".to_string()]),
         )
     }
+
+    // A module is type checked once when it is created, independently of the routes importing it.
+    // Its definitions cannot refer to the request, or call worker functions
+    fn compile_module(rib_module: &RibModule) -> Result<(), String> {
+        rib::compile_with_limited_globals(&rib_module.to_expr(), &vec![], Some(vec![]))
+            .map(|_| ())
+            .map_err(|err| format!("Invalid Rib module `{}`: {}", rib_module.name, err))
+    }
 }
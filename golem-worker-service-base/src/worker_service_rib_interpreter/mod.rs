@@ -5,9 +5,12 @@ use std::sync::Arc;
 
 use golem_wasm_rpc::protobuf::type_annotated_value::TypeAnnotatedValue;
 
+use golem_api_grpc::proto::golem::worker::InvocationContext;
 use golem_common::model::{ComponentId, IdempotencyKey};
 
-use rib::{RibByteCode, RibFunctionInvoke, RibInput, RibResult};
+use rib::{
+    Interpreter, RibByteCode, RibFunctionInvoke, RibInput, RibResult, DEFAULT_MAX_CALL_DEPTH,
+};
 
 use crate::worker_bridge_execution::{WorkerRequest, WorkerRequestExecutor};
 
@@ -22,6 +25,7 @@ pub trait WorkerServiceRibInterpreter {
         worker_name: &str,
         component_id: &ComponentId,
         idempotency_key: &Option<IdempotencyKey>,
+        invocation_context: &Option<InvocationContext>,
         rib_byte_code: &RibByteCode,
         rib_input: &RibInput,
     ) -> Result<RibResult, EvaluationError>;
@@ -44,6 +48,7 @@ impl From<String> for EvaluationError {
 
 pub struct DefaultRibInterpreter {
     worker_request_executor: Arc<dyn WorkerRequestExecutor + Sync + Send>,
+    max_call_depth: usize,
 }
 
 impl DefaultRibInterpreter {
@@ -52,8 +57,14 @@ impl DefaultRibInterpreter {
     ) -> Self {
         DefaultRibInterpreter {
             worker_request_executor,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
         }
     }
+
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
 }
 
 #[async_trait]
@@ -63,6 +74,7 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
         worker_name: &str,
         component_id: &ComponentId,
         idempotency_key: &Option<IdempotencyKey>,
+        invocation_context: &Option<InvocationContext>,
         expr: &RibByteCode,
         rib_input: &RibInput,
     ) -> Result<RibResult, EvaluationError> {
@@ -71,6 +83,7 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
         let worker_name = worker_name.to_string();
         let component_id = component_id.clone();
         let idempotency_key = idempotency_key.clone();
+        let invocation_context = invocation_context.clone();
 
         let worker_invoke_function: RibFunctionInvoke = Arc::new(
             move |function_name: String, parameters: Vec<TypeAnnotatedValue>| {
@@ -78,6 +91,7 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
                 let component_id = component_id.clone();
                 let worker_name = worker_name.clone();
                 let idempotency_key = idempotency_key.clone();
+                let invocation_context = invocation_context.clone();
                 let executor = executor.clone();
 
                 async move {
@@ -87,6 +101,7 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
                         function_name,
                         function_params: parameters,
                         idempotency_key,
+                        invocation_context,
                     };
 
                     executor
@@ -98,7 +113,9 @@ impl WorkerServiceRibInterpreter for DefaultRibInterpreter {
                 .boxed() // This ensures the future is boxed with the correct type
             },
         );
-        rib::interpret(expr, rib_input, worker_invoke_function)
+        Interpreter::new(rib_input, worker_invoke_function)
+            .with_max_call_depth(self.max_call_depth)
+            .run(expr.clone())
             .await
             .map_err(EvaluationError)
     }
@@ -16,7 +16,7 @@ use golem_worker_service_base::api_definition::http::HttpApiDefinitionRequest;
 use golem_worker_service_base::api_definition::{
     ApiDefinitionId, ApiDeploymentRequest, ApiSite, ApiSiteString, ApiVersion,
 };
-use golem_worker_service_base::repo::{api_definition, api_deployment};
+use golem_worker_service_base::repo::{api_definition, api_deployment, rib_module};
 use golem_worker_service_base::service::api_definition::{
     ApiDefinitionError, ApiDefinitionIdWithVersion, ApiDefinitionService,
     ApiDefinitionServiceDefault,
@@ -28,6 +28,9 @@ use golem_worker_service_base::service::component::{ComponentResult, ComponentSe
 use golem_worker_service_base::service::http::http_api_definition_validator::{
     HttpApiDefinitionValidator, RouteValidationError,
 };
+use golem_worker_service_base::service::rib_module::{
+    RibModuleRequest, RibModuleService, RibModuleServiceDefault,
+};
 
 use chrono::Utc;
 use golem_common::model::component_constraint::FunctionConstraintCollection;
@@ -99,8 +102,10 @@ pub async fn test_with_postgres_db() {
     let api_deployment_repo: Arc<dyn api_deployment::ApiDeploymentRepo + Sync + Send> = Arc::new(
         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
     );
+    let rib_module_repo: Arc<dyn rib_module::RibModuleRepo + Sync + Send> =
+        Arc::new(rib_module::DbRibModuleRepo::new(db_pool.clone().into()));
 
-    test_services(api_definition_repo, api_deployment_repo).await;
+    test_services(api_definition_repo, api_deployment_repo, rib_module_repo).await;
 }
 
 #[test]
@@ -123,8 +128,10 @@ pub async fn test_with_sqlite_db() {
     let api_deployment_repo: Arc<dyn api_deployment::ApiDeploymentRepo + Sync + Send> = Arc::new(
         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
     );
+    let rib_module_repo: Arc<dyn rib_module::RibModuleRepo + Sync + Send> =
+        Arc::new(rib_module::DbRibModuleRepo::new(db_pool.clone().into()));
 
-    test_services(api_definition_repo, api_deployment_repo).await;
+    test_services(api_definition_repo, api_deployment_repo, rib_module_repo).await;
 }
 
 struct TestComponentService;
@@ -132,6 +139,7 @@ struct TestComponentService;
 impl TestComponentService {
     pub fn test_component() -> Component {
         use golem_common::model::component_metadata::ComponentMetadata;
+        use golem_common::model::quotas::WorkerQuotas;
         use golem_service_base::model::{ComponentName, VersionedComponentId};
 
         let id = VersionedComponentId {
@@ -147,6 +155,7 @@ impl TestComponentService {
                 exports: Self::get_metadata(),
                 producers: vec![],
                 memories: vec![],
+                quotas: WorkerQuotas::default(),
             },
             created_at: Some(Utc::now()),
             component_type: None,
@@ -207,6 +216,7 @@ impl<AuthCtx> ComponentService<AuthCtx> for TestComponentService {
 async fn test_services(
     api_definition_repo: Arc<dyn api_definition::ApiDefinitionRepo + Sync + Send>,
     api_deployment_repo: Arc<dyn api_deployment::ApiDeploymentRepo + Sync + Send>,
+    rib_module_repo: Arc<dyn rib_module::RibModuleRepo + Sync + Send>,
 ) {
     let component_service: Arc<dyn ComponentService<EmptyAuthCtx> + Sync + Send> =
         Arc::new(TestComponentService {});
@@ -221,6 +231,7 @@ async fn test_services(
         component_service.clone(),
         api_definition_repo.clone(),
         api_deployment_repo.clone(),
+        rib_module_repo.clone(),
         api_definition_validator_service.clone(),
     ));
 
@@ -232,11 +243,16 @@ async fn test_services(
         component_service.clone(),
     ));
 
+    let rib_module_service: Arc<
+        dyn RibModuleService<EmptyAuthCtx, DefaultNamespace> + Sync + Send,
+    > = Arc::new(RibModuleServiceDefault::new(rib_module_repo.clone()));
+
     test_definition_crud(definition_service.clone()).await;
     test_delete_non_existing(definition_service.clone()).await;
     test_deployment(definition_service.clone(), deployment_service.clone()).await;
     test_deployment_conflict(definition_service.clone(), deployment_service.clone()).await;
     test_file_server(definition_service.clone(), deployment_service.clone()).await;
+    test_rib_module_import(definition_service.clone(), rib_module_service.clone()).await;
 }
 
 async fn test_deployment(
@@ -751,6 +767,101 @@ async fn test_delete_non_existing(
     assert!(delete_result.is_err(), "definition should not exist");
 }
 
+async fn test_rib_module_import(
+    definition_service: Arc<
+        dyn ApiDefinitionService<EmptyAuthCtx, DefaultNamespace, RouteValidationError>
+            + Sync
+            + Send,
+    >,
+    rib_module_service: Arc<dyn RibModuleService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+) {
+    let module_name = format!("statuses-{}", Uuid::new_v4());
+
+    let module_v0 = rib_module_service
+        .create(
+            &RibModuleRequest {
+                name: module_name.clone(),
+                source: "let not_found: u64 = 401; let success: u64 = 200".to_string(),
+            },
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(module_v0.version, 0);
+
+    let module_v1 = rib_module_service
+        .create(
+            &RibModuleRequest {
+                name: module_name.clone(),
+                source: "let not_found: u64 = 404; let success: u64 = 200".to_string(),
+            },
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(module_v1.version, 1);
+
+    let invalid_module = rib_module_service
+        .create(
+            &RibModuleRequest {
+                name: module_name.clone(),
+                source: "let status: u64 = 200; request.path.user".to_string(),
+            },
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await;
+    assert!(invalid_module.is_err(), "module should not be valid");
+
+    let def = get_api_definition(
+            &Uuid::new_v4().to_string(),
+            "0.0.1",
+            "/api/modules/{user-id}",
+            "${let userid: u64 = request.path.user; let res = if userid>100u64 then 0u64 else 1u64; \"shopping-cart-${res}\"}",
+            &format!("${{ import {module_name}; let result = golem:it/api.{{get-cart-contents}}(\"foo\"); let status = if result == \"admin\" then not_found else success; status }}"),
+            true,
+            &WorkerBindingType::Default,
+        );
+
+    let compiled = definition_service
+        .create(&def, &DefaultNamespace::default(), &EmptyAuthCtx::default())
+        .await
+        .unwrap();
+
+    // The import without a version is pinned to the latest version of the module
+    assert_eq!(
+        compiled.routes[0]
+            .binding
+            .response_compiled
+            .response_rib_expr
+            .imports(),
+        vec![rib::RibImport::new(&module_name, Some(1))]
+    );
+
+    let def_missing_module = get_api_definition(
+            &Uuid::new_v4().to_string(),
+            "0.0.1",
+            "/api/modules/{user-id}",
+            "${let userid: u64 = request.path.user; let res = if userid>100u64 then 0u64 else 1u64; \"shopping-cart-${res}\"}",
+            &format!("${{ import {module_name}@2; success }}"),
+            true,
+            &WorkerBindingType::Default,
+        );
+
+    let result = definition_service
+        .create(
+            &def_missing_module,
+            &DefaultNamespace::default(),
+            &EmptyAuthCtx::default(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ApiDefinitionError::RibModuleNotFound(_))
+    ));
+}
 
 async fn test_file_server(
     definition_service: Arc<
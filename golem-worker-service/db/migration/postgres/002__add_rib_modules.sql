@@ -0,0 +1,9 @@
+CREATE TABLE rib_modules
+(
+    namespace  text      NOT NULL,
+    name       text      NOT NULL,
+    version    bigint    NOT NULL,
+    source     text      NOT NULL,
+    created_at timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (namespace, name, version)
+);
@@ -8,6 +8,7 @@ use golem_worker_service_base::api::ApiEndpointError;
 use golem_worker_service_base::api::HttpApiDefinitionRequest;
 use golem_worker_service_base::api::HttpApiDefinitionWithTypeInfo;
 use golem_worker_service_base::api_definition::http::get_api_definition;
+use golem_worker_service_base::api_definition::http::get_open_api_definition;
 use golem_worker_service_base::api_definition::http::CompiledHttpApiDefinition;
 use golem_worker_service_base::api_definition::http::HttpApiDefinitionRequest as CoreHttpApiDefinitionRequest;
 use golem_worker_service_base::api_definition::http::JsonOpenApiDefinition;
@@ -199,6 +200,57 @@ impl RegisterApiDefinitionApi {
         record.result(response)
     }
 
+    /// Export an API definition as OpenAPI
+    ///
+    /// Exports an API definition selected by its API definition ID and version as an OpenAPI
+    /// document which can be imported again. Schemas are derived from the types of the bindings.
+    #[oai(
+        path = "/:id/:version/export",
+        method = "get",
+        operation_id = "export_definition"
+    )]
+    async fn export(
+        &self,
+        id: Path<ApiDefinitionId>,
+        version: Path<ApiVersion>,
+    ) -> Result<Json<JsonOpenApiDefinition>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "export_definition",
+            api_definition_id = id.0.to_string(),
+            version = version.0.to_string()
+        );
+
+        let response = {
+            let api_definition_id = id.0;
+
+            let api_version = version.0;
+
+            let data = self
+                .definition_service
+                .get(
+                    &api_definition_id,
+                    &api_version,
+                    &DefaultNamespace::default(),
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?;
+
+            let definition = data.ok_or(ApiEndpointError::not_found(safe(format!(
+                "Can't find api definition with id {api_definition_id}, and version {api_version}"
+            ))))?;
+
+            let openapi = get_open_api_definition(&definition).map_err(|e| {
+                error!("Failed to export API definition as OpenAPI - error: {}", e);
+                ApiEndpointError::internal(safe(e))
+            })?;
+
+            Ok(Json(JsonOpenApiDefinition(openapi)))
+        };
+
+        record.result(response)
+    }
+
     /// Delete an API definition
     ///
     /// Deletes an API definition by its API definition ID and version.
@@ -316,6 +368,7 @@ mod test {
         ApiDefinitionRepo, DbApiDefinitionRepo, LoggedApiDefinitionRepo,
     };
     use golem_worker_service_base::repo::api_deployment;
+    use golem_worker_service_base::repo::rib_module;
     use golem_worker_service_base::service::api_definition::ApiDefinitionServiceDefault;
     use golem_worker_service_base::service::component::ComponentResult;
     use golem_worker_service_base::service::http::http_api_definition_validator::HttpApiDefinitionValidator;
@@ -396,12 +449,17 @@ mod test {
             Arc::new(api_deployment::LoggedDeploymentRepo::new(
                 api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
             ));
+        let rib_module_repo: Arc<dyn rib_module::RibModuleRepo + Sync + Send> =
+            Arc::new(rib_module::LoggedRibModuleRepo::new(
+                rib_module::DbRibModuleRepo::new(db_pool.clone().into()),
+            ));
 
         let component_service: ComponentService = Arc::new(TestComponentService);
         let definition_service = ApiDefinitionServiceDefault::new(
             component_service,
             api_definition_repo,
             api_deployment_repo,
+            rib_module_repo,
             Arc::new(HttpApiDefinitionValidator {}),
         );
 
@@ -424,6 +482,8 @@ mod test {
                 version: ApiVersion("1.0".to_string()),
                 routes: vec![],
                 draft: false,
+                cors: None,
+                security_schemes: vec![],
             };
 
         let response = client
@@ -454,6 +514,8 @@ mod test {
                 version: ApiVersion("42.0".to_string()),
                 routes: vec![],
                 draft: false,
+                cors: None,
+                security_schemes: vec![],
             };
 
         let response = client
@@ -479,6 +541,8 @@ mod test {
                 version: ApiVersion("1.0".to_string()),
                 routes: vec![],
                 draft: false,
+                cors: None,
+                security_schemes: vec![],
             };
         let response = client
             .post("/v1/api/definitions")
@@ -493,6 +557,8 @@ mod test {
                 version: ApiVersion("2.0".to_string()),
                 routes: vec![],
                 draft: false,
+                cors: None,
+                security_schemes: vec![],
             };
         let response = client
             .post("/v1/api/definitions")
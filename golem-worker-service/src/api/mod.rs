@@ -1,5 +1,7 @@
 pub mod api_definition;
 pub mod api_deployment;
+pub mod rib_module;
+pub mod topic;
 pub mod worker;
 pub mod worker_connect;
 
@@ -18,6 +20,8 @@ type ApiServices = (
     WorkerApi,
     api_definition::RegisterApiDefinitionApi,
     api_deployment::ApiDeploymentApi,
+    rib_module::RibModuleApi,
+    topic::TopicApi,
     HealthcheckApi,
 );
 
@@ -37,15 +41,20 @@ pub fn combined_routes(prometheus_registry: Arc<Registry>, services: &Services)
         .nest("/metrics", metrics)
         .at(
             "/v1/components/:component_id/workers/:worker_name/connect",
-            get(worker_connect::ws.data(connect_services)),
+            get(worker_connect::ws.data(connect_services.clone())),
+        )
+        .at(
+            "/v1/components/:component_id/watch",
+            get(worker_connect::watch_ws.data(connect_services)),
         )
 }
 
-pub fn custom_request_route(services: Services) -> Route {
+pub fn custom_request_route(services: Services, rib_max_call_depth: usize) -> Route {
     let custom_request_executor = CustomHttpRequestApi::new(
         services.worker_to_http_service,
         services.http_definition_lookup_service,
         services.worker_service,
+        rib_max_call_depth,
     );
 
     Route::new().nest("/", custom_request_executor)
@@ -60,6 +69,10 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<ApiServices,
             },
             api_definition::RegisterApiDefinitionApi::new(services.definition_service.clone()),
             api_deployment::ApiDeploymentApi::new(services.deployment_service.clone()),
+            rib_module::RibModuleApi::new(services.rib_module_service.clone()),
+            topic::TopicApi {
+                worker_service: services.worker_service.clone(),
+            },
             HealthcheckApi,
         ),
         "Golem API",
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use golem_common::{recorded_http_api_request, safe};
+use golem_service_base::api_tags::ApiTags;
+use golem_service_base::auth::{DefaultNamespace, EmptyAuthCtx};
+use golem_worker_service_base::api::ApiEndpointError;
+use golem_worker_service_base::service::rib_module::{
+    RibModuleDefinition, RibModuleRequest, RibModuleService,
+};
+use poem_openapi::param::{Path, Query};
+use poem_openapi::payload::Json;
+use poem_openapi::*;
+use tracing::Instrument;
+
+pub struct RibModuleApi {
+    rib_module_service: Arc<dyn RibModuleService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+}
+
+#[OpenApi(prefix_path = "/v1/api/modules", tag = ApiTags::RibModule)]
+impl RibModuleApi {
+    pub fn new(
+        rib_module_service: Arc<dyn RibModuleService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+    ) -> Self {
+        Self { rib_module_service }
+    }
+
+    /// Create a new version of a Rib module
+    ///
+    /// Creates the first version of a Rib module, or a new version if a module with the same name
+    /// already exists. The module can only contain `let` definitions, which can be used by the
+    /// routes of API definitions after importing the module with `import <name>@<version>;`.
+    #[oai(path = "/", method = "post", operation_id = "create_rib_module")]
+    async fn create(
+        &self,
+        payload: Json<RibModuleRequest>,
+    ) -> Result<Json<RibModuleDefinition>, ApiEndpointError> {
+        let record =
+            recorded_http_api_request!("create_rib_module", rib_module_name = payload.name);
+        let response = self
+            .rib_module_service
+            .create(
+                &payload.0,
+                &DefaultNamespace::default(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map(Json)
+            .map_err(|e| e.into());
+
+        record.result(response)
+    }
+
+    /// Get Rib modules
+    ///
+    /// If `name` is not set, it lists all versions of all Rib modules.
+    /// If `name` is set, it lists all versions of the given Rib module.
+    #[oai(path = "/", method = "get", operation_id = "list_rib_modules")]
+    async fn list(
+        &self,
+        #[oai(name = "name")] name: Query<Option<String>>,
+    ) -> Result<Json<Vec<RibModuleDefinition>>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "list_rib_modules",
+            rib_module_name = name.0.clone().unwrap_or_default()
+        );
+        let response = {
+            let values = match name.0 {
+                Some(name) => {
+                    self.rib_module_service
+                        .get_all_versions(
+                            &name,
+                            &DefaultNamespace::default(),
+                            &EmptyAuthCtx::default(),
+                        )
+                        .instrument(record.span.clone())
+                        .await?
+                }
+                None => {
+                    self.rib_module_service
+                        .get_all(&DefaultNamespace::default(), &EmptyAuthCtx::default())
+                        .instrument(record.span.clone())
+                        .await?
+                }
+            };
+
+            Ok(Json(values))
+        };
+
+        record.result(response)
+    }
+
+    /// Get the latest version of a Rib module
+    #[oai(
+        path = "/:name/latest",
+        method = "get",
+        operation_id = "get_latest_rib_module"
+    )]
+    async fn get_latest(
+        &self,
+        name: Path<String>,
+    ) -> Result<Json<RibModuleDefinition>, ApiEndpointError> {
+        let record = recorded_http_api_request!("get_latest_rib_module", rib_module_name = name.0);
+        let response = {
+            let value = self
+                .rib_module_service
+                .get_latest(
+                    &name.0,
+                    &DefaultNamespace::default(),
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?
+                .ok_or(ApiEndpointError::not_found(safe(
+                    "Rib module not found".to_string(),
+                )))?;
+
+            Ok(Json(value))
+        };
+
+        record.result(response)
+    }
+
+    /// Get a version of a Rib module
+    #[oai(
+        path = "/:name/:version",
+        method = "get",
+        operation_id = "get_rib_module"
+    )]
+    async fn get(
+        &self,
+        name: Path<String>,
+        version: Path<u64>,
+    ) -> Result<Json<RibModuleDefinition>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "get_rib_module",
+            rib_module_name = name.0,
+            rib_module_version = version.0
+        );
+        let response = {
+            let value = self
+                .rib_module_service
+                .get(
+                    &name.0,
+                    version.0,
+                    &DefaultNamespace::default(),
+                    &EmptyAuthCtx::default(),
+                )
+                .instrument(record.span.clone())
+                .await?
+                .ok_or(ApiEndpointError::not_found(safe(
+                    "Rib module not found".to_string(),
+                )))?;
+
+            Ok(Json(value))
+        };
+
+        record.result(response)
+    }
+
+    /// Delete a version of a Rib module
+    ///
+    /// API definitions which are already created keep using the deleted version,
+    /// but new routes can no longer import it.
+    #[oai(
+        path = "/:name/:version",
+        method = "delete",
+        operation_id = "delete_rib_module"
+    )]
+    async fn delete(
+        &self,
+        name: Path<String>,
+        version: Path<u64>,
+    ) -> Result<Json<String>, ApiEndpointError> {
+        let record = recorded_http_api_request!(
+            "delete_rib_module",
+            rib_module_name = name.0,
+            rib_module_version = version.0
+        );
+        let response = self
+            .rib_module_service
+            .delete(
+                &name.0,
+                version.0,
+                &DefaultNamespace::default(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map(|_| Json("Rib module deleted".to_string()))
+            .map_err(|e| e.into());
+
+        record.result(response)
+    }
+}
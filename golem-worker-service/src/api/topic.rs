@@ -0,0 +1,76 @@
+use crate::empty_worker_metadata;
+use crate::service::worker::WorkerService;
+use golem_common::recorded_http_api_request;
+use golem_service_base::api_tags::ApiTags;
+use golem_service_base::auth::EmptyAuthCtx;
+use golem_service_base::model::*;
+use golem_worker_service_base::api::WorkerApiBaseError;
+use poem_openapi::param::Path;
+use poem_openapi::payload::{Binary, Json};
+use poem_openapi::*;
+use tracing::Instrument;
+
+pub struct TopicApi {
+    pub worker_service: WorkerService,
+}
+
+type Result<T> = std::result::Result<T, WorkerApiBaseError>;
+
+#[OpenApi(prefix_path = "/v1/topics", tag = ApiTags::Topic)]
+impl TopicApi {
+    /// List the subscriptions of a topic
+    #[oai(
+        path = "/:topic/subscriptions",
+        method = "get",
+        operation_id = "get_topic_subscriptions"
+    )]
+    async fn get_topic_subscriptions(
+        &self,
+        topic: Path<String>,
+    ) -> Result<Json<TopicSubscriptionsResponse>> {
+        let record = recorded_http_api_request!("get_topic_subscriptions", topic = topic.0);
+
+        let response = self
+            .worker_service
+            .get_topic_subscriptions(&topic.0, empty_worker_metadata(), &EmptyAuthCtx::default())
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|subscriptions| Json(TopicSubscriptionsResponse { subscriptions }));
+
+        record.result(response)
+    }
+
+    /// Publish a message to a topic
+    ///
+    /// The request body is the message's payload. The message gets delivered to each worker subscribed to the
+    /// topic by invoking the subscribed function, and deliveries failing to be enqueued are retried with the next
+    /// message published to the topic.
+    #[oai(
+        path = "/:topic/messages",
+        method = "post",
+        operation_id = "publish_to_topic"
+    )]
+    async fn publish_to_topic(
+        &self,
+        topic: Path<String>,
+        payload: Binary<Vec<u8>>,
+    ) -> Result<Json<PublishToTopicResponse>> {
+        let record = recorded_http_api_request!("publish_to_topic", topic = topic.0);
+
+        let response = self
+            .worker_service
+            .publish_to_topic(
+                &topic.0,
+                payload.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|message_id| Json(PublishToTopicResponse { message_id }));
+
+        record.result(response)
+    }
+}
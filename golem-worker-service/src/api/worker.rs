@@ -1,7 +1,9 @@
 use crate::empty_worker_metadata;
 use crate::service::{component::ComponentService, worker::WorkerService};
+use golem_common::grpc::trace_invocation_context;
 use golem_common::model::{
-    ComponentId, IdempotencyKey, ScanCursor, TargetWorkerId, WorkerFilter, WorkerId,
+    ComponentId, DeadLetterPolicy, DeadLetteredInvocationId, IdempotencyKey, RevertWorkerTarget,
+    ScanCursor, ScheduledInvocationId, TargetWorkerId, WorkerFilter, WorkerId,
 };
 use golem_common::recorded_http_api_request;
 use golem_service_base::api_tags::ApiTags;
@@ -9,13 +11,14 @@ use golem_service_base::auth::EmptyAuthCtx;
 use golem_service_base::model::*;
 use golem_worker_service_base::api::WorkerApiBaseError;
 use poem_openapi::param::{Header, Path, Query};
-use poem_openapi::payload::Json;
+use poem_openapi::payload::{Binary, Json};
 use poem_openapi::*;
 use std::str::FromStr;
 use tap::TapFallible;
 
 use golem_common::model::oplog::OplogIndex;
 use golem_common::model::public_oplog::OplogCursor;
+use golem_common::model::pubsub::TopicSubscription;
 use tracing::Instrument;
 
 pub struct WorkerApi {
@@ -35,6 +38,7 @@ impl WorkerApi {
     /// - `name` is the name of the created worker. This has to be unique, but only for a given component
     /// - `args` is a list of strings which appear as command line arguments for the worker
     /// - `env` is a list of key-value pairs (represented by arrays) which appear as environment variables for the worker
+    /// - `quotas` optionally overrides the resource quotas defined for the component, for this worker only
     #[oai(
         path = "/:component_id/workers",
         method = "post",
@@ -68,7 +72,12 @@ impl WorkerApi {
                     }))
                 })?;
 
-            let WorkerCreationRequest { name, args, env } = request.0;
+            let WorkerCreationRequest {
+                name,
+                args,
+                env,
+                quotas,
+            } = request.0;
 
             let worker_id = make_worker_id(component_id, name)?;
             let worker_id = self
@@ -78,6 +87,7 @@ impl WorkerApi {
                     latest_component.versioned_component_id.version,
                     args,
                     env,
+                    quotas,
                     empty_worker_metadata(),
                     &EmptyAuthCtx::default(),
                 )
@@ -136,6 +146,8 @@ impl WorkerApi {
         &self,
         component_id: Path<ComponentId>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
+        traceparent: Header<Option<String>>,
+        tracestate: Header<Option<String>>,
         function: Query<String>,
         params: Json<InvokeParameters>,
     ) -> Result<Json<InvokeResult>> {
@@ -155,7 +167,7 @@ impl WorkerApi {
                 idempotency_key.0,
                 function.0,
                 params.0.params,
-                None,
+                trace_invocation_context(traceparent.0, tracestate.0),
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -179,6 +191,8 @@ impl WorkerApi {
         component_id: Path<ComponentId>,
         worker_name: Path<String>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
+        traceparent: Header<Option<String>>,
+        tracestate: Header<Option<String>>,
         function: Query<String>,
         params: Json<InvokeParameters>,
     ) -> Result<Json<InvokeResult>> {
@@ -198,7 +212,7 @@ impl WorkerApi {
                 idempotency_key.0,
                 function.0,
                 params.0.params,
-                None,
+                trace_invocation_context(traceparent.0, tracestate.0),
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -221,6 +235,8 @@ impl WorkerApi {
         &self,
         component_id: Path<ComponentId>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
+        traceparent: Header<Option<String>>,
+        tracestate: Header<Option<String>>,
         function: Query<String>,
         params: Json<InvokeParameters>,
     ) -> Result<Json<InvokeResponse>> {
@@ -240,7 +256,7 @@ impl WorkerApi {
                 idempotency_key.0,
                 function.0,
                 params.0.params,
-                None,
+                trace_invocation_context(traceparent.0, tracestate.0),
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -264,6 +280,8 @@ impl WorkerApi {
         component_id: Path<ComponentId>,
         worker_name: Path<String>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
+        traceparent: Header<Option<String>>,
+        tracestate: Header<Option<String>>,
         function: Query<String>,
         params: Json<InvokeParameters>,
     ) -> Result<Json<InvokeResponse>> {
@@ -283,7 +301,7 @@ impl WorkerApi {
                 idempotency_key.0,
                 function.0,
                 params.0.params,
-                None,
+                trace_invocation_context(traceparent.0, tracestate.0),
                 empty_worker_metadata(),
             )
             .instrument(record.span.clone())
@@ -753,6 +771,618 @@ impl WorkerApi {
             .map(GetFileResponseContent::from)
             .map(GetFileApiResponse::Ok)
     }
+
+    /// Writes a file to the worker's file system
+    ///
+    /// Creates or overwrites the file at the given path, creating its parent directories if needed.
+    /// Files in the read-only regions of the component's initial file system cannot be changed.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/files/:path",
+        method = "put",
+        operation_id = "put_file"
+    )]
+    async fn put_file(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        path: Path<String>,
+        content: Binary<Vec<u8>>,
+    ) -> Result<Json<PutFileResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "put_file",
+            worker_id = worker_id.to_string(),
+            path = path.0
+        );
+
+        let response = self
+            .worker_service
+            .put_file(
+                &worker_id,
+                &path.0,
+                content.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(PutFileResponse {}));
+
+        record.result(response)
+    }
+
+    /// Deletes a file or directory from the worker's file system
+    ///
+    /// Directories are deleted together with their contents.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/files/:path",
+        method = "delete",
+        operation_id = "delete_file"
+    )]
+    async fn delete_file(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        path: Path<String>,
+    ) -> Result<Json<DeleteFileResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "delete_file",
+            worker_id = worker_id.to_string(),
+            path = path.0
+        );
+
+        let response = self
+            .worker_service
+            .delete_file(
+                &worker_id,
+                &path.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(DeleteFileResponse {}));
+
+        record.result(response)
+    }
+
+    /// Creates a directory in the worker's file system
+    ///
+    /// Missing parent directories are created as well.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/directories/:path",
+        method = "post",
+        operation_id = "make_directory"
+    )]
+    async fn make_directory(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        path: Path<String>,
+    ) -> Result<Json<MakeDirectoryResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "make_directory",
+            worker_id = worker_id.to_string(),
+            path = path.0
+        );
+
+        let response = self
+            .worker_service
+            .make_directory(
+                &worker_id,
+                &path.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(MakeDirectoryResponse {}));
+
+        record.result(response)
+    }
+
+    /// Schedule an invocation
+    ///
+    /// Registers an invocation of a function to be performed by the worker executor, either once at a given point
+    /// in time, or repeatedly using a fixed interval or a cron expression (including the seconds field).
+    /// Each scheduled occurrence is performed exactly once.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules",
+        method = "post",
+        operation_id = "schedule_invocation"
+    )]
+    async fn schedule_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<ScheduleInvocationRequest>,
+    ) -> Result<Json<ScheduledInvocation>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "schedule_invocation",
+            worker_id = worker_id.to_string(),
+            function = request.0.function
+        );
+
+        let ScheduleInvocationRequest {
+            function,
+            params,
+            schedule,
+        } = request.0;
+
+        let response = self
+            .worker_service
+            .schedule_invocation(
+                &worker_id,
+                function,
+                params,
+                schedule.into(),
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
+    /// List the scheduled invocations of a worker
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules",
+        method = "get",
+        operation_id = "get_scheduled_invocations"
+    )]
+    async fn get_scheduled_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+    ) -> Result<Json<ScheduledInvocationsResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_scheduled_invocations",
+            worker_id = worker_id.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .get_scheduled_invocations(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|invocations| Json(ScheduledInvocationsResponse { invocations }));
+
+        record.result(response)
+    }
+
+    /// Cancel a scheduled invocation
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules/:schedule_id",
+        method = "delete",
+        operation_id = "cancel_scheduled_invocation"
+    )]
+    async fn cancel_scheduled_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        schedule_id: Path<ScheduledInvocationId>,
+    ) -> Result<Json<CancelScheduledInvocationResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "cancel_scheduled_invocation",
+            worker_id = worker_id.to_string(),
+            schedule_id = schedule_id.0.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .cancel_scheduled_invocation(
+                &worker_id,
+                &schedule_id.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(CancelScheduledInvocationResponse {}));
+
+        record.result(response)
+    }
+
+    /// List the dead-lettered invocations of a worker
+    ///
+    /// Dead-lettered invocations are the ones which failed permanently, after exhausting all their retries,
+    /// on a worker whose component has dead-lettering enabled.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/dead-letters",
+        method = "get",
+        operation_id = "get_dead_lettered_invocations"
+    )]
+    async fn get_dead_lettered_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+    ) -> Result<Json<DeadLetteredInvocationsResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_dead_lettered_invocations",
+            worker_id = worker_id.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .get_dead_lettered_invocations(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|invocations| Json(DeadLetteredInvocationsResponse { invocations }));
+
+        record.result(response)
+    }
+
+    /// Retry a dead-lettered invocation
+    ///
+    /// Enqueues the invocation again on its worker with its original idempotency key, and removes it from
+    /// the worker's dead letters.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/dead-letters/:dead_letter_id/retry",
+        method = "post",
+        operation_id = "retry_dead_lettered_invocation"
+    )]
+    async fn retry_dead_lettered_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        dead_letter_id: Path<DeadLetteredInvocationId>,
+    ) -> Result<Json<RetryDeadLetteredInvocationResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "retry_dead_lettered_invocation",
+            worker_id = worker_id.to_string(),
+            dead_letter_id = dead_letter_id.0.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .retry_dead_lettered_invocation(
+                &worker_id,
+                &dead_letter_id.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(RetryDeadLetteredInvocationResponse {}));
+
+        record.result(response)
+    }
+
+    /// Discard a dead-lettered invocation
+    #[oai(
+        path = "/:component_id/workers/:worker_name/dead-letters/:dead_letter_id",
+        method = "delete",
+        operation_id = "discard_dead_lettered_invocation"
+    )]
+    async fn discard_dead_lettered_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        dead_letter_id: Path<DeadLetteredInvocationId>,
+    ) -> Result<Json<DiscardDeadLetteredInvocationResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "discard_dead_lettered_invocation",
+            worker_id = worker_id.to_string(),
+            dead_letter_id = dead_letter_id.0.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .discard_dead_lettered_invocation(
+                &worker_id,
+                &dead_letter_id.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(DiscardDeadLetteredInvocationResponse {}));
+
+        record.result(response)
+    }
+
+    /// Get the dead-letter policy of a component
+    #[oai(
+        path = "/:component_id/dead-letter-policy",
+        method = "get",
+        operation_id = "get_dead_letter_policy"
+    )]
+    async fn get_dead_letter_policy(
+        &self,
+        component_id: Path<ComponentId>,
+    ) -> Result<Json<DeadLetterPolicy>> {
+        let record = recorded_http_api_request!(
+            "get_dead_letter_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .get_dead_letter_policy(
+                &component_id.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(Json);
+
+        record.result(response)
+    }
+
+    /// Update the dead-letter policy of a component
+    ///
+    /// When enabled, an invocation which fails permanently no longer fails its worker. Instead it gets recorded
+    /// as a dead letter of the worker, and the worker continues with its next pending invocation.
+    #[oai(
+        path = "/:component_id/dead-letter-policy",
+        method = "put",
+        operation_id = "update_dead_letter_policy"
+    )]
+    async fn update_dead_letter_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        policy: Json<DeadLetterPolicy>,
+    ) -> Result<Json<DeadLetterPolicy>> {
+        let record = recorded_http_api_request!(
+            "update_dead_letter_policy",
+            component_id = component_id.0.to_string(),
+            enabled = policy.0.enabled
+        );
+
+        let response = self
+            .worker_service
+            .set_dead_letter_policy(
+                &component_id.0,
+                policy.0.clone(),
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(policy.0));
+
+        record.result(response)
+    }
+
+    /// List the topic subscriptions of a worker
+    #[oai(
+        path = "/:component_id/workers/:worker_name/subscriptions",
+        method = "get",
+        operation_id = "get_worker_topic_subscriptions"
+    )]
+    async fn get_worker_topic_subscriptions(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+    ) -> Result<Json<TopicSubscriptionsResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_worker_topic_subscriptions",
+            worker_id = worker_id.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .get_worker_topic_subscriptions(
+                &worker_id,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|subscriptions| Json(TopicSubscriptionsResponse { subscriptions }));
+
+        record.result(response)
+    }
+
+    /// Subscribe a worker to a topic
+    ///
+    /// Every message published to the topic after subscribing gets delivered to the worker by invoking the given
+    /// function, with the topic's name and the message's payload as parameters. Delivery is at-least-once, and
+    /// redelivered messages use the same idempotency key.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/subscriptions",
+        method = "post",
+        operation_id = "subscribe_to_topic"
+    )]
+    async fn subscribe_to_topic(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<SubscribeToTopicRequest>,
+    ) -> Result<Json<SubscribeToTopicResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "subscribe_to_topic",
+            worker_id = worker_id.to_string(),
+            topic = request.topic
+        );
+
+        let SubscribeToTopicRequest {
+            topic,
+            function_name,
+        } = request.0;
+
+        let response = self
+            .worker_service
+            .subscribe_to_topic(
+                TopicSubscription {
+                    topic,
+                    worker_id,
+                    function_name,
+                },
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(SubscribeToTopicResponse {}));
+
+        record.result(response)
+    }
+
+    /// Unsubscribe a worker from a topic
+    #[oai(
+        path = "/:component_id/workers/:worker_name/subscriptions/:topic",
+        method = "delete",
+        operation_id = "unsubscribe_from_topic"
+    )]
+    async fn unsubscribe_from_topic(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        topic: Path<String>,
+        #[oai(name = "function-name")] function_name: Query<String>,
+    ) -> Result<Json<UnsubscribeFromTopicResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "unsubscribe_from_topic",
+            worker_id = worker_id.to_string(),
+            topic = topic.0
+        );
+
+        let response = self
+            .worker_service
+            .unsubscribe_from_topic(
+                TopicSubscription {
+                    topic: topic.0,
+                    worker_id,
+                    function_name: function_name.0,
+                },
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(UnsubscribeFromTopicResponse {}));
+
+        record.result(response)
+    }
+
+    /// Fork a worker
+    ///
+    /// Creates a new worker with the given name, whose state is a copy of the source worker's state
+    /// up to and including the given oplog index.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/fork",
+        method = "post",
+        operation_id = "fork_worker"
+    )]
+    async fn fork_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        params: Json<ForkWorkerRequest>,
+    ) -> Result<Json<ForkWorkerResponse>> {
+        let source_worker_id = make_worker_id(component_id.0.clone(), worker_name.0)?;
+        let target_worker_id = make_worker_id(component_id.0, params.0.target_worker_name)?;
+
+        let record = recorded_http_api_request!(
+            "fork_worker",
+            worker_id = source_worker_id.to_string(),
+            target_worker_id = target_worker_id.to_string(),
+            oplog_index_cutoff = params.0.oplog_index_cutoff
+        );
+
+        let response = self
+            .worker_service
+            .fork_worker(
+                &source_worker_id,
+                &target_worker_id,
+                OplogIndex::from_u64(params.0.oplog_index_cutoff),
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(ForkWorkerResponse {}));
+
+        record.result(response)
+    }
+
+    /// Revert a worker
+    ///
+    /// Reverts a worker by undoing either the last few invocations or the last few recorded oplog entries.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/revert",
+        method = "post",
+        operation_id = "revert_worker"
+    )]
+    async fn revert_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        target: Json<RevertWorkerTarget>,
+    ) -> Result<Json<RevertWorkerResponse>> {
+        let worker_id = make_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "revert_worker",
+            worker_id = worker_id.to_string(),
+            target = target.0.to_string()
+        );
+
+        let response = self
+            .worker_service
+            .revert_worker(
+                &worker_id,
+                target.0,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .instrument(record.span.clone())
+            .await
+            .map_err(|e| e.into())
+            .map(|_| Json(RevertWorkerResponse {}));
+
+        record.result(response)
+    }
 }
 
 fn make_worker_id(
@@ -17,12 +17,14 @@ use std::time::Duration;
 use crate::empty_worker_metadata;
 use crate::service::worker::WorkerService;
 use futures::StreamExt;
-use golem_common::model::{ComponentId, WorkerId};
+use golem_common::model::{ComponentId, WorkerFilter, WorkerId};
 use golem_common::recorded_http_api_request;
 use golem_service_base::auth::EmptyAuthCtx;
 use golem_service_base::model::{validate_worker_name, ErrorsBody};
 use golem_worker_service_base::api::WorkerApiBaseError;
-use golem_worker_service_base::service::worker::{proxy_worker_connection, ConnectWorkerStream};
+use golem_worker_service_base::service::worker::{
+    proxy_worker_connection, proxy_worker_status_changes, ConnectWorkerStream, WatchWorkersStream,
+};
 use poem::web::websocket::WebSocket;
 use poem::web::{Data, Path};
 use poem::*;
@@ -69,6 +71,36 @@ pub async fn ws(
         .unwrap_or_else(|err| err)
 }
 
+#[handler]
+pub async fn watch_ws(
+    Path(component_id): Path<ComponentId>,
+    uri: &http::Uri,
+    websocket: WebSocket,
+    Data(service): Data<&ConnectService>,
+) -> Response {
+    watch_workers(service, component_id, uri.query())
+        .await
+        .map(|(component_id, status_stream)| {
+            websocket
+                .on_upgrade(move |socket| {
+                    tokio::spawn(async move {
+                        let (sink, stream) = socket.split();
+                        let _ = proxy_worker_status_changes(
+                            component_id,
+                            status_stream,
+                            sink,
+                            stream,
+                            PING_INTERVAL,
+                            PING_TIMEOUT,
+                        )
+                        .await;
+                    })
+                })
+                .into_response()
+        })
+        .unwrap_or_else(|err| err)
+}
+
 const PING_INTERVAL: Duration = Duration::from_secs(30);
 const PING_TIMEOUT: Duration = Duration::from_secs(15);
 
@@ -110,3 +142,49 @@ async fn connect_to_worker(
         }
     }
 }
+
+async fn watch_workers(
+    service: &ConnectService,
+    component_id: ComponentId,
+    query: Option<&str>,
+) -> Result<(ComponentId, WatchWorkersStream), Response> {
+    // The filter query parameter can be repeated, the same way as for the worker metadata endpoint
+    let filters: Vec<String> = url::form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+        .filter(|(key, _)| key == "filter")
+        .map(|(_, value)| value.into_owned())
+        .collect();
+    let filter = if filters.is_empty() {
+        None
+    } else {
+        Some(WorkerFilter::from(filters).map_err(|e| {
+            let error = WorkerApiBaseError::BadRequest(Json(ErrorsBody {
+                errors: vec![format!("Invalid worker filter: {e}")],
+            }));
+            error.into_response()
+        })?)
+    };
+
+    let record =
+        recorded_http_api_request!("watch_workers", component_id = component_id.to_string());
+
+    let result = service
+        .worker_service
+        .watch(
+            &component_id,
+            filter,
+            empty_worker_metadata(),
+            &EmptyAuthCtx::default(),
+        )
+        .instrument(record.span.clone())
+        .await;
+
+    match result {
+        Ok(status_stream) => record.succeed(Ok((component_id, status_stream))),
+        Err(error) => {
+            tracing::error!("Error watching workers: {error}");
+            let error = WorkerApiBaseError::from(error);
+            let error = record.fail(error.clone(), &error);
+            Err(error.into_response())
+        }
+    }
+}
@@ -22,7 +22,7 @@ use tracing::Instrument;
 use golem_api_grpc::proto::golem::common::{Empty, ErrorBody, ErrorsBody, FileSystemNode};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_server::WorkerService as GrpcWorkerService;
 use golem_api_grpc::proto::golem::worker::v1::{
-    complete_promise_response, delete_worker_response, get_file_response, get_file_success_response, get_files_response, get_oplog_response, get_worker_metadata_response, get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response, invoke_and_await_typed_response, invoke_response, launch_new_worker_response, resume_worker_response, search_oplog_response, update_worker_response, worker_error, worker_execution_error, CompletePromiseRequest, CompletePromiseResponse, ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse, FileChunk, GetFileRequest, GetFileResponse, GetFileSuccessResponse, GetFilesRequest, GetFilesResponse, GetFilesSuccessResponse, GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse, UnknownError, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError as GrpcWorkerError, WorkerExecutionError
+    complete_promise_response, delete_file_response, delete_worker_response, get_file_response, get_file_success_response, get_files_response, get_oplog_response, get_worker_metadata_response, get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response, invoke_and_await_typed_response, invoke_response, launch_new_worker_response, make_directory_response, put_file_response, resume_worker_response, revert_worker_response, search_oplog_response, update_worker_response, worker_error, worker_execution_error, CompletePromiseRequest, CompletePromiseResponse, ConnectWorkerRequest, DeleteFileRequest, DeleteFileResponse, DeleteWorkerRequest, DeleteWorkerResponse, FileChunk, GetFileRequest, GetFileResponse, GetFileSuccessResponse, GetFilesRequest, GetFilesResponse, GetFilesSuccessResponse, GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse, MakeDirectoryRequest, MakeDirectoryResponse, PutFileRequest, PutFileResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse, UnknownError, UpdateWorkerRequest, UpdateWorkerResponse, WatchWorkersRequest, WorkerError as GrpcWorkerError, WorkerExecutionError
 };
 use golem_api_grpc::proto::golem::worker::{InvokeResult, InvokeResultTyped, WorkerMetadata};
 use golem_common::grpc::{
@@ -31,12 +31,14 @@ use golem_common::grpc::{
     proto_worker_id_string,
 };
 use golem_common::model::oplog::OplogIndex;
-use golem_common::model::{ComponentVersion, ScanCursor, TargetWorkerId, WorkerFilter, WorkerId};
+use golem_common::model::{
+    ComponentVersion, RevertWorkerTarget, ScanCursor, TargetWorkerId, WorkerFilter, WorkerId,
+};
 use golem_common::recorded_grpc_api_request;
 use golem_service_base::auth::EmptyAuthCtx;
 use golem_service_base::model::validate_worker_name;
 use golem_worker_service_base::api::WorkerTraceErrorKind;
-use golem_worker_service_base::service::worker::ConnectWorkerStream;
+use golem_worker_service_base::service::worker::{ConnectWorkerStream, WatchWorkersStream};
 
 use crate::empty_worker_metadata;
 use crate::service::component::ComponentService;
@@ -400,6 +402,28 @@ impl GrpcWorkerService for WorkerGrpcApi {
         }
     }
 
+    type WatchWorkersStream = golem_worker_service_base::service::worker::WatchWorkersStream;
+
+    async fn watch_workers(
+        &self,
+        request: Request<WatchWorkersRequest>,
+    ) -> Result<Response<Self::WatchWorkersStream>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "watch_workers",
+            component_id = proto_component_id_string(&request.component_id),
+        );
+
+        let stream = self
+            .watch_workers(request)
+            .instrument(record.span.clone())
+            .await;
+        match stream {
+            Ok(stream) => Ok(Response::new(stream)),
+            Err(error) => Err(error_to_status(error)),
+        }
+    }
+
     async fn get_workers_metadata(
         &self,
         request: Request<GetWorkersMetadataRequest>,
@@ -561,6 +585,113 @@ impl GrpcWorkerService for WorkerGrpcApi {
             Err(error) => Err(error_to_status(error)),
         }
     }
+
+    async fn revert_worker(
+        &self,
+        request: Request<RevertWorkerRequest>,
+    ) -> Result<Response<RevertWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "revert_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .revert_worker(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(revert_worker_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                revert_worker_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(RevertWorkerResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn put_file(
+        &self,
+        request: Request<PutFileRequest>,
+    ) -> Result<Response<PutFileResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "put_file",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            path = request.path,
+        );
+
+        let response = match self.put_file(request).instrument(record.span.clone()).await {
+            Ok(()) => record.succeed(put_file_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                put_file_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(PutFileResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn delete_file(
+        &self,
+        request: Request<DeleteFileRequest>,
+    ) -> Result<Response<DeleteFileResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_file",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            path = request.path,
+        );
+
+        let response = match self
+            .delete_file(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(delete_file_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                delete_file_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(DeleteFileResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn make_directory(
+        &self,
+        request: Request<MakeDirectoryRequest>,
+    ) -> Result<Response<MakeDirectoryResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "make_directory",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            path = request.path,
+        );
+
+        let response = match self
+            .make_directory(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(make_directory_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                make_directory_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(MakeDirectoryResponse {
+            result: Some(response),
+        }))
+    }
 }
 
 impl WorkerGrpcApi {
@@ -595,6 +726,7 @@ impl WorkerGrpcApi {
                 latest_component.versioned_component_id.version,
                 request.args,
                 request.env,
+                request.quotas.map(|quotas| quotas.into()),
                 empty_worker_metadata(),
                 &EmptyAuthCtx::default(),
             )
@@ -882,6 +1014,37 @@ impl WorkerGrpcApi {
         Ok(stream)
     }
 
+    async fn watch_workers(
+        &self,
+        request: WatchWorkersRequest,
+    ) -> Result<WatchWorkersStream, GrpcWorkerError> {
+        let component_id: golem_common::model::ComponentId = request
+            .component_id
+            .ok_or_else(|| bad_request_error("Missing component id"))?
+            .try_into()
+            .map_err(|_| bad_request_error("Invalid component id"))?;
+
+        let filter: Option<WorkerFilter> =
+            match request.filter {
+                Some(f) => Some(f.try_into().map_err(|error| {
+                    bad_request_error(format!("Invalid worker filter: {error}"))
+                })?),
+                _ => None,
+            };
+
+        let stream = self
+            .worker_service
+            .watch(
+                &component_id,
+                filter,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(stream)
+    }
+
     async fn update_worker(&self, request: UpdateWorkerRequest) -> Result<(), GrpcWorkerError> {
         let worker_id = validate_protobuf_worker_id(request.worker_id.clone())?;
 
@@ -1003,6 +1166,72 @@ impl WorkerGrpcApi {
         })
     }
 
+    async fn revert_worker(&self, request: RevertWorkerRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let target: RevertWorkerTarget = request
+            .target
+            .ok_or_else(|| bad_request_error("Missing revert target"))?
+            .try_into()
+            .map_err(|error| bad_request_error(format!("Invalid revert target: {error}")))?;
+
+        self.worker_service
+            .revert_worker(
+                &worker_id,
+                target,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn put_file(&self, request: PutFileRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .put_file(
+                &worker_id,
+                &request.path,
+                request.content,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, request: DeleteFileRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .delete_file(
+                &worker_id,
+                &request.path,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn make_directory(&self, request: MakeDirectoryRequest) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        self.worker_service
+            .make_directory(
+                &worker_id,
+                &request.path,
+                empty_worker_metadata(),
+                &EmptyAuthCtx::default(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn get_file(
         &self,
         request: GetFileRequest,
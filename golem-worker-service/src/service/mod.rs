@@ -14,6 +14,7 @@ use golem_worker_service_base::http::InputHttpRequest;
 
 use golem_worker_service_base::repo::api_definition;
 use golem_worker_service_base::repo::api_deployment;
+use golem_worker_service_base::repo::rib_module;
 use golem_worker_service_base::service::api_definition::{
     ApiDefinitionService, ApiDefinitionServiceDefault,
 };
@@ -25,6 +26,7 @@ use golem_worker_service_base::service::component::RemoteComponentService;
 use golem_worker_service_base::service::http::http_api_definition_validator::{
     HttpApiDefinitionValidator, RouteValidationError,
 };
+use golem_worker_service_base::service::rib_module::{RibModuleService, RibModuleServiceDefault};
 use golem_worker_service_base::service::worker::WorkerServiceDefault;
 use golem_worker_service_base::worker_bridge_execution::WorkerRequestExecutor;
 
@@ -52,6 +54,7 @@ pub struct Services {
     >,
     pub deployment_service:
         Arc<dyn ApiDeploymentService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
+    pub rib_module_service: Arc<dyn RibModuleService<EmptyAuthCtx, DefaultNamespace> + Sync + Send>,
     pub http_definition_lookup_service:
         Arc<dyn ApiDefinitionsLookup<InputHttpRequest, CompiledHttpApiDefinition> + Sync + Send>,
     pub worker_to_http_service: Arc<dyn WorkerRequestExecutor + Sync + Send>,
@@ -108,7 +111,7 @@ impl Services {
             UnauthorisedWorkerRequestExecutor::new(worker_service.clone()),
         );
 
-        let (api_definition_repo, api_deployment_repo) = match config.db.clone() {
+        let (api_definition_repo, api_deployment_repo, rib_module_repo) = match config.db.clone() {
             DbConfig::Postgres(c) => {
                 let db_pool = db::create_postgres_pool(&c)
                     .await
@@ -121,7 +124,11 @@ impl Services {
                     Arc::new(api_deployment::LoggedDeploymentRepo::new(
                         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
                     ));
-                (api_definition_repo, api_deployment_repo)
+                let rib_module_repo: Arc<dyn rib_module::RibModuleRepo + Sync + Send> =
+                    Arc::new(rib_module::LoggedRibModuleRepo::new(
+                        rib_module::DbRibModuleRepo::new(db_pool.clone().into()),
+                    ));
+                (api_definition_repo, api_deployment_repo, rib_module_repo)
             }
             DbConfig::Sqlite(c) => {
                 let db_pool = db::create_sqlite_pool(&c)
@@ -135,7 +142,11 @@ impl Services {
                     Arc::new(api_deployment::LoggedDeploymentRepo::new(
                         api_deployment::DbApiDeploymentRepo::new(db_pool.clone().into()),
                     ));
-                (api_definition_repo, api_deployment_repo)
+                let rib_module_repo: Arc<dyn rib_module::RibModuleRepo + Sync + Send> =
+                    Arc::new(rib_module::LoggedRibModuleRepo::new(
+                        rib_module::DbRibModuleRepo::new(db_pool.clone().into()),
+                    ));
+                (api_definition_repo, api_deployment_repo, rib_module_repo)
             }
         };
 
@@ -149,6 +160,7 @@ impl Services {
             component_service.clone(),
             api_definition_repo.clone(),
             api_deployment_repo.clone(),
+            rib_module_repo.clone(),
             api_definition_validator_service.clone(),
         ));
 
@@ -160,6 +172,10 @@ impl Services {
             component_service.clone(),
         ));
 
+        let rib_module_service: Arc<
+            dyn RibModuleService<EmptyAuthCtx, DefaultNamespace> + Sync + Send,
+        > = Arc::new(RibModuleServiceDefault::new(rib_module_repo.clone()));
+
         let http_definition_lookup_service =
             Arc::new(HttpApiDefinitionLookup::new(deployment_service.clone()));
 
@@ -167,6 +183,7 @@ impl Services {
             worker_service,
             definition_service,
             deployment_service,
+            rib_module_service,
             http_definition_lookup_service,
             worker_to_http_service,
             component_service,
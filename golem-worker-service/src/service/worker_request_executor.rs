@@ -94,7 +94,7 @@ mod internal {
                 worker_request_params.idempotency_key,
                 worker_request_params.function_name,
                 invoke_parameters,
-                None,
+                worker_request_params.invocation_context,
                 empty_worker_metadata(),
             )
             .await
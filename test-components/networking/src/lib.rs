@@ -1,11 +1,26 @@
 mod bindings;
 
 use crate::bindings::exports::golem::it::api::*;
+use crate::bindings::wasi::clocks::monotonic_clock::subscribe_duration;
+use crate::bindings::wasi::io::poll::poll;
 use crate::bindings::wasi::sockets::instance_network::*;
 use crate::bindings::wasi::sockets::ip_name_lookup::*;
+use crate::bindings::wasi::sockets::network::{
+    ErrorCode, IpAddressFamily, IpSocketAddress, Ipv4SocketAddress,
+};
+use crate::bindings::wasi::sockets::tcp_create_socket::create_tcp_socket;
+use crate::bindings::wasi::sockets::udp::OutgoingDatagram;
+use crate::bindings::wasi::sockets::udp_create_socket::create_udp_socket;
 
 struct Component;
 
+fn localhost(port: u16) -> IpSocketAddress {
+    IpSocketAddress::Ipv4(Ipv4SocketAddress {
+        port,
+        address: (127, 0, 0, 1),
+    })
+}
+
 impl Guest for Component {
     fn get() -> Vec<String> {
         let network = instance_network();
@@ -25,6 +40,92 @@ impl Guest for Component {
 
         result
     }
+
+    // Sends each message as a line to a TCP server on localhost and collects the replies,
+    // stopping at the first error
+    fn tcp_exchange(port: u16, messages: Vec<String>) -> Vec<String> {
+        let network = instance_network();
+        let socket = create_tcp_socket(IpAddressFamily::Ipv4).expect("create_tcp_socket");
+        if let Err(error) = socket.start_connect(&network, localhost(port)) {
+            return vec![format!("error: {error:?}")];
+        }
+        let (input, output) = loop {
+            match socket.finish_connect() {
+                Ok(streams) => break streams,
+                Err(ErrorCode::WouldBlock) => socket.subscribe().block(),
+                Err(error) => return vec![format!("error: {error:?}")],
+            }
+        };
+
+        let mut result = Vec::new();
+        for message in messages {
+            if let Err(error) = output.blocking_write_and_flush(format!("{message}\n").as_bytes()) {
+                result.push(format!("error: {error:?}"));
+                break;
+            }
+            match input.blocking_read(1024) {
+                Ok(reply) => result.push(String::from_utf8_lossy(&reply).trim_end().to_string()),
+                Err(error) => {
+                    result.push(format!("error: {error:?}"));
+                    break;
+                }
+            }
+        }
+        result
+    }
+
+    // Sends each message as a datagram to a UDP server on localhost and collects the replies,
+    // stopping at the first error or after waiting 5 seconds for a reply
+    fn udp_exchange(port: u16, messages: Vec<String>) -> Vec<String> {
+        let network = instance_network();
+        let socket = create_udp_socket(IpAddressFamily::Ipv4).expect("create_udp_socket");
+        socket
+            .start_bind(&network, localhost(0))
+            .expect("start_bind");
+        loop {
+            match socket.finish_bind() {
+                Ok(()) => break,
+                Err(ErrorCode::WouldBlock) => socket.subscribe().block(),
+                Err(error) => return vec![format!("error: {error:?}")],
+            }
+        }
+        let (incoming, outgoing) = socket.stream(Some(localhost(port))).expect("stream");
+
+        let mut result = Vec::new();
+        for message in messages {
+            let outgoing_ready = outgoing.subscribe();
+            while outgoing.check_send() == Ok(0) {
+                outgoing_ready.block();
+            }
+            let datagram = OutgoingDatagram {
+                data: message.into_bytes(),
+                remote_address: None,
+            };
+            if let Err(error) = outgoing.send(&[datagram]) {
+                result.push(format!("error: {error:?}"));
+                break;
+            }
+
+            let incoming_ready = incoming.subscribe();
+            let timeout = subscribe_duration(5_000_000_000);
+            if !poll(&[&incoming_ready, &timeout]).contains(&0) {
+                result.push("timeout".to_string());
+                break;
+            }
+            match incoming.receive(1) {
+                Ok(datagrams) => result.extend(
+                    datagrams
+                        .into_iter()
+                        .map(|datagram| String::from_utf8_lossy(&datagram.data).to_string()),
+                ),
+                Err(error) => {
+                    result.push(format!("error: {error:?}"));
+                    break;
+                }
+            }
+        }
+        result
+    }
 }
 
 bindings::export!(Component with_types_in bindings);